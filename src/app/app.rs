@@ -3,6 +3,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use crate::app::constants::TICK_RATE;
+use indexmap::IndexMap;
 use parking_lot::{Mutex, RwLock};
 use ratatui::Terminal;
 use ratatui::backend::{Backend, CrosstermBackend};
@@ -11,7 +12,10 @@ use strum::VariantArray;
 use throbber_widgets_tui::ThrobberState;
 
 use crate::app::files::config::Config;
+use crate::app::files::cookies::CollectionCookieStores;
 use crate::app::files::theme::THEME;
+use crate::models::auth::oauth2::OAuth2TokenCache;
+use crate::models::captures::CaptureResult;
 use crate::models::collection::Collection;
 use crate::models::environment::Environment;
 use crate::models::export::ExportFormat;
@@ -19,10 +23,13 @@ use crate::tui::app_states::AppState;
 use crate::tui::ui::param_tabs::param_tabs::RequestParamsTabs;
 use crate::tui::ui::result_tabs::RequestResultTabs;
 use crate::tui::ui::views::RequestView;
+use crate::tui::utils::stateful::body_search::BodySearch;
 use crate::tui::utils::stateful::choice_popup::ChoicePopup;
 use crate::tui::utils::stateful::cookies_popup::CookiesPopup;
 use crate::tui::utils::stateful::display_popup::DisplayPopup;
+use crate::tui::utils::stateful::grpc_method_popup::GrpcMethodPopup;
 use crate::tui::utils::stateful::help_popup::HelpPopup;
+use crate::tui::utils::stateful::history_popup::HistoryPopup;
 use crate::tui::utils::stateful::new_request_popup::NewRequestPopup;
 use crate::tui::utils::stateful::script_console::ScriptConsole;
 use crate::tui::utils::stateful::settings_popup::SettingsPopup;
@@ -40,7 +47,7 @@ use ratatui::prelude::{Line, Stylize};
 
 /// Grouped TUI widget state for all authentication text inputs.
 ///
-/// Each auth type (Basic, Bearer, JWT, Digest) has one or more text inputs.
+/// Each auth type (Basic, Bearer, JWT, Digest, OAuth2, AWS SigV4) has one or more text inputs.
 /// The `text_input_selection` tracks which input is currently focused.
 pub struct AuthInputs {
 	pub text_input_selection: TextInputSelection,
@@ -55,6 +62,19 @@ pub struct AuthInputs {
 	pub digest_realm: TextInput,
 	pub digest_nonce: TextInput,
 	pub digest_opaque: TextInput,
+	pub oauth2_token_url: TextInput,
+	pub oauth2_auth_url: TextInput,
+	pub oauth2_client_id: TextInput,
+	pub oauth2_client_secret: TextInput,
+	pub oauth2_scope: TextInput,
+	pub oauth2_username: TextInput,
+	pub oauth2_password: TextInput,
+	pub oauth2_refresh_token: TextInput,
+	pub aws_sig_v4_access_key_id: TextInput,
+	pub aws_sig_v4_secret_access_key: TextInput,
+	pub aws_sig_v4_region: TextInput,
+	pub aws_sig_v4_service: TextInput,
+	pub aws_sig_v4_session_token: TextInput,
 }
 
 /// Grouped TUI widget state for editing the currently-selected request.
@@ -82,6 +102,10 @@ pub struct CollectionPopups {
 	pub rename_collection_input: TextInput,
 	pub new_request_popup: NewRequestPopup,
 	pub rename_request_input: TextInput,
+	/// YAML document of the auth, headers and scripts of a collection or a folder
+	pub properties_text_area: TextInput,
+	/// Why the properties document could not be parsed
+	pub properties_error: Option<String>,
 	pub delete_collection_popup: ValidationPopup,
 	pub delete_request_popup: ValidationPopup,
 }
@@ -89,14 +113,25 @@ pub struct CollectionPopups {
 /// Grouped TUI widget state for displaying response results.
 ///
 /// Contains the loading throbber, vertical/horizontal scrollbars for the
-/// result pane, and the text area for response body selection.
+/// result pane, the text area for response body selection, the body
+/// search and filter, and the path the body is saved to.
 pub struct ResponseViewState {
 	pub throbber_state: ThrobberState,
 	pub vertical_scrollbar: StatefulScrollbar,
 	pub horizontal_scrollbar: StatefulScrollbar,
 	pub body_text_area: TextInput,
+	pub body_search: BodySearch,
+	pub body_filter_input: TextInput,
+	/// Path the response body is saved to
+	pub body_save_input: TextInput,
+	/// Response body projected by the filter of the selected request, or why it could not be
+	pub filtered_body: Option<Result<String, String>>,
 }
 
+/// Capture results of a request sent in the background, with its collection index,
+/// waiting to be written to an environment
+pub type PendingCaptures = (Option<usize>, Vec<CaptureResult>);
+
 /// Core application state shared between TUI and CLI modes.
 ///
 /// This struct holds the data that is needed by both the interactive TUI and
@@ -107,8 +142,13 @@ pub struct CoreState {
 	pub collections: Vec<Collection>,
 	pub environments: Vec<Arc<RwLock<Environment>>>,
 	pub selected_environment: usize,
+	/// Values of the `.env.local` and `.env.<name>.local` override files, by file name
+	pub local_environments: IndexMap<String, IndexMap<String, String>>,
 	pub cookies_popup: CookiesPopup,
+	pub collection_cookie_stores: CollectionCookieStores,
+	pub oauth2_tokens: Arc<RwLock<OAuth2TokenCache>>,
 	pub received_response: Arc<Mutex<bool>>,
+	pub pending_captures: Arc<Mutex<Vec<PendingCaptures>>>,
 	pub env_json_changed: Arc<Mutex<bool>>,
 	pub _env_watcher: Option<notify::RecommendedWatcher>,
 }
@@ -128,11 +168,16 @@ pub struct App<'a> {
 
 	/* Environments */
 	pub env_editor_table: StatefulCustomTable<'a>,
+	/// Show the secret environment values in the env editor and the request exports
+	pub reveal_secrets: bool,
 
 	/* Logs */
 	pub logs_vertical_scrollbar: StatefulScrollbar,
 	pub logs_horizontal_scrollbar: StatefulScrollbar,
 
+	/* History */
+	pub history_popup: HistoryPopup,
+
 	/* Collections */
 	pub collections_tree: StatefulTree<'a>,
 
@@ -174,6 +219,7 @@ pub struct App<'a> {
 
 	/* Theme */
 	pub theme_popup: ThemePopup,
+	pub grpc_method_popup: GrpcMethodPopup,
 
 	#[cfg(feature = "clipboard")]
 	pub clipboard: Option<Clipboard>,
@@ -187,8 +233,12 @@ impl App<'_> {
 				collections: vec![],
 				environments: vec![],
 				selected_environment: 0,
+				local_environments: IndexMap::new(),
 				cookies_popup: CookiesPopup::default(),
+				collection_cookie_stores: CollectionCookieStores::default(),
+				oauth2_tokens: Arc::new(RwLock::new(OAuth2TokenCache::default())),
 				received_response: Arc::new(Mutex::new(false)),
+				pending_captures: Arc::new(Mutex::new(vec![])),
 				env_json_changed: Arc::new(Mutex::new(false)),
 				_env_watcher: None,
 			},
@@ -213,11 +263,15 @@ impl App<'_> {
 				"Key",
 				"Value",
 			),
+			reveal_secrets: false,
 
 			/* Logs */
 			logs_vertical_scrollbar: StatefulScrollbar::default(),
 			logs_horizontal_scrollbar: StatefulScrollbar::default(),
 
+			/* History */
+			history_popup: HistoryPopup::default(),
+
 			/* Collections */
 			collections_tree: StatefulTree::default(),
 
@@ -239,6 +293,8 @@ impl App<'_> {
 				rename_collection_input: TextInput::new(None),
 				new_request_popup: NewRequestPopup::default(),
 				rename_request_input: TextInput::new(None),
+				properties_text_area: TextInput::new_multiline(),
+				properties_error: None,
 				delete_collection_popup: ValidationPopup::default(),
 				delete_request_popup: ValidationPopup::default(),
 			},
@@ -269,6 +325,21 @@ impl App<'_> {
 					digest_realm: TextInput::new(Some(String::from("Realm"))),
 					digest_nonce: TextInput::new(Some(String::from("Nonce"))),
 					digest_opaque: TextInput::new(Some(String::from("Opaque"))),
+					oauth2_token_url: TextInput::new(Some(String::from("Token URL"))),
+					oauth2_auth_url: TextInput::new(Some(String::from("Authorization URL"))),
+					oauth2_client_id: TextInput::new(Some(String::from("Client ID"))),
+					oauth2_client_secret: TextInput::new(Some(String::from("Client secret"))),
+					oauth2_scope: TextInput::new(Some(String::from("Scope"))),
+					oauth2_username: TextInput::new(Some(String::from("Username"))),
+					oauth2_password: TextInput::new(Some(String::from("Password"))),
+					oauth2_refresh_token: TextInput::new(Some(String::from("Refresh token"))),
+					aws_sig_v4_access_key_id: TextInput::new(Some(String::from("Access key ID"))),
+					aws_sig_v4_secret_access_key: TextInput::new(Some(String::from(
+						"Secret access key",
+					))),
+					aws_sig_v4_region: TextInput::new(Some(String::from("Region"))),
+					aws_sig_v4_service: TextInput::new(Some(String::from("Service"))),
+					aws_sig_v4_session_token: TextInput::new(Some(String::from("Session token"))),
 				},
 				headers_table: StatefulCustomTable::new(
 					vec![
@@ -314,6 +385,10 @@ impl App<'_> {
 				vertical_scrollbar: StatefulScrollbar::default(),
 				horizontal_scrollbar: StatefulScrollbar::default(),
 				body_text_area: TextInput::new_multiline(),
+				body_search: BodySearch::default(),
+				body_filter_input: TextInput::new(Some(String::from("Filter"))),
+				body_save_input: TextInput::new(Some(String::from("Save to"))),
+				filtered_body: None,
 			},
 
 			last_messages_area_size: (0, 0),
@@ -333,6 +408,7 @@ impl App<'_> {
 
 			/* Theme */
 			theme_popup: ThemePopup::new(),
+			grpc_method_popup: GrpcMethodPopup::default(),
 
 			#[cfg(feature = "clipboard")]
 			clipboard: Clipboard::new().ok(),
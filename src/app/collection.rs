@@ -1,14 +1,20 @@
 use anyhow::anyhow;
+use indexmap::IndexMap;
 use std::sync::Arc;
 
 use crate::app::App;
 use crate::app::collection::CollectionError::{CollectionNameAlreadyExists, CollectionNameIsEmpty};
-use crate::app::collection::FolderError::{FolderNameAlreadyExists, FolderNameIsEmpty};
+use crate::app::collection::FolderError::{
+	CannotMoveFolderIntoItself, FolderNameAlreadyExists, FolderNameIsEmpty,
+};
 use crate::app::collection::RequestError::RequestNameIsEmpty;
 use crate::cli::args::ARGS;
 use crate::models::collection::Collection;
 use crate::models::folder::Folder;
+use crate::models::inheritance::SharedProperties;
 use crate::models::request::Request;
+use crate::models::scripts::RequestScripts;
+use crate::models::settings::TlsSettings;
 use parking_lot::RwLock;
 use thiserror::Error;
 use tracing::info;
@@ -33,6 +39,8 @@ pub enum FolderError {
 	FolderNameIsEmpty,
 	#[error("A folder with this name already exists in this collection")]
 	FolderNameAlreadyExists,
+	#[error("A folder cannot be moved into itself")]
+	CannotMoveFolderIntoItself,
 }
 
 impl App<'_> {
@@ -67,6 +75,11 @@ impl App<'_> {
 			requests: vec![],
 			environments: vec![],
 			selected_environment: None,
+			variables: IndexMap::new(),
+			tls: TlsSettings::default(),
+			auth: None,
+			headers: vec![],
+			scripts: RequestScripts::default(),
 			path: ARGS
 				.directory
 				.as_ref()
@@ -111,7 +124,7 @@ impl App<'_> {
 	pub fn new_request_in_folder(
 		&mut self,
 		collection_index: usize,
-		folder_index: usize,
+		folder_path: &[usize],
 		mut new_request: Request,
 	) -> Result<(), RequestError> {
 		new_request.name = sanitize_name(new_request.name);
@@ -121,11 +134,14 @@ impl App<'_> {
 		}
 
 		let collection = &mut self.core.collections[collection_index];
-		let folder = &mut collection.folders[folder_index];
+		let collection_name = collection.name.clone();
+		let folder = collection
+			.folder_mut(folder_path)
+			.expect("folder path should be valid");
 
 		info!(
 			"Request \"{}\" created in folder \"{}\" of collection \"{}\"",
-			new_request.name, folder.name, collection.name
+			new_request.name, folder.name, collection_name
 		);
 
 		folder.requests.push(Arc::new(RwLock::new(new_request)));
@@ -245,9 +261,11 @@ impl App<'_> {
 
 	// ── Folder operations ───────────────────────────────────────────────
 
+	/// Create a folder in the collection root, or in the folder at `parent_path` when not empty
 	pub fn new_folder(
 		&mut self,
 		collection_index: usize,
+		parent_path: &[usize],
 		new_folder_name: String,
 	) -> anyhow::Result<()> {
 		let new_folder_name = sanitize_name(new_folder_name);
@@ -256,8 +274,10 @@ impl App<'_> {
 			return Err(anyhow!(FolderNameIsEmpty));
 		}
 
-		// Check for duplicate folder names within the collection
-		for folder in &self.core.collections[collection_index].folders {
+		let collection = &mut self.core.collections[collection_index];
+
+		// Check for duplicate folder names next to the new folder
+		for folder in collection.folders_in(parent_path) {
 			if new_folder_name == folder.name {
 				return Err(anyhow!(FolderNameAlreadyExists));
 			}
@@ -265,33 +285,38 @@ impl App<'_> {
 
 		info!(
 			"Folder \"{}\" created in collection \"{}\"",
-			new_folder_name, &self.core.collections[collection_index].name
+			new_folder_name, collection.name
 		);
 
 		let new_folder = Folder {
 			name: new_folder_name,
 			requests: vec![],
+			..Default::default()
 		};
 
-		self.core.collections[collection_index]
-			.folders
-			.push(new_folder);
+		collection.folders_in_mut(parent_path).push(new_folder);
 		self.save_collection_to_file(collection_index);
 
 		Ok(())
 	}
 
-	/// Delete a folder and move its requests to the collection root.
-	pub fn delete_folder(&mut self, collection_index: usize, folder_index: usize) {
-		info!("Folder deleted (requests moved to collection root)");
+	/// Delete a folder and move its sub-folders and requests to its parent.
+	pub fn delete_folder(&mut self, collection_index: usize, folder_path: &[usize]) {
+		info!("Folder deleted (content moved to its parent)");
 
-		let folder = self.core.collections[collection_index]
-			.folders
-			.remove(folder_index);
+		let Some((folder_index, parent_path)) = folder_path.split_last() else {
+			return;
+		};
 
-		// Move all requests from the folder to the collection's root-level requests
-		self.core.collections[collection_index]
-			.requests
+		let collection = &mut self.core.collections[collection_index];
+		let folder = collection.folders_in_mut(parent_path).remove(*folder_index);
+
+		// Move the content of the folder to its parent, the collection root for a top folder
+		collection
+			.folders_in_mut(parent_path)
+			.extend(folder.folders);
+		collection
+			.requests_in_mut(parent_path)
 			.extend(folder.requests);
 
 		self.save_collection_to_file(collection_index);
@@ -301,13 +326,13 @@ impl App<'_> {
 	pub fn delete_folder_request(
 		&mut self,
 		collection_index: usize,
-		folder_index: usize,
+		folder_path: &[usize],
 		request_index: usize,
 	) -> anyhow::Result<()> {
 		info!("Request deleted from folder");
 
-		self.core.collections[collection_index].folders[folder_index]
-			.requests
+		self.core.collections[collection_index]
+			.requests_in_mut(folder_path)
 			.remove(request_index);
 		self.save_collection_to_file(collection_index);
 
@@ -317,7 +342,7 @@ impl App<'_> {
 	pub fn rename_folder(
 		&mut self,
 		collection_index: usize,
-		folder_index: usize,
+		folder_path: &[usize],
 		new_folder_name: String,
 	) -> anyhow::Result<()> {
 		let new_folder_name = sanitize_name(new_folder_name);
@@ -326,8 +351,14 @@ impl App<'_> {
 			return Err(anyhow!(FolderNameIsEmpty));
 		}
 
+		let Some((_, parent_path)) = folder_path.split_last() else {
+			return Ok(());
+		};
+
+		let collection = &mut self.core.collections[collection_index];
+
 		// Check for duplicate folder names
-		for folder in &self.core.collections[collection_index].folders {
+		for folder in collection.folders_in(parent_path) {
 			if new_folder_name == folder.name {
 				return Err(anyhow!(FolderNameAlreadyExists));
 			}
@@ -335,7 +366,58 @@ impl App<'_> {
 
 		info!("Folder renamed to \"{new_folder_name}\"");
 
-		self.core.collections[collection_index].folders[folder_index].name = new_folder_name;
+		collection
+			.folder_mut(folder_path)
+			.expect("folder path should be valid")
+			.name = new_folder_name;
+		self.save_collection_to_file(collection_index);
+
+		Ok(())
+	}
+
+	/// Move a folder, with everything inside it, into another folder, or to the collection root
+	/// when the parent path is empty
+	pub fn move_folder(
+		&mut self,
+		collection_index: usize,
+		folder_path: &[usize],
+		new_parent_path: &[usize],
+	) -> anyhow::Result<()> {
+		let Some((folder_index, parent_path)) = folder_path.split_last() else {
+			return Ok(());
+		};
+
+		if new_parent_path.starts_with(folder_path) {
+			return Err(anyhow!(CannotMoveFolderIntoItself));
+		}
+
+		let collection = &mut self.core.collections[collection_index];
+		let folder_name = &collection.folders_in(parent_path)[*folder_index].name;
+
+		if collection
+			.folders_in(new_parent_path)
+			.iter()
+			.any(|folder| &folder.name == folder_name)
+		{
+			return Err(anyhow!(FolderNameAlreadyExists));
+		}
+
+		let folder = collection.folders_in_mut(parent_path).remove(*folder_index);
+
+		// Removing the folder shifts the following sibling folders, the new parent may be one of
+		// them or inside one of them
+		let mut new_parent_path = new_parent_path.to_vec();
+
+		if new_parent_path.len() > parent_path.len()
+			&& new_parent_path.starts_with(parent_path)
+			&& new_parent_path[parent_path.len()] > *folder_index
+		{
+			new_parent_path[parent_path.len()] -= 1;
+		}
+
+		info!("Folder \"{}\" moved", folder.name);
+
+		collection.folders_in_mut(&new_parent_path).push(folder);
 		self.save_collection_to_file(collection_index);
 
 		Ok(())
@@ -345,7 +427,7 @@ impl App<'_> {
 	pub fn rename_folder_request(
 		&mut self,
 		collection_index: usize,
-		folder_index: usize,
+		folder_path: &[usize],
 		request_index: usize,
 		new_request_name: String,
 	) -> anyhow::Result<()> {
@@ -355,7 +437,7 @@ impl App<'_> {
 
 		info!("Request in folder renamed to \"{new_request_name}\"");
 
-		self.core.collections[collection_index].folders[folder_index].requests[request_index]
+		self.core.collections[collection_index].requests_in(folder_path)[request_index]
 			.write()
 			.name = new_request_name;
 		self.save_collection_to_file(collection_index);
@@ -363,19 +445,64 @@ impl App<'_> {
 		Ok(())
 	}
 
+	/// Auth, headers and scripts a collection, or one of its folders when the folder path is not
+	/// empty, shares with its requests
+	pub fn get_shared_properties(
+		&self,
+		collection_index: usize,
+		folder_path: &[usize],
+	) -> SharedProperties {
+		let collection = &self.core.collections[collection_index];
+
+		match collection.folder(folder_path) {
+			Some(folder) => folder.shared_properties(),
+			None => collection.shared_properties(),
+		}
+	}
+
+	pub fn set_shared_properties(
+		&mut self,
+		collection_index: usize,
+		folder_path: &[usize],
+		shared_properties: SharedProperties,
+	) {
+		let collection = &mut self.core.collections[collection_index];
+
+		match folder_path.is_empty() {
+			false => {
+				let folder = collection
+					.folder_mut(folder_path)
+					.expect("folder path should be valid");
+				info!("Folder \"{}\" properties modified", folder.name);
+				folder.set_shared_properties(shared_properties);
+			}
+			true => {
+				info!("Collection \"{}\" properties modified", collection.name);
+				collection.set_shared_properties(shared_properties);
+			}
+		}
+
+		self.save_collection_to_file(collection_index);
+	}
+
 	pub fn duplicate_folder(
 		&mut self,
 		collection_index: usize,
-		folder_index: usize,
+		folder_path: &[usize],
 	) -> anyhow::Result<()> {
-		let folder = self.core.collections[collection_index].folders[folder_index].clone();
+		let Some((folder_index, parent_path)) = folder_path.split_last() else {
+			return Ok(());
+		};
+
+		let collection = &mut self.core.collections[collection_index];
+		let folder = collection.folders_in(parent_path)[*folder_index].clone();
 
 		info!("Folder \"{}\" duplicated", folder.name);
 
 		let mut cloned = folder;
 		cloned.name = format!("{} copy", cloned.name);
-		self.core.collections[collection_index]
-			.folders
+		collection
+			.folders_in_mut(parent_path)
 			.insert(folder_index + 1, cloned);
 		self.save_collection_to_file(collection_index);
 		Ok(())
@@ -384,11 +511,11 @@ impl App<'_> {
 	pub fn duplicate_folder_request(
 		&mut self,
 		collection_index: usize,
-		folder_index: usize,
+		folder_path: &[usize],
 		request_index: usize,
 	) -> anyhow::Result<()> {
-		let request = self.core.collections[collection_index].folders[folder_index].requests
-			[request_index]
+		let collection = &mut self.core.collections[collection_index];
+		let request = collection.requests_in(folder_path)[request_index]
 			.read()
 			.clone();
 
@@ -396,8 +523,8 @@ impl App<'_> {
 
 		let mut cloned = request;
 		cloned.name = format!("{} copy", cloned.name);
-		self.core.collections[collection_index].folders[folder_index]
-			.requests
+		collection
+			.requests_in_mut(folder_path)
 			.insert(request_index + 1, Arc::new(RwLock::new(cloned)));
 		self.save_collection_to_file(collection_index);
 		Ok(())
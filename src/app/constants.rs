@@ -14,3 +14,14 @@ pub const WS_POLL_INTERVAL: Duration = Duration::from_millis(100);
 /// Prefix used in multipart form values to indicate the value is a file path.
 /// For example, `"!!/path/to/file"` means the multipart part should read from `/path/to/file`.
 pub const FILE_VALUE_PREFIX: &str = "!!";
+
+/// Default number of response body bytes kept in memory, the rest being only counted or written
+/// to the output file.
+pub const DEFAULT_MAX_RESPONSE_BODY_SIZE: usize = 10 * 1024 * 1024;
+
+/// Delay before reconnecting to a server-sent events stream, until the server sets another one
+/// with a `retry` field.
+pub const SSE_DEFAULT_RETRY: Duration = Duration::from_secs(3);
+
+/// Number of server-sent events kept by a request, the oldest ones being dropped first.
+pub const SSE_MAX_EVENTS: usize = 10_000;
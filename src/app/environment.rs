@@ -1,20 +1,20 @@
 use anyhow::anyhow;
-use chrono::Utc;
-use indexmap::IndexMap;
 use indexmap::map::MutableKeys;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use std::path::PathBuf;
 use std::sync::Arc;
 use thiserror::Error;
-use tracing::{info, trace};
-use uuid::Uuid;
+use tracing::{info, trace, warn};
 
 use crate::app::App;
 use crate::app::environment::EnvironmentError::{
-	EnvironmentNotFound, KeyAlreadyExists, KeyNotFound,
+	EnvironmentNotFound, InheritsFromItself, KeyAlreadyExists, KeyNotFound, KeyNotSecret,
 };
 use crate::app::files::environment::OS_ENV_VARS;
-use crate::models::environment::Environment;
+use crate::app::files::secrets::{SECRETS, SecretsError};
+use crate::app::template::{Interpolation, evaluate_template};
+use crate::models::environment::{Environment, SECRET_MASK};
 
 #[derive(Error, Debug)]
 pub enum EnvironmentError {
@@ -26,6 +26,28 @@ pub enum EnvironmentError {
 
 	#[error("Key already exists")]
 	KeyAlreadyExists,
+
+	#[error("Key is not secret")]
+	KeyNotSecret,
+
+	#[error("Environment \"{0}\" cannot inherit from itself")]
+	InheritsFromItself(String),
+}
+
+/// Values of an environment layer, with the file they come from
+#[derive(Debug, Clone)]
+pub struct EnvironmentLayer {
+	pub origin: String,
+	pub values: IndexMap<String, String>,
+	pub secret_keys: IndexSet<String>,
+}
+
+/// Secret values can only be changed once the secrets are unlocked
+fn ensure_secrets_unlocked() -> Result<(), SecretsError> {
+	match SECRETS.read().is_locked() {
+		true => Err(SecretsError::Locked),
+		false => Ok(()),
+	}
 }
 
 impl App<'_> {
@@ -74,7 +96,7 @@ impl App<'_> {
 				Some(value) => value,
 			};
 
-			println!("{value}");
+			println!("{}", env.displayed_value(key, value));
 		}
 
 		Ok(())
@@ -93,13 +115,14 @@ impl App<'_> {
 		{
 			let mut env = local_env.write();
 
+			info!(
+				"Environment key \"{key}\" value set to \"{}\"",
+				env.displayed_value(key, &value)
+			);
+
 			match env.values.get_mut(key) {
 				None => return Err(anyhow!(KeyNotFound)),
-				Some(old_value) => {
-					info!("Environment key \"{key}\" value set to \"{value}\"");
-
-					*old_value = value;
-				}
+				Some(old_value) => *old_value = value,
 			}
 		}
 
@@ -120,14 +143,16 @@ impl App<'_> {
 		{
 			let mut env = local_env.write();
 
-			match env.values.get_index_mut(key_index) {
-				None => return Err(anyhow!(KeyNotFound)),
-				Some((key, old_value)) => {
-					info!("Environment key \"{key}\" value set to \"{value}\"");
+			let Some(key) = env.values.get_index(key_index).map(|(key, _)| key.clone()) else {
+				return Err(anyhow!(KeyNotFound));
+			};
 
-					*old_value = value;
-				}
-			}
+			info!(
+				"Environment key \"{key}\" value set to \"{}\"",
+				env.displayed_value(&key, &value)
+			);
+
+			env.values[key_index] = value;
 		}
 
 		self.save_environment_to_file(env_index);
@@ -174,6 +199,8 @@ impl App<'_> {
 				None => return Err(anyhow!(KeyNotFound)),
 				Some(_) => info!("Key \"{key}\" deleted from environment"),
 			}
+
+			env.secret_keys.shift_remove(key);
 		}
 
 		self.save_environment_to_file(env_index);
@@ -190,7 +217,11 @@ impl App<'_> {
 
 			match env.values.shift_remove_index(index) {
 				None => return Err(anyhow!(KeyNotFound)),
-				Some((key, _)) => info!("Key \"{key}\" deleted from environment"),
+				Some((key, _)) => {
+					info!("Key \"{key}\" deleted from environment");
+
+					env.secret_keys.shift_remove(&key);
+				}
 			}
 		}
 
@@ -220,6 +251,10 @@ impl App<'_> {
 				Some(index) => index,
 			};
 
+			if env.secret_keys.shift_remove(key) {
+				env.secret_keys.insert(new_key.to_string());
+			}
+
 			let (key, _) = env
 				.values
 				.get_index_mut2(old_index)
@@ -257,6 +292,10 @@ impl App<'_> {
 			let old_key = key.clone();
 			*key = new_key.clone();
 
+			if env.secret_keys.shift_remove(&old_key) {
+				env.secret_keys.insert(new_key.clone());
+			}
+
 			info!("Environment key \"{old_key}\" renamed to \"{new_key}\"");
 		}
 
@@ -264,20 +303,273 @@ impl App<'_> {
 		Ok(())
 	}
 
-	pub fn replace_env_keys_by_value(&self, input: &String) -> String {
-		if self.core.environments.is_empty() {
-			return input.to_string();
+	/// Set the value of a secret key, adding the key or making it secret when needed
+	pub fn set_env_secret(
+		&mut self,
+		env_index: usize,
+		key: &str,
+		value: String,
+	) -> anyhow::Result<()> {
+		ensure_secrets_unlocked()?;
+
+		let local_env = self
+			.get_env_as_local_from_index(env_index)
+			.ok_or_else(|| anyhow!(EnvironmentNotFound))?;
+
+		{
+			let mut env = local_env.write();
+
+			env.values.insert(key.to_string(), value);
+			env.secret_keys.insert(key.to_string());
+
+			info!("Environment secret \"{key}\" set");
+		}
+
+		self.save_environment_to_file(env_index);
+		Ok(())
+	}
+
+	/// Move the value of a key to the secrets file, or back to the environment file
+	pub fn set_env_key_secret(
+		&mut self,
+		env_index: usize,
+		key: &str,
+		is_secret: bool,
+	) -> anyhow::Result<()> {
+		ensure_secrets_unlocked()?;
+
+		let local_env = self
+			.get_env_as_local_from_index(env_index)
+			.ok_or_else(|| anyhow!(EnvironmentNotFound))?;
+
+		{
+			let mut env = local_env.write();
+
+			if !env.values.contains_key(key) {
+				return Err(anyhow!(KeyNotFound));
+			}
+
+			match is_secret {
+				true => {
+					env.secret_keys.insert(key.to_string());
+					info!("Environment key \"{key}\" is now secret");
+				}
+				false => {
+					env.secret_keys.shift_remove(key);
+					info!("Environment key \"{key}\" is no longer secret");
+				}
+			}
 		}
 
-		let local_env = self.get_selected_env_as_local();
+		self.save_environment_to_file(env_index);
+		Ok(())
+	}
+
+	/// Print the value of a secret key, masked unless revealed
+	pub fn get_env_secret(
+		&mut self,
+		env_index: usize,
+		key: &str,
+		reveal: bool,
+	) -> anyhow::Result<()> {
+		let local_env = self
+			.get_env_as_local_from_index(env_index)
+			.ok_or_else(|| anyhow!(EnvironmentNotFound))?;
 
-		match local_env {
-			Some(local_env) => {
+		let env = local_env.read();
+
+		if !env.is_secret(key) {
+			return Err(anyhow!(KeyNotSecret));
+		}
+
+		// The values of locked secrets are unknown
+		let value = env.values.get(key).ok_or(SecretsError::Locked)?;
+
+		match reveal {
+			true => println!("{value}"),
+			false => println!("{SECRET_MASK}"),
+		}
+
+		Ok(())
+	}
+
+	pub fn list_env_secrets(&mut self, env_index: usize) -> anyhow::Result<()> {
+		let local_env = self
+			.get_env_as_local_from_index(env_index)
+			.ok_or_else(|| anyhow!(EnvironmentNotFound))?;
+
+		for key in &local_env.read().secret_keys {
+			println!("{key}");
+		}
+
+		Ok(())
+	}
+
+	/// Layers of an environment from the highest priority: its `.env.<name>.local` and `.env.local`
+	/// overrides, its own values, then the values of its parents
+	pub fn environment_layers(&self, env_index: usize) -> Vec<EnvironmentLayer> {
+		let mut layers = vec![];
+
+		let Some(local_env) = self.get_env_as_local_from_index(env_index) else {
+			return layers;
+		};
+
+		let env_name = local_env.read().name.clone();
+
+		for origin in [format!(".env.{env_name}.local"), String::from(".env.local")] {
+			if let Some(values) = self.core.local_environments.get(&origin) {
+				layers.push(EnvironmentLayer {
+					origin,
+					values: values.clone(),
+					secret_keys: IndexSet::new(),
+				});
+			}
+		}
+
+		let mut visited_names = IndexSet::new();
+		let mut next_env = Some(local_env);
+
+		while let Some(local_env) = next_env {
+			let parent = {
 				let env = local_env.read();
-				interpolate_env_keys(input, &[&env.values, &OS_ENV_VARS])
+
+				// Inheritance loops are reported at startup
+				if !visited_names.insert(env.name.clone()) {
+					break;
+				}
+
+				layers.push(EnvironmentLayer {
+					origin: format!(".env.{}", env.name),
+					values: env.values.clone(),
+					secret_keys: env.secret_keys.clone(),
+				});
+
+				env.parent.clone()
+			};
+
+			next_env = parent.and_then(|parent| {
+				self.core
+					.environments
+					.iter()
+					.find(|env| env.read().name == parent)
+					.cloned()
+			});
+		}
+
+		layers
+	}
+
+	/// Names of the parents of an environment, from the closest one, stopping on a loop or a
+	/// missing parent
+	fn environment_ancestors(&self, env_name: &str) -> Vec<String> {
+		let mut ancestors: Vec<String> = vec![];
+		let mut current_name = env_name.to_string();
+
+		while let Some(parent) = self
+			.core
+			.environments
+			.iter()
+			.find(|env| env.read().name == current_name)
+			.and_then(|env| env.read().parent.clone())
+		{
+			if ancestors.contains(&parent) {
+				break;
 			}
-			None => interpolate_env_keys(input, &[]),
+
+			ancestors.push(parent.clone());
+			current_name = parent;
 		}
+
+		ancestors
+	}
+
+	/// Warn about the parent environments that do not exist or that inherit from their children
+	pub fn check_environment_parents(&self) {
+		for local_env in &self.core.environments {
+			let (env_name, parent) = {
+				let env = local_env.read();
+				(env.name.clone(), env.parent.clone())
+			};
+
+			let Some(parent) = parent else {
+				continue;
+			};
+
+			if self.find_environment(&parent).is_err() {
+				warn!("Parent environment \"{parent}\" of \"{env_name}\" not found");
+			} else if self.environment_ancestors(&env_name).contains(&env_name) {
+				warn!("{}", InheritsFromItself(env_name));
+			}
+		}
+	}
+
+	pub fn get_env_parent(&self, env_index: usize) -> anyhow::Result<()> {
+		let local_env = self
+			.get_env_as_local_from_index(env_index)
+			.ok_or_else(|| anyhow!(EnvironmentNotFound))?;
+
+		if let Some(parent) = &local_env.read().parent {
+			println!("{parent}");
+		}
+
+		Ok(())
+	}
+
+	/// Set or remove the parent of an environment, refusing inheritance loops
+	pub fn set_env_parent(
+		&mut self,
+		env_index: usize,
+		parent: Option<String>,
+	) -> anyhow::Result<()> {
+		let local_env = self
+			.get_env_as_local_from_index(env_index)
+			.ok_or_else(|| anyhow!(EnvironmentNotFound))?;
+
+		let env_name = local_env.read().name.clone();
+
+		if let Some(parent) = &parent {
+			self.find_environment(parent)?;
+
+			if *parent == env_name || self.environment_ancestors(parent).contains(&env_name) {
+				return Err(anyhow!(InheritsFromItself(env_name)));
+			}
+		}
+
+		match &parent {
+			Some(parent) => info!("Environment \"{env_name}\" now inherits from \"{parent}\""),
+			None => info!("Environment \"{env_name}\" no longer inherits from another environment"),
+		}
+
+		local_env.write().parent = parent;
+
+		self.save_environment_to_file(env_index);
+		Ok(())
+	}
+
+	pub fn replace_env_keys_by_value(&self, input: &str) -> String {
+		self.evaluate_env_keys_by_value(input).value
+	}
+
+	/// Same as [`App::replace_env_keys_by_value`], with the placeholders that could not be evaluated
+	pub fn evaluate_env_keys_by_value(&self, input: &str) -> Interpolation {
+		if self.core.environments.is_empty() {
+			return Interpolation {
+				value: input.to_string(),
+				errors: vec![],
+			};
+		}
+
+		let layers = self.environment_layers(self.core.selected_environment);
+
+		if layers.is_empty() {
+			return evaluate_template(input, &[]);
+		}
+
+		let mut maps: Vec<&IndexMap<String, String>> =
+			layers.iter().map(|layer| &layer.values).collect();
+		maps.push(&OS_ENV_VARS);
+
+		evaluate_template(input, &maps)
 	}
 
 	/// Get the active environment values for a collection, if the collection
@@ -297,32 +589,47 @@ impl App<'_> {
 			.map(|env| env.values.clone())
 	}
 
-	/// Replace `{{KEY}}` placeholders using the collection's environment (highest priority),
-	/// then the global environment, then OS env vars, then built-in variables.
+	/// Replace `{{KEY}}` placeholders using the collection's variables (highest priority), then
+	/// the collection's environment, then the global environment layers, then OS env vars, then
+	/// built-in variables.
 	///
 	/// This is the primary interpolation method used during request preparation.
 	pub fn replace_env_keys_for_collection(&self, input: &str, collection_index: usize) -> String {
+		self.evaluate_env_keys_for_collection(input, collection_index)
+			.value
+	}
+
+	/// Same as [`App::replace_env_keys_for_collection`], with the placeholders that could not be
+	/// evaluated
+	pub fn evaluate_env_keys_for_collection(
+		&self,
+		input: &str,
+		collection_index: usize,
+	) -> Interpolation {
+		let empty_variables = IndexMap::new();
+		let collection_variables = self
+			.core
+			.collections
+			.get(collection_index)
+			.map_or(&empty_variables, |collection| &collection.variables);
 		let collection_env = self.get_collection_env_values(collection_index);
-		let global_env = self.get_selected_env_as_local();
+		let global_env_layers = self.environment_layers(self.core.selected_environment);
 
-		let mut maps: Vec<&IndexMap<String, String>> = Vec::new();
+		// Variable values referencing environment keys are replaced by the next maps
+		let mut maps: Vec<&IndexMap<String, String>> = vec![collection_variables];
 
-		// Collection env takes highest priority
+		// Collection env is next
 		if let Some(ref coll_env) = collection_env {
 			maps.push(coll_env);
 		}
 
-		// Global env is next
-		let global_env_guard;
-		if let Some(ref env_lock) = global_env {
-			global_env_guard = env_lock.read();
-			maps.push(&global_env_guard.values);
-		}
+		// Global env is next, with its local overrides and parents
+		maps.extend(global_env_layers.iter().map(|layer| &layer.values));
 
 		// OS env vars are last
 		maps.push(&OS_ENV_VARS);
 
-		interpolate_env_keys(input, &maps)
+		evaluate_template(input, &maps)
 	}
 
 	// ── Collection-scoped environment operations ──────────────────────────
@@ -369,6 +676,8 @@ impl App<'_> {
 			name: env_name.clone(),
 			values: IndexMap::new(),
 			path: PathBuf::new(),
+			secret_keys: IndexSet::new(),
+			parent: None,
 		};
 
 		self.core.collections[collection_index]
@@ -566,35 +875,14 @@ impl App<'_> {
 	}
 }
 
-/// Core interpolation logic: replaces `{{KEY}}` placeholders in `input`
-/// with values from the given environment maps (checked in order), then
-/// replaces built-in variables (`{{NOW}}`, `{{TIMESTAMP}}`, `{{UUIDv4}}`,
-/// `{{UUIDv7}}`).
-///
-/// Accepts a slice of `IndexMap` references so callers can pass multiple
-/// maps (e.g. user env + OS env) without cloning or merging them.
-pub fn interpolate_env_keys(input: &str, env_maps: &[&IndexMap<String, String>]) -> String {
-	let mut tmp_string = input.to_string();
-
-	for map in env_maps {
-		for (key, value) in *map {
-			tmp_string = tmp_string.replace(&format!("{{{{{}}}}}", key), value);
-		}
-	}
-
-	tmp_string = tmp_string
-		.replace("{{NOW}}", &Utc::now().to_string())
-		.replace("{{TIMESTAMP}}", &Utc::now().timestamp().to_string())
-		.replace("{{UUIDv4}}", &Uuid::new_v4().to_string())
-		.replace("{{UUIDv7}}", &Uuid::now_v7().to_string());
-
-	tmp_string
-}
-
 #[cfg(test)]
 mod tests {
 	use super::*;
 
+	fn interpolate_env_keys(input: &str, env_maps: &[&IndexMap<String, String>]) -> String {
+		evaluate_template(input, env_maps).value
+	}
+
 	// ── Basic key replacement ────────────────────────────────────
 
 	#[test]
@@ -773,4 +1061,17 @@ mod tests {
 		let result = interpolate_env_keys("{{KEY}} and {{OS_ONLY}}", &[&user_env, &os_env]);
 		assert_eq!(result, "user_value and from_os");
 	}
+
+	#[test]
+	fn variables_reference_previous_variables_and_next_maps() {
+		let mut variables = IndexMap::new();
+		variables.insert("BASE".to_string(), "{{HOST}}/api".to_string());
+		variables.insert("USERS".to_string(), "{{BASE}}/users".to_string());
+
+		let mut env = IndexMap::new();
+		env.insert("HOST".to_string(), "localhost".to_string());
+
+		let result = interpolate_env_keys("GET {{USERS}}", &[&variables, &env]);
+		assert_eq!(result, "GET localhost/api/users");
+	}
 }
@@ -6,6 +6,7 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::{Context, anyhow};
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use tracing::{info, trace, warn};
 
@@ -14,6 +15,10 @@ use reqwest::Url;
 use crate::app::App;
 use crate::app::files::utils::write_via_temp_file;
 use crate::cli::args::ARGS;
+use crate::cli::import::http_file::{
+	HTTP_CLIENT_ENV_FILE, HTTP_CLIENT_PRIVATE_ENV_FILE, parse_http_client_env_file,
+	parse_http_client_env_files,
+};
 use crate::models::auth::auth::Auth;
 use crate::models::collection::CollectionFileFormat::{Http, Json, Yaml};
 use crate::models::collection::{Collection, CollectionFileFormat};
@@ -139,34 +144,55 @@ impl App<'_> {
 		// We need to gather both root requests and folder requests
 		let mut file_groups: BTreeMap<PathBuf, Vec<Arc<RwLock<Request>>>> = BTreeMap::new();
 
-		for req_lock in &collection.requests {
-			self.generate_file_group(req_lock, base_dir, None, &mut file_groups);
+		// Nested folders are written as nested directories
+		for (folder_path, req_lock) in collection.requests_with_folder_path() {
+			let folder_dir = PathBuf::from(collection.folder_path_name(&folder_path));
+			self.generate_file_group(&req_lock, base_dir, &folder_dir, &mut file_groups);
 		}
 
-		for folder in &collection.folders {
-			for req_lock in &folder.requests {
-				self.generate_file_group(
-					req_lock,
-					base_dir,
-					Some(folder.name.clone()),
-					&mut file_groups,
-				);
-			}
-		}
+		// --- Phase 2: Serialize each file along with the variables it references ---
 
-		// --- Phase 2: Serialize and write each file ---
+		let mut file_contents: Vec<(&PathBuf, String, IndexMap<String, String>)> = vec![];
+		let mut unreferenced_variables = collection.variables.clone();
 
 		for (file_path, requests) in &file_groups {
+			let file_dir = file_path.parent().unwrap_or(Path::new(""));
+			let content = self.serialize_requests_to_http(requests, file_dir);
+			let variables = referenced_variables(&collection.variables, &content);
+
+			unreferenced_variables.retain(|key, _| !variables.contains_key(key));
+			file_contents.push((file_path, content, variables));
+		}
+
+		// Variables no request references are kept in the first file
+		if let Some((_, _, variables)) = file_contents.first_mut() {
+			variables.extend(unreferenced_variables);
+			variables.sort_by_cached_key(|key, _| collection.variables.get_index_of(key));
+		}
+
+		// --- Phase 3: Write each file ---
+
+		for (file_path, content, variables) in file_contents {
 			if let Some(parent) = file_path.parent() {
 				fs::create_dir_all(parent)?;
 			}
 
-			let content = self.serialize_requests_to_http(requests);
+			let content = match variables.is_empty() {
+				true => content,
+				false => {
+					let declarations: Vec<String> = variables
+						.iter()
+						.map(|(key, value)| format!("@{key} = {value}"))
+						.collect();
+
+					format!("{}\n\n{content}", declarations.join("\n"))
+				}
+			};
 
 			write_via_temp_file(file_path, content.as_bytes())?;
 		}
 
-		// --- Phase 3: Save companion env file if collection has environments ---
+		// --- Phase 4: Save companion env file if collection has environments ---
 
 		let collection = &self.core.collections[collection_index];
 		if let Err(e) = Self::save_companion_env_file(collection) {
@@ -180,7 +206,7 @@ impl App<'_> {
 		&self,
 		req_lock: &Arc<RwLock<Request>>,
 		base_dir: &Path,
-		folder_name: Option<String>,
+		folder_dir: &Path,
 		file_groups: &mut BTreeMap<PathBuf, Vec<Arc<RwLock<Request>>>>,
 	) {
 		let req = req_lock.read();
@@ -188,10 +214,7 @@ impl App<'_> {
 			Some(path) => path.clone(),
 			None => {
 				let file_name = &req.name;
-				match folder_name {
-					Some(f_name) => base_dir.join(f_name).join(format!("{file_name}.http")),
-					None => base_dir.join(format!("{file_name}.http")),
-				}
+				base_dir.join(folder_dir).join(format!("{file_name}.http"))
 			}
 		};
 
@@ -201,7 +224,12 @@ impl App<'_> {
 			.push(req_lock.clone());
 	}
 
-	fn serialize_requests_to_http(&self, requests: &Vec<Arc<RwLock<Request>>>) -> String {
+	/// Paths of included bodies are written relative to `file_dir`, the directory of the file
+	fn serialize_requests_to_http(
+		&self,
+		requests: &Vec<Arc<RwLock<Request>>>,
+		file_dir: &Path,
+	) -> String {
 		let mut results: Vec<String> = vec![];
 
 		for req_lock in requests {
@@ -211,6 +239,26 @@ impl App<'_> {
 			// --- Request separator with name ---
 			lines.push(format!("### {}", req.name));
 
+			// --- Assertions ---
+			for assertion in &req.assertions {
+				lines.push(format!("# @assert {}", assertion));
+			}
+
+			// --- Captures ---
+			for capture in &req.captures {
+				lines.push(format!("# @capture {}", capture));
+			}
+
+			// --- Response filter ---
+			if let Some(response_filter) = &req.response_filter {
+				lines.push(format!("# @filter {}", response_filter));
+			}
+
+			// --- Pre-request script ---
+			if let Some(script) = &req.scripts.pre_request_script {
+				lines.push(format!("< {{%\n{script}\n%}}"));
+			}
+
 			// --- Build the full URL with query params ---
 			let full_url = Self::build_url_with_params(&req.url, &req.params);
 
@@ -236,10 +284,8 @@ impl App<'_> {
 
 			// --- Authorization header from auth ---
 			match &req.auth {
-				Auth::BearerToken(bearer) => {
-					if !bearer.token.is_empty() {
-						lines.push(format!("Authorization: Bearer {}", bearer.token));
-					}
+				Auth::BearerToken(bearer) if !bearer.token.is_empty() => {
+					lines.push(format!("Authorization: Bearer {}", bearer.token));
 				}
 				Auth::BasicAuth(basic) => {
 					use base64::Engine;
@@ -268,7 +314,7 @@ impl App<'_> {
 							lines.push(format!("Content-Type: {}", ct));
 						}
 					}
-					Self::serialize_body(&http.body)
+					Self::serialize_body(&http.body, file_dir)
 				}
 				Protocol::WsRequest(_) => None,
 				Protocol::GraphqlRequest(gql) => {
@@ -298,6 +344,12 @@ impl App<'_> {
 					if !grpc.method.is_empty() {
 						lines.push(format!("X-Grpc-Method: {}", grpc.method));
 					}
+					if grpc.reflection {
+						lines.push("X-Grpc-Reflection: true".to_string());
+					}
+					if !grpc.compression.is_identity() {
+						lines.push(format!("X-Grpc-Compression: {}", grpc.compression));
+					}
 					if !user_has_content_type {
 						lines.push("Content-Type: application/grpc+json".to_string());
 					}
@@ -324,6 +376,12 @@ impl App<'_> {
 				lines.push(body);
 			}
 
+			// --- Response handler ---
+			if let Some(script) = &req.scripts.post_request_script {
+				lines.push(String::new());
+				lines.push(format!("> {{%\n{script}\n%}}"));
+			}
+
 			results.push(lines.join("\n"));
 		}
 
@@ -365,14 +423,19 @@ impl App<'_> {
 
 	/// Serialize an HTTP body `ContentType` into its string representation for an `.http` file.
 	/// Returns `None` for `NoBody`.
-	fn serialize_body(body: &ContentType) -> Option<String> {
+	fn serialize_body(body: &ContentType, file_dir: &Path) -> Option<String> {
 		match body {
 			ContentType::NoBody => None,
 			ContentType::File(path) => {
 				if path.is_empty() {
 					None
 				} else {
-					Some(format!("< {}", path))
+					match Path::new(path).strip_prefix(file_dir) {
+						Ok(relative_path) if !file_dir.as_os_str().is_empty() => {
+							Some(format!("< ./{}", relative_path.display()))
+						}
+						_ => Some(format!("< {}", path)),
+					}
 				}
 			}
 			ContentType::Multipart(fields) | ContentType::Form(fields) => {
@@ -407,13 +470,26 @@ impl App<'_> {
 	/// The companion env file name stored alongside .http file collections.
 	const COMPANION_ENV_FILE: &'static str = "squrl-env.json";
 
-	/// Load collection environments from a companion `squrl-env.json` file.
-	/// Returns the environments and selected environment name, if the file exists.
+	/// Load collection environments from the `http-client.env.json` and
+	/// `http-client.private.env.json` files, then from a companion `squrl-env.json` file
+	/// overriding environments of the same name.
+	/// Returns the environments and selected environment name, if one of the files exists.
 	pub fn load_companion_env_file(base_dir: &Path) -> Option<(Vec<Environment>, Option<String>)> {
+		let http_client_environments = match parse_http_client_env_files(base_dir) {
+			Ok(environments) => environments,
+			Err(e) => {
+				warn!("Could not load HTTP Client environments: {e}");
+				vec![]
+			}
+		};
+
 		let companion_path = base_dir.join(Self::COMPANION_ENV_FILE);
 
 		if !companion_path.exists() {
-			return None;
+			return match http_client_environments.is_empty() {
+				true => None,
+				false => Some((http_client_environments, None)),
+			};
 		}
 
 		trace!(
@@ -448,12 +524,13 @@ impl App<'_> {
 			.and_then(|v| v.as_str())
 			.map(|s| s.to_string());
 
+		let mut environments = http_client_environments;
+
 		let environments_obj = match parsed.get("environments").and_then(|v| v.as_object()) {
 			Some(obj) => obj,
-			None => return Some((vec![], selected_environment)),
+			None => return Some((environments, selected_environment)),
 		};
 
-		let mut environments = Vec::new();
 		for (name, values_val) in environments_obj {
 			let values = match values_val.as_object() {
 				Some(obj) => obj
@@ -462,11 +539,18 @@ impl App<'_> {
 					.collect(),
 				None => indexmap::IndexMap::new(),
 			};
-			environments.push(Environment {
+			let environment = Environment {
 				name: name.clone(),
 				values,
 				path: PathBuf::new(),
-			});
+				secret_keys: IndexSet::new(),
+				parent: None,
+			};
+
+			match environments.iter_mut().find(|env| &env.name == name) {
+				Some(existing) => *existing = environment,
+				None => environments.push(environment),
+			}
 		}
 
 		trace!(
@@ -476,11 +560,21 @@ impl App<'_> {
 		Some((environments, selected_environment))
 	}
 
-	/// Save collection environments to a companion `squrl-env.json` file.
+	/// Save collection environments to a companion `squrl-env.json` file, or to the
+	/// `http-client.env.json` file when there is one, the selected environment staying in the
+	/// companion file.
 	pub fn save_companion_env_file(collection: &Collection) -> anyhow::Result<()> {
 		let companion_path = collection.path.join(Self::COMPANION_ENV_FILE);
+		let has_http_client_env_file = collection.path.join(HTTP_CLIENT_ENV_FILE).exists();
 
-		if collection.environments.is_empty() && collection.selected_environment.is_none() {
+		if has_http_client_env_file {
+			Self::save_http_client_env_files(collection)?;
+		}
+
+		let has_companion_environments =
+			!has_http_client_env_file && !collection.environments.is_empty();
+
+		if !has_companion_environments && collection.selected_environment.is_none() {
 			// If there are no environments and no selection, remove the companion file if it exists
 			if companion_path.exists() {
 				let _ = fs::remove_file(&companion_path);
@@ -505,10 +599,12 @@ impl App<'_> {
 				serde_json::Value::String(selected.clone()),
 			);
 		}
-		root.insert(
-			"environments".to_string(),
-			serde_json::Value::Object(environments_map),
-		);
+		if has_companion_environments {
+			root.insert(
+				"environments".to_string(),
+				serde_json::Value::Object(environments_map),
+			);
+		}
 
 		let content = serde_json::to_string_pretty(&root)
 			.context("Could not serialize companion env file")?;
@@ -520,6 +616,43 @@ impl App<'_> {
 		Ok(())
 	}
 
+	/// Save collection environments to the `http-client.env.json` file, the values of the keys
+	/// found in the `http-client.private.env.json` file going to the latter
+	fn save_http_client_env_files(collection: &Collection) -> anyhow::Result<()> {
+		let env_path = collection.path.join(HTTP_CLIENT_ENV_FILE);
+		let private_env_path = collection.path.join(HTTP_CLIENT_PRIVATE_ENV_FILE);
+		let private_keys = parse_http_client_env_file(&private_env_path)?;
+
+		let mut environments: IndexMap<String, IndexMap<String, String>> = IndexMap::new();
+		let mut private_environments: IndexMap<String, IndexMap<String, String>> = IndexMap::new();
+
+		for env in &collection.environments {
+			let private_values = private_keys.get(&env.name);
+
+			for (key, value) in &env.values {
+				let is_private = private_values.is_some_and(|values| values.contains_key(key));
+
+				let environment = match is_private {
+					true => private_environments.entry(env.name.clone()).or_default(),
+					false => environments.entry(env.name.clone()).or_default(),
+				};
+
+				environment.insert(key.clone(), value.clone());
+			}
+
+			environments.entry(env.name.clone()).or_default();
+		}
+
+		write_http_client_env_file(&env_path, environments)?;
+
+		if private_env_path.exists() {
+			write_http_client_env_file(&private_env_path, private_environments)?;
+		}
+
+		trace!("HTTP Client environment files saved");
+		Ok(())
+	}
+
 	/// Delete collection file.
 	/// Logs a warning on failure rather than panicking.
 	pub fn delete_collection_file(&mut self, collection: Collection) {
@@ -543,3 +676,67 @@ impl App<'_> {
 		}
 	}
 }
+
+/// Variables referenced by an .http file content, directly or through other variables, in
+/// declaration order
+fn referenced_variables(
+	variables: &IndexMap<String, String>,
+	content: &str,
+) -> IndexMap<String, String> {
+	let mut referenced: Vec<&String> = vec![];
+	let mut texts: Vec<&str> = vec![content];
+
+	while let Some(text) = texts.pop() {
+		for (key, value) in variables {
+			if !referenced.contains(&key) && text.contains(&format!("{{{{{key}}}}}")) {
+				referenced.push(key);
+				texts.push(value);
+			}
+		}
+	}
+
+	variables
+		.iter()
+		.filter(|(key, _)| referenced.contains(key))
+		.map(|(key, value)| (key.clone(), value.clone()))
+		.collect()
+}
+
+/// Write the values of every environment, keeping the ones squrl does not handle such as
+/// `SSLConfiguration` objects
+fn write_http_client_env_file(
+	path: &Path,
+	environments: IndexMap<String, IndexMap<String, String>>,
+) -> anyhow::Result<()> {
+	let existing_environments: IndexMap<String, IndexMap<String, serde_json::Value>> =
+		fs::read_to_string(path)
+			.ok()
+			.and_then(|content| serde_json::from_str(&content).ok())
+			.unwrap_or_default();
+
+	let environments: IndexMap<String, IndexMap<String, serde_json::Value>> = environments
+		.into_iter()
+		.map(|(name, values)| {
+			let mut values: IndexMap<String, serde_json::Value> = values
+				.into_iter()
+				.map(|(key, value)| (key, serde_json::Value::String(value)))
+				.collect();
+
+			if let Some(existing_values) = existing_environments.get(&name) {
+				for (key, value) in existing_values {
+					if value.is_object() || value.is_array() {
+						values.insert(key.clone(), value.clone());
+					}
+				}
+			}
+
+			(name, values)
+		})
+		.collect();
+
+	let content = serde_json::to_string_pretty(&environments)
+		.context("Could not serialize HTTP Client environment file")?;
+
+	write_via_temp_file(path, content.as_bytes())
+		.with_context(|| format!("Could not save \"{}\"", path.display()))
+}
@@ -8,6 +8,7 @@ use tracing::{trace, warn};
 use anyhow::Context;
 
 use crate::app::App;
+use crate::app::constants::DEFAULT_MAX_RESPONSE_BODY_SIZE;
 use crate::models::collection::CollectionFileFormat;
 
 pub static SKIP_SAVE_REQUESTS_RESPONSE: OnceLock<bool> = OnceLock::new();
@@ -45,6 +46,22 @@ pub struct Config {
 	#[serde(default)]
 	/// Proxy usage
 	pub proxy: Option<Proxy>,
+
+	#[serde(default)]
+	/// Should keep one cookie jar per collection instead of a single global one
+	pub per_collection_cookie_jars: Option<bool>,
+
+	#[serde(default)]
+	/// Should not record sent requests in the history file
+	pub disable_request_history: Option<bool>,
+
+	#[serde(default)]
+	/// Should record the credential headers in clear text in the history file
+	pub record_credential_headers: Option<bool>,
+
+	#[serde(default)]
+	/// Number of response body bytes kept in memory and displayed, the rest being discarded
+	pub max_response_body_size: Option<usize>,
 }
 
 #[derive(Default, Serialize, Deserialize)]
@@ -94,6 +111,23 @@ impl Config {
 	pub fn get_proxy(&self) -> &Option<Proxy> {
 		&self.proxy
 	}
+
+	pub fn use_per_collection_cookie_jars(&self) -> bool {
+		self.per_collection_cookie_jars.unwrap_or(false)
+	}
+
+	pub fn is_request_history_disabled(&self) -> bool {
+		self.disable_request_history.unwrap_or(false)
+	}
+
+	pub fn should_record_credential_headers(&self) -> bool {
+		self.record_credential_headers.unwrap_or(false)
+	}
+
+	pub fn get_max_response_body_size(&self) -> usize {
+		self.max_response_body_size
+			.unwrap_or(DEFAULT_MAX_RESPONSE_BODY_SIZE)
+	}
 }
 
 impl App<'_> {
@@ -194,6 +228,18 @@ impl App<'_> {
 			self.core.config.proxy = global_config.proxy;
 		}
 
+		if self.core.config.per_collection_cookie_jars.is_none() {
+			self.core.config.per_collection_cookie_jars = global_config.per_collection_cookie_jars;
+		}
+
+		if self.core.config.disable_request_history.is_none() {
+			self.core.config.disable_request_history = global_config.disable_request_history;
+		}
+
+		if self.core.config.record_credential_headers.is_none() {
+			self.core.config.record_credential_headers = global_config.record_credential_headers;
+		}
+
 		self.core.config.set_should_skip_requests_response();
 
 		trace!("Global config file parsed!");
@@ -432,6 +478,18 @@ https_proxy = "https://proxy.local:8443"
 		);
 	}
 
+	#[test]
+	fn parse_toml_with_max_response_body_size() {
+		let config = Config::default();
+		assert_eq!(
+			config.get_max_response_body_size(),
+			DEFAULT_MAX_RESPONSE_BODY_SIZE
+		);
+
+		let config: Config = toml::from_str("max_response_body_size = 1024").unwrap();
+		assert_eq!(config.get_max_response_body_size(), 1024);
+	}
+
 	#[test]
 	fn parse_toml_ignores_unknown_keys() {
 		let config: Config = toml::from_str("theme = \"test\"\nunknown_key = \"ignored\"").unwrap();
@@ -498,6 +556,10 @@ save_requests_response = false
 			disable_graphical_protocol: None,
 			wrap_responses: Some(false),
 			preferred_collection_file_format: Some(CollectionFileFormat::Yaml),
+			per_collection_cookie_jars: Some(true),
+			disable_request_history: None,
+			record_credential_headers: None,
+			max_response_body_size: None,
 			proxy: Some(Proxy {
 				http_proxy: Some("http://proxy:8080".to_string()),
 				https_proxy: None,
@@ -513,6 +575,7 @@ save_requests_response = false
 		assert!(restored.is_image_preview_disabled());
 		assert!(!restored.is_graphical_protocol_disabled());
 		assert!(!restored.should_wrap_body());
+		assert!(restored.use_per_collection_cookie_jars());
 		assert!(matches!(
 			restored.get_preferred_collection_file_format(),
 			CollectionFileFormat::Yaml
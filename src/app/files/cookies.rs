@@ -0,0 +1,367 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, anyhow};
+use chrono::Utc;
+use cookie_store::{Cookie, CookieDomain, CookieExpiration, CookieStore};
+use parking_lot::RwLock;
+use reqwest::Url;
+use reqwest_cookie_store::CookieStoreRwLock;
+use thiserror::Error;
+use tracing::{info, trace, warn};
+
+use crate::app::App;
+use crate::app::files::utils::{sanitize_file_name, write_via_temp_file};
+use crate::cli::args::ARGS;
+
+/// Global cookie jar file, in the main app directory
+pub const COOKIES_FILE_NAME: &str = "squrl.cookies.json";
+
+/// Directory containing one cookie jar file per collection, in the main app directory.
+/// The file names are the sanitized collection names.
+pub const COLLECTION_COOKIES_DIRECTORY: &str = "cookies";
+
+/// Cookie jars of the collections, by collection name
+pub type CollectionCookieStores = RwLock<HashMap<String, Arc<CookieStoreRwLock>>>;
+
+#[derive(Error, Debug)]
+pub enum NetscapeCookieError {
+	#[error("Line {0}: expected 7 tab-separated fields")]
+	MissingFields(usize),
+	#[error("Line {0}: invalid expiration timestamp \"{1}\"")]
+	InvalidExpiration(usize, String),
+	#[error("Line {0}: could not parse cookie\n\t{1}")]
+	InvalidCookie(usize, String),
+}
+
+impl App<'_> {
+	/// Cookie jar used by requests of the given collection.
+	/// Returns the global jar unless per-collection jars are enabled in the config.
+	pub fn get_cookie_store(&self, collection_index: Option<usize>) -> Arc<CookieStoreRwLock> {
+		let collection = collection_index.and_then(|index| self.core.collections.get(index));
+
+		let Some(collection) = collection else {
+			return Arc::clone(&self.core.cookies_popup.cookie_store);
+		};
+
+		if !self.core.config.use_per_collection_cookie_jars() {
+			return Arc::clone(&self.core.cookies_popup.cookie_store);
+		}
+
+		if let Some(cookie_store) = self
+			.core
+			.collection_cookie_stores
+			.read()
+			.get(&collection.name)
+		{
+			return Arc::clone(cookie_store);
+		}
+
+		let cookie_store = match collection_cookies_path(&collection.name) {
+			None => CookieStore::default(),
+			Some(path) => load_cookie_store(&path).unwrap_or_else(|e| {
+				warn!("{e:#}");
+				CookieStore::default()
+			}),
+		};
+		let cookie_store = Arc::new(CookieStoreRwLock::new(cookie_store));
+
+		self.core
+			.collection_cookie_stores
+			.write()
+			.insert(collection.name.clone(), Arc::clone(&cookie_store));
+
+		cookie_store
+	}
+
+	/// Load the global cookie jar from the main app directory
+	pub fn load_global_cookie_store(&mut self) {
+		let Some(path) = global_cookies_path() else {
+			return;
+		};
+
+		match load_cookie_store(&path) {
+			Ok(cookie_store) => {
+				self.core.cookies_popup.cookie_store =
+					Arc::new(CookieStoreRwLock::new(cookie_store))
+			}
+			Err(e) => warn!("{e:#}"),
+		}
+	}
+
+	/// Save the global cookie jar and every loaded collection cookie jar
+	pub fn save_cookie_stores(&self) {
+		if let Some(path) = global_cookies_path() {
+			save_cookie_store(&self.core.cookies_popup.cookie_store, &path);
+		}
+
+		for (collection_name, cookie_store) in self.core.collection_cookie_stores.read().iter() {
+			if let Some(path) = collection_cookies_path(collection_name) {
+				save_cookie_store(cookie_store, &path);
+			}
+		}
+	}
+}
+
+fn global_cookies_path() -> Option<PathBuf> {
+	ARGS.directory
+		.as_ref()
+		.map(|directory| directory.join(COOKIES_FILE_NAME))
+}
+
+fn collection_cookies_path(collection_name: &str) -> Option<PathBuf> {
+	ARGS.directory.as_ref().map(|directory| {
+		directory
+			.join(COLLECTION_COOKIES_DIRECTORY)
+			.join(format!("{}.json", sanitize_file_name(collection_name)))
+	})
+}
+
+/// Read a cookie jar file, skipping expired cookies. A missing file gives an empty jar.
+pub fn load_cookie_store(path: &Path) -> anyhow::Result<CookieStore> {
+	if !path.exists() {
+		return Ok(CookieStore::default());
+	}
+
+	trace!("Trying to open \"{}\" cookie jar", path.display());
+
+	let file = File::open(path)
+		.with_context(|| format!("Could not open cookie jar \"{}\"", path.display()))?;
+
+	let cookie_store = cookie_store::serde::json::load(BufReader::new(file))
+		.map_err(|e| anyhow!("Could not parse cookie jar \"{}\": {e}", path.display()))?;
+
+	trace!("Cookie jar parsed!");
+	Ok(cookie_store)
+}
+
+/// Save a cookie jar through a temporary file, session cookies included.
+/// Logs a warning on failure rather than panicking.
+pub fn save_cookie_store(cookie_store: &CookieStoreRwLock, path: &Path) {
+	if !ARGS.should_save {
+		warn!("Dry-run, not saving the cookie jar");
+		return;
+	}
+
+	let mut data: Vec<u8> = vec![];
+
+	{
+		let cookie_store = cookie_store.read().expect("cookie store lock poisoned");
+
+		if cookie_store.iter_any().next().is_none() && !path.exists() {
+			return;
+		}
+
+		if let Err(e) =
+			cookie_store::serde::json::save_incl_expired_and_nonpersistent(&cookie_store, &mut data)
+		{
+			warn!("Could not serialize cookie jar: {e}");
+			return;
+		}
+	}
+
+	if let Some(parent) = path.parent()
+		&& let Err(e) = std::fs::create_dir_all(parent)
+	{
+		warn!("Could not create cookie jar directory: {e}");
+		return;
+	}
+
+	info!("Saving cookie jar \"{}\"", path.display());
+
+	if let Err(e) = write_via_temp_file(path, &data) {
+		warn!("Could not save cookie jar: {e}");
+		return;
+	}
+
+	trace!("Cookie jar saved")
+}
+
+/// Domain under which the cookie is stored, without any leading dot
+pub fn cookie_domain(cookie: &Cookie) -> String {
+	cookie
+		.domain
+		.as_cow()
+		.map(|domain| domain.to_string())
+		.unwrap_or_default()
+}
+
+/// Serialize the unexpired cookies of a jar to the Netscape cookies.txt format
+pub fn cookies_to_netscape(cookie_store: &CookieStore) -> String {
+	let mut lines = vec![String::from("# Netscape HTTP Cookie File")];
+
+	for cookie in cookie_store.iter_unexpired() {
+		let (domain, include_subdomains) = match &cookie.domain {
+			CookieDomain::Suffix(domain) => (format!(".{domain}"), "TRUE"),
+			_ => (cookie_domain(cookie), "FALSE"),
+		};
+
+		let domain = match cookie.http_only().unwrap_or(false) {
+			true => format!("#HttpOnly_{domain}"),
+			false => domain,
+		};
+
+		let expires = match &cookie.expires {
+			CookieExpiration::AtUtc(at) => at.unix_timestamp(),
+			CookieExpiration::SessionEnd => 0,
+		};
+
+		let path: &str = cookie.path.as_ref();
+
+		lines.push(format!(
+			"{domain}\t{include_subdomains}\t{path}\t{}\t{expires}\t{}\t{}",
+			netscape_bool(cookie.secure().unwrap_or(false)),
+			cookie.name(),
+			cookie.value()
+		));
+	}
+
+	lines.join("\n") + "\n"
+}
+
+/// Insert the cookies of a Netscape cookies.txt content into a jar, skipping expired ones.
+/// Returns the number of imported cookies.
+pub fn netscape_to_cookies(
+	content: &str,
+	cookie_store: &mut CookieStore,
+) -> Result<usize, NetscapeCookieError> {
+	let now = Utc::now().timestamp();
+	let mut imported = 0;
+
+	for (index, line) in content.lines().enumerate() {
+		let line_number = index + 1;
+		let line = line.trim_end_matches('\r');
+
+		let (line, http_only) = match line.strip_prefix("#HttpOnly_") {
+			Some(line) => (line, true),
+			None => (line, false),
+		};
+
+		if line.trim().is_empty() || line.starts_with('#') {
+			continue;
+		}
+
+		let fields: Vec<&str> = line.split('\t').collect();
+
+		let [
+			domain,
+			include_subdomains,
+			path,
+			secure,
+			expires,
+			name,
+			value,
+		] = fields[..]
+		else {
+			return Err(NetscapeCookieError::MissingFields(line_number));
+		};
+
+		let expires = expires.parse::<i64>().map_err(|_| {
+			NetscapeCookieError::InvalidExpiration(line_number, expires.to_string())
+		})?;
+
+		if expires != 0 && expires <= now {
+			continue;
+		}
+
+		let host = domain.trim_start_matches('.');
+		let mut cookie_str = format!("{name}={value}; Path={path}");
+
+		if include_subdomains.eq_ignore_ascii_case("TRUE") {
+			cookie_str.push_str(&format!("; Domain={host}"));
+		}
+
+		if secure.eq_ignore_ascii_case("TRUE") {
+			cookie_str.push_str("; Secure");
+		}
+
+		if http_only {
+			cookie_str.push_str("; HttpOnly");
+		}
+
+		if expires != 0 {
+			cookie_str.push_str(&format!("; Max-Age={}", expires - now));
+		}
+
+		let url = Url::parse(&format!("https://{host}{path}"))
+			.map_err(|e| NetscapeCookieError::InvalidCookie(line_number, e.to_string()))?;
+
+		cookie_store
+			.parse(&cookie_str, &url)
+			.map_err(|e| NetscapeCookieError::InvalidCookie(line_number, e.to_string()))?;
+
+		imported += 1;
+	}
+
+	Ok(imported)
+}
+
+fn netscape_bool(value: bool) -> &'static str {
+	match value {
+		true => "TRUE",
+		false => "FALSE",
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const NETSCAPE_COOKIES: &str = "# Netscape HTTP Cookie File\n\
+		.example.com\tTRUE\t/\tTRUE\t4102444800\tsession\tabc\n\
+		#HttpOnly_api.example.com\tFALSE\t/v1\tFALSE\t0\ttoken\txyz\n\
+		example.com\tFALSE\t/\tFALSE\t1\texpired\told\n";
+
+	#[test]
+	fn import_netscape_cookies() {
+		let mut cookie_store = CookieStore::default();
+
+		let imported = netscape_to_cookies(NETSCAPE_COOKIES, &mut cookie_store).unwrap();
+		assert_eq!(imported, 2);
+
+		let session = cookie_store.get("example.com", "/", "session").unwrap();
+		assert_eq!(session.value(), "abc");
+		assert!(matches!(session.domain, CookieDomain::Suffix(_)));
+		assert_eq!(session.secure(), Some(true));
+
+		let token = cookie_store.get("api.example.com", "/v1", "token").unwrap();
+		assert_eq!(token.value(), "xyz");
+		assert_eq!(token.http_only(), Some(true));
+		assert!(!token.is_persistent());
+
+		assert!(cookie_store.get("example.com", "/", "expired").is_none());
+	}
+
+	#[test]
+	fn import_invalid_netscape_cookies() {
+		let mut cookie_store = CookieStore::default();
+
+		assert!(matches!(
+			netscape_to_cookies("example.com\tFALSE\t/\n", &mut cookie_store),
+			Err(NetscapeCookieError::MissingFields(1))
+		));
+		assert!(matches!(
+			netscape_to_cookies(
+				"example.com\tFALSE\t/\tFALSE\tsoon\tname\tvalue\n",
+				&mut cookie_store
+			),
+			Err(NetscapeCookieError::InvalidExpiration(1, _))
+		));
+	}
+
+	#[test]
+	fn netscape_roundtrip() {
+		let mut cookie_store = CookieStore::default();
+		netscape_to_cookies(NETSCAPE_COOKIES, &mut cookie_store).unwrap();
+
+		let exported = cookies_to_netscape(&cookie_store);
+		assert!(exported.contains(".example.com\tTRUE\t/\tTRUE\t"));
+		assert!(exported.contains("#HttpOnly_api.example.com\tFALSE\t/v1\tFALSE\t0\ttoken\txyz"));
+
+		let mut reimported = CookieStore::default();
+		assert_eq!(netscape_to_cookies(&exported, &mut reimported).unwrap(), 2);
+	}
+}
@@ -6,6 +6,7 @@ use parking_lot::Mutex;
 use tracing::{trace, warn};
 
 use crate::app::App;
+use crate::cli::import::http_file::{HTTP_CLIENT_ENV_FILE, HTTP_CLIENT_PRIVATE_ENV_FILE};
 use crate::models::collection::CollectionFileFormat;
 use crate::tui::app_states::AppState;
 
@@ -13,17 +14,21 @@ pub fn spawn_env_json_watcher(
 	watch_dir: PathBuf,
 	changed: Arc<Mutex<bool>>,
 ) -> Option<RecommendedWatcher> {
-	let target_file = watch_dir.join("squrl-env.json");
+	let target_files = [
+		watch_dir.join("squrl-env.json"),
+		watch_dir.join(HTTP_CLIENT_ENV_FILE),
+		watch_dir.join(HTTP_CLIENT_PRIVATE_ENV_FILE),
+	];
 
 	let watcher = notify::recommended_watcher(move |res: Result<Event, _>| match res {
 		Ok(event) => {
-			let dominated = event.paths.iter().any(|p| p == &target_file);
+			let dominated = event.paths.iter().any(|p| target_files.contains(p));
 			if !dominated {
 				return;
 			}
 
 			if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
-				trace!("squrl-env.json changed on disk");
+				trace!("Environment file changed on disk");
 				*changed.lock() = true;
 			}
 		}
@@ -38,7 +43,7 @@ pub fn spawn_env_json_watcher(
 			}
 
 			trace!(
-				"Watching \"{}\" for squrl-env.json changes",
+				"Watching \"{}\" for environment file changes",
 				watch_dir.display()
 			);
 			Some(w)
@@ -71,7 +76,7 @@ impl App<'_> {
 		collection.selected_environment = new_selected;
 
 		trace!(
-			"Reloaded squrl-env.json for collection \"{}\" ({} environments)",
+			"Reloaded environment files for collection \"{}\" ({} environments)",
 			collection.name,
 			collection.environments.len(),
 		);
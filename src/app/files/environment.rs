@@ -6,21 +6,32 @@ use std::str::from_utf8;
 use std::sync::Arc;
 
 use anyhow::Context;
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use std::sync::LazyLock;
 use tracing::{info, trace, warn};
 
 use crate::app::App;
+use crate::app::files::secrets::{
+	NEW_PASSPHRASE_ENV_VAR, PASSPHRASE_ENV_VAR, save_environment_secrets,
+};
 use crate::app::files::utils::write_via_temp_file;
 use crate::cli::args::ARGS;
 use crate::models::environment::Environment;
 
-pub static OS_ENV_VARS: LazyLock<IndexMap<String, String>> =
-	LazyLock::new(|| env::vars().collect());
+/// Declares the parent of an environment in its file
+const EXTENDS_DIRECTIVE: &str = "# @extends";
+
+/// The secrets passphrases are left out
+pub static OS_ENV_VARS: LazyLock<IndexMap<String, String>> = LazyLock::new(|| {
+	env::vars()
+		.filter(|(key, _)| key != PASSPHRASE_ENV_VAR && key != NEW_PASSPHRASE_ENV_VAR)
+		.collect()
+});
 
 impl App<'_> {
-	/// Add the environment file to the app environments
+	/// Add the environment file to the app environments, or to the local overrides for the
+	/// `.env.local` and `.env.<name>.local` files
 	pub fn add_environment_from_file(&mut self, path_buf: &Path) -> anyhow::Result<()> {
 		let file_name = path_buf
 			.file_name()
@@ -40,10 +51,23 @@ impl App<'_> {
 			format!("Could not open environment file \"{}\"", path_buf.display())
 		})?;
 
+		let (values, parent) = read_environment_from_file(env_file);
+
+		if file_name == "local" || file_name.ends_with(".local") {
+			self.core
+				.local_environments
+				.insert(format!(".env.{file_name}"), values);
+
+			trace!("Local environment file parsed!");
+			return Ok(());
+		}
+
 		let environment = Environment {
 			name: file_name,
-			values: read_environment_from_file(env_file),
+			values,
 			path: path_buf.to_path_buf(),
+			secret_keys: IndexSet::new(),
+			parent,
 		};
 
 		self.core
@@ -61,17 +85,31 @@ impl App<'_> {
 	}
 }
 
-fn read_environment_from_file(file: File) -> IndexMap<String, String> {
+/// Read the values of an environment file and its parent, if declared
+fn read_environment_from_file(file: File) -> (IndexMap<String, String>, Option<String>) {
 	let reader = BufReader::new(file);
 	let mut environment_values = IndexMap::new();
+	let mut parent = None;
 
 	for line in reader.lines().map_while(Result::ok) {
-		if let Some((key, value)) = parse_line(line.trim().as_bytes()) {
+		if let Some(parent_name) = parse_extends_line(&line) {
+			parent = Some(parent_name);
+		} else if let Some((key, value)) = parse_line(line.trim().as_bytes()) {
 			environment_values.insert(key, value);
 		}
 	}
 
-	environment_values
+	(environment_values, parent)
+}
+
+/// Parent environment name of a `# @extends <name>` line
+fn parse_extends_line(line: &str) -> Option<String> {
+	let parent = line.trim().strip_prefix(EXTENDS_DIRECTIVE)?.trim();
+
+	match parent.is_empty() {
+		true => None,
+		false => Some(parent.to_string()),
+	}
 }
 
 // Code from the EnvFile crate
@@ -137,7 +175,8 @@ fn unquote_env_value(s: &str) -> String {
 	s.to_string()
 }
 
-/// Save app environment in a file through a temporary file.
+/// Save app environment in a file through a temporary file, the secret values going to the
+/// secrets file.
 /// Logs a warning on failure rather than panicking.
 pub fn save_environment_to_file(environment: &Environment) {
 	if !ARGS.should_save {
@@ -148,11 +187,19 @@ pub fn save_environment_to_file(environment: &Environment) {
 	info!("Saving environment \"{}\"", environment.name);
 
 	let mut data: String = environment
-		.values
+		.parent
 		.iter()
-		.map(|(key, value)| format!("{key}={value}\n"))
+		.map(|parent| format!("{EXTENDS_DIRECTIVE} {parent}\n"))
 		.collect();
 
+	data.extend(
+		environment
+			.values
+			.iter()
+			.filter(|(key, _)| !environment.is_secret(key))
+			.map(|(key, value)| format!("{key}={value}\n")),
+	);
+
 	// Remove trailing \n
 	data.pop();
 
@@ -161,6 +208,8 @@ pub fn save_environment_to_file(environment: &Environment) {
 		return;
 	}
 
+	save_environment_secrets(environment);
+
 	trace!("Environment saved")
 }
 
@@ -174,6 +223,21 @@ mod tests {
 		assert_eq!(result, Some(("KEY".to_string(), "value".to_string())));
 	}
 
+	#[test]
+	fn test_parse_extends_line() {
+		assert_eq!(
+			parse_extends_line("# @extends base"),
+			Some(String::from("base"))
+		);
+		assert_eq!(
+			parse_extends_line("  # @extends  base  "),
+			Some(String::from("base"))
+		);
+		assert_eq!(parse_extends_line("# @extends"), None);
+		assert_eq!(parse_extends_line("# extends base"), None);
+		assert_eq!(parse_line(b"# @extends base"), None);
+	}
+
 	#[test]
 	fn test_parse_line_comment() {
 		let result = parse_line(b"# this is a comment");
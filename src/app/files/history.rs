@@ -0,0 +1,310 @@
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use anyhow::Context;
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use thiserror::Error;
+use tracing::{trace, warn};
+
+use crate::app::App;
+use crate::app::files::secrets::{SECRETS, SecretStore};
+use crate::cli::args::ARGS;
+use crate::models::environment::SECRET_MASK;
+use crate::models::history::{HistoryEntry, SentRequest, is_credential_header};
+use crate::models::protocol::protocol::Protocol;
+use crate::models::response::RequestResponse;
+
+/// Append-only request history, one JSON entry per line, in the main app directory
+pub const HISTORY_FILE_NAME: &str = "squrl.history.jsonl";
+
+#[derive(Error, Debug)]
+pub enum HistoryError {
+	#[error("History entry \"{0}\" not found")]
+	EntryNotFound(String),
+	#[error("History entry id \"{0}\" is ambiguous, use a longer prefix")]
+	AmbiguousId(String),
+	#[error("History entries \"{0}\" and \"{1}\" were not sent from the same request")]
+	NotSameRequest(String, String),
+	#[error("No earlier history entry for the request of \"{0}\"")]
+	NoPreviousEntry(String),
+}
+
+impl App<'_> {
+	/// Record a sent request and its response, unless the history is disabled in the config
+	pub fn record_history_entry(
+		&self,
+		collection_index: Option<usize>,
+		request_name: &str,
+		protocol: &Protocol,
+		sent_request: SentRequest,
+		response: &RequestResponse,
+	) {
+		if self.core.config.is_request_history_disabled() {
+			return;
+		}
+
+		let collection_name = collection_index
+			.and_then(|index| self.core.collections.get(index))
+			.map(|collection| collection.name.clone());
+
+		let entry = HistoryEntry::new(
+			collection_name,
+			request_name.to_string(),
+			protocol.to_string(),
+			sent_request,
+			response,
+		);
+
+		append_history_entry(entry, !self.core.config.should_record_credential_headers());
+	}
+}
+
+/// Credential header values are replaced by a mask
+fn redact_credential_headers(entry: &mut HistoryEntry) {
+	for (name, value) in entry
+		.sent
+		.headers
+		.iter_mut()
+		.chain(entry.response.headers.iter_mut())
+	{
+		if is_credential_header(name) {
+			*value = SECRET_MASK.to_string();
+		}
+	}
+}
+
+/// Secret environment values are not written to the history
+fn mask_entry_secrets(entry: &mut HistoryEntry, secrets: &SecretStore) {
+	entry.sent.url = secrets.mask(&entry.sent.url);
+	entry.sent.body = entry.sent.body.as_deref().map(|body| secrets.mask(body));
+
+	for (name, value) in entry
+		.sent
+		.headers
+		.iter_mut()
+		.chain(entry.response.headers.iter_mut())
+	{
+		let is_authorization = name.eq_ignore_ascii_case("authorization")
+			|| name.eq_ignore_ascii_case("proxy-authorization");
+
+		*value = match is_authorization {
+			true => mask_basic_credentials(value, secrets),
+			false => None,
+		}
+		.unwrap_or_else(|| secrets.mask(value));
+	}
+}
+
+/// Basic credentials are base64 encoded together, the secrets are masked in the decoded
+/// `username:password`
+fn mask_basic_credentials(value: &str, secrets: &SecretStore) -> Option<String> {
+	let (scheme, credentials) = value.split_once(' ')?;
+
+	if !scheme.eq_ignore_ascii_case("basic") {
+		return None;
+	}
+
+	let decoded = BASE64_STANDARD.decode(credentials.trim()).ok()?;
+	let decoded = String::from_utf8(decoded).ok()?;
+	let masked = secrets.mask(&decoded);
+
+	match masked != decoded {
+		true => Some(format!("{scheme} {masked}")),
+		false => None,
+	}
+}
+
+fn history_path() -> Option<PathBuf> {
+	ARGS.directory
+		.as_ref()
+		.map(|directory| directory.join(HISTORY_FILE_NAME))
+}
+
+/// Append an entry to the history file, its secret values masked and its credential headers
+/// redacted unless told otherwise.
+/// Logs a warning on failure rather than panicking.
+pub fn append_history_entry(mut entry: HistoryEntry, should_redact_credentials: bool) {
+	if !ARGS.should_save {
+		warn!("Dry-run, not recording the request in the history");
+		return;
+	}
+
+	let Some(path) = history_path() else {
+		return;
+	};
+
+	if should_redact_credentials {
+		redact_credential_headers(&mut entry);
+	}
+
+	// The secrets lock is released before logging, as the log writers mask the secrets too
+	mask_entry_secrets(&mut entry, &SECRETS.read());
+
+	let mut line = match serde_json::to_string(&entry) {
+		Ok(line) => line,
+		Err(e) => {
+			warn!("Could not serialize history entry: {e}");
+			return;
+		}
+	};
+	line.push('\n');
+
+	let result = OpenOptions::new()
+		.create(true)
+		.append(true)
+		.open(&path)
+		.and_then(|mut file| file.write_all(line.as_bytes()));
+
+	match result {
+		Ok(()) => trace!("Request recorded in the history"),
+		Err(e) => warn!("Could not write history file \"{}\": {e}", path.display()),
+	}
+}
+
+/// Read every history entry, oldest first. Unparsable lines are skipped.
+pub fn read_history() -> anyhow::Result<Vec<HistoryEntry>> {
+	let Some(path) = history_path() else {
+		return Ok(vec![]);
+	};
+
+	if !path.exists() {
+		return Ok(vec![]);
+	}
+
+	let file = File::open(&path)
+		.with_context(|| format!("Could not open history file \"{}\"", path.display()))?;
+
+	let mut entries = vec![];
+
+	for (index, line) in BufReader::new(file).lines().enumerate() {
+		let line =
+			line.with_context(|| format!("Could not read history file \"{}\"", path.display()))?;
+
+		if line.trim().is_empty() {
+			continue;
+		}
+
+		match serde_json::from_str::<HistoryEntry>(&line) {
+			Ok(entry) => entries.push(entry),
+			Err(e) => warn!("Skipping history line {}: {e}", index + 1),
+		}
+	}
+
+	Ok(entries)
+}
+
+/// Find an entry by its id or a unique prefix of it
+pub fn find_history_entry<'a>(
+	entries: &'a [HistoryEntry],
+	id: &str,
+) -> Result<&'a HistoryEntry, HistoryError> {
+	let mut matching = entries.iter().filter(|entry| entry.id.starts_with(id));
+
+	match (matching.next(), matching.next()) {
+		(Some(entry), None) if !id.is_empty() => Ok(entry),
+		(Some(_), _) => Err(HistoryError::AmbiguousId(id.to_string())),
+		(None, _) => Err(HistoryError::EntryNotFound(id.to_string())),
+	}
+}
+
+/// Latest entry of the same request sent before the given one
+pub fn previous_history_entry<'a>(
+	entries: &'a [HistoryEntry],
+	entry: &HistoryEntry,
+) -> Option<&'a HistoryEntry> {
+	entries
+		.iter()
+		.take_while(|other| other.id != entry.id)
+		.filter(|other| other.is_same_request(entry))
+		.last()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::models::environment::Environment;
+	use crate::models::history::SentRequest;
+	use crate::models::response::RequestResponse;
+
+	fn entry(request: &str) -> HistoryEntry {
+		HistoryEntry::new(
+			None,
+			request.to_string(),
+			String::from("HTTP"),
+			SentRequest::default(),
+			&RequestResponse::default(),
+		)
+	}
+
+	#[test]
+	fn find_entries_by_prefix() {
+		let mut first = entry("a");
+		first.id = String::from("abc123");
+		let mut second = entry("b");
+		second.id = String::from("abd456");
+		let entries = vec![first, second];
+
+		assert_eq!(find_history_entry(&entries, "abc").unwrap().request, "a");
+		assert_eq!(find_history_entry(&entries, "abd456").unwrap().request, "b");
+		assert!(matches!(
+			find_history_entry(&entries, "ab"),
+			Err(HistoryError::AmbiguousId(_))
+		));
+		assert!(matches!(
+			find_history_entry(&entries, "zz"),
+			Err(HistoryError::EntryNotFound(_))
+		));
+	}
+
+	#[test]
+	fn previous_entry_of_same_request() {
+		let entries = vec![entry("a"), entry("b"), entry("a"), entry("a")];
+
+		let previous = previous_history_entry(&entries, &entries[3]).unwrap();
+		assert_eq!(previous.id, entries[2].id);
+
+		assert!(previous_history_entry(&entries, &entries[0]).is_none());
+		assert!(previous_history_entry(&entries, &entries[1]).is_none());
+	}
+
+	#[test]
+	fn secret_basic_auth_password_is_masked() {
+		let mut secrets = SecretStore::default();
+		secrets.unlock("passphrase").unwrap();
+		secrets
+			.update_environment(&Environment {
+				name: String::from("dev"),
+				values: [(String::from("PASSWORD"), String::from("s3cr3t/pw"))].into(),
+				path: PathBuf::new(),
+				secret_keys: [String::from("PASSWORD")].into(),
+				parent: None,
+			})
+			.unwrap();
+
+		let mut entry = entry("a");
+		entry.sent.url = String::from("https://host/login?password=s3cr3t%2Fpw");
+		entry.sent.headers = vec![
+			(
+				String::from("authorization"),
+				format!("Basic {}", BASE64_STANDARD.encode("me:s3cr3t/pw")),
+			),
+			(String::from("x-other"), String::from("Basic bWU6b3RoZXI=")),
+		];
+
+		mask_entry_secrets(&mut entry, &secrets);
+
+		assert_eq!(entry.sent.url, "https://host/login?password=********");
+		assert_eq!(
+			entry.sent.headers,
+			[
+				(
+					String::from("authorization"),
+					String::from("Basic me:********")
+				),
+				(String::from("x-other"), String::from("Basic bWU6b3RoZXI=")),
+			]
+		);
+	}
+}
@@ -41,7 +41,10 @@ nest! {
 			pub display_env_editor: KeyCombination,
 			pub display_cookies: KeyCombination,
 			pub display_logs: KeyCombination,
+			pub display_history: KeyCombination,
 			pub display_theme_picker: KeyCombination,
+
+			pub edit_properties: KeyCombination,
 		},
 
 		pub generic: #[derive(Copy, Clone, Deserialize)] #[serde(default)] pub struct Generic {
@@ -83,9 +86,11 @@ nest! {
 				pub edit_element: KeyCombination,
 				/// Only used in the collections list (main menu)
 				pub rename_element: KeyCombination,
-				/// Only used in tables (Query params, headers, cookies)
+				/// Only used in tables (Query params, headers, cookies), toggles secret keys in the env editor
 				pub toggle_element: KeyCombination,
 				pub duplicate_element: KeyCombination,
+				/// Only used in the env editor
+				pub reveal_secrets: KeyCombination,
 			}
 		},
 
@@ -105,6 +110,10 @@ nest! {
 				pub change_auth_method: KeyCombination,
 				pub change_body_content_type: KeyCombination,
 				pub change_message_type: KeyCombination,
+				pub half_close_stream: KeyCombination,
+				pub toggle_grpc_reflection: KeyCombination,
+				pub change_grpc_compression: KeyCombination,
+				pub pick_grpc_method: KeyCombination,
 			},
 
 			pub result_tabs: #[derive(Copy, Clone, Deserialize)] #[serde(default)] pub struct ResultTabs {
@@ -120,6 +129,16 @@ nest! {
 
 				/// Enter selection mode in response body
 				pub select_response_body: KeyCombination,
+
+				pub search_response_body: KeyCombination,
+				pub next_search_match: KeyCombination,
+				pub previous_search_match: KeyCombination,
+
+				/// Project the response body with a JSONPath or jq-like expression
+				pub filter_response_body: KeyCombination,
+
+				/// Write the received response body to a file
+				pub save_response_body: KeyCombination,
 			}
 		},
 	}
@@ -172,7 +191,10 @@ impl Default for MainMenu {
 			display_env_editor: key!(ctrl - e),
 			display_cookies: key!(c),
 			display_logs: key!(l),
+			display_history: key!(h),
 			display_theme_picker: key!(shift - T),
+
+			edit_properties: key!(p),
 		}
 	}
 }
@@ -232,6 +254,7 @@ impl Default for ListAndTableActions {
 			rename_element: key!(r),
 			toggle_element: key!(t),
 			duplicate_element: key!(ctrl - d),
+			reveal_secrets: key!(v),
 		}
 	}
 }
@@ -264,6 +287,10 @@ impl Default for ParamTabs {
 			change_auth_method: key!(ctrl - a),
 			change_body_content_type: key!(ctrl - b),
 			change_message_type: key!(ctrl - m),
+			half_close_stream: key!(ctrl - x),
+			toggle_grpc_reflection: key!(ctrl - r),
+			change_grpc_compression: key!(ctrl - g),
+			pick_grpc_method: key!(ctrl - p),
 		}
 	}
 }
@@ -281,6 +308,14 @@ impl Default for ResultTabs {
 			result_next_tab: key!(shift - backtab),
 
 			select_response_body: key!(o),
+
+			search_response_body: key!('/'),
+			next_search_match: key!(alt - down),
+			previous_search_match: key!(alt - up),
+
+			filter_response_body: key!(f),
+
+			save_response_body: key!(shift - S),
 		}
 	}
 }
@@ -1,8 +1,12 @@
 pub(crate) mod collection;
 pub(crate) mod config;
+pub(crate) mod cookies;
 pub(crate) mod env_watcher;
 pub(crate) mod environment;
+pub(crate) mod history;
 pub(crate) mod key_bindings;
+pub(crate) mod oauth2_tokens;
+pub(crate) mod secrets;
 pub(crate) mod theme;
 pub(crate) mod theme_presets;
 pub(crate) mod utils;
@@ -0,0 +1,79 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use tracing::{info, trace, warn};
+
+use crate::app::App;
+use crate::app::files::utils::write_via_temp_file;
+use crate::cli::args::ARGS;
+use crate::models::auth::oauth2::OAuth2TokenCache;
+
+/// OAuth2 token cache file, in the main app directory.
+/// Tokens are kept out of the collections so that they are not shared with them.
+pub const OAUTH2_TOKENS_FILE_NAME: &str = "squrl.oauth2-tokens.json";
+
+impl App<'_> {
+	/// Load the OAuth2 token cache from the main app directory
+	pub fn load_oauth2_tokens(&mut self) {
+		let Some(path) = oauth2_tokens_path() else {
+			return;
+		};
+
+		match load_oauth2_token_cache(&path) {
+			Ok(tokens) => *self.core.oauth2_tokens.write() = tokens,
+			Err(e) => warn!("{e:#}"),
+		}
+	}
+}
+
+fn oauth2_tokens_path() -> Option<PathBuf> {
+	ARGS.directory
+		.as_ref()
+		.map(|directory| directory.join(OAUTH2_TOKENS_FILE_NAME))
+}
+
+fn load_oauth2_token_cache(path: &PathBuf) -> anyhow::Result<OAuth2TokenCache> {
+	trace!("Trying to open \"{}\" OAuth2 token cache", path.display());
+
+	let file = File::open(path)
+		.with_context(|| format!("Could not open OAuth2 token cache \"{}\"", path.display()))?;
+
+	serde_json::from_reader(BufReader::new(file))
+		.with_context(|| format!("Could not parse OAuth2 token cache \"{}\"", path.display()))
+}
+
+/// Save the OAuth2 token cache through a temporary file.
+/// Logs a warning on failure rather than panicking.
+pub fn save_oauth2_tokens(tokens: &OAuth2TokenCache) {
+	if !ARGS.should_save {
+		warn!("Dry-run, not saving the OAuth2 token cache");
+		return;
+	}
+
+	let Some(path) = oauth2_tokens_path() else {
+		return;
+	};
+
+	if tokens.is_empty() && !path.exists() {
+		return;
+	}
+
+	let data = match serde_json::to_vec_pretty(tokens) {
+		Ok(data) => data,
+		Err(e) => {
+			warn!("Could not serialize OAuth2 token cache: {e}");
+			return;
+		}
+	};
+
+	info!("Saving OAuth2 token cache \"{}\"", path.display());
+
+	if let Err(e) = write_via_temp_file(&path, &data) {
+		warn!("Could not save OAuth2 token cache: {e}");
+		return;
+	}
+
+	trace!("OAuth2 token cache saved")
+}
@@ -0,0 +1,719 @@
+use std::env;
+use std::io::{IsTerminal, Write, stderr, stdin};
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use anyhow::{Context, anyhow};
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use indexmap::{IndexMap, IndexSet};
+use parking_lot::RwLock;
+use percent_encoding::utf8_percent_encode;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use ring::aead::{AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{info, trace, warn};
+
+use crate::app::App;
+use crate::app::files::utils::write_via_temp_file;
+use crate::app::template::URL_ENCODE_SET;
+use crate::cli::args::ARGS;
+use crate::models::environment::{Environment, SECRET_MASK};
+
+/// Passphrase-encrypted secret environment values, in the main app directory
+pub const SECRETS_FILE_NAME: &str = "squrl.secrets";
+
+/// Passphrase of the secrets store
+pub const PASSPHRASE_ENV_VAR: &str = "SQURL_SECRETS_PASSPHRASE";
+
+/// New passphrase of the secrets store, used when rotating it
+pub const NEW_PASSPHRASE_ENV_VAR: &str = "SQURL_NEW_SECRETS_PASSPHRASE";
+
+const SECRETS_FILE_VERSION: u32 = 1;
+
+/// OWASP recommendation for PBKDF2-HMAC-SHA256
+#[cfg(not(test))]
+const PBKDF2_ITERATIONS: u32 = 600_000;
+
+#[cfg(test)]
+const PBKDF2_ITERATIONS: u32 = 1_000;
+
+/// Iterations of the keys of new passphrases in debug builds, which keeps the integration tests of
+/// the binary fast
+#[cfg(debug_assertions)]
+const DEBUG_ITERATIONS_ENV_VAR: &str = "SQURL_DEBUG_PBKDF2_ITERATIONS";
+
+/// Shorter values, such as `1`, `true` or `dev`, would mask every occurrence of them
+const MIN_MASKED_SECRET_LENGTH: usize = 6;
+
+const SALT_LENGTH: usize = 16;
+
+pub static SECRETS: LazyLock<RwLock<SecretStore>> =
+	LazyLock::new(|| RwLock::new(SecretStore::default()));
+
+#[derive(Error, Debug)]
+pub enum SecretsError {
+	#[error("Secrets are locked, unlock them with the {PASSPHRASE_ENV_VAR} environment variable")]
+	Locked,
+
+	#[error("No passphrase given, set the {0} environment variable")]
+	NoPassphrase(&'static str),
+
+	#[error("The passphrase cannot be empty")]
+	EmptyPassphrase,
+
+	#[error("The passphrases do not match")]
+	PassphrasesDoNotMatch,
+
+	#[error("Wrong passphrase or corrupted secrets file")]
+	CouldNotDecrypt,
+
+	#[error("Could not encrypt the secrets")]
+	CouldNotEncrypt,
+
+	#[error("Unsupported secrets file version {0}")]
+	UnsupportedVersion(u32),
+}
+
+/// Secrets file content, the secret keys are readable so that they are known while locked
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SecretsFile {
+	version: u32,
+	iterations: u32,
+	salt: String,
+	nonce: String,
+	/// Secret keys of each environment, authenticated along with the encrypted values
+	keys: IndexMap<String, IndexSet<String>>,
+	ciphertext: String,
+}
+
+/// AES-256-GCM key derived from the passphrase
+struct Cipher {
+	key: LessSafeKey,
+	salt: Vec<u8>,
+	iterations: u32,
+}
+
+#[derive(Default)]
+pub struct SecretStore {
+	/// Secret keys of each environment
+	keys: IndexMap<String, IndexSet<String>>,
+	/// Secret values of each environment, empty while locked
+	values: IndexMap<String, IndexMap<String, String>>,
+	/// Last read or written file, decrypted once the passphrase is known
+	file: Option<SecretsFile>,
+	/// `None` while locked
+	cipher: Option<Cipher>,
+}
+
+impl Cipher {
+	/// Derive a key from the passphrase with a new random salt
+	fn new(passphrase: &str, iterations: u32) -> Result<Self, SecretsError> {
+		let mut salt = vec![0; SALT_LENGTH];
+
+		SystemRandom::new()
+			.fill(&mut salt)
+			.map_err(|_| SecretsError::CouldNotEncrypt)?;
+
+		Self::derive(passphrase, salt, iterations)
+	}
+
+	fn derive(passphrase: &str, salt: Vec<u8>, iterations: u32) -> Result<Self, SecretsError> {
+		if passphrase.is_empty() {
+			return Err(SecretsError::EmptyPassphrase);
+		}
+
+		let iterations_count = NonZeroU32::new(iterations).ok_or(SecretsError::CouldNotDecrypt)?;
+		let mut key = [0; 32];
+
+		pbkdf2::derive(
+			pbkdf2::PBKDF2_HMAC_SHA256,
+			iterations_count,
+			&salt,
+			passphrase.as_bytes(),
+			&mut key,
+		);
+
+		let key = UnboundKey::new(&AES_256_GCM, &key).map_err(|_| SecretsError::CouldNotEncrypt)?;
+
+		Ok(Cipher {
+			key: LessSafeKey::new(key),
+			salt,
+			iterations,
+		})
+	}
+
+	/// Encrypt with a new random nonce, returns the nonce and the ciphertext
+	fn seal(&self, aad: &[u8], plaintext: &[u8]) -> Result<(Vec<u8>, Vec<u8>), SecretsError> {
+		let mut nonce = [0; NONCE_LEN];
+
+		SystemRandom::new()
+			.fill(&mut nonce)
+			.map_err(|_| SecretsError::CouldNotEncrypt)?;
+
+		let mut in_out = plaintext.to_vec();
+
+		self.key
+			.seal_in_place_append_tag(
+				Nonce::assume_unique_for_key(nonce),
+				Aad::from(aad),
+				&mut in_out,
+			)
+			.map_err(|_| SecretsError::CouldNotEncrypt)?;
+
+		Ok((nonce.to_vec(), in_out))
+	}
+
+	fn open(&self, nonce: &[u8], aad: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, SecretsError> {
+		let nonce =
+			Nonce::try_assume_unique_for_key(nonce).map_err(|_| SecretsError::CouldNotDecrypt)?;
+		let mut in_out = ciphertext.to_vec();
+
+		let plaintext = self
+			.key
+			.open_in_place(nonce, Aad::from(aad), &mut in_out)
+			.map_err(|_| SecretsError::CouldNotDecrypt)?;
+
+		Ok(plaintext.to_vec())
+	}
+}
+
+impl SecretStore {
+	/// Read a locked store from the secrets file
+	pub fn read_from_file(path: &Path) -> anyhow::Result<SecretStore> {
+		let content = std::fs::read_to_string(path)
+			.with_context(|| format!("Could not read secrets file \"{}\"", path.display()))?;
+
+		let file: SecretsFile = serde_json::from_str(&content)
+			.with_context(|| format!("Could not parse secrets file \"{}\"", path.display()))?;
+
+		if file.version != SECRETS_FILE_VERSION {
+			return Err(anyhow!(SecretsError::UnsupportedVersion(file.version)));
+		}
+
+		Ok(SecretStore {
+			keys: file.keys.clone(),
+			values: IndexMap::new(),
+			file: Some(file),
+			cipher: None,
+		})
+	}
+
+	pub fn is_locked(&self) -> bool {
+		self.cipher.is_none()
+	}
+
+	/// Whether the store was read from or written to a file
+	pub fn has_file(&self) -> bool {
+		self.file.is_some()
+	}
+
+	/// Decrypt the secret values, or set the passphrase of a new store
+	pub fn unlock(&mut self, passphrase: &str) -> Result<(), SecretsError> {
+		let Some(file) = &self.file else {
+			self.cipher = Some(Cipher::new(passphrase, new_key_iterations())?);
+			return Ok(());
+		};
+
+		let decode = |value: &str| {
+			BASE64_STANDARD
+				.decode(value)
+				.map_err(|_| SecretsError::CouldNotDecrypt)
+		};
+
+		let cipher = Cipher::derive(passphrase, decode(&file.salt)?, file.iterations)?;
+		let plaintext = cipher.open(
+			&decode(&file.nonce)?,
+			&keys_aad(&file.keys),
+			&decode(&file.ciphertext)?,
+		)?;
+
+		self.values =
+			serde_json::from_slice(&plaintext).map_err(|_| SecretsError::CouldNotDecrypt)?;
+		self.cipher = Some(cipher);
+
+		Ok(())
+	}
+
+	/// Encrypt the secrets with a new passphrase, the store has to be unlocked
+	pub fn rotate_passphrase(&mut self, new_passphrase: &str) -> Result<(), SecretsError> {
+		if self.is_locked() {
+			return Err(SecretsError::Locked);
+		}
+
+		self.cipher = Some(Cipher::new(new_passphrase, new_key_iterations())?);
+		Ok(())
+	}
+
+	pub fn environment_keys(&self, environment_name: &str) -> IndexSet<String> {
+		self.keys.get(environment_name).cloned().unwrap_or_default()
+	}
+
+	pub fn environment_values(&self, environment_name: &str) -> Option<&IndexMap<String, String>> {
+		self.values.get(environment_name)
+	}
+
+	/// Take the secret values of the environment, returns whether the store changed
+	pub fn update_environment(&mut self, environment: &Environment) -> Result<bool, SecretsError> {
+		let keys = environment.secret_keys.clone();
+		let values: IndexMap<String, String> = keys
+			.iter()
+			.filter_map(|key| {
+				environment
+					.values
+					.get(key)
+					.map(|value| (key.clone(), value.clone()))
+			})
+			.collect();
+
+		let is_unchanged = self.environment_keys(&environment.name) == keys
+			&& self
+				.values
+				.get(&environment.name)
+				.map_or(values.is_empty(), |old_values| old_values == &values);
+
+		if is_unchanged {
+			return Ok(false);
+		}
+
+		if self.is_locked() {
+			return Err(SecretsError::Locked);
+		}
+
+		match keys.is_empty() {
+			true => {
+				self.keys.shift_remove(&environment.name);
+				self.values.shift_remove(&environment.name);
+			}
+			false => {
+				self.keys.insert(environment.name.clone(), keys);
+				self.values.insert(environment.name.clone(), values);
+			}
+		}
+
+		Ok(true)
+	}
+
+	/// Encrypt the secret values and write them through a temporary file
+	pub fn write_to_file(&mut self, path: &Path) -> anyhow::Result<()> {
+		let cipher = self.cipher.as_ref().ok_or(SecretsError::Locked)?;
+
+		let plaintext = serde_json::to_vec(&self.values)?;
+		let (nonce, ciphertext) = cipher.seal(&keys_aad(&self.keys), &plaintext)?;
+
+		let file = SecretsFile {
+			version: SECRETS_FILE_VERSION,
+			iterations: cipher.iterations,
+			salt: BASE64_STANDARD.encode(&cipher.salt),
+			nonce: BASE64_STANDARD.encode(nonce),
+			keys: self.keys.clone(),
+			ciphertext: BASE64_STANDARD.encode(ciphertext),
+		};
+
+		write_via_temp_file(path, serde_json::to_string_pretty(&file)?.as_bytes())
+			.context("Could not save secrets file")?;
+
+		self.file = Some(file);
+		Ok(())
+	}
+
+	/// Replace every known secret value by a mask, longest values first.
+	/// The percent-encoded and base64 forms of the values are masked as well. Values shorter than
+	/// [`MIN_MASKED_SECRET_LENGTH`] are left as is.
+	pub fn mask(&self, text: &str) -> String {
+		let mut secret_values: Vec<String> = self
+			.values
+			.values()
+			.flat_map(|values| values.values())
+			.filter(|value| value.chars().count() >= MIN_MASKED_SECRET_LENGTH)
+			.flat_map(|value| encoded_forms(value))
+			.collect();
+
+		secret_values.sort_by_key(|value| std::cmp::Reverse(value.len()));
+
+		let mut masked = text.to_string();
+
+		for value in secret_values {
+			if masked.contains(value.as_str()) {
+				masked = masked.replace(value.as_str(), SECRET_MASK);
+			}
+		}
+
+		masked
+	}
+}
+
+fn new_key_iterations() -> u32 {
+	#[cfg(debug_assertions)]
+	if let Some(iterations) = env::var(DEBUG_ITERATIONS_ENV_VAR)
+		.ok()
+		.and_then(|iterations| iterations.parse().ok())
+	{
+		return iterations;
+	}
+
+	PBKDF2_ITERATIONS
+}
+
+/// A secret value as is, percent-encoded in URLs, form-encoded and base64 encoded
+fn encoded_forms(value: &str) -> [String; 4] {
+	let percent_encoded = utf8_percent_encode(value, URL_ENCODE_SET).to_string();
+
+	[
+		value.to_string(),
+		percent_encoded.replace("%20", "+"),
+		percent_encoded,
+		BASE64_STANDARD.encode(value),
+	]
+}
+
+/// The secret keys cannot be modified without the passphrase
+fn keys_aad(keys: &IndexMap<String, IndexSet<String>>) -> Vec<u8> {
+	serde_json::to_vec(keys).unwrap_or_default()
+}
+
+fn secrets_path() -> Option<PathBuf> {
+	ARGS.directory
+		.as_ref()
+		.map(|directory| directory.join(SECRETS_FILE_NAME))
+}
+
+/// Replace the secret environment values found in the text by a mask
+pub fn mask_secrets(text: &str) -> String {
+	SECRETS.read().mask(text)
+}
+
+/// Save the secret values of an environment in the secrets file.
+/// Logs a warning on failure rather than panicking.
+pub fn save_environment_secrets(environment: &Environment) {
+	// The lock is released before logging, as the log writers mask the secrets
+	let result = SECRETS.write().update_environment(environment);
+
+	match result {
+		Ok(false) => {}
+		Ok(true) => match save_secrets() {
+			Ok(_) => trace!("Secrets saved"),
+			Err(e) => warn!("Could not save secrets: {e:#}"),
+		},
+		Err(e) => warn!(
+			"Could not save the secrets of environment \"{}\": {e}",
+			environment.name
+		),
+	}
+}
+
+fn save_secrets() -> anyhow::Result<()> {
+	let path = secrets_path().context("--directory argument is required")?;
+
+	SECRETS.write().write_to_file(&path)
+}
+
+fn passphrase_from_env(env_var: &str) -> Option<String> {
+	env::var(env_var)
+		.ok()
+		.filter(|passphrase| !passphrase.is_empty())
+}
+
+/// Read a passphrase or a secret value from the terminal without echoing it, `None` outside a
+/// terminal or when cancelled
+pub fn prompt_hidden_input(prompt: &str) -> Option<String> {
+	if !stdin().is_terminal() {
+		return None;
+	}
+
+	eprint!("{prompt}: ");
+	stderr().flush().ok()?;
+	enable_raw_mode().ok()?;
+
+	let mut input = String::new();
+
+	let result = loop {
+		match event::read() {
+			Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => match key.code {
+				KeyCode::Enter => break Some(input),
+				KeyCode::Esc => break None,
+				KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break None,
+				KeyCode::Char(char) => input.push(char),
+				KeyCode::Backspace => {
+					input.pop();
+				}
+				_ => {}
+			},
+			Ok(_) => {}
+			Err(_) => break None,
+		}
+	};
+
+	disable_raw_mode().ok();
+	eprintln!();
+
+	result
+}
+
+/// New passphrase from the environment variable, or prompted twice
+fn new_passphrase(env_var: &'static str, prompt: &str) -> Result<String, SecretsError> {
+	if let Some(passphrase) = passphrase_from_env(env_var) {
+		return Ok(passphrase);
+	}
+
+	let passphrase = prompt_hidden_input(prompt).ok_or(SecretsError::NoPassphrase(env_var))?;
+	let confirmation =
+		prompt_hidden_input("Confirm the passphrase").ok_or(SecretsError::NoPassphrase(env_var))?;
+
+	match passphrase == confirmation {
+		true => Ok(passphrase),
+		false => Err(SecretsError::PassphrasesDoNotMatch),
+	}
+}
+
+impl App<'_> {
+	/// Read the secrets file and unlock it when the passphrase is in the environment
+	pub fn load_secrets(&mut self) {
+		let Some(path) = secrets_path() else {
+			return;
+		};
+
+		if path.exists() {
+			match SecretStore::read_from_file(&path) {
+				Ok(store) => *SECRETS.write() = store,
+				Err(e) => {
+					warn!("{e:#}");
+					return;
+				}
+			}
+		}
+
+		if let Some(passphrase) = passphrase_from_env(PASSPHRASE_ENV_VAR) {
+			let result = SECRETS.write().unlock(&passphrase);
+
+			if let Err(e) = result {
+				warn!("Could not unlock secrets: {e}");
+			}
+		}
+
+		let is_locked = {
+			let store = SECRETS.read();
+			store.has_file() && store.is_locked()
+		};
+
+		if is_locked {
+			warn!("{}", SecretsError::Locked);
+		}
+
+		self.apply_secrets_to_environments();
+	}
+
+	/// Unlock the secrets with the passphrase from the environment or prompted, the passphrase
+	/// of a new store being asked twice
+	pub fn unlock_secrets(&mut self) -> anyhow::Result<()> {
+		let has_file = {
+			let store = SECRETS.read();
+
+			if !store.is_locked() {
+				return Ok(());
+			}
+
+			store.has_file()
+		};
+
+		let passphrase = match has_file {
+			true => passphrase_from_env(PASSPHRASE_ENV_VAR)
+				.or_else(|| prompt_hidden_input("Secrets passphrase"))
+				.ok_or(SecretsError::NoPassphrase(PASSPHRASE_ENV_VAR))?,
+			false => new_passphrase(PASSPHRASE_ENV_VAR, "New secrets passphrase")?,
+		};
+
+		SECRETS.write().unlock(&passphrase)?;
+
+		self.apply_secrets_to_environments();
+		Ok(())
+	}
+
+	/// Encrypt the secrets with a new passphrase
+	pub fn rotate_secrets_passphrase(&mut self) -> anyhow::Result<()> {
+		self.unlock_secrets()?;
+
+		let new_passphrase = new_passphrase(NEW_PASSPHRASE_ENV_VAR, "New secrets passphrase")?;
+
+		SECRETS.write().rotate_passphrase(&new_passphrase)?;
+		save_secrets()?;
+
+		info!("Secrets passphrase rotated");
+		Ok(())
+	}
+
+	/// Mark the secret keys of the environments and add their values once unlocked
+	fn apply_secrets_to_environments(&mut self) {
+		let store = SECRETS.read();
+
+		for environment in &self.core.environments {
+			let mut environment = environment.write();
+
+			environment.secret_keys = store.environment_keys(&environment.name);
+
+			if let Some(values) = store.environment_values(&environment.name) {
+				for (key, value) in values {
+					environment.values.insert(key.clone(), value.clone());
+				}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn environment(secret_keys: &[&str], values: &[(&str, &str)]) -> Environment {
+		Environment {
+			name: String::from("dev"),
+			values: values
+				.iter()
+				.map(|(key, value)| (key.to_string(), value.to_string()))
+				.collect(),
+			path: PathBuf::new(),
+			secret_keys: secret_keys.iter().map(|key| key.to_string()).collect(),
+			parent: None,
+		}
+	}
+
+	fn unlocked_store(passphrase: &str) -> SecretStore {
+		let mut store = SecretStore::default();
+		store.unlock(passphrase).unwrap();
+		store
+	}
+
+	#[test]
+	fn secrets_round_trip_through_the_file() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join(SECRETS_FILE_NAME);
+
+		let mut store = unlocked_store("passphrase");
+		let environment = environment(&["TOKEN"], &[("HOST", "localhost"), ("TOKEN", "abc")]);
+		assert!(store.update_environment(&environment).unwrap());
+		store.write_to_file(&path).unwrap();
+
+		let content = std::fs::read_to_string(&path).unwrap();
+		assert!(content.contains("TOKEN"));
+		assert!(!content.contains("abc"));
+		assert!(!content.contains("localhost"));
+
+		let mut store = SecretStore::read_from_file(&path).unwrap();
+		assert!(store.is_locked());
+		assert_eq!(
+			store.environment_keys("dev"),
+			IndexSet::from([String::from("TOKEN")])
+		);
+		assert!(store.environment_values("dev").is_none());
+
+		store.unlock("passphrase").unwrap();
+		assert_eq!(store.environment_values("dev").unwrap()["TOKEN"], "abc");
+	}
+
+	#[test]
+	fn wrong_passphrase_is_rejected() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join(SECRETS_FILE_NAME);
+
+		let mut store = unlocked_store("passphrase");
+		store
+			.update_environment(&environment(&["TOKEN"], &[("TOKEN", "abc")]))
+			.unwrap();
+		store.write_to_file(&path).unwrap();
+
+		let mut store = SecretStore::read_from_file(&path).unwrap();
+		assert!(matches!(
+			store.unlock("wrong"),
+			Err(SecretsError::CouldNotDecrypt)
+		));
+		assert!(store.is_locked());
+	}
+
+	#[test]
+	fn rotated_passphrase_replaces_the_old_one() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join(SECRETS_FILE_NAME);
+
+		let mut store = unlocked_store("old");
+		store
+			.update_environment(&environment(&["TOKEN"], &[("TOKEN", "abc")]))
+			.unwrap();
+		store.rotate_passphrase("new").unwrap();
+		store.write_to_file(&path).unwrap();
+
+		let mut store = SecretStore::read_from_file(&path).unwrap();
+		assert!(store.unlock("old").is_err());
+		store.unlock("new").unwrap();
+		assert_eq!(store.environment_values("dev").unwrap()["TOKEN"], "abc");
+	}
+
+	#[test]
+	fn locked_store_keeps_its_secrets() {
+		let mut store = SecretStore {
+			keys: IndexMap::from([(String::from("dev"), IndexSet::from([String::from("TOKEN")]))]),
+			..SecretStore::default()
+		};
+
+		// The values of a locked store are unknown, saving the environment changes nothing
+		assert!(
+			!store
+				.update_environment(&environment(&["TOKEN"], &[("HOST", "localhost")]))
+				.unwrap()
+		);
+		assert!(matches!(
+			store.update_environment(&environment(&["TOKEN", "KEY"], &[("KEY", "value")])),
+			Err(SecretsError::Locked)
+		));
+	}
+
+	#[test]
+	fn mask_replaces_secret_values() {
+		let mut store = unlocked_store("passphrase");
+		store
+			.update_environment(&environment(
+				&["TOKEN", "KEY"],
+				&[("TOKEN", "abcdef"), ("KEY", "abcdefghij")],
+			))
+			.unwrap();
+
+		assert_eq!(
+			store.mask("Authorization: Bearer abcdef, key=abcdefghij"),
+			"Authorization: Bearer ********, key=********"
+		);
+	}
+
+	#[test]
+	fn mask_skips_short_secret_values() {
+		let mut store = unlocked_store("passphrase");
+		store
+			.update_environment(&environment(
+				&["DEBUG", "STAGE"],
+				&[("DEBUG", "true"), ("STAGE", "dev")],
+			))
+			.unwrap();
+
+		assert_eq!(
+			store.mask(r#"{"debug": true, "device": "dev-1"}"#),
+			r#"{"debug": true, "device": "dev-1"}"#
+		);
+	}
+
+	#[test]
+	fn mask_replaces_encoded_secret_values() {
+		let mut store = unlocked_store("passphrase");
+		store
+			.update_environment(&environment(&["PASSWORD"], &[("PASSWORD", "p@ss w/rd")]))
+			.unwrap();
+
+		assert_eq!(
+			store.mask("https://host/?password=p%40ss%20w%2Frd&form=p%40ss+w%2Frd"),
+			"https://host/?password=********&form=********"
+		);
+		assert_eq!(store.mask("token cEBzcyB3L3Jk"), "token ********");
+	}
+}
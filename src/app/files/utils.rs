@@ -26,6 +26,18 @@ pub fn write_via_temp_file(target_path: &Path, data: &[u8]) -> std::io::Result<(
 	Ok(())
 }
 
+/// Keep the alphanumeric characters, `-` and `_` of a name, replacing the others by `_`
+pub fn sanitize_file_name(name: &str) -> String {
+	name.chars()
+		.map(
+			|char| match char.is_alphanumeric() || char == '-' || char == '_' {
+				true => char,
+				false => '_',
+			},
+		)
+		.collect()
+}
+
 pub fn expand_tilde(path_buf: PathBuf) -> PathBuf {
 	if !path_buf.starts_with("~/") {
 		return path_buf;
@@ -51,6 +63,13 @@ pub fn expand_tilde(path_buf: PathBuf) -> PathBuf {
 mod tests {
 	use super::*;
 
+	#[test]
+	fn test_sanitize_file_name() {
+		assert_eq!(sanitize_file_name("my-api_v2"), "my-api_v2");
+		assert_eq!(sanitize_file_name("../etc/passwd"), "___etc_passwd");
+		assert_eq!(sanitize_file_name("My API: v1.json"), "My_API__v1_json");
+	}
+
 	// Tilde expansion is a Unix shell convention — these tests only apply to Unix.
 	#[cfg(not(windows))]
 	#[test]
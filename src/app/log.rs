@@ -1,13 +1,17 @@
 use chrono::Utc;
 use parking_lot::Mutex;
 use std::fmt::{Debug, Write};
+use std::io;
 use std::sync::LazyLock;
 use std::sync::atomic::{AtomicBool, Ordering};
 use tracing::field::{Field, Visit};
 use tracing::{Level, Subscriber};
 use tracing_subscriber::Layer;
+use tracing_subscriber::fmt::MakeWriter;
 use tracing_subscriber::layer::Context;
 
+use crate::app::files::secrets::mask_secrets;
+
 // Avoids been lock inside the logger widget when moving around
 pub static SHOULD_RECORD_LOGS: AtomicBool = AtomicBool::new(true);
 
@@ -31,7 +35,7 @@ impl<S: Subscriber> Layer<S> for LogCounterLayer {
 		e.record(&mut StringVisitor(&mut message));
 
 		let mut logs = LOGS.lock();
-		logs.push((now, level, target, message));
+		logs.push((now, level, target, mask_secrets(&message)));
 		// Prevents keeping too many logs — remove the oldest entry
 		if logs.len() > 1000 {
 			logs.remove(0);
@@ -48,3 +52,29 @@ impl<'a> Visit for StringVisitor<'a> {
 			.expect("writing to log buffer should succeed");
 	}
 }
+
+/// Log writers masking the secret environment values
+pub struct MaskSecrets<M>(pub M);
+
+pub struct MaskSecretsWriter<W>(W);
+
+impl<'a, M: MakeWriter<'a>> MakeWriter<'a> for MaskSecrets<M> {
+	type Writer = MaskSecretsWriter<M::Writer>;
+
+	fn make_writer(&'a self) -> Self::Writer {
+		MaskSecretsWriter(self.0.make_writer())
+	}
+}
+
+impl<W: io::Write> io::Write for MaskSecretsWriter<W> {
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		let masked = mask_secrets(&String::from_utf8_lossy(buf));
+
+		self.0.write_all(masked.as_bytes())?;
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		self.0.flush()
+	}
+}
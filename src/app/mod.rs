@@ -9,7 +9,9 @@ pub(crate) mod files;
 pub(crate) mod key_value;
 pub(crate) mod log;
 pub mod request;
+pub(crate) mod runner;
 pub mod startup;
+pub(crate) mod template;
 pub(crate) mod utils;
 
 pub use app::App;
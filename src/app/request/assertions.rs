@@ -0,0 +1,514 @@
+//! Declarative request [`Assertion`]s and their evaluation against a received response.
+
+use std::time::Duration;
+
+use anyhow::anyhow;
+use regex::Regex;
+use serde_json::Value;
+use thiserror::Error;
+use tracing::info;
+
+use crate::app::App;
+use crate::app::request::json_path;
+use crate::models::assertions::{Assertion, AssertionResult};
+use crate::models::response::{RequestResponse, ResponseContent};
+
+#[derive(Error, Debug)]
+pub enum AssertionError {
+	#[error("Assertion index {0} is out of bounds")]
+	IndexOutOfBounds(usize),
+}
+
+impl App<'_> {
+	pub fn add_request_assertion(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		assertion: Assertion,
+	) -> anyhow::Result<()> {
+		self.with_request_write(collection_index, request_index, |req| {
+			info!("Assertion \"{}\" added", assertion);
+			req.assertions.push(assertion);
+		});
+
+		Ok(())
+	}
+
+	pub fn delete_request_assertion(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		index: usize,
+	) -> anyhow::Result<()> {
+		self.with_request_write_result(collection_index, request_index, |req| {
+			if index >= req.assertions.len() {
+				return Err(anyhow!(AssertionError::IndexOutOfBounds(index)));
+			}
+
+			let assertion = req.assertions.remove(index);
+			info!("Assertion \"{}\" deleted", assertion);
+
+			Ok(())
+		})
+	}
+}
+
+/// Evaluate every assertion against the response, in order.
+pub fn evaluate_assertions(
+	assertions: &[Assertion],
+	response: &RequestResponse,
+	elapsed_time: Duration,
+) -> Vec<AssertionResult> {
+	assertions
+		.iter()
+		.map(|assertion| {
+			let message = evaluate_assertion(assertion, response, elapsed_time).err();
+
+			AssertionResult {
+				assertion: assertion.to_string(),
+				passed: message.is_none(),
+				message,
+			}
+		})
+		.collect()
+}
+
+fn evaluate_assertion(
+	assertion: &Assertion,
+	response: &RequestResponse,
+	elapsed_time: Duration,
+) -> Result<(), String> {
+	match assertion {
+		Assertion::StatusEquals(expected) => {
+			let status = response_status(response)?;
+
+			match status == *expected {
+				true => Ok(()),
+				false => Err(format!("Expected status {expected}, got {status}")),
+			}
+		}
+		Assertion::StatusInRange { min, max } => {
+			let status = response_status(response)?;
+
+			match (*min..=*max).contains(&status) {
+				true => Ok(()),
+				false => Err(format!("Expected status in {min}..{max}, got {status}")),
+			}
+		}
+		Assertion::GrpcStatusEquals(expected) => {
+			let grpc_status = response
+				.grpc_status
+				.as_ref()
+				.ok_or("No gRPC status, the response is not a gRPC one")?;
+
+			match grpc_status.name() == expected {
+				true => Ok(()),
+				false => Err(format!(
+					"Expected gRPC status {expected}, got {grpc_status}"
+				)),
+			}
+		}
+		Assertion::HeaderExists(name) => match find_header(response, name) {
+			Some(_) => Ok(()),
+			None => Err(format!("Header \"{name}\" not found")),
+		},
+		Assertion::HeaderMatches { name, pattern } => {
+			let value =
+				find_header(response, name).ok_or(format!("Header \"{name}\" not found"))?;
+			let regex = Regex::new(pattern).map_err(|e| e.to_string())?;
+
+			match regex.is_match(value) {
+				true => Ok(()),
+				false => Err(format!(
+					"Header \"{name}\" value \"{value}\" does not match \"{pattern}\""
+				)),
+			}
+		}
+		Assertion::JsonPathEquals { path, value } => {
+			let body = response_json(response)?;
+			let found = json_path::query_first(&body, path).map_err(|e| e.to_string())?;
+
+			match found {
+				Some(found) if json_path::values_equal(&found, value) => Ok(()),
+				Some(found) => Err(format!("Expected {value} at \"{path}\", got {found}")),
+				None => Err(format!("Nothing found at \"{path}\"")),
+			}
+		}
+		Assertion::DurationBelow(max_ms) => {
+			let elapsed_ms = elapsed_time.as_millis();
+
+			match elapsed_ms < *max_ms as u128 {
+				true => Ok(()),
+				false => Err(format!(
+					"Expected duration below {max_ms}ms, took {elapsed_ms}ms"
+				)),
+			}
+		}
+		Assertion::JsonSchema(schema) => {
+			let body = response_json(response)?;
+			let mut errors = vec![];
+
+			validate_schema(schema, &body, "$", &mut errors);
+
+			match errors.is_empty() {
+				true => Ok(()),
+				false => Err(errors.join("; ")),
+			}
+		}
+	}
+}
+
+fn response_status(response: &RequestResponse) -> Result<u16, String> {
+	let status_code = response
+		.status_code
+		.as_deref()
+		.ok_or("No response status")?;
+
+	status_code
+		.split_whitespace()
+		.next()
+		.and_then(|code| code.parse::<u16>().ok())
+		.ok_or(format!("Unexpected status: {status_code}"))
+}
+
+pub(super) fn find_header<'a>(response: &'a RequestResponse, name: &str) -> Option<&'a str> {
+	response
+		.headers
+		.iter()
+		.find(|(header, _)| header.eq_ignore_ascii_case(name))
+		.map(|(_, value)| value.as_str())
+}
+
+pub(super) fn response_json(response: &RequestResponse) -> Result<Value, String> {
+	match &response.content {
+		Some(ResponseContent::Body(body)) => {
+			serde_json::from_str(body).map_err(|e| format!("Response body is not valid JSON: {e}"))
+		}
+		Some(ResponseContent::Image(_)) => Err(String::from("Response body is an image")),
+		None => Err(String::from("No response body")),
+	}
+}
+
+/// Validate a value against a subset of JSON Schema: `type`, `enum`, `const`, `required`,
+/// `properties`, `additionalProperties`, `items`, `minItems`/`maxItems`,
+/// `minLength`/`maxLength`, `pattern`, `minimum`/`maximum`, `exclusiveMinimum`/`exclusiveMaximum`,
+/// `allOf`, `anyOf` and `oneOf`.
+fn validate_schema(schema: &Value, value: &Value, path: &str, errors: &mut Vec<String>) {
+	let schema = match schema {
+		Value::Bool(true) => return,
+		Value::Bool(false) => {
+			errors.push(format!("{path}: no value is allowed"));
+			return;
+		}
+		Value::Object(schema) => schema,
+		_ => return,
+	};
+
+	if let Some(expected_type) = schema.get("type") {
+		let matches = match expected_type {
+			Value::String(expected_type) => type_matches(expected_type, value),
+			Value::Array(types) => types
+				.iter()
+				.filter_map(Value::as_str)
+				.any(|expected_type| type_matches(expected_type, value)),
+			_ => true,
+		};
+
+		if !matches {
+			errors.push(format!(
+				"{path}: expected type {expected_type}, got {}",
+				type_name(value)
+			));
+			return;
+		}
+	}
+
+	if let Some(Value::Array(allowed)) = schema.get("enum")
+		&& !allowed
+			.iter()
+			.any(|allowed| json_path::values_equal(allowed, value))
+	{
+		errors.push(format!(
+			"{path}: {value} is not one of {}",
+			Value::Array(allowed.clone())
+		));
+	}
+
+	if let Some(constant) = schema.get("const")
+		&& !json_path::values_equal(constant, value)
+	{
+		errors.push(format!("{path}: expected {constant}, got {value}"));
+	}
+
+	match value {
+		Value::Object(object) => {
+			if let Some(Value::Array(required)) = schema.get("required") {
+				for key in required.iter().filter_map(Value::as_str) {
+					if !object.contains_key(key) {
+						errors.push(format!("{path}: missing required property \"{key}\""));
+					}
+				}
+			}
+
+			let properties = schema.get("properties").and_then(Value::as_object);
+
+			for (key, property_value) in object {
+				let property_path = format!("{path}.{key}");
+
+				match properties.and_then(|properties| properties.get(key)) {
+					Some(property_schema) => {
+						validate_schema(property_schema, property_value, &property_path, errors)
+					}
+					None => match schema.get("additionalProperties") {
+						Some(Value::Bool(false)) => {
+							errors.push(format!("{path}: unexpected property \"{key}\""))
+						}
+						Some(additional_schema) => validate_schema(
+							additional_schema,
+							property_value,
+							&property_path,
+							errors,
+						),
+						None => {}
+					},
+				}
+			}
+		}
+		Value::Array(items) => {
+			if let Some(min_items) = schema.get("minItems").and_then(Value::as_u64)
+				&& (items.len() as u64) < min_items
+			{
+				errors.push(format!("{path}: expected at least {min_items} item(s)"));
+			}
+
+			if let Some(max_items) = schema.get("maxItems").and_then(Value::as_u64)
+				&& (items.len() as u64) > max_items
+			{
+				errors.push(format!("{path}: expected at most {max_items} item(s)"));
+			}
+
+			if let Some(items_schema) = schema.get("items") {
+				for (index, item) in items.iter().enumerate() {
+					validate_schema(items_schema, item, &format!("{path}[{index}]"), errors);
+				}
+			}
+		}
+		Value::String(string) => {
+			let length = string.chars().count() as u64;
+
+			if let Some(min_length) = schema.get("minLength").and_then(Value::as_u64)
+				&& length < min_length
+			{
+				errors.push(format!(
+					"{path}: expected at least {min_length} character(s)"
+				));
+			}
+
+			if let Some(max_length) = schema.get("maxLength").and_then(Value::as_u64)
+				&& length > max_length
+			{
+				errors.push(format!(
+					"{path}: expected at most {max_length} character(s)"
+				));
+			}
+
+			if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+				match Regex::new(pattern) {
+					Ok(regex) if !regex.is_match(string) => {
+						errors.push(format!("{path}: \"{string}\" does not match \"{pattern}\""))
+					}
+					Ok(_) => {}
+					Err(error) => errors.push(format!("{path}: invalid pattern, {error}")),
+				}
+			}
+		}
+		Value::Number(number) => {
+			let number = number.as_f64().unwrap_or_default();
+			let bound = |key: &str| schema.get(key).and_then(Value::as_f64);
+
+			if let Some(minimum) = bound("minimum")
+				&& number < minimum
+			{
+				errors.push(format!("{path}: {number} is lower than {minimum}"));
+			}
+
+			if let Some(maximum) = bound("maximum")
+				&& number > maximum
+			{
+				errors.push(format!("{path}: {number} is greater than {maximum}"));
+			}
+
+			if let Some(minimum) = bound("exclusiveMinimum")
+				&& number <= minimum
+			{
+				errors.push(format!("{path}: {number} is not greater than {minimum}"));
+			}
+
+			if let Some(maximum) = bound("exclusiveMaximum")
+				&& number >= maximum
+			{
+				errors.push(format!("{path}: {number} is not lower than {maximum}"));
+			}
+		}
+		_ => {}
+	}
+
+	if let Some(Value::Array(schemas)) = schema.get("allOf") {
+		for sub_schema in schemas {
+			validate_schema(sub_schema, value, path, errors);
+		}
+	}
+
+	if let Some(Value::Array(schemas)) = schema.get("anyOf")
+		&& !schemas.iter().any(|sub_schema| is_valid(sub_schema, value))
+	{
+		errors.push(format!(
+			"{path}: does not match any of the \"anyOf\" schemas"
+		));
+	}
+
+	if let Some(Value::Array(schemas)) = schema.get("oneOf") {
+		let matching = schemas
+			.iter()
+			.filter(|sub_schema| is_valid(sub_schema, value))
+			.count();
+
+		if matching != 1 {
+			errors.push(format!(
+				"{path}: expected exactly one \"oneOf\" schema to match, {matching} did"
+			));
+		}
+	}
+}
+
+fn is_valid(schema: &Value, value: &Value) -> bool {
+	let mut errors = vec![];
+	validate_schema(schema, value, "$", &mut errors);
+	errors.is_empty()
+}
+
+fn type_matches(expected_type: &str, value: &Value) -> bool {
+	match expected_type {
+		"integer" => value.as_i64().is_some() || value.as_u64().is_some(),
+		"number" => value.is_number(),
+		other => type_name(value) == other,
+	}
+}
+
+fn type_name(value: &Value) -> &'static str {
+	match value {
+		Value::Null => "null",
+		Value::Bool(_) => "boolean",
+		Value::Number(_) => "number",
+		Value::String(_) => "string",
+		Value::Array(_) => "array",
+		Value::Object(_) => "object",
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+	use std::str::FromStr;
+
+	fn response(status: &str, body: &str) -> RequestResponse {
+		RequestResponse {
+			status_code: Some(status.to_string()),
+			content: Some(ResponseContent::Body(body.to_string())),
+			headers: vec![(
+				String::from("content-type"),
+				String::from("application/json; charset=utf-8"),
+			)],
+			..Default::default()
+		}
+	}
+
+	fn check(assertion: &str, response: &RequestResponse) -> AssertionResult {
+		let assertion = Assertion::from_str(assertion).unwrap();
+		evaluate_assertions(&[assertion], response, Duration::from_millis(120))
+			.pop()
+			.unwrap()
+	}
+
+	#[test]
+	fn status_assertions() {
+		let response = response("201 Created", "{}");
+
+		assert!(check("status == 201", &response).passed);
+		assert!(check("status in 200..299", &response).passed);
+
+		let failed = check("status == 200", &response);
+		assert!(!failed.passed);
+		assert_eq!(failed.message.unwrap(), "Expected status 200, got 201");
+
+		assert!(!check("status == 200", &self::response("TIMEOUT", "")).passed);
+	}
+
+	#[test]
+	fn header_assertions_are_case_insensitive() {
+		let response = response("200 OK", "{}");
+
+		assert!(check("header Content-Type exists", &response).passed);
+		assert!(check("header content-type matches ^application/json", &response).passed);
+		assert!(!check("header x-missing exists", &response).passed);
+		assert!(!check("header content-type matches xml", &response).passed);
+	}
+
+	#[test]
+	fn jsonpath_assertions() {
+		let response = response("200 OK", r#"{"data": {"id": 42, "tags": ["a", "b"]}}"#);
+
+		assert!(check("jsonpath $.data.id == 42", &response).passed);
+		assert!(check("jsonpath $.data.tags[1] == b", &response).passed);
+		assert!(!check("jsonpath $.data.id == 43", &response).passed);
+
+		let missing = check("jsonpath $.data.name == x", &response);
+		assert_eq!(missing.message.unwrap(), "Nothing found at \"$.data.name\"");
+
+		assert!(!check("jsonpath $.id == 1", &self::response("200 OK", "not json")).passed);
+	}
+
+	#[test]
+	fn duration_assertion() {
+		let response = response("200 OK", "{}");
+
+		assert!(check("duration < 500", &response).passed);
+		assert!(!check("duration < 100", &response).passed);
+	}
+
+	#[test]
+	fn schema_assertion() {
+		let schema = json!({
+			"type": "object",
+			"required": ["id", "name"],
+			"properties": {
+				"id": {"type": "integer", "minimum": 1},
+				"name": {"type": "string", "minLength": 1},
+				"tags": {"type": "array", "items": {"type": "string"}}
+			},
+			"additionalProperties": false
+		});
+
+		let valid = json!({"id": 1, "name": "squrl", "tags": ["cli"]});
+		let mut errors = vec![];
+		validate_schema(&schema, &valid, "$", &mut errors);
+		assert!(errors.is_empty(), "{errors:?}");
+
+		let invalid = json!({"id": 0, "tags": [1], "extra": true});
+		let mut errors = vec![];
+		validate_schema(&schema, &invalid, "$", &mut errors);
+		assert_eq!(errors.len(), 4, "{errors:?}");
+	}
+
+	#[test]
+	fn schema_combinators() {
+		let one_of = json!({"oneOf": [{"type": "string"}, {"type": "integer"}]});
+		assert!(is_valid(&one_of, &json!("a")));
+		assert!(!is_valid(&one_of, &json!(true)));
+
+		let any_of = json!({"anyOf": [{"const": 1}, {"enum": ["a", "b"]}]});
+		assert!(is_valid(&any_of, &json!("b")));
+		assert!(!is_valid(&any_of, &json!(2)));
+	}
+}
@@ -234,6 +234,253 @@ impl App<'_> {
 		);
 	}
 
+	pub fn modify_request_auth_oauth2_token_url(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		token_url: String,
+	) {
+		self.modify_auth_field(
+			collection_index,
+			request_index,
+			"OAuth2 token URL",
+			&token_url,
+			|auth| {
+				if let Auth::OAuth2(oauth2) = auth {
+					oauth2.token_url = token_url.clone();
+				}
+			},
+		);
+	}
+
+	pub fn modify_request_auth_oauth2_auth_url(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		auth_url: String,
+	) {
+		self.modify_auth_field(
+			collection_index,
+			request_index,
+			"OAuth2 authorization URL",
+			&auth_url,
+			|auth| {
+				if let Auth::OAuth2(oauth2) = auth {
+					oauth2.auth_url = auth_url.clone();
+				}
+			},
+		);
+	}
+
+	pub fn modify_request_auth_oauth2_client_id(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		client_id: String,
+	) {
+		self.modify_auth_field(
+			collection_index,
+			request_index,
+			"OAuth2 client ID",
+			&client_id,
+			|auth| {
+				if let Auth::OAuth2(oauth2) = auth {
+					oauth2.client_id = client_id.clone();
+				}
+			},
+		);
+	}
+
+	pub fn modify_request_auth_oauth2_client_secret(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		client_secret: String,
+	) {
+		self.modify_auth_field(
+			collection_index,
+			request_index,
+			"OAuth2 client secret",
+			&client_secret,
+			|auth| {
+				if let Auth::OAuth2(oauth2) = auth {
+					oauth2.client_secret = client_secret.clone();
+				}
+			},
+		);
+	}
+
+	pub fn modify_request_auth_oauth2_scope(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		scope: String,
+	) {
+		self.modify_auth_field(
+			collection_index,
+			request_index,
+			"OAuth2 scope",
+			&scope,
+			|auth| {
+				if let Auth::OAuth2(oauth2) = auth {
+					oauth2.scope = scope.clone();
+				}
+			},
+		);
+	}
+
+	pub fn modify_request_auth_oauth2_username(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		username: String,
+	) {
+		self.modify_auth_field(
+			collection_index,
+			request_index,
+			"OAuth2 username",
+			&username,
+			|auth| {
+				if let Auth::OAuth2(oauth2) = auth {
+					oauth2.username = username.clone();
+				}
+			},
+		);
+	}
+
+	pub fn modify_request_auth_oauth2_password(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		password: String,
+	) {
+		self.modify_auth_field(
+			collection_index,
+			request_index,
+			"OAuth2 password",
+			&password,
+			|auth| {
+				if let Auth::OAuth2(oauth2) = auth {
+					oauth2.password = password.clone();
+				}
+			},
+		);
+	}
+
+	pub fn modify_request_auth_oauth2_refresh_token(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		refresh_token: String,
+	) {
+		self.modify_auth_field(
+			collection_index,
+			request_index,
+			"OAuth2 refresh token",
+			&refresh_token,
+			|auth| {
+				if let Auth::OAuth2(oauth2) = auth {
+					oauth2.refresh_token = refresh_token.clone();
+				}
+			},
+		);
+	}
+
+	pub fn modify_request_auth_aws_sig_v4_access_key_id(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		access_key_id: String,
+	) {
+		self.modify_auth_field(
+			collection_index,
+			request_index,
+			"AWS SigV4 access key ID",
+			&access_key_id,
+			|auth| {
+				if let Auth::AwsSigV4(aws_sig_v4) = auth {
+					aws_sig_v4.access_key_id = access_key_id.clone();
+				}
+			},
+		);
+	}
+
+	pub fn modify_request_auth_aws_sig_v4_secret_access_key(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		secret_access_key: String,
+	) {
+		self.modify_auth_field(
+			collection_index,
+			request_index,
+			"AWS SigV4 secret access key",
+			&secret_access_key,
+			|auth| {
+				if let Auth::AwsSigV4(aws_sig_v4) = auth {
+					aws_sig_v4.secret_access_key = secret_access_key.clone();
+				}
+			},
+		);
+	}
+
+	pub fn modify_request_auth_aws_sig_v4_region(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		region: String,
+	) {
+		self.modify_auth_field(
+			collection_index,
+			request_index,
+			"AWS SigV4 region",
+			&region,
+			|auth| {
+				if let Auth::AwsSigV4(aws_sig_v4) = auth {
+					aws_sig_v4.region = region.clone();
+				}
+			},
+		);
+	}
+
+	pub fn modify_request_auth_aws_sig_v4_service(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		service: String,
+	) {
+		self.modify_auth_field(
+			collection_index,
+			request_index,
+			"AWS SigV4 service",
+			&service,
+			|auth| {
+				if let Auth::AwsSigV4(aws_sig_v4) = auth {
+					aws_sig_v4.service = service.clone();
+				}
+			},
+		);
+	}
+
+	pub fn modify_request_auth_aws_sig_v4_session_token(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		session_token: String,
+	) {
+		self.modify_auth_field(
+			collection_index,
+			request_index,
+			"AWS SigV4 session token",
+			&session_token,
+			|auth| {
+				if let Auth::AwsSigV4(aws_sig_v4) = auth {
+					aws_sig_v4.session_token = session_token.clone();
+				}
+			},
+		);
+	}
+
 	fn modify_auth_field(
 		&mut self,
 		collection_index: usize,
@@ -0,0 +1,255 @@
+//! Request [`Capture`]s, extracting response values into environment variables.
+
+use anyhow::anyhow;
+use regex::Regex;
+use thiserror::Error;
+use tracing::{info, warn};
+
+use crate::app::App;
+use crate::app::files::environment::save_environment_to_file;
+use crate::app::request::assertions::{find_header, response_json};
+use crate::app::request::json_path;
+use crate::models::captures::{Capture, CaptureResult, CaptureSource};
+use crate::models::response::{RequestResponse, ResponseContent};
+
+#[derive(Error, Debug)]
+pub enum CaptureError {
+	#[error("Capture index {0} is out of bounds")]
+	IndexOutOfBounds(usize),
+}
+
+impl App<'_> {
+	pub fn add_request_capture(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		capture: Capture,
+	) -> anyhow::Result<()> {
+		self.with_request_write(collection_index, request_index, |req| {
+			info!("Capture \"{}\" added", capture);
+			req.captures.push(capture);
+		});
+
+		Ok(())
+	}
+
+	pub fn delete_request_capture(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+		index: usize,
+	) -> anyhow::Result<()> {
+		self.with_request_write_result(collection_index, request_index, |req| {
+			if index >= req.captures.len() {
+				return Err(anyhow!(CaptureError::IndexOutOfBounds(index)));
+			}
+
+			let capture = req.captures.remove(index);
+			info!("Capture \"{}\" deleted", capture);
+
+			Ok(())
+		})
+	}
+
+	/// Write the captured values to the collection's selected environment if there is one,
+	/// otherwise to the selected global environment.
+	pub fn apply_captures(
+		&mut self,
+		collection_index: Option<usize>,
+		capture_results: &[CaptureResult],
+	) -> anyhow::Result<()> {
+		let captured_values: Vec<(&String, &String)> = capture_results
+			.iter()
+			.filter_map(|result| result.value.as_ref().map(|value| (&result.key, value)))
+			.collect();
+
+		if captured_values.is_empty() {
+			return Ok(());
+		}
+
+		if let Some(collection_index) = collection_index
+			&& let Some(collection) = self.core.collections.get(collection_index)
+			&& let Some(env_name) = collection.selected_environment.clone()
+		{
+			for (key, value) in captured_values {
+				match self.get_collection_env_value(collection_index, &env_name, key) {
+					Ok(_) => self.set_collection_env_value(
+						collection_index,
+						&env_name,
+						key,
+						value.clone(),
+					)?,
+					Err(_) => self.create_collection_env_value(
+						collection_index,
+						&env_name,
+						key.clone(),
+						value.clone(),
+					)?,
+				}
+			}
+
+			return Ok(());
+		}
+
+		match self.get_selected_env_as_local() {
+			None => warn!("No environment selected, captured values are discarded"),
+			Some(local_env) => {
+				let mut env = local_env.write();
+
+				for (key, value) in captured_values {
+					info!("Key \"{key}\" captured");
+					env.values.insert(key.clone(), value.clone());
+				}
+
+				save_environment_to_file(&env);
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// Evaluate every capture against the response, in order.
+pub fn evaluate_captures(captures: &[Capture], response: &RequestResponse) -> Vec<CaptureResult> {
+	captures
+		.iter()
+		.map(
+			|capture| match evaluate_capture(&capture.source, response) {
+				Ok(value) => CaptureResult {
+					key: capture.key.clone(),
+					value: Some(value),
+					error: None,
+				},
+				Err(error) => CaptureResult {
+					key: capture.key.clone(),
+					value: None,
+					error: Some(error),
+				},
+			},
+		)
+		.collect()
+}
+
+fn evaluate_capture(source: &CaptureSource, response: &RequestResponse) -> Result<String, String> {
+	match source {
+		CaptureSource::JsonPath(path) => {
+			let body = response_json(response)?;
+			let found = json_path::query_first(&body, path).map_err(|e| e.to_string())?;
+
+			match found {
+				// Strings are captured without their quotes
+				Some(serde_json::Value::String(value)) => Ok(value),
+				Some(value) => Ok(value.to_string()),
+				None => Err(format!("Nothing found at \"{path}\"")),
+			}
+		}
+		CaptureSource::Header(name) => find_header(response, name)
+			.map(str::to_string)
+			.ok_or(format!("Header \"{name}\" not found")),
+		CaptureSource::Regex(pattern) => {
+			let body = match &response.content {
+				Some(ResponseContent::Body(body)) => body,
+				Some(ResponseContent::Image(_)) => {
+					return Err(String::from("Response body is an image"));
+				}
+				None => return Err(String::from("No response body")),
+			};
+			let regex = Regex::new(pattern).map_err(|e| e.to_string())?;
+			let captures = regex
+				.captures(body)
+				.ok_or(format!("\"{pattern}\" does not match the response body"))?;
+
+			// First group if there is one, whole match otherwise
+			let found = captures
+				.get(1)
+				.or_else(|| captures.get(0))
+				.expect("a regex match should have a whole match group");
+
+			Ok(found.as_str().to_string())
+		}
+		CaptureSource::Cookie(name) => response
+			.cookies
+			.as_deref()
+			.unwrap_or_default()
+			.lines()
+			.find_map(|line| {
+				let (cookie_name, value) = line.split_once(": ")?;
+				(cookie_name == name).then(|| value.to_string())
+			})
+			.ok_or(format!("Cookie \"{name}\" not found")),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::str::FromStr;
+
+	fn response() -> RequestResponse {
+		RequestResponse {
+			duration: None,
+			status_code: Some(String::from("200 OK")),
+			content: Some(ResponseContent::Body(String::from(
+				r#"{"token": "abc", "user": {"id": 42}, "csrf": "<input value=\"xyz\">"}"#,
+			))),
+			cookies: Some(String::from("theme: dark\nsession_id: s3cr3t")),
+			headers: vec![(String::from("X-Request-Id"), String::from("req-1"))],
+			..Default::default()
+		}
+	}
+
+	fn capture(input: &str) -> Capture {
+		Capture::from_str(input).unwrap()
+	}
+
+	#[test]
+	fn evaluate_all_sources() {
+		let captures = vec![
+			capture("TOKEN = jsonpath $.token"),
+			capture("USER_ID = jsonpath $.user.id"),
+			capture("REQUEST_ID = header x-request-id"),
+			capture(r#"CSRF = regex value=\\"(\w+)\\""#),
+			capture("STATUS = regex \\d+"),
+			capture("SESSION = cookie session_id"),
+		];
+
+		let values: Vec<Option<String>> = evaluate_captures(&captures, &response())
+			.into_iter()
+			.map(|result| result.value)
+			.collect();
+
+		assert_eq!(
+			values,
+			vec![
+				Some(String::from("abc")),
+				Some(String::from("42")),
+				Some(String::from("req-1")),
+				Some(String::from("xyz")),
+				Some(String::from("42")),
+				Some(String::from("s3cr3t")),
+			]
+		);
+	}
+
+	#[test]
+	fn evaluate_missing_values() {
+		let captures = vec![
+			capture("A = jsonpath $.missing"),
+			capture("B = header x-missing"),
+			capture("C = regex nope"),
+			capture("D = cookie missing"),
+		];
+
+		let results = evaluate_captures(&captures, &response());
+
+		assert!(results.iter().all(|result| result.value.is_none()));
+		assert_eq!(
+			results[0].error.as_deref(),
+			Some("Nothing found at \"$.missing\"")
+		);
+		assert_eq!(
+			results[3].error.as_deref(),
+			Some("Cookie \"missing\" not found")
+		);
+	}
+}
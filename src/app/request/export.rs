@@ -6,10 +6,12 @@ use crate::app::request::export::ExportError::{
 use crate::app::request::send::get_file_content_with_name;
 use crate::app::utils::to_train_case;
 use crate::models::auth::auth::Auth;
+use crate::models::auth::aws_sig_v4::{AwsSigV4, AwsSigV4Request, aws_sig_v4_payload_hash};
 use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
 use crate::models::auth::digest::{Digest, digest_to_authorization_header};
 use crate::models::auth::jwt::{JwtToken, jwt_do_jaat};
+use crate::models::auth::oauth2::OAuth2;
 use crate::models::export::ExportFormat;
 use crate::models::export::ExportFormat::{
 	Curl, HTTP, NodeJsAxios, PhpGuzzle, PowerShell, RustReqwest,
@@ -23,7 +25,9 @@ use crate::models::request::Request;
 use anyhow::anyhow;
 use base64::prelude::BASE64_STANDARD;
 use base64::write::EncoderWriter;
+use chrono::Utc;
 use reqwest::Url;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use std::path::PathBuf;
 use thiserror::Error;
 
@@ -72,9 +76,17 @@ impl App<'_> {
 		&self,
 		export_format: &ExportFormat,
 		request: &Request,
+		collection_index: Option<usize>,
 	) -> anyhow::Result<String> {
 		let output = String::new();
 
+		let inheritance = self.request_inheritance(request, collection_index);
+		let request = &Request {
+			auth: inheritance.resolve_auth(&request.auth),
+			headers: inheritance.resolve_headers(&request.headers),
+			..request.clone()
+		};
+
 		let params = self.key_value_vec_to_tuple_vec(&request.params);
 		let url = self.replace_env_keys_by_value(&request.url);
 
@@ -166,7 +178,7 @@ impl App<'_> {
 				output += &format!("\n{}: {}", header, value);
 			}
 
-			output += &self.resolve_auth_header_value(&request.auth, url.as_str());
+			output += &self.resolve_auth_header_value(request, &url);
 
 			let body = self.build_graphql_json_body(gql);
 			output += &format!("\nContent-Length: {}\n\n{}", body.len(), body);
@@ -198,7 +210,7 @@ impl App<'_> {
 
 		/* Auth */
 
-		output += &self.resolve_auth_header_value(&request.auth, url.as_str());
+		output += &self.resolve_auth_header_value(request, &url);
 		/* Body */
 
 		output += &match &http_request.body {
@@ -304,7 +316,7 @@ impl App<'_> {
 		// Handle GraphQL requests
 		if let Protocol::GraphqlRequest(gql) = &request.protocol {
 			output += &format!("curl --location --request POST '{}' \\", url);
-			output += &self.resolve_auth_header_value(&request.auth, url.as_str());
+			output += &self.curl_auth(request, &url);
 
 			for (header, value) in &headers {
 				output += &format!("\n--header '{}: {}' \\", header, value);
@@ -336,7 +348,7 @@ impl App<'_> {
 
 		/* Auth */
 
-		output += &self.resolve_auth_header_value(&request.auth, url.as_str());
+		output += &self.curl_auth(request, &url);
 
 		/* Headers */
 
@@ -445,7 +457,7 @@ impl App<'_> {
 
 		/* Auth */
 
-		headers_str += &self.resolve_auth_header_value(&request.auth, url.as_str());
+		headers_str += &self.resolve_auth_header_value(request, &url);
 
 		/* Headers */
 
@@ -620,7 +632,7 @@ impl App<'_> {
 		output += "  headers: { \n";
 
 		/* Auth */
-		output += &self.resolve_auth_header_value(&request.auth, url.as_str());
+		output += &self.resolve_auth_header_value(request, &url);
 
 		/* Regular Headers */
 		for (header, value) in &headers {
@@ -734,9 +746,102 @@ impl App<'_> {
 		Ok(output)
 	}
 
-	fn resolve_auth_header_value(&self, auth: &Auth, url_path: &str) -> String {
-		match auth {
-			Auth::NoAuth => String::new(),
+	/// curl signs AWS SigV4 requests itself, other auth methods are sent as headers
+	fn curl_auth(&self, request: &Request, url: &Url) -> String {
+		let Auth::AwsSigV4(aws_sig_v4) = &request.auth else {
+			return self.resolve_auth_header_value(request, url);
+		};
+
+		let region = self.replace_env_keys_by_value(&aws_sig_v4.region);
+		let service = self.replace_env_keys_by_value(&aws_sig_v4.service);
+		let access_key_id = self.replace_env_keys_by_value(&aws_sig_v4.access_key_id);
+		let secret_access_key = self.replace_env_keys_by_value(&aws_sig_v4.secret_access_key);
+		let session_token = self.replace_env_keys_by_value(&aws_sig_v4.session_token);
+
+		let mut output = format!(
+			"\n--aws-sigv4 'aws:amz:{}:{}' \\\n--user '{}:{}' \\",
+			escape(region, '\''),
+			escape(service, '\''),
+			escape(access_key_id, '\''),
+			escape(secret_access_key, '\'')
+		);
+
+		if !session_token.is_empty() {
+			output += &format!(
+				"\n--header 'X-Amz-Security-Token: {}' \\",
+				escape(session_token, '\'')
+			);
+		}
+
+		output
+	}
+
+	/// AWS SigV4 headers signed at export time, they are only valid for a few minutes
+	fn aws_sig_v4_export_headers(
+		&self,
+		aws_sig_v4: &AwsSigV4,
+		request: &Request,
+		url: &Url,
+	) -> Vec<(String, String)> {
+		let aws_sig_v4 = AwsSigV4 {
+			access_key_id: self.replace_env_keys_by_value(&aws_sig_v4.access_key_id),
+			secret_access_key: self.replace_env_keys_by_value(&aws_sig_v4.secret_access_key),
+			region: self.replace_env_keys_by_value(&aws_sig_v4.region),
+			service: self.replace_env_keys_by_value(&aws_sig_v4.service),
+			session_token: self.replace_env_keys_by_value(&aws_sig_v4.session_token),
+		};
+
+		let (method, body) = match &request.protocol {
+			Protocol::HttpRequest(http_request) => {
+				let body = match &http_request.body {
+					NoBody => Some(String::new()),
+					Raw(body) | Json(body) | Xml(body) | Html(body) | Javascript(body) => {
+						Some(self.replace_env_keys_by_value(body))
+					}
+					File(_) | Form(_) | Multipart(_) => None,
+				};
+
+				(http_request.method.to_string(), body)
+			}
+			Protocol::GraphqlRequest(gql) => (
+				Method::POST.to_string(),
+				Some(self.build_graphql_json_body(gql)),
+			),
+			Protocol::WsRequest(_) => (Method::GET.to_string(), Some(String::new())),
+			Protocol::GrpcRequest(_) => (Method::POST.to_string(), None),
+		};
+
+		let mut headers = HeaderMap::new();
+
+		for (header, value) in self.key_value_vec_to_tuple_vec(&request.headers) {
+			if let (Ok(header), Ok(value)) = (
+				HeaderName::from_bytes(header.as_bytes()),
+				HeaderValue::from_str(&value),
+			) {
+				headers.append(header, value);
+			}
+		}
+
+		aws_sig_v4
+			.signed_headers(
+				&AwsSigV4Request {
+					method: &method,
+					url,
+					headers: &headers,
+					payload_hash: &aws_sig_v4_payload_hash(body.as_ref().map(String::as_bytes)),
+				},
+				Utc::now(),
+			)
+			.into_iter()
+			.map(|(header, value)| (to_train_case(&header), value))
+			.collect()
+	}
+
+	fn resolve_auth_header_value(&self, request: &Request, url: &Url) -> String {
+		let url_path = url.as_str();
+
+		match &request.auth {
+			Auth::NoAuth | Auth::Inherit => String::new(),
 			Auth::BasicAuth(BasicAuth { username, password }) => {
 				let username = self.replace_env_keys_by_value(username);
 				let password = self.replace_env_keys_by_value(password);
@@ -800,6 +905,16 @@ impl App<'_> {
 
 				format!("\nAuthorization: {}", digest_header)
 			}
+			// Only the last token obtained can be exported
+			Auth::OAuth2(OAuth2 { token, .. }) => match token {
+				Some(token) => format!("\nAuthorization: {}", token.authorization_header()),
+				None => String::new(),
+			},
+			Auth::AwsSigV4(aws_sig_v4) => self
+				.aws_sig_v4_export_headers(aws_sig_v4, request, url)
+				.iter()
+				.map(|(header, value)| format!("\n{header}: {value}"))
+				.collect(),
 		}
 	}
 
@@ -834,7 +949,7 @@ impl App<'_> {
 
 		/* Auth */
 		match &request.auth {
-			Auth::NoAuth => {}
+			Auth::NoAuth | Auth::Inherit => {}
 			Auth::BasicAuth(BasicAuth { username, password }) => {
 				let username = self.replace_env_keys_by_value(username);
 				let password = self.replace_env_keys_by_value(password);
@@ -902,6 +1017,26 @@ impl App<'_> {
 					escape(digest_header, escape_char)
 				);
 			}
+			Auth::OAuth2(OAuth2 {
+				token: Some(token), ..
+			}) => {
+				has_headers = true;
+				headers_str += &format!(
+					"        .header(\"Authorization\", \"{}\")\n",
+					escape(token.authorization_header(), escape_char)
+				);
+			}
+			Auth::OAuth2(_) => {}
+			Auth::AwsSigV4(aws_sig_v4) => {
+				for (header, value) in self.aws_sig_v4_export_headers(aws_sig_v4, request, &url) {
+					has_headers = true;
+					headers_str += &format!(
+						"        .header(\"{}\", \"{}\")\n",
+						escape(header, escape_char),
+						escape(value, escape_char)
+					);
+				}
+			}
 		};
 
 		/* Imports */
@@ -1129,7 +1264,7 @@ impl App<'_> {
 
 		/* Headers */
 
-		let auth_header = self.resolve_auth_header_value(&request.auth, url.as_str());
+		let auth_header = self.resolve_auth_header_value(request, &url);
 		let has_headers = !headers.is_empty() || !auth_header.is_empty();
 
 		if has_headers {
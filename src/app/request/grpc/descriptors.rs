@@ -0,0 +1,133 @@
+use std::path::Path;
+use std::time::Duration;
+
+use prost_reflect::{DescriptorPool, MethodDescriptor};
+
+use crate::app::request::grpc::reflection::fetch_reflection_pool;
+use crate::app::request::grpc::send::grpc_client;
+use crate::models::protocol::grpc::grpc::GrpcRequest;
+use crate::models::settings::TlsSettings;
+
+/// Parse a `.proto` file (with optional import paths) into a `DescriptorPool`.
+pub fn parse_proto_file(
+	proto_file: &str,
+	import_paths: &[String],
+) -> anyhow::Result<DescriptorPool> {
+	let proto_path = Path::new(proto_file);
+
+	// Determine include directories: the proto file's parent dir + any user-specified import paths
+	let mut includes: Vec<&Path> = Vec::new();
+
+	if let Some(parent) = proto_path.parent() {
+		if parent.as_os_str().is_empty() {
+			includes.push(Path::new("."));
+		} else {
+			includes.push(parent);
+		}
+	} else {
+		includes.push(Path::new("."));
+	}
+
+	for import_path in import_paths {
+		includes.push(Path::new(import_path));
+	}
+
+	let file_name = proto_path
+		.file_name()
+		.ok_or_else(|| anyhow::anyhow!("Invalid proto file path"))?
+		.to_str()
+		.ok_or_else(|| anyhow::anyhow!("Proto file path is not valid UTF-8"))?;
+
+	let file_descriptor_set = protox::Compiler::new(includes)?
+		.include_imports(true)
+		.open_file(file_name)?
+		.file_descriptor_set();
+
+	let pool = DescriptorPool::from_file_descriptor_set(file_descriptor_set)?;
+
+	Ok(pool)
+}
+
+/// Resolve a service and method from the descriptor pool.
+pub fn resolve_method(
+	pool: &DescriptorPool,
+	service_name: &str,
+	method_name: &str,
+) -> anyhow::Result<MethodDescriptor> {
+	let service = pool
+		.get_service_by_name(service_name)
+		.ok_or_else(|| anyhow::anyhow!("Service '{}' not found in proto file", service_name))?;
+
+	let method = service
+		.methods()
+		.find(|m| m.name() == method_name)
+		.ok_or_else(|| {
+			anyhow::anyhow!(
+				"Method '{}' not found in service '{}'",
+				method_name,
+				service_name
+			)
+		})?;
+
+	Ok(method)
+}
+
+/// Descriptors of a request, from its `.proto` file or the server reflection service at `url`.
+pub async fn load_descriptor_pool(
+	grpc_request: &GrpcRequest,
+	url: &str,
+	headers: &[(String, String)],
+	tls: &TlsSettings,
+	timeout: Duration,
+) -> anyhow::Result<DescriptorPool> {
+	match grpc_request.reflection {
+		true => {
+			let client = grpc_client(tls, Some(timeout))?;
+
+			fetch_reflection_pool(client, url, headers).await
+		}
+		false => parse_proto_file(&grpc_request.proto_file, &grpc_request.import_paths),
+	}
+}
+
+/// A method exposed by a descriptor pool, as listed by method pickers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrpcMethodInfo {
+	pub service: String,
+	pub method: String,
+	pub client_streaming: bool,
+	pub server_streaming: bool,
+}
+
+impl GrpcMethodInfo {
+	pub fn kind(&self) -> &'static str {
+		match (self.client_streaming, self.server_streaming) {
+			(false, false) => "unary",
+			(false, true) => "server streaming",
+			(true, false) => "client streaming",
+			(true, true) => "bidi streaming",
+		}
+	}
+}
+
+/// Every method of every service in the pool, sorted by service then method name
+pub fn list_grpc_methods(pool: &DescriptorPool) -> Vec<GrpcMethodInfo> {
+	let mut methods: Vec<GrpcMethodInfo> = pool
+		.services()
+		.flat_map(|service| {
+			service
+				.methods()
+				.map(|method| GrpcMethodInfo {
+					service: service.full_name().to_string(),
+					method: method.name().to_string(),
+					client_streaming: method.is_client_streaming(),
+					server_streaming: method.is_server_streaming(),
+				})
+				.collect::<Vec<GrpcMethodInfo>>()
+		})
+		.collect();
+
+	methods.sort_by(|a, b| (&a.service, &a.method).cmp(&(&b.service, &b.method)));
+
+	methods
+}
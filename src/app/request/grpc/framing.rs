@@ -0,0 +1,201 @@
+use std::io::{Read, Write};
+
+use anyhow::anyhow;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+
+use crate::models::protocol::grpc::grpc::GrpcCompression;
+
+/// Encodings accepted for response messages, sent as `grpc-accept-encoding`
+pub const GRPC_ACCEPT_ENCODING: &str = "gzip,identity";
+
+/// Frame a protobuf message with the gRPC Length-Prefixed-Message framing.
+/// Format: 1 byte compressed flag + 4 bytes big-endian message length + message bytes.
+pub fn grpc_frame(message: &[u8], compression: GrpcCompression) -> anyhow::Result<Bytes> {
+	let (compressed, message) = match compression {
+		GrpcCompression::Identity => (0, message.to_vec()),
+		GrpcCompression::Gzip => {
+			let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+			encoder.write_all(message)?;
+			(1, encoder.finish()?)
+		}
+	};
+
+	let mut buf = BytesMut::with_capacity(5 + message.len());
+	buf.put_u8(compressed);
+	buf.put_u32(message.len() as u32);
+	buf.put_slice(&message);
+	Ok(buf.freeze())
+}
+
+/// Incremental decoder of gRPC Length-Prefixed-Messages, a message may be split
+/// across several body chunks and a chunk may hold several messages.
+#[derive(Default)]
+pub struct GrpcFrameDecoder {
+	buffer: BytesMut,
+	/// `grpc-encoding` of the response, applies to the messages flagged as compressed
+	encoding: Option<String>,
+}
+
+impl GrpcFrameDecoder {
+	pub fn with_encoding(encoding: Option<String>) -> GrpcFrameDecoder {
+		GrpcFrameDecoder {
+			buffer: BytesMut::new(),
+			encoding,
+		}
+	}
+
+	pub fn push(&mut self, chunk: &[u8]) {
+		self.buffer.extend_from_slice(chunk);
+	}
+
+	/// Next complete message, decompressed, `None` until all of its bytes were pushed
+	pub fn next_message(&mut self) -> Option<anyhow::Result<Bytes>> {
+		if self.buffer.len() < 5 {
+			return None;
+		}
+
+		let length = u32::from_be_bytes([
+			self.buffer[1],
+			self.buffer[2],
+			self.buffer[3],
+			self.buffer[4],
+		]) as usize;
+
+		if self.buffer.len() < 5 + length {
+			return None;
+		}
+
+		let compressed = self.buffer.get_u8() == 1;
+		self.buffer.advance(4);
+		let message = self.buffer.split_to(length).freeze();
+
+		match compressed {
+			false => Some(Ok(message)),
+			true => Some(self.decompress(&message)),
+		}
+	}
+
+	fn decompress(&self, message: &[u8]) -> anyhow::Result<Bytes> {
+		let encoding = self.encoding.as_deref().unwrap_or("identity");
+
+		match GrpcCompression::from_encoding(encoding) {
+			Some(GrpcCompression::Gzip) => {
+				let mut decompressed = Vec::new();
+				GzDecoder::new(message).read_to_end(&mut decompressed)?;
+				Ok(Bytes::from(decompressed))
+			}
+			Some(GrpcCompression::Identity) => Err(anyhow!(
+				"Message flagged as compressed without a grpc-encoding"
+			)),
+			None => Err(anyhow!("Unsupported grpc-encoding \"{encoding}\"")),
+		}
+	}
+}
+
+/// `grpc-timeout` header value, at most 8 digits followed by the unit
+pub fn grpc_timeout_header(timeout_ms: u64) -> String {
+	match timeout_ms {
+		0..=99_999_999 => format!("{timeout_ms}m"),
+		_ => format!("{}S", (timeout_ms / 1000).min(99_999_999)),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn identity_frame(message: &[u8]) -> Bytes {
+		grpc_frame(message, GrpcCompression::Identity).unwrap()
+	}
+
+	fn next_message(decoder: &mut GrpcFrameDecoder) -> Option<Bytes> {
+		decoder.next_message().map(|message| message.unwrap())
+	}
+
+	#[test]
+	fn decoder_waits_for_complete_frames() {
+		let mut decoder = GrpcFrameDecoder::default();
+		let frame = identity_frame(b"hello");
+
+		decoder.push(&frame[..3]);
+		assert_eq!(next_message(&mut decoder), None);
+
+		decoder.push(&frame[3..7]);
+		assert_eq!(next_message(&mut decoder), None);
+
+		decoder.push(&frame[7..]);
+		assert_eq!(
+			next_message(&mut decoder),
+			Some(Bytes::from_static(b"hello"))
+		);
+		assert_eq!(next_message(&mut decoder), None);
+	}
+
+	#[test]
+	fn decoder_splits_chunk_with_several_frames() {
+		let mut decoder = GrpcFrameDecoder::default();
+
+		let mut chunk = BytesMut::new();
+		chunk.extend_from_slice(&identity_frame(b"first"));
+		chunk.extend_from_slice(&identity_frame(b""));
+		chunk.extend_from_slice(&identity_frame(b"third"));
+
+		decoder.push(&chunk[..chunk.len() - 2]);
+
+		assert_eq!(
+			next_message(&mut decoder),
+			Some(Bytes::from_static(b"first"))
+		);
+		assert_eq!(next_message(&mut decoder), Some(Bytes::new()));
+		assert_eq!(next_message(&mut decoder), None);
+
+		decoder.push(&chunk[chunk.len() - 2..]);
+		assert_eq!(
+			next_message(&mut decoder),
+			Some(Bytes::from_static(b"third"))
+		);
+	}
+
+	#[test]
+	fn gzip_frames_round_trip() {
+		let frame = grpc_frame(b"hello hello hello", GrpcCompression::Gzip).unwrap();
+		assert_eq!(frame[0], 1);
+
+		let mut decoder = GrpcFrameDecoder::with_encoding(Some(String::from("gzip")));
+		decoder.push(&frame);
+		decoder.push(&identity_frame(b"plain"));
+
+		assert_eq!(
+			next_message(&mut decoder),
+			Some(Bytes::from_static(b"hello hello hello"))
+		);
+		assert_eq!(
+			next_message(&mut decoder),
+			Some(Bytes::from_static(b"plain"))
+		);
+	}
+
+	#[test]
+	fn compressed_frame_needs_a_supported_encoding() {
+		let frame = grpc_frame(b"hello", GrpcCompression::Gzip).unwrap();
+
+		let mut decoder = GrpcFrameDecoder::default();
+		decoder.push(&frame);
+		assert!(decoder.next_message().unwrap().is_err());
+
+		let mut decoder = GrpcFrameDecoder::with_encoding(Some(String::from("snappy")));
+		decoder.push(&frame);
+		let error = decoder.next_message().unwrap().unwrap_err();
+		assert_eq!(error.to_string(), "Unsupported grpc-encoding \"snappy\"");
+	}
+
+	#[test]
+	fn timeout_header() {
+		assert_eq!(grpc_timeout_header(30000), "30000m");
+		assert_eq!(grpc_timeout_header(99_999_999), "99999999m");
+		assert_eq!(grpc_timeout_header(100_000_000), "100000S");
+	}
+}
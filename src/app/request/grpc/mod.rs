@@ -1 +1,5 @@
+pub(crate) mod descriptors;
+pub(crate) mod framing;
+pub(crate) mod reflection;
 pub(crate) mod send;
+pub(crate) mod status;
@@ -0,0 +1,254 @@
+use std::collections::{BTreeSet, HashMap};
+
+use anyhow::{Context, anyhow};
+use prost::Message;
+use prost_reflect::DescriptorPool;
+use prost_types::FileDescriptorProto;
+use tracing::{info, trace};
+
+use crate::app::request::grpc::framing::{GrpcFrameDecoder, grpc_frame};
+use crate::models::protocol::grpc::grpc::GrpcCompression;
+
+/// Reflection service versions, in the order they are tried
+const REFLECTION_SERVICES: [&str; 2] = [
+	"grpc.reflection.v1.ServerReflection",
+	"grpc.reflection.v1alpha.ServerReflection",
+];
+
+/// `ServerReflectionRequest`, identical in `grpc.reflection.v1` and `v1alpha`
+#[derive(Clone, PartialEq, Message)]
+struct ServerReflectionRequest {
+	#[prost(string, tag = "1")]
+	host: String,
+	#[prost(oneof = "MessageRequest", tags = "3, 4, 7")]
+	message_request: Option<MessageRequest>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+enum MessageRequest {
+	#[prost(string, tag = "3")]
+	FileByFilename(String),
+	#[prost(string, tag = "4")]
+	FileContainingSymbol(String),
+	#[prost(string, tag = "7")]
+	ListServices(String),
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ServerReflectionResponse {
+	#[prost(oneof = "MessageResponse", tags = "4, 6, 7")]
+	message_response: Option<MessageResponse>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+enum MessageResponse {
+	#[prost(message, tag = "4")]
+	FileDescriptors(FileDescriptorResponse),
+	#[prost(message, tag = "6")]
+	Services(ListServiceResponse),
+	#[prost(message, tag = "7")]
+	Error(ErrorResponse),
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct FileDescriptorResponse {
+	#[prost(bytes = "vec", repeated, tag = "1")]
+	file_descriptor_proto: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ListServiceResponse {
+	#[prost(message, repeated, tag = "1")]
+	service: Vec<ServiceResponse>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ServiceResponse {
+	#[prost(string, tag = "1")]
+	name: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ErrorResponse {
+	#[prost(int32, tag = "1")]
+	error_code: i32,
+	#[prost(string, tag = "2")]
+	error_message: String,
+}
+
+struct ReflectionClient<'a> {
+	client: reqwest::Client,
+	url: &'a str,
+	headers: &'a [(String, String)],
+	service: &'static str,
+}
+
+impl ReflectionClient<'_> {
+	/// Send one reflection request as its own call and decode the first reply
+	async fn call(&self, message_request: MessageRequest) -> anyhow::Result<MessageResponse> {
+		let request = ServerReflectionRequest {
+			host: String::new(),
+			message_request: Some(message_request),
+		};
+
+		let mut request_builder = self
+			.client
+			.post(format!(
+				"{}/{}/ServerReflectionInfo",
+				self.url.trim_end_matches('/'),
+				self.service
+			))
+			.header("content-type", "application/grpc")
+			.header("te", "trailers");
+
+		for (key, value) in self.headers {
+			request_builder = request_builder.header(key.as_str(), value.as_str());
+		}
+
+		let response = request_builder
+			.body(grpc_frame(
+				&request.encode_to_vec(),
+				GrpcCompression::Identity,
+			)?)
+			.send()
+			.await?;
+
+		let status = response.status();
+		let grpc_status = response
+			.headers()
+			.get("grpc-status")
+			.and_then(|value| value.to_str().ok())
+			.map(String::from);
+
+		let mut decoder = GrpcFrameDecoder::default();
+		decoder.push(&response.bytes().await?);
+
+		let Some(message) = decoder.next_message().transpose()? else {
+			return Err(anyhow!(
+				"{} replied without a message (HTTP {status}, gRPC status {})",
+				self.service,
+				grpc_status.as_deref().unwrap_or("-")
+			));
+		};
+
+		match ServerReflectionResponse::decode(message)?.message_response {
+			Some(MessageResponse::Error(error)) => Err(anyhow!(
+				"Reflection error {}: {}",
+				error.error_code,
+				error.error_message
+			)),
+			Some(message_response) => Ok(message_response),
+			None => Err(anyhow!("Empty reflection response")),
+		}
+	}
+
+	async fn file_descriptors(
+		&self,
+		message_request: MessageRequest,
+	) -> anyhow::Result<Vec<FileDescriptorProto>> {
+		match self.call(message_request).await? {
+			MessageResponse::FileDescriptors(response) => response
+				.file_descriptor_proto
+				.iter()
+				.map(|bytes| Ok(FileDescriptorProto::decode(bytes.as_slice())?))
+				.collect(),
+			_ => Err(anyhow!("Unexpected reflection response")),
+		}
+	}
+}
+
+/// Build a `DescriptorPool` of every service exposed by the reflection service
+/// at `url`, `grpc.reflection.v1` is tried first then `v1alpha`.
+pub async fn fetch_reflection_pool(
+	client: reqwest::Client,
+	url: &str,
+	headers: &[(String, String)],
+) -> anyhow::Result<DescriptorPool> {
+	let mut last_error = None;
+
+	for service in REFLECTION_SERVICES {
+		let reflection_client = ReflectionClient {
+			client: client.clone(),
+			url,
+			headers,
+			service,
+		};
+
+		let services = match reflection_client
+			.call(MessageRequest::ListServices(String::new()))
+			.await
+		{
+			Ok(MessageResponse::Services(response)) => response.service,
+			Ok(_) => return Err(anyhow!("Unexpected reflection response")),
+			Err(error) => {
+				trace!("{service} unavailable: {error}");
+				last_error = Some(error);
+				continue;
+			}
+		};
+
+		info!("Using {service}, {} service(s) listed", services.len());
+
+		return build_pool(&reflection_client, services).await;
+	}
+
+	Err(last_error
+		.unwrap_or_else(|| anyhow!("No reflection service"))
+		.context("Server reflection is not available"))
+}
+
+async fn build_pool(
+	reflection_client: &ReflectionClient<'_>,
+	services: Vec<ServiceResponse>,
+) -> anyhow::Result<DescriptorPool> {
+	let mut files: HashMap<String, FileDescriptorProto> = HashMap::new();
+
+	// The reflection service itself isn't something to call
+	for service in services
+		.iter()
+		.filter(|service| !service.name.starts_with("grpc.reflection."))
+	{
+		let descriptors = reflection_client
+			.file_descriptors(MessageRequest::FileContainingSymbol(service.name.clone()))
+			.await
+			.with_context(|| format!("Could not resolve service \"{}\"", service.name))?;
+
+		for descriptor in descriptors {
+			files.insert(descriptor.name().to_string(), descriptor);
+		}
+	}
+
+	// Servers usually send the transitive dependencies along, fetch the missing ones
+	loop {
+		let missing: BTreeSet<String> = files
+			.values()
+			.flat_map(|file| file.dependency.iter())
+			.filter(|dependency| !files.contains_key(*dependency))
+			.cloned()
+			.collect();
+
+		if missing.is_empty() {
+			break;
+		}
+
+		for file_name in missing {
+			let descriptors = reflection_client
+				.file_descriptors(MessageRequest::FileByFilename(file_name.clone()))
+				.await
+				.with_context(|| format!("Could not resolve file \"{file_name}\""))?;
+
+			if !descriptors.iter().any(|file| file.name() == file_name) {
+				return Err(anyhow!("Server did not send file \"{file_name}\""));
+			}
+
+			for descriptor in descriptors {
+				files.insert(descriptor.name().to_string(), descriptor);
+			}
+		}
+	}
+
+	let mut pool = DescriptorPool::new();
+	pool.add_file_descriptor_protos(files.into_values())?;
+
+	Ok(pool)
+}
@@ -1,81 +1,45 @@
-use parking_lot::RwLock;
-use std::path::Path;
+use anyhow::Context;
+use chrono::Local;
+use http_body_util::BodyExt;
+use parking_lot::{Mutex, RwLock};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use bytes::{Buf, BufMut, Bytes, BytesMut};
-use prost::Message;
-use prost_reflect::{DescriptorPool, DynamicMessage, MethodDescriptor};
+use bytes::{Bytes, BytesMut};
+use prost::Message as ProstMessage;
+use prost_reflect::{DynamicMessage, MethodDescriptor};
+use thiserror::Error;
+use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info, trace};
 
 use crate::app::App;
+use crate::app::request::assertions::evaluate_assertions;
+use crate::app::request::captures::evaluate_captures;
+use crate::app::request::grpc::descriptors::{load_descriptor_pool, resolve_method};
+use crate::app::request::grpc::framing::{
+	GRPC_ACCEPT_ENCODING, GrpcFrameDecoder, grpc_frame, grpc_timeout_header,
+};
+use crate::app::request::grpc::status::parse_grpc_status;
 use crate::app::request::send::RequestResponseError;
 use crate::app::request::send::RequestResponseError::CouldNotDecodeResponse;
+use crate::app::request::tls::rustls_client_config;
 use crate::models::environment::Environment;
-use crate::models::protocol::grpc::grpc::GrpcRequest;
+use crate::models::protocol::grpc::grpc::{GrpcCall, GrpcRequest};
+use crate::models::protocol::grpc::status::GrpcStatus;
+use crate::models::protocol::ws::message_type::MessageType;
+use crate::models::protocol::ws::ws::{Message, Sender};
 use crate::models::request::Request;
 use crate::models::response::{RequestResponse, ResponseContent};
-
-/// Parse a `.proto` file (with optional import paths) into a `DescriptorPool`.
-fn parse_proto_file(proto_file: &str, import_paths: &[String]) -> anyhow::Result<DescriptorPool> {
-	let proto_path = Path::new(proto_file);
-
-	// Determine include directories: the proto file's parent dir + any user-specified import paths
-	let mut includes: Vec<&Path> = Vec::new();
-
-	if let Some(parent) = proto_path.parent() {
-		if parent.as_os_str().is_empty() {
-			includes.push(Path::new("."));
-		} else {
-			includes.push(parent);
-		}
-	} else {
-		includes.push(Path::new("."));
-	}
-
-	for import_path in import_paths {
-		includes.push(Path::new(import_path));
-	}
-
-	let file_name = proto_path
-		.file_name()
-		.ok_or_else(|| anyhow::anyhow!("Invalid proto file path"))?
-		.to_str()
-		.ok_or_else(|| anyhow::anyhow!("Proto file path is not valid UTF-8"))?;
-
-	let file_descriptor_set = protox::Compiler::new(includes)?
-		.include_imports(true)
-		.open_file(file_name)?
-		.file_descriptor_set();
-
-	let pool = DescriptorPool::from_file_descriptor_set(file_descriptor_set)?;
-
-	Ok(pool)
-}
-
-/// Resolve a service and method from the descriptor pool.
-fn resolve_method(
-	pool: &DescriptorPool,
-	service_name: &str,
-	method_name: &str,
-) -> anyhow::Result<MethodDescriptor> {
-	let service = pool
-		.get_service_by_name(service_name)
-		.ok_or_else(|| anyhow::anyhow!("Service '{}' not found in proto file", service_name))?;
-
-	let method = service
-		.methods()
-		.find(|m| m.name() == method_name)
-		.ok_or_else(|| {
-			anyhow::anyhow!(
-				"Method '{}' not found in service '{}'",
-				method_name,
-				service_name
-			)
-		})?;
-
-	Ok(method)
+use crate::models::settings::TlsSettings;
+
+/// Errors raised before a gRPC call is made, the details are shown as the response body
+#[derive(Error, Debug)]
+pub enum GrpcRequestError {
+	#[error("COULD NOT LOAD gRPC DESCRIPTORS")]
+	Descriptors,
+	#[error("COULD NOT ENCODE gRPC MESSAGE")]
+	EncodeMessage,
 }
 
 /// Encode a JSON string into a protobuf `DynamicMessage` using the method's input type descriptor.
@@ -104,52 +68,170 @@ fn protobuf_to_json(method: &MethodDescriptor, protobuf_bytes: &[u8]) -> anyhow:
 	Ok(String::from_utf8(json_bytes)?)
 }
 
-/// Frame a protobuf message with the gRPC Length-Prefixed-Message framing.
-/// Format: 1 byte compressed flag (0) + 4 bytes big-endian message length + message bytes.
-fn grpc_frame(message: &[u8]) -> Bytes {
-	let mut buf = BytesMut::with_capacity(5 + message.len());
-	buf.put_u8(0); // compression flag: not compressed
-	buf.put_u32(message.len() as u32);
-	buf.put_slice(message);
-	buf.freeze()
+/// Encode a JSON message and queue it on the request stream of the open call
+pub fn send_grpc_stream_message(
+	grpc_request: &mut GrpcRequest,
+	json_message: &str,
+) -> anyhow::Result<()> {
+	let call = grpc_request.call.as_ref().context("No open gRPC call")?;
+	let tx = call.tx.as_ref().context("Request stream is closed")?;
+
+	let encoded_message = json_to_protobuf(&call.method, json_message)?;
+	tx.send(grpc_frame(&encoded_message, call.compression)?)?;
+
+	grpc_request.messages.push(Message {
+		timestamp: Local::now(),
+		sender: Sender::You,
+		content: MessageType::Text(json_message.to_string()),
+	});
+
+	Ok(())
 }
 
-/// Extract the protobuf message bytes from a gRPC Length-Prefixed-Message frame.
-fn grpc_unframe(mut data: Bytes) -> anyhow::Result<Bytes> {
-	if data.len() < 5 {
-		return Err(anyhow::anyhow!(
-			"gRPC response too short ({} bytes)",
-			data.len()
-		));
+/// Close the request stream of the open call, the server can keep on streaming its response
+pub fn half_close_grpc_stream(grpc_request: &mut GrpcRequest) {
+	let Some(call) = &mut grpc_request.call else {
+		return;
+	};
+
+	if call.tx.take().is_some() {
+		grpc_request.messages.push(Message {
+			timestamp: Local::now(),
+			sender: Sender::You,
+			content: MessageType::Close(String::from("Half-closed")),
+		});
 	}
+}
 
-	let _compressed = data.get_u8();
-	let length = data.get_u32() as usize;
+fn header_value(headers: &[(String, String)], name: &str) -> Option<String> {
+	headers
+		.iter()
+		.find(|(key, _)| key == name)
+		.map(|(_, value)| value.clone())
+}
 
-	if data.len() < length {
-		return Err(anyhow::anyhow!(
-			"gRPC response frame length mismatch: expected {} bytes, got {}",
-			length,
-			data.len()
-		));
+fn metadata_to_vec(metadata: &http::HeaderMap) -> Vec<(String, String)> {
+	metadata
+		.iter()
+		.map(|(name, value)| (name.to_string(), value.to_str().unwrap_or("").to_string()))
+		.collect()
+}
+
+fn stream_close_reason(grpc_status: Option<&GrpcStatus>) -> String {
+	match grpc_status {
+		Some(status) => format!("Stream closed, gRPC status {status}"),
+		None => String::from("Stream closed"),
+	}
+}
+
+/// POST request of a call, `timeout_ms` is sent as the call deadline
+fn grpc_request_builder(
+	client: &reqwest::Client,
+	url: &str,
+	grpc_request: &GrpcRequest,
+	headers: &[(String, String)],
+	timeout_ms: Option<u64>,
+) -> reqwest::RequestBuilder {
+	let grpc_path = format!(
+		"{}/{}/{}",
+		url.trim_end_matches('/'),
+		grpc_request.service,
+		grpc_request.method
+	);
+
+	let mut request_builder = client
+		.post(&grpc_path)
+		.header("content-type", "application/grpc")
+		.header("te", "trailers")
+		.header("grpc-accept-encoding", GRPC_ACCEPT_ENCODING);
+
+	if !grpc_request.compression.is_identity() {
+		request_builder =
+			request_builder.header("grpc-encoding", grpc_request.compression.to_string());
+	}
+
+	if let Some(timeout_ms) = timeout_ms {
+		request_builder = request_builder.header("grpc-timeout", grpc_timeout_header(timeout_ms));
+	}
+
+	for (key, value) in headers {
+		request_builder = request_builder.header(key.as_str(), value.as_str());
+	}
+
+	request_builder
+}
+
+/// Response error shown when the call could not be made
+fn request_error_response(
+	error: GrpcRequestError,
+	details: &anyhow::Error,
+	request_start: Instant,
+) -> RequestResponse {
+	RequestResponse {
+		duration: Some(format!("{:?}", request_start.elapsed())),
+		status_code: Some(error.to_string()),
+		content: Some(ResponseContent::Body(format!("{details:#}"))),
+		..Default::default()
 	}
+}
 
-	Ok(data.slice(..length))
+/// Read a whole unary response body, along with its trailers
+async fn read_grpc_body(
+	response: reqwest::Response,
+) -> Result<(Bytes, Vec<(String, String)>), reqwest::Error> {
+	let mut body = http::Response::from(response).into_body();
+	let mut data = BytesMut::new();
+	let mut trailers = vec![];
+
+	while let Some(frame) = body.frame().await {
+		let frame = frame?;
+
+		match frame.into_data() {
+			Ok(chunk) => data.extend_from_slice(&chunk),
+			Err(frame) => {
+				if let Some(frame_trailers) = frame.trailers_ref() {
+					trailers.extend(metadata_to_vec(frame_trailers));
+				}
+			}
+		}
+	}
+
+	Ok((data.freeze(), trailers))
 }
 
-/// Send a gRPC unary request using raw HTTP/2 via reqwest.
+/// HTTP/2 client of gRPC calls, presenting the client certificate of the request
+pub(crate) fn grpc_client(
+	tls: &TlsSettings,
+	timeout: Option<Duration>,
+) -> anyhow::Result<reqwest::Client> {
+	let mut client_builder = reqwest::Client::builder()
+		.http2_prior_knowledge()
+		.tls_backend_preconfigured(rustls_client_config(tls, false, false)?);
+
+	if let Some(timeout) = timeout {
+		client_builder = client_builder.timeout(timeout);
+	}
+
+	Ok(client_builder.build()?)
+}
+
+/// Send a gRPC request using raw HTTP/2 via reqwest.
 ///
 /// This function:
 /// 1. Parses the .proto file to get service/method descriptors
 /// 2. Converts the JSON message body to protobuf binary
 /// 3. Sends the request via HTTP/2 with gRPC framing
 /// 4. Decodes the protobuf response back to JSON
+///
+/// Streaming methods are handed over to [`send_grpc_streaming_request`].
 pub async fn send_grpc_request(
 	grpc_request: &GrpcRequest,
 	url: &str,
 	headers: &[(String, String)],
+	tls: &TlsSettings,
 	local_request: Arc<RwLock<Request>>,
 	env: &Option<Arc<RwLock<Environment>>>,
+	received_response: Arc<Mutex<bool>>,
 ) -> Result<RequestResponse, RequestResponseError> {
 	info!("Sending gRPC request");
 
@@ -165,8 +247,13 @@ pub async fn send_grpc_request(
 	let request_start = Instant::now();
 	let elapsed_time: Duration;
 
-	// Phase 1: Parse the proto file and resolve the method
-	let method = match parse_proto_file(&grpc_request.proto_file, &grpc_request.import_paths)
+	// Phase 1: Load the descriptors and resolve the method
+	let descriptor_pool = tokio::select! {
+		_ = cancellation_token.cancelled() => Err(anyhow::anyhow!("Canceled")),
+		pool = load_descriptor_pool(grpc_request, url, headers, tls, Duration::from_millis(timeout_ms)) => pool,
+	};
+
+	let method = match descriptor_pool
 		.and_then(|pool| resolve_method(&pool, &grpc_request.service, &grpc_request.method))
 	{
 		Ok(method) => method,
@@ -174,16 +261,40 @@ pub async fn send_grpc_request(
 			let mut request = local_request.write();
 			request.is_pending = false;
 			request.cancellation_token = CancellationToken::new();
-			return Ok(RequestResponse {
-				duration: Some(format!("{:?}", request_start.elapsed())),
-				status_code: Some(format!("PROTO ERROR: {}", e)),
-				content: Some(ResponseContent::Body(e.to_string())),
-				cookies: None,
-				headers: vec![],
-			});
+			return Ok(request_error_response(
+				GrpcRequestError::Descriptors,
+				&e,
+				request_start,
+			));
 		}
 	};
 
+	let is_streaming = method.is_client_streaming() || method.is_server_streaming();
+
+	{
+		let mut request = local_request.write();
+		let grpc_request = request
+			.get_grpc_request_mut()
+			.expect("request should be gRPC");
+		grpc_request.is_streaming = is_streaming;
+		grpc_request.messages = vec![];
+		grpc_request.trailers = vec![];
+	}
+
+	if is_streaming {
+		return send_grpc_streaming_request(
+			grpc_request,
+			method,
+			url,
+			headers,
+			tls,
+			local_request,
+			env,
+			received_response,
+		)
+		.await;
+	}
+
 	// Phase 2: Encode the JSON message to protobuf
 	let message_json = if grpc_request.message.trim().is_empty() {
 		"{}".to_string()
@@ -191,141 +302,107 @@ pub async fn send_grpc_request(
 		grpc_request.message.clone()
 	};
 
-	let encoded_message = match json_to_protobuf(&method, &message_json) {
-		Ok(bytes) => bytes,
+	let framed_body = match json_to_protobuf(&method, &message_json)
+		.and_then(|encoded_message| grpc_frame(&encoded_message, grpc_request.compression))
+	{
+		Ok(framed_body) => framed_body,
 		Err(e) => {
 			let mut request = local_request.write();
 			request.is_pending = false;
 			request.cancellation_token = CancellationToken::new();
-			return Ok(RequestResponse {
-				duration: Some(format!("{:?}", request_start.elapsed())),
-				status_code: Some(format!("ENCODE ERROR: {}", e)),
-				content: Some(ResponseContent::Body(e.to_string())),
-				cookies: None,
-				headers: vec![],
-			});
+			return Ok(request_error_response(
+				GrpcRequestError::EncodeMessage,
+				&e,
+				request_start,
+			));
 		}
 	};
 
-	// Phase 3: Build the gRPC path
-	let grpc_path = format!(
-		"{}/{}/{}",
-		url.trim_end_matches('/'),
-		grpc_request.service,
-		grpc_request.method
-	);
-
-	// Phase 4: Send via reqwest with HTTP/2 and gRPC framing
-	let framed_body = grpc_frame(&encoded_message);
+	// Phase 3: Send via reqwest with HTTP/2 and gRPC framing
+	let client = grpc_client(tls, None).map_err(|e| {
+		error!("Failed to build HTTP/2 client: {}", e);
+		CouldNotDecodeResponse
+	})?;
 
-	let client = reqwest::Client::builder()
-		.http2_prior_knowledge()
-		.build()
-		.map_err(|e| {
-			error!("Failed to build HTTP/2 client: {}", e);
-			CouldNotDecodeResponse
-		})?;
-
-	let mut request_builder = client
-		.post(&grpc_path)
-		.header("content-type", "application/grpc")
-		.header("te", "trailers");
-
-	for (key, value) in headers {
-		request_builder = request_builder.header(key.as_str(), value.as_str());
-	}
+	let request_builder =
+		grpc_request_builder(&client, url, grpc_request, headers, Some(timeout_ms));
 
 	let mut response = tokio::select! {
 		_ = cancellation_token.cancelled() => {
 			elapsed_time = request_start.elapsed();
 			RequestResponse {
-				duration: None,
 				status_code: Some(String::from("CANCELED")),
-				content: None,
-				cookies: None,
-				headers: vec![],
+				..Default::default()
 			}
 		},
 		_ = timeout => {
 			elapsed_time = request_start.elapsed();
 			RequestResponse {
-				duration: None,
 				status_code: Some(String::from("TIMEOUT")),
-				content: None,
-				cookies: None,
-				headers: vec![],
+				..Default::default()
 			}
 		},
 		response = request_builder.body(framed_body).send() => match response {
 			Ok(response) => {
 				info!("gRPC response received");
-				elapsed_time = request_start.elapsed();
 
 				let status_code = response.status().to_string();
+				let resp_headers = metadata_to_vec(response.headers());
+				let mut decoder = GrpcFrameDecoder::with_encoding(header_value(&resp_headers, "grpc-encoding"));
+
+				let body = read_grpc_body(response).await;
+				elapsed_time = request_start.elapsed();
+
+				match body {
+					Ok((body_bytes, trailers)) => {
+						// Trailers-only responses carry the status in the headers
+						let grpc_status = parse_grpc_status(&trailers, method.parent_pool())
+							.or_else(|| parse_grpc_status(&resp_headers, method.parent_pool()));
+
+						decoder.push(&body_bytes);
 
-				let resp_headers: Vec<(String, String)> = response
-					.headers()
-					.iter()
-					.map(|(name, value)| {
-						(name.to_string(), value.to_str().unwrap_or("").to_string())
-					})
-					.collect();
-
-				// Check for gRPC status in headers/trailers
-				let grpc_status = resp_headers
-					.iter()
-					.find(|(k, _)| k == "grpc-status")
-					.map(|(_, v)| v.clone());
-
-				let grpc_message = resp_headers
-					.iter()
-					.find(|(k, _)| k == "grpc-message")
-					.map(|(_, v)| v.clone());
-
-				match response.bytes().await {
-					Ok(body_bytes) => {
-						let content = if body_bytes.len() >= 5 {
-							match grpc_unframe(body_bytes) {
+						let content = if let Some(message_bytes) = decoder.next_message() {
+							match message_bytes {
 								Ok(message_bytes) => {
 									match protobuf_to_json(&method, &message_bytes) {
 										Ok(json) => json,
 										Err(e) => format!("Failed to decode response: {}", e),
 									}
 								}
-								Err(e) => format!("Failed to unframe gRPC response: {}", e),
+								Err(e) => format!("Failed to decompress gRPC response: {}", e),
 							}
 						} else if body_bytes.is_empty() {
-							// Empty response is valid for some gRPC calls
-							String::from("{}")
+							match &grpc_status {
+								// A failed call has no message, its status is the most useful content
+								Some(status) if !status.is_ok() => serde_json::to_string_pretty(&status.to_json())
+									.unwrap_or_default(),
+								// Empty response is valid for some gRPC calls
+								_ => String::from("{}"),
+							}
 						} else {
 							format!("Unexpected response body ({} bytes)", body_bytes.len())
 						};
 
 						let display_status = match &grpc_status {
-							Some(s) if s == "0" => format!("{} (OK)", status_code),
-							Some(s) => {
-								let msg = grpc_message.as_deref().unwrap_or("");
-								format!("{} (gRPC {}{})", status_code, s,
-									if msg.is_empty() { String::new() } else { format!(": {}", msg) })
-							}
+							Some(grpc_status) => format!("{} (gRPC {})", status_code, grpc_status),
 							None => status_code,
 						};
 
 						RequestResponse {
-							duration: None,
 							status_code: Some(display_status),
 							content: Some(ResponseContent::Body(content)),
-							cookies: None,
 							headers: resp_headers,
+							trailers,
+							grpc_status,
+							..Default::default()
 						}
 					}
-					Err(_) => {
+					Err(error) => {
 						RequestResponse {
-							duration: None,
 							status_code: Some(status_code),
-							content: Some(ResponseContent::Body("Failed to read response body".to_string())),
-							cookies: None,
+							content: Some(ResponseContent::Body(format!("Failed to read response body: {error}"))),
 							headers: resp_headers,
+							..Default::default()
 						}
 					}
 				}
@@ -338,11 +415,9 @@ pub async fn send_grpc_request(
 				let result_body = ResponseContent::Body(error.to_string());
 
 				RequestResponse {
-					duration: None,
 					status_code: response_status_code,
 					content: Some(result_body),
-					cookies: None,
-					headers: vec![],
+					..Default::default()
 				}
 			}
 		}
@@ -353,16 +428,352 @@ pub async fn send_grpc_request(
 
 	// Post-request script
 	let request = local_request.read();
-	let (modified_response, post_request_output) =
+	let (modified_response, post_request_output, test_results) =
 		App::handle_post_request_script(&request, response, env)?;
 	drop(request);
 
 	{
 		let mut request = local_request.write();
 		request.console_output.post_request_output = post_request_output;
+		request.test_results = test_results;
+		request.assertion_results =
+			evaluate_assertions(&request.assertions, &modified_response, elapsed_time);
+		request.capture_results = evaluate_captures(&request.captures, &modified_response);
 		request.is_pending = false;
 		request.cancellation_token = CancellationToken::new();
 	}
 
 	Ok(modified_response)
 }
+
+/// Intermediate result of the streaming call setup, the response body is read
+/// by a spawned task once the lock is released.
+enum GrpcStreamOutcome {
+	Response(RequestResponse),
+	Streaming {
+		response: RequestResponse,
+		body: reqwest::Body,
+	},
+}
+
+/// Send a server-streaming, client-streaming or bidi gRPC request.
+///
+/// The editor message is sent first. For client-streaming methods the request
+/// stream stays open in [`GrpcRequest::call`] until it is half-closed, and
+/// response messages are decoded as they arrive into [`GrpcRequest::messages`].
+#[allow(clippy::too_many_arguments)]
+async fn send_grpc_streaming_request(
+	grpc_request: &GrpcRequest,
+	method: MethodDescriptor,
+	url: &str,
+	headers: &[(String, String)],
+	tls: &TlsSettings,
+	local_request: Arc<RwLock<Request>>,
+	env: &Option<Arc<RwLock<Environment>>>,
+	received_response: Arc<Mutex<bool>>,
+) -> Result<RequestResponse, RequestResponseError> {
+	info!("Opening gRPC stream");
+
+	let (cancellation_token, timeout_ms) = {
+		let request = local_request.read();
+		let timeout_ms = request.settings.timeout.as_u32().unwrap_or(30000) as u64;
+		(request.cancellation_token.clone(), timeout_ms)
+	};
+
+	let request_start = Instant::now();
+
+	// A client-streaming call may only be answered once the request stream is
+	// half-closed, so the first message is optional and the timeout doesn't apply
+	let first_message = match (
+		grpc_request.message.trim().is_empty(),
+		method.is_client_streaming(),
+	) {
+		(true, true) => None,
+		(true, false) => Some(String::from("{}")),
+		(false, _) => Some(grpc_request.message.clone()),
+	};
+
+	let (tx, rx) = mpsc::unbounded_channel::<Bytes>();
+	let mut messages = vec![];
+
+	if let Some(first_message) = first_message {
+		match json_to_protobuf(&method, &first_message)
+			.and_then(|encoded_message| grpc_frame(&encoded_message, grpc_request.compression))
+		{
+			Ok(frame) => {
+				tx.send(frame).ok();
+				messages.push(Message {
+					timestamp: Local::now(),
+					sender: Sender::You,
+					content: MessageType::Text(first_message),
+				});
+			}
+			Err(e) => {
+				let mut request = local_request.write();
+				request.is_pending = false;
+				request.cancellation_token = CancellationToken::new();
+				return Ok(request_error_response(
+					GrpcRequestError::EncodeMessage,
+					&e,
+					request_start,
+				));
+			}
+		}
+	}
+
+	let call_cancellation_token = CancellationToken::new();
+
+	{
+		let mut request = local_request.write();
+		let grpc_request = request
+			.get_grpc_request_mut()
+			.expect("request should be gRPC");
+		grpc_request.messages = messages;
+		grpc_request.call = Some(GrpcCall {
+			method: method.clone(),
+			tx: method.is_client_streaming().then(|| tx.clone()),
+			cancellation_token: call_cancellation_token.clone(),
+			compression: grpc_request.compression,
+		});
+	}
+
+	// Only the call keeps a sender, a server-streaming request stream ends after its message
+	drop(tx);
+
+	let request_stream = futures_util::stream::unfold(rx, |mut rx| async move {
+		rx.recv()
+			.await
+			.map(|frame| (Ok::<Bytes, std::io::Error>(frame), rx))
+	});
+
+	let client = grpc_client(tls, None).map_err(|e| {
+		error!("Failed to build HTTP/2 client: {}", e);
+		CouldNotDecodeResponse
+	})?;
+
+	// Streams are only bound by the timeout until they are opened, so no deadline is sent
+	let request_builder = grpc_request_builder(&client, url, grpc_request, headers, None);
+
+	let timeout = async {
+		match method.is_client_streaming() {
+			true => std::future::pending().await,
+			false => tokio::time::sleep(Duration::from_millis(timeout_ms)).await,
+		}
+	};
+
+	let outcome = tokio::select! {
+		_ = cancellation_token.cancelled() => {
+			GrpcStreamOutcome::Response(RequestResponse {
+				status_code: Some(String::from("CANCELED")),
+				..Default::default()
+			})
+		},
+		_ = timeout => {
+			GrpcStreamOutcome::Response(RequestResponse {
+				status_code: Some(String::from("TIMEOUT")),
+				..Default::default()
+			})
+		},
+		response = request_builder.body(reqwest::Body::wrap_stream(request_stream)).send() => match response {
+			Ok(response) => {
+				info!("gRPC stream opened");
+
+				let status = response.status();
+
+				let resp_headers = metadata_to_vec(response.headers());
+
+				match status.is_success() {
+					true => GrpcStreamOutcome::Streaming {
+						response: RequestResponse {
+							status_code: Some(status.to_string()),
+							headers: resp_headers,
+							..Default::default()
+						},
+						body: http::Response::from(response).into_body(),
+					},
+					false => GrpcStreamOutcome::Response(RequestResponse {
+						status_code: Some(status.to_string()),
+						content: response.text().await.ok().map(ResponseContent::Body),
+						headers: resp_headers,
+						..Default::default()
+					}),
+				}
+			}
+			Err(error) => {
+				error!("gRPC sending error: {}", error);
+
+				GrpcStreamOutcome::Response(RequestResponse {
+					status_code: error.status().map(|s| s.to_string()),
+					content: Some(ResponseContent::Body(error.to_string())),
+					..Default::default()
+				})
+			}
+		}
+	};
+
+	let elapsed_time = request_start.elapsed();
+
+	let (mut response, body) = match outcome {
+		GrpcStreamOutcome::Response(response) => (response, None),
+		GrpcStreamOutcome::Streaming { response, body } => (response, Some(body)),
+	};
+
+	response.duration = Some(format!("{:?}", elapsed_time));
+	trace!("gRPC stream request sent");
+
+	// Post-request script
+	let request = local_request.read();
+	let (modified_response, post_request_output, test_results) =
+		App::handle_post_request_script(&request, response.clone(), env)?;
+	drop(request);
+
+	{
+		let mut request = local_request.write();
+		request.console_output.post_request_output = post_request_output;
+		request.test_results = test_results;
+		request.assertion_results =
+			evaluate_assertions(&request.assertions, &modified_response, elapsed_time);
+		request.capture_results = evaluate_captures(&request.captures, &modified_response);
+		request.is_pending = false;
+		request.cancellation_token = CancellationToken::new();
+
+		if body.is_none() {
+			let grpc_request = request
+				.get_grpc_request_mut()
+				.expect("request should be gRPC");
+			grpc_request.call = None;
+		}
+	}
+
+	if let Some(body) = body {
+		tokio::spawn(read_grpc_response_stream(
+			body,
+			method,
+			response.headers,
+			call_cancellation_token,
+			local_request,
+			received_response,
+		));
+	}
+
+	Ok(modified_response)
+}
+
+/// Decode each response message as soon as its frame is complete, until the
+/// server ends the stream or the call is cancelled.
+async fn read_grpc_response_stream(
+	mut body: reqwest::Body,
+	method: MethodDescriptor,
+	headers: Vec<(String, String)>,
+	cancellation_token: CancellationToken,
+	local_request: Arc<RwLock<Request>>,
+	received_response: Arc<Mutex<bool>>,
+) {
+	let mut decoder = GrpcFrameDecoder::with_encoding(header_value(&headers, "grpc-encoding"));
+	let mut trailers = vec![];
+
+	let close_reason = loop {
+		let frame = tokio::select! {
+			_ = cancellation_token.cancelled() => break String::from("Call cancelled"),
+			frame = body.frame() => frame,
+		};
+
+		let frame = match frame {
+			None => {
+				// Trailers-only responses carry the status in the headers
+				let grpc_status = parse_grpc_status(&trailers, method.parent_pool())
+					.or_else(|| parse_grpc_status(&headers, method.parent_pool()));
+				break stream_close_reason(grpc_status.as_ref());
+			}
+			Some(Err(error)) => break format!("Stream error: {error}"),
+			Some(Ok(frame)) => frame,
+		};
+
+		let data = match frame.into_data() {
+			Ok(data) => data,
+			Err(frame) => {
+				if let Some(frame_trailers) = frame.trailers_ref() {
+					trailers.extend(metadata_to_vec(frame_trailers));
+				}
+				continue;
+			}
+		};
+
+		decoder.push(&data);
+
+		let mut new_messages = vec![];
+
+		while let Some(message_bytes) = decoder.next_message() {
+			let content = match message_bytes
+				.and_then(|message_bytes| protobuf_to_json(&method, &message_bytes))
+			{
+				Ok(json) => json,
+				Err(e) => format!("Failed to decode message: {}", e),
+			};
+
+			new_messages.push(Message {
+				timestamp: Local::now(),
+				sender: Sender::Server,
+				content: MessageType::Text(content),
+			});
+		}
+
+		if new_messages.is_empty() {
+			continue;
+		}
+
+		{
+			let mut request = local_request.write();
+			let grpc_request = request
+				.get_grpc_request_mut()
+				.expect("request should be gRPC");
+			grpc_request.messages.extend(new_messages);
+		}
+
+		*received_response.lock() = true;
+	};
+
+	info!("{close_reason}");
+
+	{
+		let mut request = local_request.write();
+		let grpc_request = request
+			.get_grpc_request_mut()
+			.expect("request should be gRPC");
+		grpc_request.call = None;
+		grpc_request.trailers = trailers;
+		grpc_request.messages.push(Message {
+			timestamp: Local::now(),
+			sender: Sender::Server,
+			content: MessageType::Close(close_reason),
+		});
+	}
+
+	*received_response.lock() = true;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn close_reason_includes_status() {
+		assert_eq!(
+			stream_close_reason(Some(&GrpcStatus {
+				code: 0,
+				message: String::new(),
+				details: None,
+			})),
+			"Stream closed, gRPC status OK (0)"
+		);
+		assert_eq!(
+			stream_close_reason(Some(&GrpcStatus {
+				code: 5,
+				message: String::from("not found"),
+				details: None,
+			})),
+			"Stream closed, gRPC status NOT_FOUND (5): not found"
+		);
+		assert_eq!(stream_close_reason(None), "Stream closed");
+	}
+}
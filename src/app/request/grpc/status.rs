@@ -0,0 +1,333 @@
+use std::sync::LazyLock;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD_NO_PAD;
+use prost::Message;
+use prost_reflect::{DescriptorPool, DynamicMessage};
+use protox::file::{ChainFileResolver, File, FileResolver, GoogleFileResolver};
+use tracing::warn;
+
+use crate::models::protocol::grpc::status::GrpcStatus;
+
+/// Standard error detail messages servers put in `google.rpc.Status.details`
+const ERROR_DETAILS_PROTO: &str = r#"
+syntax = "proto3";
+package google.rpc;
+
+import "google/protobuf/duration.proto";
+
+message ErrorInfo {
+	string reason = 1;
+	string domain = 2;
+	map<string, string> metadata = 3;
+}
+
+message RetryInfo {
+	google.protobuf.Duration retry_delay = 1;
+}
+
+message DebugInfo {
+	repeated string stack_entries = 1;
+	string detail = 2;
+}
+
+message QuotaFailure {
+	message Violation {
+		string subject = 1;
+		string description = 2;
+	}
+	repeated Violation violations = 1;
+}
+
+message PreconditionFailure {
+	message Violation {
+		string type = 1;
+		string subject = 2;
+		string description = 3;
+	}
+	repeated Violation violations = 1;
+}
+
+message BadRequest {
+	message FieldViolation {
+		string field = 1;
+		string description = 2;
+		string reason = 3;
+	}
+	repeated FieldViolation field_violations = 1;
+}
+
+message RequestInfo {
+	string request_id = 1;
+	string serving_data = 2;
+}
+
+message ResourceInfo {
+	string resource_type = 1;
+	string resource_name = 2;
+	string owner = 3;
+	string description = 4;
+}
+
+message Help {
+	message Link {
+		string description = 1;
+		string url = 2;
+	}
+	repeated Link links = 1;
+}
+
+message LocalizedMessage {
+	string locale = 1;
+	string message = 2;
+}
+"#;
+
+const ERROR_DETAILS_FILE: &str = "google/rpc/error_details.proto";
+
+struct ErrorDetailsFileResolver;
+
+impl FileResolver for ErrorDetailsFileResolver {
+	fn open_file(&self, name: &str) -> Result<File, protox::Error> {
+		match name {
+			ERROR_DETAILS_FILE => File::from_source(name, ERROR_DETAILS_PROTO),
+			_ => Err(protox::Error::file_not_found(name)),
+		}
+	}
+}
+
+static ERROR_DETAILS_POOL: LazyLock<Option<DescriptorPool>> = LazyLock::new(|| {
+	let mut resolver = ChainFileResolver::new();
+	resolver.add(ErrorDetailsFileResolver);
+	resolver.add(GoogleFileResolver::new());
+
+	let mut compiler = protox::Compiler::with_file_resolver(resolver);
+
+	match compiler.open_file(ERROR_DETAILS_FILE) {
+		Ok(compiler) => Some(compiler.descriptor_pool()),
+		Err(error) => {
+			warn!("Could not compile the gRPC error details: {error}");
+			None
+		}
+	}
+});
+
+/// `google.rpc.Status`
+#[derive(Clone, PartialEq, Message)]
+struct RpcStatus {
+	#[prost(int32, tag = "1")]
+	code: i32,
+	#[prost(string, tag = "2")]
+	message: String,
+	#[prost(message, repeated, tag = "3")]
+	details: Vec<prost_types::Any>,
+}
+
+/// Status of a gRPC call from its trailers, or from its headers for a trailers-only
+/// response. Details are decoded with the call descriptors or the standard error details.
+pub fn parse_grpc_status(
+	metadata: &[(String, String)],
+	pool: &DescriptorPool,
+) -> Option<GrpcStatus> {
+	let value = |name: &str| {
+		metadata
+			.iter()
+			.find(|(key, _)| key.eq_ignore_ascii_case(name))
+			.map(|(_, value)| value.as_str())
+	};
+
+	// Unparsable codes are UNKNOWN, as the spec requires
+	let code = value("grpc-status")?.trim().parse::<u32>().unwrap_or(2);
+	let message = value("grpc-message")
+		.map(percent_decode)
+		.unwrap_or_default();
+	let details =
+		value("grpc-status-details-bin").and_then(|details| decode_status_details(details, pool));
+
+	Some(GrpcStatus {
+		code,
+		message,
+		details,
+	})
+}
+
+/// `grpc-message` is percent-encoded
+fn percent_decode(value: &str) -> String {
+	let bytes = value.as_bytes();
+	let mut decoded = Vec::with_capacity(bytes.len());
+	let mut index = 0;
+
+	while index < bytes.len() {
+		if bytes[index] == b'%'
+			&& let Some(hex) = value.get(index + 1..index + 3)
+			&& let Ok(byte) = u8::from_str_radix(hex, 16)
+		{
+			decoded.push(byte);
+			index += 3;
+		} else {
+			decoded.push(bytes[index]);
+			index += 1;
+		}
+	}
+
+	String::from_utf8_lossy(&decoded).to_string()
+}
+
+fn decode_status_details(value: &str, pool: &DescriptorPool) -> Option<serde_json::Value> {
+	// Binary metadata is base64, with or without padding
+	let bytes = match STANDARD_NO_PAD.decode(value.trim().trim_end_matches('=')) {
+		Ok(bytes) => bytes,
+		Err(error) => {
+			warn!("Invalid grpc-status-details-bin: {error}");
+			return None;
+		}
+	};
+
+	let status = match RpcStatus::decode(bytes.as_slice()) {
+		Ok(status) => status,
+		Err(error) => {
+			warn!("Could not decode grpc-status-details-bin: {error}");
+			return None;
+		}
+	};
+
+	let details: Vec<serde_json::Value> = status
+		.details
+		.iter()
+		.map(|any| any_to_json(any, pool))
+		.collect();
+
+	Some(serde_json::json!({
+		"code": status.code,
+		"message": status.message,
+		"details": details,
+	}))
+}
+
+/// JSON of a `google.protobuf.Any`, its raw value is kept when the type is unknown
+fn any_to_json(any: &prost_types::Any, pool: &DescriptorPool) -> serde_json::Value {
+	let type_name = any.type_url.rsplit('/').next().unwrap_or_default();
+
+	let descriptor = pool.get_message_by_name(type_name).or_else(|| {
+		ERROR_DETAILS_POOL
+			.as_ref()
+			.and_then(|error_details| error_details.get_message_by_name(type_name))
+	});
+
+	let decoded = descriptor
+		.and_then(|descriptor| DynamicMessage::decode(descriptor, any.value.as_slice()).ok())
+		.and_then(|message| serde_json::to_value(&message).ok());
+
+	match decoded {
+		Some(serde_json::Value::Object(fields)) => {
+			let mut json = serde_json::Map::new();
+			json.insert(
+				String::from("@type"),
+				serde_json::Value::from(any.type_url.clone()),
+			);
+			json.extend(fields);
+			serde_json::Value::Object(json)
+		}
+		_ => serde_json::json!({
+			"@type": any.type_url,
+			"value": STANDARD_NO_PAD.encode(&any.value),
+		}),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn metadata(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+		pairs
+			.iter()
+			.map(|(key, value)| (key.to_string(), value.to_string()))
+			.collect()
+	}
+
+	#[test]
+	fn no_status_without_grpc_status() {
+		let status = parse_grpc_status(
+			&metadata(&[("content-type", "application/grpc")]),
+			&DescriptorPool::new(),
+		);
+		assert_eq!(status, None);
+	}
+
+	#[test]
+	fn status_with_percent_encoded_message() {
+		let status = parse_grpc_status(
+			&metadata(&[
+				("grpc-status", "5"),
+				("grpc-message", "user%2042%20not%20found"),
+			]),
+			&DescriptorPool::new(),
+		)
+		.unwrap();
+
+		assert_eq!(status.name(), "NOT_FOUND");
+		assert_eq!(status.message, "user 42 not found");
+		assert_eq!(status.details, None);
+	}
+
+	#[test]
+	fn invalid_code_is_unknown() {
+		let status =
+			parse_grpc_status(&metadata(&[("grpc-status", "abc")]), &DescriptorPool::new())
+				.unwrap();
+		assert_eq!(status.name(), "UNKNOWN");
+	}
+
+	#[test]
+	fn status_details_are_decoded() {
+		let error_info = DynamicMessage::decode(
+			ERROR_DETAILS_POOL
+				.as_ref()
+				.unwrap()
+				.get_message_by_name("google.rpc.ErrorInfo")
+				.unwrap(),
+			[].as_slice(),
+		)
+		.unwrap();
+		let mut error_info = error_info;
+		error_info.set_field_by_name(
+			"reason",
+			prost_reflect::Value::String(String::from("QUOTA")),
+		);
+
+		let details = RpcStatus {
+			code: 8,
+			message: String::from("slow down"),
+			details: vec![
+				prost_types::Any {
+					type_url: String::from("type.googleapis.com/google.rpc.ErrorInfo"),
+					value: error_info.encode_to_vec(),
+				},
+				prost_types::Any {
+					type_url: String::from("type.googleapis.com/my.Unknown"),
+					value: vec![1, 2, 3],
+				},
+			],
+		};
+		let encoded = base64::engine::general_purpose::STANDARD.encode(details.encode_to_vec());
+
+		let status = parse_grpc_status(
+			&metadata(&[("grpc-status", "8"), ("grpc-status-details-bin", &encoded)]),
+			&DescriptorPool::new(),
+		)
+		.unwrap();
+
+		assert_eq!(
+			status.details,
+			Some(serde_json::json!({
+				"code": 8,
+				"message": "slow down",
+				"details": [
+					{"@type": "type.googleapis.com/google.rpc.ErrorInfo", "reason": "QUOTA"},
+					{"@type": "type.googleapis.com/my.Unknown", "value": "AQID"},
+				]
+			}))
+		);
+	}
+}
@@ -0,0 +1,253 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::models::response::CertificateSummary;
+
+const SEQUENCE: u8 = 0x30;
+const UTC_TIME: u8 = 0x17;
+const GENERALIZED_TIME: u8 = 0x18;
+/// `[0]` explicit tag of the certificate version
+const VERSION: u8 = 0xa0;
+/// `[3]` explicit tag of the certificate extensions
+const EXTENSIONS: u8 = 0xa3;
+/// `[2]` and `[7]` implicit tags of the general names
+const DNS_NAME: u8 = 0x82;
+const IP_ADDRESS: u8 = 0x87;
+
+/// OID 2.5.29.17
+const SUBJECT_ALT_NAME_OID: &[u8] = &[0x55, 0x1d, 0x11];
+
+/// Subject, issuer, validity and alternative names of a DER encoded X.509 certificate. `None` when
+/// the certificate cannot be parsed.
+pub fn summarize_certificate(der: &[u8]) -> Option<CertificateSummary> {
+	let (SEQUENCE, certificate, _) = read_value(der)? else {
+		return None;
+	};
+	let (SEQUENCE, tbs_certificate, _) = read_value(certificate)? else {
+		return None;
+	};
+
+	let mut fields = values(tbs_certificate);
+
+	// The version is omitted for v1 certificates, the serial number comes first
+	if fields.next()?.0 == VERSION {
+		fields.next()?;
+	}
+
+	let _signature_algorithm = fields.next()?;
+	let (_, issuer) = fields.next()?;
+	let (_, validity) = fields.next()?;
+	let (_, subject) = fields.next()?;
+
+	let subject_alt_names = fields
+		.find(|(tag, _)| *tag == EXTENSIONS)
+		.map(|(_, extensions)| subject_alt_names(extensions))
+		.unwrap_or_default();
+
+	let mut validity = values(validity);
+
+	Some(CertificateSummary {
+		subject: format_name(subject),
+		issuer: format_name(issuer),
+		not_before: format_time(validity.next()?)?,
+		not_after: format_time(validity.next()?)?,
+		subject_alt_names,
+	})
+}
+
+/// Tag and content of the DER value at the start of `input`, followed by the remaining bytes
+pub(crate) fn read_value(input: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+	let (&tag, input) = input.split_first()?;
+	let (&length, input) = input.split_first()?;
+
+	let (length, input) = match length {
+		0..=0x7f => (length as usize, input),
+		// Long form, the length is encoded on the next bytes
+		0x81..=0x84 => {
+			let (length, input) = input.split_at_checked((length & 0x7f) as usize)?;
+			let length = length
+				.iter()
+				.fold(0, |length, byte| (length << 8) | *byte as usize);
+
+			(length, input)
+		}
+		_ => return None,
+	};
+
+	let (content, rest) = input.split_at_checked(length)?;
+
+	Some((tag, content, rest))
+}
+
+/// Tag and content of the consecutive DER values of `input`
+pub(crate) fn values(mut input: &[u8]) -> impl Iterator<Item = (u8, &[u8])> {
+	std::iter::from_fn(move || {
+		let (tag, content, rest) = read_value(input)?;
+		input = rest;
+
+		Some((tag, content))
+	})
+}
+
+/// Distinguished name with the usual attributes, e.g. `CN=example.com, O=Example`
+fn format_name(name: &[u8]) -> String {
+	values(name)
+		.flat_map(|(_, relative_name)| values(relative_name))
+		.filter_map(|(_, attribute)| {
+			let mut attribute = values(attribute);
+			let (_, oid) = attribute.next()?;
+			let (_, value) = attribute.next()?;
+
+			// OIDs 2.5.4.x
+			let key = match oid {
+				[0x55, 0x04, 0x03] => "CN",
+				[0x55, 0x04, 0x06] => "C",
+				[0x55, 0x04, 0x07] => "L",
+				[0x55, 0x04, 0x08] => "ST",
+				[0x55, 0x04, 0x0a] => "O",
+				[0x55, 0x04, 0x0b] => "OU",
+				_ => return None,
+			};
+
+			Some(format!("{key}={}", String::from_utf8_lossy(value)))
+		})
+		.collect::<Vec<String>>()
+		.join(", ")
+}
+
+/// `YYYY-MM-DD HH:MM:SS UTC` from an ASN.1 UTC or generalized time
+fn format_time((tag, time): (u8, &[u8])) -> Option<String> {
+	let time = std::str::from_utf8(time).ok()?;
+
+	let (year, time) = match tag {
+		// Two digits years, from 1950 to 2049
+		UTC_TIME => {
+			let year: u32 = time.get(..2)?.parse().ok()?;
+
+			match year < 50 {
+				true => (2000 + year, &time[2..]),
+				false => (1900 + year, &time[2..]),
+			}
+		}
+		GENERALIZED_TIME => (time.get(..4)?.parse().ok()?, &time[4..]),
+		_ => return None,
+	};
+
+	let time = time.get(..10)?;
+
+	if !time.bytes().all(|byte| byte.is_ascii_digit()) {
+		return None;
+	}
+
+	Some(format!(
+		"{year}-{}-{} {}:{}:{} UTC",
+		&time[0..2],
+		&time[2..4],
+		&time[4..6],
+		&time[6..8],
+		&time[8..10]
+	))
+}
+
+/// DNS names and IP addresses of the subject alternative name extension
+fn subject_alt_names(extensions: &[u8]) -> Vec<String> {
+	let Some((SEQUENCE, extensions, _)) = read_value(extensions) else {
+		return vec![];
+	};
+
+	for (_, extension) in values(extensions) {
+		let mut fields = values(extension);
+
+		if fields.next().map(|(_, oid)| oid) != Some(SUBJECT_ALT_NAME_OID) {
+			continue;
+		}
+
+		// The critical flag is optional, the value comes last
+		let Some((_, value)) = fields.last() else {
+			return vec![];
+		};
+		let Some((SEQUENCE, names, _)) = read_value(value) else {
+			return vec![];
+		};
+
+		return values(names)
+			.filter_map(|(tag, name)| match tag {
+				DNS_NAME => Some(String::from_utf8_lossy(name).to_string()),
+				IP_ADDRESS => match name.len() {
+					4 => Some(Ipv4Addr::from(<[u8; 4]>::try_from(name).ok()?).to_string()),
+					16 => Some(Ipv6Addr::from(<[u8; 16]>::try_from(name).ok()?).to_string()),
+					_ => None,
+				},
+				_ => None,
+			})
+			.collect();
+	}
+
+	vec![]
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use base64::Engine;
+	use base64::engine::general_purpose::STANDARD;
+
+	fn fixture_der(name: &str) -> Vec<u8> {
+		let pem = std::fs::read_to_string(format!(
+			"{}/tests/helpers/tls/{name}",
+			env!("CARGO_MANIFEST_DIR")
+		))
+		.unwrap();
+
+		let base64: String = pem
+			.lines()
+			.filter(|line| !line.starts_with("-----"))
+			.collect();
+
+		STANDARD.decode(base64).unwrap()
+	}
+
+	#[test]
+	fn summarizes_the_server_certificate() {
+		let summary = summarize_certificate(&fixture_der("server.pem")).unwrap();
+
+		assert_eq!(summary.subject, "CN=squrl test server");
+		assert_eq!(summary.issuer, "CN=squrl test CA");
+		assert_eq!(summary.not_before, "2026-10-18 04:17:10 UTC");
+		assert_eq!(summary.not_after, "2126-09-24 04:17:10 UTC");
+		assert_eq!(summary.subject_alt_names, vec!["localhost", "127.0.0.1"]);
+	}
+
+	#[test]
+	fn certificate_without_alternative_names() {
+		let summary = summarize_certificate(&fixture_der("ca.pem")).unwrap();
+
+		assert_eq!(summary.subject, "CN=squrl test CA");
+		assert_eq!(summary.subject, summary.issuer);
+		assert!(summary.subject_alt_names.is_empty());
+	}
+
+	#[test]
+	fn invalid_certificates_are_not_summarized() {
+		let der = fixture_der("server.pem");
+
+		assert_eq!(summarize_certificate(&der[..der.len() / 2]), None);
+		assert_eq!(summarize_certificate(b"not a certificate"), None);
+	}
+
+	#[test]
+	fn formats_utc_and_generalized_times() {
+		assert_eq!(
+			format_time((UTC_TIME, b"491231235959Z")).as_deref(),
+			Some("2049-12-31 23:59:59 UTC")
+		);
+		assert_eq!(
+			format_time((UTC_TIME, b"500101000000Z")).as_deref(),
+			Some("1950-01-01 00:00:00 UTC")
+		);
+		assert_eq!(
+			format_time((GENERALIZED_TIME, b"21260924041710Z")).as_deref(),
+			Some("2126-09-24 04:17:10 UTC")
+		);
+		assert_eq!(format_time((UTC_TIME, b"26101804Z")), None);
+	}
+}
@@ -0,0 +1,336 @@
+use chrono::Local;
+use parking_lot::{Mutex, RwLock};
+use reqwest::StatusCode;
+use reqwest::header::{CONTENT_TYPE, HeaderMap};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::app::constants::{SSE_DEFAULT_RETRY, SSE_MAX_EVENTS};
+use crate::app::request::http::redirect::send_following_redirects;
+use crate::app::request::http::response_body::ResponseBodyBuffer;
+use crate::models::protocol::http::event_stream::ServerSentEvent;
+use crate::models::protocol::ws::message_type::MessageType;
+use crate::models::protocol::ws::ws::{Message, Sender};
+use crate::models::request::Request;
+
+pub fn is_event_stream(headers: &HeaderMap) -> bool {
+	headers
+		.get(CONTENT_TYPE)
+		.and_then(|content_type| content_type.to_str().ok())
+		.is_some_and(|content_type| content_type.starts_with("text/event-stream"))
+}
+
+/// Incremental parser of a `text/event-stream` body, fed with the chunks as they arrive
+#[derive(Default)]
+pub struct EventStreamParser {
+	/// Bytes of the line being received
+	line: Vec<u8>,
+	/// Whether the last line ended with a CR, which may be followed by a LF in the next chunk
+	after_cr: bool,
+	event: String,
+	data: String,
+	/// Sent back in the `Last-Event-ID` header when reconnecting
+	pub last_event_id: Option<String>,
+	/// Reconnection delay set by the server
+	pub retry: Option<Duration>,
+}
+
+impl EventStreamParser {
+	/// Parse a chunk of the stream, returning the events it completes
+	pub fn feed(&mut self, chunk: &[u8]) -> Vec<ServerSentEvent> {
+		let mut events = vec![];
+
+		for &byte in chunk {
+			if self.after_cr {
+				self.after_cr = false;
+
+				if byte == b'\n' {
+					continue;
+				}
+			}
+
+			match byte {
+				b'\r' | b'\n' => {
+					self.after_cr = byte == b'\r';
+
+					let line = std::mem::take(&mut self.line);
+
+					if let Some(event) = self.parse_line(&String::from_utf8_lossy(&line)) {
+						events.push(event);
+					}
+				}
+				_ => self.line.push(byte),
+			}
+		}
+
+		events
+	}
+
+	fn parse_line(&mut self, line: &str) -> Option<ServerSentEvent> {
+		// An empty line ends the event, a line starting with a colon is a comment
+		if line.is_empty() {
+			return self.dispatch_event();
+		} else if line.starts_with(':') {
+			return None;
+		}
+
+		let (field, value) = match line.split_once(':') {
+			Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+			None => (line, ""),
+		};
+
+		match field {
+			"event" => self.event = value.to_string(),
+			"data" => {
+				self.data.push_str(value);
+				self.data.push('\n');
+			}
+			"id" if !value.contains('\0') => self.last_event_id = Some(value.to_string()),
+			"retry" if !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit()) => {
+				if let Ok(retry) = value.parse() {
+					self.retry = Some(Duration::from_millis(retry));
+				}
+			}
+			_ => {}
+		}
+
+		None
+	}
+
+	fn dispatch_event(&mut self) -> Option<ServerSentEvent> {
+		let event = std::mem::take(&mut self.event);
+		let mut data = std::mem::take(&mut self.data);
+
+		// Events without data are not dispatched
+		if data.is_empty() {
+			return None;
+		}
+
+		data.pop();
+
+		Some(ServerSentEvent {
+			event: match event.is_empty() {
+				true => String::from("message"),
+				false => event,
+			},
+			data,
+			id: self
+				.last_event_id
+				.clone()
+				.filter(|last_event_id| !last_event_id.is_empty()),
+		})
+	}
+}
+
+/// Read an event stream until the request is canceled or the server refuses to reconnect. The
+/// events are added to the request messages, and the raw stream to `body`.
+///
+/// When the connection ends, the request is sent again after the `retry` delay, with the
+/// `Last-Event-ID` header, following at most `max_redirects` redirects. A response other than a
+/// `200 OK` event stream, e.g. `204 No Content`, ends the stream.
+pub async fn read_event_stream(
+	response: reqwest::Response,
+	reconnect_request: Option<reqwest_middleware::RequestBuilder>,
+	max_redirects: usize,
+	local_request: &Arc<RwLock<Request>>,
+	cancellation_token: &CancellationToken,
+	received_response: &Arc<Mutex<bool>>,
+	body: &mut ResponseBodyBuffer,
+) -> std::io::Result<()> {
+	let mut parser = EventStreamParser::default();
+	let mut response = Some(response);
+
+	let close_reason = 'stream: loop {
+		if let Some(mut current_response) = response.take() {
+			loop {
+				let chunk = tokio::select! {
+					_ = cancellation_token.cancelled() => break 'stream String::from("Stream canceled"),
+					chunk = current_response.chunk() => chunk,
+				};
+
+				let chunk = match chunk {
+					Ok(Some(chunk)) => chunk,
+					Ok(None) => break,
+					Err(error) => {
+						warn!("Event stream error: {error}");
+						break;
+					}
+				};
+
+				body.push(&chunk)?;
+
+				for event in parser.feed(&chunk) {
+					push_event_stream_message(local_request, MessageType::Event(event));
+				}
+
+				*received_response.lock() = true;
+			}
+		}
+
+		let Some(request) = reconnect_request
+			.as_ref()
+			.and_then(|request| request.try_clone())
+		else {
+			break String::from("Stream ended");
+		};
+
+		let retry = parser.retry.unwrap_or(SSE_DEFAULT_RETRY);
+
+		tokio::select! {
+			_ = cancellation_token.cancelled() => break String::from("Stream canceled"),
+			_ = tokio::time::sleep(retry) => {}
+		}
+
+		let request = match &parser.last_event_id {
+			Some(last_event_id) if !last_event_id.is_empty() => {
+				request.header("Last-Event-ID", last_event_id)
+			}
+			_ => request,
+		};
+
+		info!("Reconnecting to the event stream");
+
+		let reconnection = tokio::select! {
+			_ = cancellation_token.cancelled() => break String::from("Stream canceled"),
+			reconnection = send_following_redirects(request, max_redirects) => reconnection,
+		};
+
+		match reconnection {
+			Ok((reconnection, _))
+				if reconnection.status() == StatusCode::OK
+					&& is_event_stream(reconnection.headers()) =>
+			{
+				response = Some(reconnection)
+			}
+			Ok((reconnection, _)) => {
+				break format!(
+					"Stream ended, reconnection answered {}",
+					reconnection.status()
+				);
+			}
+			// Network errors are retried after the delay
+			Err(error) => warn!("Could not reconnect to the event stream: {error}"),
+		}
+	};
+
+	info!("{close_reason}");
+
+	push_event_stream_message(local_request, MessageType::Close(close_reason));
+	*received_response.lock() = true;
+
+	Ok(())
+}
+
+/// Add a message to the request events, dropping the oldest ones over [`SSE_MAX_EVENTS`]
+fn push_event_stream_message(local_request: &Arc<RwLock<Request>>, content: MessageType) {
+	let mut request = local_request.write();
+
+	if let Ok(http_request) = request.get_http_request_mut() {
+		http_request.events.push(Message {
+			timestamp: Local::now(),
+			sender: Sender::Server,
+			content,
+		});
+
+		let overflow = http_request.events.len().saturating_sub(SSE_MAX_EVENTS);
+
+		if overflow > 0 {
+			http_request.events.drain(..overflow);
+			http_request.dropped_events += overflow;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn event(event: &str, data: &str, id: Option<&str>) -> ServerSentEvent {
+		ServerSentEvent {
+			event: event.to_string(),
+			data: data.to_string(),
+			id: id.map(str::to_string),
+		}
+	}
+
+	#[test]
+	fn parses_events_split_across_chunks() {
+		let mut parser = EventStreamParser::default();
+
+		assert!(parser.feed(b"data: hel").is_empty());
+		assert_eq!(
+			parser.feed(b"lo\n\nevent: update\r\nid: 7\r"),
+			vec![event("message", "hello", None)]
+		);
+		assert_eq!(
+			parser.feed(b"\ndata: {\"a\": 1}\r\ndata: second line\r\n\r\n"),
+			vec![event("update", "{\"a\": 1}\nsecond line", Some("7"))]
+		);
+		assert_eq!(parser.last_event_id.as_deref(), Some("7"));
+	}
+
+	#[test]
+	fn parses_comments_retry_and_fields_without_value() {
+		let mut parser = EventStreamParser::default();
+
+		let events =
+			parser.feed(b": keep-alive\nretry: 1500\nretry: 2s\n\ndata\n\ndata:no space\n\n");
+
+		assert_eq!(
+			events,
+			vec![
+				event("message", "", None),
+				event("message", "no space", None)
+			]
+		);
+		assert_eq!(parser.retry, Some(Duration::from_millis(1500)));
+	}
+
+	#[test]
+	fn keeps_the_last_event_id_for_the_next_events() {
+		let mut parser = EventStreamParser::default();
+
+		let events = parser.feed(b"id: 1\ndata: a\n\ndata: b\n\nid\ndata: c\n\n");
+
+		assert_eq!(
+			events,
+			vec![
+				event("message", "a", Some("1")),
+				event("message", "b", Some("1")),
+				event("message", "c", None),
+			]
+		);
+	}
+
+	#[test]
+	fn events_without_data_are_not_dispatched() {
+		let mut parser = EventStreamParser::default();
+
+		assert!(parser.feed(b"event: ping\n\nid: 3\n\n").is_empty());
+		assert_eq!(parser.last_event_id.as_deref(), Some("3"));
+	}
+
+	#[test]
+	fn oldest_events_are_dropped_over_the_limit() {
+		let local_request = Arc::new(RwLock::new(Request::default()));
+
+		for index in 0..SSE_MAX_EVENTS + 5 {
+			push_event_stream_message(
+				&local_request,
+				MessageType::Event(event("message", &index.to_string(), None)),
+			);
+		}
+
+		let request = local_request.read();
+		let http_request = request.get_http_request().unwrap();
+
+		assert_eq!(http_request.events.len(), SSE_MAX_EVENTS);
+		assert_eq!(http_request.dropped_events, 5);
+		assert!(matches!(
+			&http_request.events[0].content,
+			MessageType::Event(event) if event.data == "5"
+		));
+	}
+}
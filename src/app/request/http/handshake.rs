@@ -0,0 +1,402 @@
+//! rustls hooks recording the TLS handshake of the connection a `ConnectionTimer` is opening.
+//!
+//! The hooks have no access to the connection they run for, so they write to the
+//! `OPENING_CONNECTION` thread-local instead. This relies on one invariant: the whole handshake
+//! runs inside polls of the connector future, the TLS stream being only handed over to hyper once
+//! the handshake completed, as hyper-rustls does. `RecordingHandshake` makes the handshake of its
+//! connection the current one of the thread for the duration of each of its polls, so that
+//! connections opened concurrently on the same thread keep their own records. A hook running
+//! outside of these polls records nothing, the connection then having no TLS version, cipher
+//! suite or chain, which `test_tls_handshake_is_recorded_through_the_connection_pool` checks.
+//!
+//! rustls only lets a crypto provider expose the negotiated suite through the suite itself, so the
+//! aws-lc cipher suites are wrapped once in leaked copies whose AEAD records the suite.
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, LazyLock};
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::{
+	ClientSessionMemoryCache, ClientSessionStore, Tls12ClientSessionValue, Tls13ClientSessionValue,
+};
+use rustls::crypto::cipher::{
+	AeadKey, Iv, KeyBlockShape, MessageDecrypter, MessageEncrypter, Tls12AeadAlgorithm,
+	Tls13AeadAlgorithm, UnsupportedOperationError,
+};
+use rustls::crypto::{CryptoProvider, WebPkiSupportedAlgorithms};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{
+	CertificateError, CipherSuiteCommon, ConnectionTrafficSecrets, DigitallySignedStruct,
+	DistinguishedName, NamedGroup, ProtocolVersion, SignatureScheme, SupportedCipherSuite,
+	Tls12CipherSuite, Tls13CipherSuite,
+};
+
+thread_local! {
+	/// Handshake of the connection being polled on this thread, `None` outside of the polls
+	static OPENING_CONNECTION: RefCell<Option<TlsHandshake>> = const { RefCell::new(None) };
+}
+
+/// Sessions remembered by a client, for 256 servers like the rustls default
+const SESSION_CACHE_SIZE: usize = 256;
+
+/// TLS handshake of a connection
+#[derive(Default, Debug, Clone)]
+pub struct TlsHandshake {
+	/// When the ClientHello was written, once the TCP connection was open
+	pub start: Option<Instant>,
+	pub cipher_suite: Option<SupportedCipherSuite>,
+	/// Certificates presented by the server, leaf first
+	pub certificate_chain: Vec<CertificateDer<'static>>,
+}
+
+impl TlsHandshake {
+	/// e.g. `TLSv1.3`
+	pub fn version(&self) -> Option<String> {
+		let version = match self.cipher_suite?.version().version {
+			ProtocolVersion::TLSv1_2 => String::from("TLSv1.2"),
+			ProtocolVersion::TLSv1_3 => String::from("TLSv1.3"),
+			version => format!("{version:?}"),
+		};
+
+		Some(version)
+	}
+
+	/// IANA name of the cipher suite, e.g. `TLS13_AES_128_GCM_SHA256`
+	pub fn cipher_suite_name(&self) -> Option<String> {
+		self.cipher_suite
+			.map(|cipher_suite| format!("{:?}", cipher_suite.suite()))
+	}
+}
+
+fn record(update: impl FnOnce(&mut TlsHandshake)) {
+	OPENING_CONNECTION.with_borrow_mut(|handshake| {
+		if let Some(handshake) = handshake {
+			update(handshake);
+		}
+	});
+}
+
+/// Mark the start of the handshake, at the first call
+fn record_start() {
+	record(|handshake| {
+		handshake.start.get_or_insert_with(Instant::now);
+	});
+}
+
+/// Future opening a connection, returned with its TLS handshake, see the module documentation.
+/// The handshake has no start for plain HTTP connections.
+pub struct RecordingHandshake<F> {
+	inner: Pin<Box<F>>,
+	handshake: Option<TlsHandshake>,
+}
+
+impl<F> RecordingHandshake<F> {
+	pub fn new(inner: F) -> Self {
+		RecordingHandshake {
+			inner: Box::pin(inner),
+			handshake: Some(TlsHandshake::default()),
+		}
+	}
+}
+
+impl<F: Future> Future for RecordingHandshake<F> {
+	type Output = (F::Output, TlsHandshake);
+
+	fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+		let this = self.get_mut();
+
+		let outer_handshake = OPENING_CONNECTION.replace(this.handshake.take());
+		let poll = this.inner.as_mut().poll(context);
+		this.handshake = OPENING_CONNECTION.replace(outer_handshake);
+
+		poll.map(|output| (output, this.handshake.take().unwrap_or_default()))
+	}
+}
+
+/// Default crypto provider, whose cipher suites record the one negotiated by the handshakes
+pub fn recording_crypto_provider() -> CryptoProvider {
+	CryptoProvider {
+		cipher_suites: RECORDING_CIPHER_SUITES.clone(),
+		..rustls::crypto::aws_lc_rs::default_provider()
+	}
+}
+
+/// Built once, as rustls needs static cipher suites
+static RECORDING_CIPHER_SUITES: LazyLock<Vec<SupportedCipherSuite>> = LazyLock::new(|| {
+	rustls::crypto::aws_lc_rs::default_provider()
+		.cipher_suites
+		.into_iter()
+		.map(recording_cipher_suite)
+		.collect()
+});
+
+/// Same cipher suite, recorded when its keys are derived
+fn recording_cipher_suite(cipher_suite: SupportedCipherSuite) -> SupportedCipherSuite {
+	let common = |common: &CipherSuiteCommon| CipherSuiteCommon {
+		suite: common.suite,
+		hash_provider: common.hash_provider,
+		confidentiality_limit: common.confidentiality_limit,
+	};
+
+	match cipher_suite {
+		SupportedCipherSuite::Tls12(suite) => {
+			SupportedCipherSuite::Tls12(Box::leak(Box::new(Tls12CipherSuite {
+				common: common(&suite.common),
+				prf_provider: suite.prf_provider,
+				kx: suite.kx,
+				sign: suite.sign,
+				aead_alg: Box::leak(Box::new(RecordingAead {
+					inner: suite.aead_alg,
+					cipher_suite,
+				})),
+			})))
+		}
+		SupportedCipherSuite::Tls13(suite) => {
+			SupportedCipherSuite::Tls13(Box::leak(Box::new(Tls13CipherSuite {
+				common: common(&suite.common),
+				hkdf_provider: suite.hkdf_provider,
+				aead_alg: Box::leak(Box::new(RecordingAead {
+					inner: suite.aead_alg,
+					cipher_suite,
+				})),
+				quic: suite.quic,
+			})))
+		}
+	}
+}
+
+struct RecordingAead<A: ?Sized + 'static> {
+	inner: &'static A,
+	cipher_suite: SupportedCipherSuite,
+}
+
+impl<A: ?Sized> RecordingAead<A> {
+	fn record_cipher_suite(&self) {
+		record(|handshake| handshake.cipher_suite = Some(self.cipher_suite));
+	}
+}
+
+impl Tls13AeadAlgorithm for RecordingAead<dyn Tls13AeadAlgorithm> {
+	fn encrypter(&self, key: AeadKey, iv: Iv) -> Box<dyn MessageEncrypter> {
+		self.inner.encrypter(key, iv)
+	}
+
+	fn decrypter(&self, key: AeadKey, iv: Iv) -> Box<dyn MessageDecrypter> {
+		self.record_cipher_suite();
+		self.inner.decrypter(key, iv)
+	}
+
+	fn key_len(&self) -> usize {
+		self.inner.key_len()
+	}
+
+	fn extract_keys(
+		&self,
+		key: AeadKey,
+		iv: Iv,
+	) -> Result<ConnectionTrafficSecrets, UnsupportedOperationError> {
+		self.inner.extract_keys(key, iv)
+	}
+
+	fn fips(&self) -> bool {
+		self.inner.fips()
+	}
+}
+
+impl Tls12AeadAlgorithm for RecordingAead<dyn Tls12AeadAlgorithm> {
+	fn encrypter(&self, key: AeadKey, iv: &[u8], extra: &[u8]) -> Box<dyn MessageEncrypter> {
+		self.inner.encrypter(key, iv, extra)
+	}
+
+	fn decrypter(&self, key: AeadKey, iv: &[u8]) -> Box<dyn MessageDecrypter> {
+		self.record_cipher_suite();
+		self.inner.decrypter(key, iv)
+	}
+
+	fn key_block_shape(&self) -> KeyBlockShape {
+		self.inner.key_block_shape()
+	}
+
+	fn extract_keys(
+		&self,
+		key: AeadKey,
+		iv: &[u8],
+		explicit: &[u8],
+	) -> Result<ConnectionTrafficSecrets, UnsupportedOperationError> {
+		self.inner.extract_keys(key, iv, explicit)
+	}
+
+	fn fips(&self) -> bool {
+		self.inner.fips()
+	}
+}
+
+/// Session cache marking the start of the handshakes, rustls looking for a session to resume
+/// before writing the ClientHello
+#[derive(Debug)]
+pub struct RecordingSessionStore(ClientSessionMemoryCache);
+
+impl Default for RecordingSessionStore {
+	fn default() -> Self {
+		RecordingSessionStore(ClientSessionMemoryCache::new(SESSION_CACHE_SIZE))
+	}
+}
+
+impl ClientSessionStore for RecordingSessionStore {
+	fn set_kx_hint(&self, server_name: ServerName<'static>, group: NamedGroup) {
+		self.0.set_kx_hint(server_name, group)
+	}
+
+	fn kx_hint(&self, server_name: &ServerName<'_>) -> Option<NamedGroup> {
+		record_start();
+		self.0.kx_hint(server_name)
+	}
+
+	fn set_tls12_session(&self, server_name: ServerName<'static>, value: Tls12ClientSessionValue) {
+		self.0.set_tls12_session(server_name, value)
+	}
+
+	fn tls12_session(&self, server_name: &ServerName<'_>) -> Option<Tls12ClientSessionValue> {
+		record_start();
+		self.0.tls12_session(server_name)
+	}
+
+	fn remove_tls12_session(&self, server_name: &ServerName<'static>) {
+		self.0.remove_tls12_session(server_name)
+	}
+
+	fn insert_tls13_ticket(
+		&self,
+		server_name: ServerName<'static>,
+		value: Tls13ClientSessionValue,
+	) {
+		self.0.insert_tls13_ticket(server_name, value)
+	}
+
+	fn take_tls13_ticket(
+		&self,
+		server_name: &ServerName<'static>,
+	) -> Option<Tls13ClientSessionValue> {
+		record_start();
+		self.0.take_tls13_ticket(server_name)
+	}
+}
+
+/// Verifier recording the certificate chain presented by the server
+#[derive(Debug)]
+pub struct RecordingVerifier {
+	inner: Arc<dyn ServerCertVerifier>,
+	accept_invalid_hostnames: bool,
+}
+
+impl RecordingVerifier {
+	pub fn new(inner: Arc<dyn ServerCertVerifier>, accept_invalid_hostnames: bool) -> Self {
+		RecordingVerifier {
+			inner,
+			accept_invalid_hostnames,
+		}
+	}
+}
+
+impl ServerCertVerifier for RecordingVerifier {
+	fn verify_server_cert(
+		&self,
+		end_entity: &CertificateDer<'_>,
+		intermediates: &[CertificateDer<'_>],
+		server_name: &ServerName<'_>,
+		ocsp_response: &[u8],
+		now: UnixTime,
+	) -> Result<ServerCertVerified, rustls::Error> {
+		record(|handshake| {
+			handshake.certificate_chain = std::iter::once(end_entity)
+				.chain(intermediates)
+				.map(|certificate| certificate.clone().into_owned())
+				.collect();
+		});
+
+		let verified = self.inner.verify_server_cert(
+			end_entity,
+			intermediates,
+			server_name,
+			ocsp_response,
+			now,
+		);
+
+		match verified {
+			Err(rustls::Error::InvalidCertificate(
+				CertificateError::NotValidForName | CertificateError::NotValidForNameContext { .. },
+			)) if self.accept_invalid_hostnames => Ok(ServerCertVerified::assertion()),
+			verified => verified,
+		}
+	}
+
+	fn verify_tls12_signature(
+		&self,
+		message: &[u8],
+		cert: &CertificateDer<'_>,
+		dss: &DigitallySignedStruct,
+	) -> Result<HandshakeSignatureValid, rustls::Error> {
+		self.inner.verify_tls12_signature(message, cert, dss)
+	}
+
+	fn verify_tls13_signature(
+		&self,
+		message: &[u8],
+		cert: &CertificateDer<'_>,
+		dss: &DigitallySignedStruct,
+	) -> Result<HandshakeSignatureValid, rustls::Error> {
+		self.inner.verify_tls13_signature(message, cert, dss)
+	}
+
+	fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+		self.inner.supported_verify_schemes()
+	}
+
+	fn root_hint_subjects(&self) -> Option<&[DistinguishedName]> {
+		self.inner.root_hint_subjects()
+	}
+}
+
+/// Verifier of requests accepting invalid certificates, which still checks the handshake
+/// signatures
+#[derive(Debug)]
+pub struct AcceptAnyCertificate(pub WebPkiSupportedAlgorithms);
+
+impl ServerCertVerifier for AcceptAnyCertificate {
+	fn verify_server_cert(
+		&self,
+		_end_entity: &CertificateDer<'_>,
+		_intermediates: &[CertificateDer<'_>],
+		_server_name: &ServerName<'_>,
+		_ocsp_response: &[u8],
+		_now: UnixTime,
+	) -> Result<ServerCertVerified, rustls::Error> {
+		Ok(ServerCertVerified::assertion())
+	}
+
+	fn verify_tls12_signature(
+		&self,
+		message: &[u8],
+		cert: &CertificateDer<'_>,
+		dss: &DigitallySignedStruct,
+	) -> Result<HandshakeSignatureValid, rustls::Error> {
+		rustls::crypto::verify_tls12_signature(message, cert, dss, &self.0)
+	}
+
+	fn verify_tls13_signature(
+		&self,
+		message: &[u8],
+		cert: &CertificateDer<'_>,
+		dss: &DigitallySignedStruct,
+	) -> Result<HandshakeSignatureValid, rustls::Error> {
+		rustls::crypto::verify_tls13_signature(message, cert, dss, &self.0)
+	}
+
+	fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+		self.0.supported_schemes()
+	}
+}
@@ -1,3 +1,9 @@
 pub(crate) mod body;
+pub mod certificate;
+pub mod event_stream;
+pub mod handshake;
 pub(crate) mod method;
+pub mod redirect;
+pub mod response_body;
 pub mod send;
+pub mod timing;
@@ -0,0 +1,223 @@
+use reqwest::header::{
+	AUTHORIZATION, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_TYPE, COOKIE, HeaderMap, LOCATION,
+	PROXY_AUTHORIZATION, TRANSFER_ENCODING, WWW_AUTHENTICATE,
+};
+use reqwest::{Method, StatusCode, Url};
+use tracing::{info, warn};
+
+use crate::models::response::RedirectHop;
+
+/// Send a request with a client which does not follow redirects itself, following at most
+/// `max_redirects` of them. Each redirect response is recorded as a hop.
+///
+/// The last redirect response is returned as is when the limit is reached, or when it cannot be
+/// followed, e.g. without `Location` header or with a body that cannot be sent again.
+pub async fn send_following_redirects(
+	mut request_builder: reqwest_middleware::RequestBuilder,
+	max_redirects: usize,
+) -> reqwest_middleware::Result<(reqwest::Response, Vec<RedirectHop>)> {
+	let extensions = std::mem::take(request_builder.extensions());
+	let (client, request) = request_builder.build_split();
+
+	let mut request = request?;
+	let mut hops = vec![];
+
+	loop {
+		let method = request.method().clone();
+		let url = request.url().clone();
+		let headers = request.headers().clone();
+		let has_body = request.body().is_some();
+		let body = request
+			.try_clone()
+			.and_then(|mut copy| copy.body_mut().take());
+		let timeout = request.timeout().copied();
+		let version = request.version();
+
+		let response = client
+			.execute_with_extensions(request, &mut extensions.clone())
+			.await?;
+
+		let status = response.status();
+
+		let Some(next_method) = redirect_method(status, &method) else {
+			return Ok((response, hops));
+		};
+
+		if hops.len() >= max_redirects {
+			info!("Max redirects reached, stopped at {url}");
+			return Ok((response, hops));
+		}
+
+		let Some(next_url) = redirect_location(&url, response.headers()) else {
+			warn!("Could not follow the redirect of {url}, invalid or missing location");
+			return Ok((response, hops));
+		};
+
+		// The body is only sent again by 307 and 308 redirects
+		let keeps_body = matches!(
+			status,
+			StatusCode::TEMPORARY_REDIRECT | StatusCode::PERMANENT_REDIRECT
+		);
+
+		if keeps_body && has_body && body.is_none() {
+			warn!("Could not follow the redirect of {url}, the streamed body cannot be sent again");
+			return Ok((response, hops));
+		}
+
+		info!("Redirected to {next_url}");
+
+		hops.push(RedirectHop {
+			method: method.to_string(),
+			url: url.to_string(),
+			status_code: status.to_string(),
+			headers: response
+				.headers()
+				.iter()
+				.map(|(header_name, header_value)| {
+					(
+						header_name.to_string(),
+						header_value.to_str().unwrap_or("").to_string(),
+					)
+				})
+				.collect(),
+			location: next_url.to_string(),
+		});
+
+		let mut headers = headers;
+
+		if !keeps_body {
+			for header_name in [
+				CONTENT_TYPE,
+				CONTENT_LENGTH,
+				CONTENT_ENCODING,
+				TRANSFER_ENCODING,
+			] {
+				headers.remove(header_name);
+			}
+		}
+
+		// Credentials are not sent to another host, the cookie store adds the ones of the new host
+		if is_cross_origin(&url, &next_url) {
+			for header_name in [AUTHORIZATION, COOKIE, PROXY_AUTHORIZATION, WWW_AUTHENTICATE] {
+				headers.remove(header_name);
+			}
+		}
+
+		let mut next_request = reqwest::Request::new(next_method, next_url);
+		*next_request.headers_mut() = headers;
+		*next_request.timeout_mut() = timeout;
+		*next_request.version_mut() = version;
+
+		if keeps_body {
+			*next_request.body_mut() = body;
+		}
+
+		request = next_request;
+	}
+}
+
+/// Method of the request following a redirect response, `None` when the status is not a redirect.
+/// 301, 302 and 303 redirects are followed with a `GET`, like browsers do.
+fn redirect_method(status: StatusCode, method: &Method) -> Option<Method> {
+	match status {
+		StatusCode::MOVED_PERMANENTLY | StatusCode::FOUND | StatusCode::SEE_OTHER => {
+			match *method == Method::HEAD {
+				true => Some(Method::HEAD),
+				false => Some(Method::GET),
+			}
+		}
+		StatusCode::TEMPORARY_REDIRECT | StatusCode::PERMANENT_REDIRECT => Some(method.clone()),
+		_ => None,
+	}
+}
+
+/// HTTP or HTTPS URL of the `Location` header, resolved from the redirected URL
+fn redirect_location(url: &Url, headers: &HeaderMap) -> Option<Url> {
+	let location = headers.get(LOCATION)?.to_str().ok()?;
+	let location = url.join(location).ok()?;
+
+	match location.scheme() {
+		"http" | "https" => Some(location),
+		_ => None,
+	}
+}
+
+fn is_cross_origin(url: &Url, next_url: &Url) -> bool {
+	url.host_str() != next_url.host_str()
+		|| url.port_or_known_default() != next_url.port_or_known_default()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reqwest::header::HeaderValue;
+
+	#[test]
+	fn redirects_change_the_method_like_browsers() {
+		let redirected_methods = [
+			(StatusCode::MOVED_PERMANENTLY, Method::POST, Method::GET),
+			(StatusCode::FOUND, Method::PUT, Method::GET),
+			(StatusCode::SEE_OTHER, Method::HEAD, Method::HEAD),
+			(StatusCode::TEMPORARY_REDIRECT, Method::POST, Method::POST),
+			(
+				StatusCode::PERMANENT_REDIRECT,
+				Method::DELETE,
+				Method::DELETE,
+			),
+		];
+
+		for (status, method, redirected_method) in redirected_methods {
+			assert_eq!(redirect_method(status, &method), Some(redirected_method));
+		}
+
+		assert_eq!(
+			redirect_method(StatusCode::NOT_MODIFIED, &Method::GET),
+			None
+		);
+		assert_eq!(redirect_method(StatusCode::OK, &Method::GET), None);
+	}
+
+	#[test]
+	fn locations_are_resolved_from_the_redirected_url() {
+		let url = Url::parse("http://localhost:8080/a/b?c=d").unwrap();
+		let location = |location: &str| {
+			let mut headers = HeaderMap::new();
+			headers.insert(LOCATION, HeaderValue::from_str(location).unwrap());
+
+			redirect_location(&url, &headers).map(|location| location.to_string())
+		};
+
+		assert_eq!(
+			location("/login").as_deref(),
+			Some("http://localhost:8080/login")
+		);
+		assert_eq!(
+			location("next").as_deref(),
+			Some("http://localhost:8080/a/next")
+		);
+		assert_eq!(
+			location("https://example.com/").as_deref(),
+			Some("https://example.com/")
+		);
+		assert_eq!(location("ftp://example.com/"), None);
+		assert_eq!(redirect_location(&url, &HeaderMap::new()), None);
+	}
+
+	#[test]
+	fn other_hosts_and_ports_are_cross_origin() {
+		let url = Url::parse("http://localhost:8080/").unwrap();
+
+		assert!(!is_cross_origin(
+			&url,
+			&Url::parse("http://localhost:8080/other").unwrap()
+		));
+		assert!(is_cross_origin(
+			&url,
+			&Url::parse("http://localhost:8081/").unwrap()
+		));
+		assert!(is_cross_origin(
+			&url,
+			&Url::parse("http://example.com:8080/").unwrap()
+		));
+	}
+}
@@ -0,0 +1,157 @@
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+
+/// Response body read chunk by chunk. Only the first `max_size` bytes are kept in memory, the
+/// whole body being written to the output file when there is one.
+pub struct ResponseBodyBuffer {
+	pub data: Vec<u8>,
+	/// Size of the whole body received so far
+	pub size: u64,
+	max_size: usize,
+	output: Option<File>,
+}
+
+impl ResponseBodyBuffer {
+	pub fn new(max_size: usize, output: Option<&Path>) -> io::Result<Self> {
+		let output = match output {
+			None => None,
+			Some(path) => Some(File::create(path)?),
+		};
+
+		Ok(ResponseBodyBuffer {
+			data: vec![],
+			size: 0,
+			max_size,
+			output,
+		})
+	}
+
+	pub fn push(&mut self, chunk: &[u8]) -> io::Result<()> {
+		if let Some(output) = &mut self.output {
+			output.write_all(chunk)?;
+		}
+
+		let kept = chunk
+			.len()
+			.min(self.max_size.saturating_sub(self.data.len()));
+		self.data.extend_from_slice(&chunk[..kept]);
+		self.size += chunk.len() as u64;
+
+		Ok(())
+	}
+
+	pub fn is_truncated(&self) -> bool {
+		self.size > self.data.len() as u64
+	}
+
+	pub fn finish(&mut self) -> io::Result<()> {
+		match &mut self.output {
+			None => Ok(()),
+			Some(output) => output.flush(),
+		}
+	}
+}
+
+/// Decode a body as UTF-8 text. A truncated body may end in the middle of a character, which is
+/// then left out.
+pub fn decode_text_body(bytes: &[u8], is_truncated: bool) -> Option<String> {
+	match std::str::from_utf8(bytes) {
+		Ok(text) => Some(text.to_string()),
+		Err(error) if is_truncated && error.error_len().is_none() => {
+			Some(String::from_utf8_lossy(&bytes[..error.valid_up_to()]).to_string())
+		}
+		Err(_) => None,
+	}
+}
+
+/// Canonical hex and ASCII view of binary content, 16 bytes per line, as `hexdump -C` prints it
+pub fn hexdump(bytes: &[u8]) -> String {
+	let mut dump = String::new();
+
+	for (index, line) in bytes.chunks(16).enumerate() {
+		let _ = write!(dump, "{:08x} ", index * 16);
+
+		for column in 0..16 {
+			if column == 8 {
+				dump.push(' ');
+			}
+
+			match line.get(column) {
+				Some(byte) => {
+					let _ = write!(dump, " {byte:02x}");
+				}
+				None => dump.push_str("   "),
+			}
+		}
+
+		dump.push_str("  |");
+		dump.extend(
+			line.iter()
+				.map(|byte| match byte.is_ascii_graphic() || *byte == b' ' {
+					true => *byte as char,
+					false => '.',
+				}),
+		);
+		dump.push_str("|\n");
+	}
+
+	dump
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn buffer_keeps_at_most_max_size_bytes() {
+		let mut buffer = ResponseBodyBuffer::new(5, None).unwrap();
+		buffer.push(b"abc").unwrap();
+		assert!(!buffer.is_truncated());
+
+		buffer.push(b"defgh").unwrap();
+		buffer.push(b"ijk").unwrap();
+		assert_eq!(buffer.data, b"abcde");
+		assert_eq!(buffer.size, 11);
+		assert!(buffer.is_truncated());
+	}
+
+	#[test]
+	fn buffer_writes_the_whole_body_to_the_output() {
+		let path = std::env::temp_dir().join(format!("squrl-body-{}", std::process::id()));
+
+		let mut buffer = ResponseBodyBuffer::new(2, Some(&path)).unwrap();
+		buffer.push(b"hello ").unwrap();
+		buffer.push(b"world").unwrap();
+		buffer.finish().unwrap();
+
+		assert_eq!(buffer.data, b"he");
+		assert_eq!(std::fs::read(&path).unwrap(), b"hello world");
+		std::fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn decode_text_body_drops_a_cut_character_of_a_truncated_body() {
+		let bytes = "café".as_bytes();
+		let cut = &bytes[..bytes.len() - 1];
+
+		assert_eq!(decode_text_body(bytes, false).as_deref(), Some("café"));
+		assert_eq!(decode_text_body(cut, true).as_deref(), Some("caf"));
+		assert_eq!(decode_text_body(cut, false), None);
+		assert_eq!(decode_text_body(&[0xff, 0x00, 0x41], true), None);
+	}
+
+	#[test]
+	fn hexdump_prints_offsets_hex_and_ascii() {
+		let bytes: Vec<u8> = (0x41..0x41 + 18).chain([0x00, 0xff]).collect();
+
+		assert_eq!(
+			hexdump(&bytes),
+			"00000000  41 42 43 44 45 46 47 48  49 4a 4b 4c 4d 4e 4f 50  |ABCDEFGHIJKLMNOP|\n\
+			 00000010  51 52 00 ff                                       |QR..|\n"
+		);
+		assert_eq!(hexdump(&[]), "");
+	}
+}
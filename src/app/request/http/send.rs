@@ -1,30 +1,69 @@
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
+use std::path::Path;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use crate::app::App;
+use crate::app::request::assertions::evaluate_assertions;
+use crate::app::request::captures::evaluate_captures;
+use crate::app::request::http::certificate::summarize_certificate;
+use crate::app::request::http::event_stream::{is_event_stream, read_event_stream};
+use crate::app::request::http::handshake::TlsHandshake;
+use crate::app::request::http::redirect::send_following_redirects;
+use crate::app::request::http::response_body::{ResponseBodyBuffer, decode_text_body, hexdump};
+use crate::app::request::http::timing::ConnectionTimer;
 use crate::app::request::send::RequestResponseError;
-use crate::app::request::send::RequestResponseError::CouldNotDecodeResponse;
+use crate::app::request::send::RequestResponseError::{
+	CouldNotDecodeResponse, CouldNotWriteResponseBody,
+};
 use crate::models::environment::Environment;
 use crate::models::protocol::http::body::find_file_format_in_content_type;
 use crate::models::request::Request;
-use crate::models::response::{ImageResponse, RequestResponse, ResponseContent};
+use crate::models::response::{
+	ConnectionInfo, DownloadProgress, ImageResponse, RedirectHop, RequestResponse, ResponseContent,
+};
 use rayon::prelude::*;
 use reqwest::header::CONTENT_TYPE;
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info, trace};
 
+/// Send an HTTP request. At most `max_body_size` bytes of the response body are kept, the whole
+/// body being streamed to `output` when given.
+///
+/// A `text/event-stream` response to an HTTP request is read until the request is canceled, its
+/// events being added to the request messages as they arrive.
+///
+/// The response is timed with the `connection_timer` of the client sending the request.
+///
+/// Redirects are followed here up to the request max redirects setting, each one being recorded.
+/// The client sending the request must not follow them itself.
 pub async fn send_http_request(
 	prepared_request: reqwest_middleware::RequestBuilder,
+	connection_timer: ConnectionTimer,
 	local_request: Arc<RwLock<Request>>,
 	env: &Option<Arc<RwLock<Environment>>>,
+	received_response: Arc<Mutex<bool>>,
+	max_body_size: usize,
+	output: Option<&Path>,
 ) -> Result<RequestResponse, RequestResponseError> {
 	info!("Sending request");
 
 	// Extract the values we need from the lock, then drop it before any await.
-	let (cancellation_token, timeout_ms, pretty_print) = {
+	let (cancellation_token, timeout_ms, pretty_print, max_redirects, can_stream_events) = {
 		let mut request = local_request.write();
 		request.is_pending = true;
+		request.download_progress = None;
+
+		let can_stream_events = match request.get_http_request_mut() {
+			Ok(http_request) => {
+				http_request.events.clear();
+				http_request.dropped_events = 0;
+				http_request.is_event_stream = false;
+				true
+			}
+			Err(_) => false,
+		};
+
 		let cancellation_token = request.cancellation_token.clone();
 		let timeout_ms = request.settings.timeout.as_u32().unwrap_or(30000) as u64;
 		let pretty_print = request
@@ -32,14 +71,29 @@ pub async fn send_http_request(
 			.pretty_print_response_content
 			.as_bool()
 			.unwrap_or(true);
-		(cancellation_token, timeout_ms, pretty_print)
+		let max_redirects = match request.settings.allow_redirects.as_bool().unwrap_or(true) {
+			true => request.settings.max_redirects.as_u32().unwrap_or(10) as usize,
+			false => 0,
+		};
+		(
+			cancellation_token,
+			timeout_ms,
+			pretty_print,
+			max_redirects,
+			can_stream_events,
+		)
 	};
 	// Write guard is dropped here — safe to await
 
+	// Event streams are sent again when their connection ends
+	let reconnect_request = prepared_request.try_clone();
+
 	let timeout = tokio::time::sleep(Duration::from_millis(timeout_ms));
 
 	let request_start = Instant::now();
 	let elapsed_time: Duration;
+	let mut connection: Option<ConnectionInfo> = None;
+	let mut redirects: Vec<RedirectHop> = vec![];
 
 	let mut response = tokio::select! {
 		_ = cancellation_token.cancelled() => {
@@ -50,7 +104,8 @@ pub async fn send_http_request(
 				status_code: Some(String::from("CANCELED")),
 				content: None,
 				cookies: None,
-				headers: vec![]
+				headers: vec![],
+				..Default::default()
 			}
 		},
 		_ = timeout => {
@@ -61,15 +116,20 @@ pub async fn send_http_request(
 				status_code: Some(String::from("TIMEOUT")),
 				content: None,
 				cookies: None,
-				headers: vec![]
+				headers: vec![],
+				..Default::default()
 			}
 		},
-		response = prepared_request.send() => match response {
-			Ok(response) => {
+		response = send_following_redirects(prepared_request, max_redirects) => match response {
+			Ok((response, hops)) => {
 				info!("Response received");
 
+				redirects = hops;
+
 				elapsed_time = request_start.elapsed();
 
+				connection = Some(connection_info(&response, &connection_timer));
+
 				let status_code = response.status().to_string();
 
 				let mut is_image = false;
@@ -95,46 +155,105 @@ pub async fn send_http_request(
 					.collect::<Vec<String>>()
 					.join("\n");
 
-				let response_content = match is_image {
-					true => match response.bytes().await {
-						Ok(content) => {
-							let image = image::load_from_memory(content.as_ref());
+				let body = match can_stream_events && is_event_stream(response.headers()) {
+					true => {
+						info!("Event stream opened");
 
-							ResponseContent::Image(ImageResponse {
-								data: content.to_vec(),
-								image: image.ok(),
-							})
+						// The status and headers are shown while the events are received
+						{
+							let mut request = local_request.write();
+							request.response = RequestResponse {
+								status_code: Some(status_code.clone()),
+								cookies: Some(cookies.clone()),
+								headers: headers.clone(),
+								redirects: redirects.clone(),
+								..Default::default()
+							};
+							if let Ok(http_request) = request.get_http_request_mut() {
+								http_request.is_event_stream = true;
+							}
 						}
-						Err(_) => return Err(CouldNotDecodeResponse)
-					},
-					false => match response.bytes().await {
-						Ok(bytes) => match String::from_utf8(bytes.to_vec()) {
-							Ok(mut result_body) => {
-								// If a file format has been found in the content-type header
-								if let Some(file_format) = find_file_format_in_content_type(&headers) {
-									// If the request response content can be pretty printed
-									if pretty_print {
-										// Match the file format
-										if file_format.as_str() == "json" {
-														  result_body = jsonxf::pretty_print(&result_body).unwrap_or(result_body);
-													  }
-									}
-								}
 
-								ResponseContent::Body(result_body)
-							},
-							Err(_) => ResponseContent::Body(format!("{:#X?}", bytes))
-						},
-						Err(_) => return Err(CouldNotDecodeResponse)
+						*received_response.lock() = true;
+
+						let mut body = ResponseBodyBuffer::new(max_body_size, output)
+							.map_err(write_error(output))?;
+
+						read_event_stream(
+							response,
+							reconnect_request,
+							max_redirects,
+							&local_request,
+							&cancellation_token,
+							&received_response,
+							&mut body,
+						)
+						.await
+						.and_then(|_| body.finish())
+						.map_err(write_error(output))?;
+
+						Some(body)
 					}
+					false => read_response_body(
+						response,
+						&local_request,
+						&cancellation_token,
+						max_body_size,
+						output,
+					)
+					.await?,
 				};
 
-				RequestResponse {
-					duration: None,
-					status_code: Some(status_code),
-					content: Some(response_content),
-					cookies: Some(cookies),
-					headers
+				match body {
+					// Canceled while the body was downloaded
+					None => RequestResponse {
+						status_code: Some(String::from("CANCELED")),
+						..Default::default()
+					},
+					Some(body) => {
+						let is_body_truncated = body.is_truncated();
+
+						let response_content = match is_image {
+							true => {
+								let image = image::load_from_memory(&body.data);
+
+								ResponseContent::Image(ImageResponse {
+									data: body.data.clone(),
+									image: image.ok(),
+								})
+							}
+							false => match decode_text_body(&body.data, is_body_truncated) {
+								Some(mut result_body) => {
+									// If a file format has been found in the content-type header
+									if let Some(file_format) = find_file_format_in_content_type(&headers) {
+										// If the request response content can be pretty printed
+										if pretty_print {
+											// Match the file format
+											if file_format.as_str() == "json" {
+												result_body = jsonxf::pretty_print(&result_body).unwrap_or(result_body);
+											}
+										}
+									}
+
+									ResponseContent::Body(result_body)
+								},
+								// Binary content
+								None => ResponseContent::Body(hexdump(&body.data))
+							}
+						};
+
+						RequestResponse {
+							duration: None,
+							status_code: Some(status_code),
+							content: Some(response_content),
+							cookies: Some(cookies),
+							headers,
+							body_size: Some(body.size),
+							is_body_truncated,
+							raw_body: body.data,
+							..Default::default()
+						}
+					}
 				}
 			},
 			Err(error) => {
@@ -157,7 +276,8 @@ pub async fn send_http_request(
 					status_code: response_status_code,
 					content: Some(result_body),
 					cookies: None,
-					headers: vec![]
+					headers: vec![],
+					..Default::default()
 				}
 			}
 		}
@@ -165,23 +285,116 @@ pub async fn send_http_request(
 
 	response.duration = Some(format!("{:?}", elapsed_time));
 
+	// Only timed when a response was received, the body included
+	if connection.is_some() {
+		response.timing = Some(connection_timer.response_timing(
+			request_start,
+			elapsed_time,
+			request_start.elapsed(),
+		));
+		response.connection = connection;
+	}
+
+	response.redirects = redirects;
+
 	trace!("Request sent");
 
 	/* POST-REQUEST SCRIPT */
 
+	// The script sees the decoded content, the received bytes are kept as they were
+	let raw_body = std::mem::take(&mut response.raw_body);
+
 	// Re-acquire a read guard only for the post-request script.
 	let request = local_request.read();
-	let (modified_response, post_request_output) =
+	let (mut modified_response, post_request_output, test_results) =
 		App::handle_post_request_script(&request, response, env)?;
 	drop(request);
 
+	modified_response.raw_body = raw_body;
+
 	{
 		let mut request = local_request.write();
 
 		request.console_output.post_request_output = post_request_output;
+		request.test_results = test_results;
+		request.assertion_results =
+			evaluate_assertions(&request.assertions, &modified_response, elapsed_time);
+		request.capture_results = evaluate_captures(&request.captures, &modified_response);
 		request.is_pending = false;
+		request.download_progress = None;
 		request.cancellation_token = CancellationToken::new();
 	}
 
 	Ok(modified_response)
 }
+
+/// Read the response body chunk by chunk, updating the download progress of the request. Returns
+/// `None` when the request is canceled during the download.
+async fn read_response_body(
+	mut response: reqwest::Response,
+	local_request: &Arc<RwLock<Request>>,
+	cancellation_token: &CancellationToken,
+	max_body_size: usize,
+	output: Option<&Path>,
+) -> Result<Option<ResponseBodyBuffer>, RequestResponseError> {
+	let mut body = ResponseBodyBuffer::new(max_body_size, output).map_err(write_error(output))?;
+	let mut progress = DownloadProgress {
+		received: 0,
+		total: response.content_length(),
+	};
+
+	loop {
+		let chunk = tokio::select! {
+			_ = cancellation_token.cancelled() => return Ok(None),
+			chunk = response.chunk() => chunk.map_err(|_| CouldNotDecodeResponse)?,
+		};
+
+		let Some(chunk) = chunk else {
+			break;
+		};
+
+		body.push(&chunk).map_err(write_error(output))?;
+
+		progress.received = body.size;
+		local_request.write().download_progress = Some(progress);
+	}
+
+	body.finish().map_err(write_error(output))?;
+
+	Ok(Some(body))
+}
+
+fn connection_info(
+	response: &reqwest::Response,
+	connection_timer: &ConnectionTimer,
+) -> ConnectionInfo {
+	let leaf_certificate = response
+		.extensions()
+		.get::<reqwest::tls::TlsInfo>()
+		.and_then(|tls_info| tls_info.peer_certificate());
+
+	let handshake = leaf_certificate.and_then(|leaf| connection_timer.tls_handshake(leaf));
+
+	// The chain is only known when the certificates were verified during the handshake
+	let certificate_chain = handshake
+		.iter()
+		.flat_map(|handshake| handshake.certificate_chain.iter().skip(1))
+		.filter_map(|certificate| summarize_certificate(certificate))
+		.collect();
+
+	ConnectionInfo {
+		http_version: format!("{:?}", response.version()),
+		remote_address: response.remote_addr().map(|address| address.to_string()),
+		tls_version: handshake.as_ref().and_then(TlsHandshake::version),
+		cipher_suite: handshake.as_ref().and_then(TlsHandshake::cipher_suite_name),
+		certificate: leaf_certificate.and_then(summarize_certificate),
+		certificate_chain,
+	}
+}
+
+fn write_error(output: Option<&Path>) -> impl Fn(std::io::Error) -> RequestResponseError {
+	move |error| {
+		let path = output.map(|path| path.display().to_string());
+		CouldNotWriteResponseBody(path.unwrap_or_default(), error)
+	}
+}
@@ -0,0 +1,253 @@
+use parking_lot::Mutex;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tower::{Layer, Service};
+
+use crate::app::request::http::handshake::{RecordingHandshake, TlsHandshake};
+use crate::models::response::ResponseTiming;
+
+#[derive(Default)]
+struct ConnectionEvents {
+	/// Start and end of the last DNS lookup
+	dns_lookup: Option<(Instant, Instant)>,
+	/// Start and end of the last connection, DNS lookup and TLS handshake included
+	connect: Option<(Instant, Instant)>,
+	/// TLS handshakes of the connections, in the order they were opened
+	handshakes: Vec<TlsHandshake>,
+}
+
+/// Records when a client resolves a host and opens a connection, being both its DNS resolver and a
+/// layer of its connector
+#[derive(Clone, Default)]
+pub struct ConnectionTimer(Arc<Mutex<ConnectionEvents>>);
+
+impl ConnectionTimer {
+	/// Phases of a request sent at `request_start`, which received the first byte of its response
+	/// and its whole body after the given durations. The lookups and connections of previous
+	/// requests are left out, a pooled connection being reused.
+	pub fn response_timing(
+		&self,
+		request_start: Instant,
+		time_to_first_byte: Duration,
+		total: Duration,
+	) -> ResponseTiming {
+		let events = self.0.lock();
+		let is_new = |(start, _): &(Instant, Instant)| *start >= request_start;
+
+		let dns_lookup = events
+			.dns_lookup
+			.filter(is_new)
+			.map(|(start, end)| end - start);
+		let connect_events = events.connect.filter(is_new);
+
+		// The connector resolves the host before connecting to it, then shakes hands over TLS
+		let tls_start = events
+			.handshakes
+			.last()
+			.and_then(|handshake| handshake.start)
+			.filter(|tls_start| {
+				connect_events.is_some_and(|(start, end)| (start..=end).contains(tls_start))
+			});
+
+		let connect = connect_events.map(|(start, end)| {
+			let start = match events.dns_lookup {
+				Some((_, dns_end)) if (start..=end).contains(&dns_end) => dns_end,
+				_ => start,
+			};
+
+			tls_start.unwrap_or(end).saturating_duration_since(start)
+		});
+
+		let tls_handshake = connect_events
+			.zip(tls_start)
+			.map(|((_, end), tls_start)| end - tls_start);
+
+		let connection_setup = dns_lookup.unwrap_or_default()
+			+ connect.unwrap_or_default()
+			+ tls_handshake.unwrap_or_default();
+
+		ResponseTiming {
+			dns_lookup,
+			connect,
+			tls_handshake,
+			waiting: time_to_first_byte.saturating_sub(connection_setup),
+			download: total.saturating_sub(time_to_first_byte),
+			total,
+		}
+	}
+
+	/// Last TLS handshake of a connection presenting this leaf certificate, or the last one when
+	/// none did, as resumed sessions skip the certificates
+	pub fn tls_handshake(&self, leaf_certificate: &[u8]) -> Option<TlsHandshake> {
+		let events = self.0.lock();
+
+		events
+			.handshakes
+			.iter()
+			.rev()
+			.find(|handshake| {
+				handshake
+					.certificate_chain
+					.first()
+					.is_some_and(|certificate| certificate.as_ref() == leaf_certificate)
+			})
+			.or(events.handshakes.last())
+			.cloned()
+	}
+}
+
+impl Resolve for ConnectionTimer {
+	fn resolve(&self, name: Name) -> Resolving {
+		let events = self.0.clone();
+
+		Box::pin(async move {
+			let start = Instant::now();
+			let addresses: Vec<SocketAddr> =
+				tokio::net::lookup_host((name.as_str(), 0)).await?.collect();
+			events.lock().dns_lookup = Some((start, Instant::now()));
+
+			Ok(Box::new(addresses.into_iter()) as Addrs)
+		})
+	}
+}
+
+impl<S> Layer<S> for ConnectionTimer {
+	type Service = TimedConnector<S>;
+
+	fn layer(&self, inner: S) -> TimedConnector<S> {
+		TimedConnector {
+			inner,
+			timer: self.clone(),
+		}
+	}
+}
+
+/// Connector recording the start and end of the connections it opens, and their TLS handshakes
+#[derive(Clone)]
+pub struct TimedConnector<S> {
+	inner: S,
+	timer: ConnectionTimer,
+}
+
+impl<S, R> Service<R> for TimedConnector<S>
+where
+	S: Service<R>,
+	S::Future: Send + 'static,
+{
+	type Response = S::Response;
+	type Error = S::Error;
+	type Future = Pin<Box<dyn Future<Output = Result<S::Response, S::Error>> + Send>>;
+
+	fn poll_ready(&mut self, context: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+		self.inner.poll_ready(context)
+	}
+
+	fn call(&mut self, request: R) -> Self::Future {
+		let events = self.timer.0.clone();
+		let start = Instant::now();
+		let connecting = RecordingHandshake::new(self.inner.call(request));
+
+		Box::pin(async move {
+			let (connection, handshake) = connecting.await;
+			let mut events = events.lock();
+			events.connect = Some((start, Instant::now()));
+
+			if handshake.start.is_some() {
+				events.handshakes.push(handshake);
+			}
+
+			connection
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rustls::pki_types::CertificateDer;
+
+	#[test]
+	fn response_timing_splits_the_time_to_first_byte() {
+		let timer = ConnectionTimer::default();
+		let start = Instant::now();
+
+		{
+			let mut events = timer.0.lock();
+			events.dns_lookup = Some((start, start + Duration::from_millis(10)));
+			events.connect = Some((start, start + Duration::from_millis(40)));
+			events.handshakes.push(TlsHandshake {
+				start: Some(start + Duration::from_millis(25)),
+				..Default::default()
+			});
+		}
+
+		let timing = timer.response_timing(
+			start,
+			Duration::from_millis(100),
+			Duration::from_millis(150),
+		);
+
+		assert_eq!(
+			timing,
+			ResponseTiming {
+				dns_lookup: Some(Duration::from_millis(10)),
+				connect: Some(Duration::from_millis(15)),
+				tls_handshake: Some(Duration::from_millis(15)),
+				waiting: Duration::from_millis(60),
+				download: Duration::from_millis(50),
+				total: Duration::from_millis(150),
+			}
+		);
+
+		// Sent later on the same connection
+		let timing = timer.response_timing(
+			start + Duration::from_millis(200),
+			Duration::from_millis(30),
+			Duration::from_millis(35),
+		);
+
+		assert_eq!(timing.dns_lookup, None);
+		assert_eq!(timing.connect, None);
+		assert_eq!(timing.tls_handshake, None);
+		assert_eq!(timing.waiting, Duration::from_millis(30));
+	}
+
+	#[test]
+	fn tls_handshake_of_the_leaf_certificate() {
+		let timer = ConnectionTimer::default();
+		let handshake = |leaf: &[u8]| TlsHandshake {
+			start: Some(Instant::now()),
+			cipher_suite: None,
+			certificate_chain: vec![CertificateDer::from(leaf.to_vec())],
+		};
+
+		timer.0.lock().handshakes = vec![handshake(b"first"), handshake(b"second")];
+
+		let found = timer.tls_handshake(b"first").unwrap();
+		assert_eq!(found.certificate_chain[0].as_ref(), b"first");
+
+		// Resumed sessions do not present their certificates again
+		let resumed = timer.tls_handshake(b"unknown").unwrap();
+		assert_eq!(resumed.certificate_chain[0].as_ref(), b"second");
+	}
+
+	#[test]
+	fn response_timing_without_connection() {
+		let timing = ConnectionTimer::default().response_timing(
+			Instant::now(),
+			Duration::from_millis(20),
+			Duration::from_millis(25),
+		);
+
+		assert_eq!(timing.dns_lookup, None);
+		assert_eq!(timing.connect, None);
+		assert_eq!(timing.tls_handshake, None);
+		assert_eq!(timing.waiting, Duration::from_millis(20));
+		assert_eq!(timing.time_to_first_byte(), Duration::from_millis(20));
+	}
+}
@@ -0,0 +1,121 @@
+use crate::app::App;
+use crate::models::auth::auth::Auth;
+use crate::models::collection::Collection;
+use crate::models::folder::Folder;
+use crate::models::inheritance::Inheritance;
+use crate::models::request::Request;
+
+impl App<'_> {
+	/// Auth, headers and scripts a request inherits from its collection and folder
+	pub fn request_inheritance(
+		&self,
+		request: &Request,
+		collection_index: Option<usize>,
+	) -> Inheritance {
+		let Some(collection_index) = collection_index else {
+			return Inheritance::default();
+		};
+
+		let folder_path = self
+			.core
+			.collections
+			.get(collection_index)
+			.and_then(|collection| find_request_folder_path(collection, request))
+			.unwrap_or_default();
+
+		self.folder_inheritance(collection_index, &folder_path)
+	}
+
+	/// Auth, headers and scripts the requests of a collection, or of one of its folders when the
+	/// folder path is not empty, inherit
+	pub fn folder_inheritance(
+		&self,
+		collection_index: usize,
+		folder_path: &[usize],
+	) -> Inheritance {
+		let mut inheritance = Inheritance::default();
+
+		let Some(collection) = self.core.collections.get(collection_index) else {
+			return inheritance;
+		};
+
+		inheritance.push(&collection.shared_properties());
+
+		// Parent folders first, so that the closest folder takes precedence
+		for depth in 1..=folder_path.len() {
+			if let Some(folder) = collection.folder(&folder_path[..depth]) {
+				inheritance.push(&folder.shared_properties());
+			}
+		}
+
+		inheritance
+	}
+
+	/// Auth a request using the "inherit" auth resolves to, so that its state (e.g. an OAuth2
+	/// token) can be stored where it is defined
+	pub fn inherited_auth_mut(
+		&mut self,
+		collection_index: usize,
+		request: &Request,
+	) -> Option<&mut Auth> {
+		let collection = self.core.collections.get_mut(collection_index)?;
+		let is_defined = |auth: &Auth| !matches!(auth, Auth::Inherit);
+
+		let mut folder_path = find_request_folder_path(collection, request).unwrap_or_default();
+
+		// Closest folder defining an auth
+		while !folder_path.is_empty() {
+			let folder_auth = collection
+				.folder(&folder_path)
+				.and_then(|folder| folder.auth.as_ref());
+
+			if folder_auth.is_some_and(is_defined) {
+				return collection
+					.folder_mut(&folder_path)
+					.and_then(|folder| folder.auth.as_mut());
+			}
+
+			folder_path.pop();
+		}
+
+		collection.auth.as_mut().filter(|auth| is_defined(auth))
+	}
+}
+
+/// Path of the folder holding a request, `None` for a root-level request. The request is
+/// identified by its address, so that it can be looked up while its lock is held.
+pub fn find_request_folder_path(collection: &Collection, request: &Request) -> Option<Vec<usize>> {
+	collection
+		.folders
+		.iter()
+		.enumerate()
+		.find_map(|(folder_index, folder)| {
+			find_in_folder(folder, request).map(|mut folder_path| {
+				folder_path.insert(0, folder_index);
+				folder_path
+			})
+		})
+}
+
+/// Path of the folder holding a request, relative to `folder` (empty when `folder` holds it)
+fn find_in_folder(folder: &Folder, request: &Request) -> Option<Vec<usize>> {
+	let is_in_folder = folder
+		.requests
+		.iter()
+		.any(|folder_request| std::ptr::eq(folder_request.data_ptr(), request));
+
+	if is_in_folder {
+		return Some(vec![]);
+	}
+
+	folder
+		.folders
+		.iter()
+		.enumerate()
+		.find_map(|(sub_folder_index, sub_folder)| {
+			find_in_folder(sub_folder, request).map(|mut folder_path| {
+				folder_path.insert(0, sub_folder_index);
+				folder_path
+			})
+		})
+}
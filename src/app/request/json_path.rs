@@ -0,0 +1,594 @@
+//! Minimal JSONPath evaluator used by assertions, captures and response filters.
+//!
+//! Supported syntax: `$`, `.key`, `['key']`, `[0]`, `[-1]`, `[*]`, `.*`, `[start:end]`,
+//! `..key` (recursive descent) and simple filters such as `[?(@.id == 3)]` or `[?(@.name)]`.
+//!
+//! Response filters also accept a jq-like syntax: `.` for the whole value, `[]` as a wildcard,
+//! `|` to pipe the results of a path into the next one, and the `length` and `keys` functions.
+
+use serde_json::Value;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum JsonPathError {
+	#[error("Invalid JSONPath \"{0}\": {1}")]
+	InvalidPath(String, String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+	Name(String),
+	Wildcard,
+	Index(i64),
+	Slice(Option<i64>, Option<i64>),
+	Filter(Filter),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+	Child(Selector),
+	Descendant(Selector),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Filter {
+	path: Vec<Segment>,
+	comparison: Option<(Comparison, Value)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Comparison {
+	Equal,
+	NotEqual,
+	Lower,
+	LowerOrEqual,
+	Greater,
+	GreaterOrEqual,
+}
+
+/// Evaluate a JSONPath expression against a JSON value, returning every matched node.
+pub fn query<'a>(value: &'a Value, path: &str) -> Result<Vec<&'a Value>, JsonPathError> {
+	let segments = parse(path)?;
+
+	Ok(evaluate(value, &segments))
+}
+
+/// Evaluate a JSONPath expression and return the first matched node, if any.
+pub fn query_first(value: &Value, path: &str) -> Result<Option<Value>, JsonPathError> {
+	Ok(query(value, path)?.first().map(|value| (*value).clone()))
+}
+
+/// Evaluate a response filter, a JSONPath expression or jq-like paths piped with `|`,
+/// returning every resulting value.
+pub fn filter(value: &Value, expression: &str) -> Result<Vec<Value>, JsonPathError> {
+	let error = |message: String| JsonPathError::InvalidPath(expression.to_string(), message);
+
+	let mut current = vec![value.clone()];
+
+	for stage in split_pipes(expression) {
+		let stage = stage.trim();
+		let mut next = vec![];
+
+		for node in &current {
+			match stage {
+				"length" => next.push(match node {
+					Value::Array(array) => Value::from(array.len()),
+					Value::Object(object) => Value::from(object.len()),
+					Value::String(string) => Value::from(string.chars().count()),
+					Value::Null => Value::from(0),
+					other => return Err(error(format!("{other} has no length"))),
+				}),
+				"keys" => next.push(match node {
+					Value::Array(array) => (0..array.len()).map(Value::from).collect(),
+					Value::Object(object) => {
+						let mut keys: Vec<&String> = object.keys().collect();
+						keys.sort();
+						keys.into_iter()
+							.map(|key| Value::from(key.as_str()))
+							.collect()
+					}
+					other => return Err(error(format!("{other} has no keys"))),
+				}),
+				path => next.extend(query(node, path)?.into_iter().cloned()),
+			}
+		}
+
+		current = next;
+	}
+
+	Ok(current)
+}
+
+/// Split a filter on the `|` that are neither quoted nor inside brackets
+fn split_pipes(expression: &str) -> Vec<&str> {
+	let mut stages = vec![];
+	let mut depth = 0;
+	let mut quote: Option<char> = None;
+	let mut start = 0;
+
+	for (index, char) in expression.char_indices() {
+		match (quote, char) {
+			(Some(q), c) if c == q => quote = None,
+			(Some(_), _) => {}
+			(None, '\'' | '"') => quote = Some(char),
+			(None, '[') => depth += 1,
+			(None, ']') => depth -= 1,
+			(None, '|') if depth == 0 => {
+				stages.push(&expression[start..index]);
+				start = index + 1;
+			}
+			_ => {}
+		}
+	}
+
+	stages.push(&expression[start..]);
+	stages
+}
+
+fn evaluate<'a>(value: &'a Value, segments: &[Segment]) -> Vec<&'a Value> {
+	let mut current: Vec<&'a Value> = vec![value];
+
+	for segment in segments {
+		let mut next = vec![];
+
+		for node in current {
+			match segment {
+				Segment::Child(selector) => select(node, selector, &mut next),
+				Segment::Descendant(selector) => {
+					let mut descendants = vec![];
+					collect_descendants(node, &mut descendants);
+
+					for descendant in descendants {
+						select(descendant, selector, &mut next);
+					}
+				}
+			}
+		}
+
+		current = next;
+	}
+
+	current
+}
+
+fn collect_descendants<'a>(value: &'a Value, output: &mut Vec<&'a Value>) {
+	output.push(value);
+
+	match value {
+		Value::Array(array) => array.iter().for_each(|v| collect_descendants(v, output)),
+		Value::Object(object) => object.values().for_each(|v| collect_descendants(v, output)),
+		_ => {}
+	}
+}
+
+fn select<'a>(value: &'a Value, selector: &Selector, output: &mut Vec<&'a Value>) {
+	match selector {
+		Selector::Name(name) => {
+			if let Some(child) = value.get(name) {
+				output.push(child);
+			}
+		}
+		Selector::Wildcard => match value {
+			Value::Array(array) => output.extend(array.iter()),
+			Value::Object(object) => output.extend(object.values()),
+			_ => {}
+		},
+		Selector::Index(index) => {
+			if let Value::Array(array) = value
+				&& let Some(index) = normalize_index(*index, array.len())
+				&& let Some(child) = array.get(index)
+			{
+				output.push(child);
+			}
+		}
+		Selector::Slice(start, end) => {
+			if let Value::Array(array) = value {
+				let len = array.len() as i64;
+				let clamp = |bound: i64| match bound < 0 {
+					true => (len + bound).max(0),
+					false => bound.min(len),
+				};
+				let start = clamp(start.unwrap_or(0));
+				let end = clamp(end.unwrap_or(len));
+
+				if start < end {
+					output.extend(&array[start as usize..end as usize]);
+				}
+			}
+		}
+		Selector::Filter(filter) => {
+			let children: Vec<&Value> = match value {
+				Value::Array(array) => array.iter().collect(),
+				Value::Object(object) => object.values().collect(),
+				_ => vec![],
+			};
+
+			for child in children {
+				if filter_matches(child, filter) {
+					output.push(child);
+				}
+			}
+		}
+	}
+}
+
+fn normalize_index(index: i64, len: usize) -> Option<usize> {
+	match index < 0 {
+		true => len.checked_sub(index.unsigned_abs() as usize),
+		false => Some(index as usize),
+	}
+}
+
+fn filter_matches(value: &Value, filter: &Filter) -> bool {
+	let matched = evaluate(value, &filter.path);
+
+	match &filter.comparison {
+		None => !matched.is_empty(),
+		Some((comparison, expected)) => matched
+			.iter()
+			.any(|actual| compare(actual, *comparison, expected)),
+	}
+}
+
+fn compare(actual: &Value, comparison: Comparison, expected: &Value) -> bool {
+	match comparison {
+		Comparison::Equal => values_equal(actual, expected),
+		Comparison::NotEqual => !values_equal(actual, expected),
+		_ => {
+			let ordering = match (actual, expected) {
+				(Value::Number(a), Value::Number(b)) => a
+					.as_f64()
+					.zip(b.as_f64())
+					.and_then(|(a, b)| a.partial_cmp(&b)),
+				(Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+				_ => None,
+			};
+
+			let Some(ordering) = ordering else {
+				return false;
+			};
+
+			match comparison {
+				Comparison::Lower => ordering.is_lt(),
+				Comparison::LowerOrEqual => ordering.is_le(),
+				Comparison::Greater => ordering.is_gt(),
+				Comparison::GreaterOrEqual => ordering.is_ge(),
+				Comparison::Equal | Comparison::NotEqual => unreachable!(),
+			}
+		}
+	}
+}
+
+/// Compare two JSON values, treating numbers by value (`1` equals `1.0`).
+pub fn values_equal(a: &Value, b: &Value) -> bool {
+	match (a, b) {
+		(Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+		_ => a == b,
+	}
+}
+
+fn parse(path: &str) -> Result<Vec<Segment>, JsonPathError> {
+	let error = |message: &str| JsonPathError::InvalidPath(path.to_string(), message.to_string());
+
+	let trimmed = path.trim();
+	let mut chars: Vec<char> = trimmed.chars().collect();
+
+	// Allow "data.id" as a shorthand for "$.data.id"
+	match chars.first() {
+		Some('$') | Some('@') => {
+			chars.remove(0);
+		}
+		Some('.') | Some('[') => {}
+		Some(_) => chars.insert(0, '.'),
+		None => return Err(error("empty path")),
+	}
+
+	// jq-like identity, the whole value
+	if chars == ['.'] {
+		return Ok(vec![]);
+	}
+
+	let mut segments = vec![];
+	let mut i = 0;
+
+	while i < chars.len() {
+		match chars[i] {
+			'.' => {
+				let descendant = chars.get(i + 1) == Some(&'.');
+				i += if descendant { 2 } else { 1 };
+
+				let selector = if chars.get(i) == Some(&'[') {
+					let (selector, next) = parse_bracket(&chars, i).map_err(|e| error(&e))?;
+					i = next;
+					selector
+				} else if chars.get(i) == Some(&'*') {
+					i += 1;
+					Selector::Wildcard
+				} else {
+					let start = i;
+					while i < chars.len() && !matches!(chars[i], '.' | '[') {
+						i += 1;
+					}
+
+					if start == i {
+						return Err(error("expected a member name"));
+					}
+
+					Selector::Name(chars[start..i].iter().collect())
+				};
+
+				segments.push(match descendant {
+					true => Segment::Descendant(selector),
+					false => Segment::Child(selector),
+				});
+			}
+			'[' => {
+				let (selector, next) = parse_bracket(&chars, i).map_err(|e| error(&e))?;
+				i = next;
+				segments.push(Segment::Child(selector));
+			}
+			c => return Err(error(&format!("unexpected character '{c}'"))),
+		}
+	}
+
+	Ok(segments)
+}
+
+/// Parse a `[...]` selector starting at `start` (the opening bracket).
+/// Returns the selector and the index following the closing bracket.
+fn parse_bracket(chars: &[char], start: usize) -> Result<(Selector, usize), String> {
+	let mut depth = 0;
+	let mut quote: Option<char> = None;
+	let mut end = None;
+
+	for (index, char) in chars.iter().enumerate().skip(start) {
+		match (quote, char) {
+			(Some(q), c) if *c == q => quote = None,
+			(Some(_), _) => {}
+			(None, '\'' | '"') => quote = Some(*char),
+			(None, '[') => depth += 1,
+			(None, ']') => {
+				depth -= 1;
+				if depth == 0 {
+					end = Some(index);
+					break;
+				}
+			}
+			_ => {}
+		}
+	}
+
+	let Some(end) = end else {
+		return Err(String::from("unclosed bracket"));
+	};
+
+	let content: String = chars[start + 1..end].iter().collect();
+	let content = content.trim();
+
+	// `[]` is the jq-like wildcard
+	let selector = if content == "*" || content.is_empty() {
+		Selector::Wildcard
+	} else if let Some(filter) = content.strip_prefix('?') {
+		Selector::Filter(parse_filter(filter.trim())?)
+	} else if (content.starts_with('\'') && content.ends_with('\''))
+		|| (content.starts_with('"') && content.ends_with('"'))
+	{
+		if content.len() < 2 {
+			return Err(String::from("invalid quoted name"));
+		}
+
+		Selector::Name(content[1..content.len() - 1].to_string())
+	} else if let Some((slice_start, slice_end)) = content.split_once(':') {
+		let parse_bound = |bound: &str| -> Result<Option<i64>, String> {
+			match bound.trim() {
+				"" => Ok(None),
+				bound => bound
+					.parse::<i64>()
+					.map(Some)
+					.map_err(|_| format!("invalid slice bound \"{bound}\"")),
+			}
+		};
+
+		Selector::Slice(parse_bound(slice_start)?, parse_bound(slice_end)?)
+	} else {
+		match content.parse::<i64>() {
+			Ok(index) => Selector::Index(index),
+			Err(_) => return Err(format!("invalid selector \"{content}\"")),
+		}
+	};
+
+	Ok((selector, end + 1))
+}
+
+fn parse_filter(filter: &str) -> Result<Filter, String> {
+	let inner = filter
+		.strip_prefix('(')
+		.and_then(|f| f.strip_suffix(')'))
+		.unwrap_or(filter)
+		.trim();
+
+	if !inner.starts_with('@') {
+		return Err(String::from("filters must start with @"));
+	}
+
+	const OPERATORS: [(&str, Comparison); 6] = [
+		("==", Comparison::Equal),
+		("!=", Comparison::NotEqual),
+		("<=", Comparison::LowerOrEqual),
+		(">=", Comparison::GreaterOrEqual),
+		("<", Comparison::Lower),
+		(">", Comparison::Greater),
+	];
+
+	for (operator, comparison) in OPERATORS {
+		if let Some((left, right)) = inner.split_once(operator) {
+			let path = parse(left.trim()).map_err(|e| e.to_string())?;
+			let right = right.trim();
+			let expected = match serde_json::from_str::<Value>(right) {
+				Ok(value) => value,
+				Err(_) => Value::String(right.trim_matches('\'').to_string()),
+			};
+
+			return Ok(Filter {
+				path,
+				comparison: Some((comparison, expected)),
+			});
+		}
+	}
+
+	Ok(Filter {
+		path: parse(inner).map_err(|e| e.to_string())?,
+		comparison: None,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	fn sample() -> Value {
+		json!({
+			"data": {
+				"id": 42,
+				"name": "squrl",
+				"tags": ["a", "b", "c"],
+				"users": [
+					{"id": 1, "name": "alice", "age": 30},
+					{"id": 2, "name": "bob", "age": 25},
+					{"id": 3, "name": "carol"}
+				]
+			},
+			"key with space": true
+		})
+	}
+
+	#[test]
+	fn root_returns_whole_document() {
+		let value = sample();
+		assert_eq!(query(&value, "$").unwrap(), vec![&value]);
+	}
+
+	#[test]
+	fn dot_notation() {
+		let value = sample();
+		assert_eq!(query(&value, "$.data.id").unwrap(), vec![&json!(42)]);
+	}
+
+	#[test]
+	fn shorthand_without_dollar() {
+		let value = sample();
+		assert_eq!(query(&value, "data.name").unwrap(), vec![&json!("squrl")]);
+	}
+
+	#[test]
+	fn bracket_notation_and_indexes() {
+		let value = sample();
+		assert_eq!(
+			query(&value, "$['key with space']").unwrap(),
+			vec![&json!(true)]
+		);
+		assert_eq!(query(&value, "$.data.tags[1]").unwrap(), vec![&json!("b")]);
+		assert_eq!(query(&value, "$.data.tags[-1]").unwrap(), vec![&json!("c")]);
+	}
+
+	#[test]
+	fn wildcard_and_slice() {
+		let value = sample();
+		assert_eq!(query(&value, "$.data.tags[*]").unwrap().len(), 3);
+		assert_eq!(
+			query(&value, "$.data.tags[0:2]").unwrap(),
+			vec![&json!("a"), &json!("b")]
+		);
+		assert_eq!(query(&value, "$.data.users[*].name").unwrap().len(), 3);
+	}
+
+	#[test]
+	fn recursive_descent() {
+		let value = sample();
+		let ids = query(&value, "$..id").unwrap();
+		assert_eq!(ids.len(), 4);
+	}
+
+	#[test]
+	fn filters() {
+		let value = sample();
+		assert_eq!(
+			query(&value, "$.data.users[?(@.id == 2)].name").unwrap(),
+			vec![&json!("bob")]
+		);
+		assert_eq!(
+			query(&value, "$.data.users[?(@.age > 26)].name").unwrap(),
+			vec![&json!("alice")]
+		);
+		assert_eq!(query(&value, "$.data.users[?(@.age)]").unwrap().len(), 2);
+		assert_eq!(
+			query(&value, "$.data.users[?(@.name == 'carol')].id").unwrap(),
+			vec![&json!(3)]
+		);
+	}
+
+	#[test]
+	fn missing_path_returns_nothing() {
+		let value = sample();
+		assert!(query(&value, "$.data.unknown").unwrap().is_empty());
+		assert!(query(&value, "$.data.tags[10]").unwrap().is_empty());
+	}
+
+	#[test]
+	fn invalid_paths_are_errors() {
+		let value = sample();
+		assert!(query(&value, "").is_err());
+		assert!(query(&value, "$.data[").is_err());
+		assert!(query(&value, "$.data[abc]").is_err());
+	}
+
+	#[test]
+	fn query_first_clones_value() {
+		let value = sample();
+		assert_eq!(
+			query_first(&value, "$.data.users[0].name").unwrap(),
+			Some(json!("alice"))
+		);
+	}
+
+	#[test]
+	fn jq_like_filters() {
+		let value = sample();
+		assert_eq!(filter(&value, ".").unwrap(), vec![value.clone()]);
+		assert_eq!(
+			filter(&value, ".data.users[].name").unwrap(),
+			vec![json!("alice"), json!("bob"), json!("carol")]
+		);
+		assert_eq!(
+			filter(&value, ".data.users[] | .id").unwrap(),
+			vec![json!(1), json!(2), json!(3)]
+		);
+		assert_eq!(
+			filter(&value, ".data.tags | length").unwrap(),
+			vec![json!(3)]
+		);
+		assert_eq!(
+			filter(&value, ".data.users[0] | keys").unwrap(),
+			vec![json!(["age", "id", "name"])]
+		);
+		assert_eq!(
+			filter(&value, "$.data.users[?(@.name == 'a|b')]").unwrap(),
+			Vec::<Value>::new()
+		);
+	}
+
+	#[test]
+	fn jq_like_filter_errors() {
+		let value = sample();
+		assert!(filter(&value, ".data.id | keys").is_err());
+		assert!(filter(&value, ".data.id | length").is_err());
+		assert!(filter(&value, ".data |").is_err());
+	}
+
+	#[test]
+	fn numbers_are_compared_by_value() {
+		assert!(values_equal(&json!(1), &json!(1.0)));
+		assert!(!values_equal(&json!(1), &json!("1")));
+	}
+}
@@ -1,12 +1,19 @@
+pub(crate) mod assertions;
 pub(crate) mod auth;
+pub(crate) mod captures;
 pub(crate) mod export;
 pub mod grpc;
 pub(crate) mod headers;
 pub mod http;
+pub(crate) mod inheritance;
+pub(crate) mod json_path;
 mod key_value_crud;
+pub(crate) mod oauth2;
+pub(crate) mod pkcs12;
 pub(crate) mod query_params;
 pub(crate) mod scripts;
 pub(crate) mod send;
+pub mod tls;
 pub(crate) mod url;
 pub(crate) mod utils;
 pub(crate) mod ws;
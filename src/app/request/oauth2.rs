@@ -0,0 +1,426 @@
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use parking_lot::RwLock;
+use reqwest::header::{ACCEPT, AUTHORIZATION};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::app::App;
+use crate::app::files::oauth2_tokens::save_oauth2_tokens;
+use crate::app::request::send::PrepareRequestError;
+use crate::app::request::tls::rustls_client_config;
+use crate::models::auth::auth::Auth;
+use crate::models::auth::oauth2::{
+	AuthorizationCode, OAuth2, OAuth2Error, OAuth2GrantType, OAuth2Token, OAuth2TokenCache,
+	parse_authorization_redirect, parse_token_response, pkce_challenge,
+};
+use crate::models::history::SentRequest;
+use crate::models::request::Request;
+
+/// How long the redirect listener waits for the authorization in the browser
+const AUTHORIZATION_TIMEOUT: Duration = Duration::from_secs(180);
+
+/// Token to request before sending a request using OAuth2, once the request is prepared
+pub struct OAuth2TokenRequest {
+	client: reqwest::Client,
+	/// Resolved auth, holding the expired token for its refresh token
+	oauth2: OAuth2,
+	open_browser: bool,
+	tokens: Arc<RwLock<OAuth2TokenCache>>,
+}
+
+impl OAuth2TokenRequest {
+	/// Request the token and store it in the token cache.
+	///
+	/// An expired token is refreshed with its refresh token when it has one, otherwise a new
+	/// one is requested with the configured grant. The authorization code grant opens the
+	/// authorization page in a browser when `open_browser` is set, otherwise its URL is printed
+	/// on stderr.
+	pub async fn acquire(self) -> Result<OAuth2Token, OAuth2Error> {
+		let token = acquire_token(&self.client, &self.oauth2, self.open_browser).await?;
+
+		let mut tokens = self.tokens.write();
+		tokens.insert(&self.oauth2, token.clone());
+		save_oauth2_tokens(&tokens);
+
+		Ok(token)
+	}
+}
+
+impl App<'_> {
+	/// Make sure that a request using OAuth2 holds an access token before it is sent.
+	pub async fn refresh_oauth2_token(
+		&mut self,
+		local_request: &Arc<RwLock<Request>>,
+		collection_index: Option<usize>,
+		open_browser: bool,
+	) -> Result<(), PrepareRequestError> {
+		let Some(token_request) =
+			self.prepare_oauth2_token(local_request, collection_index, open_browser)?
+		else {
+			return Ok(());
+		};
+
+		let token = token_request.acquire().await?;
+		self.set_oauth2_token(local_request, collection_index, Some(token));
+
+		Ok(())
+	}
+
+	/// Give a request using OAuth2 its cached token when it is still valid.
+	/// Otherwise returns the token request to run before sending it, without blocking.
+	pub fn prepare_oauth2_token(
+		&mut self,
+		local_request: &Arc<RwLock<Request>>,
+		collection_index: Option<usize>,
+		open_browser: bool,
+	) -> Result<Option<OAuth2TokenRequest>, PrepareRequestError> {
+		let Some(mut oauth2) = self.resolved_oauth2(local_request, collection_index) else {
+			return Ok(None);
+		};
+
+		let cached_token = self.core.oauth2_tokens.read().get(&oauth2).cloned();
+
+		if let Some(token) = &cached_token
+			&& !token.is_expired(Utc::now())
+		{
+			self.set_oauth2_token(local_request, collection_index, cached_token);
+			return Ok(None);
+		}
+
+		let client = self.oauth2_token_client(&local_request.read(), collection_index)?;
+
+		oauth2.token = cached_token;
+
+		Ok(Some(OAuth2TokenRequest {
+			client,
+			oauth2,
+			open_browser,
+			tokens: Arc::clone(&self.core.oauth2_tokens),
+		}))
+	}
+
+	/// Take the cached token of a request using OAuth2, expired or not, to display its state
+	pub fn load_oauth2_token(
+		&mut self,
+		local_request: &Arc<RwLock<Request>>,
+		collection_index: Option<usize>,
+	) {
+		let Some(oauth2) = self.resolved_oauth2(local_request, collection_index) else {
+			return;
+		};
+
+		let cached_token = self.core.oauth2_tokens.read().get(&oauth2).cloned();
+		self.set_oauth2_token(local_request, collection_index, cached_token);
+	}
+
+	/// Forget the token of a request using OAuth2, a new one is requested on the next send
+	pub fn clear_oauth2_token(
+		&mut self,
+		local_request: &Arc<RwLock<Request>>,
+		collection_index: Option<usize>,
+	) {
+		let Some(oauth2) = self.resolved_oauth2(local_request, collection_index) else {
+			return;
+		};
+
+		{
+			let mut tokens = self.core.oauth2_tokens.write();
+			if tokens.remove(&oauth2) {
+				save_oauth2_tokens(&tokens);
+			}
+		}
+
+		self.set_oauth2_token(local_request, collection_index, None);
+	}
+
+	/// OAuth2 auth of a request, inherited or not, with its environment keys resolved
+	fn resolved_oauth2(
+		&self,
+		local_request: &Arc<RwLock<Request>>,
+		collection_index: Option<usize>,
+	) -> Option<OAuth2> {
+		let request = local_request.read();
+		let auth = self
+			.request_inheritance(&request, collection_index)
+			.resolve_auth(&request.auth);
+
+		match &auth {
+			Auth::OAuth2(oauth2) => Some(self.resolve_oauth2_env(oauth2, collection_index)),
+			_ => None,
+		}
+	}
+
+	/// Keep the token in memory, where the auth is defined, for the request to use it
+	fn set_oauth2_token(
+		&mut self,
+		local_request: &Arc<RwLock<Request>>,
+		collection_index: Option<usize>,
+		token: Option<OAuth2Token>,
+	) {
+		let mut request = local_request.write();
+
+		// An inherited token is held by the folder or the collection defining the auth
+		let auth = match (&request.auth, collection_index) {
+			(Auth::Inherit, Some(collection_index)) => {
+				self.inherited_auth_mut(collection_index, &request)
+			}
+			_ => Some(&mut request.auth),
+		};
+
+		if let Some(Auth::OAuth2(oauth2)) = auth {
+			oauth2.token = token;
+		}
+	}
+
+	/// Client requesting the tokens of a request, through the same proxy, with the same TLS
+	/// settings and timeout as the request itself
+	fn oauth2_token_client(
+		&self,
+		request: &Request,
+		collection_index: Option<usize>,
+	) -> Result<reqwest::Client, PrepareRequestError> {
+		let timeout = request.settings.timeout.as_u32().unwrap_or(30000) as u64;
+
+		let tls = self.resolve_tls_settings(request, collection_index);
+		let tls_config = rustls_client_config(
+			&tls,
+			request
+				.settings
+				.accept_invalid_certs
+				.as_bool()
+				.unwrap_or(false),
+			request
+				.settings
+				.accept_invalid_hostnames
+				.as_bool()
+				.unwrap_or(false),
+		)?;
+
+		self.apply_proxy_settings(reqwest::Client::builder(), request)?
+			.timeout(Duration::from_millis(timeout))
+			.tls_backend_preconfigured(tls_config)
+			.build()
+			.map_err(|error| OAuth2Error::TokenRequest(error.to_string()).into())
+	}
+
+	fn resolve_oauth2_env(&self, oauth2: &OAuth2, collection_index: Option<usize>) -> OAuth2 {
+		let replace_env = |input: &String| -> String {
+			match collection_index {
+				Some(collection_index) => {
+					self.replace_env_keys_for_collection(input, collection_index)
+				}
+				None => self.replace_env_keys_by_value(input),
+			}
+		};
+
+		OAuth2 {
+			token_url: replace_env(&oauth2.token_url),
+			auth_url: replace_env(&oauth2.auth_url),
+			client_id: replace_env(&oauth2.client_id),
+			client_secret: replace_env(&oauth2.client_secret),
+			scope: replace_env(&oauth2.scope),
+			username: replace_env(&oauth2.username),
+			password: replace_env(&oauth2.password),
+			refresh_token: replace_env(&oauth2.refresh_token),
+			..oauth2.clone()
+		}
+	}
+}
+
+async fn acquire_token(
+	client: &reqwest::Client,
+	oauth2: &OAuth2,
+	open_browser: bool,
+) -> Result<OAuth2Token, OAuth2Error> {
+	if oauth2.token_url.is_empty() {
+		return Err(OAuth2Error::MissingTokenUrl);
+	}
+
+	let cached_refresh_token = oauth2
+		.token
+		.as_ref()
+		.and_then(|token| token.refresh_token.clone());
+
+	if let Some(refresh_token) = cached_refresh_token {
+		let params = oauth2.refresh_params(&refresh_token);
+
+		match request_token(client, oauth2, &params, Some(refresh_token)).await {
+			Ok(token) => {
+				info!("OAuth2 token refreshed");
+				return Ok(token);
+			}
+			Err(error) => {
+				warn!("Could not refresh the OAuth2 token, requesting a new one: {error}")
+			}
+		}
+	}
+
+	let authorization_code = match oauth2.grant_type {
+		OAuth2GrantType::AuthorizationCode => Some(authorize(oauth2, open_browser).await?),
+		_ => None,
+	};
+
+	let params = oauth2.grant_params(authorization_code.as_ref());
+	let token = request_token(client, oauth2, &params, None).await?;
+
+	info!("OAuth2 token obtained with the {} grant", oauth2.grant_type);
+
+	Ok(token)
+}
+
+async fn request_token(
+	client: &reqwest::Client,
+	oauth2: &OAuth2,
+	params: &[(&str, String)],
+	previous_refresh_token: Option<String>,
+) -> Result<OAuth2Token, OAuth2Error> {
+	let mut request_builder = client
+		.post(&oauth2.token_url)
+		.header(ACCEPT, "application/json")
+		.form(params);
+
+	if !oauth2.client_secret.is_empty() {
+		request_builder =
+			request_builder.basic_auth(&oauth2.client_id, Some(&oauth2.client_secret));
+	}
+
+	let token_request_error = |error: reqwest::Error| OAuth2Error::TokenRequest(error.to_string());
+
+	let response = request_builder.send().await.map_err(token_request_error)?;
+	let status = response.status();
+	let body = response.text().await.map_err(token_request_error)?;
+
+	match parse_token_response(&body, previous_refresh_token, Utc::now()) {
+		Err(OAuth2Error::InvalidTokenResponse) if !status.is_success() => {
+			Err(OAuth2Error::TokenRequest(format!("HTTP {status}")))
+		}
+		result => result,
+	}
+}
+
+/// Browser part of the authorization code grant, the code is received by a loopback listener
+async fn authorize(oauth2: &OAuth2, open_browser: bool) -> Result<AuthorizationCode, OAuth2Error> {
+	let listener = TcpListener::bind(("127.0.0.1", oauth2.redirect_port))
+		.await
+		.map_err(redirect_listener_error)?;
+	let port = listener
+		.local_addr()
+		.map_err(redirect_listener_error)?
+		.port();
+
+	let redirect_uri = format!("http://127.0.0.1:{port}/callback");
+	let state = Uuid::new_v4().simple().to_string();
+	let code_verifier = oauth2
+		.pkce
+		.then(|| format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple()));
+	let code_challenge = code_verifier.as_deref().map(pkce_challenge);
+
+	let authorization_url =
+		oauth2.authorization_url(&redirect_uri, &state, code_challenge.as_deref())?;
+
+	match open_browser {
+		true => {
+			info!("Opening {authorization_url}");
+			open_in_browser(authorization_url.as_str());
+		}
+		false => eprintln!("Open this URL to authorize the request:\n{authorization_url}"),
+	}
+
+	let code = tokio::time::timeout(AUTHORIZATION_TIMEOUT, wait_for_redirect(&listener, &state))
+		.await
+		.map_err(|_| OAuth2Error::AuthorizationTimeout)??;
+
+	Ok(AuthorizationCode {
+		code,
+		redirect_uri,
+		code_verifier,
+	})
+}
+
+async fn wait_for_redirect(listener: &TcpListener, state: &str) -> Result<String, OAuth2Error> {
+	loop {
+		let (mut stream, _) = listener.accept().await.map_err(redirect_listener_error)?;
+
+		let mut request_line = String::new();
+
+		{
+			let mut reader = BufReader::new(&mut stream);
+			reader
+				.read_line(&mut request_line)
+				.await
+				.map_err(redirect_listener_error)?;
+
+			// Skip the headers
+			let mut line = String::new();
+			while reader.read_line(&mut line).await.is_ok_and(|read| read > 2) {
+				line.clear();
+			}
+		}
+
+		let result = parse_authorization_redirect(&request_line, state);
+
+		let (status, page) = match &result {
+			Ok(Some(_)) => (
+				"200 OK",
+				String::from("Authorization complete, you can close this page."),
+			),
+			Ok(None) => ("404 Not Found", String::from("Not found")),
+			Err(error) => ("400 Bad Request", error.to_string()),
+		};
+
+		let response = format!(
+			"HTTP/1.1 {status}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{page}",
+			page.len()
+		);
+		let _ = stream.write_all(response.as_bytes()).await;
+
+		if let Some(code) = result? {
+			return Ok(code);
+		}
+	}
+}
+
+/// Add the token obtained after the request was prepared to its Authorization header
+pub fn authorize_prepared_request(
+	builder: reqwest_middleware::RequestBuilder,
+	sent_request: &mut SentRequest,
+	token: &OAuth2Token,
+) -> reqwest_middleware::RequestBuilder {
+	let authorization = token.authorization_header();
+
+	sent_request
+		.headers
+		.push((AUTHORIZATION.to_string(), authorization.clone()));
+
+	builder.header(AUTHORIZATION, authorization)
+}
+
+fn redirect_listener_error(error: std::io::Error) -> OAuth2Error {
+	OAuth2Error::RedirectListener(error.to_string())
+}
+
+fn open_in_browser(url: &str) {
+	let (program, args): (&str, &[&str]) = if cfg!(target_os = "macos") {
+		("open", &[])
+	} else if cfg!(target_os = "windows") {
+		("cmd", &["/C", "start", ""])
+	} else {
+		("xdg-open", &[])
+	};
+
+	if let Err(error) = std::process::Command::new(program)
+		.args(args)
+		.arg(url)
+		.stdout(Stdio::null())
+		.stderr(Stdio::null())
+		.spawn()
+	{
+		warn!("Could not open a browser: {error}");
+	}
+}
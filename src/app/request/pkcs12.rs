@@ -0,0 +1,508 @@
+use std::num::NonZeroU32;
+
+use aws_lc_rs::cipher::{
+	AES_128, AES_128_KEY_LEN, AES_192, AES_192_KEY_LEN, AES_256, AES_256_KEY_LEN, Algorithm,
+	DecryptionContext, PaddedBlockDecryptingKey, UnboundCipherKey,
+};
+use aws_lc_rs::{digest, hmac, pbkdf2};
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer};
+use thiserror::Error;
+
+use crate::app::request::http::certificate::{read_value, values};
+
+const INTEGER: u8 = 0x02;
+const OCTET_STRING: u8 = 0x04;
+const OID: u8 = 0x06;
+const SEQUENCE: u8 = 0x30;
+const SET: u8 = 0x31;
+/// `[0]` explicit tag of the content of the content infos and safe bags
+const EXPLICIT_CONTENT: u8 = 0xa0;
+/// `[0]` implicit tag of the encrypted content
+const ENCRYPTED_CONTENT: u8 = 0x80;
+
+/// OIDs 1.2.840.113549.1.7.x
+const DATA_OID: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x07, 0x01];
+const ENCRYPTED_DATA_OID: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x07, 0x06];
+/// OIDs 1.2.840.113549.1.12.10.1.x
+const KEY_BAG_OID: &[u8] = &[
+	0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x0c, 0x0a, 0x01, 0x01,
+];
+const SHROUDED_KEY_BAG_OID: &[u8] = &[
+	0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x0c, 0x0a, 0x01, 0x02,
+];
+const CERT_BAG_OID: &[u8] = &[
+	0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x0c, 0x0a, 0x01, 0x03,
+];
+/// OID 1.2.840.113549.1.9.22.1
+const X509_CERTIFICATE_OID: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x09, 0x16, 0x01];
+/// OID 1.2.840.113549.1.9.21, identifying the certificate of the key
+const LOCAL_KEY_ID_OID: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x09, 0x15];
+/// OIDs 1.2.840.113549.1.5.x
+const PBES2_OID: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x05, 0x0d];
+const PBKDF2_OID: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x05, 0x0c];
+
+/// Client certificate and private key of a PKCS#12 archive
+#[derive(Debug)]
+pub struct Pkcs12Identity {
+	/// Leaf certificate first
+	pub certificates: Vec<CertificateDer<'static>>,
+	pub key: PrivateKeyDer<'static>,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum Pkcs12Error {
+	#[error("not a DER encoded PKCS#12 archive")]
+	Malformed,
+	#[error("wrong password")]
+	WrongPassword,
+	#[error(
+		"unsupported {0} algorithm, export the archive again with the AES-256 default of OpenSSL 3"
+	)]
+	UnsupportedAlgorithm(&'static str),
+	#[error("no certificate found")]
+	NoCertificate,
+	#[error("no private key found")]
+	NoPrivateKey,
+}
+
+/// Certificates and private key of a DER encoded PKCS#12 archive, encrypted with PBES2 and AES
+/// like the ones exported by OpenSSL 3. The legacy RC2 and 3DES encryptions are not supported.
+pub fn parse_pkcs12(der: &[u8], password: &str) -> Result<Pkcs12Identity, Pkcs12Error> {
+	let (SEQUENCE, pfx, _) = read_value(der).ok_or(Pkcs12Error::Malformed)? else {
+		return Err(Pkcs12Error::Malformed);
+	};
+
+	let mut pfx = values(pfx);
+
+	let Some((INTEGER, [3])) = pfx.next() else {
+		return Err(Pkcs12Error::Malformed);
+	};
+
+	let (SEQUENCE, auth_safe) = pfx.next().ok_or(Pkcs12Error::Malformed)? else {
+		return Err(Pkcs12Error::Malformed);
+	};
+	let auth_safe = data_content(auth_safe)?;
+
+	if let Some((SEQUENCE, mac_data)) = pfx.next() {
+		verify_mac(mac_data, auth_safe, password)?;
+	}
+
+	let (SEQUENCE, content_infos, _) = read_value(auth_safe).ok_or(Pkcs12Error::Malformed)? else {
+		return Err(Pkcs12Error::Malformed);
+	};
+
+	// With their local key ID
+	let mut certificates = vec![];
+	let mut key = None;
+
+	for (_, content_info) in values(content_infos) {
+		let mut content_info = values(content_info);
+		let (OID, content_type) = content_info.next().ok_or(Pkcs12Error::Malformed)? else {
+			return Err(Pkcs12Error::Malformed);
+		};
+		let (EXPLICIT_CONTENT, content) = content_info.next().ok_or(Pkcs12Error::Malformed)? else {
+			return Err(Pkcs12Error::Malformed);
+		};
+
+		let safe_contents = match content_type {
+			DATA_OID => octet_string(content)?.to_vec(),
+			ENCRYPTED_DATA_OID => decrypt_encrypted_data(content, password)?,
+			_ => continue,
+		};
+
+		let (SEQUENCE, safe_bags, _) = read_value(&safe_contents).ok_or(Pkcs12Error::Malformed)?
+		else {
+			return Err(Pkcs12Error::Malformed);
+		};
+
+		for (_, safe_bag) in values(safe_bags) {
+			let mut safe_bag = values(safe_bag);
+			let (OID, bag_type) = safe_bag.next().ok_or(Pkcs12Error::Malformed)? else {
+				return Err(Pkcs12Error::Malformed);
+			};
+			let (EXPLICIT_CONTENT, bag_value) = safe_bag.next().ok_or(Pkcs12Error::Malformed)?
+			else {
+				return Err(Pkcs12Error::Malformed);
+			};
+
+			let local_key_id = match safe_bag.next() {
+				Some((SET, attributes)) => local_key_id(attributes),
+				_ => None,
+			};
+
+			match bag_type {
+				CERT_BAG_OID => {
+					if let Some(certificate) = certificate(bag_value)? {
+						certificates.push((certificate, local_key_id));
+					}
+				}
+				KEY_BAG_OID => key = Some((bag_value.to_vec(), local_key_id)),
+				SHROUDED_KEY_BAG_OID => {
+					key = Some((decrypt_shrouded_key(bag_value, password)?, local_key_id))
+				}
+				_ => {}
+			}
+		}
+	}
+
+	let (key, key_id) = key.ok_or(Pkcs12Error::NoPrivateKey)?;
+
+	if certificates.is_empty() {
+		return Err(Pkcs12Error::NoCertificate);
+	}
+
+	// The certificate of the key comes first, the chain keeps the archive order
+	if let Some(leaf) = certificates
+		.iter()
+		.position(|(_, local_key_id)| key_id.is_some() && *local_key_id == key_id)
+	{
+		let leaf = certificates.remove(leaf);
+		certificates.insert(0, leaf);
+	}
+
+	Ok(Pkcs12Identity {
+		certificates: certificates
+			.into_iter()
+			.map(|(certificate, _)| certificate)
+			.collect(),
+		key: PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(key)),
+	})
+}
+
+/// Local key ID among the attributes of a safe bag
+fn local_key_id(attributes: &[u8]) -> Option<Vec<u8>> {
+	values(attributes).find_map(|(_, attribute)| {
+		let mut attribute = values(attribute);
+
+		match (attribute.next()?, attribute.next()?) {
+			((OID, LOCAL_KEY_ID_OID), (SET, key_id)) => {
+				octet_string(key_id).ok().map(<[u8]>::to_vec)
+			}
+			_ => None,
+		}
+	})
+}
+
+/// Octet string of a `data` content info
+fn data_content(content_info: &[u8]) -> Result<&[u8], Pkcs12Error> {
+	let mut content_info = values(content_info);
+
+	match (content_info.next(), content_info.next()) {
+		(Some((OID, DATA_OID)), Some((EXPLICIT_CONTENT, content))) => octet_string(content),
+		_ => Err(Pkcs12Error::Malformed),
+	}
+}
+
+fn octet_string(input: &[u8]) -> Result<&[u8], Pkcs12Error> {
+	match read_value(input) {
+		Some((OCTET_STRING, content, _)) => Ok(content),
+		_ => Err(Pkcs12Error::Malformed),
+	}
+}
+
+fn integer(content: &[u8]) -> Result<u32, Pkcs12Error> {
+	if content.is_empty() || content.len() > 5 {
+		return Err(Pkcs12Error::Malformed);
+	}
+
+	let value = content
+		.iter()
+		.fold(0u64, |value, byte| (value << 8) | *byte as u64);
+
+	u32::try_from(value).map_err(|_| Pkcs12Error::Malformed)
+}
+
+/// DER X.509 certificate of a certificate bag, other certificate types are skipped
+fn certificate(cert_bag: &[u8]) -> Result<Option<CertificateDer<'static>>, Pkcs12Error> {
+	let (SEQUENCE, cert_bag, _) = read_value(cert_bag).ok_or(Pkcs12Error::Malformed)? else {
+		return Err(Pkcs12Error::Malformed);
+	};
+	let mut cert_bag = values(cert_bag);
+
+	match (cert_bag.next(), cert_bag.next()) {
+		(Some((OID, X509_CERTIFICATE_OID)), Some((EXPLICIT_CONTENT, certificate))) => Ok(Some(
+			CertificateDer::from(octet_string(certificate)?.to_vec()),
+		)),
+		(Some((OID, _)), _) => Ok(None),
+		_ => Err(Pkcs12Error::Malformed),
+	}
+}
+
+/// PKCS#8 private key of an `EncryptedPrivateKeyInfo`
+fn decrypt_shrouded_key(shrouded_key: &[u8], password: &str) -> Result<Vec<u8>, Pkcs12Error> {
+	let (SEQUENCE, shrouded_key, _) = read_value(shrouded_key).ok_or(Pkcs12Error::Malformed)?
+	else {
+		return Err(Pkcs12Error::Malformed);
+	};
+	let mut shrouded_key = values(shrouded_key);
+
+	match (shrouded_key.next(), shrouded_key.next()) {
+		(Some((SEQUENCE, algorithm)), Some((OCTET_STRING, encrypted_key))) => {
+			decrypt(algorithm, encrypted_key, password)
+		}
+		_ => Err(Pkcs12Error::Malformed),
+	}
+}
+
+/// Safe contents of an `EncryptedData` content
+fn decrypt_encrypted_data(content: &[u8], password: &str) -> Result<Vec<u8>, Pkcs12Error> {
+	let (SEQUENCE, encrypted_data, _) = read_value(content).ok_or(Pkcs12Error::Malformed)? else {
+		return Err(Pkcs12Error::Malformed);
+	};
+	let mut encrypted_data = values(encrypted_data);
+
+	let (Some((INTEGER, _)), Some((SEQUENCE, encrypted_content_info))) =
+		(encrypted_data.next(), encrypted_data.next())
+	else {
+		return Err(Pkcs12Error::Malformed);
+	};
+
+	let mut encrypted_content_info = values(encrypted_content_info);
+
+	match (
+		encrypted_content_info.next(),
+		encrypted_content_info.next(),
+		encrypted_content_info.next(),
+	) {
+		(
+			Some((OID, _)),
+			Some((SEQUENCE, algorithm)),
+			Some((ENCRYPTED_CONTENT, encrypted_content)),
+		) => decrypt(algorithm, encrypted_content, password),
+		_ => Err(Pkcs12Error::Malformed),
+	}
+}
+
+/// Decrypt with the PBES2 parameters of an algorithm identifier
+fn decrypt(algorithm: &[u8], encrypted: &[u8], password: &str) -> Result<Vec<u8>, Pkcs12Error> {
+	let mut algorithm = values(algorithm);
+
+	let (Some((OID, PBES2_OID)), Some((SEQUENCE, parameters))) =
+		(algorithm.next(), algorithm.next())
+	else {
+		return Err(Pkcs12Error::UnsupportedAlgorithm("encryption"));
+	};
+
+	let mut parameters = values(parameters);
+	let (Some((SEQUENCE, key_derivation)), Some((SEQUENCE, encryption_scheme))) =
+		(parameters.next(), parameters.next())
+	else {
+		return Err(Pkcs12Error::Malformed);
+	};
+
+	let mut key_derivation = values(key_derivation);
+	let (Some((OID, PBKDF2_OID)), Some((SEQUENCE, pbkdf2_parameters))) =
+		(key_derivation.next(), key_derivation.next())
+	else {
+		return Err(Pkcs12Error::UnsupportedAlgorithm("key derivation"));
+	};
+
+	let mut pbkdf2_parameters = values(pbkdf2_parameters);
+	let (Some((OCTET_STRING, salt)), Some((INTEGER, iterations))) =
+		(pbkdf2_parameters.next(), pbkdf2_parameters.next())
+	else {
+		return Err(Pkcs12Error::Malformed);
+	};
+
+	// The key length is optional, then comes the PRF which defaults to HMAC-SHA1
+	let prf = pbkdf2_parameters
+		.find(|(tag, _)| *tag == SEQUENCE)
+		.map(|(_, prf)| values(prf).next())
+		.unwrap_or_default();
+
+	let prf = match prf {
+		// OIDs 1.2.840.113549.2.x
+		None | Some((OID, [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x02, 0x07])) => {
+			pbkdf2::PBKDF2_HMAC_SHA1
+		}
+		Some((OID, [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x02, 0x09])) => pbkdf2::PBKDF2_HMAC_SHA256,
+		Some((OID, [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x02, 0x0a])) => pbkdf2::PBKDF2_HMAC_SHA384,
+		Some((OID, [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x02, 0x0b])) => pbkdf2::PBKDF2_HMAC_SHA512,
+		_ => return Err(Pkcs12Error::UnsupportedAlgorithm("key derivation")),
+	};
+
+	let mut encryption_scheme = values(encryption_scheme);
+	let (Some((OID, cipher)), Some((OCTET_STRING, iv))) =
+		(encryption_scheme.next(), encryption_scheme.next())
+	else {
+		return Err(Pkcs12Error::UnsupportedAlgorithm("encryption"));
+	};
+
+	let (cipher, key_len): (&'static Algorithm, usize) = match cipher {
+		// OIDs 2.16.840.1.101.3.4.1.x
+		[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x01, 0x02] => (&AES_128, AES_128_KEY_LEN),
+		[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x01, 0x16] => (&AES_192, AES_192_KEY_LEN),
+		[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x01, 0x2a] => (&AES_256, AES_256_KEY_LEN),
+		_ => return Err(Pkcs12Error::UnsupportedAlgorithm("encryption")),
+	};
+
+	let iterations = NonZeroU32::new(integer(iterations)?).ok_or(Pkcs12Error::Malformed)?;
+	let mut key = vec![0; key_len];
+	pbkdf2::derive(prf, iterations, salt, password.as_bytes(), &mut key);
+
+	let iv = iv.try_into().map_err(|_| Pkcs12Error::Malformed)?;
+	let decrypting_key = UnboundCipherKey::new(cipher, &key)
+		.and_then(PaddedBlockDecryptingKey::cbc_pkcs7)
+		.map_err(|_| Pkcs12Error::Malformed)?;
+
+	let mut content = encrypted.to_vec();
+	let decrypted = decrypting_key
+		.decrypt(&mut content, DecryptionContext::Iv128(iv))
+		.map_err(|_| Pkcs12Error::WrongPassword)?;
+
+	Ok(decrypted.to_vec())
+}
+
+/// Check the HMAC of the authenticated safe, keyed with the PKCS#12 key derivation
+fn verify_mac(mac_data: &[u8], auth_safe: &[u8], password: &str) -> Result<(), Pkcs12Error> {
+	let mut mac_data = values(mac_data);
+
+	let (Some((SEQUENCE, digest_info)), Some((OCTET_STRING, salt))) =
+		(mac_data.next(), mac_data.next())
+	else {
+		return Err(Pkcs12Error::Malformed);
+	};
+
+	let iterations = match mac_data.next() {
+		Some((INTEGER, iterations)) => integer(iterations)?,
+		_ => 1,
+	};
+
+	let mut digest_info = values(digest_info);
+	let (Some((SEQUENCE, digest_algorithm)), Some((OCTET_STRING, mac))) =
+		(digest_info.next(), digest_info.next())
+	else {
+		return Err(Pkcs12Error::Malformed);
+	};
+
+	let (hash, hmac_algorithm) = match values(digest_algorithm).next() {
+		// OID 1.3.14.3.2.26
+		Some((OID, [0x2b, 0x0e, 0x03, 0x02, 0x1a])) => (
+			&digest::SHA1_FOR_LEGACY_USE_ONLY,
+			hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY,
+		),
+		// OIDs 2.16.840.1.101.3.4.2.x
+		Some((OID, [0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01])) => {
+			(&digest::SHA256, hmac::HMAC_SHA256)
+		}
+		Some((OID, [0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02])) => {
+			(&digest::SHA384, hmac::HMAC_SHA384)
+		}
+		Some((OID, [0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03])) => {
+			(&digest::SHA512, hmac::HMAC_SHA512)
+		}
+		_ => return Err(Pkcs12Error::UnsupportedAlgorithm("integrity")),
+	};
+
+	let mac_key = pkcs12_kdf(hash, password, salt, iterations, hash.output_len());
+	let mac_key = hmac::Key::new(hmac_algorithm, &mac_key);
+
+	hmac::verify(&mac_key, auth_safe, mac).map_err(|_| Pkcs12Error::WrongPassword)
+}
+
+/// MAC key derivation of RFC 7292 appendix B
+fn pkcs12_kdf(
+	hash: &'static digest::Algorithm,
+	password: &str,
+	salt: &[u8],
+	iterations: u32,
+	key_len: usize,
+) -> Vec<u8> {
+	/// Purpose of the derived bytes, 3 for a MAC key
+	const MAC_KEY_ID: u8 = 3;
+
+	let block_len = hash.block_len();
+
+	// Big-endian UTF-16 password, NUL terminated
+	let password: Vec<u8> = password
+		.encode_utf16()
+		.chain([0])
+		.flat_map(u16::to_be_bytes)
+		.collect();
+
+	let repeat = |input: &[u8]| -> Vec<u8> {
+		let len = input.len().div_ceil(block_len) * block_len;
+		input.iter().copied().cycle().take(len).collect()
+	};
+
+	let diversifier = vec![MAC_KEY_ID; block_len];
+	let mut input = repeat(salt);
+	input.extend(repeat(&password));
+
+	let mut key = vec![];
+
+	while key.len() < key_len {
+		let mut context = digest::Context::new(hash);
+		context.update(&diversifier);
+		context.update(&input);
+		let mut block = context.finish().as_ref().to_vec();
+
+		for _ in 1..iterations {
+			block = digest::digest(hash, &block).as_ref().to_vec();
+		}
+
+		// Add the block, plus one, to each block of the input
+		let addend = repeat(&block);
+
+		for input_block in input.chunks_mut(block_len) {
+			let mut carry = 1u16;
+
+			for (byte, addend_byte) in input_block.iter_mut().zip(&addend).rev() {
+				let sum = *byte as u16 + *addend_byte as u16 + carry;
+				*byte = sum as u8;
+				carry = sum >> 8;
+			}
+		}
+
+		key.extend(block);
+	}
+
+	key.truncate(key_len);
+	key
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn fixture(name: &str) -> Vec<u8> {
+		std::fs::read(format!(
+			"{}/tests/helpers/tls/{name}",
+			env!("CARGO_MANIFEST_DIR")
+		))
+		.unwrap()
+	}
+
+	#[test]
+	fn test_archive_exported_by_openssl() {
+		let identity = parse_pkcs12(&fixture("client.p12"), "squrl").unwrap();
+
+		// The client certificate, then the CA exported along with it
+		assert_eq!(identity.certificates.len(), 2);
+		assert!(matches!(identity.key, PrivateKeyDer::Pkcs8(_)));
+	}
+
+	#[test]
+	fn test_wrong_password() {
+		assert_eq!(
+			parse_pkcs12(&fixture("client.p12"), "wrong").unwrap_err(),
+			Pkcs12Error::WrongPassword
+		);
+	}
+
+	#[test]
+	fn test_legacy_encryption_is_not_supported() {
+		assert_eq!(
+			parse_pkcs12(&fixture("client-legacy.p12"), "squrl").unwrap_err(),
+			Pkcs12Error::UnsupportedAlgorithm("encryption")
+		);
+	}
+
+	#[test]
+	fn test_pem_file_is_not_an_archive() {
+		assert_eq!(
+			parse_pkcs12(&fixture("client.pem"), "").unwrap_err(),
+			Pkcs12Error::Malformed
+		);
+	}
+}
@@ -5,7 +5,7 @@ use tracing::{info, trace};
 use crate::app::App;
 use crate::models::request::Request;
 use crate::models::response::RequestResponse;
-use crate::models::scripts::ScriptType;
+use crate::models::scripts::{ScriptTestResult, ScriptType};
 
 impl App<'_> {
 	pub fn modify_request_script(
@@ -45,6 +45,389 @@ function pretty_print(data) {
 }
 "#;
 
+/// `test()` / `expect()` assertion library, available in post-request scripts
+const JS_TESTS: &str = r#"
+let test_results = [];
+
+class AssertionError extends Error {
+  constructor(message) {
+    super(message);
+    this.name = "AssertionError";
+  }
+}
+
+function test(name, callback) {
+  try {
+    callback();
+    test_results.push({ name: String(name), passed: true, error: null });
+  } catch (error) {
+    const message = error && error.message !== undefined ? error.message : error;
+    test_results.push({ name: String(name), passed: false, error: String(message) });
+  }
+}
+
+function repr(value) {
+  if (value instanceof PmResponse) return `response ${repr(value.code)}`;
+  try {
+    const json = JSON.stringify(value);
+    return json === undefined ? String(value) : json;
+  } catch (e) {
+    return String(value);
+  }
+}
+
+function deep_equal(a, b) {
+  if (a === b) return true;
+  if (a === null || b === null || typeof a !== "object" || typeof b !== "object") return false;
+  if (Array.isArray(a) !== Array.isArray(b)) return false;
+
+  const keys_a = Object.keys(a);
+  const keys_b = Object.keys(b);
+
+  if (keys_a.length !== keys_b.length) return false;
+
+  return keys_a.every(key => deep_equal(a[key], b[key]));
+}
+
+function type_of(value) {
+  if (value === null) return "null";
+  if (Array.isArray(value)) return "array";
+  return typeof value;
+}
+
+class Expectation {
+  constructor(value) {
+    this.value = value;
+    this.negated = false;
+    this.deep_comparison = false;
+  }
+
+  get to() { return this; }
+  get be() { return this; }
+  get been() { return this; }
+  get is() { return this; }
+  get that() { return this; }
+  get which() { return this; }
+  get and() { return this; }
+  get has() { return this; }
+  get have() { return this; }
+  get with() { return this; }
+  get at() { return this; }
+  get of() { return this; }
+  get same() { return this; }
+  get does() { return this; }
+  get not() { this.negated = !this.negated; return this; }
+  get deep() { this.deep_comparison = true; return this; }
+
+  check(condition, message) {
+    if (this.negated ? condition : !condition) {
+      throw new AssertionError(`expected ${repr(this.value)} ${this.negated ? "not " : ""}${message}`);
+    }
+    return this;
+  }
+
+  equal(expected) {
+    const equal = this.deep_comparison ? deep_equal(this.value, expected) : this.value === expected;
+    return this.check(equal, `to equal ${repr(expected)}`);
+  }
+  equals(expected) { return this.equal(expected); }
+  eq(expected) { return this.equal(expected); }
+  eql(expected) { return this.check(deep_equal(this.value, expected), `to deeply equal ${repr(expected)}`); }
+
+  a(type) { return this.check(type_of(this.value) === type.toLowerCase(), `to be a ${type}`); }
+  an(type) { return this.a(type); }
+
+  include(item) {
+    let included;
+    if (typeof this.value === "string") included = this.value.includes(item);
+    else if (Array.isArray(this.value)) included = this.value.some(value => deep_equal(value, item));
+    else if (this.value !== null && typeof this.value === "object" && typeof item === "object")
+      included = Object.keys(item).every(key => deep_equal(this.value[key], item[key]));
+    else included = false;
+    return this.check(included, `to include ${repr(item)}`);
+  }
+  includes(item) { return this.include(item); }
+  contain(item) { return this.include(item); }
+  contains(item) { return this.include(item); }
+
+  property(name, ...expected) {
+    const has_property = this.value !== null && this.value !== undefined && Object(this.value).hasOwnProperty(name);
+    if (expected.length === 0) return this.check(has_property, `to have property ${repr(name)}`);
+    return this.check(has_property && deep_equal(this.value[name], expected[0]), `to have property ${repr(name)} of ${repr(expected[0])}`);
+  }
+
+  length(expected) {
+    const length = this.value === null || this.value === undefined ? undefined : this.value.length;
+    return this.check(length === expected, `to have a length of ${expected}`);
+  }
+  lengthOf(expected) { return this.length(expected); }
+
+  above(expected) { return this.check(this.value > expected, `to be above ${expected}`); }
+  gt(expected) { return this.above(expected); }
+  greaterThan(expected) { return this.above(expected); }
+  below(expected) { return this.check(this.value < expected, `to be below ${expected}`); }
+  lt(expected) { return this.below(expected); }
+  lessThan(expected) { return this.below(expected); }
+  least(expected) { return this.check(this.value >= expected, `to be at least ${expected}`); }
+  gte(expected) { return this.least(expected); }
+  most(expected) { return this.check(this.value <= expected, `to be at most ${expected}`); }
+  lte(expected) { return this.most(expected); }
+  within(min, max) { return this.check(this.value >= min && this.value <= max, `to be within ${min}..${max}`); }
+
+  match(regex) { return this.check(new RegExp(regex).test(this.value), `to match ${regex}`); }
+  oneOf(list) { return this.check(list.some(item => deep_equal(item, this.value)), `to be one of ${repr(list)}`); }
+
+  get ok() {
+    if (this.value instanceof PmResponse) {
+      return this.check(this.value.code >= 200 && this.value.code < 300, "to be a 2xx response");
+    }
+    return this.check(!!this.value, "to be truthy");
+  }
+  get exist() { return this.check(this.value !== null && this.value !== undefined, "to exist"); }
+  get empty() {
+    const value = this.value;
+    const empty = value === null || value === undefined
+      || ((typeof value === "string" || Array.isArray(value)) && value.length === 0)
+      || (typeof value === "object" && Object.keys(value).length === 0);
+    return this.check(empty, "to be empty");
+  }
+
+  /* Postman response assertions, e.g. pm.response.to.have.status(200) */
+  status(expected) {
+    const actual = typeof expected === "number" ? this.value.code : this.value.status;
+    return this.check(actual === expected, `to have status ${repr(expected)}`);
+  }
+  header(name, ...expected) {
+    const value = this.value.headers.get(name);
+    if (expected.length === 0) return this.check(value !== undefined, `to have header ${repr(name)}`);
+    return this.check(value === expected[0], `to have header ${repr(name)} with value ${repr(expected[0])}`);
+  }
+  jsonBody(...expected) {
+    let body;
+    try { body = this.value.json(); } catch (e) { return this.check(false, "to have a JSON body"); }
+    if (expected.length === 0) return this.check(true, "to have a JSON body");
+    return this.check(deep_equal(body, expected[0]), `to have JSON body ${repr(expected[0])}`);
+  }
+}
+
+/* Reserved words can't be used as getter names in a class body */
+for (const [name, expected] of [["true", true], ["false", false], ["null", null], ["undefined", undefined]]) {
+  Object.defineProperty(Expectation.prototype, name, {
+    get() { return this.check(this.value === expected, `to be ${name}`); },
+  });
+}
+
+function expect(value) {
+  return new Expectation(value);
+}
+"#;
+
+/// Postman-like `pm` object, must be declared after `response` and `env`
+const JS_PM: &str = r#"
+class PmResponse {
+  constructor(response) {
+    const status_code = response.status_code || "";
+    const code = parseInt(status_code, 10);
+
+    this.raw = response;
+    this.code = isNaN(code) ? 0 : code;
+    this.status = status_code.replace(/^\d+\s*/, "");
+    this.responseTime = parse_duration(response.duration);
+    this.headers = {
+      all: () => response.headers.map(([key, value]) => ({ key, value })),
+      get: (name) => {
+        const header = response.headers.find(([key]) => key.toLowerCase() === String(name).toLowerCase());
+        return header === undefined ? undefined : header[1];
+      },
+      has: (name) => this.headers.get(name) !== undefined,
+    };
+  }
+
+  text() { return typeof this.raw.content === "string" ? this.raw.content : ""; }
+  json() { return JSON.parse(this.text()); }
+  get to() { return expect(this); }
+}
+
+function parse_duration(duration) {
+  const matches = /^([\d.]+)(ns|µs|ms|s)$/.exec(duration || "");
+  if (matches === null) return 0;
+
+  const value = parseFloat(matches[1]);
+  const factors = { "ns": 0.000001, "µs": 0.001, "ms": 1, "s": 1000 };
+
+  return Math.round(value * factors[matches[2]]);
+}
+
+const pm = {
+  test,
+  expect,
+  response: new PmResponse(response),
+  environment: pm_environment,
+  variables: pm_environment,
+  globals: pm_environment,
+  collectionVariables: pm_environment,
+  info: { eventName: "test" },
+};
+"#;
+
+/// Postman-like `pm` object of pre-request scripts, must be declared after `request` and `env`
+const JS_PM_REQUEST: &str = r#"
+class PmRequestHeaders {
+  constructor(request) { this.request = request; }
+
+  find(name) {
+    return this.request.headers.find(({ data }) => data[0].toLowerCase() === String(name).toLowerCase());
+  }
+  get(name) {
+    const header = this.find(name);
+    return header === undefined ? undefined : header.data[1];
+  }
+  has(name) { return this.find(name) !== undefined; }
+  all() { return this.request.headers.map(({ data }) => ({ key: data[0], value: data[1] })); }
+  add(header) {
+    const { key, value } = parse_header(header);
+    this.request.headers.push({ enabled: true, data: [key, value] });
+  }
+  upsert(header) {
+    const { key, value } = parse_header(header);
+    const existing = this.find(key);
+    if (existing === undefined) return this.add({ key, value });
+    existing.enabled = true;
+    existing.data[1] = value;
+  }
+  remove(name) {
+    this.request.headers = this.request.headers.filter(({ data }) => data[0].toLowerCase() !== String(name).toLowerCase());
+  }
+}
+
+/* Headers are either { key, value } objects or "Key: value" strings */
+function parse_header(header) {
+  if (typeof header !== "string") return { key: String(header.key), value: String(header.value) };
+
+  const separator = header.indexOf(":");
+  if (separator === -1) return { key: header.trim(), value: "" };
+
+  return { key: header.slice(0, separator).trim(), value: header.slice(separator + 1).trim() };
+}
+
+class PmRequest {
+  constructor(request) {
+    this.raw = request;
+    this.headers = new PmRequestHeaders(request);
+  }
+
+  get url() { return this.raw.url; }
+  set url(url) { this.raw.url = String(url); }
+  get method() { return this.raw.protocol.method; }
+  set method(method) { if (this.raw.protocol.type === "http") this.raw.protocol.method = String(method).toUpperCase(); }
+}
+
+const pm = {
+  request: new PmRequest(request),
+  environment: pm_environment,
+  variables: pm_environment,
+  globals: pm_environment,
+  collectionVariables: pm_environment,
+  info: { eventName: "prerequest", requestName: request.name },
+};
+"#;
+
+/// Postman-like variable scopes and legacy `postman` object, must be declared after `env`.
+/// Every scope is backed by the environment, collection values included.
+const JS_PM_VARIABLES: &str = r#"
+const pm_environment = {
+  get: (key) => env === undefined || env === null ? undefined : env[key],
+  set: (key, value) => {
+    if (env === undefined || env === null) {
+      console.log(`No environment selected, could not set "${key}"`);
+      return;
+    }
+    env[key] = String(value);
+  },
+  unset: (key) => { if (env !== undefined && env !== null) delete env[key]; },
+  has: (key) => env !== undefined && env !== null && Object.prototype.hasOwnProperty.call(env, key),
+  toObject: () => env === undefined || env === null ? {} : Object.assign({}, env),
+  replaceIn: (template) => String(template).replace(/{{\s*([^{}\s]+)\s*}}/g, (placeholder, key) => {
+    const value = pm_environment.get(key);
+    return value === undefined ? placeholder : value;
+  }),
+};
+
+const postman = {
+  getEnvironmentVariable: pm_environment.get,
+  setEnvironmentVariable: pm_environment.set,
+  clearEnvironmentVariable: pm_environment.unset,
+  getGlobalVariable: pm_environment.get,
+  setGlobalVariable: pm_environment.set,
+  clearGlobalVariable: pm_environment.unset,
+};
+"#;
+
+/// JetBrains HTTP Client-like `client` object of `.http` file scripts, must be declared after
+/// `pm_environment`. Global variables are backed by the environment.
+const JS_CLIENT: &str = r#"
+const client = {
+  global: {
+    get: pm_environment.get,
+    set: pm_environment.set,
+    clear: pm_environment.unset,
+    clearAll: () => Object.keys(pm_environment.toObject()).forEach(pm_environment.unset),
+    isEmpty: () => Object.keys(pm_environment.toObject()).length === 0,
+  },
+  log: (...messages) => console.log(messages.join(" ")),
+  test: (name, callback) => test(name, callback),
+  assert: (condition, message) => {
+    if (!condition) throw new Error(message === undefined ? "Assertion failed" : String(message));
+  },
+};
+"#;
+
+/// JetBrains HTTP Client-like `request.variables` of pre-request scripts, not serialized back
+const JS_CLIENT_REQUEST: &str = r#"
+Object.defineProperty(request, "variables", {
+  value: { get: pm_environment.get, set: pm_environment.set },
+});
+"#;
+
+/// JetBrains HTTP Client-like accessors of response handlers, not serialized back
+const JS_CLIENT_RESPONSE: &str = r#"
+Object.defineProperties(response.headers, {
+  valueOf: {
+    value: (name) => {
+      const header = response.headers.find(([key]) => key.toLowerCase() === String(name).toLowerCase());
+      return header === undefined ? null : header[1];
+    },
+  },
+  valuesOf: {
+    value: (name) => response.headers
+      .filter(([key]) => key.toLowerCase() === String(name).toLowerCase())
+      .map(([, value]) => value),
+  },
+});
+
+Object.defineProperties(response, {
+  body: {
+    get() {
+      const text = typeof this.content === "string" ? this.content : "";
+      try { return JSON.parse(text); } catch (error) { return text; }
+    },
+  },
+  status: {
+    get() {
+      const code = parseInt(this.status_code || "", 10);
+      return isNaN(code) ? 0 : code;
+    },
+  },
+  contentType: {
+    get() {
+      const [mimeType, ...parameters] = (this.headers.valueOf("content-type") || "").split(";");
+      const charset = parameters.map((parameter) => parameter.trim()).find((parameter) => parameter.toLowerCase().startsWith("charset="));
+      return { mimeType: mimeType.trim(), charset: charset === undefined ? undefined : charset.slice(8) };
+    },
+  },
+});
+"#;
+
 pub fn execute_pre_request_script(
 	user_script: &String,
 	request: &Request,
@@ -72,6 +455,10 @@ pub fn execute_pre_request_script(
 
         {JS_CONSOLE}
         {JS_UTILS}
+        {JS_PM_VARIABLES}
+        {JS_PM_REQUEST}
+        {JS_CLIENT}
+        {JS_CLIENT_REQUEST}
 
         /* Start of the user script */
 
@@ -108,26 +495,44 @@ pub fn execute_pre_request_script(
 	(result_request, result_env_values, console_output)
 }
 
+/// Modified response, modified environment, console output and test results
+pub type PostRequestScriptOutput = (
+	Option<RequestResponse>,
+	Option<IndexMap<String, String>>,
+	String,
+	Vec<ScriptTestResult>,
+);
+
 pub fn execute_post_request_script(
 	user_script: &String,
 	response: &RequestResponse,
 	env: Option<IndexMap<String, String>>,
-) -> (
-	Option<RequestResponse>,
-	Option<IndexMap<String, String>>,
-	String,
-) {
+) -> PostRequestScriptOutput {
 	// Instantiate the execution context
 	let mut context = Context::default();
 
 	let response_json = match serde_json::to_string(response) {
 		Ok(json) => json,
-		Err(e) => return (None, env, format!("Failed to serialize response: {e}")),
+		Err(e) => {
+			return (
+				None,
+				env,
+				format!("Failed to serialize response: {e}"),
+				vec![],
+			);
+		}
 	};
 	let env_json = match &env {
 		Some(env) => match serde_json::to_string(env) {
 			Ok(json) => json,
-			Err(e) => return (None, None, format!("Failed to serialize environment: {e}")),
+			Err(e) => {
+				return (
+					None,
+					None,
+					format!("Failed to serialize environment: {e}"),
+					vec![],
+				);
+			}
 		},
 		None => String::from("undefined"),
 	};
@@ -139,6 +544,11 @@ pub fn execute_post_request_script(
 
         {JS_CONSOLE}
         {JS_UTILS}
+        {JS_TESTS}
+        {JS_PM_VARIABLES}
+        {JS_PM}
+        {JS_CLIENT}
+        {JS_CLIENT_RESPONSE}
 
         /* Start of the user script */
 
@@ -146,7 +556,7 @@ pub fn execute_post_request_script(
 
         /* End of the user script */
 
-        JSON.stringify([response, env, console_log_output])
+        JSON.stringify([response, env, console_log_output, test_results])
     "#
 	);
 
@@ -154,34 +564,54 @@ pub fn execute_post_request_script(
 
 	let result = match context.eval(Source::from_bytes(&script)) {
 		Ok(result) => result,
-		Err(error) => return (None, env, error.to_string()),
+		Err(error) => return (None, env, error.to_string(), vec![]),
 	};
 
 	let stringed_result = match result.as_string() {
 		Some(s) => s.to_std_string_escaped(),
-		None => return (None, env, "Script result was not a string".to_string()),
+		None => {
+			return (
+				None,
+				env,
+				"Script result was not a string".to_string(),
+				vec![],
+			);
+		}
 	};
 
-	let (response_result, result_env_values, console_output) =
-		match serde_json::from_str::<(RequestResponse, Option<IndexMap<String, String>>, String)>(
-			&stringed_result,
-		) {
-			Ok((mut response_result, result_env_values, console_output)) => {
-				// Avoid losing those fields since they are not serialized
-				response_result.duration = response.duration.clone();
-				response_result.status_code = response.status_code.clone();
-
-				(Some(response_result), result_env_values, console_output)
-			}
-			Err(error) => (None, env, error.to_string()),
-		};
-
-	(response_result, result_env_values, console_output)
+	match serde_json::from_str::<(
+		RequestResponse,
+		Option<IndexMap<String, String>>,
+		String,
+		Vec<ScriptTestResult>,
+	)>(&stringed_result)
+	{
+		Ok((mut response_result, result_env_values, console_output, test_results)) => {
+			// Avoid losing those fields since they are not serialized
+			response_result.duration = response.duration.clone();
+			response_result.status_code = response.status_code.clone();
+			response_result.timing = response.timing.clone();
+			response_result.connection = response.connection.clone();
+			response_result.redirects = response.redirects.clone();
+
+			(
+				Some(response_result),
+				result_env_values,
+				console_output,
+				test_results,
+			)
+		}
+		Err(error) => (None, env, error.to_string(), vec![]),
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::models::protocol::http::http::HttpRequest;
+	use crate::models::protocol::http::method::Method;
+	use crate::models::protocol::protocol::Protocol;
+	use crate::models::request::KeyValue;
 	use crate::models::response::ResponseContent;
 
 	// ── Pre-request script tests ─────────────────────────────────
@@ -318,6 +748,69 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn pre_request_script_pm_request() {
+		let request = Request {
+			url: "https://example.com".to_string(),
+			headers: vec![KeyValue {
+				enabled: false,
+				data: ("Accept".to_string(), "text/plain".to_string()),
+			}],
+			protocol: Protocol::HttpRequest(HttpRequest::default()),
+			..Default::default()
+		};
+		let script = String::from(
+			r#"
+			pm.request.url = pm.request.url + "/items";
+			pm.request.method = "post";
+			pm.request.headers.upsert({ key: "accept", value: "application/json" });
+			pm.request.headers.add("X-Trace: abc");
+			console.log(pm.request.headers.get("x-trace"));
+		"#,
+		);
+
+		let (result_request, _, console_output) =
+			execute_pre_request_script(&script, &request, None);
+
+		let result_request = result_request.expect("should return a request");
+		assert_eq!(result_request.url, "https://example.com/items");
+		assert!(matches!(
+			result_request.get_http_request().unwrap().method,
+			Method::POST
+		));
+		assert!(result_request.headers[0].enabled);
+		assert_eq!(result_request.headers[0].data.1, "application/json");
+		assert_eq!(
+			result_request.headers[1].data,
+			("X-Trace".to_string(), "abc".to_string())
+		);
+		assert_eq!(console_output, "abc\n");
+	}
+
+	#[test]
+	fn pre_request_script_pm_variables() {
+		let mut env = IndexMap::new();
+		env.insert("HOST".to_string(), "example.com".to_string());
+		let script = String::from(
+			r#"
+			pm.collectionVariables.set("URL", pm.variables.replaceIn("https://{{HOST}}/{{MISSING}}"));
+			postman.setEnvironmentVariable("LEGACY", postman.getEnvironmentVariable("HOST"));
+			console.log(pm.info.eventName);
+		"#,
+		);
+
+		let (_, result_env, console_output) =
+			execute_pre_request_script(&script, &Request::default(), Some(env));
+
+		let result_env = result_env.expect("should return env");
+		assert_eq!(
+			result_env.get("URL").unwrap(),
+			"https://example.com/{{MISSING}}"
+		);
+		assert_eq!(result_env.get("LEGACY").unwrap(), "example.com");
+		assert_eq!(console_output, "prerequest\n");
+	}
+
 	// ── Post-request script tests ────────────────────────────────
 
 	#[test]
@@ -328,10 +821,11 @@ mod tests {
 			content: Some(ResponseContent::Body("hello".to_string())),
 			cookies: None,
 			headers: vec![],
+			..Default::default()
 		};
 		let script = String::new();
 
-		let (result_response, result_env, console_output) =
+		let (result_response, result_env, console_output, _) =
 			execute_post_request_script(&script, &response, None);
 
 		let result_response = result_response.expect("should return a response");
@@ -349,12 +843,13 @@ mod tests {
 			content: Some(ResponseContent::Body("not found".to_string())),
 			cookies: None,
 			headers: vec![],
+			..Default::default()
 		};
 		// The script could try to modify these, but the code re-assigns them
 		let script =
 			String::from("response.duration = 'tampered'; response.status_code = 'tampered';");
 
-		let (result_response, _, _) = execute_post_request_script(&script, &response, None);
+		let (result_response, _, _, _) = execute_post_request_script(&script, &response, None);
 
 		let result_response = result_response.expect("should return a response");
 		// duration and status_code are re-assigned from original after deserialization
@@ -370,7 +865,7 @@ mod tests {
 		let response = RequestResponse::default();
 		let script = String::from("console.log('post-script output');");
 
-		let (_, _, console_output) = execute_post_request_script(&script, &response, None);
+		let (_, _, console_output, _) = execute_post_request_script(&script, &response, None);
 
 		assert!(
 			console_output.contains("post-script output"),
@@ -385,7 +880,7 @@ mod tests {
 		env.insert("TOKEN".to_string(), "old".to_string());
 		let script = String::from("env.TOKEN = 'refreshed';");
 
-		let (_, result_env, _) = execute_post_request_script(&script, &response, Some(env));
+		let (_, result_env, _, _) = execute_post_request_script(&script, &response, Some(env));
 
 		let result_env = result_env.expect("should return env");
 		assert_eq!(result_env.get("TOKEN").unwrap(), "refreshed");
@@ -396,7 +891,7 @@ mod tests {
 		let response = RequestResponse::default();
 		let script = String::from("function {broken");
 
-		let (result_response, _, console_output) =
+		let (result_response, _, console_output, _) =
 			execute_post_request_script(&script, &response, None);
 
 		assert!(result_response.is_none());
@@ -416,7 +911,7 @@ mod tests {
 		"#,
 		);
 
-		let (_, _, console_output) = execute_post_request_script(&script, &response, None);
+		let (_, _, console_output, _) = execute_post_request_script(&script, &response, None);
 
 		assert!(
 			console_output.contains("test"),
@@ -431,10 +926,191 @@ mod tests {
 		env.insert("EXISTING".to_string(), "val".to_string());
 		let script = String::from("env.NEW_KEY = 'new_val';");
 
-		let (_, result_env, _) = execute_post_request_script(&script, &response, Some(env));
+		let (_, result_env, _, _) = execute_post_request_script(&script, &response, Some(env));
 
 		let result_env = result_env.expect("should return env");
 		assert_eq!(result_env.get("EXISTING").unwrap(), "val");
 		assert_eq!(result_env.get("NEW_KEY").unwrap(), "new_val");
 	}
+
+	// ── test() / expect() tests ──────────────────────────────────
+
+	fn json_response() -> RequestResponse {
+		RequestResponse {
+			duration: Some("12.5ms".to_string()),
+			status_code: Some("200 OK".to_string()),
+			content: Some(ResponseContent::Body(
+				r#"{"id": 42, "tags": ["a", "b"], "user": {"name": "squrl"}}"#.to_string(),
+			)),
+			cookies: None,
+			headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn post_request_script_collects_test_results() {
+		let script = String::from(
+			r#"
+			test("passes", () => expect(1).to.equal(1));
+			pm.test("fails", () => pm.expect("a").to.equal("b"));
+		"#,
+		);
+
+		let (result_response, _, _, test_results) =
+			execute_post_request_script(&script, &json_response(), None);
+
+		assert!(result_response.is_some());
+		assert_eq!(
+			test_results,
+			vec![
+				ScriptTestResult {
+					name: String::from("passes"),
+					passed: true,
+					error: None,
+				},
+				ScriptTestResult {
+					name: String::from("fails"),
+					passed: false,
+					error: Some(String::from(r#"expected "a" to equal "b""#)),
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn post_request_script_expect_chains() {
+		let script = String::from(
+			r#"
+			test("chains", () => {
+				expect([1, 2, 3]).to.have.lengthOf(3).and.include(2);
+				expect({ a: { b: 1 } }).to.deep.equal({ a: { b: 1 } });
+				expect({ a: 1 }).to.have.property("a", 1);
+				expect("squrl").to.be.a("string");
+				expect(5).to.be.above(1).and.below(10).and.within(5, 5);
+				expect(null).to.be.null;
+				expect(undefined).to.not.exist;
+				expect([]).to.be.empty;
+				expect(2).to.be.oneOf([1, 2]);
+				expect("abc").to.match(/^a/);
+				expect(1).to.not.equal(2);
+			});
+			test("negated failure", () => expect(true).to.not.be.true);
+		"#,
+		);
+
+		let (_, _, _, test_results) = execute_post_request_script(&script, &json_response(), None);
+
+		assert_eq!(test_results.len(), 2);
+		assert!(test_results[0].passed, "{:?}", test_results[0].error);
+		assert!(!test_results[1].passed);
+		assert_eq!(
+			test_results[1].error.as_deref(),
+			Some("expected true not to be true")
+		);
+	}
+
+	#[test]
+	fn post_request_script_pm_response() {
+		let script = String::from(
+			r#"
+			pm.test("status", () => {
+				pm.response.to.have.status(200);
+				pm.response.to.be.ok;
+				pm.expect(pm.response.code).to.equal(200);
+				pm.expect(pm.response.status).to.equal("OK");
+			});
+			pm.test("headers", () => {
+				pm.response.to.have.header("content-type", "application/json");
+				pm.expect(pm.response.headers.has("X-Missing")).to.be.false;
+			});
+			pm.test("body", () => {
+				const body = pm.response.json();
+				pm.expect(body.id).to.eql(42);
+				pm.expect(body.user).to.have.property("name", "squrl");
+				pm.expect(pm.response.text()).to.include("tags");
+			});
+			pm.test("response time", () => pm.expect(pm.response.responseTime).to.be.below(100));
+			pm.test("wrong status", () => pm.response.to.have.status(404));
+		"#,
+		);
+
+		let (_, _, _, test_results) = execute_post_request_script(&script, &json_response(), None);
+
+		let passed: Vec<bool> = test_results.iter().map(|result| result.passed).collect();
+		assert_eq!(
+			passed,
+			vec![true, true, true, true, false],
+			"{test_results:?}"
+		);
+	}
+
+	#[test]
+	fn post_request_script_pm_environment() {
+		let mut env = IndexMap::new();
+		env.insert("OLD".to_string(), "value".to_string());
+		let script = String::from(
+			r#"
+			pm.environment.set("TOKEN", pm.response.json().id);
+			pm.environment.unset("OLD");
+			test("has token", () => expect(pm.environment.has("TOKEN")).to.be.true);
+		"#,
+		);
+
+		let (_, result_env, _, test_results) =
+			execute_post_request_script(&script, &json_response(), Some(env));
+
+		let result_env = result_env.expect("should return env");
+		assert_eq!(result_env.get("TOKEN").unwrap(), "42");
+		assert!(result_env.get("OLD").is_none());
+		assert!(test_results[0].passed);
+	}
+
+	#[test]
+	fn post_request_script_http_client_response_handler() {
+		let env = IndexMap::new();
+		let script = String::from(
+			r#"
+			client.global.set("ID", response.body.id);
+			client.test("status", () => client.assert(response.status === 200, "not ok"));
+			client.test("content type", () => {
+				client.assert(response.contentType.mimeType === "application/json");
+				client.assert(response.headers.valueOf("content-type") === "application/json");
+			});
+			client.test("fails", () => client.assert(response.body.user.name === "other", "wrong user"));
+			client.log("done", response.headers.valuesOf("x-missing").length);
+		"#,
+		);
+
+		let (result_response, result_env, console_output, test_results) =
+			execute_post_request_script(&script, &json_response(), Some(env));
+
+		// The accessors are not serialized back
+		assert!(result_response.is_some());
+		assert_eq!(result_env.unwrap().get("ID").unwrap(), "42");
+		assert_eq!(console_output, "done 0\n");
+
+		let passed: Vec<bool> = test_results.iter().map(|result| result.passed).collect();
+		assert_eq!(passed, vec![true, true, false], "{test_results:?}");
+		assert_eq!(test_results[2].error.as_deref(), Some("wrong user"));
+	}
+
+	#[test]
+	fn pre_request_script_http_client_variables() {
+		let env = IndexMap::new();
+		let script = String::from(
+			r#"
+			request.variables.set("TRACE", "abc");
+			client.global.set("GLOBAL", request.variables.get("TRACE") + "-global");
+		"#,
+		);
+
+		let (result_request, result_env, _) =
+			execute_pre_request_script(&script, &Request::default(), Some(env));
+
+		assert!(result_request.is_some());
+		let result_env = result_env.expect("should return env");
+		assert_eq!(result_env.get("TRACE").unwrap(), "abc");
+		assert_eq!(result_env.get("GLOBAL").unwrap(), "abc-global");
+	}
 }
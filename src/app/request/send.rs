@@ -1,7 +1,9 @@
+use std::cell::RefCell;
 use std::io::Read;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use chrono::Utc;
 use parking_lot::RwLock;
 use reqwest::header::HeaderMap;
 use reqwest::multipart::Part;
@@ -17,20 +19,29 @@ use indexmap::IndexMap;
 use crate::app::App;
 use crate::app::constants::FILE_VALUE_PREFIX;
 use crate::app::files::environment::save_environment_to_file;
+use crate::app::request::http::timing::ConnectionTimer;
 use crate::app::request::scripts::{execute_post_request_script, execute_pre_request_script};
 use crate::app::request::send::RequestResponseError::PostRequestScript;
+use crate::app::request::tls::{TlsError, rustls_client_config};
+use crate::app::template::TemplateError;
 use crate::models::auth::auth::Auth;
+use crate::models::auth::aws_sig_v4::{
+	AwsSigV4, AwsSigV4Request, aws_sig_v4_file_payload_hash, aws_sig_v4_payload_hash,
+};
 use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
 use crate::models::auth::digest::{Digest, digest_to_authorization_header};
 use crate::models::auth::jwt::{JwtError, JwtToken, jwt_do_jaat};
+use crate::models::auth::oauth2::{OAuth2, OAuth2Error};
 use crate::models::environment::Environment;
+use crate::models::history::SentRequest;
 use crate::models::protocol::http::body::ContentType::{
 	File, Form, Html, Javascript, Json, Multipart, NoBody, Raw, Xml,
 };
 use crate::models::protocol::protocol::Protocol;
-use crate::models::request::Request;
+use crate::models::request::{KeyValue, Request};
 use crate::models::response::RequestResponse;
+use crate::models::scripts::ScriptTestResult;
 use anyhow::Context;
 
 #[derive(Error, Debug)]
@@ -43,6 +54,10 @@ pub enum PrepareRequestError {
 	CouldNotOpenFile,
 	#[error("{0}")]
 	JwtError(#[from] JwtError),
+	#[error("COULD NOT GET OAUTH2 TOKEN ({0})")]
+	OAuth2(#[from] OAuth2Error),
+	#[error("COULD NOT LOAD TLS CERTIFICATES ({0})")]
+	Tls(#[from] TlsError),
 	#[error("{0}")]
 	Other(#[from] anyhow::Error),
 }
@@ -55,6 +70,12 @@ pub enum PrepareRequestError {
 pub struct PreparedRequest {
 	pub builder: reqwest_middleware::RequestBuilder,
 	pub pending_file: Option<PathBuf>,
+	/// Resolved request, recorded in the history once the response is received
+	pub sent_request: SentRequest,
+	/// Placeholders sent as is because they could not be evaluated, reported before sending
+	pub unresolved: Vec<TemplateError>,
+	/// Times the DNS lookup and connection of the request
+	pub connection_timer: ConnectionTimer,
 }
 
 #[derive(Error, Debug)]
@@ -63,11 +84,36 @@ pub enum RequestResponseError {
 	PostRequestScript,
 	#[error("COULD NOT DECODE RESPONSE TEXT OR BYTES")]
 	CouldNotDecodeResponse,
+	#[error("COULD NOT WRITE RESPONSE BODY TO {0}: {1}")]
+	CouldNotWriteResponseBody(String, std::io::Error),
 	#[error(transparent)]
 	WebsocketError(#[from] reqwest_websocket::Error),
 }
 
 impl App<'_> {
+	/// Send through the proxies of the config, unless the request settings say otherwise
+	pub fn apply_proxy_settings(
+		&self,
+		mut client_builder: ClientBuilder,
+		request: &Request,
+	) -> anyhow::Result<ClientBuilder> {
+		if request.settings.use_config_proxy.as_bool().unwrap_or(true)
+			&& let Some(proxy) = &self.core.config.get_proxy()
+		{
+			if let Some(http_proxy_str) = &proxy.http_proxy {
+				let proxy = Proxy::http(http_proxy_str).context("Could not parse HTTP proxy")?;
+				client_builder = client_builder.proxy(proxy);
+			}
+
+			if let Some(https_proxy_str) = &proxy.https_proxy {
+				let proxy = Proxy::https(https_proxy_str).context("Could not parse HTTPS proxy")?;
+				client_builder = client_builder.proxy(proxy);
+			}
+		}
+
+		Ok(client_builder)
+	}
+
 	/// Prepare an HTTP/WS request synchronously.
 	///
 	/// When the request body is a `File`, the returned [`PreparedRequest`] will
@@ -84,6 +130,11 @@ impl App<'_> {
 	) -> Result<PreparedRequest, PrepareRequestError> {
 		trace!("Preparing request");
 
+		// Results of the previous send no longer apply
+		request.assertion_results.clear();
+		request.test_results.clear();
+		request.capture_results.clear();
+
 		let env = self.get_selected_env_as_local();
 
 		let mut client_builder = ClientBuilder::new()
@@ -92,7 +143,13 @@ impl App<'_> {
 
 		/* REDIRECTS */
 
-		if !request.settings.allow_redirects.as_bool().unwrap_or(true) {
+		// HTTP requests follow the redirects themselves, to record each hop
+		let follows_redirects_itself = matches!(
+			request.protocol,
+			Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_)
+		);
+
+		if follows_redirects_itself || !request.settings.allow_redirects.as_bool().unwrap_or(true) {
 			client_builder = client_builder.redirect(Policy::none());
 		}
 
@@ -108,50 +165,51 @@ impl App<'_> {
 
 		/* PROXY */
 
-		if request.settings.use_config_proxy.as_bool().unwrap_or(true)
-			&& let Some(proxy) = &self.core.config.get_proxy()
-		{
-			if let Some(http_proxy_str) = &proxy.http_proxy {
-				let proxy = Proxy::http(http_proxy_str).context("Could not parse HTTP proxy")?;
-				client_builder = client_builder.proxy(proxy);
-			}
-
-			if let Some(https_proxy_str) = &proxy.https_proxy {
-				let proxy = Proxy::https(https_proxy_str).context("Could not parse HTTPS proxy")?;
-				client_builder = client_builder.proxy(proxy);
-			}
-		}
+		client_builder = self.apply_proxy_settings(client_builder, request)?;
 
 		/* COOKIES */
 
-		let local_cookie_store = Arc::clone(&self.core.cookies_popup.cookie_store);
+		let local_cookie_store = self.get_cookie_store(collection_index);
 		client_builder = client_builder.cookie_provider(local_cookie_store);
 
 		/* PRE-REQUEST SCRIPT */
 
-		let modified_request = self.handle_pre_request_script(request, env, collection_index)?;
+		let inheritance = self.request_inheritance(request, collection_index);
+		request.inherited_scripts = inheritance.scripts.clone();
 
-		/* INVALID CERTS */
+		let mut modified_request =
+			self.handle_pre_request_script(request, env, collection_index)?;
 
-		if request
-			.settings
-			.accept_invalid_certs
-			.as_bool()
-			.unwrap_or(false)
-		{
-			client_builder = client_builder.danger_accept_invalid_certs(true);
-		}
+		/* INHERITED AUTH AND HEADERS */
 
-		/* INVALID HOSTNAMES */
+		modified_request.auth = inheritance.resolve_auth(&modified_request.auth);
+		modified_request.headers = inheritance.resolve_headers(&modified_request.headers);
 
-		if request
-			.settings
-			.accept_invalid_hostnames
-			.as_bool()
-			.unwrap_or(false)
-		{
-			client_builder = client_builder.danger_accept_invalid_hostnames(true);
-		}
+		/* INVALID CERTS AND HOSTNAMES, CLIENT CERTIFICATE AND CA CERTIFICATES */
+
+		let tls = self.resolve_tls_settings(request, collection_index);
+		let tls_config = rustls_client_config(
+			&tls,
+			request
+				.settings
+				.accept_invalid_certs
+				.as_bool()
+				.unwrap_or(false),
+			request
+				.settings
+				.accept_invalid_hostnames
+				.as_bool()
+				.unwrap_or(false),
+		)?;
+		client_builder = client_builder.tls_backend_preconfigured(tls_config);
+
+		/* TIMING AND CONNECTION INFO */
+
+		let connection_timer = ConnectionTimer::default();
+		client_builder = client_builder
+			.dns_resolver(connection_timer.clone())
+			.connector_layer(connection_timer.clone())
+			.tls_info(true);
 
 		/* CLIENT */
 
@@ -164,22 +222,42 @@ impl App<'_> {
 			.with_init(Extension(DisableOtelPropagation))
 			.build();
 
+		let unresolved: RefCell<Vec<TemplateError>> = RefCell::new(vec![]);
+
 		// Build a helper closure that dispatches to collection-aware or global env resolution
 		let replace_env = |app: &App, input: &String| -> String {
-			match collection_index {
-				Some(ci) => app.replace_env_keys_for_collection(input, ci),
-				None => app.replace_env_keys_by_value(input),
+			let interpolation = match collection_index {
+				Some(ci) => app.evaluate_env_keys_for_collection(input, ci),
+				None => app.evaluate_env_keys_by_value(input),
+			};
+
+			let mut unresolved = unresolved.borrow_mut();
+
+			for error in interpolation.errors {
+				if !unresolved.contains(&error) {
+					unresolved.push(error);
+				}
 			}
+
+			interpolation.value
+		};
+
+		let replace_env_in_key_values = |app: &App, key_values: &[KeyValue]| {
+			key_values
+				.iter()
+				.filter(|key_value| key_value.enabled)
+				.map(|key_value| {
+					(
+						replace_env(app, &key_value.data.0),
+						replace_env(app, &key_value.data.1),
+					)
+				})
+				.collect::<Vec<(String, String)>>()
 		};
 
 		/* PARAMS */
 
-		let params = match collection_index {
-			Some(ci) => {
-				self.key_value_vec_to_tuple_vec_for_collection(&modified_request.params, ci)
-			}
-			None => self.key_value_vec_to_tuple_vec(&modified_request.params),
-		};
+		let params = replace_env_in_key_values(self, &modified_request.params);
 		let query_params = params
 			.iter()
 			.filter(|(key, _)| !(key.starts_with("{") && key.ends_with("}")));
@@ -222,12 +300,14 @@ impl App<'_> {
 			Protocol::GrpcRequest(_) => reqwest::Method::POST,
 		};
 
-		let mut request_builder = client.request(method, url);
+		let sent_url = url.to_string();
+		let signed_url = url.clone();
+		let mut request_builder = client.request(method.clone(), url);
 
 		/* AUTH */
 
 		match &modified_request.auth {
-			Auth::NoAuth => {}
+			Auth::NoAuth | Auth::Inherit => {}
 			Auth::BasicAuth(BasicAuth { username, password }) => {
 				let username = replace_env(self, username);
 				let password = replace_env(self, password);
@@ -265,10 +345,16 @@ impl App<'_> {
 				qop,
 				user_hash,
 				charset,
-				..
+				nc,
 			}) => {
-				let digest = request.auth.get_digest_mut();
-				digest.nc += 1;
+				// The nonce count of an inherited digest is not stored
+				let nc = match &mut request.auth {
+					Auth::Digest(digest) => {
+						digest.nc += 1;
+						digest.nc
+					}
+					_ => nc + 1,
+				};
 
 				let digest_header = digest_to_authorization_header(
 					username,
@@ -283,11 +369,22 @@ impl App<'_> {
 					qop,
 					*user_hash,
 					charset,
-					digest.nc,
+					nc,
 				);
 
 				request_builder = request_builder.header("Authorization", &digest_header);
 			}
+			// The token is obtained beforehand by refresh_oauth2_token
+			Auth::OAuth2(OAuth2 { token, .. }) => {
+				if let Some(token) = token
+					&& !token.is_expired(Utc::now())
+				{
+					request_builder =
+						request_builder.header("Authorization", token.authorization_header());
+				}
+			}
+			// Signed once the body and headers are set
+			Auth::AwsSigV4(_) => {}
 		}
 
 		/* BODY */
@@ -325,10 +422,7 @@ impl App<'_> {
 					request_builder = request_builder.multipart(multipart);
 				}
 				Form(form_data) => {
-					let form = match collection_index {
-						Some(ci) => self.key_value_vec_to_tuple_vec_for_collection(form_data, ci),
-						None => self.key_value_vec_to_tuple_vec(form_data),
-					};
+					let form = replace_env_in_key_values(self, form_data);
 
 					request_builder = request_builder.form(&form);
 				}
@@ -385,11 +479,52 @@ impl App<'_> {
 			request_builder = request_builder.header(header_name, header_value);
 		}
 
+		/* AWS SIGNATURE V4 */
+
+		if let Auth::AwsSigV4(aws_sig_v4) = &modified_request.auth {
+			let aws_sig_v4 = AwsSigV4 {
+				access_key_id: replace_env(self, &aws_sig_v4.access_key_id),
+				secret_access_key: replace_env(self, &aws_sig_v4.secret_access_key),
+				region: replace_env(self, &aws_sig_v4.region),
+				service: replace_env(self, &aws_sig_v4.service),
+				session_token: replace_env(self, &aws_sig_v4.session_token),
+			};
+
+			request_builder = sign_aws_sig_v4(
+				request_builder,
+				&aws_sig_v4,
+				&method,
+				&signed_url,
+				&pending_file,
+			)?;
+		}
+
+		/* HISTORY */
+
+		let sent_method = match &modified_request.protocol {
+			Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) => None,
+			Protocol::WsRequest(_) => Some(String::from("WEBSOCKET")),
+			Protocol::GrpcRequest(_) => Some(String::from("GRPC")),
+		};
+
+		// Streamed bodies (multipart) cannot be cloned, only the URL is recorded then
+		let sent_request = match request_builder.try_clone().map(|builder| builder.build()) {
+			Some(Ok(built_request)) => sent_request_from_reqwest(&built_request, sent_method),
+			_ => SentRequest {
+				method: sent_method.unwrap_or_else(|| String::from("POST")),
+				url: sent_url,
+				..Default::default()
+			},
+		};
+
 		trace!("Request prepared");
 
 		Ok(PreparedRequest {
 			builder: request_builder,
 			pending_file,
+			sent_request,
+			unresolved: unresolved.take(),
+			connection_timer,
 		})
 	}
 
@@ -413,74 +548,93 @@ impl App<'_> {
 		env: Option<Arc<RwLock<Environment>>>,
 		collection_index: Option<usize>,
 	) -> anyhow::Result<Request, PrepareRequestError> {
-		match &request.scripts.pre_request_script {
-			None => {
-				request.console_output.pre_request_output = None;
-				Ok(request.clone())
+		// Inherited scripts first, the request one last
+		let pre_request_scripts: Vec<String> = request
+			.inherited_scripts
+			.iter()
+			.chain([&request.scripts])
+			.filter_map(|scripts| scripts.pre_request_script.clone())
+			.collect();
+
+		if pre_request_scripts.is_empty() {
+			request.console_output.pre_request_output = None;
+			return Ok(request.clone());
+		}
+
+		// Merge collection env values with global env values for scripts.
+		// Collection env takes priority: its keys override global env keys.
+		let mut env_values = {
+			let mut merged = IndexMap::new();
+
+			// Start with global env values (lower priority)
+			if let Some(ref local_env) = env {
+				let env = local_env.read();
+				merged.extend(env.values.clone());
 			}
-			Some(pre_request_script) => {
-				// Merge collection env values with global env values for scripts.
-				// Collection env takes priority: its keys override global env keys.
-				let env_values = {
-					let mut merged = IndexMap::new();
-
-					// Start with global env values (lower priority)
-					if let Some(ref local_env) = env {
-						let env = local_env.read();
-						merged.extend(env.values.clone());
-					}
 
-					// Override with collection env values (higher priority)
-					if let Some(ci) = collection_index
-						&& let Some(coll_env) = self.get_collection_env_values(ci)
-					{
-						merged.extend(coll_env);
-					}
+			// Override with collection env values (higher priority)
+			if let Some(ci) = collection_index
+				&& let Some(coll_env) = self.get_collection_env_values(ci)
+			{
+				merged.extend(coll_env);
+			}
 
-					if merged.is_empty() {
-						None
-					} else {
-						Some(merged)
-					}
-				};
+			if merged.is_empty() {
+				None
+			} else {
+				Some(merged)
+			}
+		};
 
-				let (result_request, env_variables, console_output) =
-					execute_pre_request_script(pre_request_script, request, env_values);
-
-				// Write back modified env variables.
-				// If we have a collection env, write to the collection env.
-				// Otherwise fall back to the global env.
-				if let Some(env_variables) = env_variables {
-					let mut wrote_to_collection = false;
-
-					if let Some(ci) = collection_index
-						&& let Some(collection) = self.core.collections.get(ci)
-						&& let Some(selected_name) = &collection.selected_environment
-						&& let Some(coll_env) = collection
-							.environments
-							.iter()
-							.find(|e| &e.name == selected_name)
-					{
-						// We can't mutate collection envs here since we only have &self.
-						// The env_variables will be written back in the caller.
-						let _ = coll_env;
-						wrote_to_collection = false;
-					}
+		let mut result_request = Some(request.clone());
+		let mut console_output = String::new();
 
-					if !wrote_to_collection && let Some(local_env) = &env {
-						let mut env = local_env.write();
-						env.values = env_variables;
-						save_environment_to_file(&env);
-					}
-				}
+		// Each script receives the request and environment modified by the previous one
+		for pre_request_script in &pre_request_scripts {
+			let Some(current_request) = &result_request else {
+				break;
+			};
 
-				request.console_output.pre_request_output = Some(console_output);
+			let (script_request, env_variables, script_console_output) =
+				execute_pre_request_script(pre_request_script, current_request, env_values);
 
-				match result_request {
-					None => Err(PrepareRequestError::PreRequestScript),
-					Some(request) => Ok(request),
-				}
+			result_request = script_request;
+			env_values = env_variables;
+			console_output.push_str(&script_console_output);
+		}
+
+		// Write back modified env variables.
+		// If we have a collection env, write to the collection env.
+		// Otherwise fall back to the global env.
+		if let Some(env_variables) = env_values {
+			let mut wrote_to_collection = false;
+
+			if let Some(ci) = collection_index
+				&& let Some(collection) = self.core.collections.get(ci)
+				&& let Some(selected_name) = &collection.selected_environment
+				&& let Some(coll_env) = collection
+					.environments
+					.iter()
+					.find(|e| &e.name == selected_name)
+			{
+				// We can't mutate collection envs here since we only have &self.
+				// The env_variables will be written back in the caller.
+				let _ = coll_env;
+				wrote_to_collection = false;
 			}
+
+			if !wrote_to_collection && let Some(local_env) = &env {
+				let mut env = local_env.write();
+				env.values = env_variables;
+				save_environment_to_file(&env);
+			}
+		}
+
+		request.console_output.pre_request_output = Some(console_output);
+
+		match result_request {
+			None => Err(PrepareRequestError::PreRequestScript),
+			Some(request) => Ok(request),
 		}
 	}
 
@@ -488,39 +642,138 @@ impl App<'_> {
 		request: &Request,
 		response: RequestResponse,
 		env: &Option<Arc<RwLock<Environment>>>,
-	) -> anyhow::Result<(RequestResponse, Option<String>), RequestResponseError> {
-		match &request.scripts.post_request_script {
-			None => Ok((response, None)),
-			Some(post_request_script) => {
-				let env_values = match &env {
-					None => None,
-					Some(env) => {
-						let env = env.read();
-						Some(env.values.clone())
-					}
-				};
+	) -> anyhow::Result<
+		(RequestResponse, Option<String>, Vec<ScriptTestResult>),
+		RequestResponseError,
+	> {
+		// Inherited scripts first, the request one last
+		let post_request_scripts: Vec<&String> = request
+			.inherited_scripts
+			.iter()
+			.chain([&request.scripts])
+			.filter_map(|scripts| scripts.post_request_script.as_ref())
+			.collect();
 
-				let (result_response, env_variables, result_console_output) =
-					execute_post_request_script(post_request_script, &response, env_values);
-
-				match env {
-					None => {}
-					Some(env) => match env_variables {
-						None => {}
-						Some(env_variables) => {
-							let mut env = env.write();
-							env.values = env_variables;
-							save_environment_to_file(&env);
-						}
-					},
-				}
+		if post_request_scripts.is_empty() {
+			return Ok((response, None, vec![]));
+		}
 
-				match result_response {
-					None => Err(PostRequestScript),
-					Some(result_response) => Ok((result_response, Some(result_console_output))),
-				}
+		let mut env_values = match &env {
+			None => None,
+			Some(env) => {
+				let env = env.read();
+				Some(env.values.clone())
 			}
+		};
+
+		let mut result_response = Some(response);
+		let mut console_output = String::new();
+		let mut test_results = vec![];
+
+		// Each script receives the response and environment modified by the previous one
+		for post_request_script in post_request_scripts {
+			let Some(current_response) = &result_response else {
+				break;
+			};
+
+			let (script_response, env_variables, script_console_output, script_test_results) =
+				execute_post_request_script(post_request_script, current_response, env_values);
+
+			result_response = script_response;
+			env_values = env_variables;
+			console_output.push_str(&script_console_output);
+			test_results.extend(script_test_results);
+		}
+
+		match env {
+			None => {}
+			Some(env) => match env_values {
+				None => {}
+				Some(env_variables) => {
+					let mut env = env.write();
+					env.values = env_variables;
+					save_environment_to_file(&env);
+				}
+			},
 		}
+
+		match result_response {
+			None => Err(PostRequestScript),
+			Some(result_response) => Ok((result_response, Some(console_output), test_results)),
+		}
+	}
+}
+
+/// Add the AWS Signature V4 headers to a fully built request.
+/// Streamed bodies (multipart) cannot be inspected, only the URL is signed then.
+fn sign_aws_sig_v4(
+	request_builder: reqwest_middleware::RequestBuilder,
+	aws_sig_v4: &AwsSigV4,
+	method: &reqwest::Method,
+	url: &Url,
+	pending_file: &Option<PathBuf>,
+) -> Result<reqwest_middleware::RequestBuilder, PrepareRequestError> {
+	let built_request = request_builder
+		.try_clone()
+		.and_then(|builder| builder.build().ok());
+
+	let empty_headers = HeaderMap::new();
+	let (headers, payload_hash) = match (&built_request, pending_file) {
+		(Some(built_request), Some(file_path)) => (
+			built_request.headers(),
+			aws_sig_v4_file_payload_hash(file_path)
+				.map_err(|_| PrepareRequestError::CouldNotOpenFile)?,
+		),
+		(Some(built_request), None) => (
+			built_request.headers(),
+			aws_sig_v4_payload_hash(match built_request.body() {
+				Some(body) => body.as_bytes(),
+				None => Some(b""),
+			}),
+		),
+		(None, _) => (&empty_headers, aws_sig_v4_payload_hash(None)),
+	};
+
+	let signed_headers = aws_sig_v4.signed_headers(
+		&AwsSigV4Request {
+			method: method.as_str(),
+			url,
+			headers,
+			payload_hash: &payload_hash,
+		},
+		Utc::now(),
+	);
+
+	Ok(signed_headers
+		.into_iter()
+		.fold(request_builder, |request_builder, (name, value)| {
+			request_builder.header(name, value)
+		}))
+}
+
+fn sent_request_from_reqwest(request: &reqwest::Request, method: Option<String>) -> SentRequest {
+	let headers = request
+		.headers()
+		.iter()
+		.map(|(name, value)| {
+			(
+				name.to_string(),
+				String::from_utf8_lossy(value.as_bytes()).to_string(),
+			)
+		})
+		.collect();
+
+	let body = request
+		.body()
+		.and_then(|body| body.as_bytes())
+		.and_then(|bytes| std::str::from_utf8(bytes).ok())
+		.map(String::from);
+
+	SentRequest {
+		method: method.unwrap_or_else(|| request.method().to_string()),
+		url: request.url().to_string(),
+		headers,
+		body,
 	}
 }
 
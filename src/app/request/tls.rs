@@ -0,0 +1,322 @@
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use rustls::client::Resumption;
+use rustls::client::danger::ServerCertVerifier;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use thiserror::Error;
+
+use crate::app::request::http::handshake::{
+	AcceptAnyCertificate, RecordingSessionStore, RecordingVerifier, recording_crypto_provider,
+};
+
+use crate::app::App;
+use crate::app::request::pkcs12::{Pkcs12Error, parse_pkcs12};
+use crate::models::request::Request;
+use crate::models::settings::TlsSettings;
+
+#[derive(Error, Debug)]
+pub enum TlsError {
+	#[error("could not read {path}: {source}")]
+	ReadFile {
+		path: String,
+		source: std::io::Error,
+	},
+	#[error("invalid PKCS#12 archive {path}: {source}")]
+	InvalidPkcs12 { path: String, source: Pkcs12Error },
+	#[error("client key {0} is set along with a PKCS#12 archive, which holds the key")]
+	KeyWithPkcs12Archive(String),
+	#[error("invalid client certificate or key {path}: {source}")]
+	InvalidIdentity {
+		path: String,
+		source: Box<dyn std::error::Error + Send + Sync>,
+	},
+	#[error("client key {0} is set without a client certificate")]
+	KeyWithoutCertificate(String),
+	#[error("invalid CA certificate {path}: {source}")]
+	InvalidCaCertificate {
+		path: String,
+		source: Box<dyn std::error::Error + Send + Sync>,
+	},
+	#[error("no PEM certificate found in {0}")]
+	NoCaCertificate(String),
+	#[error("invalid TLS configuration: {0}")]
+	Configuration(#[from] rustls::Error),
+}
+
+impl App<'_> {
+	/// TLS settings of a request merged with the ones of its collection, environment keys
+	/// included
+	pub fn resolve_tls_settings(
+		&self,
+		request: &Request,
+		collection_index: Option<usize>,
+	) -> TlsSettings {
+		let tls = match collection_index.and_then(|index| self.core.collections.get(index)) {
+			Some(collection) => request.settings.tls.or_collection(&collection.tls),
+			None => request.settings.tls.clone(),
+		};
+
+		let replace_env = |input: &String| -> String {
+			match collection_index {
+				Some(collection_index) => {
+					self.replace_env_keys_for_collection(input, collection_index)
+				}
+				None => self.replace_env_keys_by_value(input),
+			}
+		};
+
+		TlsSettings {
+			client_certificate: tls.client_certificate.as_ref().map(replace_env),
+			client_key: tls.client_key.as_ref().map(replace_env),
+			client_certificate_password: tls.client_certificate_password.as_ref().map(replace_env),
+			ca_certificates: tls.ca_certificates.iter().map(replace_env).collect(),
+		}
+	}
+}
+
+/// rustls configuration of an HTTP client with the TLS settings, recording the TLS handshakes of
+/// its connections
+pub fn rustls_client_config(
+	tls: &TlsSettings,
+	accept_invalid_certs: bool,
+	accept_invalid_hostnames: bool,
+) -> Result<rustls::ClientConfig, TlsError> {
+	let provider = Arc::new(recording_crypto_provider());
+
+	let verifier: Arc<dyn ServerCertVerifier> = match accept_invalid_certs {
+		true => Arc::new(AcceptAnyCertificate(
+			provider.signature_verification_algorithms,
+		)),
+		false => {
+			let mut ca_certificates = vec![];
+
+			for ca_path in &tls.ca_certificates {
+				let certificates = CertificateDer::pem_slice_iter(&read_file(ca_path)?)
+					.collect::<Result<Vec<_>, _>>()
+					.map_err(|source| TlsError::InvalidCaCertificate {
+						path: ca_path.clone(),
+						source: source.into(),
+					})?;
+
+				if certificates.is_empty() {
+					return Err(TlsError::NoCaCertificate(ca_path.clone()));
+				}
+
+				ca_certificates.extend(certificates);
+			}
+
+			Arc::new(rustls_platform_verifier::Verifier::new_with_extra_roots(
+				ca_certificates,
+				provider.clone(),
+			)?)
+		}
+	};
+
+	let config_builder = rustls::ClientConfig::builder_with_provider(provider)
+		.with_safe_default_protocol_versions()?
+		.dangerous()
+		.with_custom_certificate_verifier(Arc::new(RecordingVerifier::new(
+			verifier,
+			accept_invalid_hostnames,
+		)));
+
+	let mut config = match client_identity(tls)? {
+		Some((certificates, key)) => config_builder
+			.with_client_auth_cert(certificates, key)
+			.map_err(|source| TlsError::InvalidIdentity {
+				path: tls
+					.client_key
+					.as_ref()
+					.or(tls.client_certificate.as_ref())
+					.cloned()
+					.unwrap_or_default(),
+				source: source.into(),
+			})?,
+		None => config_builder.with_no_client_auth(),
+	};
+
+	config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+	config.resumption = Resumption::store(Arc::new(RecordingSessionStore::default()));
+
+	Ok(config)
+}
+
+/// Certificate chain and private key presented by the client, from PEM files or a PKCS#12
+/// archive
+fn client_identity(
+	tls: &TlsSettings,
+) -> Result<Option<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>)>, TlsError> {
+	let certificate_path = match (&tls.client_certificate, &tls.client_key) {
+		(Some(certificate_path), _) => certificate_path,
+		(None, Some(key_path)) => return Err(TlsError::KeyWithoutCertificate(key_path.clone())),
+		(None, None) => return Ok(None),
+	};
+
+	let certificate_file = read_file(certificate_path)?;
+
+	if is_pkcs12_archive(certificate_path, &certificate_file) {
+		if let Some(key_path) = &tls.client_key {
+			return Err(TlsError::KeyWithPkcs12Archive(key_path.clone()));
+		}
+
+		let password = tls
+			.client_certificate_password
+			.as_deref()
+			.unwrap_or_default();
+		let identity = parse_pkcs12(&certificate_file, password).map_err(|source| {
+			TlsError::InvalidPkcs12 {
+				path: certificate_path.clone(),
+				source,
+			}
+		})?;
+
+		return Ok(Some((identity.certificates, identity.key)));
+	}
+
+	let key_pem = match &tls.client_key {
+		Some(key_path) => read_file(key_path)?,
+		None => certificate_file.clone(),
+	};
+	let invalid_identity =
+		|source: Box<dyn std::error::Error + Send + Sync>| TlsError::InvalidIdentity {
+			path: tls.client_key.as_ref().unwrap_or(certificate_path).clone(),
+			source,
+		};
+
+	let certificates = CertificateDer::pem_slice_iter(&certificate_file)
+		.collect::<Result<Vec<_>, _>>()
+		.map_err(|error| invalid_identity(error.into()))?;
+	let key =
+		PrivateKeyDer::from_pem_slice(&key_pem).map_err(|error| invalid_identity(error.into()))?;
+
+	Ok(Some((certificates, key)))
+}
+
+/// PKCS#12 archives are DER sequences, PEM files are text
+fn is_pkcs12_archive(path: &str, content: &[u8]) -> bool {
+	let has_pkcs12_extension = Path::new(path)
+		.extension()
+		.and_then(|extension| extension.to_str())
+		.is_some_and(|extension| {
+			extension.eq_ignore_ascii_case("p12") || extension.eq_ignore_ascii_case("pfx")
+		});
+
+	has_pkcs12_extension || content.first() == Some(&0x30)
+}
+
+fn read_file(path: &str) -> Result<Vec<u8>, TlsError> {
+	fs::read(path).map_err(|source| TlsError::ReadFile {
+		path: path.to_string(),
+		source,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn fixture(name: &str) -> String {
+		format!("{}/tests/helpers/tls/{name}", env!("CARGO_MANIFEST_DIR"))
+	}
+
+	#[test]
+	fn test_client_certificate_with_separate_key() {
+		let tls = TlsSettings {
+			client_certificate: Some(fixture("client.pem")),
+			client_key: Some(fixture("client.key")),
+			client_certificate_password: None,
+			ca_certificates: vec![fixture("ca.pem")],
+		};
+
+		let config = rustls_client_config(&tls, false, true).unwrap();
+		assert!(config.client_auth_cert_resolver.has_certs());
+		assert_eq!(
+			config.alpn_protocols,
+			[b"h2".to_vec(), b"http/1.1".to_vec()]
+		);
+	}
+
+	#[test]
+	fn test_client_certificate_without_key_is_rejected() {
+		let tls = TlsSettings {
+			client_certificate: Some(fixture("client.pem")),
+			..Default::default()
+		};
+
+		assert!(matches!(
+			rustls_client_config(&tls, false, false),
+			Err(TlsError::InvalidIdentity { .. })
+		));
+	}
+
+	#[test]
+	fn test_key_without_client_certificate_is_rejected() {
+		let tls = TlsSettings {
+			client_key: Some(fixture("client.key")),
+			..Default::default()
+		};
+
+		assert!(matches!(
+			rustls_client_config(&tls, false, false),
+			Err(TlsError::KeyWithoutCertificate(_))
+		));
+	}
+
+	#[test]
+	fn test_pkcs12_client_certificate() {
+		let tls = TlsSettings {
+			client_certificate: Some(fixture("client.p12")),
+			client_certificate_password: Some(String::from("squrl")),
+			..Default::default()
+		};
+
+		let config = rustls_client_config(&tls, false, false).unwrap();
+		assert!(config.client_auth_cert_resolver.has_certs());
+
+		let tls = TlsSettings {
+			client_key: Some(fixture("client.key")),
+			..tls
+		};
+
+		assert!(matches!(
+			rustls_client_config(&tls, false, false),
+			Err(TlsError::KeyWithPkcs12Archive(_))
+		));
+	}
+
+	#[test]
+	fn test_der_client_certificate_is_read_as_pkcs12_archive() {
+		let dir = tempfile::tempdir().unwrap();
+		let der_path = dir.path().join("client.crt");
+		fs::write(&der_path, [0x30, 0x82, 0x01, 0x00]).unwrap();
+
+		let tls = TlsSettings {
+			client_certificate: Some(der_path.to_string_lossy().to_string()),
+			..Default::default()
+		};
+
+		assert!(matches!(
+			rustls_client_config(&tls, false, false),
+			Err(TlsError::InvalidPkcs12 {
+				source: Pkcs12Error::Malformed,
+				..
+			})
+		));
+	}
+
+	#[test]
+	fn test_ca_file_without_certificate_is_rejected() {
+		let tls = TlsSettings {
+			ca_certificates: vec![fixture("client.key")],
+			..Default::default()
+		};
+
+		assert!(matches!(
+			rustls_client_config(&tls, false, false),
+			Err(TlsError::NoCaCertificate(_))
+		));
+	}
+}
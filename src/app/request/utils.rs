@@ -18,9 +18,8 @@ impl App<'_> {
 			SelectedRequest::RootRequest(collection_index, request_index) => {
 				self.core.collections[*collection_index].requests[*request_index].clone()
 			}
-			SelectedRequest::FolderRequest(collection_index, folder_index, request_index) => {
-				self.core.collections[*collection_index].folders[*folder_index].requests
-					[*request_index]
+			SelectedRequest::FolderRequest(collection_index, folder_path, request_index) => {
+				self.core.collections[*collection_index].requests_in(folder_path)[*request_index]
 					.clone()
 			}
 		}
@@ -64,6 +64,7 @@ pub async fn send_ws_request(
 					content: None,
 					cookies: None,
 					headers: vec![],
+					..Default::default()
 				},
 				request_start.elapsed(),
 			)
@@ -76,6 +77,7 @@ pub async fn send_ws_request(
 					content: None,
 					cookies: None,
 					headers: vec![],
+					..Default::default()
 				},
 				request_start.elapsed(),
 			)
@@ -118,6 +120,7 @@ pub async fn send_ws_request(
 						content: None,
 						cookies: Some(cookies),
 						headers,
+						..Default::default()
 					},
 					elapsed_time,
 				websocket: Websocket {
@@ -140,6 +143,7 @@ pub async fn send_ws_request(
 						content: Some(result_body),
 						cookies: None,
 						headers: vec![],
+						..Default::default()
 					},
 					request_start.elapsed(),
 				)
@@ -165,7 +169,7 @@ pub async fn send_ws_request(
 
 	// Re-acquire read guard for post-request script, then drop it.
 	let request = local_request.read();
-	let (modified_response, post_request_output) =
+	let (modified_response, post_request_output, test_results) =
 		App::handle_post_request_script(&request, response, env)?;
 	drop(request);
 
@@ -173,6 +177,7 @@ pub async fn send_ws_request(
 		let mut request = local_request.write();
 
 		request.console_output.post_request_output = post_request_output;
+		request.test_results = test_results;
 		request.is_pending = false;
 		request.cancellation_token = CancellationToken::new();
 
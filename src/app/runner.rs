@@ -0,0 +1,338 @@
+//! Collection runner results and their JUnit XML / JSON report serialization.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use serde::Serialize;
+
+use crate::models::protocol::grpc::status::GrpcStatus;
+
+/// Outcome of a whole collection run, across every iteration.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct RunReport {
+	pub collection: String,
+	pub iterations: u32,
+	pub timestamp: String,
+	pub duration_ms: u128,
+	pub results: Vec<RequestRunResult>,
+}
+
+/// Outcome of a single request execution within a collection run.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct RequestRunResult {
+	pub iteration: u32,
+	pub name: String,
+	pub folder: Option<String>,
+	pub status_code: Option<String>,
+	pub duration: Option<String>,
+	pub duration_ms: u128,
+	pub skipped: bool,
+	pub failures: Vec<String>,
+}
+
+impl RequestRunResult {
+	/// Display path of the request, e.g. `folder/request` or `request`
+	pub fn path(&self) -> String {
+		match &self.folder {
+			Some(folder) => format!("{folder}/{}", self.name),
+			None => self.name.clone(),
+		}
+	}
+
+	pub fn is_failure(&self) -> bool {
+		!self.skipped && !self.failures.is_empty()
+	}
+}
+
+impl RunReport {
+	pub fn failed_count(&self) -> usize {
+		self.results.iter().filter(|r| r.is_failure()).count()
+	}
+
+	pub fn skipped_count(&self) -> usize {
+		self.results.iter().filter(|r| r.skipped).count()
+	}
+
+	pub fn passed_count(&self) -> usize {
+		self.results.len() - self.failed_count() - self.skipped_count()
+	}
+
+	pub fn to_json(&self) -> anyhow::Result<String> {
+		let mut value = serde_json::to_value(self)?;
+
+		value["summary"] = serde_json::json!({
+			"total": self.results.len(),
+			"passed": self.passed_count(),
+			"failed": self.failed_count(),
+			"skipped": self.skipped_count(),
+		});
+
+		Ok(serde_json::to_string_pretty(&value)?)
+	}
+
+	/// Serialize the report as JUnit XML: one `<testsuite>` per iteration, one `<testcase>` per request.
+	pub fn to_junit_xml(&self) -> String {
+		let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+		let _ = writeln!(
+			xml,
+			"<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{}\">",
+			escape_xml(&self.collection),
+			self.results.len(),
+			self.failed_count(),
+			self.skipped_count(),
+			format_seconds(self.duration_ms),
+		);
+
+		for iteration in 1..=self.iterations.max(1) {
+			let results: Vec<&RequestRunResult> = self
+				.results
+				.iter()
+				.filter(|r| r.iteration == iteration)
+				.collect();
+
+			if results.is_empty() {
+				continue;
+			}
+
+			let suite_name = match self.iterations > 1 {
+				true => format!("{} (iteration {iteration})", self.collection),
+				false => self.collection.clone(),
+			};
+			let failures = results.iter().filter(|r| r.is_failure()).count();
+			let skipped = results.iter().filter(|r| r.skipped).count();
+			let time: u128 = results.iter().map(|r| r.duration_ms).sum();
+
+			let _ = writeln!(
+				xml,
+				"  <testsuite name=\"{}\" tests=\"{}\" failures=\"{failures}\" skipped=\"{skipped}\" time=\"{}\" timestamp=\"{}\">",
+				escape_xml(&suite_name),
+				results.len(),
+				format_seconds(time),
+				escape_xml(&self.timestamp),
+			);
+
+			for result in results {
+				let _ = write!(
+					xml,
+					"    <testcase name=\"{}\" classname=\"{}\" time=\"{}\"",
+					escape_xml(&result.path()),
+					escape_xml(&self.collection),
+					format_seconds(result.duration_ms),
+				);
+
+				if result.skipped {
+					xml.push_str(">\n      <skipped/>\n    </testcase>\n");
+				} else if result.failures.is_empty() {
+					xml.push_str("/>\n");
+				} else {
+					xml.push_str(">\n");
+
+					for failure in &result.failures {
+						let _ =
+							writeln!(xml, "      <failure message=\"{}\"/>", escape_xml(failure));
+					}
+
+					xml.push_str("    </testcase>\n");
+				}
+			}
+
+			xml.push_str("  </testsuite>\n");
+		}
+
+		xml.push_str("</testsuites>\n");
+
+		xml
+	}
+
+	pub fn write_json(&self, path: &Path) -> anyhow::Result<()> {
+		fs::write(path, self.to_json()?)
+			.with_context(|| format!("Could not write JSON report to \"{}\"", path.display()))
+	}
+
+	pub fn write_junit(&self, path: &Path) -> anyhow::Result<()> {
+		fs::write(path, self.to_junit_xml())
+			.with_context(|| format!("Could not write JUnit report to \"{}\"", path.display()))
+	}
+}
+
+/// Returns a failure message when the response status is missing, not numeric
+/// (e.g. `TIMEOUT`, `CANCELED`) or a 4xx/5xx code.
+pub fn status_failure(status_code: &Option<String>) -> Option<String> {
+	let Some(status_code) = status_code else {
+		return Some(String::from("No response status"));
+	};
+
+	let code = status_code
+		.split_whitespace()
+		.next()
+		.and_then(|code| code.parse::<u16>().ok());
+
+	match code {
+		Some(code) if code < 400 => None,
+		_ => Some(format!("Unexpected status: {status_code}")),
+	}
+}
+
+/// Returns a failure message when a gRPC call ended with a status other than `OK`. The HTTP status
+/// of a failed call is usually `200 OK`, the error being in its trailers.
+pub fn grpc_status_failure(grpc_status: &Option<GrpcStatus>) -> Option<String> {
+	match grpc_status {
+		Some(grpc_status) if !grpc_status.is_ok() => {
+			Some(format!("Unexpected gRPC status: {grpc_status}"))
+		}
+		_ => None,
+	}
+}
+
+fn format_seconds(duration_ms: u128) -> String {
+	format!("{:.3}", duration_ms as f64 / 1000.0)
+}
+
+fn escape_xml(input: &str) -> String {
+	let mut escaped = String::with_capacity(input.len());
+
+	for char in input.chars() {
+		match char {
+			'&' => escaped.push_str("&amp;"),
+			'<' => escaped.push_str("&lt;"),
+			'>' => escaped.push_str("&gt;"),
+			'"' => escaped.push_str("&quot;"),
+			'\'' => escaped.push_str("&apos;"),
+			'\n' => escaped.push_str("&#10;"),
+			c if c.is_control() => {}
+			c => escaped.push(c),
+		}
+	}
+
+	escaped
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn result(name: &str, failures: Vec<&str>) -> RequestRunResult {
+		RequestRunResult {
+			iteration: 1,
+			name: name.to_string(),
+			failures: failures.into_iter().map(String::from).collect(),
+			..Default::default()
+		}
+	}
+
+	fn report(results: Vec<RequestRunResult>) -> RunReport {
+		RunReport {
+			collection: String::from("my-api"),
+			iterations: 1,
+			timestamp: String::from("2026-01-01T00:00:00+00:00"),
+			duration_ms: 1500,
+			results,
+		}
+	}
+
+	#[test]
+	fn status_failure_accepts_success_and_redirects() {
+		assert!(status_failure(&Some("200 OK".to_string())).is_none());
+		assert!(status_failure(&Some("302 Found".to_string())).is_none());
+	}
+
+	#[test]
+	fn status_failure_rejects_errors() {
+		assert!(status_failure(&Some("404 Not Found".to_string())).is_some());
+		assert!(status_failure(&Some("500 Internal Server Error".to_string())).is_some());
+		assert!(status_failure(&Some("TIMEOUT".to_string())).is_some());
+		assert!(status_failure(&None).is_some());
+	}
+
+	#[test]
+	fn grpc_status_failure_rejects_failed_calls() {
+		let grpc_status = |code: u32| {
+			Some(GrpcStatus {
+				code,
+				message: String::from("user missing not found"),
+				details: None,
+			})
+		};
+
+		assert!(grpc_status_failure(&grpc_status(0)).is_none());
+		assert!(grpc_status_failure(&None).is_none());
+		assert_eq!(
+			grpc_status_failure(&grpc_status(5)).as_deref(),
+			Some("Unexpected gRPC status: NOT_FOUND (5): user missing not found")
+		);
+	}
+
+	#[test]
+	fn report_counts() {
+		let mut skipped = result("ws", vec![]);
+		skipped.skipped = true;
+
+		let report = report(vec![
+			result("ok", vec![]),
+			result("ko", vec!["Unexpected status: 500"]),
+			skipped,
+		]);
+
+		assert_eq!(report.passed_count(), 1);
+		assert_eq!(report.failed_count(), 1);
+		assert_eq!(report.skipped_count(), 1);
+	}
+
+	#[test]
+	fn result_path_includes_folder() {
+		let mut result = result("get-user", vec![]);
+		assert_eq!(result.path(), "get-user");
+
+		result.folder = Some(String::from("users"));
+		assert_eq!(result.path(), "users/get-user");
+	}
+
+	#[test]
+	fn junit_contains_testcases_and_failures() {
+		let report = report(vec![
+			result("ok", vec![]),
+			result("ko", vec!["Unexpected status: 500 <error>"]),
+		]);
+
+		let xml = report.to_junit_xml();
+
+		assert!(xml.contains("<testsuites name=\"my-api\" tests=\"2\" failures=\"1\""));
+		assert!(xml.contains("<testcase name=\"ok\" classname=\"my-api\" time=\"0.000\"/>"));
+		assert!(xml.contains("<failure message=\"Unexpected status: 500 &lt;error&gt;\"/>"));
+		assert!(xml.ends_with("</testsuites>\n"));
+	}
+
+	#[test]
+	fn junit_has_one_suite_per_iteration() {
+		let mut second = result("ok", vec![]);
+		second.iteration = 2;
+
+		let mut report = report(vec![result("ok", vec![]), second]);
+		report.iterations = 2;
+
+		let xml = report.to_junit_xml();
+
+		assert!(xml.contains("my-api (iteration 1)"));
+		assert!(xml.contains("my-api (iteration 2)"));
+	}
+
+	#[test]
+	fn json_report_contains_summary() {
+		let report = report(vec![result("ok", vec![]), result("ko", vec!["failed"])]);
+
+		let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+
+		assert_eq!(json["summary"]["total"], 2);
+		assert_eq!(json["summary"]["passed"], 1);
+		assert_eq!(json["summary"]["failed"], 1);
+		assert_eq!(json["results"][1]["failures"][0], "failed");
+	}
+
+	#[test]
+	fn escape_xml_escapes_special_characters() {
+		assert_eq!(escape_xml("a&b<c>\"d'"), "a&amp;b&lt;c&gt;&quot;d&apos;");
+	}
+}
@@ -1,15 +1,20 @@
 use crate::app::App;
+use crate::app::files::cookies::COOKIES_FILE_NAME;
 use crate::app::files::env_watcher::spawn_env_json_watcher;
-use crate::app::log::LogCounterLayer;
+use crate::app::files::oauth2_tokens::OAUTH2_TOKENS_FILE_NAME;
+use crate::app::files::secrets::SECRETS;
+use crate::app::log::{LogCounterLayer, MaskSecrets};
 use crate::cli::args::{ARGS, Command};
 use crate::cli::import::http_file;
 use crate::errors::panic_error;
 use crate::models::collection::{Collection, CollectionFileFormat};
-use crate::models::folder::Folder;
+use crate::models::folder::{Folder, get_or_create_folder};
+use crate::models::scripts::RequestScripts;
+use crate::models::settings::TlsSettings;
 use AppMode::{CLI, TUI};
 use anyhow::Context;
 use clap_verbosity_flag::log::LevelFilter;
-use std::collections::BTreeMap;
+use indexmap::IndexMap;
 use std::fs::{File, OpenOptions};
 use std::path::PathBuf;
 use tracing::{trace, warn};
@@ -36,6 +41,7 @@ impl<'a> App<'a> {
 				.with_file(false)
 				.with_line_number(false)
 				.with_ansi(ARGS.ansi_log)
+				.with_writer(MaskSecrets(std::io::stdout))
 				.init(),
 			// TUI
 			false => {
@@ -52,7 +58,7 @@ impl<'a> App<'a> {
 
 				tracing_subscriber::fmt()
 					.with_max_level(verbosity.as_trace())
-					.with_writer(log_file)
+					.with_writer(MaskSecrets(log_file))
 					.with_file(false)
 					.with_line_number(false)
 					.with_ansi(ARGS.ansi_log)
@@ -75,6 +81,13 @@ impl<'a> App<'a> {
 			self.load_theme();
 			self.update_text_inputs_handler();
 
+			// The passphrase is asked before the terminal is taken over
+			let has_secrets_file = SECRETS.read().has_file();
+
+			if has_secrets_file && let Err(e) = self.unlock_secrets() {
+				warn!("{e:#}");
+			}
+
 			TUI
 		}
 	}
@@ -123,6 +136,12 @@ impl<'a> App<'a> {
 			} else if file_name == "squrl.log" {
 				trace!("Log file is not parsable");
 				continue;
+			} else if file_name == COOKIES_FILE_NAME {
+				self.load_global_cookie_store();
+				continue;
+			} else if file_name == OAUTH2_TOKENS_FILE_NAME {
+				self.load_oauth2_tokens();
+				continue;
 			}
 
 			if let Some(filter) = &ARGS.collection_filter
@@ -145,6 +164,10 @@ impl<'a> App<'a> {
 			}
 		}
 
+		// Secret values are added to the environments once they are all loaded
+		self.load_secrets();
+		self.check_environment_parents();
+
 		// Check if the global config file exists
 		if let Some(config_directory) = &ARGS.config_directory {
 			let global_config_file_path = config_directory.join("global.toml");
@@ -181,8 +204,8 @@ impl<'a> App<'a> {
 		});
 
 		// Auto-load .http files from a "requests" subdirectory if we're in a git repo
-		// Recursively searches subdirectories and groups files into folders
-		// named after the top-level child directory of requests/
+		// Recursively searches subdirectories and groups files into nested folders
+		// named after the subdirectories of requests/
 		// Added after the save loop and sort to avoid saving ephemeral collections to disk
 		if let Ok(cwd) = std::env::current_dir() {
 			let requests_dir = cwd.join("requests");
@@ -207,9 +230,10 @@ impl<'a> App<'a> {
 				http_file_paths.sort();
 
 				// Group files: root-level files go to collection.requests,
-				// files in subdirectories go to folders named after the top-level child
+				// files in subdirectories go to nested folders named after the subdirectories
 				let mut root_requests = vec![];
-				let mut folder_map: BTreeMap<String, Vec<_>> = BTreeMap::new();
+				let mut folders: Vec<Folder> = vec![];
+				let mut variables = IndexMap::new();
 
 				for http_path in &http_file_paths {
 					let relative = match http_path.strip_prefix(&requests_dir) {
@@ -219,8 +243,8 @@ impl<'a> App<'a> {
 
 					let components: Vec<_> = relative.components().collect();
 
-					let parsed_requests = match http_file::parse_http_file(http_path) {
-						Ok(reqs) => reqs,
+					let parsed_file = match http_file::parse_http_file(http_path) {
+						Ok(parsed_file) => parsed_file,
 						Err(e) => {
 							warn!(
 								"Could not parse .http file \"{}\": {}",
@@ -231,34 +255,35 @@ impl<'a> App<'a> {
 						}
 					};
 
+					let parsed_requests = parsed_file.requests;
+
 					for req in &parsed_requests {
 						req.write().source_path = Some(http_path.clone());
 					}
 
-					if components.len() == 1 {
+					// File variables are shared by the whole collection
+					variables.extend(parsed_file.variables);
+
+					// File in a subdirectory -> folder of each directory component.
+					// Paths are sorted, so folders are created in alphabetical order.
+					let folder_names: Vec<String> = components[..components.len() - 1]
+						.iter()
+						.map(|component| {
+							component
+								.as_os_str()
+								.to_str()
+								.unwrap_or("unknown")
+								.to_string()
+						})
+						.collect();
+
+					match get_or_create_folder(&mut folders, &folder_names) {
+						Some(folder) => folder.requests.extend(parsed_requests),
 						// File directly in requests/ -> root-level requests
-						root_requests.extend(parsed_requests);
-					} else {
-						// File in a subdirectory -> folder named after the first path component
-						let folder_name = components[0]
-							.as_os_str()
-							.to_str()
-							.unwrap_or("unknown")
-							.to_string();
-
-						folder_map
-							.entry(folder_name)
-							.or_default()
-							.extend(parsed_requests);
+						None => root_requests.extend(parsed_requests),
 					}
 				}
 
-				// Build folders from BTreeMap (alphabetically ordered by key)
-				let folders: Vec<Folder> = folder_map
-					.into_iter()
-					.map(|(name, requests)| Folder { name, requests })
-					.collect();
-
 				if !root_requests.is_empty() || !folders.is_empty() {
 					trace!(
 						"Found {} root request(s) and {} folder(s) in requests/ directory, \
@@ -281,6 +306,11 @@ impl<'a> App<'a> {
 						file_format: CollectionFileFormat::Http,
 						environments,
 						selected_environment,
+						variables,
+						tls: TlsSettings::default(),
+						auth: None,
+						headers: vec![],
+						scripts: RequestScripts::default(),
 					};
 
 					self.core.collections.push(collection);
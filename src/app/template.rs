@@ -0,0 +1,862 @@
+//! Template evaluator of the `{{...}}` placeholders used in requests and environment values.
+//!
+//! Supported syntax:
+//! - `{{KEY}}`, looked up in the given maps in order, then in the built-in variables (`NOW`,
+//!   `TIMESTAMP`, `UUIDv4`, `UUIDv7`)
+//! - `{{KEY:-fallback}}`, the fallback being used when the key is missing or empty
+//! - `{{$env.NAME}}` for the process environment variables
+//! - `{{$function}}` and `{{$function(argument, "quoted argument")}}`, see [`call_function`]
+//!
+//! Values referencing other keys are evaluated too. A value referencing its own key gets it from
+//! the next maps. Placeholders that cannot be evaluated are left as is and reported.
+
+use std::fmt::Write;
+use std::fs;
+use std::mem::take;
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use chrono::{DateTime, SecondsFormat, TimeDelta, Utc};
+use indexmap::IndexMap;
+use md5::Md5;
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, percent_decode_str, utf8_percent_encode};
+use rand::Rng;
+use rand::distr::Alphanumeric;
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::app::files::environment::OS_ENV_VARS;
+
+/// Characters kept as is by `$urlEncode`, as in RFC 3986 unreserved characters
+pub const URL_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+	.remove(b'-')
+	.remove(b'_')
+	.remove(b'.')
+	.remove(b'~');
+
+const DEFAULT_RANDOM_INT_MAX: i64 = 1000;
+const DEFAULT_RANDOM_STRING_LENGTH: usize = 16;
+const MAX_RANDOM_STRING_LENGTH: usize = 4096;
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum TemplateError {
+	#[error("Unresolved variable \"{0}\"")]
+	UnresolvedVariable(String),
+
+	#[error("Unresolved environment variable \"$env.{0}\"")]
+	UnresolvedEnvVariable(String),
+
+	#[error("Unknown function \"${0}\"")]
+	UnknownFunction(String),
+
+	#[error("Invalid arguments, expected {0}")]
+	InvalidArguments(&'static str),
+
+	#[error("Invalid date offset \"{0}\", expected e.g. \"+1d\", \"-2h30m\"")]
+	InvalidDateOffset(String),
+
+	#[error("Invalid date format \"{0}\"")]
+	InvalidDateFormat(String),
+
+	#[error("Could not decode \"{0}\"")]
+	CouldNotDecode(String),
+
+	#[error("Could not read file \"{0}\" ({1})")]
+	CouldNotReadFile(String, String),
+}
+
+/// Evaluated template
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Interpolation {
+	pub value: String,
+	/// Why the placeholders left as is could not be evaluated, without duplicates
+	pub errors: Vec<TemplateError>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+	Text(String),
+	Placeholder {
+		/// Written placeholder, kept when it cannot be evaluated
+		source: String,
+		expression: Expression,
+	},
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expression {
+	Variable {
+		key: String,
+		fallback: Option<Vec<Segment>>,
+	},
+	EnvVariable(String),
+	Function {
+		name: String,
+		arguments: Vec<Vec<Segment>>,
+	},
+}
+
+/// End of the segments being parsed
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Until {
+	InputEnd,
+	/// `}}` of a fallback
+	PlaceholderEnd,
+	/// `"` of a quoted argument
+	Quote,
+	/// `,` or `)` of an unquoted argument
+	ArgumentEnd,
+}
+
+/// Evaluate the placeholders of the input, keys being looked up in the environment maps in order
+pub fn evaluate_template(input: &str, env_maps: &[&IndexMap<String, String>]) -> Interpolation {
+	let mut evaluator = Evaluator {
+		env_maps,
+		resolving: vec![],
+		errors: vec![],
+	};
+
+	let value = evaluator.evaluate(&parse(input));
+
+	Interpolation {
+		value,
+		errors: evaluator.errors,
+	}
+}
+
+fn parse(input: &str) -> Vec<Segment> {
+	let mut parser = Parser {
+		chars: input.chars().collect(),
+		position: 0,
+	};
+
+	// Never fails, unclosed placeholders are parsed as text
+	parser.segments(Until::InputEnd).unwrap_or_default()
+}
+
+struct Parser {
+	chars: Vec<char>,
+	position: usize,
+}
+
+impl Parser {
+	fn peek(&self) -> Option<char> {
+		self.chars.get(self.position).copied()
+	}
+
+	fn starts_with(&self, pattern: &str) -> bool {
+		pattern
+			.chars()
+			.enumerate()
+			.all(|(index, char)| self.chars.get(self.position + index) == Some(&char))
+	}
+
+	fn skip_whitespaces(&mut self) {
+		while self.peek().is_some_and(char::is_whitespace) {
+			self.position += 1;
+		}
+	}
+
+	fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> String {
+		let mut taken = String::new();
+
+		while let Some(char) = self.peek().filter(|char| predicate(*char)) {
+			taken.push(char);
+			self.position += 1;
+		}
+
+		taken
+	}
+
+	/// Parse texts and placeholders up to the end of the context, `None` if the end is missing
+	fn segments(&mut self, until: Until) -> Option<Vec<Segment>> {
+		let mut segments = vec![];
+		let mut text = String::new();
+
+		loop {
+			let Some(char) = self.peek() else {
+				match until {
+					Until::InputEnd => break,
+					_ => return None,
+				}
+			};
+
+			let is_end = match until {
+				Until::InputEnd => false,
+				Until::PlaceholderEnd => self.starts_with("}}"),
+				Until::Quote => char == '"',
+				Until::ArgumentEnd => char == ',' || char == ')',
+			};
+
+			if is_end {
+				break;
+			}
+
+			if until == Until::Quote && char == '\\' {
+				match self.chars.get(self.position + 1) {
+					Some(escaped @ ('"' | '\\')) => {
+						text.push(*escaped);
+						self.position += 2;
+					}
+					_ => {
+						text.push(char);
+						self.position += 1;
+					}
+				}
+
+				continue;
+			}
+
+			// In "{{{KEY}}}", the first brace is text
+			if self.starts_with("{{") && !self.starts_with("{{{") {
+				let start = self.position;
+				self.position += 2;
+
+				match self.placeholder() {
+					Some(expression) => {
+						if !text.is_empty() {
+							segments.push(Segment::Text(take(&mut text)));
+						}
+
+						segments.push(Segment::Placeholder {
+							source: self.chars[start..self.position].iter().collect(),
+							expression,
+						});
+					}
+					None => {
+						self.position = start + 2;
+						text.push_str("{{");
+					}
+				}
+
+				continue;
+			}
+
+			text.push(char);
+			self.position += 1;
+		}
+
+		if !text.is_empty() {
+			segments.push(Segment::Text(text));
+		}
+
+		Some(segments)
+	}
+
+	/// Parse a placeholder content and its closing braces
+	fn placeholder(&mut self) -> Option<Expression> {
+		self.skip_whitespaces();
+
+		let expression = match self.peek()? {
+			'$' => {
+				self.position += 1;
+				self.function()?
+			}
+			_ => self.variable()?,
+		};
+
+		self.skip_whitespaces();
+
+		if !self.starts_with("}}") {
+			return None;
+		}
+
+		self.position += 2;
+		Some(expression)
+	}
+
+	fn variable(&mut self) -> Option<Expression> {
+		let mut key = String::new();
+
+		while !self.starts_with("}}") && !self.starts_with(":-") {
+			match self.peek()? {
+				'{' | '}' | '"' | '\n' => return None,
+				char => key.push(char),
+			}
+
+			self.position += 1;
+		}
+
+		let key = key.trim_end().to_string();
+
+		if key.is_empty() {
+			return None;
+		}
+
+		let fallback = match self.starts_with(":-") {
+			true => {
+				self.position += 2;
+				Some(self.segments(Until::PlaceholderEnd)?)
+			}
+			false => None,
+		};
+
+		Some(Expression::Variable { key, fallback })
+	}
+
+	fn function(&mut self) -> Option<Expression> {
+		let name = self.take_while(|char| char.is_ascii_alphanumeric() || char == '_');
+
+		if name.is_empty() {
+			return None;
+		}
+
+		if name == "env" && self.peek() == Some('.') {
+			self.position += 1;
+
+			let env_name = self.take_while(|char| !char.is_whitespace() && char != '}');
+
+			return match env_name.is_empty() {
+				true => None,
+				false => Some(Expression::EnvVariable(env_name)),
+			};
+		}
+
+		let mut arguments = vec![];
+
+		self.skip_whitespaces();
+
+		if self.peek() == Some('(') {
+			self.position += 1;
+			self.skip_whitespaces();
+
+			if self.peek() == Some(')') {
+				self.position += 1;
+			} else {
+				loop {
+					arguments.push(self.argument()?);
+
+					match self.peek()? {
+						',' => self.position += 1,
+						')' => {
+							self.position += 1;
+							break;
+						}
+						_ => return None,
+					}
+				}
+			}
+		}
+
+		Some(Expression::Function { name, arguments })
+	}
+
+	fn argument(&mut self) -> Option<Vec<Segment>> {
+		self.skip_whitespaces();
+
+		if self.peek() == Some('"') {
+			self.position += 1;
+			let segments = self.segments(Until::Quote)?;
+			self.position += 1;
+			self.skip_whitespaces();
+
+			return Some(segments);
+		}
+
+		let mut segments = self.segments(Until::ArgumentEnd)?;
+
+		if let Some(Segment::Text(text)) = segments.last_mut() {
+			text.truncate(text.trim_end().len());
+		}
+
+		Some(segments)
+	}
+}
+
+struct Evaluator<'a> {
+	env_maps: &'a [&'a IndexMap<String, String>],
+	/// Keys being evaluated with the index of the map they were found in
+	resolving: Vec<(String, usize)>,
+	errors: Vec<TemplateError>,
+}
+
+impl Evaluator<'_> {
+	fn evaluate(&mut self, segments: &[Segment]) -> String {
+		let mut output = String::new();
+
+		for segment in segments {
+			match segment {
+				Segment::Text(text) => output.push_str(text),
+				Segment::Placeholder { source, expression } => match self.expression(expression) {
+					Ok(value) => output.push_str(&value),
+					Err(error) => {
+						if !self.errors.contains(&error) {
+							self.errors.push(error);
+						}
+
+						output.push_str(source);
+					}
+				},
+			}
+		}
+
+		output
+	}
+
+	fn expression(&mut self, expression: &Expression) -> Result<String, TemplateError> {
+		match expression {
+			Expression::Variable { key, fallback } => match (self.variable(key), fallback) {
+				(Some(value), None) => Ok(value),
+				(Some(value), Some(_)) if !value.is_empty() => Ok(value),
+				(_, Some(fallback)) => Ok(self.evaluate(fallback)),
+				(None, None) => Err(TemplateError::UnresolvedVariable(key.clone())),
+			},
+			Expression::EnvVariable(name) => OS_ENV_VARS
+				.get(name)
+				.cloned()
+				.ok_or_else(|| TemplateError::UnresolvedEnvVariable(name.clone())),
+			Expression::Function { name, arguments } => {
+				let arguments: Vec<String> = arguments
+					.iter()
+					.map(|argument| self.evaluate(argument))
+					.collect();
+
+				call_function(name, &arguments)
+			}
+		}
+	}
+
+	/// Evaluated value of a key, a key referenced by its own value being looked up in the next maps
+	fn variable(&mut self, key: &str) -> Option<String> {
+		let first_map = self
+			.resolving
+			.iter()
+			.filter(|(resolving_key, _)| resolving_key == key)
+			.map(|(_, map_index)| map_index + 1)
+			.max()
+			.unwrap_or(0);
+
+		let found = self
+			.env_maps
+			.iter()
+			.enumerate()
+			.skip(first_map)
+			.find_map(|(map_index, map)| map.get(key).map(|value| (map_index, value)));
+
+		let Some((map_index, value)) = found else {
+			return builtin_variable(key);
+		};
+
+		self.resolving.push((key.to_string(), map_index));
+		let value = self.evaluate(&parse(value));
+		self.resolving.pop();
+
+		Some(value)
+	}
+}
+
+fn builtin_variable(key: &str) -> Option<String> {
+	match key {
+		"NOW" => Some(Utc::now().to_string()),
+		"TIMESTAMP" => Some(Utc::now().timestamp().to_string()),
+		"UUIDv4" => Some(Uuid::new_v4().to_string()),
+		"UUIDv7" => Some(Uuid::now_v7().to_string()),
+		_ => None,
+	}
+}
+
+/// Call a `$function` with its evaluated arguments:
+/// - `$uuid`, `$guid`, `$randomUUID`
+/// - `$timestamp`, `$timestamp(offset)`, `$isoTimestamp`
+/// - `$date`, `$date(format)`, `$date(format, offset)`, with a chrono `strftime` format and an
+///   offset such as `+1d`, `-2h30m` (units: `s`, `m`, `h`, `d`, `w`)
+/// - `$randomInt`, `$randomInt(max)`, `$randomInt(min, max)`, bounds included
+/// - `$randomString`, `$randomString(length)`, `$randomString(min, max)`, alphanumeric, at most
+///   [`MAX_RANDOM_STRING_LENGTH`] characters
+/// - `$base64(text)`, `$base64Decode(text)`, `$urlEncode(text)`, `$urlDecode(text)`
+/// - `$md5(text)`, `$sha1(text)`, `$sha256(text)`, `$sha512(text)`, hexadecimal digests
+/// - `$file(path)`, the file content
+fn call_function(name: &str, arguments: &[String]) -> Result<String, TemplateError> {
+	let arguments: Vec<&str> = arguments.iter().map(String::as_str).collect();
+
+	match (name, arguments.as_slice()) {
+		("uuid" | "guid" | "randomUUID", []) => Ok(Uuid::new_v4().to_string()),
+		("timestamp", []) => Ok(Utc::now().timestamp().to_string()),
+		("timestamp", [offset]) => Ok(offset_date(offset)?.timestamp().to_string()),
+		("isoTimestamp", []) => Ok(Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)),
+		("date", []) => Ok(Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true)),
+		("date", [format]) => format_date(Utc::now(), format),
+		("date", [format, offset]) => format_date(offset_date(offset)?, format),
+		("randomInt", []) => Ok(random_int(0, DEFAULT_RANDOM_INT_MAX)),
+		("randomInt", [max]) => {
+			let max = parse_argument(name, max)?;
+
+			match max >= 0 {
+				true => Ok(random_int(0, max)),
+				false => Err(TemplateError::InvalidArguments(usage(name))),
+			}
+		}
+		("randomInt", [min, max]) => {
+			let (min, max) = (parse_argument(name, min)?, parse_argument(name, max)?);
+
+			match min <= max {
+				true => Ok(random_int(min, max)),
+				false => Err(TemplateError::InvalidArguments(usage(name))),
+			}
+		}
+		("randomString", []) => Ok(random_string(DEFAULT_RANDOM_STRING_LENGTH)),
+		("randomString", [length]) => {
+			let length = parse_argument(name, length)?;
+
+			match length <= MAX_RANDOM_STRING_LENGTH {
+				true => Ok(random_string(length)),
+				false => Err(TemplateError::InvalidArguments(usage(name))),
+			}
+		}
+		("randomString", [min, max]) => {
+			let (min, max): (usize, usize) =
+				(parse_argument(name, min)?, parse_argument(name, max)?);
+
+			match min <= max && max <= MAX_RANDOM_STRING_LENGTH {
+				true => Ok(random_string(rand::rng().random_range(min..=max))),
+				false => Err(TemplateError::InvalidArguments(usage(name))),
+			}
+		}
+		("base64", [text]) => Ok(BASE64_STANDARD.encode(text)),
+		("base64Decode", [text]) => BASE64_STANDARD
+			.decode(text.trim())
+			.ok()
+			.and_then(|bytes| String::from_utf8(bytes).ok())
+			.ok_or_else(|| TemplateError::CouldNotDecode(text.to_string())),
+		("urlEncode", [text]) => Ok(utf8_percent_encode(text, URL_ENCODE_SET).to_string()),
+		("urlDecode", [text]) => percent_decode_str(text)
+			.decode_utf8()
+			.map(|decoded| decoded.to_string())
+			.map_err(|_| TemplateError::CouldNotDecode(text.to_string())),
+		("md5", [text]) => Ok(hex::encode(Md5::digest(text))),
+		("sha1", [text]) => Ok(hex::encode(Sha1::digest(text))),
+		("sha256", [text]) => Ok(hex::encode(Sha256::digest(text))),
+		("sha512", [text]) => Ok(hex::encode(Sha512::digest(text))),
+		("file", [path]) => fs::read(path)
+			.map(|content| String::from_utf8_lossy(&content).to_string())
+			.map_err(|error| TemplateError::CouldNotReadFile(path.to_string(), error.to_string())),
+		_ => match usage(name) {
+			"" => Err(TemplateError::UnknownFunction(name.to_string())),
+			usage => Err(TemplateError::InvalidArguments(usage)),
+		},
+	}
+}
+
+/// Expected calls of a function, empty for unknown functions
+fn usage(name: &str) -> &'static str {
+	match name {
+		"uuid" => "$uuid",
+		"guid" => "$guid",
+		"randomUUID" => "$randomUUID",
+		"timestamp" => "$timestamp or $timestamp(offset)",
+		"isoTimestamp" => "$isoTimestamp",
+		"date" => "$date, $date(format) or $date(format, offset)",
+		"randomInt" => "$randomInt, $randomInt(max) or $randomInt(min, max)",
+		"randomString" => {
+			"$randomString, $randomString(length) or $randomString(min, max), up to 4096 characters"
+		}
+		"base64" => "$base64(text)",
+		"base64Decode" => "$base64Decode(text)",
+		"urlEncode" => "$urlEncode(text)",
+		"urlDecode" => "$urlDecode(text)",
+		"md5" => "$md5(text)",
+		"sha1" => "$sha1(text)",
+		"sha256" => "$sha256(text)",
+		"sha512" => "$sha512(text)",
+		"file" => "$file(path)",
+		_ => "",
+	}
+}
+
+fn parse_argument<T: std::str::FromStr>(name: &str, argument: &str) -> Result<T, TemplateError> {
+	argument
+		.trim()
+		.parse()
+		.map_err(|_| TemplateError::InvalidArguments(usage(name)))
+}
+
+fn random_int(min: i64, max: i64) -> String {
+	rand::rng().random_range(min..=max).to_string()
+}
+
+fn random_string(length: usize) -> String {
+	rand::rng()
+		.sample_iter(&Alphanumeric)
+		.take(length)
+		.map(char::from)
+		.collect()
+}
+
+/// Current date moved by an offset such as `+1d`, `-2h30m` or `1w`
+fn offset_date(offset: &str) -> Result<DateTime<Utc>, TemplateError> {
+	let invalid_offset = || TemplateError::InvalidDateOffset(offset.to_string());
+
+	let trimmed = offset.trim();
+	let (sign, mut rest) = match trimmed.strip_prefix('-') {
+		Some(rest) => (-1, rest),
+		None => (1, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+	};
+
+	if rest.is_empty() {
+		return Err(invalid_offset());
+	}
+
+	let mut seconds: i64 = 0;
+
+	while !rest.is_empty() {
+		let digits_end = rest
+			.find(|char: char| !char.is_ascii_digit())
+			.ok_or_else(invalid_offset)?;
+		let amount: i64 = rest[..digits_end].parse().map_err(|_| invalid_offset())?;
+
+		let unit_seconds = match rest[digits_end..].chars().next() {
+			Some('s') => 1,
+			Some('m') => 60,
+			Some('h') => 3600,
+			Some('d') => 86_400,
+			Some('w') => 604_800,
+			_ => return Err(invalid_offset()),
+		};
+
+		seconds = amount
+			.checked_mul(unit_seconds)
+			.and_then(|amount_seconds| seconds.checked_add(amount_seconds))
+			.ok_or_else(invalid_offset)?;
+		rest = &rest[digits_end + 1..];
+	}
+
+	TimeDelta::try_seconds(sign * seconds)
+		.and_then(|delta| Utc::now().checked_add_signed(delta))
+		.ok_or_else(invalid_offset)
+}
+
+fn format_date(date: DateTime<Utc>, format: &str) -> Result<String, TemplateError> {
+	let mut formatted = String::new();
+
+	// Invalid formats are only detected while writing
+	match write!(formatted, "{}", date.format(format)) {
+		Ok(()) => Ok(formatted),
+		Err(_) => Err(TemplateError::InvalidDateFormat(format.to_string())),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn evaluate(input: &str, env: &IndexMap<String, String>) -> Interpolation {
+		evaluate_template(input, &[env])
+	}
+
+	fn env(values: &[(&str, &str)]) -> IndexMap<String, String> {
+		values
+			.iter()
+			.map(|(key, value)| (key.to_string(), value.to_string()))
+			.collect()
+	}
+
+	#[test]
+	fn fallback_is_used_for_missing_or_empty_keys() {
+		let env = env(&[("EMPTY", ""), ("HOST", "example.com"), ("PORT", "8080")]);
+
+		let result = evaluate(
+			"{{HOST:-localhost}} {{EMPTY:-none}} {{MISSING:-{{PORT}}}} {{MISSING:-}}",
+			&env,
+		);
+		assert_eq!(result.value, "example.com none 8080 ");
+		assert!(result.errors.is_empty());
+	}
+
+	#[test]
+	fn unresolved_placeholders_are_reported_once() {
+		let result = evaluate("{{A}}/{{A}}/{{$nope}}/{{$env.SQURL_NOT_SET}}", &env(&[]));
+
+		assert_eq!(result.value, "{{A}}/{{A}}/{{$nope}}/{{$env.SQURL_NOT_SET}}");
+		assert_eq!(
+			result.errors,
+			[
+				TemplateError::UnresolvedVariable(String::from("A")),
+				TemplateError::UnknownFunction(String::from("nope")),
+				TemplateError::UnresolvedEnvVariable(String::from("SQURL_NOT_SET")),
+			]
+		);
+	}
+
+	#[test]
+	fn values_are_evaluated() {
+		let base = env(&[("URL", "{{HOST}}/{{VERSION}}"), ("VERSION", "v1")]);
+		let next = env(&[("HOST", "localhost"), ("VERSION", "v2")]);
+
+		let result = evaluate_template("{{URL}}", &[&base, &next]);
+		assert_eq!(result.value, "localhost/v1");
+	}
+
+	#[test]
+	fn self_references_use_the_next_maps() {
+		let local = env(&[("PATH", "{{PATH}}/local"), ("LOOP", "{{OTHER}}")]);
+		let parent = env(&[("PATH", "/api"), ("OTHER", "{{LOOP}}")]);
+
+		let result = evaluate_template("{{PATH}} {{LOOP}}", &[&local, &parent]);
+		assert_eq!(result.value, "/api/local {{LOOP}}");
+		assert_eq!(
+			result.errors,
+			[TemplateError::UnresolvedVariable(String::from("LOOP"))]
+		);
+	}
+
+	#[test]
+	fn process_env_variables() {
+		let path = OS_ENV_VARS.get("PATH").cloned().unwrap_or_default();
+
+		let result = evaluate("{{$env.PATH}}", &env(&[("PATH", "ignored")]));
+		assert_eq!(result.value, path);
+	}
+
+	#[test]
+	fn random_values_respect_their_bounds() {
+		for _ in 0..50 {
+			let value: i64 = evaluate("{{$randomInt(-3, 3)}}", &env(&[]))
+				.value
+				.parse()
+				.unwrap();
+			assert!((-3..=3).contains(&value));
+
+			let value = evaluate("{{$randomString(2, 4)}}", &env(&[])).value;
+			assert!((2..=4).contains(&value.len()));
+			assert!(value.chars().all(|char| char.is_ascii_alphanumeric()));
+		}
+
+		assert_eq!(evaluate("{{$randomString}}", &env(&[])).value.len(), 16);
+		assert_eq!(
+			evaluate("{{$randomInt(3, 1)}}", &env(&[])).errors,
+			[TemplateError::InvalidArguments(
+				"$randomInt, $randomInt(max) or $randomInt(min, max)"
+			)]
+		);
+	}
+
+	#[test]
+	fn random_int_rejects_a_negative_max() {
+		assert_eq!(
+			evaluate("{{$randomInt(-5)}}", &env(&[])).errors,
+			[TemplateError::InvalidArguments(
+				"$randomInt, $randomInt(max) or $randomInt(min, max)"
+			)]
+		);
+		assert_eq!(evaluate("{{$randomInt(0)}}", &env(&[])).value, "0");
+	}
+
+	#[test]
+	fn random_string_length_is_capped() {
+		let usage = "$randomString, $randomString(length) or $randomString(min, max), up to 4096 characters";
+
+		assert_eq!(
+			evaluate("{{$randomString(4096)}}", &env(&[])).value.len(),
+			4096
+		);
+		assert_eq!(
+			evaluate("{{$randomString(4097)}}", &env(&[])).errors,
+			[TemplateError::InvalidArguments(usage)]
+		);
+		assert_eq!(
+			evaluate("{{$randomString(1, 1000000000)}}", &env(&[])).errors,
+			[TemplateError::InvalidArguments(usage)]
+		);
+	}
+
+	#[test]
+	fn dates_are_formatted_and_offset() {
+		let tomorrow = (Utc::now() + TimeDelta::days(1))
+			.format("%Y-%m-%d")
+			.to_string();
+		let result = evaluate(r#"{{$date("%Y-%m-%d", "+1d")}}"#, &env(&[]));
+		assert_eq!(result.value, tomorrow);
+
+		let timestamp: i64 = evaluate("{{$timestamp(-1h30m)}}", &env(&[]))
+			.value
+			.parse()
+			.unwrap();
+		assert!((Utc::now().timestamp() - 5400 - timestamp).abs() <= 1);
+
+		let result = evaluate("{{$date(%Y, 2x)}}", &env(&[]));
+		assert_eq!(
+			result.errors,
+			[TemplateError::InvalidDateOffset(String::from("2x"))]
+		);
+
+		let result = evaluate("{{$date(%Q)}}", &env(&[]));
+		assert_eq!(
+			result.errors,
+			[TemplateError::InvalidDateFormat(String::from("%Q"))]
+		);
+	}
+
+	#[test]
+	fn encodings_and_hashes() {
+		let env = env(&[("USER", "squrl"), ("PASSWORD", "p@ss word")]);
+
+		let cases = [
+			(
+				r#"{{$base64("{{USER}}:{{PASSWORD}}")}}"#,
+				"c3F1cmw6cEBzcyB3b3Jk",
+			),
+			("{{$base64Decode(c3F1cmw=)}}", "squrl"),
+			("{{$urlEncode({{PASSWORD}})}}", "p%40ss%20word"),
+			("{{$urlDecode(p%40ss%20word)}}", "p@ss word"),
+			("{{$md5(squrl)}}", "c9e8569c424e3cfa428468740e924c99"),
+			(
+				"{{$sha256(squrl)}}",
+				"8e86e9dab0d45d56e364298588ed4850d275409ae7ceff8c7219909bf04602b8",
+			),
+		];
+
+		for (input, expected) in cases {
+			assert_eq!(evaluate(input, &env).value, expected, "input was: {input}");
+		}
+	}
+
+	#[test]
+	fn quoted_arguments_keep_commas_and_escaped_quotes() {
+		let result = evaluate(
+			r#"{{$base64Decode("{{$base64("a, \"b\")")}}")}}"#,
+			&env(&[]),
+		);
+		assert_eq!(result.value, r#"a, "b")"#);
+	}
+
+	#[test]
+	fn file_contents() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("token.txt");
+		fs::write(&path, "file-token").unwrap();
+
+		let env = env(&[("TOKEN_FILE", path.to_str().unwrap())]);
+
+		let result = evaluate("Bearer {{$file({{TOKEN_FILE}})}}", &env);
+		assert_eq!(result.value, "Bearer file-token");
+
+		let result = evaluate("{{$file(missing.txt)}}", &env);
+		assert_eq!(result.value, "{{$file(missing.txt)}}");
+		assert!(matches!(
+			result.errors[0],
+			TemplateError::CouldNotReadFile(..)
+		));
+	}
+
+	#[test]
+	fn non_placeholders_are_text() {
+		let env = env(&[("KEY", "value")]);
+
+		for input in [
+			r#"{"a": {"b": 1}}"#,
+			r#"{{"a": 1}}"#,
+			"{{ }}",
+			"{{KEY",
+			"{{$base64(unclosed}}",
+			"{{$}}",
+		] {
+			let result = evaluate(input, &env);
+			assert_eq!(result.value, input);
+			assert!(result.errors.is_empty(), "input was: {input}");
+		}
+
+		assert_eq!(evaluate("{{ KEY }}", &env).value, "value");
+	}
+}
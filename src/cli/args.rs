@@ -4,7 +4,9 @@ use std::{env, fs};
 use crate::app::files::utils::expand_tilde;
 use crate::cli::commands::collection_commands::collection_commands::CollectionCommand;
 use crate::cli::commands::completions::CompletionsCommand;
+use crate::cli::commands::cookies::CookiesCommand;
 use crate::cli::commands::env::EnvCommand;
+use crate::cli::commands::history::HistoryCommand;
 use crate::cli::commands::import::ImportCommand;
 use crate::cli::commands::man::ManCommand;
 use crate::cli::commands::request_commands::request_commands::RequestCommand;
@@ -89,6 +91,28 @@ squrl
 		  - set
 		  - delete
 		  - rename
+	  - resolve
+	  - parent
+		  - get
+		  - set
+		  - remove
+	  - secret
+		  - list
+		  - get
+		  - set
+		  - mark
+		  - unmark
+	  - rotate-passphrase
+  - cookies
+	  - list
+	  - clear
+	  - import
+	  - export
+  - history
+	  - list
+	  - show
+	  - replay
+	  - diff
   - import
 	  - postman
 	  - curl
@@ -116,6 +140,12 @@ pub enum Command {
 	/// Environment commands
 	Env(EnvCommand),
 
+	/// Cookie jar commands (list, clear, import, export)
+	Cookies(CookiesCommand),
+
+	/// Request history commands (list, show, replay, diff)
+	History(HistoryCommand),
+
 	/// Import a collection, a request or an environment from other file formats (Postman v2.1.0, cURL, OpenAPI)
 	Import(ImportCommand),
 
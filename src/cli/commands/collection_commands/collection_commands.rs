@@ -1,4 +1,9 @@
+use crate::cli::commands::collection_commands::folder::FolderCommand;
+use crate::cli::commands::collection_commands::run::RunCommand;
 use crate::cli::commands::key::KeyCommand;
+use crate::cli::commands::key_value::KeyValueCommand;
+use crate::cli::commands::request_commands::auth::AuthCommand;
+use crate::cli::commands::request_commands::scripts::ScriptsCommand;
 use crate::cli::commands::request_commands::send::SendCommand;
 use clap::Subcommand;
 
@@ -53,15 +58,67 @@ pub enum CollectionSubcommand {
 		new_collection_name: String,
 	},
 
-	/// Send all the collection's requests
+	/// Run all the collection's requests, folders included, and report failures
 	Send {
 		/// e.g. my_collection, "my collection"
 		collection_name: String,
 
+		#[clap(flatten)]
+		run_command: RunCommand,
+
 		#[clap(flatten)]
 		subcommand: SendCommand,
 	},
 
+	/// Auth inherited by the requests using the "inherit" auth method. Set it to "inherit" to
+	/// remove it.
+	Auth {
+		/// Collection name
+		collection_name: String,
+
+		/// Manage the auth of this folder instead of the collection one, e.g. admin/users
+		#[arg(long)]
+		folder: Option<String>,
+
+		#[command(subcommand)]
+		subcommand: AuthCommand,
+	},
+
+	/// Headers added to every request, unless the request redefines them
+	Header {
+		/// Collection name
+		collection_name: String,
+
+		/// Manage the headers of this folder instead of the collection ones, e.g. admin/users
+		#[arg(long)]
+		folder: Option<String>,
+
+		#[command(subcommand)]
+		subcommand: KeyValueCommand,
+	},
+
+	/// Scripts run before the scripts of every request
+	Scripts {
+		/// Collection name
+		collection_name: String,
+
+		/// Manage the scripts of this folder instead of the collection ones, e.g. admin/users
+		#[arg(long)]
+		folder: Option<String>,
+
+		#[command(subcommand)]
+		subcommand: ScriptsCommand,
+	},
+
+	/// Manage the folders of a collection, at any depth
+	Folder {
+		/// Collection name
+		collection_name: String,
+
+		#[command(subcommand)]
+		subcommand: FolderCommand,
+	},
+
 	/// Manage collection-scoped environments
 	Env {
 		/// Collection name
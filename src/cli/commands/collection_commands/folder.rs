@@ -0,0 +1,33 @@
+use clap::Subcommand;
+
+/// Folders are addressed by their path from the collection root, e.g. "admin/users"
+#[derive(Subcommand, Debug, Clone)]
+pub enum FolderCommand {
+	/// Print the folders of the collection as a tree
+	List,
+
+	/// Create a folder, its parent folder must exist
+	New {
+		/// e.g. admin, admin/users
+		folder_path: String,
+	},
+
+	/// Delete a folder, its sub-folders and requests are moved to its parent
+	Delete { folder_path: String },
+
+	/// Rename a folder
+	Rename {
+		folder_path: String,
+
+		/// New folder name
+		new_folder_name: String,
+	},
+
+	/// Move a folder, with everything inside it, into another folder
+	Move {
+		folder_path: String,
+
+		/// Destination folder, the collection root when omitted
+		parent_folder_path: Option<String>,
+	},
+}
@@ -1,2 +1,4 @@
 #[allow(clippy::module_inception)]
 pub(crate) mod collection_commands;
+pub(crate) mod folder;
+pub(crate) mod run;
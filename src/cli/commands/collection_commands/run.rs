@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct RunCommand {
+	/// Only run the requests of this folder and of its sub-folders, e.g. admin/users (can be
+	/// repeated)
+	#[arg(long, value_name = "FOLDER_PATH")]
+	pub folder: Vec<String>,
+
+	/// Only run the request with this name (can be repeated)
+	#[arg(long, value_name = "REQUEST_NAME")]
+	pub request: Vec<String>,
+
+	/// Stop the run at the first failing request
+	#[arg(long, default_value_t = false)]
+	pub bail: bool,
+
+	/// Number of times the whole collection is run
+	#[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+	pub iterations: u32,
+
+	/// Write a JUnit XML report to this file
+	#[arg(long, value_name = "PATH", value_hint = clap::ValueHint::FilePath)]
+	pub junit: Option<PathBuf>,
+
+	/// Write a JSON report to this file
+	#[arg(long, value_name = "PATH", value_hint = clap::ValueHint::FilePath)]
+	pub json_report: Option<PathBuf>,
+}
@@ -0,0 +1,33 @@
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct CookiesCommand {
+	/// Use the cookie jar of this collection, when per-collection cookie jars are enabled
+	#[arg(short, long, global = true)]
+	pub collection: Option<String>,
+
+	#[command(subcommand)]
+	pub cookies_subcommand: CookiesSubCommand,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum CookiesSubCommand {
+	/// List the cookies of the jar
+	List,
+	/// Remove every cookie from the jar
+	Clear,
+	/// Import cookies from a Netscape cookies.txt file
+	Import {
+		/// Path to the cookies.txt file
+		#[arg(value_hint = clap::ValueHint::FilePath)]
+		path: PathBuf,
+	},
+	/// Export the cookies to the Netscape cookies.txt format
+	Export {
+		/// Output file, prints to stdout when omitted
+		#[arg(value_hint = clap::ValueHint::FilePath)]
+		path: Option<PathBuf>,
+	},
+}
@@ -1,4 +1,6 @@
 use crate::cli::commands::key::KeyCommand;
+use crate::cli::commands::parent::ParentCommand;
+use crate::cli::commands::secret::SecretCommand;
 use clap::Subcommand;
 
 #[derive(clap::Args, Debug, Clone)]
@@ -21,4 +23,29 @@ pub enum EnvSubCommand {
 		#[command(subcommand)]
 		subcommand: KeyCommand,
 	},
+	/// Print the effective values of an environment, its local overrides and parents included,
+	/// with the file each value comes from
+	Resolve {
+		env_name: String,
+
+		/// Show the secret values instead of masking them
+		#[arg(long, default_value_t = false)]
+		reveal_secrets: bool,
+	},
+	/// Environment whose values are inherited
+	Parent {
+		env_name: String,
+
+		#[command(subcommand)]
+		subcommand: ParentCommand,
+	},
+	/// Secret keys, whose values are kept in a passphrase-encrypted file
+	Secret {
+		env_name: String,
+
+		#[command(subcommand)]
+		subcommand: SecretCommand,
+	},
+	/// Encrypt the secrets with a new passphrase, read from SQURL_NEW_SECRETS_PASSPHRASE or prompted
+	RotatePassphrase,
 }
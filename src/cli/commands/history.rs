@@ -0,0 +1,48 @@
+use clap::Subcommand;
+
+use crate::cli::commands::request_commands::send::SendCommand;
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct HistoryCommand {
+	#[command(subcommand)]
+	pub history_subcommand: HistorySubCommand,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum HistorySubCommand {
+	/// List the recorded requests, newest first
+	List {
+		/// Only list the requests of this collection
+		#[arg(long, value_name = "COLLECTION_NAME")]
+		collection: Option<String>,
+
+		/// Only list the requests with this name
+		#[arg(long, value_name = "REQUEST_NAME")]
+		request: Option<String>,
+
+		/// Maximum number of entries to list
+		#[arg(short = 'n', long, default_value_t = 20)]
+		limit: usize,
+	},
+	/// Show a recorded request and its response metadata
+	Show {
+		/// Entry id, or a unique prefix of it
+		id: String,
+	},
+	/// Send a recorded HTTP request again, exactly as it was sent
+	Replay {
+		/// Entry id, or a unique prefix of it
+		id: String,
+
+		#[command(flatten)]
+		send_command: SendCommand,
+	},
+	/// Diff two entries of the same request
+	Diff {
+		/// Entry id, or a unique prefix of it
+		id: String,
+
+		/// Entry to compare with, defaults to the previous entry of the same request
+		other_id: Option<String>,
+	},
+}
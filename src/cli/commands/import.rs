@@ -34,7 +34,8 @@ pub struct PostmanImport {
 	#[clap(value_hint = clap::ValueHint::FilePath)]
 	pub import_path: PathBuf,
 
-	/// Max depth at which import should stop creating nested collections and only get the deeper requests
+	/// Max depth at which import should stop creating nested folders and only get the deeper
+	/// requests
 	#[arg(long)]
 	pub max_depth: Option<u16>,
 }
@@ -81,7 +82,7 @@ pub struct OpenApiImport {
 	#[clap(value_hint = clap::ValueHint::FilePath)]
 	pub import_path: PathBuf,
 
-	/// Max depth at which import should stop creating nested collections and only get the deeper requests
+	/// Max depth of the nested folders created from the operation tags, "/" separating folders
 	#[arg(long)]
 	pub max_depth: Option<u16>,
 }
@@ -99,7 +100,8 @@ pub struct HttpFileImport {
 	#[arg(short, long)]
 	pub recursive: bool,
 
-	/// Max depth at which import should stop creating nested collections and only get the deeper requests
+	/// Max depth at which import should stop searching for files, each sub-directory becoming a
+	/// nested folder
 	#[arg(long, requires = "recursive")]
 	pub max_depth: Option<u16>,
 }
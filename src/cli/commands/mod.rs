@@ -1,10 +1,14 @@
 pub(crate) mod collection_commands;
 pub(crate) mod completions;
+pub(crate) mod cookies;
 pub(crate) mod env;
+pub(crate) mod history;
 pub(crate) mod import;
 pub(crate) mod key;
 pub(crate) mod key_value;
 pub(crate) mod man;
+pub(crate) mod parent;
 pub(crate) mod request_commands;
+pub(crate) mod secret;
 pub(crate) mod theme;
 pub(crate) mod try_command;
@@ -0,0 +1,11 @@
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ParentCommand {
+	/// Print the parent environment name, if any
+	Get,
+	/// Inherit the values of another environment
+	Set { parent: String },
+	/// Stop inheriting values
+	Remove,
+}
@@ -0,0 +1,20 @@
+use clap::Subcommand;
+
+use crate::models::assertions::Assertion;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum AssertionsCommand {
+	/// Print the request assertions
+	All,
+	/// Add an assertion, e.g. "status == 200", "status in 200..299", "header content-type exists",
+	/// "header content-type matches json", "jsonpath $.id == 42", "duration < 500" or "schema {...}"
+	Add {
+		/// Assertion to add
+		assertion: Assertion,
+	},
+	/// Delete an assertion
+	Delete {
+		/// Index of the assertion, as printed by "all"
+		index: usize,
+	},
+}
@@ -8,6 +8,6 @@ pub enum AuthCommand {
 	/// Set the request auth method
 	Set {
 		#[command(subcommand)]
-		auth_method: Auth,
+		auth_method: Box<Auth>,
 	},
 }
@@ -0,0 +1,20 @@
+use clap::Subcommand;
+
+use crate::models::captures::Capture;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum CapturesCommand {
+	/// Print the request captures
+	All,
+	/// Add a capture, e.g. "TOKEN = jsonpath $.token", "ID = header x-request-id",
+	/// "CSRF = regex csrf=(\w+)" or "SESSION = cookie session_id"
+	Add {
+		/// Capture to add
+		capture: Capture,
+	},
+	/// Delete a capture
+	Delete {
+		/// Index of the capture, as printed by "all"
+		index: usize,
+	},
+}
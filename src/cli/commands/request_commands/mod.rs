@@ -1,5 +1,7 @@
+pub(crate) mod assertions;
 pub(crate) mod auth;
 pub(crate) mod body;
+pub(crate) mod captures;
 pub(crate) mod method;
 pub(crate) mod new;
 #[allow(clippy::module_inception)]
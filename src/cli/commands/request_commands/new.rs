@@ -43,19 +43,23 @@ pub struct NewRequestCommand {
 	#[arg(long, default_value_t = false, display_order = 20)]
 	pub no_redirects: bool,
 
-	#[arg(long, default_value_t = 30000, display_order = 21)]
+	/// Number of redirects followed before the last redirect response is kept
+	#[arg(long, default_value_t = 10, display_order = 21)]
+	pub max_redirects: u32,
+
+	#[arg(long, default_value_t = 30000, display_order = 22)]
 	pub timeout: u32,
 
-	#[arg(long, default_value_t = false, display_order = 22)]
+	#[arg(long, default_value_t = false, display_order = 23)]
 	pub no_cookies: bool,
 
-	#[arg(long, default_value_t = false, display_order = 23)]
+	#[arg(long, default_value_t = false, display_order = 24)]
 	pub no_pretty: bool,
 
-	#[arg(long, default_value_t = false, display_order = 24)]
+	#[arg(long, default_value_t = false, display_order = 25)]
 	pub accept_invalid_certs: bool,
 
-	#[arg(long, default_value_t = false, display_order = 25)]
+	#[arg(long, default_value_t = false, display_order = 26)]
 	pub accept_invalid_hostnames: bool,
 }
 
@@ -1,8 +1,10 @@
 use clap::Subcommand;
 
 use crate::cli::commands::key_value::KeyValueCommand;
+use crate::cli::commands::request_commands::assertions::AssertionsCommand;
 use crate::cli::commands::request_commands::auth::AuthCommand;
 use crate::cli::commands::request_commands::body::BodySubcommand;
+use crate::cli::commands::request_commands::captures::CapturesCommand;
 use crate::cli::commands::request_commands::method::MethodCommand;
 use crate::cli::commands::request_commands::new::NewRequestCommand;
 use crate::cli::commands::request_commands::scripts::ScriptsCommand;
@@ -29,6 +31,10 @@ pub enum RequestSubcommand {
 		#[arg(value_parser=collection_slash_request_validator)]
 		collection_slash_request: (String, String),
 
+		/// Create the request in this folder of the collection, e.g. admin/users
+		#[arg(long)]
+		folder: Option<String>,
+
 		#[clap(flatten)]
 		subcommand: NewRequestCommand,
 	},
@@ -91,6 +97,20 @@ pub enum RequestSubcommand {
 		#[command(subcommand)]
 		subcommand: ScriptsCommand,
 	},
+	Assertions {
+		#[arg(value_parser=collection_slash_request_validator)]
+		collection_slash_request: (String, String),
+
+		#[command(subcommand)]
+		subcommand: AssertionsCommand,
+	},
+	Captures {
+		#[arg(value_parser=collection_slash_request_validator)]
+		collection_slash_request: (String, String),
+
+		#[command(subcommand)]
+		subcommand: CapturesCommand,
+	},
 	Send {
 		#[arg(value_parser=collection_slash_request_validator)]
 		collection_slash_request: (String, String),
@@ -110,5 +130,9 @@ pub enum RequestSubcommand {
 		collection_slash_request: (String, String),
 
 		format: ExportFormat,
+
+		/// Show the secret environment values instead of masking them
+		#[arg(long, default_value_t = false)]
+		reveal_secrets: bool,
 	},
 }
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 #[derive(clap::Args, Debug, Clone)]
 pub struct SendCommand {
 	#[arg(long, default_value_t = false)]
@@ -9,6 +11,14 @@ pub struct SendCommand {
 	#[arg(long, default_value_t = false)]
 	pub duration: bool,
 
+	/// Print the time spent in each phase of HTTP requests and the connection details
+	#[arg(long, default_value_t = false)]
+	pub timing: bool,
+
+	/// Print the redirects followed before the response, with their headers and cookies
+	#[arg(long, default_value_t = false)]
+	pub redirects: bool,
+
 	#[arg(long, default_value_t = false)]
 	pub headers: bool,
 
@@ -21,6 +31,10 @@ pub struct SendCommand {
 	#[arg(long, default_value_t = false)]
 	pub request_name: bool,
 
+	/// Write the whole response body to this file instead of printing it
+	#[arg(short, long, value_name = "PATH")]
+	pub output: Option<PathBuf>,
+
 	/// Name of the global environment to use, e.g. my_env (from file .env.my_env)
 	#[arg(long, value_name = "ENV_NAME", display_order = 98)]
 	pub env: Option<String>,
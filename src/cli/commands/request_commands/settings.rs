@@ -17,7 +17,8 @@ pub enum SettingsCommand {
 		/// Setting name to set status
 		setting_name: RequestSettingName,
 
-		/// New value to apply to the setting
+		/// New value to apply to the setting, an empty path removes a certificate and an empty
+		/// password removes the password
 		new_value: Setting,
 	},
 }
@@ -28,10 +29,20 @@ pub enum RequestSettingName {
 	Proxy,
 	/// Allow redirects
 	Redirects,
+	/// Max redirects followed
+	MaxRedirects,
 	/// Timeout (ms)
 	Timeout,
 	/// Store received cookies
 	Cookies,
 	/// Pretty print response content
 	Pretty,
+	/// Client certificate PEM file, optionally holding the private key
+	ClientCert,
+	/// Client private key PEM file
+	ClientKey,
+	/// Password of a PKCS#12 client certificate
+	ClientCertPassword,
+	/// Comma separated CA certificate PEM files
+	CaCerts,
 }
@@ -0,0 +1,20 @@
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum SecretCommand {
+	/// List the secret keys
+	List,
+	/// Print the value of a secret key, masked unless revealed
+	Get {
+		key: String,
+
+		#[arg(long, default_value_t = false)]
+		reveal: bool,
+	},
+	/// Set or replace the value of a secret key, prompted or read from stdin when omitted
+	Set { key: String, value: Option<String> },
+	/// Move the value of an existing key to the secrets file
+	Mark { key: String },
+	/// Move the value of a secret key back to the environment file
+	Unmark { key: String },
+}
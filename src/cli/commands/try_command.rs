@@ -219,6 +219,15 @@ mod tests {
 		assert!(cli.cmd.no_redirects);
 	}
 
+	#[test]
+	fn new_request_max_redirects() {
+		let cli = NewRequestCli::try_parse_from(["test"]).unwrap();
+		assert_eq!(cli.cmd.max_redirects, 10);
+
+		let cli = NewRequestCli::try_parse_from(["test", "--max-redirects", "3"]).unwrap();
+		assert_eq!(cli.cmd.max_redirects, 3);
+	}
+
 	#[test]
 	fn new_request_parses_single_query_param() {
 		let cli = NewRequestCli::try_parse_from(["test", "--add-param", "key", "value"]).unwrap();
@@ -540,6 +549,18 @@ mod tests {
 		assert!(cli.cmd.duration);
 	}
 
+	#[test]
+	fn send_command_timing_flag() {
+		let cli = SendCli::try_parse_from(["test", "--timing"]).unwrap();
+		assert!(cli.cmd.timing);
+	}
+
+	#[test]
+	fn send_command_redirects_flag() {
+		let cli = SendCli::try_parse_from(["test", "--redirects"]).unwrap();
+		assert!(cli.cmd.redirects);
+	}
+
 	#[test]
 	fn send_command_headers_flag() {
 		let cli = SendCli::try_parse_from(["test", "--headers"]).unwrap();
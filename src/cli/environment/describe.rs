@@ -11,10 +11,15 @@ impl App<'_> {
 			let env = local_env.read();
 
 			println!("name: {}", env.name);
+
+			if let Some(parent) = &env.parent {
+				println!("parent: {parent}");
+			}
+
 			println!("values:");
 
 			for (key, value) in &env.values {
-				println!("\t{key}: {value}");
+				println!("\t{key}: {}", env.displayed_value(key, value));
 			}
 
 			if os_vars {
@@ -1 +1,2 @@
 mod describe;
+mod resolve;
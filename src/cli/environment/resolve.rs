@@ -0,0 +1,78 @@
+use indexmap::IndexMap;
+
+use crate::app::App;
+use crate::app::files::environment::OS_ENV_VARS;
+use crate::app::files::secrets::{SECRETS, mask_secrets};
+use crate::app::template::evaluate_template;
+use crate::models::environment::SECRET_MASK;
+
+impl App<'_> {
+	/// Print the effective values of an environment, with the file each value comes from
+	pub fn cli_resolve_env(
+		&mut self,
+		env_index: usize,
+		reveal_secrets: bool,
+	) -> anyhow::Result<()> {
+		let has_secrets_file = SECRETS.read().has_file();
+
+		if reveal_secrets && has_secrets_file {
+			self.unlock_secrets()?;
+		}
+
+		let layers = self.environment_layers(env_index);
+
+		let mut maps: Vec<&IndexMap<String, String>> =
+			layers.iter().map(|layer| &layer.values).collect();
+		maps.push(&OS_ENV_VARS);
+
+		// Inherited keys first, in the order of their first declaration
+		let mut keys: Vec<&String> = vec![];
+
+		for layer in layers.iter().rev() {
+			for key in layer.values.keys().chain(&layer.secret_keys) {
+				if !keys.contains(&key) {
+					keys.push(key);
+				}
+			}
+		}
+
+		let mut warnings = vec![];
+
+		for key in keys {
+			let Some(layer) = layers
+				.iter()
+				.find(|layer| layer.values.contains_key(key) || layer.secret_keys.contains(key))
+			else {
+				continue;
+			};
+
+			let value = match layer.values.contains_key(key) {
+				// Locked secrets have no value
+				false => String::from(SECRET_MASK),
+				true => {
+					let interpolation = evaluate_template(&format!("{{{{{key}}}}}"), &maps);
+
+					for error in interpolation.errors {
+						if !warnings.contains(&error) {
+							warnings.push(error);
+						}
+					}
+
+					match (reveal_secrets, layer.secret_keys.contains(key)) {
+						(true, _) => interpolation.value,
+						(false, true) => String::from(SECRET_MASK),
+						(false, false) => mask_secrets(&interpolation.value),
+					}
+				}
+			};
+
+			println!("{key}: {value} ({})", layer.origin);
+		}
+
+		for warning in warnings {
+			eprintln!("Warning: {warning}");
+		}
+
+		Ok(())
+	}
+}
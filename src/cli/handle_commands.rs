@@ -14,7 +14,9 @@ impl App<'_> {
 				self.handle_collection_command(collection_command).await
 			}
 			Completions(completions_command) => generate_completions(completions_command),
+			Cookies(cookies_command) => self.handle_cookies_command(cookies_command),
 			Env(env_command) => self.handle_env_commands(env_command),
+			History(history_command) => self.handle_history_command(history_command).await,
 			Import(import_command) => match &import_command.import_type {
 				ImportType::Postman(postman_import) => {
 					self.import_postman_collection(postman_import)
@@ -1,9 +1,19 @@
 use crate::app::App;
+use crate::app::key_value::{find_key, print_key_value_vector};
 use crate::cli::commands::collection_commands::collection_commands::{
 	CollectionCommand, CollectionEnvSubcommand, CollectionSubcommand,
 };
+use crate::cli::commands::collection_commands::folder::FolderCommand;
 use crate::cli::commands::key::KeyCommand;
+use crate::cli::commands::key_value::KeyValueCommand;
+use crate::cli::commands::request_commands::auth::AuthCommand;
+use crate::cli::commands::request_commands::scripts::ScriptsCommand;
+use crate::cli::request::auth::print_auth;
+use crate::models::auth::auth::Auth;
 use crate::models::collection::Collection;
+use crate::models::folder::Folder;
+use crate::models::request::KeyValue;
+use crate::models::scripts::ScriptType;
 
 impl App<'_> {
 	pub async fn handle_collection_command(
@@ -35,13 +45,217 @@ impl App<'_> {
 			} => self.cli_rename_collection(collection_name, new_collection_name.clone()),
 			CollectionSubcommand::Send {
 				collection_name,
+				run_command,
 				subcommand,
-			} => self.cli_send_collection(collection_name, subcommand).await,
+			} => {
+				self.cli_send_collection(collection_name, run_command, subcommand)
+					.await
+			}
 			CollectionSubcommand::Env {
 				collection_name,
 				subcommand,
 			} => self.handle_collection_env_command(collection_name, subcommand),
+			CollectionSubcommand::Folder {
+				collection_name,
+				subcommand,
+			} => self.handle_collection_folder_command(collection_name, subcommand),
+			CollectionSubcommand::Auth {
+				collection_name,
+				folder,
+				subcommand,
+			} => self.handle_collection_auth_command(collection_name, folder, subcommand),
+			CollectionSubcommand::Header {
+				collection_name,
+				folder,
+				subcommand,
+			} => self.handle_collection_header_command(collection_name, folder, subcommand),
+			CollectionSubcommand::Scripts {
+				collection_name,
+				folder,
+				subcommand,
+			} => self.handle_collection_scripts_command(collection_name, folder, subcommand),
+		}
+	}
+
+	/// Collection index, and folder path (empty for the collection itself) when a folder path is
+	/// given
+	fn find_collection_or_folder(
+		&mut self,
+		collection_name: &str,
+		folder_name: &Option<String>,
+	) -> anyhow::Result<(usize, Vec<usize>)> {
+		let collection_index = self.find_collection(collection_name)?;
+
+		let folder_path = match folder_name {
+			Some(folder_name) => self.find_folder(collection_index, folder_name)?,
+			None => vec![],
+		};
+
+		Ok((collection_index, folder_path))
+	}
+
+	fn handle_collection_folder_command(
+		&mut self,
+		collection_name: &str,
+		subcommand: &FolderCommand,
+	) -> anyhow::Result<()> {
+		let collection_index = self.find_collection(collection_name)?;
+
+		match subcommand {
+			FolderCommand::List => {
+				print_folders(&self.core.collections[collection_index].folders, 0, false);
+				Ok(())
+			}
+			FolderCommand::New { folder_path } => {
+				let (parent_path, folder_name) = match folder_path.rsplit_once('/') {
+					Some((parent_path, folder_name)) => (
+						self.find_folder(collection_index, parent_path)?,
+						folder_name,
+					),
+					None => (vec![], folder_path.as_str()),
+				};
+
+				self.new_folder(collection_index, &parent_path, folder_name.to_string())
+			}
+			FolderCommand::Delete { folder_path } => {
+				let folder_path = self.find_folder(collection_index, folder_path)?;
+				self.delete_folder(collection_index, &folder_path);
+				Ok(())
+			}
+			FolderCommand::Rename {
+				folder_path,
+				new_folder_name,
+			} => {
+				let folder_path = self.find_folder(collection_index, folder_path)?;
+				self.rename_folder(collection_index, &folder_path, new_folder_name.clone())
+			}
+			FolderCommand::Move {
+				folder_path,
+				parent_folder_path,
+			} => {
+				let folder_path = self.find_folder(collection_index, folder_path)?;
+				let parent_folder_path = match parent_folder_path {
+					Some(parent_folder_path) => {
+						self.find_folder(collection_index, parent_folder_path)?
+					}
+					None => vec![],
+				};
+
+				self.move_folder(collection_index, &folder_path, &parent_folder_path)
+			}
+		}
+	}
+
+	fn handle_collection_auth_command(
+		&mut self,
+		collection_name: &str,
+		folder_name: &Option<String>,
+		subcommand: &AuthCommand,
+	) -> anyhow::Result<()> {
+		let (collection_index, folder_path) =
+			self.find_collection_or_folder(collection_name, folder_name)?;
+		let mut shared_properties = self.get_shared_properties(collection_index, &folder_path);
+
+		match subcommand {
+			AuthCommand::Get => {
+				print_auth(shared_properties.auth.as_ref().unwrap_or(&Auth::Inherit));
+				return Ok(());
+			}
+			AuthCommand::Set { auth_method } => {
+				shared_properties.auth = match auth_method.as_ref() {
+					Auth::Inherit => None,
+					auth => Some(auth.clone()),
+				};
+			}
+		}
+
+		self.set_shared_properties(collection_index, &folder_path, shared_properties);
+
+		Ok(())
+	}
+
+	fn handle_collection_header_command(
+		&mut self,
+		collection_name: &str,
+		folder_name: &Option<String>,
+		subcommand: &KeyValueCommand,
+	) -> anyhow::Result<()> {
+		let (collection_index, folder_path) =
+			self.find_collection_or_folder(collection_name, folder_name)?;
+		let mut shared_properties = self.get_shared_properties(collection_index, &folder_path);
+		let headers = &mut shared_properties.headers;
+
+		match subcommand {
+			KeyValueCommand::All => {
+				print_key_value_vector(headers, None);
+				return Ok(());
+			}
+			KeyValueCommand::Key(KeyCommand::Get { key }) => {
+				let header_index = find_key(headers, key)?;
+				println!("{}", headers[header_index].data.1);
+				return Ok(());
+			}
+			KeyValueCommand::Key(KeyCommand::Add { key, value }) => headers.push(KeyValue {
+				enabled: true,
+				data: (key.clone(), value.clone()),
+			}),
+			KeyValueCommand::Key(KeyCommand::Set { key, value }) => {
+				let header_index = find_key(headers, key)?;
+				headers[header_index].data.1 = value.clone();
+			}
+			KeyValueCommand::Key(KeyCommand::Rename { key, new_key }) => {
+				let header_index = find_key(headers, key)?;
+				headers[header_index].data.0 = new_key.clone();
+			}
+			KeyValueCommand::Key(KeyCommand::Delete { key }) => {
+				let header_index = find_key(headers, key)?;
+				headers.remove(header_index);
+			}
+			KeyValueCommand::Toggle { key, state } => {
+				let header_index = find_key(headers, key)?;
+				let header = &mut headers[header_index];
+				header.enabled = state.unwrap_or(!header.enabled);
+			}
+		}
+
+		self.set_shared_properties(collection_index, &folder_path, shared_properties);
+
+		Ok(())
+	}
+
+	fn handle_collection_scripts_command(
+		&mut self,
+		collection_name: &str,
+		folder_name: &Option<String>,
+		subcommand: &ScriptsCommand,
+	) -> anyhow::Result<()> {
+		let (collection_index, folder_path) =
+			self.find_collection_or_folder(collection_name, folder_name)?;
+		let mut shared_properties = self.get_shared_properties(collection_index, &folder_path);
+		let scripts = &mut shared_properties.scripts;
+
+		match subcommand {
+			ScriptsCommand::Get { script_type } => {
+				let script = match script_type {
+					ScriptType::Pre => &scripts.pre_request_script,
+					ScriptType::Post => &scripts.post_request_script,
+				};
+
+				println!("{}", script.as_deref().unwrap_or("None"));
+				return Ok(());
+			}
+			ScriptsCommand::Set {
+				script_type,
+				script,
+			} => match script_type {
+				ScriptType::Pre => scripts.pre_request_script = script.clone(),
+				ScriptType::Post => scripts.post_request_script = script.clone(),
+			},
 		}
+
+		self.set_shared_properties(collection_index, &folder_path, shared_properties);
+
+		Ok(())
 	}
 
 	fn handle_collection_env_command(
@@ -171,6 +385,15 @@ fn print_collection(collection: &Collection, shortened: bool, with_request_names
 		println!("{}", collection.name);
 	} else {
 		println!("collection: {}", collection.name);
+
+		if let Some(auth) = &collection.auth {
+			println!("auth: {auth}");
+		}
+
+		if !collection.headers.is_empty() {
+			println!("headers:");
+			print_key_value_vector(&collection.headers, Some("\t"));
+		}
 	}
 
 	if with_request_names {
@@ -179,5 +402,26 @@ fn print_collection(collection: &Collection, shortened: bool, with_request_names
 			let local_request = request.read();
 			println!("\t{}", local_request.name);
 		}
+
+		if !collection.folders.is_empty() {
+			println!("folders:");
+			print_folders(&collection.folders, 1, true);
+		}
+	}
+}
+
+/// Print folders as an indented tree, their requests included if asked
+fn print_folders(folders: &[Folder], depth: usize, with_request_names: bool) {
+	let indent = "\t".repeat(depth);
+
+	for folder in folders {
+		println!("{indent}{}/", folder.name);
+		print_folders(&folder.folders, depth + 1, with_request_names);
+
+		if with_request_names {
+			for request in &folder.requests {
+				println!("{indent}\t{}", request.read().name);
+			}
+		}
 	}
 }
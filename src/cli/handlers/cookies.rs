@@ -0,0 +1,81 @@
+use std::fs;
+
+use anyhow::Context;
+
+use crate::app::App;
+use crate::app::files::cookies::{cookie_domain, cookies_to_netscape, netscape_to_cookies};
+use crate::cli::commands::cookies::{CookiesCommand, CookiesSubCommand};
+
+impl App<'_> {
+	pub fn handle_cookies_command(
+		&mut self,
+		cookies_command: &CookiesCommand,
+	) -> anyhow::Result<()> {
+		let collection_index = match &cookies_command.collection {
+			None => None,
+			Some(collection_name) => Some(self.find_collection(collection_name)?),
+		};
+
+		let local_cookie_store = self.get_cookie_store(collection_index);
+
+		match &cookies_command.cookies_subcommand {
+			CookiesSubCommand::List => {
+				let cookie_store = local_cookie_store
+					.read()
+					.expect("cookie store lock poisoned");
+
+				for cookie in cookie_store.iter_unexpired() {
+					let path: &str = cookie.path.as_ref();
+
+					println!(
+						"{}\t{path}\t{}={}",
+						cookie_domain(cookie),
+						cookie.name(),
+						cookie.value()
+					);
+				}
+			}
+			CookiesSubCommand::Clear => {
+				local_cookie_store
+					.write()
+					.expect("cookie store lock poisoned")
+					.clear();
+
+				self.save_cookie_stores();
+			}
+			CookiesSubCommand::Import { path } => {
+				let content = fs::read_to_string(path).with_context(|| {
+					format!("Could not read cookie file \"{}\"", path.display())
+				})?;
+
+				let imported = {
+					let mut cookie_store = local_cookie_store
+						.write()
+						.expect("cookie store lock poisoned");
+
+					netscape_to_cookies(&content, &mut cookie_store)?
+				};
+
+				self.save_cookie_stores();
+
+				println!("{imported} cookie(s) imported");
+			}
+			CookiesSubCommand::Export { path } => {
+				let content = cookies_to_netscape(
+					&local_cookie_store
+						.read()
+						.expect("cookie store lock poisoned"),
+				);
+
+				match path {
+					None => print!("{content}"),
+					Some(path) => fs::write(path, content).with_context(|| {
+						format!("Could not write cookie file \"{}\"", path.display())
+					})?,
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
@@ -1,13 +1,23 @@
+use std::io::{IsTerminal, stdin};
+
+use anyhow::Context;
+
 use crate::app::App;
+use crate::app::files::secrets::prompt_hidden_input;
 use crate::cli::commands::env::{EnvCommand, EnvSubCommand};
 use crate::cli::commands::key::KeyCommand;
+use crate::cli::commands::parent::ParentCommand;
+use crate::cli::commands::secret::SecretCommand;
 
 impl App<'_> {
 	pub fn handle_env_commands(&mut self, env_command: &EnvCommand) -> anyhow::Result<()> {
 		let env_index = match &env_command.env_subcommand {
-			EnvSubCommand::Info { env_name, .. } | EnvSubCommand::Key { env_name, .. } => {
-				self.find_environment(env_name)?
-			}
+			EnvSubCommand::Info { env_name, .. }
+			| EnvSubCommand::Key { env_name, .. }
+			| EnvSubCommand::Resolve { env_name, .. }
+			| EnvSubCommand::Parent { env_name, .. }
+			| EnvSubCommand::Secret { env_name, .. } => self.find_environment(env_name)?,
+			EnvSubCommand::RotatePassphrase => return self.rotate_secrets_passphrase(),
 		};
 
 		match &env_command.env_subcommand {
@@ -21,6 +31,51 @@ impl App<'_> {
 				KeyCommand::Delete { key } => self.delete_env_key(env_index, key),
 				KeyCommand::Rename { key, new_key } => self.rename_env_key(env_index, key, new_key),
 			},
+			EnvSubCommand::Resolve { reveal_secrets, .. } => {
+				self.cli_resolve_env(env_index, *reveal_secrets)
+			}
+			EnvSubCommand::Parent { subcommand, .. } => match subcommand {
+				ParentCommand::Get => self.get_env_parent(env_index),
+				ParentCommand::Set { parent } => {
+					self.set_env_parent(env_index, Some(parent.clone()))
+				}
+				ParentCommand::Remove => self.set_env_parent(env_index, None),
+			},
+			EnvSubCommand::Secret { subcommand, .. } => {
+				if !matches!(subcommand, SecretCommand::List) {
+					self.unlock_secrets()?;
+				}
+
+				match subcommand {
+					SecretCommand::List => self.list_env_secrets(env_index),
+					SecretCommand::Get { key, reveal } => {
+						self.get_env_secret(env_index, key, *reveal)
+					}
+					SecretCommand::Set { key, value } => {
+						let value = match value {
+							Some(value) => value.clone(),
+							None => read_secret_value(key)?,
+						};
+
+						self.set_env_secret(env_index, key, value)
+					}
+					SecretCommand::Mark { key } => self.set_env_key_secret(env_index, key, true),
+					SecretCommand::Unmark { key } => self.set_env_key_secret(env_index, key, false),
+				}
+			}
+			EnvSubCommand::RotatePassphrase => unreachable!(),
 		}
 	}
 }
+
+/// Secret values are kept out of the shell history, prompted or piped
+fn read_secret_value(key: &str) -> anyhow::Result<String> {
+	match stdin().is_terminal() {
+		true => prompt_hidden_input(&format!("Value of \"{key}\"")).context("No value given"),
+		false => stdin()
+			.lines()
+			.next()
+			.context("No value given on stdin")?
+			.context("Could not read the value from stdin"),
+	}
+}
@@ -0,0 +1,85 @@
+use crate::app::App;
+use crate::app::files::history::{
+	HistoryError, find_history_entry, previous_history_entry, read_history,
+};
+use crate::cli::commands::history::{HistoryCommand, HistorySubCommand};
+
+impl App<'_> {
+	pub async fn handle_history_command(
+		&mut self,
+		history_command: &HistoryCommand,
+	) -> anyhow::Result<()> {
+		let entries = read_history()?;
+
+		match &history_command.history_subcommand {
+			HistorySubCommand::List {
+				collection,
+				request,
+				limit,
+			} => {
+				let entries = entries
+					.iter()
+					.rev()
+					.filter(|entry| {
+						collection.is_none() || entry.collection.as_ref() == collection.as_ref()
+					})
+					.filter(|entry| request.is_none() || Some(&entry.request) == request.as_ref())
+					.take(*limit);
+
+				for entry in entries {
+					println!("{}  {}", entry.summary(), entry.request_path());
+				}
+			}
+			HistorySubCommand::Show { id } => {
+				let entry = find_history_entry(&entries, id)?;
+
+				println!("id: {}", entry.id);
+				println!("date: {}", entry.local_time());
+				println!("request: {}", entry.request_path());
+
+				for line in entry.to_lines() {
+					println!("{line}");
+				}
+			}
+			HistorySubCommand::Replay { id, send_command } => {
+				let entry = find_history_entry(&entries, id)?.clone();
+				self.replay_history_entry(&entry, send_command).await?;
+			}
+			HistorySubCommand::Diff { id, other_id } => {
+				let entry = find_history_entry(&entries, id)?;
+
+				let (old, new) = match other_id {
+					Some(other_id) => {
+						let other = find_history_entry(&entries, other_id)?;
+
+						if !entry.is_same_request(other) {
+							return Err(HistoryError::NotSameRequest(
+								id.to_string(),
+								other_id.to_string(),
+							)
+							.into());
+						}
+
+						match entry.timestamp <= other.timestamp {
+							true => (entry, other),
+							false => (other, entry),
+						}
+					}
+					None => match previous_history_entry(&entries, entry) {
+						Some(previous) => (previous, entry),
+						None => return Err(HistoryError::NoPreviousEntry(id.to_string()).into()),
+					},
+				};
+
+				println!("--- {}  {}", old.short_id(), old.local_time());
+				println!("+++ {}  {}", new.short_id(), new.local_time());
+
+				for line in old.diff(new) {
+					println!("{}", line.to_prefixed_string());
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
@@ -1,8 +1,9 @@
 use std::fs;
+use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::anyhow;
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use openapiv3::{OpenAPI, ReferenceOr};
 use parking_lot::RwLock;
 use rayon::prelude::*;
@@ -26,6 +27,9 @@ use crate::cli::import::postman_collection::ImportPostmanError::{
 use crate::cli::import::postman_env::{ImportPostmanEnvironmentError, PostmanEnv};
 use crate::models::collection::Collection;
 use crate::models::environment::Environment;
+use crate::models::folder::get_or_create_folder;
+use crate::models::scripts::RequestScripts;
+use crate::models::settings::TlsSettings;
 
 impl App<'_> {
 	pub fn import_postman_collection(
@@ -48,6 +52,8 @@ impl App<'_> {
 		};
 
 		let collection_name = postman_collection.info.name.clone();
+		let postman_collection_events = postman_collection.event.take();
+		let postman_variables = postman_collection.variable.take();
 
 		println!("Collection name: {}", collection_name);
 
@@ -59,73 +65,54 @@ impl App<'_> {
 
 		let file_format = self.core.config.get_preferred_collection_file_format();
 
-		let mut collections: Vec<Collection> = vec![Collection {
+		let (folders, requests) = match max_depth {
+			// Flatten every request at the collection root
+			0 => {
+				let mut requests = vec![];
+
+				for item in postman_collection.item.iter_mut() {
+					requests.extend(postman_collection::recursive_get_requests(item)?);
+				}
+
+				(vec![], requests)
+			}
+			_ => postman_collection::parse_items(postman_collection.item, 1, max_depth)?,
+		};
+
+		/* COLLECTION SCRIPTS & VARIABLES */
+
+		let collection_scripts =
+			postman_collection::retrieve_scripts(postman_collection_events.as_deref());
+		let collection_environment =
+			postman_collection::retrieve_collection_environment(postman_variables.as_deref());
+
+		let (environments, selected_environment) = match collection_environment {
+			Some(environment) => (vec![environment.clone()], Some(environment.name)),
+			None => (vec![], None),
+		};
+
+		let collection = Collection {
 			name: collection_name.clone(),
 			last_position: Some(self.core.collections.len().saturating_sub(1)),
-			folders: vec![],
-			requests: vec![],
-			environments: vec![],
-			selected_environment: None,
+			folders,
+			requests,
+			environments,
+			selected_environment,
+			variables: IndexMap::new(),
+			tls: TlsSettings::default(),
+			auth: None,
+			headers: vec![],
+			scripts: collection_scripts,
 			path: ARGS
 				.directory
 				.as_ref()
 				.expect("--directory argument is required")
 				.join(format!("{}.{}", collection_name, file_format)),
 			file_format,
-		}];
-
-		let mut depth_level: u16 = 0;
-
-		if max_depth == 0 {
-			for item in postman_collection.item.iter_mut() {
-				collections[0]
-					.requests
-					.extend(postman_collection::recursive_get_requests(item)?);
-			}
-		} else {
-			for mut item in postman_collection.item {
-				if item.name.is_none() {
-					continue;
-				}
-
-				// If this is a folder
-				if postman_collection::is_folder(&item) {
-					let mut temp_nesting_prefix = String::new();
-					let new_collections: Vec<Collection> = vec![];
-
-					let file_format = self.core.config.get_preferred_collection_file_format();
-
-					postman_collection::recursive_has_requests(
-						&mut item,
-						&mut collections,
-						&mut temp_nesting_prefix,
-						&mut depth_level,
-						max_depth,
-						file_format,
-					)?;
-
-					collections.extend(new_collections);
-				} else {
-					collections[0].requests.push(Arc::new(RwLock::new(
-						postman_collection::parse_request(item)?,
-					)));
-				}
-			}
-		}
-
-		// Prevent from having an empty collection
-		if collections.len() > 1 && collections[0].requests.is_empty() {
-			collections.remove(0);
-		}
-
-		let collections_length = collections.len();
-
-		let start_index = self.core.collections.len();
-		self.core.collections.extend(collections);
+		};
 
-		for collection_index in start_index..start_index + collections_length {
-			self.save_collection_to_file(collection_index);
-		}
+		self.core.collections.push(collection);
+		self.save_collection_to_file(self.core.collections.len() - 1);
 
 		Ok(())
 	}
@@ -173,6 +160,8 @@ impl App<'_> {
 			name: postman_environment.name,
 			values: IndexMap::new(),
 			path,
+			secret_keys: IndexSet::new(),
+			parent: None,
 		};
 
 		for env_variable in postman_environment.values {
@@ -201,6 +190,7 @@ impl App<'_> {
 		openapi_import: &OpenApiImport,
 	) -> anyhow::Result<()> {
 		let path_buf = &openapi_import.import_path;
+		let max_depth = openapi_import.max_depth.unwrap_or(99);
 
 		println!("Parsing OpenAPI specification");
 
@@ -260,6 +250,11 @@ impl App<'_> {
 			requests: Vec::new(),
 			environments: vec![],
 			selected_environment: None,
+			variables: IndexMap::new(),
+			tls: TlsSettings::default(),
+			auth: None,
+			headers: vec![],
+			scripts: RequestScripts::default(),
 			path: ARGS
 				.directory
 				.as_ref()
@@ -294,6 +289,7 @@ impl App<'_> {
 						path,
 						&base_url,
 						&spec,
+						max_depth,
 					)?;
 				}
 			}
@@ -301,7 +297,7 @@ impl App<'_> {
 
 		println!(
 			"\tFound {} requests in OpenAPI spec",
-			collection.requests.len()
+			collection.total_request_count()
 		);
 
 		// Add the collection to app's collections
@@ -353,6 +349,11 @@ impl App<'_> {
 					requests: vec![],
 					environments: vec![],
 					selected_environment: None,
+					variables: IndexMap::new(),
+					tls: TlsSettings::default(),
+					auth: None,
+					headers: vec![],
+					scripts: RequestScripts::default(),
 					path: ARGS
 						.directory
 						.as_ref()
@@ -373,13 +374,42 @@ impl App<'_> {
 			}
 		};
 
-		let requests = if path_buf.is_file() {
-			http_file::parse_http_file(path_buf)?
+		let env_dir = if path_buf.is_file() {
+			let http_file = http_file::parse_http_file(path_buf)?;
+
+			collection.variables.extend(http_file.variables);
+			collection.requests.extend(http_file.requests);
+
+			path_buf.parent().unwrap_or(Path::new(""))
 		} else {
-			http_file::parse_http_files_recursively(path_buf, *recursive, max_depth)?
+			// Files in sub-directories go to nested folders named after the directories
+			for (directory_names, http_file) in
+				http_file::parse_http_files_recursively(path_buf, *recursive, max_depth)?
+			{
+				collection.variables.extend(http_file.variables);
+
+				match get_or_create_folder(&mut collection.folders, &directory_names) {
+					Some(folder) => folder.requests.extend(http_file.requests),
+					None => collection.requests.extend(http_file.requests),
+				}
+			}
+
+			path_buf.as_path()
 		};
 
-		collection.requests.extend(requests);
+		// JetBrains HTTP Client environments become collection environments
+		for environment in http_file::parse_http_client_env_files(env_dir)? {
+			println!("Found environment \"{}\"", environment.name);
+
+			match collection
+				.environments
+				.iter_mut()
+				.find(|existing| existing.name == environment.name)
+			{
+				Some(existing) => *existing = environment,
+				None => collection.environments.push(environment),
+			}
+		}
 
 		self.save_collection_to_file(collection_index);
 
@@ -417,6 +447,11 @@ impl App<'_> {
 					requests: vec![],
 					environments: vec![],
 					selected_environment: None,
+					variables: IndexMap::new(),
+					tls: TlsSettings::default(),
+					auth: None,
+					headers: vec![],
+					scripts: RequestScripts::default(),
 					path: ARGS
 						.directory
 						.as_ref()
@@ -1,6 +1,8 @@
 pub(crate) mod collection;
 pub(crate) mod completions;
+pub(crate) mod cookies;
 pub(crate) mod env;
+pub(crate) mod history;
 pub(crate) mod import;
 pub(crate) mod man;
 pub(crate) mod request;
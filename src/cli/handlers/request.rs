@@ -1,8 +1,10 @@
 use crate::app::App;
 use crate::cli::commands::key::KeyCommand;
 use crate::cli::commands::key_value::KeyValueCommand;
+use crate::cli::commands::request_commands::assertions::AssertionsCommand;
 use crate::cli::commands::request_commands::auth::AuthCommand;
 use crate::cli::commands::request_commands::body::BodySubcommand;
+use crate::cli::commands::request_commands::captures::CapturesCommand;
 use crate::cli::commands::request_commands::method::MethodCommand;
 use crate::cli::commands::request_commands::request_commands::{RequestCommand, RequestSubcommand};
 use crate::cli::commands::request_commands::scripts::ScriptsCommand;
@@ -54,6 +56,14 @@ impl App<'_> {
 				collection_slash_request,
 				..
 			}
+			| RequestSubcommand::Assertions {
+				collection_slash_request,
+				..
+			}
+			| RequestSubcommand::Captures {
+				collection_slash_request,
+				..
+			}
 			| RequestSubcommand::Send {
 				collection_slash_request,
 				..
@@ -72,8 +82,15 @@ impl App<'_> {
 			// Specific case
 			RequestSubcommand::New {
 				collection_slash_request,
+				folder,
 				subcommand,
-			} => return self.cli_new_request(collection_slash_request.clone(), subcommand.clone()),
+			} => {
+				return self.cli_new_request(
+					collection_slash_request.clone(),
+					folder.as_deref(),
+					subcommand.clone(),
+				);
+			}
 		};
 
 		match &request_command.request_subcommand {
@@ -109,7 +126,7 @@ impl App<'_> {
 				AuthCommand::Set { auth_method } => self.modify_request_auth(
 					collection_index,
 					request_index,
-					auth_method.to_owned(),
+					auth_method.as_ref().to_owned(),
 				),
 			},
 			RequestSubcommand::Header { subcommand, .. } => {
@@ -132,6 +149,28 @@ impl App<'_> {
 					script.clone(),
 				),
 			},
+			RequestSubcommand::Assertions { subcommand, .. } => match subcommand {
+				AssertionsCommand::All => {
+					self.cli_print_request_assertions(collection_index, request_index)
+				}
+				AssertionsCommand::Add { assertion } => {
+					self.add_request_assertion(collection_index, request_index, assertion.clone())
+				}
+				AssertionsCommand::Delete { index } => {
+					self.delete_request_assertion(collection_index, request_index, *index)
+				}
+			},
+			RequestSubcommand::Captures { subcommand, .. } => match subcommand {
+				CapturesCommand::All => {
+					self.cli_print_request_captures(collection_index, request_index)
+				}
+				CapturesCommand::Add { capture } => {
+					self.add_request_capture(collection_index, request_index, capture.clone())
+				}
+				CapturesCommand::Delete { index } => {
+					self.delete_request_capture(collection_index, request_index, *index)
+				}
+			},
 			RequestSubcommand::Send { subcommand, .. } => {
 				self.cli_send_request(collection_index, request_index, subcommand)
 					.await
@@ -153,9 +192,11 @@ impl App<'_> {
 					new_value,
 				),
 			},
-			RequestSubcommand::Export { format, .. } => {
-				self.cli_export_request(collection_index, request_index, format)
-			}
+			RequestSubcommand::Export {
+				format,
+				reveal_secrets,
+				..
+			} => self.cli_export_request(collection_index, request_index, format, *reveal_secrets),
 		}
 	}
 
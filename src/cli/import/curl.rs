@@ -261,7 +261,11 @@ pub fn parse_request(path: &PathBuf, request_name: String) -> anyhow::Result<Arc
 		params,
 		headers,
 		auth,
-		protocol: Protocol::HttpRequest(HttpRequest { method, body }),
+		protocol: Protocol::HttpRequest(HttpRequest {
+			method,
+			body,
+			..Default::default()
+		}),
 		..Default::default()
 	};
 
@@ -1,11 +1,15 @@
 use crate::cli::import::http_file::ImportHttpFileError::{
-	CouldNotParseMethod, CouldNotParseUrl, CouldNotReadFile, NoRequestsFound,
+	CouldNotParseAssertion, CouldNotParseCapture, CouldNotParseEnvFile, CouldNotParseMethod,
+	CouldNotParseUrl, CouldNotReadFile, NoRequestsFound,
 };
+use crate::models::assertions::Assertion;
 use crate::models::auth::auth::Auth;
 use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
+use crate::models::captures::Capture;
+use crate::models::environment::Environment;
 use crate::models::protocol::graphql::graphql::GraphqlRequest;
-use crate::models::protocol::grpc::grpc::GrpcRequest;
+use crate::models::protocol::grpc::grpc::{GrpcCompression, GrpcRequest};
 use crate::models::protocol::http::body::ContentType;
 use crate::models::protocol::http::body::ContentType::NoBody;
 use crate::models::protocol::http::http::HttpRequest;
@@ -13,11 +17,13 @@ use crate::models::protocol::http::method::Method;
 use crate::models::protocol::protocol::Protocol;
 use crate::models::protocol::ws::ws::WsRequest;
 use crate::models::request::{KeyValue, Request};
+use crate::models::scripts::RequestScripts;
 use anyhow::anyhow;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use reqwest::Url;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 use thiserror::Error;
@@ -31,23 +37,58 @@ pub enum ImportHttpFileError {
 	CouldNotParseMethod(String),
 	#[error("Could not parse URL\n\t{0}")]
 	CouldNotParseUrl(String),
+	#[error("Could not parse assertion\n\t{0}")]
+	CouldNotParseAssertion(String),
+	#[error("Could not parse capture\n\t{0}")]
+	CouldNotParseCapture(String),
 	#[error("No requests found in .http file")]
 	NoRequestsFound,
+	#[error("Could not parse HTTP Client environment file\n\t{0}")]
+	CouldNotParseEnvFile(String),
 }
 
+/// JetBrains HTTP Client environments, one object of values per environment
+pub const HTTP_CLIENT_ENV_FILE: &str = "http-client.env.json";
+
+/// Values of the JetBrains HTTP Client environments kept out of version control
+pub const HTTP_CLIENT_PRIVATE_ENV_FILE: &str = "http-client.private.env.json";
+
+/// Requests and file variables (`@name = value`) of an .http file
+#[derive(Debug, Default)]
+pub struct HttpFile {
+	pub variables: IndexMap<String, String>,
+	pub requests: Vec<Arc<RwLock<Request>>>,
+}
+
+/// Metadata found before a request line: `# @name`, `# @assert`, `# @capture` and `# @filter`
+/// comments and `< {% ... %}` pre-request scripts
+#[derive(Default)]
+struct RequestPreamble {
+	name: Option<String>,
+	assertions: Vec<Assertion>,
+	captures: Vec<Capture>,
+	response_filter: Option<String>,
+	pre_request_script: Option<String>,
+}
+
+/// Directory names leading to an .http file and the file
+pub type HttpFileInDirectory = (Vec<String>, HttpFile);
+
+/// Every .http file, along with the names of the directories leading to the file from `path`
 pub fn parse_http_files_recursively(
 	path: &PathBuf,
 	recursive: bool,
 	max_depth: u16,
-) -> anyhow::Result<Vec<Arc<RwLock<Request>>>> {
+) -> anyhow::Result<Vec<HttpFileInDirectory>> {
 	let max_depth: usize = match recursive {
 		true => max_depth as usize,
 		false => 1,
 	};
 
-	let mut requests: Vec<Arc<RwLock<Request>>> = vec![];
+	let mut files: Vec<HttpFileInDirectory> = vec![];
 	let walker = WalkDir::new(path)
 		.max_depth(max_depth)
+		.sort_by_file_name()
 		.into_iter()
 		.filter_map(|e| e.ok());
 
@@ -60,35 +101,118 @@ pub fn parse_http_files_recursively(
 
 		if file_path.extension().is_some_and(|ext| ext == "http") {
 			let parsed = parse_http_file(&file_path)?;
-			requests.extend(parsed);
+
+			let directory_names = file_path
+				.parent()
+				.and_then(|parent| parent.strip_prefix(path).ok())
+				.map(|relative_directory| {
+					relative_directory
+						.components()
+						.map(|component| component.as_os_str().to_string_lossy().to_string())
+						.collect()
+				})
+				.unwrap_or_default();
+
+			files.push((directory_names, parsed));
 		}
 	}
 
-	Ok(requests)
+	Ok(files)
 }
 
-pub fn parse_http_file(path: &PathBuf) -> anyhow::Result<Vec<Arc<RwLock<Request>>>> {
+/// Environments of the `http-client.env.json` file of a directory, along with the values of its
+/// `http-client.private.env.json` file
+pub fn parse_http_client_env_files(dir: &Path) -> anyhow::Result<Vec<Environment>> {
+	let mut environments: Vec<Environment> = vec![];
+
+	for file_name in [HTTP_CLIENT_ENV_FILE, HTTP_CLIENT_PRIVATE_ENV_FILE] {
+		for (name, values) in parse_http_client_env_file(&dir.join(file_name))? {
+			match environments.iter_mut().find(|env| env.name == name) {
+				Some(environment) => environment.values.extend(values),
+				None => environments.push(Environment {
+					name,
+					values,
+					path: PathBuf::new(),
+					secret_keys: IndexSet::new(),
+					parent: None,
+				}),
+			}
+		}
+	}
+
+	Ok(environments)
+}
+
+/// Values of every environment of an HTTP Client environment file, nothing if it does not
+/// exist. Numbers and booleans are kept as strings, objects such as `SSLConfiguration` are
+/// ignored.
+pub fn parse_http_client_env_file(
+	path: &Path,
+) -> anyhow::Result<IndexMap<String, IndexMap<String, String>>> {
+	if !path.exists() {
+		return Ok(IndexMap::new());
+	}
+
 	let content = match fs::read_to_string(path) {
 		Ok(content) => content,
 		Err(e) => return Err(anyhow!(CouldNotReadFile(e.to_string()))),
 	};
 
-	parse_http_content(&content)
+	let environments: IndexMap<String, IndexMap<String, serde_json::Value>> =
+		match serde_json::from_str(&content) {
+			Ok(environments) => environments,
+			Err(e) => return Err(anyhow!(CouldNotParseEnvFile(e.to_string()))),
+		};
+
+	let environments = environments
+		.into_iter()
+		.map(|(name, values)| {
+			let values = values
+				.into_iter()
+				.filter_map(|(key, value)| match value {
+					serde_json::Value::String(value) => Some((key, value)),
+					serde_json::Value::Number(_) | serde_json::Value::Bool(_) => {
+						Some((key, value.to_string()))
+					}
+					_ => None,
+				})
+				.collect();
+
+			(name, values)
+		})
+		.collect();
+
+	Ok(environments)
 }
 
-pub fn parse_http_content(content: &str) -> anyhow::Result<Vec<Arc<RwLock<Request>>>> {
+pub fn parse_http_file(path: &PathBuf) -> anyhow::Result<HttpFile> {
+	let content = match fs::read_to_string(path) {
+		Ok(content) => content,
+		Err(e) => return Err(anyhow!(CouldNotReadFile(e.to_string()))),
+	};
+
+	// Included bodies and scripts are relative to the .http file
+	let base_dir = path.parent().unwrap_or(Path::new(""));
+
+	parse_http_content(&content, base_dir)
+}
+
+pub fn parse_http_content(content: &str, base_dir: &Path) -> anyhow::Result<HttpFile> {
 	let lines: Vec<&str> = content.lines().collect();
 	let mut requests: Vec<Arc<RwLock<Request>>> = vec![];
+	let mut variables: IndexMap<String, String> = IndexMap::new();
+	// Metadata found before the next request line
+	let mut preamble = RequestPreamble::default();
 
 	let mut i = 0;
 	while i < lines.len() {
-		// Skip leading blank lines and comments
-		let line = lines[i].trim();
-		if line.is_empty() || is_comment(line) && !line.starts_with("###") {
-			i += 1;
+		// Skip leading blank lines, comments and variables
+		if parse_preamble_line(&lines, &mut i, base_dir, &mut variables, &mut preamble)? {
 			continue;
 		}
 
+		let line = lines[i].trim();
+
 		// Check for request separator with optional name
 		let request_name = if line.starts_with("###") {
 			let name = line.trim_start_matches('#').trim().to_string();
@@ -98,15 +222,10 @@ pub fn parse_http_content(content: &str) -> anyhow::Result<Vec<Arc<RwLock<Reques
 			None
 		};
 
-		// Skip blank lines and comments after ###
-		while i < lines.len() {
-			let l = lines[i].trim();
-			if l.is_empty() || (is_comment(l) && !l.starts_with("###")) {
-				i += 1;
-			} else {
-				break;
-			}
-		}
+		// Skip blank lines, comments and variables after ###
+		while i < lines.len()
+			&& parse_preamble_line(&lines, &mut i, base_dir, &mut variables, &mut preamble)?
+		{}
 
 		if i >= lines.len() {
 			break;
@@ -149,8 +268,8 @@ pub fn parse_http_content(content: &str) -> anyhow::Result<Vec<Arc<RwLock<Reques
 		while i < lines.len() {
 			let l = lines[i].trim();
 
-			// Stop at blank line, next request separator, or EOF
-			if l.is_empty() || l.starts_with("###") {
+			// Stop at blank line, next request separator, response handler, or EOF
+			if l.is_empty() || l.starts_with("###") || is_response_handler(l) {
 				break;
 			}
 
@@ -175,17 +294,30 @@ pub fn parse_http_content(content: &str) -> anyhow::Result<Vec<Arc<RwLock<Reques
 			i += 1;
 		}
 
-		// Parse body (everything until next ### or EOF)
+		// Parse body (everything until the response handler, next ### or EOF)
 		let mut body_lines: Vec<&str> = vec![];
 		while i < lines.len() {
 			let l = lines[i].trim();
-			if l.starts_with("###") {
+			if l.starts_with("###") || is_response_handler(l) {
 				break;
 			}
 			body_lines.push(lines[i]);
 			i += 1;
 		}
 
+		// "> {% ... %}" or "> handler.js", the response references and redirections that
+		// may follow are ignored
+		let mut post_request_script = None;
+		while i < lines.len() && !lines[i].trim().starts_with("###") {
+			let l = lines[i].trim();
+
+			if post_request_script.is_none() && is_response_handler(l) {
+				post_request_script = Some(parse_script(&lines, &mut i, base_dir)?);
+			} else {
+				i += 1;
+			}
+		}
+
 		// Trim trailing blank lines from body
 		while body_lines.last().is_some_and(|l| l.trim().is_empty()) {
 			body_lines.pop();
@@ -216,8 +348,8 @@ pub fn parse_http_content(content: &str) -> anyhow::Result<Vec<Arc<RwLock<Reques
 			(parsed_url.to_string(), params)
 		};
 
-		// Derive request name if not provided
-		let name = match request_name {
+		// Derive request name if not provided, "# @name" taking precedence over "###"
+		let name = match preamble.name.take().or(request_name) {
 			Some(n) => n,
 			None => {
 				// For template URLs, derive name from the raw URL string;
@@ -236,7 +368,13 @@ pub fn parse_http_content(content: &str) -> anyhow::Result<Vec<Arc<RwLock<Reques
 
 		// Build headers (exclude Authorization since it's handled by auth,
 		// and gRPC-specific pseudo-headers that are handled by the gRPC protocol model)
-		let grpc_pseudo_headers = ["x-proto-file", "x-grpc-service", "x-grpc-method"];
+		let grpc_pseudo_headers = [
+			"x-proto-file",
+			"x-grpc-service",
+			"x-grpc-method",
+			"x-grpc-reflection",
+			"x-grpc-compression",
+		];
 		let headers: Vec<KeyValue> = raw_headers
 			.iter()
 			.filter(|(name, _)| {
@@ -282,8 +420,9 @@ pub fn parse_http_content(content: &str) -> anyhow::Result<Vec<Arc<RwLock<Reques
 				operation_name,
 			})
 		} else if is_grpc {
-			// For gRPC .http files, extract proto file path, service, and method from
-			// custom headers (X-Proto-File, X-Grpc-Service, X-Grpc-Method).
+			// For gRPC .http files, extract proto file path, service, method, reflection and
+			// compression from custom headers (X-Proto-File, X-Grpc-Service, X-Grpc-Method,
+			// X-Grpc-Reflection, X-Grpc-Compression).
 			// The body is the JSON message.
 			let proto_file = raw_headers
 				.iter()
@@ -300,6 +439,14 @@ pub fn parse_http_content(content: &str) -> anyhow::Result<Vec<Arc<RwLock<Reques
 				.find(|(name, _)| name.to_lowercase() == "x-grpc-method")
 				.map(|(_, v)| v.clone())
 				.unwrap_or_default();
+			let reflection = raw_headers
+				.iter()
+				.any(|(name, v)| name.to_lowercase() == "x-grpc-reflection" && v.trim() == "true");
+			let compression = raw_headers
+				.iter()
+				.find(|(name, _)| name.to_lowercase() == "x-grpc-compression")
+				.and_then(|(_, v)| GrpcCompression::from_encoding(v.trim()))
+				.unwrap_or_default();
 
 			Protocol::GrpcRequest(GrpcRequest {
 				proto_file,
@@ -307,12 +454,19 @@ pub fn parse_http_content(content: &str) -> anyhow::Result<Vec<Arc<RwLock<Reques
 				service,
 				method: grpc_method,
 				message: body_string,
+				reflection,
+				compression,
+				..Default::default()
 			})
 		} else {
 			let method = method.expect("HTTP method should be present");
 
 			let body = if body_string.is_empty() {
 				NoBody
+			} else if let Some(included_path) = body_string.trim().strip_prefix("< ")
+				&& !body_string.contains('\n')
+			{
+				ContentType::File(resolve_relative_path(base_dir, included_path.trim()))
 			} else {
 				let content_type_value = raw_headers
 					.iter()
@@ -325,7 +479,11 @@ pub fn parse_http_content(content: &str) -> anyhow::Result<Vec<Arc<RwLock<Reques
 				}
 			};
 
-			Protocol::HttpRequest(HttpRequest { method, body })
+			Protocol::HttpRequest(HttpRequest {
+				method,
+				body,
+				..Default::default()
+			})
 		};
 
 		let request = Request {
@@ -335,6 +493,13 @@ pub fn parse_http_content(content: &str) -> anyhow::Result<Vec<Arc<RwLock<Reques
 			headers,
 			auth,
 			protocol,
+			assertions: std::mem::take(&mut preamble.assertions),
+			captures: std::mem::take(&mut preamble.captures),
+			response_filter: preamble.response_filter.take(),
+			scripts: RequestScripts {
+				pre_request_script: preamble.pre_request_script.take(),
+				post_request_script,
+			},
 			..Default::default()
 		};
 
@@ -345,7 +510,125 @@ pub fn parse_http_content(content: &str) -> anyhow::Result<Vec<Arc<RwLock<Reques
 		return Err(anyhow!(NoRequestsFound));
 	}
 
-	Ok(requests)
+	Ok(HttpFile {
+		variables,
+		requests,
+	})
+}
+
+/// Consume the line at `i` when it comes before a request line: blank lines, comments,
+/// `@name = value` variables and `< {% ... %}` or `< script.js` pre-request scripts
+fn parse_preamble_line(
+	lines: &[&str],
+	i: &mut usize,
+	base_dir: &Path,
+	variables: &mut IndexMap<String, String>,
+	preamble: &mut RequestPreamble,
+) -> anyhow::Result<bool> {
+	let line = lines[*i].trim();
+
+	if line.is_empty() {
+		*i += 1;
+		return Ok(true);
+	}
+
+	if is_comment(line) {
+		preamble.assertions.extend(parse_assert_comment(line)?);
+		preamble.captures.extend(parse_capture_comment(line)?);
+
+		if let Some(name) = parse_name_comment(line) {
+			preamble.name = Some(name);
+		}
+
+		if let Some(response_filter) = parse_filter_comment(line) {
+			preamble.response_filter = Some(response_filter);
+		}
+
+		*i += 1;
+		return Ok(true);
+	}
+
+	if let Some(variable) = line.strip_prefix('@')
+		&& let Some((key, value)) = variable.split_once('=')
+	{
+		variables.insert(key.trim().to_string(), value.trim().to_string());
+		*i += 1;
+		return Ok(true);
+	}
+
+	if line.starts_with("< {%") || line.starts_with("< ") && line.ends_with(".js") {
+		preamble.pre_request_script = Some(parse_script(lines, i, base_dir)?);
+		return Ok(true);
+	}
+
+	Ok(false)
+}
+
+/// `> {% ... %}` or `> handler.js`, but not `>> response.json` redirections
+fn is_response_handler(line: &str) -> bool {
+	match line.strip_prefix("> ") {
+		Some(handler) => {
+			let handler = handler.trim();
+			handler.starts_with("{%") || handler.ends_with(".js")
+		}
+		None => false,
+	}
+}
+
+/// Parse a `> {% ... %}` or `< {% ... %}` script block starting at `i`, possibly spanning
+/// several lines, or read the script file of `> handler.js` or `< script.js`
+fn parse_script(lines: &[&str], i: &mut usize, base_dir: &Path) -> anyhow::Result<String> {
+	let first_line = lines[*i].trim()[1..].trim();
+	*i += 1;
+
+	let Some(first_line) = first_line.strip_prefix("{%") else {
+		let script_path = resolve_relative_path(base_dir, first_line);
+
+		return match fs::read_to_string(&script_path) {
+			Ok(script) => Ok(script.trim_end().to_string()),
+			Err(e) => Err(anyhow!(CouldNotReadFile(format!("{script_path}: {e}")))),
+		};
+	};
+
+	if let Some((script, _)) = first_line.split_once("%}") {
+		return Ok(script.trim().to_string());
+	}
+
+	let mut script_lines: Vec<&str> = vec![first_line];
+
+	while *i < lines.len() {
+		let line = lines[*i];
+		*i += 1;
+
+		match line.split_once("%}") {
+			Some((last_line, _)) => {
+				script_lines.push(last_line);
+				break;
+			}
+			None => script_lines.push(line),
+		}
+	}
+
+	// Blank lines around the script are not kept, but its indentation is
+	while script_lines.first().is_some_and(|l| l.trim().is_empty()) {
+		script_lines.remove(0);
+	}
+	while script_lines.last().is_some_and(|l| l.trim().is_empty()) {
+		script_lines.pop();
+	}
+
+	Ok(script_lines.join("\n"))
+}
+
+/// Paths of included files are relative to the .http file, unless absolute or templated
+fn resolve_relative_path(base_dir: &Path, path: &str) -> String {
+	if path.starts_with("{{") || Path::new(path).is_absolute() {
+		return path.to_string();
+	}
+
+	let path = path.strip_prefix("./").unwrap_or(path);
+
+	base_dir.join(path).to_string_lossy().to_string()
 }
 
 fn is_comment(line: &str) -> bool {
@@ -356,6 +639,70 @@ fn is_comment(line: &str) -> bool {
 	line.starts_with('#') || line.starts_with("//")
 }
 
+/// Parse a `# @assert <assertion>` or `// @assert <assertion>` comment line.
+fn parse_assert_comment(line: &str) -> anyhow::Result<Option<Assertion>> {
+	let comment = line
+		.strip_prefix("//")
+		.or_else(|| line.strip_prefix('#'))
+		.unwrap_or(line)
+		.trim();
+
+	match comment.strip_prefix("@assert ") {
+		None => Ok(None),
+		Some(assertion) => match Assertion::from_str(assertion) {
+			Ok(assertion) => Ok(Some(assertion)),
+			Err(e) => Err(anyhow!(CouldNotParseAssertion(e.to_string()))),
+		},
+	}
+}
+
+/// Parse a `# @name <name>` or `// @name=<name>` comment line.
+fn parse_name_comment(line: &str) -> Option<String> {
+	let comment = line
+		.strip_prefix("//")
+		.or_else(|| line.strip_prefix('#'))
+		.unwrap_or(line)
+		.trim();
+
+	let name = comment.strip_prefix("@name")?;
+
+	match name.starts_with([' ', '=']) {
+		true => Some(name.trim_start_matches([' ', '=']).trim().to_string()),
+		false => None,
+	}
+}
+
+/// Parse a `# @filter <expression>` or `// @filter <expression>` comment line.
+fn parse_filter_comment(line: &str) -> Option<String> {
+	let comment = line
+		.strip_prefix("//")
+		.or_else(|| line.strip_prefix('#'))
+		.unwrap_or(line)
+		.trim();
+
+	comment
+		.strip_prefix("@filter ")
+		.map(|expression| expression.trim().to_string())
+		.filter(|expression| !expression.is_empty())
+}
+
+/// Parse a `# @capture <capture>` or `// @capture <capture>` comment line.
+fn parse_capture_comment(line: &str) -> anyhow::Result<Option<Capture>> {
+	let comment = line
+		.strip_prefix("//")
+		.or_else(|| line.strip_prefix('#'))
+		.unwrap_or(line)
+		.trim();
+
+	match comment.strip_prefix("@capture ") {
+		None => Ok(None),
+		Some(capture) => match Capture::from_str(capture) {
+			Ok(capture) => Ok(Some(capture)),
+			Err(e) => Err(anyhow!(CouldNotParseCapture(e.to_string()))),
+		},
+	}
+}
+
 fn extract_auth_from_headers(headers: &[(String, String)]) -> Auth {
 	let auth_header = headers
 		.iter()
@@ -405,7 +752,7 @@ GET https://api.example.com/users HTTP/1.1
 Accept: application/json
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 1);
 
 		let req = requests[0].read();
@@ -430,7 +777,7 @@ Content-Type: application/json
 {"name": "John", "email": "john@example.com"}
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 1);
 
 		let req = requests[0].read();
@@ -465,7 +812,7 @@ Content-Type: application/json
 DELETE https://api.example.com/third
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 3);
 
 		assert_eq!(requests[0].read().name, "First");
@@ -484,7 +831,7 @@ GET https://api.example.com/test
 Accept: application/json
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 1);
 		assert_eq!(requests[0].read().name, "My Request");
 	}
@@ -493,7 +840,7 @@ Accept: application/json
 	fn derive_name_from_method_and_path() {
 		let content = "GET https://api.example.com/api/users\n";
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 1);
 		assert_eq!(requests[0].read().name, "GET /api/users");
 	}
@@ -504,7 +851,7 @@ Accept: application/json
 GET https://api.example.com/search?q=rust&page=1
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 1);
 
 		let req = requests[0].read();
@@ -521,7 +868,7 @@ GET https://api.example.com/me
 Authorization: Bearer my-secret-token
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		let req = requests[0].read();
 
 		match &req.auth {
@@ -539,7 +886,7 @@ Authorization: Bearer my-secret-token
 GET https://api.example.com/health
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		let req = requests[0].read();
 
 		match &req.protocol {
@@ -553,7 +900,7 @@ GET https://api.example.com/health
 	#[test]
 	fn no_requests_returns_error() {
 		let content = "# Just a comment\n// Another comment\n";
-		let result = parse_http_content(content);
+		let result = parse_http_content(content, Path::new(""));
 		assert!(result.is_err());
 	}
 
@@ -561,7 +908,7 @@ GET https://api.example.com/health
 	fn request_without_separator() {
 		let content = "GET https://api.example.com/test\nAccept: application/json\n";
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 1);
 		assert_eq!(requests[0].read().name, "GET /test");
 	}
@@ -581,7 +928,7 @@ Content-Type: application/json
 {"name": "updated"}
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 2);
 
 		let req1 = requests[0].read();
@@ -604,7 +951,7 @@ Content-Type: application/json
 WEBSOCKET wss://echo.websocket.org
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 1);
 
 		let req = requests[0].read();
@@ -625,7 +972,7 @@ Authorization: Bearer my-token
 X-Custom: header-value
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 1);
 
 		let req = requests[0].read();
@@ -661,7 +1008,7 @@ GRAPHQL https://countries.trevorblades.com/graphql
 }
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 1);
 
 		let req = requests[0].read();
@@ -689,7 +1036,7 @@ Content-Type: application/json
 {"query": "query GetCountry($code: ID!) { country(code: $code) { name capital } }", "variables": {"code": "CA"}, "operationName": "GetCountry"}
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 1);
 
 		let req = requests[0].read();
@@ -714,7 +1061,7 @@ GRAPHQL https://api.example.com/graphql
 {"query": "{ users(limit: $limit) { id name } }", "variables": {"limit": 10}}
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		let req = requests[0].read();
 
 		match &req.protocol {
@@ -737,7 +1084,7 @@ X-Custom-Header: custom-value
 { viewer { login } }
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		let req = requests[0].read();
 
 		match &req.auth {
@@ -763,7 +1110,7 @@ X-Custom-Header: custom-value
 GRAPHQL https://api.example.com/graphql
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		let req = requests[0].read();
 
 		match &req.protocol {
@@ -796,7 +1143,7 @@ Content-Type: application/json
 {"name": "Jane"}
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 4);
 
 		assert_eq!(requests[0].read().name, "Get Users");
@@ -841,7 +1188,7 @@ Content-Type: application/json
 {"name": "Jane"}
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 3);
 
 		assert_eq!(requests[0].read().name, "Get Users");
@@ -874,7 +1221,7 @@ X-Grpc-Method: SayHello
 {"name": "World"}
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 1);
 
 		let req = requests[0].read();
@@ -899,7 +1246,7 @@ X-Grpc-Service: grpc.health.v1.Health
 X-Grpc-Method: Check
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 1);
 
 		let req = requests[0].read();
@@ -913,6 +1260,48 @@ X-Grpc-Method: Check
 		}
 	}
 
+	#[test]
+	fn parse_grpc_request_with_reflection() {
+		let content = r#"### Reflected
+GRPC http://localhost:50051
+X-Grpc-Reflection: true
+X-Grpc-Service: helloworld.Greeter
+X-Grpc-Method: SayHello
+"#;
+
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
+		let req = requests[0].read();
+
+		assert!(req.headers.is_empty());
+		match &req.protocol {
+			Protocol::GrpcRequest(grpc) => {
+				assert!(grpc.reflection);
+				assert!(grpc.proto_file.is_empty());
+			}
+			_ => panic!("Expected GrpcRequest"),
+		}
+	}
+
+	#[test]
+	fn parse_grpc_request_with_compression() {
+		let content = r#"### Compressed
+GRPC http://localhost:50051
+X-Proto-File: hello.proto
+X-Grpc-Service: helloworld.Greeter
+X-Grpc-Method: SayHello
+X-Grpc-Compression: gzip
+"#;
+
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
+		let req = requests[0].read();
+
+		assert!(req.headers.is_empty());
+		match &req.protocol {
+			Protocol::GrpcRequest(grpc) => assert_eq!(grpc.compression, GrpcCompression::Gzip),
+			_ => panic!("Expected GrpcRequest"),
+		}
+	}
+
 	#[test]
 	fn parse_grpc_request_with_extra_headers() {
 		let content = r#"### Auth Call
@@ -926,7 +1315,7 @@ x-custom-header: value
 {"field": "data"}
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 1);
 
 		let req = requests[0].read();
@@ -984,7 +1373,7 @@ X-Grpc-Method: DoWork
 {"key": "value"}
 "#;
 
-		let requests = parse_http_content(content).unwrap();
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
 		assert_eq!(requests.len(), 4);
 
 		assert!(matches!(
@@ -1004,4 +1393,229 @@ X-Grpc-Method: DoWork
 			Protocol::GrpcRequest(_)
 		));
 	}
+
+	#[test]
+	fn parse_assert_comments() {
+		let content = r#"### Get User
+# @assert status == 200
+// @assert jsonpath $.id == 1
+# a regular comment
+GET https://api.example.com/users/1
+
+### Health
+GET https://api.example.com/health
+"#;
+
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
+		assert_eq!(requests.len(), 2);
+
+		assert_eq!(
+			requests[0].read().assertions,
+			vec![
+				Assertion::StatusEquals(200),
+				Assertion::JsonPathEquals {
+					path: String::from("$.id"),
+					value: serde_json::json!(1),
+				},
+			]
+		);
+		assert!(requests[1].read().assertions.is_empty());
+	}
+
+	#[test]
+	fn parse_invalid_assert_comment_fails() {
+		let content = "### Bad\n# @assert status is ok\nGET https://api.example.com\n";
+
+		assert!(parse_http_content(content, Path::new("")).is_err());
+	}
+
+	#[test]
+	fn parse_capture_comments() {
+		let content = "### Login\n# @capture TOKEN = jsonpath $.token\n// @capture ID = header x-id\nPOST https://api.example.com/login\n";
+
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
+		let request = requests[0].read();
+
+		assert_eq!(
+			request
+				.captures
+				.iter()
+				.map(ToString::to_string)
+				.collect::<Vec<String>>(),
+			vec!["TOKEN = jsonpath $.token", "ID = header x-id"]
+		);
+	}
+
+	#[test]
+	fn parse_filter_comments() {
+		let content = "### Users\n# @filter .users[] | .name\nGET https://api.example.com/users\n";
+
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
+
+		assert_eq!(
+			requests[0].read().response_filter.as_deref(),
+			Some(".users[] | .name")
+		);
+	}
+
+	#[test]
+	fn parse_invalid_capture_comment_fails() {
+		let content = "### Bad\n# @capture TOKEN = body token\nGET https://api.example.com\n";
+
+		assert!(parse_http_content(content, Path::new("")).is_err());
+	}
+
+	#[test]
+	fn parse_file_variables_and_name_comments() {
+		let content = r#"@host = https://api.example.com
+@users = {{host}}/users
+
+### List users
+# @name list-users
+GET {{users}}
+
+###
+// @name=create-user
+POST {{users}}
+"#;
+
+		let http_file = parse_http_content(content, Path::new("")).unwrap();
+
+		assert_eq!(
+			http_file.variables.get("host").unwrap(),
+			"https://api.example.com"
+		);
+		assert_eq!(http_file.variables.get("users").unwrap(), "{{host}}/users");
+		assert_eq!(http_file.requests[0].read().name, "list-users");
+		assert_eq!(http_file.requests[0].read().url, "{{users}}");
+		assert_eq!(http_file.requests[1].read().name, "create-user");
+	}
+
+	#[test]
+	fn parse_body_include_relative_to_file() {
+		let content = "### Upload\nPOST https://api.example.com/upload\nContent-Type: application/json\n\n< ./payloads/user.json\n";
+
+		let requests = parse_http_content(content, Path::new("requests"))
+			.unwrap()
+			.requests;
+
+		match &requests[0].read().protocol {
+			Protocol::HttpRequest(http) => assert!(matches!(
+				&http.body,
+				ContentType::File(path) if path == &Path::new("requests").join("payloads/user.json").to_string_lossy()
+			)),
+			_ => panic!("Expected HttpRequest"),
+		}
+	}
+
+	#[test]
+	fn parse_pre_request_script_and_response_handler() {
+		let content = r#"### Login
+< {% request.variables.set("nonce", "abc"); %}
+POST https://api.example.com/login
+Content-Type: application/json
+
+{"user": "squrl"}
+
+> {%
+    client.test("logged in", () => {
+        client.assert(response.status === 200);
+    });
+    client.global.set("token", response.body.token);
+%}
+
+<> 2024-01-01T000000.200.json
+
+### Me
+GET https://api.example.com/me
+> {% client.log(response.status); %}
+"#;
+
+		let requests = parse_http_content(content, Path::new("")).unwrap().requests;
+		let login = requests[0].read();
+
+		assert_eq!(
+			login.scripts.pre_request_script.as_deref(),
+			Some(r#"request.variables.set("nonce", "abc");"#)
+		);
+		assert_eq!(
+			login.scripts.post_request_script.as_deref(),
+			Some(
+				"    client.test(\"logged in\", () => {\n        client.assert(response.status === 200);\n    });\n    client.global.set(\"token\", response.body.token);"
+			)
+		);
+
+		// The handler and response reference are not part of the body
+		match &login.protocol {
+			Protocol::HttpRequest(http) => {
+				assert!(
+					matches!(&http.body, ContentType::Json(body) if body == r#"{"user": "squrl"}"#)
+				)
+			}
+			_ => panic!("Expected HttpRequest"),
+		}
+
+		let me = requests[1].read();
+		assert!(me.headers.is_empty());
+		assert_eq!(
+			me.scripts.post_request_script.as_deref(),
+			Some("client.log(response.status);")
+		);
+	}
+
+	#[test]
+	fn parse_response_handler_file() {
+		let dir = tempfile::tempdir().unwrap();
+		fs::write(
+			dir.path().join("handler.js"),
+			"client.log(response.status);\n",
+		)
+		.unwrap();
+
+		let content = "GET https://api.example.com\n\n> ./handler.js\n";
+		let requests = parse_http_content(content, dir.path()).unwrap().requests;
+
+		assert_eq!(
+			requests[0].read().scripts.post_request_script.as_deref(),
+			Some("client.log(response.status);")
+		);
+
+		let content = "GET https://api.example.com\n\n> ./missing.js\n";
+		assert!(parse_http_content(content, dir.path()).is_err());
+	}
+
+	#[test]
+	fn parse_http_client_env_files_merges_private_values() {
+		let dir = tempfile::tempdir().unwrap();
+		fs::write(
+			dir.path().join(HTTP_CLIENT_ENV_FILE),
+			r#"{"dev": {"host": "localhost", "port": 8080, "SSLConfiguration": {"verifyHostCertificate": false}}, "prod": {"host": "example.com"}}"#,
+		)
+		.unwrap();
+		fs::write(
+			dir.path().join(HTTP_CLIENT_PRIVATE_ENV_FILE),
+			r#"{"dev": {"password": "secret"}}"#,
+		)
+		.unwrap();
+
+		let environments = parse_http_client_env_files(dir.path()).unwrap();
+
+		assert_eq!(environments.len(), 2);
+		assert_eq!(environments[0].name, "dev");
+		assert_eq!(
+			environments[0]
+				.values
+				.iter()
+				.map(|(key, value)| format!("{key}={value}"))
+				.collect::<Vec<String>>(),
+			vec!["host=localhost", "port=8080", "password=secret"]
+		);
+		assert_eq!(environments[1].values.get("host").unwrap(), "example.com");
+
+		assert!(
+			parse_http_client_env_files(&dir.path().join("missing"))
+				.unwrap()
+				.is_empty()
+		);
+	}
 }
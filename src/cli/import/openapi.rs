@@ -13,6 +13,7 @@ use crate::models::auth::auth::Auth;
 use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
 use crate::models::collection::Collection;
+use crate::models::folder::get_or_create_folder;
 use crate::models::protocol::http::body::ContentType;
 use crate::models::protocol::http::http::HttpRequest;
 use crate::models::protocol::http::method::Method;
@@ -39,6 +40,7 @@ pub fn process_path_operations(
 	path: &str,
 	base_url: &str,
 	spec: &OpenAPI,
+	max_depth: u16,
 ) -> anyhow::Result<()> {
 	// Process GET operations
 	if let Some(op) = &path_item.get {
@@ -47,7 +49,7 @@ pub fn process_path_operations(
 			.clone()
 			.unwrap_or_else(|| format!("GET {}", path));
 		let request = create_request(name, Method::GET, path, base_url, op, spec)?;
-		collection.requests.push(Arc::new(RwLock::new(request)));
+		add_request(collection, op, request, max_depth);
 	}
 
 	// Process POST operations
@@ -57,7 +59,7 @@ pub fn process_path_operations(
 			.clone()
 			.unwrap_or_else(|| format!("POST {}", path));
 		let request = create_request(name, Method::POST, path, base_url, op, spec)?;
-		collection.requests.push(Arc::new(RwLock::new(request)));
+		add_request(collection, op, request, max_depth);
 	}
 
 	// Process PUT operations
@@ -67,7 +69,7 @@ pub fn process_path_operations(
 			.clone()
 			.unwrap_or_else(|| format!("PUT {}", path));
 		let request = create_request(name, Method::PUT, path, base_url, op, spec)?;
-		collection.requests.push(Arc::new(RwLock::new(request)));
+		add_request(collection, op, request, max_depth);
 	}
 
 	// Process DELETE operations
@@ -77,7 +79,7 @@ pub fn process_path_operations(
 			.clone()
 			.unwrap_or_else(|| format!("DELETE {}", path));
 		let request = create_request(name, Method::DELETE, path, base_url, op, spec)?;
-		collection.requests.push(Arc::new(RwLock::new(request)));
+		add_request(collection, op, request, max_depth);
 	}
 
 	// Process PATCH operations
@@ -87,7 +89,7 @@ pub fn process_path_operations(
 			.clone()
 			.unwrap_or_else(|| format!("PATCH {}", path));
 		let request = create_request(name, Method::PATCH, path, base_url, op, spec)?;
-		collection.requests.push(Arc::new(RwLock::new(request)));
+		add_request(collection, op, request, max_depth);
 	}
 
 	// Process OPTIONS operations
@@ -97,7 +99,7 @@ pub fn process_path_operations(
 			.clone()
 			.unwrap_or_else(|| format!("OPTIONS {}", path));
 		let request = create_request(name, Method::OPTIONS, path, base_url, op, spec)?;
-		collection.requests.push(Arc::new(RwLock::new(request)));
+		add_request(collection, op, request, max_depth);
 	}
 
 	// Process HEAD operations
@@ -107,12 +109,39 @@ pub fn process_path_operations(
 			.clone()
 			.unwrap_or_else(|| format!("HEAD {}", path));
 		let request = create_request(name, Method::HEAD, path, base_url, op, spec)?;
-		collection.requests.push(Arc::new(RwLock::new(request)));
+		add_request(collection, op, request, max_depth);
 	}
 
 	Ok(())
 }
 
+/// Add a request to the folder named after the first tag of its operation, "/" in the tag
+/// creating nested folders down to `max_depth`. Untagged operations go to the collection root.
+fn add_request(
+	collection: &mut Collection,
+	operation: &Operation,
+	request: Request,
+	max_depth: u16,
+) {
+	let folder_names: Vec<String> = match operation.tags.first() {
+		Some(tag) => tag
+			.split('/')
+			.map(str::trim)
+			.filter(|folder_name| !folder_name.is_empty())
+			.take(max_depth as usize)
+			.map(String::from)
+			.collect(),
+		None => vec![],
+	};
+
+	let request = Arc::new(RwLock::new(request));
+
+	match get_or_create_folder(&mut collection.folders, &folder_names) {
+		Some(folder) => folder.requests.push(request),
+		None => collection.requests.push(request),
+	}
+}
+
 pub fn create_request(
 	name: String,
 	method: Method,
@@ -129,6 +158,7 @@ pub fn create_request(
 		protocol: Protocol::HttpRequest(HttpRequest {
 			method,
 			body: ContentType::NoBody,
+			..Default::default()
 		}),
 		..Default::default()
 	};
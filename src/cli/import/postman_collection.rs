@@ -6,26 +6,33 @@ use clap::ValueEnum;
 use parking_lot::RwLock;
 use rayon::prelude::*;
 
+use indexmap::IndexMap;
 use parse_postman_collection::v2_1_0::{
-	AuthType, Body, FormParameterSrcUnion, HeaderUnion, Host, Items, Language, Mode, RequestClass,
-	RequestUnion, Url,
+	AuthType, Body, Event, FormParameterSrcUnion, HeaderUnion, Host, Items, Language, Mode,
+	RequestClass, RequestUnion, Script, Url, Variable,
 };
 use thiserror::Error;
 
-use crate::cli::args::ARGS;
 use crate::models::auth::auth::Auth;
+use crate::models::auth::aws_sig_v4::AwsSigV4;
 use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
 use crate::models::auth::digest::{Digest, DigestAlgorithm, DigestCharset, DigestError, DigestQop};
 use crate::models::auth::jwt::{JwtAlgorithm, JwtSecretType, JwtToken};
-use crate::models::collection::{Collection, CollectionFileFormat};
+use crate::models::auth::oauth2::{OAuth2, OAuth2GrantType};
+use crate::models::environment::Environment;
+use crate::models::folder::Folder;
 use crate::models::protocol::http::body::ContentType;
 use crate::models::protocol::http::http::HttpRequest;
 use crate::models::protocol::http::method::Method;
 use crate::models::protocol::protocol::Protocol;
 use crate::models::request::{DEFAULT_HEADERS, KeyValue, Request};
+use crate::models::scripts::RequestScripts;
 use crate::models::settings::{RequestSettings, Setting};
 
+/// Name of the environment holding the imported collection variables
+pub const COLLECTION_VARIABLES_ENVIRONMENT: &str = "collection_variables";
+
 #[derive(Error, Debug)]
 pub enum ImportPostmanError {
 	#[error("Could not parse Postman collection \"{0}\"\n\t{1}")]
@@ -38,87 +45,56 @@ pub enum ImportPostmanError {
 	AuthError(String),
 }
 
-pub fn recursive_has_requests(
-	item: &mut Items,
-	collections: &mut Vec<Collection>,
-	nesting_prefix: &mut String,
-	depth_level: &mut u16,
-	max_depth: u16,
-	file_format: CollectionFileFormat,
-) -> anyhow::Result<Option<Arc<RwLock<Request>>>> {
-	if is_folder(item) {
-		let mut requests: Vec<Arc<RwLock<Request>>> = vec![];
+/// Folders and requests parsed from Postman items
+pub type ParsedItems = (Vec<Folder>, Vec<Arc<RwLock<Request>>>);
 
-		let mut folder_name = item
-			.clone()
-			.name
-			.expect("postman folder should have a name");
-		folder_name = folder_name.replace("/", "-");
-		folder_name = folder_name.replace("\\", "-");
-		folder_name = folder_name.trim().to_string();
-
-		let collection_name = format!("{nesting_prefix}{folder_name}");
-
-		*depth_level += 1;
-
-		if *depth_level == max_depth {
-			println!("\tMet max depth level");
-			requests = recursive_get_requests(item)?;
-		} else {
-			nesting_prefix.push_str(&format!("{folder_name} "));
-
-			let mut has_sub_folders = false;
-
-			for mut sub_item in item
-				.item
-				.clone()
-				.expect("postman folder should have sub-items")
-			{
-				if let Some(request) = recursive_has_requests(
-					&mut sub_item,
-					collections,
-					nesting_prefix,
-					depth_level,
-					max_depth,
-					file_format,
-				)? {
-					requests.push(request);
-				} else {
-					has_sub_folders = true;
-				}
-			}
+/// Folders and requests of the items of a Postman collection or folder. Folders at `max_depth`
+/// do not keep their sub-folders, their deeper requests are flattened into them.
+pub fn parse_items(
+	items: Vec<Items>,
+	depth_level: u16,
+	max_depth: u16,
+) -> anyhow::Result<ParsedItems> {
+	let mut folders: Vec<Folder> = vec![];
+	let mut requests: Vec<Arc<RwLock<Request>>> = vec![];
 
-			if has_sub_folders {
-				nesting_prefix.clear();
-			}
+	for mut item in items {
+		if item.name.is_none() {
+			continue;
 		}
 
-		if !requests.is_empty() {
-			println!("\tFound collection \"{}\"", collection_name);
-
-			let collection = Collection {
-				name: collection_name.clone(),
-				last_position: Some(collections.len() - 1),
-				folders: vec![],
-				requests,
-				environments: vec![],
-				selected_environment: None,
-				path: ARGS
-					.directory
-					.as_ref()
-					.expect("--directory argument is required")
-					.join(format!("{}.{}", collection_name, file_format)),
-				file_format,
-			};
-
-			collections.push(collection);
-			*depth_level -= 1;
+		if !is_folder(&item) {
+			requests.push(Arc::new(RwLock::new(parse_request(item)?)));
+			continue;
 		}
 
-		Ok(None)
-	} else {
-		Ok(Some(Arc::new(RwLock::new(parse_request(item.clone())?))))
+		let folder_name = item
+			.name
+			.clone()
+			.expect("postman folder should have a name")
+			.replace("/", "-")
+			.replace("\\", "-")
+			.trim()
+			.to_string();
+
+		println!("\tFound folder \"{}\"", folder_name);
+
+		let scripts = retrieve_request_scripts(&item);
+		let (sub_folders, folder_requests) = match depth_level >= max_depth {
+			true => (vec![], recursive_get_requests(&mut item)?),
+			false => parse_items(item.item.unwrap_or_default(), depth_level + 1, max_depth)?,
+		};
+
+		folders.push(Folder {
+			name: folder_name,
+			folders: sub_folders,
+			requests: folder_requests,
+			scripts,
+			..Default::default()
+		});
 	}
+
+	Ok((folders, requests))
 }
 
 pub fn recursive_get_requests(item: &mut Items) -> anyhow::Result<Vec<Arc<RwLock<Request>>>> {
@@ -150,7 +126,7 @@ pub fn parse_request(item: Items) -> anyhow::Result<Request> {
 		..Default::default()
 	};
 
-	request.scripts.pre_request_script = retrieve_request_scripts(&item);
+	request.scripts = retrieve_request_scripts(&item);
 
 	/* SETTINGS */
 
@@ -524,12 +500,83 @@ pub fn retrieve_auth(request_class: &RequestClass) -> Option<anyhow::Result<Auth
 				nc: 0,
 			})))
 		}
-		AuthType::Awsv4 => Some(Ok(Auth::NoAuth)),
+		AuthType::Awsv4 => {
+			let awsv4_attributes = auth.awsv4?;
+
+			let mut aws_sig_v4 = AwsSigV4::default();
+
+			for awsv4_attribute in awsv4_attributes {
+				let Some(serde_json::Value::String(value)) = awsv4_attribute.value else {
+					continue;
+				};
+
+				match awsv4_attribute.key.as_str() {
+					"accessKey" => aws_sig_v4.access_key_id = value,
+					"secretKey" => aws_sig_v4.secret_access_key = value,
+					"sessionToken" => aws_sig_v4.session_token = value,
+					"region" => aws_sig_v4.region = value,
+					"service" => aws_sig_v4.service = value,
+					_ => {}
+				}
+			}
+
+			Some(Ok(Auth::AwsSigV4(aws_sig_v4)))
+		}
 		AuthType::Hawk => Some(Ok(Auth::NoAuth)),
 		AuthType::Noauth => Some(Ok(Auth::NoAuth)),
 		AuthType::Ntlm => Some(Ok(Auth::NoAuth)),
 		AuthType::Oauth1 => Some(Ok(Auth::NoAuth)),
-		AuthType::Oauth2 => Some(Ok(Auth::NoAuth)),
+		AuthType::Oauth2 => {
+			let oauth2_attributes = auth.oauth2?;
+
+			let mut oauth2 = OAuth2 {
+				// Postman's default grant
+				grant_type: OAuth2GrantType::AuthorizationCode,
+				pkce: false,
+				..Default::default()
+			};
+
+			for oauth2_attribute in oauth2_attributes {
+				let value = match oauth2_attribute.value {
+					Some(serde_json::Value::String(value)) => value,
+					Some(serde_json::Value::Bool(value)) => value.to_string(),
+					Some(serde_json::Value::Number(value)) => value.to_string(),
+					_ => continue,
+				};
+
+				match oauth2_attribute.key.as_str() {
+					"grant_type" => match value.as_str() {
+						"client_credentials" => {
+							oauth2.grant_type = OAuth2GrantType::ClientCredentials
+						}
+						"password_credentials" => oauth2.grant_type = OAuth2GrantType::Password,
+						"authorization_code_with_pkce" => {
+							oauth2.grant_type = OAuth2GrantType::AuthorizationCode;
+							oauth2.pkce = true;
+						}
+						_ => oauth2.grant_type = OAuth2GrantType::AuthorizationCode,
+					},
+					"accessTokenUrl" => oauth2.token_url = value,
+					"authUrl" => oauth2.auth_url = value,
+					"clientId" => oauth2.client_id = value,
+					"clientSecret" => oauth2.client_secret = value,
+					"scope" => oauth2.scope = value,
+					"username" => oauth2.username = value,
+					"password" => oauth2.password = value,
+					"refreshToken" => oauth2.refresh_token = value,
+					"redirect_uri" => {
+						if let Some(port) =
+							reqwest::Url::parse(&value).ok().and_then(|url| url.port())
+						{
+							oauth2.redirect_port = port;
+						}
+					}
+					_ => {}
+				}
+			}
+
+			Some(Ok(Auth::OAuth2(oauth2)))
+		}
 	}
 }
 
@@ -553,27 +600,80 @@ pub fn retrieve_headers(request_class: &RequestClass) -> Option<Vec<KeyValue>> {
 	}
 }
 
-pub fn retrieve_request_scripts(item: &Items) -> Option<String> {
-	let events = item.event.clone()?;
-
-	for event in events {
-		if event.listen == "prerequest" {
-			let script = event.script?;
-			match script.exec? {
-				Host::String(_) => {}
-				Host::StringArray(exec) => {
-					let script: String = exec
-						.par_iter()
-						.map(|line| line.replace("pm.", "") + "\n")
-						.collect();
-
-					return Some(script);
-				}
-			}
+pub fn retrieve_request_scripts(item: &Items) -> RequestScripts {
+	retrieve_scripts(item.event.as_deref())
+}
+
+/// Pre-request scripts come from `prerequest` events, post-request ones from `test` events
+pub fn retrieve_scripts(events: Option<&[Event]>) -> RequestScripts {
+	let mut scripts = RequestScripts::default();
+
+	for event in events.unwrap_or_default() {
+		if event.disabled == Some(true) {
+			continue;
+		}
+
+		let script = match &event.script {
+			Some(Script {
+				exec: Some(Host::String(exec)),
+				..
+			}) => exec.clone() + "\n",
+			Some(Script {
+				exec: Some(Host::StringArray(exec)),
+				..
+			}) => exec.par_iter().map(|line| line.clone() + "\n").collect(),
+			_ => continue,
+		};
+
+		let target = match event.listen.as_str() {
+			"prerequest" => &mut scripts.pre_request_script,
+			"test" => &mut scripts.post_request_script,
+			_ => continue,
+		};
+
+		// Several events may listen to the same moment
+		match target {
+			Some(existing_script) => existing_script.push_str(&script),
+			None => *target = Some(script),
 		}
 	}
 
-	None
+	scripts
+}
+
+/// Collection variables, as a collection environment
+pub fn retrieve_collection_environment(variables: Option<&[Variable]>) -> Option<Environment> {
+	let mut values = IndexMap::new();
+
+	for variable in variables.unwrap_or_default() {
+		if variable.disabled == Some(true) {
+			continue;
+		}
+
+		let Some(key) = &variable.key else {
+			continue;
+		};
+
+		let value = match &variable.value {
+			None | Some(serde_json::Value::Null) => String::new(),
+			Some(serde_json::Value::String(value)) => value.clone(),
+			Some(value) => value.to_string(),
+		};
+
+		values.insert(key.clone(), value);
+	}
+
+	if values.is_empty() {
+		return None;
+	}
+
+	Some(Environment {
+		name: String::from(COLLECTION_VARIABLES_ENVIRONMENT),
+		values,
+		path: Default::default(),
+		secret_keys: Default::default(),
+		parent: Default::default(),
+	})
 }
 
 pub fn retrieve_settings(item: &Items) -> Option<RequestSettings> {
@@ -598,7 +698,7 @@ mod tests {
 	use parse_postman_collection::v2_1_0::{
 		Auth as PostmanAuth, AuthAttribute, BodyClass, Event, File as PostmanFile, FormParameter,
 		Header, Language, Mode, Options, ProtocolProfileBehavior, QueryParam, Raw, RequestClass,
-		RequestUnion, Script, Url, UrlClass, UrlEncodedParameter,
+		RequestUnion, Script, Url, UrlClass, UrlEncodedParameter, Variable,
 	};
 
 	/// Helper to build a minimal Items leaf (request, not folder)
@@ -1260,15 +1360,93 @@ mod tests {
 		assert!(matches!(auth, Auth::NoAuth));
 	}
 
+	#[test]
+	fn retrieve_auth_oauth2() {
+		let attribute = |key: &str, value: &str| AuthAttribute {
+			key: key.to_string(),
+			auth_type: None,
+			value: Some(serde_json::Value::String(value.to_string())),
+		};
+
+		let mut postman_auth = make_auth(AuthType::Oauth2);
+		postman_auth.oauth2 = Some(vec![
+			attribute("grant_type", "authorization_code_with_pkce"),
+			attribute("accessTokenUrl", "https://auth.example.com/token"),
+			attribute("authUrl", "https://auth.example.com/authorize"),
+			attribute("clientId", "{{CLIENT_ID}}"),
+			attribute("scope", "read write"),
+			attribute("redirect_uri", "http://localhost:5000/callback"),
+			attribute("accessToken", "saved-token"),
+		]);
+		let rc = RequestClass {
+			auth: Some(postman_auth),
+			body: None,
+			certificate: None,
+			description: None,
+			header: None,
+			method: None,
+			proxy: None,
+			url: None,
+		};
+
+		match retrieve_auth(&rc).unwrap().unwrap() {
+			Auth::OAuth2(oauth2) => {
+				assert_eq!(oauth2.grant_type, OAuth2GrantType::AuthorizationCode);
+				assert!(oauth2.pkce);
+				assert_eq!(oauth2.token_url, "https://auth.example.com/token");
+				assert_eq!(oauth2.auth_url, "https://auth.example.com/authorize");
+				assert_eq!(oauth2.client_id, "{{CLIENT_ID}}");
+				assert_eq!(oauth2.scope, "read write");
+				assert_eq!(oauth2.redirect_port, 5000);
+				// Tokens are not kept in collections, a new one is requested on the first send
+				assert!(oauth2.token.is_none());
+			}
+			other => panic!("Expected OAuth2 auth, got {other}"),
+		}
+	}
+
+	#[test]
+	fn retrieve_auth_awsv4() {
+		let attribute = |key: &str, value: &str| AuthAttribute {
+			key: key.to_string(),
+			auth_type: None,
+			value: Some(serde_json::Value::String(value.to_string())),
+		};
+
+		let mut postman_auth = make_auth(AuthType::Awsv4);
+		postman_auth.awsv4 = Some(vec![
+			attribute("accessKey", "AKIDEXAMPLE"),
+			attribute("secretKey", "{{AWS_SECRET}}"),
+			attribute("sessionToken", "session"),
+			attribute("region", "eu-west-1"),
+			attribute("service", "execute-api"),
+		]);
+		let rc = RequestClass {
+			auth: Some(postman_auth),
+			body: None,
+			certificate: None,
+			description: None,
+			header: None,
+			method: None,
+			proxy: None,
+			url: None,
+		};
+
+		match retrieve_auth(&rc).unwrap().unwrap() {
+			Auth::AwsSigV4(aws_sig_v4) => {
+				assert_eq!(aws_sig_v4.access_key_id, "AKIDEXAMPLE");
+				assert_eq!(aws_sig_v4.secret_access_key, "{{AWS_SECRET}}");
+				assert_eq!(aws_sig_v4.session_token, "session");
+				assert_eq!(aws_sig_v4.region, "eu-west-1");
+				assert_eq!(aws_sig_v4.service, "execute-api");
+			}
+			other => panic!("Expected AWS SigV4 auth, got {other}"),
+		}
+	}
+
 	#[test]
 	fn retrieve_auth_unsupported_types_return_noauth() {
-		for auth_type in [
-			AuthType::Awsv4,
-			AuthType::Hawk,
-			AuthType::Ntlm,
-			AuthType::Oauth1,
-			AuthType::Oauth2,
-		] {
+		for auth_type in [AuthType::Hawk, AuthType::Ntlm, AuthType::Oauth1] {
 			let postman_auth = make_auth(auth_type);
 			let rc = RequestClass {
 				auth: Some(postman_auth),
@@ -1403,11 +1581,13 @@ mod tests {
 			item: None,
 		};
 
-		let script = retrieve_request_scripts(&item).unwrap();
-		// pm. prefix should be stripped
-		assert!(script.contains("environment.set('key', 'value');"));
-		assert!(script.contains("console.log('hello');"));
-		assert!(!script.contains("pm."));
+		let scripts = retrieve_request_scripts(&item);
+		// pm. calls are kept, the scripts provide a pm object
+		assert_eq!(
+			scripts.pre_request_script.as_deref(),
+			Some("pm.environment.set('key', 'value');\npm.console.log('hello');\n")
+		);
+		assert!(scripts.post_request_script.is_none());
 	}
 
 	#[test]
@@ -1425,17 +1605,17 @@ mod tests {
 			item: None,
 		};
 
-		assert!(retrieve_request_scripts(&item).is_none());
+		assert!(retrieve_request_scripts(&item).is_empty());
 	}
 
 	#[test]
-	fn retrieve_request_scripts_ignores_test_events() {
+	fn retrieve_request_scripts_test_events_are_post_request_scripts() {
 		let item = Items {
 			description: None,
 			event: Some(vec![Event {
 				disabled: None,
 				id: None,
-				listen: "test".to_string(), // not "prerequest"
+				listen: "test".to_string(),
 				script: Some(Script {
 					exec: Some(Host::StringArray(vec!["pm.test('check');".to_string()])),
 					id: None,
@@ -1454,7 +1634,108 @@ mod tests {
 			item: None,
 		};
 
-		assert!(retrieve_request_scripts(&item).is_none());
+		let scripts = retrieve_request_scripts(&item);
+		assert!(scripts.pre_request_script.is_none());
+		assert_eq!(
+			scripts.post_request_script.as_deref(),
+			Some("pm.test('check');\n")
+		);
+	}
+
+	fn make_event(listen: &str, exec: Host, disabled: Option<bool>) -> Event {
+		Event {
+			disabled,
+			id: None,
+			listen: listen.to_string(),
+			script: Some(Script {
+				exec: Some(exec),
+				id: None,
+				name: None,
+				src: None,
+				script_type: None,
+			}),
+		}
+	}
+
+	#[test]
+	fn retrieve_scripts_concatenates_events_and_skips_disabled_ones() {
+		let events = vec![
+			make_event("prerequest", Host::String("first();".to_string()), None),
+			make_event(
+				"prerequest",
+				Host::String("disabled();".to_string()),
+				Some(true),
+			),
+			make_event(
+				"prerequest",
+				Host::StringArray(vec!["second();".to_string()]),
+				Some(false),
+			),
+			make_event("unknown", Host::String("ignored();".to_string()), None),
+		];
+
+		let scripts = retrieve_scripts(Some(&events));
+		assert_eq!(
+			scripts.pre_request_script.as_deref(),
+			Some("first();\nsecond();\n")
+		);
+		assert!(scripts.post_request_script.is_none());
+	}
+
+	// ── retrieve_collection_environment ──────────────────────────
+
+	fn make_variable(
+		key: &str,
+		value: Option<serde_json::Value>,
+		disabled: Option<bool>,
+	) -> Variable {
+		Variable {
+			description: None,
+			disabled,
+			id: None,
+			key: Some(key.to_string()),
+			name: None,
+			system: None,
+			variable_type: None,
+			value,
+		}
+	}
+
+	#[test]
+	fn retrieve_collection_environment_converts_values() {
+		let variables = vec![
+			make_variable(
+				"base_url",
+				Some(serde_json::json!("https://example.com")),
+				None,
+			),
+			make_variable("retries", Some(serde_json::json!(3)), None),
+			make_variable("empty", None, None),
+			make_variable("disabled", Some(serde_json::json!("x")), Some(true)),
+		];
+
+		let environment = retrieve_collection_environment(Some(&variables)).unwrap();
+		assert_eq!(environment.name, COLLECTION_VARIABLES_ENVIRONMENT);
+		assert_eq!(
+			environment
+				.values
+				.into_iter()
+				.collect::<Vec<(String, String)>>(),
+			vec![
+				("base_url".to_string(), "https://example.com".to_string()),
+				("retries".to_string(), "3".to_string()),
+				("empty".to_string(), String::new()),
+			]
+		);
+	}
+
+	#[test]
+	fn retrieve_collection_environment_without_variables_returns_none() {
+		assert!(retrieve_collection_environment(None).is_none());
+		assert!(
+			retrieve_collection_environment(Some(&[make_variable("disabled", None, Some(true))]))
+				.is_none()
+		);
 	}
 
 	// ── retrieve_settings ────────────────────────────────────────
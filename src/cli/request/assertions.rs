@@ -0,0 +1,22 @@
+use crate::app::App;
+
+impl App<'_> {
+	pub fn cli_print_request_assertions(
+		&mut self,
+		collection_index: usize,
+		request_index: usize,
+	) -> anyhow::Result<()> {
+		let local_selected_request =
+			self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+		{
+			let selected_request = local_selected_request.read();
+
+			for (index, assertion) in selected_request.assertions.iter().enumerate() {
+				println!("{index}: {assertion}");
+			}
+		}
+
+		Ok(())
+	}
+}
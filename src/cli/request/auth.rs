@@ -1,9 +1,11 @@
 use crate::app::App;
 use crate::models::auth::auth::Auth;
+use crate::models::auth::aws_sig_v4::AwsSigV4;
 use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
 use crate::models::auth::digest::Digest;
 use crate::models::auth::jwt::JwtToken;
+use chrono::Utc;
 
 impl App<'_> {
 	pub fn cli_print_request_auth(
@@ -13,48 +15,74 @@ impl App<'_> {
 	) -> anyhow::Result<()> {
 		let local_selected_request =
 			self.get_request_as_local_from_indexes(&(collection_index, request_index));
+		self.load_oauth2_token(&local_selected_request, Some(collection_index));
 
-		{
-			let selected_request = local_selected_request.read();
+		print_auth(&local_selected_request.read().auth);
 
-			print!("{}\n\t", selected_request.auth);
+		Ok(())
+	}
+}
 
-			match &selected_request.auth {
-				Auth::NoAuth => {}
-				Auth::BasicAuth(BasicAuth { username, password }) => {
-					println!("username: {username}\n\tpassword: {password}")
-				}
-				Auth::BearerToken(BearerToken {
-					token: bearer_token,
-				}) => println!("token: {bearer_token}"),
-				Auth::JwtToken(JwtToken {
-					algorithm,
-					secret_type,
-					secret,
-					payload,
-				}) => println!(
-					"algorithm: {algorithm}\n\tsecret_type: {secret_type}\n\tsecret: {secret}\n\tpayload: {payload}"
-				),
-				Auth::Digest(Digest {
-					username,
-					password,
-					domains,
-					realm,
-					nonce,
-					opaque,
-					stale,
-					algorithm,
-					qop,
-					user_hash,
-					charset,
-					..
-				}) => println!(
-					"username: {username}\n\tpassword: {password}\n\tdomains: {domains}\n\trealm: {realm}\n\tnonce: {nonce}\n\topaque: {opaque}\n\tstale: {}\n\talgorithm: {algorithm}\n\tqop: {qop}\n\tuser_hash: {}\n\tcharset: {charset}",
-					stale, user_hash
-				),
-			}
-		}
+/// Print an auth method followed by its fields
+pub fn print_auth(auth: &Auth) {
+	print!("{auth}\n\t");
 
-		Ok(())
+	match auth {
+		Auth::NoAuth | Auth::Inherit => {}
+		Auth::BasicAuth(BasicAuth { username, password }) => {
+			println!("username: {username}\n\tpassword: {password}")
+		}
+		Auth::BearerToken(BearerToken {
+			token: bearer_token,
+		}) => println!("token: {bearer_token}"),
+		Auth::JwtToken(JwtToken {
+			algorithm,
+			secret_type,
+			secret,
+			payload,
+		}) => println!(
+			"algorithm: {algorithm}\n\tsecret_type: {secret_type}\n\tsecret: {secret}\n\tpayload: {payload}"
+		),
+		Auth::Digest(Digest {
+			username,
+			password,
+			domains,
+			realm,
+			nonce,
+			opaque,
+			stale,
+			algorithm,
+			qop,
+			user_hash,
+			charset,
+			..
+		}) => println!(
+			"username: {username}\n\tpassword: {password}\n\tdomains: {domains}\n\trealm: {realm}\n\tnonce: {nonce}\n\topaque: {opaque}\n\tstale: {}\n\talgorithm: {algorithm}\n\tqop: {qop}\n\tuser_hash: {}\n\tcharset: {charset}",
+			stale, user_hash
+		),
+		Auth::OAuth2(oauth2) => println!(
+			"grant_type: {}\n\ttoken_url: {}\n\tauth_url: {}\n\tclient_id: {}\n\tclient_secret: {}\n\tscope: {}\n\tusername: {}\n\tpassword: {}\n\trefresh_token: {}\n\tpkce: {}\n\tredirect_port: {}\n\ttoken: {}",
+			oauth2.grant_type,
+			oauth2.token_url,
+			oauth2.auth_url,
+			oauth2.client_id,
+			oauth2.client_secret,
+			oauth2.scope,
+			oauth2.username,
+			oauth2.password,
+			oauth2.refresh_token,
+			oauth2.pkce,
+			oauth2.redirect_port,
+			oauth2.token_state(Utc::now())
+		),
+		Auth::AwsSigV4(AwsSigV4 {
+			access_key_id,
+			secret_access_key,
+			region,
+			service,
+			session_token,
+		}) => println!(
+			"access_key_id: {access_key_id}\n\tsecret_access_key: {secret_access_key}\n\tregion: {region}\n\tservice: {service}\n\tsession_token: {session_token}"
+		),
 	}
 }
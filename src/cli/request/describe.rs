@@ -1,12 +1,14 @@
 use crate::app::App;
 use crate::app::key_value::print_key_value_vector;
 use crate::models::auth::auth::Auth;
+use crate::models::auth::aws_sig_v4::AwsSigV4;
 use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
 use crate::models::auth::digest::Digest;
 use crate::models::auth::jwt::JwtToken;
 use crate::models::protocol::http::body::ContentType;
 use crate::models::protocol::protocol::Protocol;
+use chrono::Utc;
 
 impl App<'_> {
 	pub fn cli_describe_request(
@@ -16,6 +18,7 @@ impl App<'_> {
 	) -> anyhow::Result<()> {
 		let local_request =
 			self.get_request_as_local_from_indexes(&(collection_index, request_index));
+		self.load_oauth2_token(&local_request, Some(collection_index));
 		let request = local_request.read();
 
 		println!("name: {}", request.name);
@@ -34,6 +37,7 @@ impl App<'_> {
 
 		match &request.auth {
 			Auth::NoAuth => {}
+			Auth::Inherit => println!("auth: Inherit"),
 			Auth::BasicAuth(BasicAuth { username, password }) => {
 				println!("auth: Basic\n\tusername: {username}\n\tpassword: {password}")
 			}
@@ -65,6 +69,30 @@ impl App<'_> {
 				"auth: Digest\n\tusername: {username}\n\tpassword: {password}\n\tdomains: {domains}\n\trealm: {realm}\n\tnonce: {nonce}\n\topaque: {opaque}\n\tstale: {}\n\talgorithm: {algorithm}\n\tqop: {qop}\n\tuser_hash: {}\n\tcharset: {charset}",
 				stale, user_hash
 			),
+			Auth::OAuth2(oauth2) => println!(
+				"auth: OAuth2\n\tgrant_type: {}\n\ttoken_url: {}\n\tauth_url: {}\n\tclient_id: {}\n\tclient_secret: {}\n\tscope: {}\n\tusername: {}\n\tpassword: {}\n\trefresh_token: {}\n\tpkce: {}\n\tredirect_port: {}\n\ttoken: {}",
+				oauth2.grant_type,
+				oauth2.token_url,
+				oauth2.auth_url,
+				oauth2.client_id,
+				oauth2.client_secret,
+				oauth2.scope,
+				oauth2.username,
+				oauth2.password,
+				oauth2.refresh_token,
+				oauth2.pkce,
+				oauth2.redirect_port,
+				oauth2.token_state(Utc::now())
+			),
+			Auth::AwsSigV4(AwsSigV4 {
+				access_key_id,
+				secret_access_key,
+				region,
+				service,
+				session_token,
+			}) => println!(
+				"auth: AWS SigV4\n\taccess_key_id: {access_key_id}\n\tsecret_access_key: {secret_access_key}\n\tregion: {region}\n\tservice: {service}\n\tsession_token: {session_token}"
+			),
 		}
 
 		if let Protocol::HttpRequest(http_request) = &request.protocol {
@@ -97,6 +125,10 @@ impl App<'_> {
 			(true, true) => println!("scripts:\n\tpre and post-request"),
 		}
 
+		if let Some(response_filter) = &request.response_filter {
+			println!("response filter: {response_filter}");
+		}
+
 		Ok(())
 	}
 }
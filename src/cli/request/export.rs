@@ -1,4 +1,5 @@
 use crate::app::App;
+use crate::app::files::secrets::mask_secrets;
 use crate::models::export::ExportFormat;
 
 impl App<'_> {
@@ -7,6 +8,7 @@ impl App<'_> {
 		collection_index: usize,
 		request_index: usize,
 		export_format: &ExportFormat,
+		reveal_secrets: bool,
 	) -> anyhow::Result<()> {
 		let local_selected_request =
 			self.get_request_as_local_from_indexes(&(collection_index, request_index));
@@ -14,10 +16,16 @@ impl App<'_> {
 		{
 			let selected_request = local_selected_request.read();
 
-			let export_result =
-				self.export_request_to_string_with_format(export_format, &selected_request)?;
+			let export_result = self.export_request_to_string_with_format(
+				export_format,
+				&selected_request,
+				Some(collection_index),
+			)?;
 
-			println!("{export_result}");
+			match reveal_secrets {
+				true => println!("{export_result}"),
+				false => println!("{}", mask_secrets(&export_result)),
+			}
 		}
 
 		Ok(())
@@ -1,10 +1,13 @@
+pub(crate) mod assertions;
 pub(crate) mod auth;
+pub(crate) mod captures;
 pub(crate) mod describe;
 pub(crate) mod export;
 pub(crate) mod headers;
 pub(crate) mod http;
 pub(super) mod new;
 pub(crate) mod query_params;
+pub(crate) mod replay;
 pub(crate) mod scripts;
 pub(crate) mod send;
 pub(crate) mod settings;
@@ -11,7 +11,7 @@ use crate::models::protocol::protocol::Protocol;
 use crate::models::request::{ConsoleOutput, DEFAULT_HEADERS, KeyValue, Request};
 use crate::models::response::RequestResponse;
 use crate::models::scripts::RequestScripts;
-use crate::models::settings::{RequestSettings, Setting};
+use crate::models::settings::{RequestSettings, Setting, TlsSettings};
 use anyhow::anyhow;
 use clap::ValueEnum;
 use tokio_util::sync::CancellationToken;
@@ -20,15 +20,23 @@ impl App<'_> {
 	pub fn cli_new_request(
 		&mut self,
 		collection_slash_request: (String, String),
+		folder_path: Option<&str>,
 		new_request_command: NewRequestCommand,
 	) -> anyhow::Result<()> {
 		let collection_index = self.find_collection(&collection_slash_request.0)?;
+		let folder_path = match folder_path {
+			Some(folder_path) => self.find_folder(collection_index, folder_path)?,
+			None => vec![],
+		};
 		let new_request = create_request_from_new_request_command(
 			collection_slash_request.1.trim().to_string(),
 			new_request_command,
 		)?;
 
-		self.new_request(collection_index, new_request)?;
+		match folder_path.is_empty() {
+			true => self.new_request(collection_index, new_request)?,
+			false => self.new_request_in_folder(collection_index, &folder_path, new_request)?,
+		}
 
 		Ok(())
 	}
@@ -132,17 +140,27 @@ pub fn create_request_from_new_request_command(
 		settings: RequestSettings {
 			use_config_proxy: Setting::Bool(!new_request_command.no_proxy),
 			allow_redirects: Setting::Bool(!new_request_command.no_redirects),
+			max_redirects: Setting::U32(new_request_command.max_redirects),
 			timeout: Setting::U32(new_request_command.timeout),
 			store_received_cookies: Setting::Bool(!new_request_command.no_cookies),
 			pretty_print_response_content: Setting::Bool(!new_request_command.no_pretty),
 			accept_invalid_certs: Setting::Bool(new_request_command.accept_invalid_certs),
 			accept_invalid_hostnames: Setting::Bool(new_request_command.accept_invalid_hostnames),
+			tls: TlsSettings::default(),
 		},
+		assertions: vec![],
+		captures: vec![],
+		response_filter: None,
 		response: RequestResponse::default(),
 		console_output: ConsoleOutput::default(),
+		assertion_results: vec![],
+		test_results: vec![],
+		capture_results: vec![],
 		is_pending: false,
+		download_progress: None,
 		cancellation_token: CancellationToken::new(),
 		source_path: None,
+		inherited_scripts: vec![],
 	};
 
 	request.update_url_and_params(new_request_command.url);
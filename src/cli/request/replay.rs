@@ -0,0 +1,118 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use parking_lot::RwLock;
+
+use crate::app::App;
+use crate::cli::commands::request_commands::send::SendCommand;
+use crate::models::auth::auth::Auth;
+use crate::models::history::{HistoryEntry, is_credential_header};
+use crate::models::protocol::http::body::ContentType;
+use crate::models::protocol::http::http::HttpRequest;
+use crate::models::protocol::http::method::Method;
+use crate::models::protocol::protocol::Protocol;
+use crate::models::request::{KeyValue, Request};
+
+impl App<'_> {
+	/// Send a history entry again with its resolved URL, headers and body.
+	/// The recorded credentials are not replayed: the auth and credential headers of the request
+	/// are resolved again when it is still in its collection.
+	/// The new exchange is recorded under the same request.
+	pub async fn replay_history_entry(
+		&mut self,
+		entry: &HistoryEntry,
+		send_command: &SendCommand,
+	) -> anyhow::Result<()> {
+		let method = Method::from_str(&entry.sent.method).map_err(|_| {
+			anyhow!(
+				"Only HTTP requests can be replayed, \"{}\" is a {} request",
+				entry.short_id(),
+				entry.protocol
+			)
+		})?;
+
+		let body = match &entry.sent.body {
+			None => ContentType::NoBody,
+			Some(body) => ContentType::Raw(body.clone()),
+		};
+
+		// Keep the replay attached to its collection for the history and the cookie jar
+		let collection_index = entry.collection.as_ref().and_then(|collection_name| {
+			self.core
+				.collections
+				.iter()
+				.position(|collection| &collection.name == collection_name)
+		});
+
+		let (auth, credential_headers) = self
+			.replayed_request_credentials(collection_index, &entry.request)
+			.unwrap_or_default();
+
+		let headers = entry
+			.sent
+			.headers
+			.iter()
+			.filter(|(name, _)| !is_credential_header(name) && !is_signature_header(name))
+			.map(|(name, value)| KeyValue {
+				enabled: true,
+				data: (name.clone(), value.clone()),
+			})
+			.chain(credential_headers)
+			.collect();
+
+		let request = Request {
+			name: entry.request.clone(),
+			url: entry.sent.url.clone(),
+			headers,
+			auth,
+			protocol: Protocol::HttpRequest(HttpRequest {
+				method,
+				body,
+				..Default::default()
+			}),
+			..Default::default()
+		};
+
+		self.local_send_request(
+			send_command,
+			Arc::new(RwLock::new(request)),
+			collection_index,
+		)
+		.await?;
+
+		Ok(())
+	}
+
+	/// Auth and enabled credential headers of the collection request a history entry was sent
+	/// from, inheritance resolved
+	fn replayed_request_credentials(
+		&self,
+		collection_index: Option<usize>,
+		request_name: &str,
+	) -> Option<(Auth, Vec<KeyValue>)> {
+		let collection_index = collection_index?;
+		let collection = self.core.collections.get(collection_index)?;
+
+		let (folder_path, request) = collection
+			.requests_with_folder_path()
+			.into_iter()
+			.find(|(_, request)| request.read().name == request_name)?;
+
+		let request = request.read();
+		let inheritance = self.folder_inheritance(collection_index, &folder_path);
+
+		let credential_headers = inheritance
+			.resolve_headers(&request.headers)
+			.into_iter()
+			.filter(|header| header.enabled && is_credential_header(&header.data.0))
+			.collect();
+
+		Some((inheritance.resolve_auth(&request.auth), credential_headers))
+	}
+}
+
+/// Headers added by the AWS signature, signed again on replay
+fn is_signature_header(name: &str) -> bool {
+	name.eq_ignore_ascii_case("x-amz-date") || name.eq_ignore_ascii_case("x-amz-content-sha256")
+}
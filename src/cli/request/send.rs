@@ -1,13 +1,21 @@
 use crate::app::App;
-use crate::app::request::grpc::send::send_grpc_request;
+use crate::app::constants::WS_POLL_INTERVAL;
+use crate::app::request::grpc::send::{
+	half_close_grpc_stream, send_grpc_request, send_grpc_stream_message,
+};
 use crate::app::request::http::send::send_http_request;
 use crate::app::request::ws::send::send_ws_request;
+use crate::app::runner::{RequestRunResult, RunReport, grpc_status_failure, status_failure};
+use crate::cli::commands::collection_commands::run::RunCommand;
 use crate::cli::commands::request_commands::send::SendCommand;
+use crate::models::assertions::{Assertion, AssertionResult};
+use crate::models::captures::CaptureResult;
 use crate::models::protocol::protocol::Protocol;
 use crate::models::protocol::ws::message_type::MessageType;
 use crate::models::protocol::ws::ws::{Message, Sender};
 use crate::models::request::Request;
-use crate::models::response::ResponseContent;
+use crate::models::response::{RequestResponse, ResponseContent, format_size};
+use crate::models::scripts::ScriptTestResult;
 use anyhow::anyhow;
 use chrono::Local;
 use futures_util::SinkExt;
@@ -20,6 +28,7 @@ use ratatui_image::picker::Picker;
 use ratatui_image::{Resize, ResizeEncodeRender};
 use std::io::stdout;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::io;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tracing::{info, warn};
@@ -34,41 +43,259 @@ impl App<'_> {
 		let local_request =
 			self.get_request_as_local_from_indexes(&(collection_index, request_index));
 
-		self.local_send_request(send_command, local_request, Some(collection_index))
+		let response = self
+			.local_send_request(send_command, local_request.clone(), Some(collection_index))
 			.await?;
 
 		if self.core.config.should_save_requests_response() {
 			self.save_collection_to_file(collection_index);
 		}
 
-		Ok(())
+		let request = local_request.read();
+
+		for result in &request.assertion_results {
+			print_assertion_result(result);
+		}
+
+		for result in &request.test_results {
+			print_test_result(result);
+		}
+
+		for result in &request.capture_results {
+			print_capture_result(result);
+		}
+
+		let mut failures: Vec<String> = vec![];
+
+		// A gRPC status assertion replaces the default check of the call status
+		if !request
+			.assertions
+			.iter()
+			.any(Assertion::is_grpc_status_check)
+		{
+			failures.extend(grpc_status_failure(&response.grpc_status));
+		}
+
+		let failed_assertions = request
+			.assertion_results
+			.iter()
+			.filter(|r| !r.passed)
+			.count();
+
+		if failed_assertions > 0 {
+			failures.push(format!(
+				"{failed_assertions} of {} assertion(s) failed",
+				request.assertion_results.len()
+			));
+		}
+
+		let failed_tests = request.test_results.iter().filter(|r| !r.passed).count();
+
+		if failed_tests > 0 {
+			failures.push(format!(
+				"{failed_tests} of {} test(s) failed",
+				request.test_results.len()
+			));
+		}
+
+		let failed_captures = request
+			.capture_results
+			.iter()
+			.filter(|r| r.value.is_none())
+			.count();
+
+		if failed_captures > 0 {
+			failures.push(format!(
+				"{failed_captures} of {} capture(s) failed",
+				request.capture_results.len()
+			));
+		}
+
+		match failures.is_empty() {
+			true => Ok(()),
+			false => Err(anyhow!(failures.join(", "))),
+		}
 	}
 
 	pub async fn cli_send_collection(
 		&mut self,
 		collection_name: &str,
+		run_command: &RunCommand,
 		send_command: &SendCommand,
 	) -> anyhow::Result<()> {
+		if send_command.output.is_some() {
+			return Err(anyhow!(
+				"--output can only be used when sending a single request"
+			));
+		}
+
 		let collection_index = self.find_collection(collection_name)?;
 		let collection = &self.core.collections[collection_index];
 
-		let mut requests: Vec<Arc<RwLock<Request>>> = vec![];
+		// Folders first, then root-level requests, same order as the collection tree
+		let mut requests: Vec<(Option<String>, Arc<RwLock<Request>>)> = vec![];
+
+		for (folder_path, request) in collection.requests_with_folder_path() {
+			let folder = match folder_path.is_empty() {
+				true => None,
+				false => Some(collection.folder_path_name(&folder_path)),
+			};
+
+			// A folder filter also selects the sub-folders
+			let is_filtered_out = !run_command.folder.is_empty()
+				&& !folder.as_ref().is_some_and(|folder| {
+					run_command
+						.folder
+						.iter()
+						.any(|filter| folder == filter || folder.starts_with(&format!("{filter}/")))
+				});
 
-		for request in &collection.requests {
-			let local_request = request.clone();
-			requests.push(local_request);
+			if !is_filtered_out {
+				requests.push((folder, request));
+			}
 		}
 
-		for request in requests {
-			self.local_send_request(send_command, request, Some(collection_index))
-				.await?;
+		if !run_command.request.is_empty() {
+			requests.retain(|(_, request)| run_command.request.contains(&request.read().name));
+		}
+
+		if requests.is_empty() {
+			return Err(anyhow!("No request matches the given filters"));
+		}
+
+		let mut report = RunReport {
+			collection: collection.name.clone(),
+			iterations: run_command.iterations,
+			timestamp: Local::now().to_rfc3339(),
+			..Default::default()
+		};
+
+		let run_start = Instant::now();
+
+		'iterations: for iteration in 1..=run_command.iterations {
+			for (folder, request) in &requests {
+				let (name, is_websocket) = {
+					let request = request.read();
+					(
+						request.name.clone(),
+						matches!(request.protocol, Protocol::WsRequest(_)),
+					)
+				};
+
+				let mut result = RequestRunResult {
+					iteration,
+					name,
+					folder: folder.clone(),
+					..Default::default()
+				};
+
+				// WebSocket requests are interactive and cannot be part of an unattended run
+				if is_websocket {
+					result.skipped = true;
+				} else {
+					let request_start = Instant::now();
+
+					let stream_stop =
+						tokio::spawn(stop_event_stream_after_timeout(request.clone()));
+					let response = self
+						.local_send_request(send_command, request.clone(), Some(collection_index))
+						.await;
+					stream_stop.abort();
+
+					match response {
+						Ok(response) => {
+							let request = request.read();
+
+							// An explicit status assertion replaces the default 4xx/5xx check
+							if !request.assertions.iter().any(Assertion::is_status_check) {
+								result
+									.failures
+									.extend(status_failure(&response.status_code));
+							}
+
+							// gRPC calls fail in their trailers, usually with a 200 HTTP status
+							if !request
+								.assertions
+								.iter()
+								.any(Assertion::is_grpc_status_check)
+							{
+								result
+									.failures
+									.extend(grpc_status_failure(&response.grpc_status));
+							}
+
+							result.failures.extend(
+								request
+									.assertion_results
+									.iter()
+									.filter(|assertion_result| !assertion_result.passed)
+									.map(assertion_failure),
+							);
+
+							result.failures.extend(
+								request
+									.test_results
+									.iter()
+									.filter(|test_result| !test_result.passed)
+									.map(test_failure),
+							);
+
+							result.failures.extend(
+								request
+									.capture_results
+									.iter()
+									.filter(|capture_result| capture_result.value.is_none())
+									.map(capture_failure),
+							);
+
+							result.status_code = response.status_code;
+							result.duration = response.duration;
+						}
+						Err(error) => result.failures.push(error.to_string()),
+					}
 
-			if self.core.config.should_save_requests_response() {
-				self.save_collection_to_file(collection_index);
+					result.duration_ms = request_start.elapsed().as_millis();
+
+					if self.core.config.should_save_requests_response() {
+						self.save_collection_to_file(collection_index);
+					}
+				}
+
+				print_run_result(&result);
+
+				let is_failure = result.is_failure();
+				report.results.push(result);
+
+				if is_failure && run_command.bail {
+					break 'iterations;
+				}
 			}
 		}
 
-		Ok(())
+		report.duration_ms = run_start.elapsed().as_millis();
+
+		println!(
+			"{} passed, {} failed, {} skipped",
+			report.passed_count(),
+			report.failed_count(),
+			report.skipped_count()
+		);
+
+		if let Some(junit_path) = &run_command.junit {
+			report.write_junit(junit_path)?;
+		}
+
+		if let Some(json_report_path) = &run_command.json_report {
+			report.write_json(json_report_path)?;
+		}
+
+		match report.failed_count() {
+			0 => Ok(()),
+			failed_count => Err(anyhow!(
+				"{failed_count} of {} request(s) failed",
+				report.results.len()
+			)),
+		}
 	}
 
 	pub async fn local_send_request(
@@ -76,26 +303,29 @@ impl App<'_> {
 		send_command: &SendCommand,
 		local_request: Arc<RwLock<Request>>,
 		collection_index: Option<usize>,
-	) -> anyhow::Result<()> {
-		// Synchronous phase: prepare the request while holding the write guard.
-		let (prepared, protocol) = {
-			let mut request = local_request.write();
+	) -> anyhow::Result<RequestResponse> {
+		if let Some(env_name) = &send_command.env {
+			let env_index = self.find_environment(env_name)?;
+			self.core.selected_environment = env_index;
+		};
 
-			if let Some(env_name) = &send_command.env {
-				let env_index = self.find_environment(env_name)?;
-				self.core.selected_environment = env_index;
-			};
+		// If --collection-env is specified, set the collection's selected environment
+		if let Some(coll_env_name) = &send_command.collection_env
+			&& let Some(ci) = collection_index
+		{
+			self.core.collections[ci].selected_environment = Some(coll_env_name.clone());
+		}
 
-			// If --collection-env is specified, set the collection's selected environment
-			if let Some(coll_env_name) = &send_command.collection_env
-				&& let Some(ci) = collection_index
-			{
-				self.core.collections[ci].selected_environment = Some(coll_env_name.clone());
-			}
+		if send_command.request_name {
+			println!("{}", local_request.read().name);
+		}
 
-			if send_command.request_name {
-				println!("{}", request.name);
-			}
+		self.refresh_oauth2_token(&local_request, collection_index, false)
+			.await?;
+
+		// Synchronous phase: prepare the request while holding the write guard.
+		let (prepared, protocol, tls, inherited_headers) = {
+			let mut request = local_request.write();
 
 			let prepared = match self.prepare_request(&mut request, collection_index) {
 				Ok(prepared) => prepared,
@@ -111,32 +341,95 @@ impl App<'_> {
 			};
 
 			let protocol = request.protocol.clone();
-			(prepared, protocol)
+			let tls = self.resolve_tls_settings(&request, collection_index);
+			let inherited_headers = self
+				.request_inheritance(&request, collection_index)
+				.resolve_headers(&request.headers);
+			(prepared, protocol, tls, inherited_headers)
 		};
 		// Guard is dropped here — safe to await for file body finalization
 
+		for error in &prepared.unresolved {
+			eprintln!("Warning: {error}, sent as is");
+		}
+
+		if send_command.output.is_some()
+			&& !matches!(
+				protocol,
+				Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_)
+			) {
+			return Err(anyhow!("--output is only supported by HTTP requests"));
+		}
+
+		let sent_request = prepared.sent_request.clone();
+		let connection_timer = prepared.connection_timer.clone();
 		let prepared_request = App::finalize_prepared_request(prepared).await?;
 
 		let local_env = self.get_selected_env_as_local();
 		let response = match protocol {
 			Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) => {
-				send_http_request(prepared_request, local_request.clone(), &local_env).await?
+				let send = send_http_request(
+					prepared_request,
+					connection_timer,
+					local_request.clone(),
+					&local_env,
+					self.core.received_response.clone(),
+					self.core.config.get_max_response_body_size(),
+					send_command.output.as_deref(),
+				);
+				tokio::pin!(send);
+
+				// Server-sent events are printed as they arrive
+				let mut printed_events = 0;
+
+				loop {
+					tokio::select! {
+						response = &mut send => {
+							print_new_events(&local_request, &mut printed_events);
+							break response?;
+						},
+						_ = tokio::time::sleep(WS_POLL_INTERVAL) => {
+							print_new_events(&local_request, &mut printed_events);
+						}
+					}
+				}
 			}
 			Protocol::GrpcRequest(ref grpc_req) => {
 				let url = {
 					let req = local_request.read();
 					req.url.clone()
 				};
-				let headers = {
-					let req = local_request.read();
-					req.headers
-						.iter()
-						.filter(|h| h.enabled)
-						.map(|h| (h.data.0.clone(), h.data.1.clone()))
-						.collect::<Vec<_>>()
-				};
-				send_grpc_request(grpc_req, &url, &headers, local_request.clone(), &local_env)
-					.await?
+				let headers = inherited_headers
+					.iter()
+					.filter(|h| h.enabled)
+					.map(|h| (h.data.0.clone(), h.data.1.clone()))
+					.collect::<Vec<_>>();
+				let send = send_grpc_request(
+					grpc_req,
+					&url,
+					&headers,
+					&tls,
+					local_request.clone(),
+					&local_env,
+					self.core.received_response.clone(),
+				);
+				tokio::pin!(send);
+
+				// A client-streaming call may only be answered once its request stream is
+				// half-closed, so stdin is forwarded as soon as the call is open
+				let mut forwarding_stdin = false;
+
+				loop {
+					tokio::select! {
+						response = &mut send => break response?,
+						_ = tokio::time::sleep(WS_POLL_INTERVAL), if !forwarding_stdin => {
+							if local_request.read().get_grpc_request()?.can_send_messages() {
+								forward_stdin_to_grpc_stream(local_request.clone());
+								forwarding_stdin = true;
+							}
+						}
+					}
+				}
 			}
 			Protocol::WsRequest(_) => {
 				send_ws_request(
@@ -149,99 +442,149 @@ impl App<'_> {
 			}
 		};
 
-		let request = local_request.read();
+		self.save_cookie_stores();
 
-		if send_command.status_code
-			&& let Some(status_code) = response.status_code.as_ref()
-		{
-			println!("{}", status_code);
-		}
+		let request_name = local_request.read().name.clone();
+		self.record_history_entry(
+			collection_index,
+			&request_name,
+			&protocol,
+			sent_request,
+			&response,
+		);
 
-		if send_command.duration
-			&& let Some(duration) = &response.duration
-		{
-			println!("{}", duration);
-		}
+		let capture_results = local_request.read().capture_results.clone();
+		self.apply_captures(collection_index, &capture_results)?;
 
-		if send_command.cookies
-			&& let Some(cookies) = &response.cookies
 		{
-			println!("{}", cookies);
-		}
+			let request = local_request.read();
 
-		if send_command.headers {
-			println!("{:?}", response.headers);
-		}
+			if send_command.status_code
+				&& let Some(status_code) = response.status_code.as_ref()
+			{
+				println!("{}", status_code);
+			}
 
-		if send_command.console {
-			let console_output = match (
-				&request.console_output.pre_request_output,
-				&request.console_output.post_request_output,
-			) {
-				(None, None) => &String::new(),
-				(Some(pre_request_console_output), None) => pre_request_console_output,
-				(None, Some(post_request_console_output)) => post_request_console_output,
-				(Some(pre_request_console_output), Some(post_request_console_output)) => {
-					&format!("{pre_request_console_output}\n{post_request_console_output}")
-				}
-			};
+			if send_command.duration
+				&& let Some(duration) = &response.duration
+			{
+				println!("{}", duration);
+			}
 
-			println!("{}", console_output);
-		}
+			if send_command.timing && response.timing.is_some() {
+				println!("{}", response.timing_report());
+			}
 
-		if !send_command.hide_content {
-			match response.content {
-				None => {}
-				Some(content) => match content {
-					ResponseContent::Body(body) => println!("{}", body),
-					ResponseContent::Image(image) => match image.image {
-						None => {
-							println!("{:?}", image.data)
-						}
-						Some(dynamic_image) => {
-							let image_width = dynamic_image.width() as f32;
-							let image_height = dynamic_image.height() as f32;
+			if send_command.redirects && !response.redirects.is_empty() {
+				println!("{}", response.redirects_report());
+			}
 
-							let backend = CrosstermBackend::new(stdout());
-							let terminal_size = backend.size()?;
+			if send_command.cookies
+				&& let Some(cookies) = &response.cookies
+			{
+				println!("{}", cookies);
+			}
 
-							let width_ratio = terminal_size.width as f32 / image_width;
-							let height_ratio = terminal_size.height as f32 / image_height;
+			if send_command.headers {
+				println!("{:?}", response.headers);
 
-							let ratio = width_ratio.min(height_ratio);
+				if !response.trailers.is_empty() {
+					println!("{:?}", response.trailers);
+				}
+			}
 
-							let mut terminal = Terminal::with_options(
-								backend,
-								TerminalOptions {
-									viewport: Viewport::Inline((image_height * ratio) as u16),
-								},
-							)?;
+			if send_command.console {
+				let console_output = match (
+					&request.console_output.pre_request_output,
+					&request.console_output.post_request_output,
+				) {
+					(None, None) => &String::new(),
+					(Some(pre_request_console_output), None) => pre_request_console_output,
+					(None, Some(post_request_console_output)) => post_request_console_output,
+					(Some(pre_request_console_output), Some(post_request_console_output)) => {
+						&format!("{pre_request_console_output}\n{post_request_console_output}")
+					}
+				};
 
-							let picker = match self.core.config.is_graphical_protocol_disabled() {
-								true => Picker::halfblocks(),
-								false => Picker::from_query_stdio().unwrap_or(Picker::halfblocks()),
-							};
+				println!("{}", console_output);
+			}
+
+			if let Some(output) = &send_command.output {
+				if let Some(body_size) = response.body_size {
+					eprintln!(
+						"Response body saved to {} ({})",
+						output.display(),
+						format_size(body_size)
+					);
+				}
+			}
+			// The events of a stream were printed as they arrived
+			else if !send_command.hide_content && !request.is_event_stream() {
+				if response.is_body_truncated
+					&& let Some(body_size) = response.body_size
+				{
+					eprintln!(
+						"Warning: Response body of {} truncated to its first {}, use --output to save it whole",
+						format_size(body_size),
+						format_size(response.raw_body.len() as u64)
+					);
+				}
 
-							let mut stateful_protocol = picker.new_resize_protocol(dynamic_image);
+				match &response.content {
+					None => {}
+					Some(content) => match content {
+						ResponseContent::Body(body) => println!("{}", body),
+						ResponseContent::Image(image) => match &image.image {
+							None => {
+								println!("{:?}", image.data)
+							}
+							Some(dynamic_image) => {
+								let image_width = dynamic_image.width() as f32;
+								let image_height = dynamic_image.height() as f32;
 
-							terminal.draw(|frame| {
-								stateful_protocol.resize_encode_render(
-									&Resize::Fit(None),
-									Rect {
-										x: 0,
-										y: 0,
-										width: (image_width * ratio) as u16,
-										height: (image_height * ratio) as u16,
+								let backend = CrosstermBackend::new(stdout());
+								let terminal_size = backend.size()?;
+
+								let width_ratio = terminal_size.width as f32 / image_width;
+								let height_ratio = terminal_size.height as f32 / image_height;
+
+								let ratio = width_ratio.min(height_ratio);
+
+								let mut terminal = Terminal::with_options(
+									backend,
+									TerminalOptions {
+										viewport: Viewport::Inline((image_height * ratio) as u16),
 									},
-									frame.buffer_mut(),
-								)
-							})?;
-						}
+								)?;
+
+								let picker =
+									match self.core.config.is_graphical_protocol_disabled() {
+										true => Picker::halfblocks(),
+										false => Picker::from_query_stdio()
+											.unwrap_or(Picker::halfblocks()),
+									};
+
+								let mut stateful_protocol =
+									picker.new_resize_protocol(dynamic_image.clone());
+
+								terminal.draw(|frame| {
+									stateful_protocol.resize_encode_render(
+										&Resize::Fit(None),
+										Rect {
+											x: 0,
+											y: 0,
+											width: (image_width * ratio) as u16,
+											height: (image_height * ratio) as u16,
+										},
+										frame.buffer_mut(),
+									)
+								})?;
+							}
+						},
 					},
-				},
-			};
+				};
+			}
 		}
-		drop(request);
 
 		if let Protocol::WsRequest(_) = &protocol {
 			let mut last_length = 0;
@@ -316,13 +659,7 @@ impl App<'_> {
 					let messages = &ws_request.messages[last_length..];
 
 					for message in messages {
-						println!(
-							"=== {} - New {} message from {} ===\n{}",
-							message.timestamp.format("%H:%M:%S %d/%m/%Y"),
-							message.content,
-							message.sender,
-							message.content.to_content()
-						)
+						print_stream_message(message);
 					}
 
 					last_length = ws_request.messages.len();
@@ -330,6 +667,182 @@ impl App<'_> {
 			}
 		}
 
-		Ok(())
+		if let Protocol::GrpcRequest(_) = &protocol {
+			print_grpc_stream(local_request).await?;
+		}
+
+		Ok(response)
+	}
+}
+
+/// Send each stdin line as a client message of the open gRPC call, EOF half-closes
+/// the request stream.
+fn forward_stdin_to_grpc_stream(local_request: Arc<RwLock<Request>>) {
+	tokio::spawn(async move {
+		let mut lines = BufReader::new(io::stdin()).lines();
+
+		while let Ok(Some(line)) = lines.next_line().await {
+			if line.trim().is_empty() {
+				continue;
+			}
+
+			let mut request = local_request.write();
+			let Ok(grpc_request) = request.get_grpc_request_mut() else {
+				return;
+			};
+
+			if let Err(e) = send_grpc_stream_message(grpc_request, &line) {
+				warn!("Failed to send gRPC message: {e}");
+			}
+		}
+
+		let mut request = local_request.write();
+		if let Ok(grpc_request) = request.get_grpc_request_mut() {
+			half_close_grpc_stream(grpc_request);
+		}
+	});
+}
+
+/// Print the server-sent events received since the last call. `printed_events` counts the
+/// events dropped from the request as well.
+fn print_new_events(local_request: &Arc<RwLock<Request>>, printed_events: &mut usize) {
+	let request = local_request.read();
+
+	let Ok(http_request) = request.get_http_request() else {
+		return;
+	};
+
+	let skipped_events = printed_events.saturating_sub(http_request.dropped_events);
+
+	for message in http_request.events.iter().skip(skipped_events) {
+		print_stream_message(message);
+	}
+
+	*printed_events = http_request.dropped_events + http_request.events.len();
+}
+
+/// Event streams are reconnected until canceled, a collection run reads them for the request
+/// timeout at most
+async fn stop_event_stream_after_timeout(local_request: Arc<RwLock<Request>>) {
+	let start = Instant::now();
+
+	loop {
+		tokio::time::sleep(WS_POLL_INTERVAL).await;
+
+		let request = local_request.read();
+		let timeout = request.settings.timeout.as_u32().unwrap_or(30000) as u64;
+
+		if request.is_event_stream() && start.elapsed() >= Duration::from_millis(timeout) {
+			info!("Event stream read for {timeout} ms, closing it");
+			request.cancellation_token.cancel();
+			return;
+		}
+	}
+}
+
+/// Print the messages of a streaming gRPC call until the server ends it
+async fn print_grpc_stream(local_request: Arc<RwLock<Request>>) -> anyhow::Result<()> {
+	let mut last_length = 0;
+
+	loop {
+		let is_connected = {
+			let request = local_request.read();
+			let grpc_request = request.get_grpc_request()?;
+
+			for message in &grpc_request.messages[last_length..] {
+				print_stream_message(message);
+			}
+
+			last_length = grpc_request.messages.len();
+			grpc_request.is_connected()
+		};
+
+		if !is_connected {
+			break;
+		}
+
+		tokio::time::sleep(WS_POLL_INTERVAL).await;
+	}
+
+	Ok(())
+}
+
+fn print_stream_message(message: &Message) {
+	println!(
+		"=== {} - New {} message from {} ===\n{}",
+		message.timestamp.format("%H:%M:%S %d/%m/%Y"),
+		message.content,
+		message.sender,
+		message.content.to_content()
+	)
+}
+
+fn assertion_failure(result: &AssertionResult) -> String {
+	match &result.message {
+		Some(message) => format!("Assertion \"{}\" failed: {message}", result.assertion),
+		None => format!("Assertion \"{}\" failed", result.assertion),
+	}
+}
+
+fn print_assertion_result(result: &AssertionResult) {
+	match (result.passed, &result.message) {
+		(true, _) => println!("PASS {}", result.assertion),
+		(false, Some(message)) => println!("FAIL {} ({message})", result.assertion),
+		(false, None) => println!("FAIL {}", result.assertion),
+	}
+}
+
+fn test_failure(result: &ScriptTestResult) -> String {
+	match &result.error {
+		Some(error) => format!("Test \"{}\" failed: {error}", result.name),
+		None => format!("Test \"{}\" failed", result.name),
+	}
+}
+
+fn print_test_result(result: &ScriptTestResult) {
+	match (result.passed, &result.error) {
+		(true, _) => println!("PASS test \"{}\"", result.name),
+		(false, Some(error)) => println!("FAIL test \"{}\" ({error})", result.name),
+		(false, None) => println!("FAIL test \"{}\"", result.name),
+	}
+}
+
+fn capture_failure(result: &CaptureResult) -> String {
+	match &result.error {
+		Some(error) => format!("Capture \"{}\" failed: {error}", result.key),
+		None => format!("Capture \"{}\" failed", result.key),
+	}
+}
+
+fn print_capture_result(result: &CaptureResult) {
+	match (&result.value, &result.error) {
+		(Some(_), _) => println!("PASS capture \"{}\"", result.key),
+		(None, Some(error)) => println!("FAIL capture \"{}\" ({error})", result.key),
+		(None, None) => println!("FAIL capture \"{}\"", result.key),
+	}
+}
+
+fn print_run_result(result: &RequestRunResult) {
+	if result.skipped {
+		println!("SKIP {}", result.path());
+		return;
+	}
+
+	let details = [&result.status_code, &result.duration]
+		.into_iter()
+		.flatten()
+		.cloned()
+		.collect::<Vec<String>>()
+		.join(", ");
+
+	match result.is_failure() {
+		false => println!("PASS {} ({details})", result.path()),
+		true => {
+			println!("FAIL {} ({details})", result.path());
+
+			for failure in &result.failures {
+				println!("\t- {failure}");
+			}
+		}
 	}
 }
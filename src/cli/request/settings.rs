@@ -3,7 +3,7 @@ use tracing::info;
 
 use crate::app::App;
 use crate::cli::commands::request_commands::settings::RequestSettingName;
-use crate::models::settings::Setting;
+use crate::models::settings::{Setting, split_paths};
 
 impl App<'_> {
 	pub fn cli_print_request_settings(
@@ -40,39 +40,58 @@ impl App<'_> {
 
 			info!("Setting \"{}\" set to \"{}\"", setting_name, new_value);
 
-			match new_value {
-				Setting::Bool(bool) => match setting_name {
-					RequestSettingName::Proxy => {
-						selected_request.settings.use_config_proxy = Setting::Bool(*bool)
-					}
-					RequestSettingName::Redirects => {
-						selected_request.settings.allow_redirects = Setting::Bool(*bool)
-					}
-					RequestSettingName::Cookies => {
-						selected_request.settings.store_received_cookies = Setting::Bool(*bool)
-					}
-					RequestSettingName::Pretty => {
-						selected_request.settings.pretty_print_response_content =
-							Setting::Bool(*bool)
-					}
-					_ => {
-						return Err(anyhow!(format!(
-							"The setting \"{}\" only takes positive int values",
-							setting_name
-						)));
-					}
-				},
-				Setting::U32(u32) => match setting_name {
-					RequestSettingName::Timeout => {
-						selected_request.settings.timeout = Setting::U32(*u32)
-					}
-					_ => {
-						return Err(anyhow!(format!(
-							"The setting \"{}\" only takes boolean values",
-							setting_name
-						)));
-					}
-				},
+			let settings = &mut selected_request.settings;
+
+			match (setting_name, new_value) {
+				(RequestSettingName::Proxy, Setting::Bool(bool)) => {
+					settings.use_config_proxy = Setting::Bool(*bool)
+				}
+				(RequestSettingName::Redirects, Setting::Bool(bool)) => {
+					settings.allow_redirects = Setting::Bool(*bool)
+				}
+				(RequestSettingName::Cookies, Setting::Bool(bool)) => {
+					settings.store_received_cookies = Setting::Bool(*bool)
+				}
+				(RequestSettingName::Pretty, Setting::Bool(bool)) => {
+					settings.pretty_print_response_content = Setting::Bool(*bool)
+				}
+				(RequestSettingName::MaxRedirects, Setting::U32(u32)) => {
+					settings.max_redirects = Setting::U32(*u32)
+				}
+				(RequestSettingName::Timeout, Setting::U32(u32)) => {
+					settings.timeout = Setting::U32(*u32)
+				}
+				(RequestSettingName::ClientCert, Setting::Text(path)) => {
+					settings.tls.client_certificate = (!path.is_empty()).then(|| path.clone())
+				}
+				(RequestSettingName::ClientKey, Setting::Text(path)) => {
+					settings.tls.client_key = (!path.is_empty()).then(|| path.clone())
+				}
+				// Digits only passwords are parsed as numbers
+				(RequestSettingName::ClientCertPassword, password) => {
+					let password = password.to_string();
+					settings.tls.client_certificate_password =
+						(!password.is_empty()).then_some(password)
+				}
+				(RequestSettingName::CaCerts, Setting::Text(paths)) => {
+					settings.tls.ca_certificates = split_paths(paths)
+				}
+				_ => {
+					let expected_values = match setting_name {
+						RequestSettingName::MaxRedirects | RequestSettingName::Timeout => {
+							"positive int"
+						}
+						RequestSettingName::ClientCert
+						| RequestSettingName::ClientKey
+						| RequestSettingName::CaCerts => "file path",
+						_ => "boolean",
+					};
+
+					return Err(anyhow!(format!(
+						"The setting \"{}\" only takes {} values",
+						setting_name, expected_values
+					)));
+				}
 			}
 		}
 
@@ -93,13 +112,30 @@ impl App<'_> {
 		{
 			let selected_request = local_selected_request.read();
 
+			let settings = &selected_request.settings;
+
 			let setting = match setting_name {
-				RequestSettingName::Proxy => &selected_request.settings.use_config_proxy,
-				RequestSettingName::Timeout => &selected_request.settings.timeout,
-				RequestSettingName::Redirects => &selected_request.settings.allow_redirects,
-				RequestSettingName::Cookies => &selected_request.settings.store_received_cookies,
-				RequestSettingName::Pretty => {
-					&selected_request.settings.pretty_print_response_content
+				RequestSettingName::Proxy => settings.use_config_proxy.clone(),
+				RequestSettingName::Timeout => settings.timeout.clone(),
+				RequestSettingName::Redirects => settings.allow_redirects.clone(),
+				RequestSettingName::MaxRedirects => settings.max_redirects.clone(),
+				RequestSettingName::Cookies => settings.store_received_cookies.clone(),
+				RequestSettingName::Pretty => settings.pretty_print_response_content.clone(),
+				RequestSettingName::ClientCert => {
+					Setting::Text(settings.tls.client_certificate.clone().unwrap_or_default())
+				}
+				RequestSettingName::ClientKey => {
+					Setting::Text(settings.tls.client_key.clone().unwrap_or_default())
+				}
+				RequestSettingName::ClientCertPassword => Setting::Text(
+					settings
+						.tls
+						.client_certificate_password
+						.clone()
+						.unwrap_or_default(),
+				),
+				RequestSettingName::CaCerts => {
+					Setting::Text(settings.tls.ca_certificates.join(", "))
 				}
 			};
 
@@ -2,14 +2,19 @@ use anyhow::anyhow;
 use thiserror::Error;
 
 use crate::app::App;
-use crate::cli::utils::collection::FindElementError::{CollectionNotFound, RequestNotFound};
+use crate::cli::utils::collection::FindElementError::{
+	CollectionNotFound, FolderNotFound, RequestNotFound,
+};
 
 #[derive(Error, Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum FindElementError {
 	#[error("Collection not found")]
 	CollectionNotFound,
 	#[error("Request not found")]
 	RequestNotFound,
+	#[error("Folder not found")]
+	FolderNotFound,
 }
 
 impl App<'_> {
@@ -23,6 +28,31 @@ impl App<'_> {
 		Err(anyhow!(CollectionNotFound))
 	}
 
+	/// Path of folder indexes of a "/" separated folder path, e.g. "admin/users"
+	pub fn find_folder(
+		&mut self,
+		collection_index: usize,
+		folder_path: &str,
+	) -> anyhow::Result<Vec<usize>> {
+		let collection = &self.core.collections[collection_index];
+		let mut folder_indexes = vec![];
+
+		for folder_name in folder_path.split('/').filter(|name| !name.is_empty()) {
+			let folder_index = collection
+				.folders_in(&folder_indexes)
+				.iter()
+				.position(|folder| folder.name == folder_name)
+				.ok_or(anyhow!(FolderNotFound))?;
+
+			folder_indexes.push(folder_index);
+		}
+
+		match folder_indexes.is_empty() {
+			true => Err(anyhow!(FolderNotFound)),
+			false => Ok(folder_indexes),
+		}
+	}
+
 	pub fn find_collection_slash_request(
 		&mut self,
 		collection_name: &str,
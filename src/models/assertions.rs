@@ -0,0 +1,340 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::models::protocol::grpc::status::{grpc_code_from_name, grpc_code_name};
+
+/// A declarative check evaluated against a request's response.
+///
+/// Assertions are stored in collection files and can be written in a short
+/// textual form (see [`Assertion::from_str`]), which is used by the CLI and
+/// the `.http` file format (`# @assert <assertion>`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Assertion {
+	/// `status == 200`
+	StatusEquals(u16),
+	/// `status in 200..299` (inclusive)
+	StatusInRange { min: u16, max: u16 },
+	/// `grpc-status == NOT_FOUND`, holding the canonical name of the gRPC status
+	GrpcStatusEquals(String),
+	/// `header content-type exists`
+	HeaderExists(String),
+	/// `header content-type matches ^application/json`
+	HeaderMatches { name: String, pattern: String },
+	/// `jsonpath $.data.id == 42`
+	JsonPathEquals {
+		path: String,
+		value: serde_json::Value,
+	},
+	/// `duration < 500` (milliseconds)
+	DurationBelow(u64),
+	/// `schema {"type": "object", "required": ["id"]}`
+	JsonSchema(serde_json::Value),
+}
+
+/// Result of the evaluation of a single [`Assertion`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssertionResult {
+	pub assertion: String,
+	pub passed: bool,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub message: Option<String>,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ParseAssertionError {
+	#[error(
+		"Unknown assertion \"{0}\", expected one of: status, grpc-status, header, jsonpath, duration, schema"
+	)]
+	UnknownAssertion(String),
+	#[error("Invalid assertion \"{0}\"\n\t{1}")]
+	InvalidAssertion(String, String),
+}
+
+impl Assertion {
+	/// Whether this assertion checks the response status code.
+	pub fn is_status_check(&self) -> bool {
+		matches!(
+			self,
+			Assertion::StatusEquals(_) | Assertion::StatusInRange { .. }
+		)
+	}
+
+	/// Whether this assertion checks the gRPC status of the response.
+	pub fn is_grpc_status_check(&self) -> bool {
+		matches!(self, Assertion::GrpcStatusEquals(_))
+	}
+}
+
+impl Display for Assertion {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Assertion::StatusEquals(status) => write!(f, "status == {status}"),
+			Assertion::StatusInRange { min, max } => write!(f, "status in {min}..{max}"),
+			Assertion::GrpcStatusEquals(status) => write!(f, "grpc-status == {status}"),
+			Assertion::HeaderExists(name) => write!(f, "header {name} exists"),
+			Assertion::HeaderMatches { name, pattern } => {
+				write!(f, "header {name} matches {pattern}")
+			}
+			Assertion::JsonPathEquals { path, value } => write!(f, "jsonpath {path} == {value}"),
+			Assertion::DurationBelow(duration) => write!(f, "duration < {duration}"),
+			Assertion::JsonSchema(schema) => write!(f, "schema {schema}"),
+		}
+	}
+}
+
+impl FromStr for Assertion {
+	type Err = ParseAssertionError;
+
+	fn from_str(input: &str) -> Result<Self, Self::Err> {
+		let input = input.trim();
+		let invalid = |message: &str| {
+			ParseAssertionError::InvalidAssertion(input.to_string(), message.to_string())
+		};
+
+		let (kind, rest) = input.split_once(char::is_whitespace).unwrap_or((input, ""));
+		let rest = rest.trim();
+
+		match kind.to_lowercase().as_str() {
+			"status" => {
+				if let Some(status) = rest.strip_prefix("==") {
+					let status = parse_status(status).map_err(|e| invalid(&e))?;
+					Ok(Assertion::StatusEquals(status))
+				} else if let Some(range) = rest.strip_prefix("in ") {
+					let (min, max) = range
+						.split_once("..")
+						.ok_or_else(|| invalid("expected a range such as 200..299"))?;
+					let min = parse_status(min).map_err(|e| invalid(&e))?;
+					let max = parse_status(max).map_err(|e| invalid(&e))?;
+
+					if min > max {
+						return Err(invalid("the range minimum is greater than its maximum"));
+					}
+
+					Ok(Assertion::StatusInRange { min, max })
+				} else {
+					Err(invalid(
+						"expected \"status == <code>\" or \"status in <min>..<max>\"",
+					))
+				}
+			}
+			"grpc-status" => {
+				let status = rest
+					.strip_prefix("==")
+					.ok_or_else(|| invalid("expected \"grpc-status == <name or code>\""))?
+					.trim();
+
+				let code = match status.parse::<u32>() {
+					Ok(code) if (code as usize) < 17 => Some(code),
+					Ok(_) => None,
+					Err(_) => grpc_code_from_name(status),
+				};
+
+				match code {
+					Some(code) => Ok(Assertion::GrpcStatusEquals(
+						grpc_code_name(code).to_string(),
+					)),
+					None => Err(invalid(&format!("\"{status}\" is not a valid gRPC status"))),
+				}
+			}
+			"header" => {
+				let (name, condition) = rest
+					.split_once(char::is_whitespace)
+					.ok_or_else(|| invalid("expected \"header <name> exists|matches <regex>\""))?;
+				let condition = condition.trim();
+
+				if condition == "exists" {
+					Ok(Assertion::HeaderExists(name.to_string()))
+				} else if let Some(pattern) = condition.strip_prefix("matches") {
+					let pattern = pattern.trim();
+
+					if let Err(error) = regex::Regex::new(pattern) {
+						return Err(invalid(&error.to_string()));
+					}
+
+					Ok(Assertion::HeaderMatches {
+						name: name.to_string(),
+						pattern: pattern.to_string(),
+					})
+				} else {
+					Err(invalid("expected \"exists\" or \"matches <regex>\""))
+				}
+			}
+			"jsonpath" => {
+				let (path, value) = rest
+					.split_once("==")
+					.ok_or_else(|| invalid("expected \"jsonpath <path> == <value>\""))?;
+				let value = value.trim();
+
+				// Non-JSON values are compared as plain strings
+				let value = serde_json::from_str(value)
+					.unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+
+				Ok(Assertion::JsonPathEquals {
+					path: path.trim().to_string(),
+					value,
+				})
+			}
+			"duration" => {
+				let duration = rest
+					.strip_prefix('<')
+					.ok_or_else(|| invalid("expected \"duration < <milliseconds>\""))?
+					.trim()
+					.trim_end_matches("ms")
+					.parse::<u64>()
+					.map_err(|e| invalid(&e.to_string()))?;
+
+				Ok(Assertion::DurationBelow(duration))
+			}
+			"schema" => {
+				let schema = serde_json::from_str(rest).map_err(|e| invalid(&e.to_string()))?;
+				Ok(Assertion::JsonSchema(schema))
+			}
+			_ => Err(ParseAssertionError::UnknownAssertion(input.to_string())),
+		}
+	}
+}
+
+fn parse_status(status: &str) -> Result<u16, String> {
+	status
+		.trim()
+		.parse::<u16>()
+		.map_err(|_| format!("\"{}\" is not a valid status code", status.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn parse_status_assertions() {
+		assert_eq!(
+			Assertion::from_str("status == 200").unwrap(),
+			Assertion::StatusEquals(200)
+		);
+		assert_eq!(
+			Assertion::from_str("status in 200..299").unwrap(),
+			Assertion::StatusInRange { min: 200, max: 299 }
+		);
+		assert!(Assertion::from_str("status in 300..200").is_err());
+		assert!(Assertion::from_str("status == abc").is_err());
+	}
+
+	#[test]
+	fn parse_grpc_status_assertions() {
+		assert_eq!(
+			Assertion::from_str("grpc-status == not_found").unwrap(),
+			Assertion::GrpcStatusEquals(String::from("NOT_FOUND"))
+		);
+		assert_eq!(
+			Assertion::from_str("grpc-status == 0").unwrap(),
+			Assertion::GrpcStatusEquals(String::from("OK"))
+		);
+		assert!(Assertion::from_str("grpc-status == 17").is_err());
+		assert!(Assertion::from_str("grpc-status == MISSING").is_err());
+	}
+
+	#[test]
+	fn parse_header_assertions() {
+		assert_eq!(
+			Assertion::from_str("header content-type exists").unwrap(),
+			Assertion::HeaderExists(String::from("content-type"))
+		);
+		assert_eq!(
+			Assertion::from_str("header content-type matches ^application/json").unwrap(),
+			Assertion::HeaderMatches {
+				name: String::from("content-type"),
+				pattern: String::from("^application/json"),
+			}
+		);
+		assert!(Assertion::from_str("header content-type matches (").is_err());
+	}
+
+	#[test]
+	fn parse_jsonpath_assertions() {
+		assert_eq!(
+			Assertion::from_str("jsonpath $.data.id == 42").unwrap(),
+			Assertion::JsonPathEquals {
+				path: String::from("$.data.id"),
+				value: json!(42),
+			}
+		);
+		assert_eq!(
+			Assertion::from_str("jsonpath $.name == squrl").unwrap(),
+			Assertion::JsonPathEquals {
+				path: String::from("$.name"),
+				value: json!("squrl"),
+			}
+		);
+	}
+
+	#[test]
+	fn parse_duration_and_schema_assertions() {
+		assert_eq!(
+			Assertion::from_str("duration < 500ms").unwrap(),
+			Assertion::DurationBelow(500)
+		);
+		assert_eq!(
+			Assertion::from_str(r#"schema {"type": "object"}"#).unwrap(),
+			Assertion::JsonSchema(json!({"type": "object"}))
+		);
+	}
+
+	#[test]
+	fn parse_unknown_assertion() {
+		assert!(matches!(
+			Assertion::from_str("body contains foo"),
+			Err(ParseAssertionError::UnknownAssertion(_))
+		));
+	}
+
+	#[test]
+	fn display_roundtrips_through_from_str() {
+		let assertions = vec![
+			Assertion::StatusEquals(201),
+			Assertion::StatusInRange { min: 200, max: 204 },
+			Assertion::GrpcStatusEquals(String::from("UNAVAILABLE")),
+			Assertion::HeaderExists(String::from("x-id")),
+			Assertion::HeaderMatches {
+				name: String::from("content-type"),
+				pattern: String::from("json$"),
+			},
+			Assertion::JsonPathEquals {
+				path: String::from("$.items[0].name"),
+				value: json!("first"),
+			},
+			Assertion::DurationBelow(250),
+			Assertion::JsonSchema(json!({"type": "array", "items": {"type": "number"}})),
+		];
+
+		for assertion in assertions {
+			let parsed = Assertion::from_str(&assertion.to_string()).unwrap();
+			assert_eq!(parsed, assertion);
+		}
+	}
+
+	#[test]
+	fn serde_roundtrip_json_and_yaml() {
+		let assertions = vec![
+			Assertion::StatusEquals(200),
+			Assertion::HeaderMatches {
+				name: String::from("content-type"),
+				pattern: String::from("json"),
+			},
+			Assertion::JsonSchema(json!({"type": "object"})),
+		];
+
+		let json = serde_json::to_string(&assertions).unwrap();
+		assert!(json.contains("\"status_equals\":200"));
+		let from_json: Vec<Assertion> = serde_json::from_str(&json).unwrap();
+		assert_eq!(from_json, assertions);
+
+		let yaml = serde_yaml_ng::to_string(&assertions).unwrap();
+		let from_yaml: Vec<Assertion> = serde_yaml_ng::from_str(&yaml).unwrap();
+		assert_eq!(from_yaml, assertions);
+	}
+}
@@ -1,7 +1,9 @@
+use crate::models::auth::aws_sig_v4::AwsSigV4;
 use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
 use crate::models::auth::digest::Digest;
 use crate::models::auth::jwt::JwtToken;
+use crate::models::auth::oauth2::OAuth2;
 use clap::Subcommand;
 use serde::{Deserialize, Serialize};
 use strum::Display;
@@ -33,6 +35,21 @@ pub enum Auth {
 	#[strum(to_string = "Digest")]
 	/// Digest auth method
 	Digest(Digest),
+
+	#[strum(to_string = "OAuth2")]
+	#[clap(name = "oauth2")]
+	#[serde(rename = "oauth2")]
+	/// OAuth 2.0 auth method
+	OAuth2(OAuth2),
+
+	#[strum(to_string = "AWS SigV4")]
+	#[clap(visible_alias = "aws")]
+	/// AWS Signature Version 4 auth method
+	AwsSigV4(AwsSigV4),
+
+	#[strum(to_string = "Inherit")]
+	/// Use the auth of the request folder, or else of its collection
+	Inherit,
 }
 
 impl Auth {
@@ -63,6 +80,27 @@ impl Auth {
 			_ => unreachable!(),
 		}
 	}
+
+	pub fn get_oauth2(&self) -> &OAuth2 {
+		match self {
+			Auth::OAuth2(oauth2) => oauth2,
+			_ => unreachable!(),
+		}
+	}
+
+	pub fn get_oauth2_mut(&mut self) -> &mut OAuth2 {
+		match self {
+			Auth::OAuth2(oauth2) => oauth2,
+			_ => unreachable!(),
+		}
+	}
+
+	pub fn get_aws_sig_v4(&self) -> &AwsSigV4 {
+		match self {
+			Auth::AwsSigV4(aws_sig_v4) => aws_sig_v4,
+			_ => unreachable!(),
+		}
+	}
 }
 
 pub fn next_auth(auth: &Auth) -> Auth {
@@ -71,6 +109,9 @@ pub fn next_auth(auth: &Auth) -> Auth {
 		Auth::BasicAuth(_) => Auth::BearerToken(BearerToken::default()),
 		Auth::BearerToken(_) => Auth::JwtToken(JwtToken::default()),
 		Auth::JwtToken(_) => Auth::Digest(Digest::default()),
-		Auth::Digest(_) => Auth::NoAuth,
+		Auth::Digest(_) => Auth::OAuth2(OAuth2::default()),
+		Auth::OAuth2(_) => Auth::AwsSigV4(AwsSigV4::default()),
+		Auth::AwsSigV4(_) => Auth::Inherit,
+		Auth::Inherit => Auth::NoAuth,
 	}
 }
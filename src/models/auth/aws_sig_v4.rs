@@ -0,0 +1,377 @@
+use chrono::{DateTime, Utc};
+use clap::Args;
+use hmac::{Hmac, Mac};
+use reqwest::Url;
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::path::Path;
+
+const ALGORITHM: &str = "AWS4-HMAC-SHA256";
+
+/// Payload hash of streamed bodies, which cannot be hashed before being sent
+const UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
+
+/// Headers that can be modified on the way to the server, so they are never signed
+const UNSIGNED_HEADERS: [&str; 4] = ["authorization", "user-agent", "expect", "x-amzn-trace-id"];
+
+#[derive(Args, Clone, Default, Debug, Serialize, Deserialize)]
+pub struct AwsSigV4 {
+	pub access_key_id: String,
+	pub secret_access_key: String,
+	/// e.g. us-east-1
+	pub region: String,
+	/// e.g. execute-api, s3, lambda
+	pub service: String,
+	/// Session token of temporary credentials
+	#[arg(long, default_value_t)]
+	#[serde(default)]
+	pub session_token: String,
+}
+
+/// Request parts covered by the signature
+pub struct AwsSigV4Request<'a> {
+	pub method: &'a str,
+	pub url: &'a Url,
+	pub headers: &'a HeaderMap,
+	/// See [`aws_sig_v4_payload_hash`]
+	pub payload_hash: &'a str,
+}
+
+impl AwsSigV4 {
+	/// Sign a request, returning the headers to add to it (`Authorization` last)
+	pub fn signed_headers(
+		&self,
+		request: &AwsSigV4Request,
+		now: DateTime<Utc>,
+	) -> Vec<(String, String)> {
+		let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+		let date = now.format("%Y%m%d").to_string();
+		let is_s3 = self.service == "s3";
+
+		let mut added_headers = vec![(String::from("x-amz-date"), amz_date.clone())];
+
+		if !self.session_token.is_empty() {
+			added_headers.push((
+				String::from("x-amz-security-token"),
+				self.session_token.clone(),
+			));
+		}
+
+		// S3 requires the payload hash as a header, other services only use it in the signature
+		if is_s3 {
+			added_headers.push((
+				String::from("x-amz-content-sha256"),
+				request.payload_hash.to_string(),
+			));
+		}
+
+		/* CANONICAL REQUEST */
+
+		let mut headers: Vec<(String, String)> = vec![(String::from("host"), host(request.url))];
+
+		for (name, value) in request.headers {
+			let name = name.as_str().to_lowercase();
+
+			// Headers set by the signature replace the request ones
+			if UNSIGNED_HEADERS.contains(&name.as_str())
+				|| name == "host"
+				|| added_headers.iter().any(|(added, _)| *added == name)
+			{
+				continue;
+			}
+
+			let value = String::from_utf8_lossy(value.as_bytes());
+			let value = value.split_whitespace().collect::<Vec<&str>>().join(" ");
+
+			match headers.iter_mut().find(|(existing, _)| *existing == name) {
+				Some((_, existing_value)) => {
+					existing_value.push(',');
+					existing_value.push_str(&value);
+				}
+				None => headers.push((name, value)),
+			}
+		}
+
+		headers.extend(added_headers.iter().cloned());
+		headers.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+		let canonical_headers: String = headers
+			.iter()
+			.map(|(name, value)| format!("{name}:{value}\n"))
+			.collect();
+		let signed_headers = headers
+			.iter()
+			.map(|(name, _)| name.as_str())
+			.collect::<Vec<&str>>()
+			.join(";");
+
+		let canonical_request = [
+			request.method,
+			&canonical_uri(request.url, is_s3),
+			&canonical_query(request.url),
+			&canonical_headers,
+			&signed_headers,
+			request.payload_hash,
+		]
+		.join("\n");
+
+		/* STRING TO SIGN */
+
+		let scope = format!("{date}/{}/{}/aws4_request", self.region, self.service);
+		let string_to_sign = format!(
+			"{ALGORITHM}\n{amz_date}\n{scope}\n{}",
+			sha256_hex(canonical_request.as_bytes())
+		);
+
+		/* SIGNATURE */
+
+		let secret = format!("AWS4{}", self.secret_access_key);
+		let signing_key = [
+			date.as_str(),
+			self.region.as_str(),
+			self.service.as_str(),
+			"aws4_request",
+		]
+		.iter()
+		.fold(secret.into_bytes(), |key, data| {
+			hmac_sha256(&key, data.as_bytes())
+		});
+		let signature = hex::encode(hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+
+		added_headers.push((
+			String::from("authorization"),
+			format!(
+				"{ALGORITHM} Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+				self.access_key_id
+			),
+		));
+
+		added_headers
+	}
+}
+
+/// Hash of the request body, or `UNSIGNED-PAYLOAD` when the body is streamed
+pub fn aws_sig_v4_payload_hash(body: Option<&[u8]>) -> String {
+	match body {
+		Some(body) => sha256_hex(body),
+		None => String::from(UNSIGNED_PAYLOAD),
+	}
+}
+
+/// Hash of a file body, without loading the whole file in memory
+pub fn aws_sig_v4_file_payload_hash(path: &Path) -> std::io::Result<String> {
+	let mut hasher = Sha256::new();
+	std::io::copy(&mut File::open(path)?, &mut hasher)?;
+
+	Ok(hex::encode(hasher.finalize()))
+}
+
+fn host(url: &Url) -> String {
+	let host = url.host_str().unwrap_or_default();
+
+	match url.port() {
+		Some(port) => format!("{host}:{port}"),
+		None => host.to_string(),
+	}
+}
+
+/// The path is already percent-encoded once by the URL, every service but S3 expects it encoded
+/// twice
+fn canonical_uri(url: &Url, is_s3: bool) -> String {
+	let path = match url.path() {
+		"" => "/",
+		path => path,
+	};
+
+	match is_s3 {
+		true => path.to_string(),
+		false => path
+			.split('/')
+			.map(uri_encode)
+			.collect::<Vec<String>>()
+			.join("/"),
+	}
+}
+
+fn canonical_query(url: &Url) -> String {
+	let mut pairs: Vec<(String, String)> = url
+		.query_pairs()
+		.map(|(key, value)| (uri_encode(&key), uri_encode(&value)))
+		.collect();
+
+	pairs.sort();
+
+	pairs
+		.iter()
+		.map(|(key, value)| format!("{key}={value}"))
+		.collect::<Vec<String>>()
+		.join("&")
+}
+
+fn uri_encode(input: &str) -> String {
+	let mut encoded = String::with_capacity(input.len());
+
+	for byte in input.bytes() {
+		match byte {
+			b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+				encoded.push(byte as char)
+			}
+			_ => encoded.push_str(&format!("%{byte:02X}")),
+		}
+	}
+
+	encoded
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+	hex::encode(Sha256::digest(data))
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+	let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any size");
+	mac.update(data);
+	mac.finalize().into_bytes().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chrono::TimeZone;
+	use reqwest::header::{CONTENT_TYPE, HeaderValue};
+
+	/// Credentials of the AWS Signature V4 test suite
+	fn test_suite_credentials() -> AwsSigV4 {
+		AwsSigV4 {
+			access_key_id: String::from("AKIDEXAMPLE"),
+			secret_access_key: String::from("wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY"),
+			region: String::from("us-east-1"),
+			service: String::from("service"),
+			session_token: String::new(),
+		}
+	}
+
+	fn test_suite_date() -> DateTime<Utc> {
+		Utc.with_ymd_and_hms(2015, 8, 30, 12, 36, 0).unwrap()
+	}
+
+	fn authorization(headers: &[(String, String)]) -> &str {
+		&headers.last().unwrap().1
+	}
+
+	#[test]
+	fn test_get_vanilla() {
+		let url = Url::parse("https://example.amazonaws.com/").unwrap();
+		let headers = test_suite_credentials().signed_headers(
+			&AwsSigV4Request {
+				method: "GET",
+				url: &url,
+				headers: &HeaderMap::new(),
+				payload_hash: &aws_sig_v4_payload_hash(Some(b"")),
+			},
+			test_suite_date(),
+		);
+
+		assert_eq!(
+			headers[0],
+			(String::from("x-amz-date"), String::from("20150830T123600Z"))
+		);
+		assert_eq!(
+			authorization(&headers),
+			"AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, SignedHeaders=host;x-amz-date, Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+		);
+	}
+
+	#[test]
+	fn test_get_vanilla_query_order() {
+		let url = Url::parse("https://example.amazonaws.com/?Param2=value2&Param1=value1").unwrap();
+		let headers = test_suite_credentials().signed_headers(
+			&AwsSigV4Request {
+				method: "GET",
+				url: &url,
+				headers: &HeaderMap::new(),
+				payload_hash: &aws_sig_v4_payload_hash(Some(b"")),
+			},
+			test_suite_date(),
+		);
+
+		assert!(authorization(&headers).ends_with(
+			"Signature=b97d918cfa904a5beff61c982a1b6f458b799221646efd99d3219ec94cdf2500"
+		));
+	}
+
+	#[test]
+	fn test_post_x_www_form_urlencoded() {
+		let url = Url::parse("https://example.amazonaws.com/").unwrap();
+		let mut request_headers = HeaderMap::new();
+		request_headers.insert(
+			CONTENT_TYPE,
+			HeaderValue::from_static("application/x-www-form-urlencoded"),
+		);
+
+		let headers = test_suite_credentials().signed_headers(
+			&AwsSigV4Request {
+				method: "POST",
+				url: &url,
+				headers: &request_headers,
+				payload_hash: &aws_sig_v4_payload_hash(Some(b"Param1=value1")),
+			},
+			test_suite_date(),
+		);
+
+		assert_eq!(
+			authorization(&headers),
+			"AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, SignedHeaders=content-type;host;x-amz-date, Signature=ff11897932ad3f4e8b18135d722051e5ac45fc38421b1da7b9d196a0fe09473a"
+		);
+	}
+
+	#[test]
+	fn test_session_token_and_s3_payload_hash_are_signed() {
+		let url = Url::parse("https://bucket.s3.amazonaws.com:8443/my%20file.txt").unwrap();
+		let aws_sig_v4 = AwsSigV4 {
+			service: String::from("s3"),
+			session_token: String::from("session"),
+			..test_suite_credentials()
+		};
+
+		let headers = aws_sig_v4.signed_headers(
+			&AwsSigV4Request {
+				method: "PUT",
+				url: &url,
+				headers: &HeaderMap::new(),
+				payload_hash: &aws_sig_v4_payload_hash(None),
+			},
+			test_suite_date(),
+		);
+
+		assert_eq!(
+			headers[1],
+			(
+				String::from("x-amz-security-token"),
+				String::from("session")
+			)
+		);
+		assert_eq!(
+			headers[2],
+			(
+				String::from("x-amz-content-sha256"),
+				String::from(UNSIGNED_PAYLOAD)
+			)
+		);
+		assert!(
+			authorization(&headers).contains(
+				"SignedHeaders=host;x-amz-content-sha256;x-amz-date;x-amz-security-token,"
+			)
+		);
+	}
+
+	#[test]
+	fn test_canonical_uri_and_query() {
+		let url = Url::parse("https://example.com/a b/c%2Fd?b=2&a=x y&a=1").unwrap();
+
+		assert_eq!(canonical_uri(&url, false), "/a%2520b/c%252Fd");
+		assert_eq!(canonical_uri(&url, true), "/a%20b/c%2Fd");
+		assert_eq!(canonical_query(&url), "a=1&a=x%20y&b=2");
+	}
+}
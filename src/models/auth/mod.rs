@@ -1,6 +1,8 @@
 #[allow(clippy::module_inception)]
 pub(crate) mod auth;
+pub(crate) mod aws_sig_v4;
 pub(crate) mod basic;
 pub(crate) mod bearer_token;
 pub(crate) mod digest;
 pub(crate) mod jwt;
+pub(crate) mod oauth2;
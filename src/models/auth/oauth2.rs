@@ -0,0 +1,653 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, TimeDelta, Utc};
+use clap::{Args, ValueEnum};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use strum::Display;
+use thiserror::Error;
+
+/// Tokens are refreshed a bit before they expire, so that they are still valid once the request
+/// reaches the server
+const EXPIRY_MARGIN: TimeDelta = TimeDelta::seconds(30);
+
+#[derive(Args, Default, Clone, Debug, Serialize, Deserialize)]
+pub struct OAuth2 {
+	/// Grant used to obtain the access token
+	pub grant_type: OAuth2GrantType,
+	/// Token endpoint
+	pub token_url: String,
+	/// Authorization endpoint, used by the authorization code grant
+	#[arg(long, default_value_t)]
+	#[serde(default)]
+	pub auth_url: String,
+	#[arg(long, default_value_t)]
+	#[serde(default)]
+	pub client_id: String,
+	/// Sent with HTTP Basic auth, public clients leave it empty
+	#[arg(long, default_value_t)]
+	#[serde(default)]
+	pub client_secret: String,
+	/// Space separated scopes
+	#[arg(long, default_value_t)]
+	#[serde(default)]
+	pub scope: String,
+	/// Resource owner username, used by the password grant
+	#[arg(long, default_value_t)]
+	#[serde(default)]
+	pub username: String,
+	/// Resource owner password, used by the password grant
+	#[arg(long, default_value_t)]
+	#[serde(default)]
+	pub password: String,
+	/// Refresh token, used by the refresh token grant
+	#[arg(long, default_value_t)]
+	#[serde(default)]
+	pub refresh_token: String,
+	/// Use PKCE with the authorization code grant
+	#[arg(long, action = clap::ArgAction::Set, default_value_t = true)]
+	#[serde(default = "default_pkce")]
+	pub pkce: bool,
+	/// Port of the loopback redirect listener, 0 picks a free one
+	#[arg(long, default_value_t)]
+	#[serde(default)]
+	pub redirect_port: u16,
+
+	/// Last access token obtained, kept in the token cache file rather than the collection
+	#[arg(skip)]
+	#[serde(skip)]
+	pub token: Option<OAuth2Token>,
+}
+
+fn default_pkce() -> bool {
+	true
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, ValueEnum, Display, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuth2GrantType {
+	#[default]
+	#[strum(to_string = "Client credentials")]
+	ClientCredentials,
+	#[strum(to_string = "Password")]
+	Password,
+	#[strum(to_string = "Authorization code")]
+	AuthorizationCode,
+	#[strum(to_string = "Refresh token")]
+	RefreshToken,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OAuth2Token {
+	pub access_token: String,
+	pub token_type: String,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub refresh_token: Option<String>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// Token obtained with an OAuth2 configuration, whose environment keys are resolved
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedOAuth2Token {
+	pub grant_type: OAuth2GrantType,
+	pub token_url: String,
+	#[serde(default)]
+	pub client_id: String,
+	#[serde(default)]
+	pub username: String,
+	#[serde(default)]
+	pub scope: String,
+	pub token: OAuth2Token,
+}
+
+impl CachedOAuth2Token {
+	fn is_for(&self, oauth2: &OAuth2) -> bool {
+		self.grant_type == oauth2.grant_type
+			&& self.token_url == oauth2.token_url
+			&& self.client_id == oauth2.client_id
+			&& self.username == oauth2.username
+			&& self.scope == oauth2.scope
+	}
+}
+
+/// Tokens of the OAuth2 configurations, saved in the main app directory
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct OAuth2TokenCache {
+	tokens: Vec<CachedOAuth2Token>,
+}
+
+impl OAuth2TokenCache {
+	pub fn get(&self, oauth2: &OAuth2) -> Option<&OAuth2Token> {
+		self.tokens
+			.iter()
+			.find(|cached| cached.is_for(oauth2))
+			.map(|cached| &cached.token)
+	}
+
+	/// Store the token of a configuration, replacing its previous one
+	pub fn insert(&mut self, oauth2: &OAuth2, token: OAuth2Token) {
+		self.remove(oauth2);
+
+		self.tokens.push(CachedOAuth2Token {
+			grant_type: oauth2.grant_type,
+			token_url: oauth2.token_url.clone(),
+			client_id: oauth2.client_id.clone(),
+			username: oauth2.username.clone(),
+			scope: oauth2.scope.clone(),
+			token,
+		});
+	}
+
+	/// Returns whether the configuration had a token
+	pub fn remove(&mut self, oauth2: &OAuth2) -> bool {
+		let count = self.tokens.len();
+		self.tokens.retain(|cached| !cached.is_for(oauth2));
+		self.tokens.len() != count
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.tokens.is_empty()
+	}
+}
+
+#[derive(Error, Debug)]
+pub enum OAuth2Error {
+	#[error("No token URL")]
+	MissingTokenUrl,
+
+	#[error("Invalid {0} URL")]
+	InvalidUrl(&'static str),
+
+	#[error("Token request failed: {0}")]
+	TokenRequest(String),
+
+	#[error("Token endpoint replied \"{0}\"")]
+	TokenEndpoint(String),
+
+	#[error("Invalid token response")]
+	InvalidTokenResponse,
+
+	#[error("Authorization denied: {0}")]
+	AuthorizationDenied(String),
+
+	#[error("Authorization response state does not match")]
+	StateMismatch,
+
+	#[error("No authorization response received")]
+	AuthorizationTimeout,
+
+	#[error("Redirect listener error: {0}")]
+	RedirectListener(String),
+}
+
+impl OAuth2Token {
+	pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+		self.expires_at
+			.is_some_and(|expires_at| expires_at - EXPIRY_MARGIN <= now)
+	}
+
+	/// Value of the `Authorization` header, the token type is normalized as most servers only
+	/// accept `Bearer`
+	pub fn authorization_header(&self) -> String {
+		let token_type =
+			match self.token_type.is_empty() || self.token_type.eq_ignore_ascii_case("bearer") {
+				true => "Bearer",
+				false => &self.token_type,
+			};
+
+		format!("{token_type} {}", self.access_token)
+	}
+
+	pub fn state_description(&self, now: DateTime<Utc>) -> String {
+		match self.expires_at {
+			None => String::from("Valid, no expiry"),
+			Some(_) if self.is_expired(now) => match self.refresh_token {
+				Some(_) => String::from("Expired, will be refreshed"),
+				None => String::from("Expired"),
+			},
+			Some(expires_at) => {
+				let remaining = (expires_at - now).num_seconds();
+				format!("Valid, expires in {}m {}s", remaining / 60, remaining % 60)
+			}
+		}
+	}
+}
+
+/// Result of the browser part of the authorization code grant
+pub struct AuthorizationCode {
+	pub code: String,
+	pub redirect_uri: String,
+	pub code_verifier: Option<String>,
+}
+
+impl OAuth2 {
+	pub fn token_state(&self, now: DateTime<Utc>) -> String {
+		match &self.token {
+			Some(token) => token.state_description(now),
+			None => String::from("No token"),
+		}
+	}
+
+	/// Form parameters of the token request for the configured grant, the authorization code grant
+	/// needs the code obtained through the browser
+	pub fn grant_params(
+		&self,
+		authorization_code: Option<&AuthorizationCode>,
+	) -> Vec<(&'static str, String)> {
+		let mut params = match (self.grant_type, authorization_code) {
+			(OAuth2GrantType::RefreshToken, _) => return self.refresh_params(&self.refresh_token),
+			(OAuth2GrantType::AuthorizationCode, Some(authorization_code)) => {
+				let mut params = vec![
+					("grant_type", String::from("authorization_code")),
+					("code", authorization_code.code.clone()),
+					("redirect_uri", authorization_code.redirect_uri.clone()),
+				];
+
+				if let Some(code_verifier) = &authorization_code.code_verifier {
+					params.push(("code_verifier", code_verifier.clone()));
+				}
+
+				params
+			}
+			(OAuth2GrantType::Password, _) => vec![
+				("grant_type", String::from("password")),
+				("username", self.username.clone()),
+				("password", self.password.clone()),
+			],
+			_ => vec![("grant_type", String::from("client_credentials"))],
+		};
+
+		// The scope of the authorization code grant is sent to the authorization endpoint
+		if self.grant_type != OAuth2GrantType::AuthorizationCode && !self.scope.is_empty() {
+			params.push(("scope", self.scope.clone()));
+		}
+
+		self.push_client_id(&mut params);
+		params
+	}
+
+	/// Form parameters to refresh a token
+	pub fn refresh_params(&self, refresh_token: &str) -> Vec<(&'static str, String)> {
+		let mut params = vec![
+			("grant_type", String::from("refresh_token")),
+			("refresh_token", refresh_token.to_string()),
+		];
+
+		if !self.scope.is_empty() {
+			params.push(("scope", self.scope.clone()));
+		}
+
+		self.push_client_id(&mut params);
+		params
+	}
+
+	/// Public clients identify themselves in the form, confidential ones with HTTP Basic auth
+	fn push_client_id(&self, params: &mut Vec<(&'static str, String)>) {
+		if self.client_secret.is_empty() && !self.client_id.is_empty() {
+			params.push(("client_id", self.client_id.clone()));
+		}
+	}
+
+	pub fn authorization_url(
+		&self,
+		redirect_uri: &str,
+		state: &str,
+		code_challenge: Option<&str>,
+	) -> Result<Url, OAuth2Error> {
+		let mut url =
+			Url::parse(&self.auth_url).map_err(|_| OAuth2Error::InvalidUrl("authorization"))?;
+
+		{
+			let mut query = url.query_pairs_mut();
+			query
+				.append_pair("response_type", "code")
+				.append_pair("client_id", &self.client_id)
+				.append_pair("redirect_uri", redirect_uri)
+				.append_pair("state", state);
+
+			if !self.scope.is_empty() {
+				query.append_pair("scope", &self.scope);
+			}
+
+			if let Some(code_challenge) = code_challenge {
+				query
+					.append_pair("code_challenge", code_challenge)
+					.append_pair("code_challenge_method", "S256");
+			}
+		}
+
+		Ok(url)
+	}
+}
+
+/// S256 PKCE code challenge of a code verifier
+pub fn pkce_challenge(verifier: &str) -> String {
+	URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()))
+}
+
+/// Parse a token endpoint response, `previous_refresh_token` is kept when the server does not
+/// rotate it
+pub fn parse_token_response(
+	body: &str,
+	previous_refresh_token: Option<String>,
+	now: DateTime<Utc>,
+) -> Result<OAuth2Token, OAuth2Error> {
+	let json: Value = serde_json::from_str(body).map_err(|_| OAuth2Error::InvalidTokenResponse)?;
+
+	if let Some(error) = json.get("error").and_then(Value::as_str) {
+		let error = match json.get("error_description").and_then(Value::as_str) {
+			Some(description) => format!("{error}: {description}"),
+			None => error.to_string(),
+		};
+
+		return Err(OAuth2Error::TokenEndpoint(error));
+	}
+
+	let access_token = json
+		.get("access_token")
+		.and_then(Value::as_str)
+		.ok_or(OAuth2Error::InvalidTokenResponse)?
+		.to_string();
+
+	// Some servers send expires_in as a string
+	let expires_in = match json.get("expires_in") {
+		Some(Value::Number(number)) => number.as_i64(),
+		Some(Value::String(string)) => string.parse::<i64>().ok(),
+		_ => None,
+	};
+
+	Ok(OAuth2Token {
+		access_token,
+		token_type: json
+			.get("token_type")
+			.and_then(Value::as_str)
+			.unwrap_or("Bearer")
+			.to_string(),
+		refresh_token: json
+			.get("refresh_token")
+			.and_then(Value::as_str)
+			.map(String::from)
+			.or(previous_refresh_token),
+		expires_at: expires_in.map(|expires_in| now + TimeDelta::seconds(expires_in)),
+	})
+}
+
+/// Extract the authorization code from the request line of a redirect, `None` when the request
+/// is not the redirect (e.g. a favicon request)
+pub fn parse_authorization_redirect(
+	request_line: &str,
+	expected_state: &str,
+) -> Result<Option<String>, OAuth2Error> {
+	let Some(target) = request_line.split_whitespace().nth(1) else {
+		return Ok(None);
+	};
+
+	let Ok(url) = Url::parse(&format!("http://localhost{target}")) else {
+		return Ok(None);
+	};
+
+	if url.path() != "/callback" {
+		return Ok(None);
+	}
+
+	let query = |key: &str| {
+		url.query_pairs()
+			.find(|(name, _)| name == key)
+			.map(|(_, value)| value.to_string())
+	};
+
+	if let Some(error) = query("error") {
+		return Err(OAuth2Error::AuthorizationDenied(
+			match query("error_description") {
+				Some(description) => format!("{error}: {description}"),
+				None => error,
+			},
+		));
+	}
+
+	if query("state").as_deref() != Some(expected_state) {
+		return Err(OAuth2Error::StateMismatch);
+	}
+
+	query("code")
+		.map(Some)
+		.ok_or(OAuth2Error::AuthorizationDenied(String::from("no code")))
+}
+
+pub fn next_oauth2_grant_type(grant_type: &OAuth2GrantType) -> OAuth2GrantType {
+	match grant_type {
+		OAuth2GrantType::ClientCredentials => OAuth2GrantType::Password,
+		OAuth2GrantType::Password => OAuth2GrantType::AuthorizationCode,
+		OAuth2GrantType::AuthorizationCode => OAuth2GrantType::RefreshToken,
+		OAuth2GrantType::RefreshToken => OAuth2GrantType::ClientCredentials,
+	}
+}
+
+pub fn previous_oauth2_grant_type(grant_type: &OAuth2GrantType) -> OAuth2GrantType {
+	match grant_type {
+		OAuth2GrantType::ClientCredentials => OAuth2GrantType::RefreshToken,
+		OAuth2GrantType::Password => OAuth2GrantType::ClientCredentials,
+		OAuth2GrantType::AuthorizationCode => OAuth2GrantType::Password,
+		OAuth2GrantType::RefreshToken => OAuth2GrantType::AuthorizationCode,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn now() -> DateTime<Utc> {
+		DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+	}
+
+	#[test]
+	fn parse_token_response_with_expiry() {
+		let token = parse_token_response(
+			r#"{"access_token": "abc", "token_type": "bearer", "expires_in": 3600, "refresh_token": "r1"}"#,
+			None,
+			now(),
+		)
+		.unwrap();
+
+		assert_eq!(token.access_token, "abc");
+		assert_eq!(token.refresh_token.as_deref(), Some("r1"));
+		assert_eq!(token.expires_at, Some(now() + TimeDelta::hours(1)));
+		assert_eq!(token.authorization_header(), "Bearer abc");
+	}
+
+	#[test]
+	fn parse_token_response_keeps_previous_refresh_token() {
+		let token = parse_token_response(
+			r#"{"access_token": "abc", "expires_in": "60"}"#,
+			Some(String::from("r1")),
+			now(),
+		)
+		.unwrap();
+
+		assert_eq!(token.refresh_token.as_deref(), Some("r1"));
+		assert_eq!(token.expires_at, Some(now() + TimeDelta::minutes(1)));
+	}
+
+	#[test]
+	fn parse_token_error_response() {
+		let error = parse_token_response(
+			r#"{"error": "invalid_client", "error_description": "Unknown client"}"#,
+			None,
+			now(),
+		)
+		.unwrap_err();
+
+		assert_eq!(
+			error.to_string(),
+			"Token endpoint replied \"invalid_client: Unknown client\""
+		);
+	}
+
+	#[test]
+	fn token_expires_before_its_expiry() {
+		let token = OAuth2Token {
+			access_token: String::from("abc"),
+			expires_at: Some(now() + TimeDelta::seconds(10)),
+			..Default::default()
+		};
+
+		assert!(token.is_expired(now()));
+		assert!(!token.is_expired(now() - TimeDelta::minutes(5)));
+		assert!(
+			!OAuth2Token::default().is_expired(now()),
+			"tokens without expiry never expire"
+		);
+	}
+
+	#[test]
+	fn token_cache_by_configuration() {
+		let oauth2 = OAuth2 {
+			token_url: String::from("https://auth.example.com/token"),
+			client_id: String::from("app"),
+			scope: String::from("read"),
+			..Default::default()
+		};
+		let other_scope = OAuth2 {
+			scope: String::from("write"),
+			..oauth2.clone()
+		};
+		let token = |access_token: &str| OAuth2Token {
+			access_token: String::from(access_token),
+			..Default::default()
+		};
+
+		let mut cache = OAuth2TokenCache::default();
+		cache.insert(&oauth2, token("t1"));
+		cache.insert(&oauth2, token("t2"));
+
+		assert_eq!(cache.get(&oauth2), Some(&token("t2")));
+		assert_eq!(cache.get(&other_scope), None);
+
+		let json = serde_json::to_string(&cache).unwrap();
+		assert_eq!(
+			json,
+			r#"[{"grant_type":"client_credentials","token_url":"https://auth.example.com/token","client_id":"app","username":"","scope":"read","token":{"access_token":"t2","token_type":""}}]"#
+		);
+
+		assert!(!cache.remove(&other_scope));
+		assert!(cache.remove(&oauth2));
+		assert!(cache.is_empty());
+	}
+
+	#[test]
+	fn token_is_not_saved_in_collections() {
+		let oauth2 = OAuth2 {
+			token: Some(OAuth2Token::default()),
+			..Default::default()
+		};
+
+		let json = serde_json::to_value(&oauth2).unwrap();
+
+		assert!(json.get("token").is_none());
+	}
+
+	#[test]
+	fn pkce_challenge_matches_rfc_example() {
+		// RFC 7636, appendix B
+		assert_eq!(
+			pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+			"E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+		);
+	}
+
+	#[test]
+	fn grant_params_by_grant_type() {
+		let mut oauth2 = OAuth2 {
+			client_id: String::from("app"),
+			scope: String::from("read write"),
+			username: String::from("alice"),
+			password: String::from("secret"),
+			..Default::default()
+		};
+
+		assert_eq!(
+			oauth2.grant_params(None),
+			vec![
+				("grant_type", String::from("client_credentials")),
+				("scope", String::from("read write")),
+				("client_id", String::from("app")),
+			]
+		);
+
+		oauth2.grant_type = OAuth2GrantType::Password;
+		oauth2.client_secret = String::from("s3cr3t");
+		assert_eq!(
+			oauth2.grant_params(None),
+			vec![
+				("grant_type", String::from("password")),
+				("username", String::from("alice")),
+				("password", String::from("secret")),
+				("scope", String::from("read write")),
+			]
+		);
+
+		oauth2.grant_type = OAuth2GrantType::AuthorizationCode;
+		assert_eq!(
+			oauth2.grant_params(Some(&AuthorizationCode {
+				code: String::from("c0de"),
+				redirect_uri: String::from("http://127.0.0.1:8000/callback"),
+				code_verifier: Some(String::from("v")),
+			})),
+			vec![
+				("grant_type", String::from("authorization_code")),
+				("code", String::from("c0de")),
+				(
+					"redirect_uri",
+					String::from("http://127.0.0.1:8000/callback")
+				),
+				("code_verifier", String::from("v")),
+			]
+		);
+	}
+
+	#[test]
+	fn authorization_url_with_pkce() {
+		let oauth2 = OAuth2 {
+			auth_url: String::from("https://auth.example.com/authorize?audience=api"),
+			client_id: String::from("app"),
+			scope: String::from("read"),
+			..Default::default()
+		};
+
+		let url = oauth2
+			.authorization_url("http://127.0.0.1:8000/callback", "st4te", Some("ch4llenge"))
+			.unwrap();
+
+		assert_eq!(
+			url.as_str(),
+			"https://auth.example.com/authorize?audience=api&response_type=code&client_id=app&redirect_uri=http%3A%2F%2F127.0.0.1%3A8000%2Fcallback&state=st4te&scope=read&code_challenge=ch4llenge&code_challenge_method=S256"
+		);
+	}
+
+	#[test]
+	fn parse_redirects() {
+		assert_eq!(
+			parse_authorization_redirect("GET /callback?code=abc&state=s1 HTTP/1.1", "s1").unwrap(),
+			Some(String::from("abc"))
+		);
+		assert_eq!(
+			parse_authorization_redirect("GET /favicon.ico HTTP/1.1", "s1").unwrap(),
+			None
+		);
+		assert!(matches!(
+			parse_authorization_redirect("GET /callback?code=abc&state=s2 HTTP/1.1", "s1"),
+			Err(OAuth2Error::StateMismatch)
+		));
+		assert!(matches!(
+			parse_authorization_redirect(
+				"GET /callback?error=access_denied&state=s1 HTTP/1.1",
+				"s1"
+			),
+			Err(OAuth2Error::AuthorizationDenied(_))
+		));
+	}
+}
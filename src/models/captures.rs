@@ -0,0 +1,194 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Extracts a value from a response into an environment key.
+///
+/// Captures are stored in collection files and can be written in a short
+/// textual form (see [`Capture::from_str`]), which is used by the CLI and
+/// the `.http` file format (`# @capture <capture>`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Capture {
+	/// Environment key receiving the captured value
+	pub key: String,
+	pub source: CaptureSource,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureSource {
+	/// `TOKEN = jsonpath $.data.token`
+	JsonPath(String),
+	/// `REQUEST_ID = header x-request-id`
+	Header(String),
+	/// `CSRF = regex name="csrf" value="([^"]+)"`, first group or whole match of the body
+	Regex(String),
+	/// `SESSION = cookie session_id`
+	Cookie(String),
+}
+
+/// Result of the evaluation of a single [`Capture`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaptureResult {
+	pub key: String,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub value: Option<String>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub error: Option<String>,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ParseCaptureError {
+	#[error("Invalid capture \"{0}\", expected \"<KEY> = <source> <expression>\"")]
+	MissingKey(String),
+	#[error("Unknown capture source \"{0}\", expected one of: jsonpath, header, regex, cookie")]
+	UnknownSource(String),
+	#[error("Invalid capture \"{0}\"\n\t{1}")]
+	InvalidCapture(String, String),
+}
+
+impl Display for Capture {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{} = {}", self.key, self.source)
+	}
+}
+
+impl Display for CaptureSource {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			CaptureSource::JsonPath(path) => write!(f, "jsonpath {path}"),
+			CaptureSource::Header(name) => write!(f, "header {name}"),
+			CaptureSource::Regex(pattern) => write!(f, "regex {pattern}"),
+			CaptureSource::Cookie(name) => write!(f, "cookie {name}"),
+		}
+	}
+}
+
+impl FromStr for Capture {
+	type Err = ParseCaptureError;
+
+	fn from_str(input: &str) -> Result<Self, Self::Err> {
+		let input = input.trim();
+		let invalid = |message: &str| {
+			ParseCaptureError::InvalidCapture(input.to_string(), message.to_string())
+		};
+
+		let (key, source) = input
+			.split_once('=')
+			.ok_or_else(|| ParseCaptureError::MissingKey(input.to_string()))?;
+		let key = key.trim();
+
+		if key.is_empty() || key.contains(char::is_whitespace) {
+			return Err(invalid("the key must be a single word"));
+		}
+
+		let source = source.trim();
+		let (kind, expression) = source
+			.split_once(char::is_whitespace)
+			.unwrap_or((source, ""));
+		let expression = expression.trim();
+
+		if expression.is_empty() {
+			return Err(invalid("missing capture expression"));
+		}
+
+		let source = match kind.to_lowercase().as_str() {
+			"jsonpath" => CaptureSource::JsonPath(expression.to_string()),
+			"header" => CaptureSource::Header(expression.to_string()),
+			"regex" => {
+				if let Err(error) = regex::Regex::new(expression) {
+					return Err(invalid(&error.to_string()));
+				}
+
+				CaptureSource::Regex(expression.to_string())
+			}
+			"cookie" => CaptureSource::Cookie(expression.to_string()),
+			_ => return Err(ParseCaptureError::UnknownSource(kind.to_string())),
+		};
+
+		Ok(Capture {
+			key: key.to_string(),
+			source,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_captures() {
+		assert_eq!(
+			Capture::from_str("TOKEN = jsonpath $.data.token").unwrap(),
+			Capture {
+				key: String::from("TOKEN"),
+				source: CaptureSource::JsonPath(String::from("$.data.token")),
+			}
+		);
+		assert_eq!(
+			Capture::from_str("ID=header x-request-id").unwrap(),
+			Capture {
+				key: String::from("ID"),
+				source: CaptureSource::Header(String::from("x-request-id")),
+			}
+		);
+		assert_eq!(
+			Capture::from_str(r#"CSRF = regex value="([^"]+)""#).unwrap(),
+			Capture {
+				key: String::from("CSRF"),
+				source: CaptureSource::Regex(String::from(r#"value="([^"]+)""#)),
+			}
+		);
+		assert_eq!(
+			Capture::from_str("SESSION = cookie session_id").unwrap(),
+			Capture {
+				key: String::from("SESSION"),
+				source: CaptureSource::Cookie(String::from("session_id")),
+			}
+		);
+	}
+
+	#[test]
+	fn parse_invalid_captures() {
+		assert!(matches!(
+			Capture::from_str("jsonpath $.token"),
+			Err(ParseCaptureError::MissingKey(_))
+		));
+		assert!(matches!(
+			Capture::from_str("TOKEN = body $.token"),
+			Err(ParseCaptureError::UnknownSource(_))
+		));
+		assert!(Capture::from_str("TOKEN = header").is_err());
+		assert!(Capture::from_str("MY TOKEN = header x-id").is_err());
+		assert!(Capture::from_str("TOKEN = regex (").is_err());
+	}
+
+	#[test]
+	fn display_roundtrips_through_from_str() {
+		let captures = vec![
+			"TOKEN = jsonpath $.items[0].token",
+			"ID = header x-request-id",
+			"CSRF = regex token=(\\w+)",
+			"SESSION = cookie session_id",
+		];
+
+		for capture in captures {
+			let parsed = Capture::from_str(capture).unwrap();
+			assert_eq!(parsed.to_string(), capture);
+		}
+	}
+
+	#[test]
+	fn serde_roundtrip() {
+		let capture = Capture::from_str("TOKEN = jsonpath $.token").unwrap();
+
+		let json = serde_json::to_string(&capture).unwrap();
+		assert_eq!(json, r#"{"key":"TOKEN","source":{"json_path":"$.token"}}"#);
+
+		let from_json: Capture = serde_json::from_str(&json).unwrap();
+		assert_eq!(from_json, capture);
+	}
+}
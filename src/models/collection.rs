@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use indexmap::IndexMap;
 use parking_lot::RwLock;
 use ratatui::style::Stylize;
 use ratatui::text::{Line, Span};
@@ -10,9 +11,13 @@ use strum::Display;
 use tui_tree_widget::TreeItem;
 
 use crate::app::files::theme::THEME;
+use crate::models::auth::auth::Auth;
 use crate::models::environment::Environment;
 use crate::models::folder::Folder;
-use crate::models::request::Request;
+use crate::models::inheritance::SharedProperties;
+use crate::models::request::{KeyValue, Request};
+use crate::models::scripts::RequestScripts;
+use crate::models::settings::TlsSettings;
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Collection {
@@ -33,6 +38,28 @@ pub struct Collection {
 
 	#[serde(default, skip_serializing_if = "Vec::is_empty")]
 	pub environments: Vec<Environment>,
+
+	/// Variables of every request, taking precedence over the environments, e.g. `.http` file
+	/// variables. Their values can reference environment keys and previous variables.
+	#[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+	pub variables: IndexMap<String, String>,
+
+	/// Client certificate and CA certificates of every request, see [`TlsSettings::or_collection`]
+	#[serde(default, skip_serializing_if = "TlsSettings::is_empty")]
+	pub tls: TlsSettings,
+
+	/// Auth of the requests using the "inherit" auth, unless their folder has one
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub auth: Option<Auth>,
+
+	/// Headers sent with every request of the collection, unless the request or its folder
+	/// redefines them
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub headers: Vec<KeyValue>,
+
+	/// Scripts run before the ones of every request of the collection
+	#[serde(default, skip_serializing_if = "RequestScripts::is_empty")]
+	pub scripts: RequestScripts,
 }
 
 #[derive(Debug, Default, Copy, Clone, Display, Serialize, Deserialize)]
@@ -50,9 +77,23 @@ pub enum CollectionFileFormat {
 }
 
 impl Collection {
+	pub fn shared_properties(&self) -> SharedProperties {
+		SharedProperties {
+			auth: self.auth.clone(),
+			headers: self.headers.clone(),
+			scripts: self.scripts.clone(),
+		}
+	}
+
+	pub fn set_shared_properties(&mut self, shared_properties: SharedProperties) {
+		self.auth = shared_properties.auth;
+		self.headers = shared_properties.headers;
+		self.scripts = shared_properties.scripts;
+	}
+
 	/// Returns the total number of requests across folders and root-level requests
 	pub fn total_request_count(&self) -> usize {
-		let folder_requests: usize = self.folders.iter().map(|f| f.requests.len()).sum();
+		let folder_requests: usize = self.folders.iter().map(Folder::total_request_count).sum();
 		folder_requests + self.requests.len()
 	}
 
@@ -61,6 +102,119 @@ impl Collection {
 		self.folders.len() + self.requests.len()
 	}
 
+	/// Folder at the given path of folder indexes, starting from the collection folders
+	pub fn folder(&self, folder_path: &[usize]) -> Option<&Folder> {
+		let (first, rest) = folder_path.split_first()?;
+		let mut folder = self.folders.get(*first)?;
+
+		for folder_index in rest {
+			folder = folder.folders.get(*folder_index)?;
+		}
+
+		Some(folder)
+	}
+
+	pub fn folder_mut(&mut self, folder_path: &[usize]) -> Option<&mut Folder> {
+		let (first, rest) = folder_path.split_first()?;
+		let mut folder = self.folders.get_mut(*first)?;
+
+		for folder_index in rest {
+			folder = folder.folders.get_mut(*folder_index)?;
+		}
+
+		Some(folder)
+	}
+
+	/// Sub-folders of a folder, or of the collection root when the path is empty
+	pub fn folders_in(&self, folder_path: &[usize]) -> &Vec<Folder> {
+		match folder_path.is_empty() {
+			true => &self.folders,
+			false => {
+				&self
+					.folder(folder_path)
+					.expect("folder path should be valid")
+					.folders
+			}
+		}
+	}
+
+	pub fn folders_in_mut(&mut self, folder_path: &[usize]) -> &mut Vec<Folder> {
+		match folder_path.is_empty() {
+			true => &mut self.folders,
+			false => {
+				&mut self
+					.folder_mut(folder_path)
+					.expect("folder path should be valid")
+					.folders
+			}
+		}
+	}
+
+	/// Requests of a folder, or root-level requests when the path is empty
+	pub fn requests_in(&self, folder_path: &[usize]) -> &Vec<Arc<RwLock<Request>>> {
+		match folder_path.is_empty() {
+			true => &self.requests,
+			false => {
+				&self
+					.folder(folder_path)
+					.expect("folder path should be valid")
+					.requests
+			}
+		}
+	}
+
+	pub fn requests_in_mut(&mut self, folder_path: &[usize]) -> &mut Vec<Arc<RwLock<Request>>> {
+		match folder_path.is_empty() {
+			true => &mut self.requests,
+			false => {
+				&mut self
+					.folder_mut(folder_path)
+					.expect("folder path should be valid")
+					.requests
+			}
+		}
+	}
+
+	/// Path of every folder, depth first, parents before their sub-folders
+	pub fn folder_paths(&self) -> Vec<Vec<usize>> {
+		fn collect(folders: &[Folder], parent_path: &[usize], folder_paths: &mut Vec<Vec<usize>>) {
+			for (folder_index, folder) in folders.iter().enumerate() {
+				let mut folder_path = parent_path.to_vec();
+				folder_path.push(folder_index);
+				folder_paths.push(folder_path.clone());
+				collect(&folder.folders, &folder_path, folder_paths);
+			}
+		}
+
+		let mut folder_paths = Vec::new();
+		collect(&self.folders, &[], &mut folder_paths);
+		folder_paths
+	}
+
+	/// Names of the folders along a path, joined with "/"
+	pub fn folder_path_name(&self, folder_path: &[usize]) -> String {
+		(1..=folder_path.len())
+			.filter_map(|depth| self.folder(&folder_path[..depth]))
+			.map(|folder| folder.name.as_str())
+			.collect::<Vec<&str>>()
+			.join("/")
+	}
+
+	/// Every request of the collection along with the path of the folder holding it, depth first
+	pub fn requests_with_folder_path(&self) -> Vec<(Vec<usize>, Arc<RwLock<Request>>)> {
+		let mut requests = Vec::new();
+
+		for (folder_index, folder) in self.folders.iter().enumerate() {
+			folder.collect_requests(vec![folder_index], &mut requests);
+		}
+
+		for request in &self.requests {
+			requests.push((vec![], request.clone()));
+		}
+
+		requests
+	}
+
 	pub fn to_tree_item<'a>(&self, identifier: usize) -> TreeItem<'a, usize> {
 		let name = self.name.clone();
 
@@ -69,54 +223,168 @@ impl Collection {
 			Span::from(format!(" ({})", self.total_request_count())),
 		]);
 
-		// Build children: folders first, then root-level requests
-		let mut items: Vec<TreeItem<usize>> = Vec::new();
-
-		// Add folders as children
-		let folder_items: Vec<TreeItem<usize>> = self
-			.folders
-			.par_iter()
-			.enumerate()
-			.map(|(folder_index, folder)| folder.to_tree_item(folder_index))
-			.collect();
-		items.extend(folder_items);
-
-		// Add root-level requests as children (identifiers offset by folder count)
-		let folder_count = self.folders.len();
-		let requests_len = self.requests.len();
-		let is_last_folder = self.folders.is_empty();
-		let request_items: Vec<TreeItem<usize>> = self
-			.requests
-			.par_iter()
-			.enumerate()
-			.map(|(request_index, request)| {
-				let is_last = is_last_folder && request_index == requests_len - 1;
-				request
-					.read()
-					.to_tree_item(folder_count + request_index, is_last)
-			})
-			.collect();
-		items.extend(request_items);
+		let items = children_tree_items(&self.folders, &self.requests);
 
 		TreeItem::new(identifier, line, items).expect("tree item creation should succeed")
 	}
 
-	/// Resolves a child index within this collection to either a folder or a root request.
-	/// Returns `ChildRef::Folder(folder_index)` or `ChildRef::RootRequest(request_index)`.
-	pub fn resolve_child(&self, child_index: usize) -> ChildRef {
-		if child_index < self.folders.len() {
-			ChildRef::Folder(child_index)
+	/// Resolves a tree path below the collection (the collection index excluded) to either a
+	/// folder or a request. At every level, folders come first and requests are offset by the
+	/// folder count.
+	pub fn resolve_tree_path(&self, tree_path: &[usize]) -> Option<TreeElement> {
+		let (last, parents) = tree_path.split_last()?;
+
+		// Every element but the last one is a folder, whose tree identifier is its index
+		let folders = match parents.is_empty() {
+			true => &self.folders,
+			false => &self.folder(parents)?.folders,
+		};
+
+		if *last < folders.len() {
+			Some(TreeElement::Folder(tree_path.to_vec()))
 		} else {
-			ChildRef::RootRequest(child_index - self.folders.len())
+			let request_index = last - folders.len();
+
+			match request_index < self.requests_in(parents).len() {
+				true => Some(TreeElement::Request(parents.to_vec(), request_index)),
+				false => None,
+			}
 		}
 	}
 }
 
-/// Represents what a child index within a collection points to.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ChildRef {
-	/// A folder at the given index in `collection.folders`
-	Folder(usize),
-	/// A root-level request at the given index in `collection.requests`
-	RootRequest(usize),
+/// Tree items of the children of a collection or of a folder: folders first, then requests
+/// whose identifiers are offset by the folder count
+pub fn children_tree_items<'a>(
+	folders: &[Folder],
+	requests: &[Arc<RwLock<Request>>],
+) -> Vec<TreeItem<'a, usize>> {
+	let mut items: Vec<TreeItem<usize>> = folders
+		.par_iter()
+		.enumerate()
+		.map(|(folder_index, folder)| folder.to_tree_item(folder_index))
+		.collect();
+
+	let folder_count = folders.len();
+	let requests_len = requests.len();
+	let request_items: Vec<TreeItem<usize>> = requests
+		.par_iter()
+		.enumerate()
+		.map(|(request_index, request)| {
+			request.read().to_tree_item(
+				folder_count + request_index,
+				request_index == requests_len - 1,
+			)
+		})
+		.collect();
+	items.extend(request_items);
+
+	items
+}
+
+/// Represents what a tree path within a collection points to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeElement {
+	/// A folder, identified by the path of folder indexes leading to it
+	Folder(Vec<usize>),
+	/// A request at the given index in the requests of a folder, or of the collection root when
+	/// the folder path is empty
+	Request(Vec<usize>, usize),
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn request(name: &str) -> Arc<RwLock<Request>> {
+		Arc::new(RwLock::new(Request {
+			name: name.to_string(),
+			..Default::default()
+		}))
+	}
+
+	/// admin/ (users/ (list-users), admin-request), root-request
+	fn nested_collection() -> Collection {
+		Collection {
+			name: String::from("my-api"),
+			folders: vec![Folder {
+				name: String::from("admin"),
+				folders: vec![Folder {
+					name: String::from("users"),
+					requests: vec![request("list-users")],
+					..Default::default()
+				}],
+				requests: vec![request("admin-request")],
+				..Default::default()
+			}],
+			requests: vec![request("root-request")],
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn test_resolve_tree_path() {
+		let collection = nested_collection();
+
+		assert_eq!(
+			collection.resolve_tree_path(&[0]),
+			Some(TreeElement::Folder(vec![0]))
+		);
+		assert_eq!(
+			collection.resolve_tree_path(&[0, 0]),
+			Some(TreeElement::Folder(vec![0, 0]))
+		);
+		// Requests come after the folders of their level
+		assert_eq!(
+			collection.resolve_tree_path(&[0, 0, 0]),
+			Some(TreeElement::Request(vec![0, 0], 0))
+		);
+		assert_eq!(
+			collection.resolve_tree_path(&[0, 1]),
+			Some(TreeElement::Request(vec![0], 0))
+		);
+		assert_eq!(
+			collection.resolve_tree_path(&[1]),
+			Some(TreeElement::Request(vec![], 0))
+		);
+		assert_eq!(collection.resolve_tree_path(&[2]), None);
+		assert_eq!(collection.resolve_tree_path(&[]), None);
+	}
+
+	#[test]
+	fn test_folder_paths_and_names() {
+		let collection = nested_collection();
+
+		assert_eq!(collection.folder_paths(), vec![vec![0], vec![0, 0]]);
+		assert_eq!(collection.folder_path_name(&[0, 0]), "admin/users");
+		assert_eq!(collection.total_request_count(), 3);
+	}
+
+	#[test]
+	fn test_requests_with_folder_path_follow_the_tree_order() {
+		let names: Vec<(Vec<usize>, String)> = nested_collection()
+			.requests_with_folder_path()
+			.into_iter()
+			.map(|(folder_path, request)| (folder_path, request.read().name.clone()))
+			.collect();
+
+		assert_eq!(
+			names,
+			vec![
+				(vec![0, 0], String::from("list-users")),
+				(vec![0], String::from("admin-request")),
+				(vec![], String::from("root-request")),
+			]
+		);
+	}
+
+	#[test]
+	fn test_nested_folders_round_trip() {
+		let json = serde_json::to_string(&nested_collection()).unwrap();
+		let collection: Collection = serde_json::from_str(&json).unwrap();
+
+		assert_eq!(collection.folders[0].folders[0].name, "users");
+		// Folders without sub-folders do not serialize them
+		assert!(!json.contains(r#""folders":[]"#));
+	}
 }
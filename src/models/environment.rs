@@ -1,20 +1,42 @@
 use std::path::PathBuf;
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use serde::{Deserialize, Serialize};
 
+/// Displayed in place of the secret values
+pub const SECRET_MASK: &str = "********";
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Environment {
 	pub name: String,
 	pub values: IndexMap<String, String>,
 	#[serde(default, skip_serializing)]
 	pub path: PathBuf,
+	/// Keys whose values are kept in the encrypted secrets store instead of the environment file
+	#[serde(skip)]
+	pub secret_keys: IndexSet<String>,
+	/// Environment whose values are inherited, declared by a `# @extends <name>` line
+	#[serde(skip)]
+	pub parent: Option<String>,
+}
+
+impl Environment {
+	pub fn is_secret(&self, key: &str) -> bool {
+		self.secret_keys.contains(key)
+	}
+
+	/// Value of a key as it can be displayed, secret values being masked
+	pub fn displayed_value<'a>(&self, key: &str, value: &'a str) -> &'a str {
+		match self.is_secret(key) {
+			true => SECRET_MASK,
+			false => value,
+		}
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use indexmap::IndexMap;
 	use std::path::PathBuf;
 
 	#[test]
@@ -56,6 +78,8 @@ mod tests {
 			name: "test".to_string(),
 			values: IndexMap::new(),
 			path: test_env_path(),
+			secret_keys: IndexSet::new(),
+			parent: None,
 		};
 		env.values
 			.insert("API_KEY".to_string(), "secret".to_string());
@@ -89,6 +113,8 @@ mod tests {
 			name: "original".to_string(),
 			values: IndexMap::new(),
 			path: test_env_path(),
+			secret_keys: IndexSet::new(),
+			parent: None,
 		};
 		env.values.insert("key".to_string(), "value".to_string());
 
@@ -103,4 +129,13 @@ mod tests {
 		assert_eq!(cloned.name, "cloned");
 		assert_eq!(cloned.values.len(), 2);
 	}
+
+	#[test]
+	fn secret_values_are_masked_when_displayed() {
+		let mut env = Environment::default();
+		env.secret_keys.insert("TOKEN".to_string());
+
+		assert_eq!(env.displayed_value("TOKEN", "abc"), SECRET_MASK);
+		assert_eq!(env.displayed_value("HOST", "localhost"), "localhost");
+	}
 }
@@ -3,40 +3,114 @@ use std::sync::Arc;
 use parking_lot::RwLock;
 use ratatui::style::Stylize;
 use ratatui::text::{Line, Span};
-use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use tui_tree_widget::TreeItem;
 
 use crate::app::files::theme::THEME;
-use crate::models::request::Request;
+use crate::models::auth::auth::Auth;
+use crate::models::collection::children_tree_items;
+use crate::models::inheritance::SharedProperties;
+use crate::models::request::{KeyValue, Request};
+use crate::models::scripts::RequestScripts;
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Folder {
 	pub name: String,
+
+	/// Sub-folders, displayed before the requests
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub folders: Vec<Folder>,
+
 	pub requests: Vec<Arc<RwLock<Request>>>,
+
+	/// Auth of the requests using the "inherit" auth, takes precedence over the collection one
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub auth: Option<Auth>,
+
+	/// Headers sent with every request of the folder, unless the request redefines them
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub headers: Vec<KeyValue>,
+
+	/// Scripts run before the ones of every request of the folder, after the collection ones
+	#[serde(default, skip_serializing_if = "RequestScripts::is_empty")]
+	pub scripts: RequestScripts,
 }
 
 impl Folder {
+	pub fn shared_properties(&self) -> SharedProperties {
+		SharedProperties {
+			auth: self.auth.clone(),
+			headers: self.headers.clone(),
+			scripts: self.scripts.clone(),
+		}
+	}
+
+	pub fn set_shared_properties(&mut self, shared_properties: SharedProperties) {
+		self.auth = shared_properties.auth;
+		self.headers = shared_properties.headers;
+		self.scripts = shared_properties.scripts;
+	}
+
+	/// Number of requests of the folder and of its sub-folders
+	pub fn total_request_count(&self) -> usize {
+		let folder_requests: usize = self.folders.iter().map(Folder::total_request_count).sum();
+		folder_requests + self.requests.len()
+	}
+
+	pub(crate) fn collect_requests(
+		&self,
+		folder_path: Vec<usize>,
+		requests: &mut Vec<(Vec<usize>, Arc<RwLock<Request>>)>,
+	) {
+		for (folder_index, folder) in self.folders.iter().enumerate() {
+			let mut sub_folder_path = folder_path.clone();
+			sub_folder_path.push(folder_index);
+			folder.collect_requests(sub_folder_path, requests);
+		}
+
+		for request in &self.requests {
+			requests.push((folder_path.clone(), request.clone()));
+		}
+	}
+
 	pub fn to_tree_item<'a>(&self, identifier: usize) -> TreeItem<'a, usize> {
 		let name = self.name.clone();
 
 		let line = Line::from(vec![
 			Span::raw("📁 "),
 			Span::raw(name).fg(THEME.read().ui.font_color),
-			Span::from(format!(" ({})", self.requests.len())),
+			Span::from(format!(" ({})", self.total_request_count())),
 		]);
 
-		let items: Vec<TreeItem<usize>> = self
-			.requests
-			.par_iter()
-			.enumerate()
-			.map(|(request_index, request)| {
-				request
-					.read()
-					.to_tree_item(request_index, request_index == self.requests.len() - 1)
-			})
-			.collect();
+		let items = children_tree_items(&self.folders, &self.requests);
 
 		TreeItem::new(identifier, line, items).expect("tree item creation should succeed")
 	}
 }
+
+/// Folder at the given path of folder names, the missing folders being created along the way.
+/// Returns `None` for an empty path.
+pub fn get_or_create_folder<'a>(
+	folders: &'a mut Vec<Folder>,
+	folder_names: &[String],
+) -> Option<&'a mut Folder> {
+	let (first, rest) = folder_names.split_first()?;
+
+	let folder_index = match folders.iter().position(|folder| &folder.name == first) {
+		Some(folder_index) => folder_index,
+		None => {
+			folders.push(Folder {
+				name: first.clone(),
+				..Default::default()
+			});
+			folders.len() - 1
+		}
+	};
+
+	let folder = &mut folders[folder_index];
+
+	match rest.is_empty() {
+		true => Some(folder),
+		false => get_or_create_folder(&mut folder.folders, rest),
+	}
+}
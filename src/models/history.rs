@@ -0,0 +1,324 @@
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::response::{RequestResponse, ResponseContent};
+
+/// Number of characters of the id shown in lists, any unique prefix is accepted
+pub const SHORT_ID_LENGTH: usize = 8;
+
+/// Headers carrying credentials, redacted in the history unless the config says otherwise
+pub const CREDENTIAL_HEADERS: [&str; 6] = [
+	"authorization",
+	"proxy-authorization",
+	"cookie",
+	"set-cookie",
+	"x-api-key",
+	"x-amz-security-token",
+];
+
+pub fn is_credential_header(name: &str) -> bool {
+	CREDENTIAL_HEADERS
+		.iter()
+		.any(|credential_header| name.eq_ignore_ascii_case(credential_header))
+}
+
+/// One sent request and the metadata of its response, as recorded in the history file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+	pub id: String,
+	pub timestamp: DateTime<Utc>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub collection: Option<String>,
+	pub request: String,
+	pub protocol: String,
+	pub sent: SentRequest,
+	pub response: HistoryResponse,
+}
+
+/// Request as it was sent, environment values and scripts applied
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SentRequest {
+	pub method: String,
+	pub url: String,
+	pub headers: Vec<(String, String)>,
+	/// Text body, not recorded for multipart, file and binary bodies
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub body: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryResponse {
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub status_code: Option<String>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub duration: Option<String>,
+	pub headers: Vec<(String, String)>,
+	/// Size of the whole received body, even when truncated or shown as a hexdump
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub body_size: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiffLine {
+	Same(String),
+	Removed(String),
+	Added(String),
+}
+
+impl HistoryEntry {
+	pub fn new(
+		collection: Option<String>,
+		request: String,
+		protocol: String,
+		sent: SentRequest,
+		response: &RequestResponse,
+	) -> Self {
+		// WebSocket and gRPC responses have no body size, their content is measured instead
+		let body_size = response.body_size.or_else(|| {
+			response.content.as_ref().map(|content| match content {
+				ResponseContent::Body(body) => body.len() as u64,
+				ResponseContent::Image(image) => image.data.len() as u64,
+			})
+		});
+
+		HistoryEntry {
+			id: uuid::Uuid::new_v4().simple().to_string(),
+			timestamp: Utc::now(),
+			collection,
+			request,
+			protocol,
+			sent,
+			response: HistoryResponse {
+				status_code: response.status_code.clone(),
+				duration: response.duration.clone(),
+				headers: response.headers.clone(),
+				body_size,
+			},
+		}
+	}
+
+	pub fn short_id(&self) -> &str {
+		&self.id[..SHORT_ID_LENGTH.min(self.id.len())]
+	}
+
+	/// `collection/request`, or only the request name when sent outside a collection
+	pub fn request_path(&self) -> String {
+		match &self.collection {
+			None => self.request.clone(),
+			Some(collection) => format!("{collection}/{}", self.request),
+		}
+	}
+
+	/// Whether both entries were sent from the same request
+	pub fn is_same_request(&self, other: &HistoryEntry) -> bool {
+		self.collection == other.collection && self.request == other.request
+	}
+
+	/// One-line summary used by lists
+	pub fn summary(&self) -> String {
+		format!(
+			"{}  {}  {} {}  {}  {}",
+			self.short_id(),
+			self.local_time(),
+			self.sent.method,
+			self.sent.url,
+			self.response.status_code.as_deref().unwrap_or("-"),
+			self.response.duration.as_deref().unwrap_or("-"),
+		)
+	}
+
+	pub fn local_time(&self) -> String {
+		self.timestamp
+			.with_timezone(&Local)
+			.format("%Y-%m-%d %H:%M:%S")
+			.to_string()
+	}
+
+	/// Readable representation of the entry, also used as the base of [`HistoryEntry::diff`]
+	pub fn to_lines(&self) -> Vec<String> {
+		let mut lines = vec![
+			format!("{} {}", self.sent.method, self.sent.url),
+			format!("protocol: {}", self.protocol),
+		];
+
+		for (name, value) in &self.sent.headers {
+			lines.push(format!("> {name}: {value}"));
+		}
+
+		if let Some(body) = &self.sent.body {
+			lines.push(String::new());
+			lines.extend(body.lines().map(String::from));
+		}
+
+		lines.push(String::new());
+		lines.push(format!(
+			"status: {}",
+			self.response.status_code.as_deref().unwrap_or("-")
+		));
+		lines.push(format!(
+			"duration: {}",
+			self.response.duration.as_deref().unwrap_or("-")
+		));
+
+		if let Some(body_size) = self.response.body_size {
+			lines.push(format!("body size: {body_size} bytes"));
+		}
+
+		for (name, value) in &self.response.headers {
+			lines.push(format!("< {name}: {value}"));
+		}
+
+		lines
+	}
+
+	/// Line diff from `self` (older) to `other` (newer)
+	pub fn diff(&self, other: &HistoryEntry) -> Vec<DiffLine> {
+		diff_lines(&self.to_lines(), &other.to_lines())
+	}
+}
+
+/// Longest common subsequence line diff
+pub fn diff_lines(old: &[String], new: &[String]) -> Vec<DiffLine> {
+	let (n, m) = (old.len(), new.len());
+	let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+
+	for i in (0..n).rev() {
+		for j in (0..m).rev() {
+			lcs[i][j] = match old[i] == new[j] {
+				true => lcs[i + 1][j + 1] + 1,
+				false => lcs[i + 1][j].max(lcs[i][j + 1]),
+			};
+		}
+	}
+
+	let mut diff = vec![];
+	let (mut i, mut j) = (0, 0);
+
+	while i < n && j < m {
+		if old[i] == new[j] {
+			diff.push(DiffLine::Same(old[i].clone()));
+			i += 1;
+			j += 1;
+		} else if lcs[i + 1][j] >= lcs[i][j + 1] {
+			diff.push(DiffLine::Removed(old[i].clone()));
+			i += 1;
+		} else {
+			diff.push(DiffLine::Added(new[j].clone()));
+			j += 1;
+		}
+	}
+
+	diff.extend(old[i..].iter().cloned().map(DiffLine::Removed));
+	diff.extend(new[j..].iter().cloned().map(DiffLine::Added));
+
+	diff
+}
+
+impl DiffLine {
+	pub fn to_prefixed_string(&self) -> String {
+		match self {
+			DiffLine::Same(line) => format!("  {line}"),
+			DiffLine::Removed(line) => format!("- {line}"),
+			DiffLine::Added(line) => format!("+ {line}"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn entry(url: &str, status: &str, body: Option<&str>) -> HistoryEntry {
+		HistoryEntry::new(
+			Some(String::from("my-api")),
+			String::from("req"),
+			String::from("HTTP"),
+			SentRequest {
+				method: String::from("POST"),
+				url: url.to_string(),
+				headers: vec![(String::from("accept"), String::from("*/*"))],
+				body: body.map(String::from),
+			},
+			&RequestResponse {
+				status_code: Some(status.to_string()),
+				content: Some(ResponseContent::Body(String::from("{}"))),
+				..Default::default()
+			},
+		)
+	}
+
+	#[test]
+	fn diff_lines_keeps_common_lines() {
+		let old: Vec<String> = ["a", "b", "c"].map(String::from).to_vec();
+		let new: Vec<String> = ["a", "x", "c", "d"].map(String::from).to_vec();
+
+		assert_eq!(
+			diff_lines(&old, &new),
+			vec![
+				DiffLine::Same(String::from("a")),
+				DiffLine::Removed(String::from("b")),
+				DiffLine::Added(String::from("x")),
+				DiffLine::Same(String::from("c")),
+				DiffLine::Added(String::from("d")),
+			]
+		);
+	}
+
+	#[test]
+	fn diff_entries() {
+		let old = entry("https://example.com/a", "200 OK", Some("{\n\"id\": 1\n}"));
+		let new = entry(
+			"https://example.com/a",
+			"500 Internal Server Error",
+			Some("{\n\"id\": 2\n}"),
+		);
+
+		let changes: Vec<String> = old
+			.diff(&new)
+			.iter()
+			.filter(|line| !matches!(line, DiffLine::Same(_)))
+			.map(DiffLine::to_prefixed_string)
+			.collect();
+
+		assert_eq!(
+			changes,
+			vec![
+				"- \"id\": 1",
+				"+ \"id\": 2",
+				"- status: 200 OK",
+				"+ status: 500 Internal Server Error",
+			]
+		);
+	}
+
+	#[test]
+	fn entry_metadata() {
+		let entry = entry("https://example.com", "200 OK", None);
+
+		assert_eq!(entry.short_id().len(), SHORT_ID_LENGTH);
+		assert_eq!(entry.request_path(), "my-api/req");
+		assert_eq!(entry.response.body_size, Some(2));
+
+		let json = serde_json::to_string(&entry).unwrap();
+		let from_json: HistoryEntry = serde_json::from_str(&json).unwrap();
+		assert_eq!(from_json, entry);
+	}
+
+	#[test]
+	fn entry_body_size_of_a_truncated_body() {
+		let entry = HistoryEntry::new(
+			None,
+			String::from("req"),
+			String::from("HTTP"),
+			SentRequest::default(),
+			&RequestResponse {
+				content: Some(ResponseContent::Body(String::from("head"))),
+				body_size: Some(4096),
+				is_body_truncated: true,
+				..Default::default()
+			},
+		);
+
+		assert_eq!(entry.response.body_size, Some(4096));
+	}
+}
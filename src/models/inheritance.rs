@@ -0,0 +1,219 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::auth::auth::Auth;
+use crate::models::request::KeyValue;
+use crate::models::scripts::RequestScripts;
+
+/// Auth, headers and scripts a collection or a folder shares with its requests
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SharedProperties {
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub auth: Option<Auth>,
+
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub headers: Vec<KeyValue>,
+
+	#[serde(default, skip_serializing_if = "RequestScripts::is_empty")]
+	pub scripts: RequestScripts,
+}
+
+/// What a request inherits from its collection and folder, resolved at send time
+#[derive(Debug, Default, Clone)]
+pub struct Inheritance {
+	/// Closest auth defined by the folder or the collection
+	pub auth: Auth,
+	/// Collection headers overridden by the folder ones
+	pub headers: Vec<KeyValue>,
+	/// Collection scripts, then folder scripts
+	pub scripts: Vec<RequestScripts>,
+}
+
+impl Inheritance {
+	/// Add the properties of a level, which take precedence over the previous ones
+	pub fn push(&mut self, shared_properties: &SharedProperties) {
+		match &shared_properties.auth {
+			None | Some(Auth::Inherit) => {}
+			Some(auth) => self.auth = auth.clone(),
+		}
+
+		self.headers = merge_headers(&self.headers, &shared_properties.headers);
+
+		if !shared_properties.scripts.is_empty() {
+			self.scripts.push(shared_properties.scripts.clone());
+		}
+	}
+
+	/// The inherited auth when the request uses the "inherit" one
+	pub fn resolve_auth(&self, auth: &Auth) -> Auth {
+		match auth {
+			Auth::Inherit => self.auth.clone(),
+			auth => auth.clone(),
+		}
+	}
+
+	/// Inherited headers the request does not redefine, then the request ones
+	pub fn resolve_headers(&self, headers: &[KeyValue]) -> Vec<KeyValue> {
+		merge_headers(&self.headers, headers)
+	}
+}
+
+/// Enabled headers of `base` not redefined by an enabled header of `overrides`, then `overrides`.
+/// Header names are case-insensitive.
+fn merge_headers(base: &[KeyValue], overrides: &[KeyValue]) -> Vec<KeyValue> {
+	let is_overridden = |header: &KeyValue| {
+		overrides.iter().any(|override_header| {
+			override_header.enabled && override_header.data.0.eq_ignore_ascii_case(&header.data.0)
+		})
+	};
+
+	base.iter()
+		.filter(|header| header.enabled && !is_overridden(header))
+		.chain(overrides)
+		.cloned()
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::models::auth::bearer_token::BearerToken;
+
+	fn header(key: &str, value: &str, enabled: bool) -> KeyValue {
+		KeyValue {
+			enabled,
+			data: (key.to_string(), value.to_string()),
+		}
+	}
+
+	fn bearer(token: &str) -> Auth {
+		Auth::BearerToken(BearerToken {
+			token: token.to_string(),
+		})
+	}
+
+	#[test]
+	fn test_closest_auth_wins() {
+		let mut inheritance = Inheritance::default();
+		inheritance.push(&SharedProperties {
+			auth: Some(bearer("collection")),
+			..Default::default()
+		});
+		inheritance.push(&SharedProperties {
+			auth: Some(bearer("folder")),
+			..Default::default()
+		});
+
+		assert!(matches!(
+			inheritance.resolve_auth(&Auth::Inherit),
+			Auth::BearerToken(BearerToken { token }) if token == "folder"
+		));
+		assert!(matches!(
+			inheritance.resolve_auth(&Auth::NoAuth),
+			Auth::NoAuth
+		));
+	}
+
+	#[test]
+	fn test_undefined_or_inherit_auth_keeps_the_parent_one() {
+		let mut inheritance = Inheritance::default();
+		inheritance.push(&SharedProperties {
+			auth: Some(bearer("collection")),
+			..Default::default()
+		});
+		inheritance.push(&SharedProperties::default());
+		inheritance.push(&SharedProperties {
+			auth: Some(Auth::Inherit),
+			..Default::default()
+		});
+
+		assert!(matches!(
+			inheritance.resolve_auth(&Auth::Inherit),
+			Auth::BearerToken(BearerToken { token }) if token == "collection"
+		));
+		assert!(matches!(
+			Inheritance::default().resolve_auth(&Auth::Inherit),
+			Auth::NoAuth
+		));
+	}
+
+	#[test]
+	fn test_closest_headers_win() {
+		let mut inheritance = Inheritance::default();
+		inheritance.push(&SharedProperties {
+			headers: vec![
+				header("Accept", "text/plain", true),
+				header("X-Collection", "1", true),
+				header("X-Disabled", "1", false),
+			],
+			..Default::default()
+		});
+		inheritance.push(&SharedProperties {
+			headers: vec![header("accept", "application/json", true)],
+			..Default::default()
+		});
+
+		let headers = inheritance.resolve_headers(&[
+			header("X-COLLECTION", "request", true),
+			header("Accept", "ignored", false),
+		]);
+
+		assert_eq!(
+			headers,
+			vec![
+				header("accept", "application/json", true),
+				header("X-COLLECTION", "request", true),
+				header("Accept", "ignored", false),
+			]
+		);
+	}
+
+	#[test]
+	fn test_scripts_are_ordered_from_the_collection() {
+		let scripts = |script: &str| RequestScripts {
+			pre_request_script: Some(script.to_string()),
+			post_request_script: None,
+		};
+
+		let mut inheritance = Inheritance::default();
+		inheritance.push(&SharedProperties {
+			scripts: scripts("collection"),
+			..Default::default()
+		});
+		inheritance.push(&SharedProperties::default());
+		inheritance.push(&SharedProperties {
+			scripts: scripts("folder"),
+			..Default::default()
+		});
+
+		assert_eq!(
+			inheritance.scripts,
+			vec![scripts("collection"), scripts("folder")]
+		);
+	}
+
+	#[test]
+	fn test_shared_properties_yaml_round_trip() {
+		assert_eq!(
+			serde_yaml_ng::to_string(&SharedProperties::default()).unwrap(),
+			"{}\n"
+		);
+
+		let yaml = serde_yaml_ng::to_string(&SharedProperties {
+			auth: Some(bearer("secret")),
+			headers: vec![header("X-Team", "core", true)],
+			..Default::default()
+		})
+		.unwrap();
+		let shared_properties: SharedProperties = serde_yaml_ng::from_str(&yaml).unwrap();
+
+		assert!(matches!(
+			shared_properties.auth,
+			Some(Auth::BearerToken(BearerToken { token })) if token == "secret"
+		));
+		assert_eq!(
+			shared_properties.headers,
+			vec![header("X-Team", "core", true)]
+		);
+		assert!(shared_properties.scripts.is_empty());
+	}
+}
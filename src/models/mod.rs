@@ -1,10 +1,14 @@
 //! Data models for requests, responses, collections, environments, and protocols.
 
+pub mod assertions;
 pub(crate) mod auth;
+pub mod captures;
 pub(crate) mod collection;
 pub mod environment;
 pub(crate) mod export;
 pub mod folder;
+pub mod history;
+pub(crate) mod inheritance;
 pub(crate) mod protocol;
 pub mod request;
 pub mod response;
@@ -1,4 +1,11 @@
+use bytes::Bytes;
+use prost_reflect::MethodDescriptor;
 use serde::{Deserialize, Serialize};
+use strum::Display;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio_util::sync::CancellationToken;
+
+use crate::models::protocol::ws::ws::Message;
 
 /// Model for a gRPC request.
 ///
@@ -8,6 +15,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// Import paths are additional directories to search when resolving proto
 /// imports (similar to protoc's `-I` flag).
+///
+/// With `reflection` enabled the `.proto` file isn't needed, the descriptors
+/// are fetched from the `grpc.reflection.v1` (or `v1alpha`) service instead.
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct GrpcRequest {
 	/// Path to the `.proto` file defining the service.
@@ -20,4 +30,83 @@ pub struct GrpcRequest {
 	pub method: String,
 	/// Request message body as JSON (will be converted to protobuf).
 	pub message: String,
+	/// Build the descriptors from the server reflection service at the request URL
+	/// instead of `proto_file`.
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+	pub reflection: bool,
+	/// Encoding of the sent messages, response messages are accepted in any supported encoding.
+	#[serde(default, skip_serializing_if = "GrpcCompression::is_identity")]
+	pub compression: GrpcCompression,
+
+	/// Messages exchanged during the last streaming call.
+	#[serde(skip)]
+	pub messages: Vec<Message>,
+
+	/// Trailing metadata received when the last streaming call ended.
+	#[serde(skip)]
+	pub trailers: Vec<(String, String)>,
+
+	/// Whether the last sent method streams in either direction, known once sent.
+	#[serde(skip)]
+	pub is_streaming: bool,
+
+	/// Streaming call still receiving messages from the server.
+	#[serde(skip)]
+	pub call: Option<GrpcCall>,
+}
+
+/// Handle on an open streaming call.
+#[derive(Debug, Clone)]
+pub struct GrpcCall {
+	pub method: MethodDescriptor,
+	/// Sender of the request stream, `None` once the client half is closed.
+	pub tx: Option<UnboundedSender<Bytes>>,
+	/// Stops reading the response stream.
+	pub cancellation_token: CancellationToken,
+	/// Encoding of the client messages.
+	pub compression: GrpcCompression,
+}
+
+/// Message encodings, as named in the `grpc-encoding` header
+#[derive(Default, Debug, Copy, Clone, PartialEq, Display, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GrpcCompression {
+	#[default]
+	#[strum(to_string = "identity")]
+	Identity,
+	#[strum(to_string = "gzip")]
+	Gzip,
+}
+
+impl GrpcCompression {
+	pub fn is_identity(&self) -> bool {
+		*self == GrpcCompression::Identity
+	}
+
+	/// Encoding named by a `grpc-encoding` header, `None` when it isn't supported
+	pub fn from_encoding(encoding: &str) -> Option<GrpcCompression> {
+		match encoding.trim() {
+			"identity" => Some(GrpcCompression::Identity),
+			"gzip" => Some(GrpcCompression::Gzip),
+			_ => None,
+		}
+	}
+
+	pub fn next(&self) -> GrpcCompression {
+		match self {
+			GrpcCompression::Identity => GrpcCompression::Gzip,
+			GrpcCompression::Gzip => GrpcCompression::Identity,
+		}
+	}
+}
+
+impl GrpcRequest {
+	pub fn is_connected(&self) -> bool {
+		self.call.is_some()
+	}
+
+	/// Whether client messages can still be sent on the open call
+	pub fn can_send_messages(&self) -> bool {
+		self.call.as_ref().is_some_and(|call| call.tx.is_some())
+	}
 }
@@ -1,2 +1,3 @@
 #[allow(clippy::module_inception)]
 pub(crate) mod grpc;
+pub(crate) mod status;
@@ -0,0 +1,125 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// Canonical names of the gRPC status codes, indexed by code
+const GRPC_CODE_NAMES: [&str; 17] = [
+	"OK",
+	"CANCELLED",
+	"UNKNOWN",
+	"INVALID_ARGUMENT",
+	"DEADLINE_EXCEEDED",
+	"NOT_FOUND",
+	"ALREADY_EXISTS",
+	"PERMISSION_DENIED",
+	"RESOURCE_EXHAUSTED",
+	"FAILED_PRECONDITION",
+	"ABORTED",
+	"OUT_OF_RANGE",
+	"UNIMPLEMENTED",
+	"INTERNAL",
+	"UNAVAILABLE",
+	"DATA_LOSS",
+	"UNAUTHENTICATED",
+];
+
+/// Name of a gRPC status code, codes outside of the spec are `UNKNOWN`
+pub fn grpc_code_name(code: u32) -> &'static str {
+	GRPC_CODE_NAMES
+		.get(code as usize)
+		.copied()
+		.unwrap_or("UNKNOWN")
+}
+
+/// Code of a gRPC status name, e.g. `NOT_FOUND`, the name being case insensitive
+pub fn grpc_code_from_name(name: &str) -> Option<u32> {
+	GRPC_CODE_NAMES
+		.iter()
+		.position(|code_name| code_name.eq_ignore_ascii_case(name))
+		.map(|code| code as u32)
+}
+
+/// Final status of a gRPC call, from the `grpc-status`, `grpc-message` and
+/// `grpc-status-details-bin` trailers (or headers of a trailers-only response).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GrpcStatus {
+	pub code: u32,
+	#[serde(default, skip_serializing_if = "String::is_empty")]
+	pub message: String,
+	/// Decoded `google.rpc.Status`, as JSON
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub details: Option<serde_json::Value>,
+}
+
+impl GrpcStatus {
+	pub fn name(&self) -> &'static str {
+		grpc_code_name(self.code)
+	}
+
+	pub fn is_ok(&self) -> bool {
+		self.code == 0
+	}
+
+	/// JSON representation shown as the body of a failed call
+	pub fn to_json(&self) -> serde_json::Value {
+		let mut json = serde_json::json!({
+			"code": self.code,
+			"status": self.name(),
+			"message": self.message,
+		});
+
+		if let Some(details) = &self.details {
+			json["details"] = details.clone();
+		}
+
+		json
+	}
+}
+
+impl Display for GrpcStatus {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{} ({})", self.name(), self.code)?;
+
+		if !self.message.is_empty() {
+			write!(f, ": {}", self.message)?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn code_names() {
+		assert_eq!(grpc_code_name(0), "OK");
+		assert_eq!(grpc_code_name(5), "NOT_FOUND");
+		assert_eq!(grpc_code_name(14), "UNAVAILABLE");
+		assert_eq!(grpc_code_name(16), "UNAUTHENTICATED");
+		assert_eq!(grpc_code_name(42), "UNKNOWN");
+		assert_eq!(grpc_code_from_name("not_found"), Some(5));
+		assert_eq!(grpc_code_from_name("NOT FOUND"), None);
+	}
+
+	#[test]
+	fn display_status() {
+		let status = GrpcStatus {
+			code: 5,
+			message: String::from("user 42 not found"),
+			details: None,
+		};
+
+		assert_eq!(status.to_string(), "NOT_FOUND (5): user 42 not found");
+		assert_eq!(
+			GrpcStatus {
+				code: 0,
+				message: String::new(),
+				details: None
+			}
+			.to_string(),
+			"OK (0)"
+		);
+	}
+}
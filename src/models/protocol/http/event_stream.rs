@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// Event received from a `text/event-stream` response
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServerSentEvent {
+	/// Event type, `message` when the server did not name it
+	pub event: String,
+	pub data: String,
+	/// Last event ID sent by the server, the event's own or a previous one
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+}
+
+/// Fields other than the data, as they are sent, followed by the data lines
+impl Display for ServerSentEvent {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		if self.event != "message" {
+			writeln!(f, "event: {}", self.event)?;
+		}
+
+		if let Some(id) = &self.id {
+			writeln!(f, "id: {id}")?;
+		}
+
+		write!(f, "{}", self.data)
+	}
+}
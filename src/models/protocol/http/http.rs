@@ -2,9 +2,22 @@ use serde::{Deserialize, Serialize};
 
 use crate::models::protocol::http::body::ContentType;
 use crate::models::protocol::http::method::Method;
+use crate::models::protocol::ws::ws::Message;
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct HttpRequest {
 	pub method: Method,
 	pub body: ContentType,
+
+	/// Events received from the last `text/event-stream` response, at most `SSE_MAX_EVENTS`
+	#[serde(skip)]
+	pub events: Vec<Message>,
+
+	/// Number of the oldest events dropped from `events`
+	#[serde(skip)]
+	pub dropped_events: usize,
+
+	/// Whether the last response is a server-sent events stream, known once sent
+	#[serde(skip)]
+	pub is_event_stream: bool,
 }
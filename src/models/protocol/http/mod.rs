@@ -1,4 +1,5 @@
 pub(crate) mod body;
+pub(crate) mod event_stream;
 #[allow(clippy::module_inception)]
 pub(crate) mod http;
 pub(crate) mod method;
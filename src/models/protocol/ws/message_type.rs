@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use strum::Display;
 
+use crate::models::protocol::http::event_stream::ServerSentEvent;
+
 #[derive(Debug, Clone, Display, Serialize, Deserialize)]
 #[serde(tag = "type", content = "content")]
 #[serde(rename_all = "lowercase")]
@@ -19,6 +21,10 @@ pub enum MessageType {
 
 	#[strum(to_string = "Close")]
 	Close(String),
+
+	/// Received from a server-sent events stream, never sent
+	#[strum(to_string = "Event")]
+	Event(ServerSentEvent),
 }
 
 impl Default for MessageType {
@@ -34,6 +40,7 @@ impl MessageType {
 			MessageType::Binary(bytes) | MessageType::Ping(bytes) | MessageType::Pong(bytes) => {
 				format!("{:?}", bytes)
 			}
+			MessageType::Event(event) => event.to_string(),
 		}
 	}
 }
@@ -45,5 +52,6 @@ pub fn next_message_type(message_type: &MessageType) -> MessageType {
 		MessageType::Ping(ping) => MessageType::Pong(ping.clone()),
 		MessageType::Pong(pong) => MessageType::Close(String::from_utf8_lossy(pong).to_string()),
 		MessageType::Close(close) => MessageType::Text(close.clone()),
+		MessageType::Event(event) => MessageType::Text(event.data.clone()),
 	}
 }
@@ -1,8 +1,9 @@
 use crate::app::files::config::SKIP_SAVE_REQUESTS_RESPONSE;
+use crate::app::files::secrets::mask_secrets;
 use crate::models::protocol::ws::message_type::MessageType;
 use chrono::{DateTime, Local};
 use futures_util::stream::{SplitSink, SplitStream};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 use std::sync::Arc;
 use strum::Display;
 use tokio::sync::Mutex;
@@ -11,6 +12,7 @@ use tokio::sync::Mutex;
 pub struct WsRequest {
 	#[serde(
 		skip_serializing_if = "should_skip_requests_messages",
+		serialize_with = "serialize_messages",
 		default = "Vec::default"
 	)]
 	pub messages: Vec<Message>,
@@ -49,3 +51,22 @@ pub enum Sender {
 pub fn should_skip_requests_messages(_: &Vec<Message>) -> bool {
 	*SKIP_SAVE_REQUESTS_RESPONSE.get().unwrap_or(&true)
 }
+
+/// Saved messages do not contain the secret environment values
+fn serialize_messages<S: Serializer>(
+	messages: &[Message],
+	serializer: S,
+) -> Result<S::Ok, S::Error> {
+	let messages: Vec<Message> = messages
+		.iter()
+		.map(|message| match &message.content {
+			MessageType::Text(text) => Message {
+				content: MessageType::Text(mask_secrets(text)),
+				..message.clone()
+			},
+			_ => message.clone(),
+		})
+		.collect();
+
+	messages.serialize(serializer)
+}
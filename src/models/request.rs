@@ -3,7 +3,7 @@ use ratatui::prelude::{Line, Modifier, Span};
 use ratatui::style::Stylize;
 use rayon::prelude::*;
 use regex::Regex;
-use serde::Serialize;
+use serde::{Serialize, Serializer};
 use serde_versioning::Deserialize;
 use std::path::PathBuf;
 use std::sync::LazyLock;
@@ -14,7 +14,9 @@ use tui_tree_widget::TreeItem;
 use crate::app::App;
 use crate::app::files::config::SKIP_SAVE_REQUESTS_RESPONSE;
 use crate::app::files::theme::THEME;
+use crate::models::assertions::{Assertion, AssertionResult};
 use crate::models::auth::auth::Auth;
+use crate::models::captures::{Capture, CaptureResult};
 use crate::models::protocol::graphql::graphql::GraphqlRequest;
 use crate::models::protocol::grpc::grpc::GrpcRequest;
 use crate::models::protocol::http::http::HttpRequest;
@@ -22,9 +24,9 @@ use crate::models::protocol::protocol::Protocol;
 use crate::models::protocol::protocol::ProtocolTypeError::{
 	NotAGraphqlRequest, NotAGrpcRequest, NotAWsRequest, NotAnHttpRequest,
 };
-use crate::models::protocol::ws::ws::WsRequest;
-use crate::models::response::RequestResponse;
-use crate::models::scripts::RequestScripts;
+use crate::models::protocol::ws::ws::{Message, WsRequest};
+use crate::models::response::{DownloadProgress, RequestResponse};
+use crate::models::scripts::{RequestScripts, ScriptTestResult};
 use crate::models::settings::RequestSettings;
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
@@ -37,10 +39,21 @@ pub struct Request {
 	pub scripts: RequestScripts,
 	pub settings: RequestSettings,
 
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub assertions: Vec<Assertion>,
+
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub captures: Vec<Capture>,
+
+	/// JSONPath or jq-like expression projecting the response body in the TUI
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub response_filter: Option<String>,
+
 	pub protocol: Protocol,
 
 	#[serde(
 		skip_serializing_if = "should_skip_requests_response",
+		serialize_with = "serialize_response",
 		default = "RequestResponse::default"
 	)]
 	pub response: RequestResponse,
@@ -48,17 +61,34 @@ pub struct Request {
 	#[serde(skip)]
 	pub console_output: ConsoleOutput,
 
+	#[serde(skip)]
+	pub assertion_results: Vec<AssertionResult>,
+
+	#[serde(skip)]
+	pub test_results: Vec<ScriptTestResult>,
+
+	#[serde(skip)]
+	pub capture_results: Vec<CaptureResult>,
+
 	#[serde(skip)]
 	pub is_pending: bool,
 
+	/// Bytes of the response body received so far, while it is downloaded
+	#[serde(skip)]
+	pub download_progress: Option<DownloadProgress>,
+
 	#[serde(skip)]
 	pub cancellation_token: CancellationToken,
 
 	#[serde(skip)]
 	pub source_path: Option<PathBuf>,
+
+	/// Scripts of the collection and folder, run before the request ones, outermost first
+	#[serde(skip)]
+	pub inherited_scripts: Vec<RequestScripts>,
 }
 
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeyValue {
 	pub enabled: bool,
 	pub data: (String, String),
@@ -74,6 +104,14 @@ fn should_skip_requests_response(_: &RequestResponse) -> bool {
 	*SKIP_SAVE_REQUESTS_RESPONSE.get().unwrap_or(&true)
 }
 
+/// Saved responses do not contain the secret environment values
+fn serialize_response<S: Serializer>(
+	response: &RequestResponse,
+	serializer: S,
+) -> Result<S::Ok, S::Error> {
+	response.with_masked_secrets().serialize(serializer)
+}
+
 impl App<'_> {
 	pub fn key_value_vec_to_tuple_vec(&self, key_value: &[KeyValue]) -> Vec<(String, String)> {
 		key_value
@@ -202,6 +240,35 @@ impl Request {
 		}
 	}
 
+	/// Whether the last response is a server-sent events stream
+	pub fn is_event_stream(&self) -> bool {
+		matches!(&self.protocol, Protocol::HttpRequest(http_request) if http_request.is_event_stream)
+	}
+
+	/// Message log of WebSocket and streaming gRPC requests, or server-sent events
+	pub fn get_stream_messages(&self) -> Option<&[Message]> {
+		match &self.protocol {
+			Protocol::HttpRequest(http_request) if http_request.is_event_stream => {
+				Some(&http_request.events)
+			}
+			Protocol::WsRequest(ws_request) => Some(&ws_request.messages),
+			Protocol::GrpcRequest(grpc_request) if grpc_request.is_streaming => {
+				Some(&grpc_request.messages)
+			}
+			_ => None,
+		}
+	}
+
+	/// Trailing metadata of the last response, received once the stream ended for streaming gRPC calls
+	pub fn get_response_trailers(&self) -> &[(String, String)] {
+		match &self.protocol {
+			Protocol::GrpcRequest(grpc_request) if grpc_request.is_streaming => {
+				&grpc_request.trailers
+			}
+			_ => &self.response.trailers,
+		}
+	}
+
 	pub fn to_tree_item<'a>(&self, identifier: usize, is_last: bool) -> TreeItem<'a, usize> {
 		let mut line_elements: Vec<Span> = vec![];
 
@@ -1,5 +1,11 @@
 use image::DynamicImage;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use crate::app::files::secrets::mask_secrets;
+use crate::models::protocol::grpc::status::GrpcStatus;
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct RequestResponse {
@@ -8,6 +14,193 @@ pub struct RequestResponse {
 	pub content: Option<ResponseContent>,
 	pub cookies: Option<String>,
 	pub headers: Vec<(String, String)>,
+	/// Trailing metadata, only sent by gRPC servers
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub trailers: Vec<(String, String)>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub grpc_status: Option<GrpcStatus>,
+	/// Size in bytes of the whole received body, which may be larger than the content kept
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub body_size: Option<u64>,
+	/// Whether only the beginning of the body is kept, the rest being over the in-memory limit
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+	pub is_body_truncated: bool,
+	/// Received bytes of the body kept in memory, to save them to a file as they were sent
+	#[serde(skip)]
+	pub raw_body: Vec<u8>,
+	/// Time spent in each phase of the request, only measured for HTTP requests
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub timing: Option<ResponseTiming>,
+	/// Connection the response was received on, only known for HTTP requests
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub connection: Option<ConnectionInfo>,
+	/// Redirects followed before receiving the response, in order
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub redirects: Vec<RedirectHop>,
+}
+
+/// Redirect response received while sending an HTTP request
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RedirectHop {
+	pub method: String,
+	pub url: String,
+	pub status_code: String,
+	/// Response headers, `Set-Cookie` ones included
+	pub headers: Vec<(String, String)>,
+	/// URL the request was redirected to, resolved from the `Location` header
+	pub location: String,
+}
+
+/// Time spent in each phase of an HTTP request, like the `-w` timings of curl. The phases add up
+/// to the total time.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResponseTiming {
+	/// `None` when the host is an IP address or no new connection was opened
+	pub dns_lookup: Option<Duration>,
+	/// TCP connection. `None` when no new connection was opened.
+	pub connect: Option<Duration>,
+	/// `None` for plain HTTP or when no new connection was opened
+	#[serde(default)]
+	pub tls_handshake: Option<Duration>,
+	/// Sending the request and waiting for the first byte of the response
+	pub waiting: Duration,
+	/// Receiving the response body
+	pub download: Duration,
+	pub total: Duration,
+}
+
+impl ResponseTiming {
+	/// Name and duration of each phase, in order
+	pub fn phases(&self) -> Vec<(&'static str, Option<Duration>)> {
+		vec![
+			("DNS lookup", self.dns_lookup),
+			("TCP connect", self.connect),
+			("TLS handshake", self.tls_handshake),
+			("Waiting", Some(self.waiting)),
+			("Download", Some(self.download)),
+		]
+	}
+
+	pub fn time_to_first_byte(&self) -> Duration {
+		self.total.saturating_sub(self.download)
+	}
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConnectionInfo {
+	/// Negotiated HTTP version, e.g. `HTTP/2.0`
+	pub http_version: String,
+	/// IP address and port of the server, or of the proxy
+	pub remote_address: Option<String>,
+	/// e.g. `TLSv1.3`, `None` for plain HTTP
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub tls_version: Option<String>,
+	/// e.g. `TLS13_AES_128_GCM_SHA256`, `None` for plain HTTP
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub cipher_suite: Option<String>,
+	/// Certificate presented by the server, `None` for plain HTTP
+	pub certificate: Option<CertificateSummary>,
+	/// Certificates sent by the server after its own one, in order
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub certificate_chain: Vec<CertificateSummary>,
+}
+
+/// Main fields of a certificate presented by a TLS server
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificateSummary {
+	pub subject: String,
+	pub issuer: String,
+	pub not_before: String,
+	pub not_after: String,
+	/// DNS names and IP addresses the certificate is valid for
+	pub subject_alt_names: Vec<String>,
+}
+
+impl ConnectionInfo {
+	/// Label and value of each known detail of the connection
+	pub fn details(&self) -> Vec<(&'static str, String)> {
+		let mut details = vec![("HTTP version", self.http_version.clone())];
+
+		if let Some(remote_address) = &self.remote_address {
+			details.push(("Remote address", remote_address.clone()));
+		}
+
+		if let Some(tls_version) = &self.tls_version {
+			details.push(("TLS version", tls_version.clone()));
+		}
+
+		if let Some(cipher_suite) = &self.cipher_suite {
+			details.push(("Cipher suite", cipher_suite.clone()));
+		}
+
+		if let Some(certificate) = &self.certificate {
+			details.push(("Certificate subject", certificate.subject.clone()));
+			details.push(("Certificate issuer", certificate.issuer.clone()));
+			details.push(("Valid from", certificate.not_before.clone()));
+			details.push(("Valid until", certificate.not_after.clone()));
+
+			if !certificate.subject_alt_names.is_empty() {
+				details.push((
+					"Alternative names",
+					certificate.subject_alt_names.join(", "),
+				));
+			}
+		}
+
+		for certificate in &self.certificate_chain {
+			details.push((
+				"Chain certificate",
+				format!(
+					"{} (issuer {}, valid until {})",
+					certificate.subject, certificate.issuer, certificate.not_after
+				),
+			));
+		}
+
+		details
+	}
+}
+
+/// Bytes of the response body received so far, while it is downloaded
+#[derive(Default, Debug, Clone, Copy)]
+pub struct DownloadProgress {
+	pub received: u64,
+	/// From the content-length header, when the server sent one
+	pub total: Option<u64>,
+}
+
+impl Display for DownloadProgress {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self.total {
+			Some(total) if total > 0 => write!(
+				f,
+				"{} / {} ({}%)",
+				format_size(self.received),
+				format_size(total),
+				self.received.min(total) * 100 / total
+			),
+			_ => write!(f, "{}", format_size(self.received)),
+		}
+	}
+}
+
+/// Human readable size, e.g. `512 B` or `1.50 MB`
+pub fn format_size(bytes: u64) -> String {
+	const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+
+	if bytes < 1024 {
+		return format!("{bytes} B");
+	}
+
+	let mut size = bytes as f64 / 1024.0;
+	let mut unit = 0;
+
+	while size >= 1024.0 && unit < UNITS.len() - 1 {
+		size /= 1024.0;
+		unit += 1;
+	}
+
+	format!("{size:.2} {}", UNITS[unit])
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,6 +218,121 @@ pub struct ImageResponse {
 	pub image: Option<DynamicImage>,
 }
 
+impl RequestResponse {
+	/// Duration of each phase, then the time to first byte and the total, as label and value rows.
+	/// Empty when the response was not timed.
+	pub fn timing_details(&self) -> Vec<(&'static str, String)> {
+		let mut details = vec![];
+
+		if let Some(timing) = &self.timing {
+			for (phase, duration) in timing.phases() {
+				let duration = match duration {
+					Some(duration) => format!("{duration:.2?}"),
+					None => String::from("-"),
+				};
+
+				details.push((phase, duration));
+			}
+
+			details.push((
+				"Time to first byte",
+				format!("{:.2?}", timing.time_to_first_byte()),
+			));
+			details.push(("Total", format!("{:.2?}", timing.total)));
+		}
+
+		details
+	}
+
+	pub fn connection_details(&self) -> Vec<(&'static str, String)> {
+		match &self.connection {
+			Some(connection) => connection.details(),
+			None => vec![],
+		}
+	}
+
+	/// Timing details, then connection details after an empty line, aligned in two columns
+	pub fn timing_report(&self) -> String {
+		let timing_details = self.timing_details();
+		let connection_details = self.connection_details();
+
+		let label_width = timing_details
+			.iter()
+			.chain(&connection_details)
+			.map(|(label, _)| label.len())
+			.max()
+			.unwrap_or(0);
+
+		let format_rows = |rows: &[(&str, String)]| {
+			rows.iter()
+				.map(|(label, value)| format!("{label:label_width$}  {value}"))
+				.collect::<Vec<String>>()
+				.join("\n")
+		};
+
+		[
+			format_rows(&timing_details),
+			format_rows(&connection_details),
+		]
+		.into_iter()
+		.filter(|section| !section.is_empty())
+		.collect::<Vec<String>>()
+		.join("\n\n")
+	}
+
+	/// Each redirect followed with its response headers, the hops being separated by empty lines
+	pub fn redirects_report(&self) -> String {
+		self.redirects
+			.iter()
+			.enumerate()
+			.map(|(index, hop)| {
+				let mut lines = vec![
+					format!("{}. {} {}", index + 1, hop.method, hop.url),
+					format!("   {} -> {}", hop.status_code, hop.location),
+				];
+
+				lines.extend(hop.headers.iter().map(|(header_name, header_value)| {
+					format!("   {header_name}: {header_value}")
+				}));
+
+				lines.join("\n")
+			})
+			.collect::<Vec<String>>()
+			.join("\n\n")
+	}
+
+	/// Copy of the response with the secret environment values masked, as it is saved
+	pub fn with_masked_secrets(&self) -> RequestResponse {
+		let mask_values = |pairs: &[(String, String)]| -> Vec<(String, String)> {
+			pairs
+				.iter()
+				.map(|(key, value)| (key.clone(), mask_secrets(value)))
+				.collect()
+		};
+
+		RequestResponse {
+			content: match &self.content {
+				Some(ResponseContent::Body(body)) => {
+					Some(ResponseContent::Body(mask_secrets(body)))
+				}
+				content => content.clone(),
+			},
+			cookies: self.cookies.as_deref().map(mask_secrets),
+			headers: mask_values(&self.headers),
+			trailers: mask_values(&self.trailers),
+			redirects: self
+				.redirects
+				.iter()
+				.map(|hop| RedirectHop {
+					headers: mask_values(&hop.headers),
+					..hop.clone()
+				})
+				.collect(),
+			..self.clone()
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -81,6 +389,137 @@ mod tests {
 		}
 	}
 
+	fn timed_response() -> RequestResponse {
+		RequestResponse {
+			timing: Some(ResponseTiming {
+				dns_lookup: None,
+				connect: Some(Duration::from_millis(5)),
+				tls_handshake: Some(Duration::from_millis(15)),
+				waiting: Duration::from_millis(100),
+				download: Duration::from_millis(5),
+				total: Duration::from_millis(125),
+			}),
+			connection: Some(ConnectionInfo {
+				http_version: String::from("HTTP/2.0"),
+				remote_address: Some(String::from("127.0.0.1:443")),
+				tls_version: Some(String::from("TLSv1.3")),
+				cipher_suite: Some(String::from("TLS13_AES_128_GCM_SHA256")),
+				certificate: Some(CertificateSummary {
+					subject: String::from("CN=localhost"),
+					issuer: String::from("CN=test CA"),
+					not_before: String::from("2026-01-01 00:00:00 UTC"),
+					not_after: String::from("2027-01-01 00:00:00 UTC"),
+					subject_alt_names: vec![String::from("localhost")],
+				}),
+				certificate_chain: vec![CertificateSummary {
+					subject: String::from("CN=test CA"),
+					issuer: String::from("CN=test CA"),
+					not_before: String::from("2026-01-01 00:00:00 UTC"),
+					not_after: String::from("2036-01-01 00:00:00 UTC"),
+					subject_alt_names: vec![],
+				}],
+			}),
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn timing_details_list_the_phases_then_the_totals() {
+		let response = timed_response();
+		let details = response.timing_details();
+
+		assert_eq!(details[0], ("DNS lookup", String::from("-")));
+		assert_eq!(details[1], ("TCP connect", String::from("5.00ms")));
+		assert_eq!(details[2], ("TLS handshake", String::from("15.00ms")));
+		assert_eq!(details[5], ("Time to first byte", String::from("120.00ms")));
+		assert_eq!(details[6], ("Total", String::from("125.00ms")));
+		assert_eq!(details.len(), 7);
+
+		let connection_details = response.connection_details();
+		assert_eq!(
+			connection_details[0],
+			("HTTP version", String::from("HTTP/2.0"))
+		);
+		assert_eq!(
+			connection_details[2],
+			("TLS version", String::from("TLSv1.3"))
+		);
+		assert_eq!(
+			connection_details.last(),
+			Some(&(
+				"Chain certificate",
+				String::from("CN=test CA (issuer CN=test CA, valid until 2036-01-01 00:00:00 UTC)")
+			))
+		);
+	}
+
+	#[test]
+	fn timing_report_aligns_the_values() {
+		let response = RequestResponse {
+			timing: Some(ResponseTiming {
+				total: Duration::from_millis(1),
+				..Default::default()
+			}),
+			connection: Some(ConnectionInfo {
+				http_version: String::from("HTTP/1.1"),
+				..Default::default()
+			}),
+			..Default::default()
+		};
+
+		assert_eq!(
+			response.timing_report(),
+			"DNS lookup          -\n\
+			 TCP connect         -\n\
+			 TLS handshake       -\n\
+			 Waiting             0.00ns\n\
+			 Download            0.00ns\n\
+			 Time to first byte  1.00ms\n\
+			 Total               1.00ms\n\
+			 \n\
+			 HTTP version        HTTP/1.1"
+		);
+		assert_eq!(RequestResponse::default().timing_report(), "");
+	}
+
+	#[test]
+	fn redirects_report_lists_the_hops_with_their_headers() {
+		let response = RequestResponse {
+			redirects: vec![
+				RedirectHop {
+					method: String::from("POST"),
+					url: String::from("http://localhost/login"),
+					status_code: String::from("302 Found"),
+					headers: vec![
+						(String::from("location"), String::from("/home")),
+						(String::from("set-cookie"), String::from("session=1")),
+					],
+					location: String::from("http://localhost/home"),
+				},
+				RedirectHop {
+					method: String::from("GET"),
+					url: String::from("http://localhost/home"),
+					status_code: String::from("301 Moved Permanently"),
+					headers: vec![],
+					location: String::from("https://localhost/home"),
+				},
+			],
+			..Default::default()
+		};
+
+		assert_eq!(
+			response.redirects_report(),
+			"1. POST http://localhost/login\n   \
+			 302 Found -> http://localhost/home\n   \
+			 location: /home\n   \
+			 set-cookie: session=1\n\
+			 \n\
+			 2. GET http://localhost/home\n   \
+			 301 Moved Permanently -> https://localhost/home"
+		);
+		assert_eq!(RequestResponse::default().redirects_report(), "");
+	}
+
 	#[test]
 	fn response_serializes_to_json() {
 		let resp = RequestResponse {
@@ -89,6 +528,7 @@ mod tests {
 			content: Some(ResponseContent::Body("test".to_string())),
 			cookies: None,
 			headers: vec![("content-type".to_string(), "text/plain".to_string())],
+			..Default::default()
 		};
 		let json = serde_json::to_string(&resp).unwrap();
 		assert!(json.contains("200 OK"));
@@ -112,9 +552,51 @@ mod tests {
 			content: Some(ResponseContent::Body("original".to_string())),
 			cookies: None,
 			headers: vec![],
+			..Default::default()
 		};
 		let cloned = resp.clone();
 		assert_eq!(resp.status_code, cloned.status_code);
 		assert_eq!(resp.duration, cloned.duration);
 	}
+
+	#[test]
+	fn format_size_uses_the_largest_unit() {
+		assert_eq!(format_size(0), "0 B");
+		assert_eq!(format_size(1023), "1023 B");
+		assert_eq!(format_size(1536), "1.50 KB");
+		assert_eq!(format_size(10 * 1024 * 1024), "10.00 MB");
+	}
+
+	#[test]
+	fn download_progress_shows_percentage_when_total_is_known() {
+		let progress = DownloadProgress {
+			received: 512,
+			total: Some(2048),
+		};
+		assert_eq!(progress.to_string(), "512 B / 2.00 KB (25%)");
+
+		let progress = DownloadProgress {
+			received: 2048,
+			total: None,
+		};
+		assert_eq!(progress.to_string(), "2.00 KB");
+	}
+
+	#[test]
+	fn truncated_body_is_saved_with_its_size() {
+		let resp = RequestResponse {
+			content: Some(ResponseContent::Body("head".to_string())),
+			body_size: Some(4096),
+			is_body_truncated: true,
+			raw_body: b"head".to_vec(),
+			..Default::default()
+		};
+		let json = serde_json::to_string(&resp).unwrap();
+		assert!(json.contains(r#""body_size":4096"#));
+		assert!(json.contains(r#""is_body_truncated":true"#));
+		assert!(!json.contains("raw_body"));
+
+		let json = serde_json::to_string(&RequestResponse::default()).unwrap();
+		assert!(!json.contains("is_body_truncated"));
+	}
 }
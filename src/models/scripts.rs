@@ -2,14 +2,29 @@ use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 use strum::Display;
 
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RequestScripts {
 	pub pre_request_script: Option<String>,
 	pub post_request_script: Option<String>,
 }
 
+impl RequestScripts {
+	pub fn is_empty(&self) -> bool {
+		self.pre_request_script.is_none() && self.post_request_script.is_none()
+	}
+}
+
 #[derive(ValueEnum, Debug, Clone, Display)]
 pub enum ScriptType {
 	Pre,
 	Post,
 }
+
+/// Outcome of a `test()` / `pm.test()` call made by a post-request script
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScriptTestResult {
+	pub name: String,
+	pub passed: bool,
+	#[serde(default)]
+	pub error: Option<String>,
+}
@@ -9,11 +9,34 @@ use std::str::FromStr;
 pub struct RequestSettings {
 	pub use_config_proxy: Setting,
 	pub allow_redirects: Setting,
+	/// Number of redirects followed before the last redirect response is kept as the response
+	pub max_redirects: Setting,
 	pub timeout: Setting,
 	pub store_received_cookies: Setting,
 	pub pretty_print_response_content: Setting,
 	pub accept_invalid_certs: Setting,
 	pub accept_invalid_hostnames: Setting,
+	#[serde(default, skip_serializing_if = "TlsSettings::is_empty")]
+	pub tls: TlsSettings,
+}
+
+/// Client certificate and extra trusted root certificates, set on a request or on a whole
+/// collection. Every value is a file path, which may contain environment keys.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TlsSettings {
+	/// PEM certificate, optionally followed by its private key, or PKCS#12 archive (`.p12`,
+	/// `.pfx`) holding both
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub client_certificate: Option<String>,
+	/// PEM private key, when it is not bundled with the client certificate
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub client_key: Option<String>,
+	/// Password of a PKCS#12 client certificate, empty when `None`
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub client_certificate_password: Option<String>,
+	/// PEM files of root certificates trusted on top of the system ones
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub ca_certificates: Vec<String>,
 }
 
 /// Raw deserialization target that mirrors [`RequestSettings`] before
@@ -23,11 +46,19 @@ pub struct RequestSettings {
 struct RawRequestSettings {
 	use_config_proxy: Setting,
 	allow_redirects: Setting,
+	#[serde(default = "default_max_redirects")]
+	max_redirects: Setting,
 	timeout: Setting,
 	store_received_cookies: Setting,
 	pretty_print_response_content: Setting,
 	accept_invalid_certs: Setting,
 	accept_invalid_hostnames: Setting,
+	#[serde(default)]
+	tls: TlsSettings,
+}
+
+fn default_max_redirects() -> Setting {
+	RequestSettings::default().max_redirects
 }
 
 impl From<RawRequestSettings> for RequestSettings {
@@ -35,11 +66,13 @@ impl From<RawRequestSettings> for RequestSettings {
 		let mut settings = RequestSettings {
 			use_config_proxy: raw.use_config_proxy,
 			allow_redirects: raw.allow_redirects,
+			max_redirects: raw.max_redirects,
 			timeout: raw.timeout,
 			store_received_cookies: raw.store_received_cookies,
 			pretty_print_response_content: raw.pretty_print_response_content,
 			accept_invalid_certs: raw.accept_invalid_certs,
 			accept_invalid_hostnames: raw.accept_invalid_hostnames,
+			tls: raw.tls,
 		};
 		settings.normalize();
 		settings
@@ -51,6 +84,7 @@ impl From<RawRequestSettings> for RequestSettings {
 pub enum Setting {
 	Bool(bool),
 	U32(u32),
+	Text(String),
 }
 
 /// Custom [`Deserialize`] for [`Setting`] that handles cross-type coercion.
@@ -58,7 +92,7 @@ pub enum Setting {
 /// When using `#[serde(untagged)]`, a YAML integer `0` or `1` would always
 /// deserialize as `U32` even when a boolean was intended (e.g. a user
 /// hand-editing a collection file).  This visitor accepts booleans as
-/// `Bool` and integers as `U32`, making the per-field normalization in
+/// `Bool`, integers as `U32` and strings as `Text`, making the per-field normalization in
 /// [`RequestSettings::normalize`] the single place that resolves any
 /// remaining ambiguity.
 impl<'de> Deserialize<'de> for Setting {
@@ -72,7 +106,11 @@ impl<'de> Deserialize<'de> for Setting {
 			type Value = Setting;
 
 			fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-				formatter.write_str("a boolean or a positive integer")
+				formatter.write_str("a boolean, a positive integer or a string")
+			}
+
+			fn visit_str<E: de::Error>(self, v: &str) -> Result<Setting, E> {
+				Ok(Setting::Text(v.to_string()))
 			}
 
 			fn visit_bool<E: de::Error>(self, v: bool) -> Result<Setting, E> {
@@ -120,19 +158,25 @@ impl Setting {
 			_ => None,
 		}
 	}
+
+	pub fn as_text(&self) -> Option<&str> {
+		match self {
+			Setting::Text(text) => Some(text),
+			_ => None,
+		}
+	}
 }
 
 impl FromStr for Setting {
 	type Err = String;
 
+	/// Anything that is neither a boolean nor a positive int is kept as text
 	fn from_str(input: &str) -> Result<Self, String> {
 		match bool::from_str(&input.to_lowercase()) {
 			Ok(bool) => Ok(Setting::Bool(bool)),
 			Err(_) => match u32::from_str(input) {
 				Ok(u32) => Ok(Setting::U32(u32)),
-				Err(_) => Err(String::from(
-					"Value should either be a boolean or a positive int",
-				)),
+				Err(_) => Ok(Setting::Text(input.to_string())),
 			},
 		}
 	}
@@ -143,6 +187,7 @@ impl Display for Setting {
 		let str = match self {
 			Setting::Bool(bool) => bool.to_string(),
 			Setting::U32(uint) => uint.to_string(),
+			Setting::Text(text) => text.clone(),
 		};
 		write!(f, "{}", str)
 	}
@@ -153,11 +198,13 @@ impl Default for RequestSettings {
 		RequestSettings {
 			use_config_proxy: Setting::Bool(true),
 			allow_redirects: Setting::Bool(true),
+			max_redirects: Setting::U32(10),
 			timeout: Setting::U32(30000),
 			store_received_cookies: Setting::Bool(true),
 			pretty_print_response_content: Setting::Bool(true),
 			accept_invalid_certs: Setting::Bool(false),
 			accept_invalid_hostnames: Setting::Bool(false),
+			tls: TlsSettings::default(),
 		}
 	}
 }
@@ -166,11 +213,16 @@ impl Default for RequestSettings {
 /// and [`RequestSettings::update_from_vec`] to avoid duplicated string literals.
 const SETTING_USE_CONFIG_PROXY: &str = "Use config proxy";
 const SETTING_ALLOW_REDIRECTS: &str = "Allow redirects";
+const SETTING_MAX_REDIRECTS: &str = "Max redirects";
 const SETTING_TIMEOUT: &str = "Timeout (ms)";
 const SETTING_STORE_RECEIVED_COOKIES: &str = "Store received cookies";
 const SETTING_PRETTY_PRINT_RESPONSE_CONTENT: &str = "Pretty print response content";
 const SETTING_ACCEPT_INVALID_CERTS: &str = "Accept invalid certs";
 const SETTING_ACCEPT_INVALID_HOSTNAMES: &str = "Accept invalid hostnames";
+const SETTING_CLIENT_CERTIFICATE: &str = "Client certificate";
+const SETTING_CLIENT_KEY: &str = "Client key";
+const SETTING_CLIENT_CERTIFICATE_PASSWORD: &str = "Client certificate password";
+const SETTING_CA_CERTIFICATES: &str = "CA certificates";
 
 impl RequestSettings {
 	/// Coerce each field to its expected variant type.
@@ -181,12 +233,13 @@ impl RequestSettings {
 	///   - `U32(1)` → `Bool(true)`
 	///   - any other `U32` → default value for that field
 	///
-	/// The `timeout` field, conversely, coerces `Bool` to its default (`30000`).
+	/// The `timeout` and `max_redirects` fields, conversely, coerce `Bool` to their default.
 	pub fn normalize(&mut self) {
 		let defaults = RequestSettings::default();
 
 		self.use_config_proxy = coerce_to_bool(&self.use_config_proxy, &defaults.use_config_proxy);
 		self.allow_redirects = coerce_to_bool(&self.allow_redirects, &defaults.allow_redirects);
+		self.max_redirects = coerce_to_u32(&self.max_redirects, &defaults.max_redirects);
 		self.timeout = coerce_to_u32(&self.timeout, &defaults.timeout);
 		self.store_received_cookies = coerce_to_bool(
 			&self.store_received_cookies,
@@ -214,6 +267,10 @@ impl RequestSettings {
 				String::from(SETTING_ALLOW_REDIRECTS),
 				self.allow_redirects.clone(),
 			),
+			(
+				String::from(SETTING_MAX_REDIRECTS),
+				self.max_redirects.clone(),
+			),
 			(String::from(SETTING_TIMEOUT), self.timeout.clone()),
 			(
 				String::from(SETTING_STORE_RECEIVED_COOKIES),
@@ -231,6 +288,27 @@ impl RequestSettings {
 				String::from(SETTING_ACCEPT_INVALID_HOSTNAMES),
 				self.accept_invalid_hostnames.clone(),
 			),
+			(
+				String::from(SETTING_CLIENT_CERTIFICATE),
+				Setting::Text(self.tls.client_certificate.clone().unwrap_or_default()),
+			),
+			(
+				String::from(SETTING_CLIENT_KEY),
+				Setting::Text(self.tls.client_key.clone().unwrap_or_default()),
+			),
+			(
+				String::from(SETTING_CLIENT_CERTIFICATE_PASSWORD),
+				Setting::Text(
+					self.tls
+						.client_certificate_password
+						.clone()
+						.unwrap_or_default(),
+				),
+			),
+			(
+				String::from(SETTING_CA_CERTIFICATES),
+				Setting::Text(self.tls.ca_certificates.join(", ")),
+			),
 		]
 	}
 
@@ -239,6 +317,7 @@ impl RequestSettings {
 			match setting_name.as_str() {
 				SETTING_USE_CONFIG_PROXY => self.use_config_proxy = setting_value.clone(),
 				SETTING_ALLOW_REDIRECTS => self.allow_redirects = setting_value.clone(),
+				SETTING_MAX_REDIRECTS => self.max_redirects = setting_value.clone(),
 				SETTING_TIMEOUT => self.timeout = setting_value.clone(),
 				SETTING_STORE_RECEIVED_COOKIES => {
 					self.store_received_cookies = setting_value.clone()
@@ -250,12 +329,83 @@ impl RequestSettings {
 				SETTING_ACCEPT_INVALID_HOSTNAMES => {
 					self.accept_invalid_hostnames = setting_value.clone()
 				}
+				SETTING_CLIENT_CERTIFICATE => {
+					self.tls.client_certificate = setting_value.as_text().and_then(non_empty)
+				}
+				SETTING_CLIENT_KEY => {
+					self.tls.client_key = setting_value.as_text().and_then(non_empty)
+				}
+				SETTING_CLIENT_CERTIFICATE_PASSWORD => {
+					self.tls.client_certificate_password =
+						setting_value.as_text().and_then(non_empty)
+				}
+				SETTING_CA_CERTIFICATES => {
+					self.tls.ca_certificates =
+						split_paths(setting_value.as_text().unwrap_or_default())
+				}
 				_ => {}
 			}
 		}
 	}
 }
 
+impl TlsSettings {
+	pub fn is_empty(&self) -> bool {
+		self.client_certificate.is_none()
+			&& self.client_key.is_none()
+			&& self.client_certificate_password.is_none()
+			&& self.ca_certificates.is_empty()
+	}
+
+	/// Settings of a request, falling back to the ones of its collection.
+	///
+	/// The client certificate, key and password go together, so the collection ones are only used
+	/// when the request has no certificate. CA certificates of both are trusted.
+	pub fn or_collection(&self, collection: &TlsSettings) -> TlsSettings {
+		let client = match &self.client_certificate {
+			Some(_) => self,
+			None => collection,
+		};
+
+		let mut ca_certificates = collection.ca_certificates.clone();
+		ca_certificates.extend(
+			self.ca_certificates
+				.iter()
+				.filter(|path| !collection.ca_certificates.contains(path))
+				.cloned(),
+		);
+
+		TlsSettings {
+			client_certificate: client.client_certificate.clone(),
+			client_key: client.client_key.clone(),
+			client_certificate_password: client.client_certificate_password.clone(),
+			ca_certificates,
+		}
+	}
+}
+
+fn non_empty(text: &str) -> Option<String> {
+	let text = text.trim();
+
+	match text.is_empty() {
+		true => None,
+		false => Some(text.to_string()),
+	}
+}
+
+/// Step, minimum and maximum of a positive int setting shown by [`RequestSettings::to_vec`]
+pub fn u32_setting_range(setting_name: &str) -> (u32, u32, u32) {
+	match setting_name {
+		SETTING_MAX_REDIRECTS => (1, 0, 50),
+		_ => (100, 100, 100000),
+	}
+}
+
+/// Comma separated list of paths, as shown by [`RequestSettings::to_vec`]
+pub fn split_paths(paths: &str) -> Vec<String> {
+	paths.split(',').filter_map(non_empty).collect()
+}
+
 /// Coerce a `Setting` to `Bool`. If it is already `Bool`, return as-is.
 /// If it is `U32(0)` → `Bool(false)`, `U32(1)` → `Bool(true)`.
 /// Any other `U32` value falls back to the provided default.
@@ -264,7 +414,7 @@ fn coerce_to_bool(setting: &Setting, default: &Setting) -> Setting {
 		Setting::Bool(_) => setting.clone(),
 		Setting::U32(0) => Setting::Bool(false),
 		Setting::U32(1) => Setting::Bool(true),
-		Setting::U32(_) => default.clone(),
+		Setting::U32(_) | Setting::Text(_) => default.clone(),
 	}
 }
 
@@ -273,7 +423,7 @@ fn coerce_to_bool(setting: &Setting, default: &Setting) -> Setting {
 fn coerce_to_u32(setting: &Setting, default: &Setting) -> Setting {
 	match setting {
 		Setting::U32(_) => setting.clone(),
-		Setting::Bool(_) => default.clone(),
+		Setting::Bool(_) | Setting::Text(_) => default.clone(),
 	}
 }
 
@@ -328,11 +478,13 @@ mod tests {
 		let original = RequestSettings {
 			use_config_proxy: Setting::Bool(false),
 			allow_redirects: Setting::Bool(false),
+			max_redirects: Setting::U32(3),
 			timeout: Setting::U32(5000),
 			store_received_cookies: Setting::Bool(false),
 			pretty_print_response_content: Setting::Bool(false),
 			accept_invalid_certs: Setting::Bool(true),
 			accept_invalid_hostnames: Setting::Bool(true),
+			..Default::default()
 		};
 
 		let vec = original.to_vec();
@@ -341,6 +493,7 @@ mod tests {
 
 		assert_eq!(restored.use_config_proxy.as_bool(), Some(false));
 		assert_eq!(restored.allow_redirects.as_bool(), Some(false));
+		assert_eq!(restored.max_redirects.as_u32(), Some(3));
 		assert_eq!(restored.timeout.as_u32(), Some(5000));
 		assert_eq!(restored.store_received_cookies.as_bool(), Some(false));
 		assert_eq!(
@@ -371,11 +524,16 @@ mod tests {
 			vec![
 				"Use config proxy",
 				"Allow redirects",
+				"Max redirects",
 				"Timeout (ms)",
 				"Store received cookies",
 				"Pretty print response content",
 				"Accept invalid certs",
 				"Accept invalid hostnames",
+				"Client certificate",
+				"Client key",
+				"Client certificate password",
+				"CA certificates",
 			]
 		);
 	}
@@ -392,6 +550,7 @@ mod tests {
 			pretty_print_response_content: Setting::U32(0),
 			accept_invalid_certs: Setting::U32(0),
 			accept_invalid_hostnames: Setting::U32(0),
+			..Default::default()
 		};
 		settings.normalize();
 
@@ -416,6 +575,7 @@ mod tests {
 			pretty_print_response_content: Setting::U32(1),
 			accept_invalid_certs: Setting::U32(1),
 			accept_invalid_hostnames: Setting::U32(1),
+			..Default::default()
 		};
 		settings.normalize();
 
@@ -437,6 +597,7 @@ mod tests {
 			pretty_print_response_content: Setting::U32(200),
 			accept_invalid_certs: Setting::U32(300),
 			accept_invalid_hostnames: Setting::U32(400),
+			..Default::default()
 		};
 		settings.normalize();
 
@@ -457,8 +618,10 @@ mod tests {
 
 	#[test]
 	fn normalize_coerces_bool_on_timeout_to_default() {
-		let mut settings = RequestSettings::default();
-		settings.timeout = Setting::Bool(true);
+		let mut settings = RequestSettings {
+			timeout: Setting::Bool(true),
+			..Default::default()
+		};
 		settings.normalize();
 
 		assert_eq!(settings.timeout.as_u32(), Some(30000));
@@ -474,6 +637,7 @@ mod tests {
 			pretty_print_response_content: Setting::Bool(true),
 			accept_invalid_certs: Setting::Bool(true),
 			accept_invalid_hostnames: Setting::Bool(false),
+			..Default::default()
 		};
 		settings.normalize();
 
@@ -498,6 +662,7 @@ mod tests {
 			pretty_print_response_content: Setting::Bool(true),
 			accept_invalid_certs: Setting::Bool(true),
 			accept_invalid_hostnames: Setting::Bool(false),
+			..Default::default()
 		};
 
 		let json = serde_json::to_string(&original).unwrap();
@@ -551,6 +716,29 @@ mod tests {
 		assert_eq!(settings.timeout.as_u32(), Some(30000));
 	}
 
+	#[test]
+	fn max_redirects_has_its_own_range() {
+		assert_eq!(u32_setting_range(SETTING_MAX_REDIRECTS), (1, 0, 50));
+		assert_eq!(u32_setting_range(SETTING_TIMEOUT), (100, 100, 100000));
+	}
+
+	#[test]
+	fn json_without_max_redirects_uses_the_default() {
+		let json = r#"{
+			"use_config_proxy": true,
+			"allow_redirects": true,
+			"timeout": 5000,
+			"store_received_cookies": true,
+			"pretty_print_response_content": true,
+			"accept_invalid_certs": false,
+			"accept_invalid_hostnames": false
+		}"#;
+
+		let settings: RequestSettings = serde_json::from_str(json).unwrap();
+
+		assert_eq!(settings.max_redirects.as_u32(), Some(10));
+	}
+
 	// ── YAML roundtrip ──────────────────────────────────────────
 
 	#[test]
@@ -563,6 +751,7 @@ mod tests {
 			pretty_print_response_content: Setting::Bool(true),
 			accept_invalid_certs: Setting::Bool(true),
 			accept_invalid_hostnames: Setting::Bool(false),
+			..Default::default()
 		};
 
 		let yaml = serde_yaml_ng::to_string(&original).unwrap();
@@ -651,4 +840,92 @@ accept_invalid_hostnames: false
 			Setting::U32(30000)
 		));
 	}
+
+	// ── TLS ─────────────────────────────────────────────────────
+
+	#[test]
+	fn from_str_keeps_paths_as_text() {
+		assert!(matches!(
+			Setting::from_str("certs/client.pem"),
+			Ok(Setting::Text(path)) if path == "certs/client.pem"
+		));
+	}
+
+	#[test]
+	fn tls_settings_roundtrip_through_vec() {
+		let original = RequestSettings {
+			tls: TlsSettings {
+				client_certificate: Some(String::from("client.p12")),
+				client_key: None,
+				client_certificate_password: Some(String::from("{{P12_PASSWORD}}")),
+				ca_certificates: vec![String::from("ca.pem"), String::from("other-ca.pem")],
+			},
+			..Default::default()
+		};
+
+		let mut restored = RequestSettings::default();
+		restored.update_from_vec(&original.to_vec());
+
+		assert_eq!(restored.tls, original.tls);
+	}
+
+	#[test]
+	fn tls_settings_are_omitted_when_empty() {
+		let json = serde_json::to_string(&RequestSettings::default()).unwrap();
+		assert!(!json.contains("tls"));
+
+		let yaml = "
+use_config_proxy: true
+allow_redirects: true
+timeout: 5000
+store_received_cookies: true
+pretty_print_response_content: true
+accept_invalid_certs: false
+accept_invalid_hostnames: false
+tls:
+  client_certificate: client.pem
+  ca_certificates:
+    - ca.pem
+";
+		let settings: RequestSettings = serde_yaml_ng::from_str(yaml).unwrap();
+
+		assert_eq!(
+			settings.tls.client_certificate.as_deref(),
+			Some("client.pem")
+		);
+		assert_eq!(settings.tls.ca_certificates, vec!["ca.pem"]);
+	}
+
+	#[test]
+	fn request_tls_settings_fall_back_to_collection_ones() {
+		let collection = TlsSettings {
+			client_certificate: Some(String::from("collection.pem")),
+			client_key: Some(String::from("collection.key")),
+			client_certificate_password: Some(String::from("collection password")),
+			ca_certificates: vec![String::from("ca.pem")],
+		};
+
+		let request = TlsSettings {
+			ca_certificates: vec![String::from("ca.pem"), String::from("request-ca.pem")],
+			..Default::default()
+		};
+		let merged = request.or_collection(&collection);
+		assert_eq!(merged.client_certificate.as_deref(), Some("collection.pem"));
+		assert_eq!(merged.client_key.as_deref(), Some("collection.key"));
+		assert_eq!(
+			merged.client_certificate_password.as_deref(),
+			Some("collection password")
+		);
+		assert_eq!(merged.ca_certificates, vec!["ca.pem", "request-ca.pem"]);
+
+		// The request certificate never gets paired with the collection key
+		let request = TlsSettings {
+			client_certificate: Some(String::from("request.pem")),
+			..Default::default()
+		};
+		let merged = request.or_collection(&collection);
+		assert_eq!(merged.client_certificate.as_deref(), Some("request.pem"));
+		assert_eq!(merged.client_key, None);
+		assert_eq!(merged.client_certificate_password, None);
+	}
 }
@@ -24,6 +24,10 @@ pub enum AppState {
 	#[strum(to_string = "Displaying logs")]
 	DisplayingLogs,
 
+	/* History */
+	#[strum(to_string = "Displaying history")]
+	DisplayingHistory,
+
 	/* Collections */
 	#[strum(to_string = "Choosing an element to create")]
 	ChoosingElementToCreate,
@@ -56,6 +60,9 @@ pub enum AppState {
 	#[strum(to_string = "Renaming folder")]
 	RenamingFolder,
 
+	#[strum(to_string = "Editing auth, headers and scripts")]
+	EditingCollectionProperties,
+
 	/* Request */
 	#[strum(to_string = "Request menu")]
 	SelectedRequest,
@@ -99,6 +106,45 @@ pub enum AppState {
 	#[strum(to_string = "Editing request digest opaque")]
 	EditingRequestAuthDigestOpaque,
 
+	#[strum(to_string = "Editing request OAuth2 token URL")]
+	EditingRequestAuthOAuth2TokenUrl,
+
+	#[strum(to_string = "Editing request OAuth2 authorization URL")]
+	EditingRequestAuthOAuth2AuthUrl,
+
+	#[strum(to_string = "Editing request OAuth2 client ID")]
+	EditingRequestAuthOAuth2ClientId,
+
+	#[strum(to_string = "Editing request OAuth2 client secret")]
+	EditingRequestAuthOAuth2ClientSecret,
+
+	#[strum(to_string = "Editing request OAuth2 scope")]
+	EditingRequestAuthOAuth2Scope,
+
+	#[strum(to_string = "Editing request OAuth2 username")]
+	EditingRequestAuthOAuth2Username,
+
+	#[strum(to_string = "Editing request OAuth2 password")]
+	EditingRequestAuthOAuth2Password,
+
+	#[strum(to_string = "Editing request OAuth2 refresh token")]
+	EditingRequestAuthOAuth2RefreshToken,
+
+	#[strum(to_string = "Editing request AWS SigV4 access key ID")]
+	EditingRequestAuthAwsSigV4AccessKeyId,
+
+	#[strum(to_string = "Editing request AWS SigV4 secret access key")]
+	EditingRequestAuthAwsSigV4SecretAccessKey,
+
+	#[strum(to_string = "Editing request AWS SigV4 region")]
+	EditingRequestAuthAwsSigV4Region,
+
+	#[strum(to_string = "Editing request AWS SigV4 service")]
+	EditingRequestAuthAwsSigV4Service,
+
+	#[strum(to_string = "Editing request AWS SigV4 session token")]
+	EditingRequestAuthAwsSigV4SessionToken,
+
 	#[strum(to_string = "Editing request header")]
 	EditingRequestHeader,
 
@@ -132,6 +178,9 @@ pub enum AppState {
 	#[strum(to_string = "Editing gRPC message")]
 	EditingGrpcMessage,
 
+	#[strum(to_string = "Choosing gRPC method")]
+	ChoosingGrpcMethod,
+
 	#[strum(to_string = "Editing pre-request script")]
 	EditingPreRequestScript,
 
@@ -141,6 +190,9 @@ pub enum AppState {
 	#[strum(to_string = "Editing request settings")]
 	EditingRequestSettings,
 
+	#[strum(to_string = "Editing request setting")]
+	EditingRequestSettingText,
+
 	#[strum(to_string = "Choosing request export format")]
 	ChoosingRequestExportFormat,
 
@@ -151,6 +203,15 @@ pub enum AppState {
 	#[strum(to_string = "Selecting response body")]
 	SelectingResponseBody,
 
+	#[strum(to_string = "Searching response body")]
+	SearchingResponseBody,
+
+	#[strum(to_string = "Editing response body filter")]
+	EditingResponseBodyFilter,
+
+	#[strum(to_string = "Saving response body")]
+	SavingResponseBody,
+
 	/* Theme */
 	#[strum(to_string = "Choosing theme")]
 	ChoosingTheme,
@@ -130,6 +130,16 @@ impl AppState {
 						"Display logs",
 						None,
 					)),
+					DisplayHistory(EventKeyBinding::new(
+						vec![key_bindings.main_menu.display_history],
+						"Display history",
+						None,
+					)),
+					EditElementProperties(EventKeyBinding::new(
+						vec![key_bindings.main_menu.edit_properties],
+						"Edit auth, headers and scripts",
+						None,
+					)),
 					DisplayThemePicker(EventKeyBinding::new(
 						vec![key_bindings.main_menu.display_theme_picker],
 						"Theme picker",
@@ -142,25 +152,42 @@ impl AppState {
 				base_events
 			}
 
-			DisplayingEnvEditor => list_view_events(
-				&key_bindings,
-				EditEnvVariable,
-				"Edit env variable",
-				EnvVariablesMoveUp,
-				EnvVariablesMoveDown,
-				EnvVariablesMoveLeft,
-				EnvVariablesMoveRight,
-				Some((
-					CreateEnvVariable,
-					"Create env variable",
-					Some("Create variable"),
-				)),
-				Some((
-					DeleteEnvVariable,
-					"Delete env variable",
-					Some("Delete variable"),
-				)),
-			),
+			DisplayingEnvEditor => {
+				let mut events = list_view_events(
+					&key_bindings,
+					EditEnvVariable,
+					"Edit env variable",
+					EnvVariablesMoveUp,
+					EnvVariablesMoveDown,
+					EnvVariablesMoveLeft,
+					EnvVariablesMoveRight,
+					Some((
+						CreateEnvVariable,
+						"Create env variable",
+						Some("Create variable"),
+					)),
+					Some((
+						DeleteEnvVariable,
+						"Delete env variable",
+						Some("Delete variable"),
+					)),
+				);
+
+				events.extend([
+					ToggleEnvVariableSecret(EventKeyBinding::new(
+						vec![key_bindings.generic.list_and_table_actions.toggle_element],
+						"Toggle env variable secret",
+						Some("Secret"),
+					)),
+					RevealEnvSecrets(EventKeyBinding::new(
+						vec![key_bindings.generic.list_and_table_actions.reveal_secrets],
+						"Show or mask secret values",
+						Some("Reveal"),
+					)),
+				]);
+
+				events
+			}
 
 			EditingEnvVariable => simple_text_input_events(
 				&key_bindings,
@@ -206,6 +233,39 @@ impl AppState {
 				None,
 			),
 
+			DisplayingHistory => vec![
+				GoBackToLastState(EventKeyBinding::new(
+					vec![key_bindings.generic.navigation.go_back],
+					"Quit",
+					Some("Quit"),
+				)),
+				HistoryMoveUp(EventKeyBinding::new(
+					vec![key_bindings.generic.navigation.move_cursor_up],
+					"Previous entry",
+					Some("Up"),
+				)),
+				HistoryMoveDown(EventKeyBinding::new(
+					vec![key_bindings.generic.navigation.move_cursor_down],
+					"Next entry",
+					Some("Down"),
+				)),
+				HistoryToggleDiff(EventKeyBinding::new(
+					vec![key_bindings.generic.navigation.select],
+					"Toggle diff with previous entry",
+					Some("Diff"),
+				)),
+				ScrollHistoryUp(EventKeyBinding::new(
+					vec![key_bindings.request_selected.result_tabs.scroll_up],
+					"Scroll details up",
+					None,
+				)),
+				ScrollHistoryDown(EventKeyBinding::new(
+					vec![key_bindings.request_selected.result_tabs.scroll_down],
+					"Scroll details down",
+					None,
+				)),
+			],
+
 			ChoosingElementToCreate => vec![
 				GoBackToLastState(EventKeyBinding::new(
 					vec![key_bindings.generic.navigation.go_back],
@@ -334,6 +394,15 @@ impl AppState {
 				false,
 			),
 
+			EditingCollectionProperties => simple_text_input_events(
+				&key_bindings,
+				ModifyCollectionProperties,
+				CancelEditCollectionProperties,
+				KeyEventEditCollectionProperties,
+				false,
+				false,
+			),
+
 			SelectedRequest => selected_request_events(
 				&key_bindings,
 				request_view,
@@ -460,6 +529,123 @@ impl AppState {
 				false,
 			),
 
+			EditingRequestAuthOAuth2TokenUrl => simple_text_input_events(
+				&key_bindings,
+				ModifyRequestAuthOAuth2TokenUrl,
+				CancelEditRequestAuthOAuth2TokenUrl,
+				KeyEventEditRequestAuthOAuth2TokenUrl,
+				true,
+				false,
+			),
+
+			EditingRequestAuthOAuth2AuthUrl => simple_text_input_events(
+				&key_bindings,
+				ModifyRequestAuthOAuth2AuthUrl,
+				CancelEditRequestAuthOAuth2AuthUrl,
+				KeyEventEditRequestAuthOAuth2AuthUrl,
+				true,
+				false,
+			),
+
+			EditingRequestAuthOAuth2ClientId => simple_text_input_events(
+				&key_bindings,
+				ModifyRequestAuthOAuth2ClientId,
+				CancelEditRequestAuthOAuth2ClientId,
+				KeyEventEditRequestAuthOAuth2ClientId,
+				true,
+				false,
+			),
+
+			EditingRequestAuthOAuth2ClientSecret => simple_text_input_events(
+				&key_bindings,
+				ModifyRequestAuthOAuth2ClientSecret,
+				CancelEditRequestAuthOAuth2ClientSecret,
+				KeyEventEditRequestAuthOAuth2ClientSecret,
+				true,
+				false,
+			),
+
+			EditingRequestAuthOAuth2Scope => simple_text_input_events(
+				&key_bindings,
+				ModifyRequestAuthOAuth2Scope,
+				CancelEditRequestAuthOAuth2Scope,
+				KeyEventEditRequestAuthOAuth2Scope,
+				true,
+				false,
+			),
+
+			EditingRequestAuthOAuth2Username => simple_text_input_events(
+				&key_bindings,
+				ModifyRequestAuthOAuth2Username,
+				CancelEditRequestAuthOAuth2Username,
+				KeyEventEditRequestAuthOAuth2Username,
+				true,
+				false,
+			),
+
+			EditingRequestAuthOAuth2Password => simple_text_input_events(
+				&key_bindings,
+				ModifyRequestAuthOAuth2Password,
+				CancelEditRequestAuthOAuth2Password,
+				KeyEventEditRequestAuthOAuth2Password,
+				true,
+				false,
+			),
+
+			EditingRequestAuthOAuth2RefreshToken => simple_text_input_events(
+				&key_bindings,
+				ModifyRequestAuthOAuth2RefreshToken,
+				CancelEditRequestAuthOAuth2RefreshToken,
+				KeyEventEditRequestAuthOAuth2RefreshToken,
+				true,
+				false,
+			),
+
+			EditingRequestAuthAwsSigV4AccessKeyId => simple_text_input_events(
+				&key_bindings,
+				ModifyRequestAuthAwsSigV4AccessKeyId,
+				CancelEditRequestAuthAwsSigV4AccessKeyId,
+				KeyEventEditRequestAuthAwsSigV4AccessKeyId,
+				true,
+				false,
+			),
+
+			EditingRequestAuthAwsSigV4SecretAccessKey => simple_text_input_events(
+				&key_bindings,
+				ModifyRequestAuthAwsSigV4SecretAccessKey,
+				CancelEditRequestAuthAwsSigV4SecretAccessKey,
+				KeyEventEditRequestAuthAwsSigV4SecretAccessKey,
+				true,
+				false,
+			),
+
+			EditingRequestAuthAwsSigV4Region => simple_text_input_events(
+				&key_bindings,
+				ModifyRequestAuthAwsSigV4Region,
+				CancelEditRequestAuthAwsSigV4Region,
+				KeyEventEditRequestAuthAwsSigV4Region,
+				true,
+				false,
+			),
+
+			EditingRequestAuthAwsSigV4Service => simple_text_input_events(
+				&key_bindings,
+				ModifyRequestAuthAwsSigV4Service,
+				CancelEditRequestAuthAwsSigV4Service,
+				KeyEventEditRequestAuthAwsSigV4Service,
+				true,
+				false,
+			),
+
+			EditingRequestAuthAwsSigV4SessionToken => simple_text_input_events(
+				&key_bindings,
+				ModifyRequestAuthAwsSigV4SessionToken,
+				CancelEditRequestAuthAwsSigV4SessionToken,
+				KeyEventEditRequestAuthAwsSigV4SessionToken,
+				true,
+				false,
+			),
+
 			EditingRequestHeader => simple_text_input_events(
 				&key_bindings,
 				ModifyRequestHeader,
@@ -611,6 +797,15 @@ impl AppState {
 				)),
 			],
 
+			EditingRequestSettingText => simple_text_input_events(
+				&key_bindings,
+				ModifyRequestSettingText,
+				CancelEditRequestSettingText,
+				KeyEventEditRequestSettingText,
+				true,
+				false,
+			),
+
 			ChoosingRequestExportFormat => vec![
 				GoBackToRequestMenu(EventKeyBinding::new(
 					vec![key_bindings.generic.navigation.go_back],
@@ -662,6 +857,85 @@ impl AppState {
 				false,
 			),
 
+			SearchingResponseBody => text_input_events(
+				vec![
+					ConfirmResponseBodySearch(EventKeyBinding::new(
+						vec![key_bindings.generic.text_input.save_and_quit_single_line],
+						"Confirm",
+						Some("Confirm"),
+					)),
+					CancelResponseBodySearch(EventKeyBinding::new(
+						vec![key_bindings.generic.text_input.quit_without_saving],
+						"Cancel",
+						Some("Cancel"),
+					)),
+					NextResponseBodySearchMatch(EventKeyBinding::new(
+						vec![
+							key_bindings.generic.navigation.move_cursor_down,
+							key_bindings.request_selected.result_tabs.next_search_match,
+						],
+						"Next match",
+						Some("Next"),
+					)),
+					PreviousResponseBodySearchMatch(EventKeyBinding::new(
+						vec![
+							key_bindings.generic.navigation.move_cursor_up,
+							key_bindings
+								.request_selected
+								.result_tabs
+								.previous_search_match,
+						],
+						"Previous match",
+						Some("Previous"),
+					)),
+					KeyEventSearchResponseBody(EventKeyBinding::new(vec![], "Any input", None)),
+				],
+				&key_bindings,
+				true,
+				true,
+			),
+
+			EditingResponseBodyFilter => simple_text_input_events(
+				&key_bindings,
+				ModifyResponseBodyFilter,
+				CancelEditResponseBodyFilter,
+				KeyEventEditResponseBodyFilter,
+				true,
+				false,
+			),
+
+			SavingResponseBody => simple_text_input_events(
+				&key_bindings,
+				ConfirmSaveResponseBody,
+				CancelSaveResponseBody,
+				KeyEventSaveResponseBody,
+				true,
+				false,
+			),
+
+			ChoosingGrpcMethod => vec![
+				GoBackToRequestMenu(EventKeyBinding::new(
+					vec![key_bindings.generic.navigation.go_back],
+					"Quit",
+					Some("Quit"),
+				)),
+				GrpcMethodPickerMoveUp(EventKeyBinding::new(
+					vec![key_bindings.generic.navigation.move_cursor_up],
+					"Previous method",
+					Some("Up"),
+				)),
+				GrpcMethodPickerMoveDown(EventKeyBinding::new(
+					vec![key_bindings.generic.navigation.move_cursor_down],
+					"Next method",
+					Some("Down"),
+				)),
+				SelectGrpcMethod(EventKeyBinding::new(
+					vec![key_bindings.generic.navigation.select],
+					"Select method",
+					Some("Select"),
+				)),
+			],
+
 			ChoosingTheme => vec![
 				GoBackToLastState(EventKeyBinding::new(
 					vec![key_bindings.generic.navigation.go_back],
@@ -123,6 +123,11 @@ fn selected_request_base_events(
 			"Display logs",
 			None,
 		)),
+		DisplayHistory(EventKeyBinding::new(
+			vec![key_bindings.main_menu.display_history],
+			"Display history",
+			None,
+		)),
 		ExportRequest(EventKeyBinding::new(
 			vec![key_bindings.request_selected.export_request],
 			"Export request",
@@ -181,9 +186,16 @@ fn selected_request_param_tab_events(
 				// GraphQL requests don't need method or body type toggles
 				vec![]
 			}
-			Protocol::GrpcRequest(_) => {
+			Protocol::GrpcRequest(grpc_request) => {
 				// gRPC requests don't need method or body type toggles
-				vec![]
+				match grpc_request.can_send_messages() {
+					true => vec![HalfCloseGrpcStream(EventKeyBinding::new(
+						vec![key_bindings.request_selected.param_tabs.half_close_stream],
+						"Half-close stream",
+						Some("Half-close"),
+					))],
+					false => vec![],
+				}
 			}
 		};
 
@@ -396,11 +408,33 @@ fn selected_request_param_tab_events(
 			))]
 		}
 		RequestParamsTabs::GrpcProtoFile => {
-			vec![EditGrpcProtoFile(EventKeyBinding::new(
-				vec![key_bindings.generic.list_and_table_actions.edit_element],
-				"Edit proto file",
-				None,
-			))]
+			vec![
+				EditGrpcProtoFile(EventKeyBinding::new(
+					vec![key_bindings.generic.list_and_table_actions.edit_element],
+					"Edit proto file",
+					None,
+				)),
+				ToggleGrpcReflection(EventKeyBinding::new(
+					vec![
+						key_bindings
+							.request_selected
+							.param_tabs
+							.toggle_grpc_reflection,
+					],
+					"Toggle server reflection",
+					Some("Reflection"),
+				)),
+				ChangeGrpcCompression(EventKeyBinding::new(
+					vec![
+						key_bindings
+							.request_selected
+							.param_tabs
+							.change_grpc_compression,
+					],
+					"Change message compression",
+					Some("Compression"),
+				)),
+			]
 		}
 		RequestParamsTabs::GrpcService => {
 			vec![
@@ -414,6 +448,11 @@ fn selected_request_param_tab_events(
 					"Edit method",
 					None,
 				)),
+				DisplayGrpcMethodPicker(EventKeyBinding::new(
+					vec![key_bindings.request_selected.param_tabs.pick_grpc_method],
+					"Pick service and method",
+					Some("Pick method"),
+				)),
 			]
 		}
 		RequestParamsTabs::GrpcMessage => {
@@ -483,5 +522,45 @@ fn selected_request_result_tab_events(key_bindings: &KeyBindings) -> Vec<AppEven
 			"Select response body",
 			Some("Select"),
 		)),
+		SearchResponseBody(EventKeyBinding::new(
+			vec![
+				key_bindings
+					.request_selected
+					.result_tabs
+					.search_response_body,
+			],
+			"Search response body",
+			None,
+		)),
+		NextResponseBodySearchMatch(EventKeyBinding::new(
+			vec![key_bindings.request_selected.result_tabs.next_search_match],
+			"Next search match",
+			None,
+		)),
+		PreviousResponseBodySearchMatch(EventKeyBinding::new(
+			vec![
+				key_bindings
+					.request_selected
+					.result_tabs
+					.previous_search_match,
+			],
+			"Previous search match",
+			None,
+		)),
+		FilterResponseBody(EventKeyBinding::new(
+			vec![
+				key_bindings
+					.request_selected
+					.result_tabs
+					.filter_response_body,
+			],
+			"Filter response body",
+			None,
+		)),
+		SaveResponseBody(EventKeyBinding::new(
+			vec![key_bindings.request_selected.result_tabs.save_response_body],
+			"Save response body to a file",
+			None,
+		)),
 	]
 }
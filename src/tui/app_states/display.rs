@@ -8,6 +8,7 @@ use std::sync::LazyLock;
 
 use crate::app::App;
 use crate::app::files::theme::THEME;
+use crate::models::collection::TreeElement;
 use crate::tui::app_states::AppState::*;
 use crate::tui::events::AppEvent;
 use crate::tui::events::AppEvent::*;
@@ -74,7 +75,8 @@ impl App<'_> {
 			| CreatingNewFolder
 			| DisplayingCookies
 			| EditingCookies
-			| DisplayingLogs => Line::from(self.state.to_string())
+			| DisplayingLogs
+			| DisplayingHistory => Line::from(self.state.to_string())
 				.fg(THEME.read().ui.font_color)
 				.bg(THEME.read().ui.main_background_color),
 
@@ -93,11 +95,12 @@ impl App<'_> {
 			}
 
 			DeletingFolder | RenamingFolder => {
-				let selected = self.collections_tree.state.selected();
-				let collection_index = selected[0];
-				let folder_index = selected[1];
-				let folder_name =
-					&self.core.collections[collection_index].folders[folder_index].name;
+				let folder_name = match self.tree_selection() {
+					Some((collection_index, Some(TreeElement::Folder(folder_path)))) => {
+						self.core.collections[collection_index].folder_path_name(&folder_path)
+					}
+					_ => String::from("Unknown"),
+				};
 
 				Line::from(vec![
 					Span::raw("Folder > ").fg(THEME.read().ui.secondary_foreground_color),
@@ -110,28 +113,15 @@ impl App<'_> {
 			}
 
 			DeletingRequest | RenamingRequest => {
-				let selected = self.collections_tree.state.selected();
-				let request_name = match selected.len() {
-					2 => {
-						let collection_index = selected[0];
-						let child_index = selected[1];
-						let folder_count = self.core.collections[collection_index].folders.len();
-						let request_index = child_index - folder_count;
-						self.core.collections[collection_index].requests[request_index]
-							.read()
-							.name
-							.clone()
-					}
-					3 => {
-						let collection_index = selected[0];
-						let folder_index = selected[1];
-						let request_index = selected[2];
-						self.core.collections[collection_index].folders[folder_index].requests
-							[request_index]
-							.read()
-							.name
-							.clone()
-					}
+				let request_name = match self.tree_selection() {
+					Some((
+						collection_index,
+						Some(TreeElement::Request(folder_path, request_index)),
+					)) => self.core.collections[collection_index].requests_in(&folder_path)
+						[request_index]
+						.read()
+						.name
+						.clone(),
 					_ => String::from("Unknown"),
 				};
 
@@ -181,6 +171,19 @@ impl App<'_> {
 			| EditingRequestAuthDigestRealm
 			| EditingRequestAuthDigestNonce
 			| EditingRequestAuthDigestOpaque
+			| EditingRequestAuthOAuth2TokenUrl
+			| EditingRequestAuthOAuth2AuthUrl
+			| EditingRequestAuthOAuth2ClientId
+			| EditingRequestAuthOAuth2ClientSecret
+			| EditingRequestAuthOAuth2Scope
+			| EditingRequestAuthOAuth2Username
+			| EditingRequestAuthOAuth2Password
+			| EditingRequestAuthOAuth2RefreshToken
+			| EditingRequestAuthAwsSigV4AccessKeyId
+			| EditingRequestAuthAwsSigV4SecretAccessKey
+			| EditingRequestAuthAwsSigV4Region
+			| EditingRequestAuthAwsSigV4Service
+			| EditingRequestAuthAwsSigV4SessionToken
 			| EditingRequestHeader
 			| EditingRequestBodyTable
 			| EditingRequestBodyFile
@@ -192,12 +195,17 @@ impl App<'_> {
 			| EditingGrpcService
 			| EditingGrpcMethod
 			| EditingGrpcMessage
+			| ChoosingGrpcMethod
 			| EditingPreRequestScript
 			| EditingPostRequestScript
 			| EditingRequestSettings
+			| EditingRequestSettingText
 			| ChoosingRequestExportFormat
 			| DisplayingRequestExport
-			| SelectingResponseBody => {
+			| SelectingResponseBody
+			| SearchingResponseBody
+			| EditingResponseBodyFilter
+			| SavingResponseBody => {
 				let Some(local_selected_request) = self.get_selected_request_as_local() else {
 					return Line::default();
 				};
@@ -222,6 +230,29 @@ impl App<'_> {
 				}
 			}
 
+			EditingCollectionProperties => {
+				let (element_type, element_name) = match self.selected_collection_or_folder() {
+					Some((collection_index, folder_path)) if !folder_path.is_empty() => (
+						"Folder > ",
+						self.core.collections[collection_index].folder_path_name(&folder_path),
+					),
+					Some((collection_index, _)) => (
+						"Collection > ",
+						self.core.collections[collection_index].name.clone(),
+					),
+					None => ("Collection > ", String::from("Unknown")),
+				};
+
+				Line::from(vec![
+					Span::raw(element_type).fg(THEME.read().ui.secondary_foreground_color),
+					Span::raw(format!("{} > ", element_name))
+						.fg(THEME.read().ui.secondary_foreground_color),
+					Span::raw(self.state.to_string())
+						.fg(THEME.read().ui.font_color)
+						.bg(THEME.read().ui.main_background_color),
+				])
+			}
+
 			ChoosingTheme => Line::from(self.state.to_string())
 				.fg(THEME.read().ui.font_color)
 				.bg(THEME.read().ui.main_background_color),
@@ -239,6 +270,7 @@ impl App<'_> {
 				| RenamingCollection
 				| RenamingRequest
 				| RenamingFolder
+				| EditingCollectionProperties
 				| EditingRequestUrl
 				| EditingRequestParam
 				| EditingRequestAuthBasicUsername
@@ -259,7 +291,11 @@ impl App<'_> {
 				| EditingPreRequestScript
 				| EditingPostRequestScript
 				| EditingRequestSettings
+				| EditingRequestSettingText
 				| SelectingResponseBody
+				| SearchingResponseBody
+				| EditingResponseBodyFilter
+				| SavingResponseBody
 		)
 	}
 }
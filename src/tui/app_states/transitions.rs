@@ -8,7 +8,8 @@ pub fn next_app_state(app_state: &AppState) -> AppState {
 		EditingEnvVariable => DisplayingCookies,
 		DisplayingCookies => EditingCookies,
 		EditingCookies => DisplayingLogs,
-		DisplayingLogs => ChoosingElementToCreate,
+		DisplayingLogs => DisplayingHistory,
+		DisplayingHistory => ChoosingElementToCreate,
 		ChoosingElementToCreate => CreatingNewCollection,
 		CreatingNewCollection => CreatingNewRequest,
 		CreatingNewRequest => DeletingCollection,
@@ -18,7 +19,8 @@ pub fn next_app_state(app_state: &AppState) -> AppState {
 		RenamingRequest => CreatingNewFolder,
 		CreatingNewFolder => DeletingFolder,
 		DeletingFolder => RenamingFolder,
-		RenamingFolder => SelectedRequest,
+		RenamingFolder => EditingCollectionProperties,
+		EditingCollectionProperties => SelectedRequest,
 		SelectedRequest => EditingRequestUrl,
 		EditingRequestUrl => EditingRequestParam,
 		EditingRequestParam => EditingRequestAuthBasicUsername,
@@ -32,7 +34,20 @@ pub fn next_app_state(app_state: &AppState) -> AppState {
 		EditingRequestAuthDigestDomains => EditingRequestAuthDigestRealm,
 		EditingRequestAuthDigestRealm => EditingRequestAuthDigestNonce,
 		EditingRequestAuthDigestNonce => EditingRequestAuthDigestOpaque,
-		EditingRequestAuthDigestOpaque => EditingRequestHeader,
+		EditingRequestAuthDigestOpaque => EditingRequestAuthOAuth2TokenUrl,
+		EditingRequestAuthOAuth2TokenUrl => EditingRequestAuthOAuth2AuthUrl,
+		EditingRequestAuthOAuth2AuthUrl => EditingRequestAuthOAuth2ClientId,
+		EditingRequestAuthOAuth2ClientId => EditingRequestAuthOAuth2ClientSecret,
+		EditingRequestAuthOAuth2ClientSecret => EditingRequestAuthOAuth2Scope,
+		EditingRequestAuthOAuth2Scope => EditingRequestAuthOAuth2Username,
+		EditingRequestAuthOAuth2Username => EditingRequestAuthOAuth2Password,
+		EditingRequestAuthOAuth2Password => EditingRequestAuthOAuth2RefreshToken,
+		EditingRequestAuthOAuth2RefreshToken => EditingRequestAuthAwsSigV4AccessKeyId,
+		EditingRequestAuthAwsSigV4AccessKeyId => EditingRequestAuthAwsSigV4SecretAccessKey,
+		EditingRequestAuthAwsSigV4SecretAccessKey => EditingRequestAuthAwsSigV4Region,
+		EditingRequestAuthAwsSigV4Region => EditingRequestAuthAwsSigV4Service,
+		EditingRequestAuthAwsSigV4Service => EditingRequestAuthAwsSigV4SessionToken,
+		EditingRequestAuthAwsSigV4SessionToken => EditingRequestHeader,
 		EditingRequestHeader => EditingRequestBodyTable,
 		EditingRequestBodyTable => EditingRequestBodyFile,
 		EditingRequestBodyFile => EditingRequestBodyString,
@@ -43,13 +58,18 @@ pub fn next_app_state(app_state: &AppState) -> AppState {
 		EditingGrpcProtoFile => EditingGrpcService,
 		EditingGrpcService => EditingGrpcMethod,
 		EditingGrpcMethod => EditingGrpcMessage,
-		EditingGrpcMessage => EditingPreRequestScript,
+		EditingGrpcMessage => ChoosingGrpcMethod,
+		ChoosingGrpcMethod => EditingPreRequestScript,
 		EditingPreRequestScript => EditingPostRequestScript,
 		EditingPostRequestScript => EditingRequestSettings,
-		EditingRequestSettings => ChoosingRequestExportFormat,
+		EditingRequestSettings => EditingRequestSettingText,
+		EditingRequestSettingText => ChoosingRequestExportFormat,
 		ChoosingRequestExportFormat => DisplayingRequestExport,
 		DisplayingRequestExport => SelectingResponseBody,
-		SelectingResponseBody => ChoosingTheme,
+		SelectingResponseBody => SearchingResponseBody,
+		SearchingResponseBody => EditingResponseBodyFilter,
+		EditingResponseBodyFilter => SavingResponseBody,
+		SavingResponseBody => ChoosingTheme,
 		ChoosingTheme => Normal,
 	}
 }
@@ -62,14 +82,16 @@ pub fn previous_app_state(app_state: &AppState) -> AppState {
 		DisplayingCookies => EditingEnvVariable,
 		EditingCookies => DisplayingCookies,
 		DisplayingLogs => EditingCookies,
-		ChoosingElementToCreate => DisplayingLogs,
+		DisplayingHistory => DisplayingLogs,
+		ChoosingElementToCreate => DisplayingHistory,
 		CreatingNewCollection => ChoosingElementToCreate,
 		CreatingNewRequest => CreatingNewCollection,
 		DeletingCollection => CreatingNewRequest,
 		DeletingRequest => DeletingCollection,
 		RenamingCollection => DeletingRequest,
 		RenamingRequest => RenamingCollection,
-		SelectedRequest => RenamingFolder,
+		SelectedRequest => EditingCollectionProperties,
+		EditingCollectionProperties => RenamingFolder,
 		RenamingFolder => DeletingFolder,
 		DeletingFolder => CreatingNewFolder,
 		CreatingNewFolder => RenamingRequest,
@@ -86,7 +108,20 @@ pub fn previous_app_state(app_state: &AppState) -> AppState {
 		EditingRequestAuthDigestRealm => EditingRequestAuthDigestDomains,
 		EditingRequestAuthDigestNonce => EditingRequestAuthDigestRealm,
 		EditingRequestAuthDigestOpaque => EditingRequestAuthDigestNonce,
-		EditingRequestHeader => EditingRequestAuthDigestOpaque,
+		EditingRequestAuthOAuth2TokenUrl => EditingRequestAuthDigestOpaque,
+		EditingRequestAuthOAuth2AuthUrl => EditingRequestAuthOAuth2TokenUrl,
+		EditingRequestAuthOAuth2ClientId => EditingRequestAuthOAuth2AuthUrl,
+		EditingRequestAuthOAuth2ClientSecret => EditingRequestAuthOAuth2ClientId,
+		EditingRequestAuthOAuth2Scope => EditingRequestAuthOAuth2ClientSecret,
+		EditingRequestAuthOAuth2Username => EditingRequestAuthOAuth2Scope,
+		EditingRequestAuthOAuth2Password => EditingRequestAuthOAuth2Username,
+		EditingRequestAuthOAuth2RefreshToken => EditingRequestAuthOAuth2Password,
+		EditingRequestAuthAwsSigV4AccessKeyId => EditingRequestAuthOAuth2RefreshToken,
+		EditingRequestAuthAwsSigV4SecretAccessKey => EditingRequestAuthAwsSigV4AccessKeyId,
+		EditingRequestAuthAwsSigV4Region => EditingRequestAuthAwsSigV4SecretAccessKey,
+		EditingRequestAuthAwsSigV4Service => EditingRequestAuthAwsSigV4Region,
+		EditingRequestAuthAwsSigV4SessionToken => EditingRequestAuthAwsSigV4Service,
+		EditingRequestHeader => EditingRequestAuthAwsSigV4SessionToken,
 		EditingRequestBodyTable => EditingRequestHeader,
 		EditingRequestBodyFile => EditingRequestBodyTable,
 		EditingRequestBodyString => EditingRequestBodyFile,
@@ -97,12 +132,17 @@ pub fn previous_app_state(app_state: &AppState) -> AppState {
 		EditingGrpcService => EditingGrpcProtoFile,
 		EditingGrpcMethod => EditingGrpcService,
 		EditingGrpcMessage => EditingGrpcMethod,
-		EditingPreRequestScript => EditingGrpcMessage,
+		ChoosingGrpcMethod => EditingGrpcMessage,
+		EditingPreRequestScript => ChoosingGrpcMethod,
 		EditingPostRequestScript => EditingPreRequestScript,
 		EditingRequestSettings => EditingPostRequestScript,
-		ChoosingRequestExportFormat => EditingRequestSettings,
+		EditingRequestSettingText => EditingRequestSettings,
+		ChoosingRequestExportFormat => EditingRequestSettingText,
 		DisplayingRequestExport => ChoosingRequestExportFormat,
 		SelectingResponseBody => DisplayingRequestExport,
-		ChoosingTheme => SelectingResponseBody,
+		SearchingResponseBody => SelectingResponseBody,
+		EditingResponseBodyFilter => SearchingResponseBody,
+		SavingResponseBody => EditingResponseBodyFilter,
+		ChoosingTheme => SavingResponseBody,
 	}
 }
@@ -27,7 +27,9 @@ get_key_bindings! {
 		DisplayEnvEditor(EventKeyBinding),
 		DisplayCookies(EventKeyBinding),
 		DisplayLogs(EventKeyBinding),
+		DisplayHistory(EventKeyBinding),
 		DisplayThemePicker(EventKeyBinding),
+		EditElementProperties(EventKeyBinding),
 
 		GoBackToLastState(EventKeyBinding),
 
@@ -40,6 +42,8 @@ get_key_bindings! {
 		EnvVariablesMoveRight(EventKeyBinding),
 		CreateEnvVariable(EventKeyBinding),
 		DeleteEnvVariable(EventKeyBinding),
+		ToggleEnvVariableSecret(EventKeyBinding),
+		RevealEnvSecrets(EventKeyBinding),
 
 		ModifyEnvVariable(EventKeyBinding),
 		CancelModifyEnvVariable(EventKeyBinding),
@@ -66,6 +70,14 @@ get_key_bindings! {
 		ScrollLogsLeft(EventKeyBinding),
 		ScrollLogsRight(EventKeyBinding),
 
+		/* History */
+
+		HistoryMoveUp(EventKeyBinding),
+		HistoryMoveDown(EventKeyBinding),
+		HistoryToggleDiff(EventKeyBinding),
+		ScrollHistoryUp(EventKeyBinding),
+		ScrollHistoryDown(EventKeyBinding),
+
 		/* Collections */
 
 		ChooseElementToCreateMoveCursorLeft(EventKeyBinding),
@@ -114,6 +126,10 @@ get_key_bindings! {
 		CancelRenameFolder(EventKeyBinding),
 		KeyEventRenameFolder(EventKeyBinding),
 
+		ModifyCollectionProperties(EventKeyBinding),
+		CancelEditCollectionProperties(EventKeyBinding),
+		KeyEventEditCollectionProperties(EventKeyBinding),
+
 		/* Request */
 
 		GoBackToRequestMenu(EventKeyBinding),
@@ -133,6 +149,10 @@ get_key_bindings! {
 		ModifyRequestAuthMethod(EventKeyBinding),
 		ModifyRequestBodyContentType(EventKeyBinding),
 		ModifyRequestMessageType(EventKeyBinding),
+		HalfCloseGrpcStream(EventKeyBinding),
+		ToggleGrpcReflection(EventKeyBinding),
+		ChangeGrpcCompression(EventKeyBinding),
+		DisplayGrpcMethodPicker(EventKeyBinding),
 
 		EditRequestQueryParam(EventKeyBinding),
 		RequestQueryParamsMoveUp(EventKeyBinding),
@@ -203,6 +223,25 @@ get_key_bindings! {
 		ExitResponseBodySelection(EventKeyBinding),
 		KeyEventSelectResponseBody(EventKeyBinding),
 
+		/* Response Body Search, Filter & Save */
+
+		SearchResponseBody(EventKeyBinding),
+		ConfirmResponseBodySearch(EventKeyBinding),
+		CancelResponseBodySearch(EventKeyBinding),
+		KeyEventSearchResponseBody(EventKeyBinding),
+		NextResponseBodySearchMatch(EventKeyBinding),
+		PreviousResponseBodySearchMatch(EventKeyBinding),
+
+		FilterResponseBody(EventKeyBinding),
+		ModifyResponseBodyFilter(EventKeyBinding),
+		CancelEditResponseBodyFilter(EventKeyBinding),
+		KeyEventEditResponseBodyFilter(EventKeyBinding),
+
+		SaveResponseBody(EventKeyBinding),
+		ConfirmSaveResponseBody(EventKeyBinding),
+		CancelSaveResponseBody(EventKeyBinding),
+		KeyEventSaveResponseBody(EventKeyBinding),
+
 		/* Request export */
 
 		ExportRequest(EventKeyBinding),
@@ -272,6 +311,58 @@ get_key_bindings! {
 		CancelEditRequestAuthDigestOpaque(EventKeyBinding),
 		KeyEventEditRequestAuthDigestOpaque(EventKeyBinding),
 
+		ModifyRequestAuthOAuth2TokenUrl(EventKeyBinding),
+		CancelEditRequestAuthOAuth2TokenUrl(EventKeyBinding),
+		KeyEventEditRequestAuthOAuth2TokenUrl(EventKeyBinding),
+
+		ModifyRequestAuthOAuth2AuthUrl(EventKeyBinding),
+		CancelEditRequestAuthOAuth2AuthUrl(EventKeyBinding),
+		KeyEventEditRequestAuthOAuth2AuthUrl(EventKeyBinding),
+
+		ModifyRequestAuthOAuth2ClientId(EventKeyBinding),
+		CancelEditRequestAuthOAuth2ClientId(EventKeyBinding),
+		KeyEventEditRequestAuthOAuth2ClientId(EventKeyBinding),
+
+		ModifyRequestAuthOAuth2ClientSecret(EventKeyBinding),
+		CancelEditRequestAuthOAuth2ClientSecret(EventKeyBinding),
+		KeyEventEditRequestAuthOAuth2ClientSecret(EventKeyBinding),
+
+		ModifyRequestAuthOAuth2Scope(EventKeyBinding),
+		CancelEditRequestAuthOAuth2Scope(EventKeyBinding),
+		KeyEventEditRequestAuthOAuth2Scope(EventKeyBinding),
+
+		ModifyRequestAuthOAuth2Username(EventKeyBinding),
+		CancelEditRequestAuthOAuth2Username(EventKeyBinding),
+		KeyEventEditRequestAuthOAuth2Username(EventKeyBinding),
+
+		ModifyRequestAuthOAuth2Password(EventKeyBinding),
+		CancelEditRequestAuthOAuth2Password(EventKeyBinding),
+		KeyEventEditRequestAuthOAuth2Password(EventKeyBinding),
+
+		ModifyRequestAuthOAuth2RefreshToken(EventKeyBinding),
+		CancelEditRequestAuthOAuth2RefreshToken(EventKeyBinding),
+		KeyEventEditRequestAuthOAuth2RefreshToken(EventKeyBinding),
+
+		ModifyRequestAuthAwsSigV4AccessKeyId(EventKeyBinding),
+		CancelEditRequestAuthAwsSigV4AccessKeyId(EventKeyBinding),
+		KeyEventEditRequestAuthAwsSigV4AccessKeyId(EventKeyBinding),
+
+		ModifyRequestAuthAwsSigV4SecretAccessKey(EventKeyBinding),
+		CancelEditRequestAuthAwsSigV4SecretAccessKey(EventKeyBinding),
+		KeyEventEditRequestAuthAwsSigV4SecretAccessKey(EventKeyBinding),
+
+		ModifyRequestAuthAwsSigV4Region(EventKeyBinding),
+		CancelEditRequestAuthAwsSigV4Region(EventKeyBinding),
+		KeyEventEditRequestAuthAwsSigV4Region(EventKeyBinding),
+
+		ModifyRequestAuthAwsSigV4Service(EventKeyBinding),
+		CancelEditRequestAuthAwsSigV4Service(EventKeyBinding),
+		KeyEventEditRequestAuthAwsSigV4Service(EventKeyBinding),
+
+		ModifyRequestAuthAwsSigV4SessionToken(EventKeyBinding),
+		CancelEditRequestAuthAwsSigV4SessionToken(EventKeyBinding),
+		KeyEventEditRequestAuthAwsSigV4SessionToken(EventKeyBinding),
+
 		/* Headers */
 
 		ModifyRequestHeader(EventKeyBinding),
@@ -344,6 +435,16 @@ get_key_bindings! {
 		RequestSettingsToggleSettingRight(EventKeyBinding),
 		ModifyRequestSettings(EventKeyBinding),
 
+		ModifyRequestSettingText(EventKeyBinding),
+		CancelEditRequestSettingText(EventKeyBinding),
+		KeyEventEditRequestSettingText(EventKeyBinding),
+
+		/* gRPC Method Picker */
+
+		GrpcMethodPickerMoveUp(EventKeyBinding),
+		GrpcMethodPickerMoveDown(EventKeyBinding),
+		SelectGrpcMethod(EventKeyBinding),
+
 		/* Theme Picker */
 
 		ThemePickerMoveUp(EventKeyBinding),
@@ -4,7 +4,7 @@ use ratatui::crossterm::event;
 use ratatui::crossterm::event::{Event, KeyEventKind};
 use ratatui::prelude::CrosstermBackend;
 use std::io::Stdout;
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::app::App;
 use crate::app::files::key_bindings::KEY_BINDINGS;
@@ -40,10 +40,22 @@ impl App<'_> {
 			}
 		}
 
+		let pending_captures = std::mem::take(&mut *self.core.pending_captures.lock());
+		for (collection_index, capture_results) in pending_captures {
+			if let Err(error) = self.apply_captures(collection_index, &capture_results) {
+				warn!("Could not apply captures: {error}");
+			}
+		}
+
 		let received_response = *self.core.received_response.lock();
 		if received_response {
+			self.tui_update_request_result_tab();
+			self.tui_apply_response_body_filter();
 			self.tui_highlight_response_body_and_console();
 			self.tui_refresh_result_scrollbars();
+			self.tui_update_response_body_search_matches();
+
+			self.save_cookie_stores();
 
 			if self.core.config.should_save_requests_response() {
 				let selection = self.collections_tree.state.selected().to_vec();
@@ -138,7 +150,9 @@ impl App<'_> {
 				| AppEvent::DisplayEnvEditor(_)
 				| AppEvent::DisplayCookies(_)
 				| AppEvent::DisplayLogs(_)
+				| AppEvent::DisplayHistory(_)
 				| AppEvent::DisplayThemePicker(_)
+				| AppEvent::EditElementProperties(_)
 				| AppEvent::GoBackToLastState(_) => self.handle_main_menu_event(event, key),
 
 				/* Env editor */
@@ -149,6 +163,8 @@ impl App<'_> {
 				| AppEvent::EnvVariablesMoveRight(_)
 				| AppEvent::CreateEnvVariable(_)
 				| AppEvent::DeleteEnvVariable(_)
+				| AppEvent::ToggleEnvVariableSecret(_)
+				| AppEvent::RevealEnvSecrets(_)
 				| AppEvent::ModifyEnvVariable(_)
 				| AppEvent::CancelModifyEnvVariable(_)
 				| AppEvent::KeyEventModifyEnvVariable(_) => self.handle_env_editor_event(event, key),
@@ -171,6 +187,13 @@ impl App<'_> {
 				| AppEvent::ScrollLogsLeft(_)
 				| AppEvent::ScrollLogsRight(_) => self.handle_logs_event(event, key),
 
+				/* History */
+				AppEvent::HistoryMoveUp(_)
+				| AppEvent::HistoryMoveDown(_)
+				| AppEvent::HistoryToggleDiff(_)
+				| AppEvent::ScrollHistoryUp(_)
+				| AppEvent::ScrollHistoryDown(_) => self.handle_history_event(event, key),
+
 				/* Collections */
 				AppEvent::ChooseElementToCreateMoveCursorLeft(_)
 				| AppEvent::ChooseElementToCreateMoveCursorRight(_)
@@ -207,7 +230,10 @@ impl App<'_> {
 				| AppEvent::DeleteFolder(_)
 				| AppEvent::RenameFolder(_)
 				| AppEvent::CancelRenameFolder(_)
-				| AppEvent::KeyEventRenameFolder(_) => self.handle_folders_event(event, key),
+				| AppEvent::KeyEventRenameFolder(_)
+				| AppEvent::ModifyCollectionProperties(_)
+				| AppEvent::CancelEditCollectionProperties(_)
+				| AppEvent::KeyEventEditCollectionProperties(_) => self.handle_folders_event(event, key),
 
 				/* Selected request (async — contains tui_send_request) */
 				AppEvent::GoBackToRequestMenu(_)
@@ -215,13 +241,17 @@ impl App<'_> {
 				| AppEvent::EditMethod(_)
 				| AppEvent::EditSettings(_)
 				| AppEvent::NextView(_)
-				| AppEvent::SendRequest(_) => self.handle_selected_request_event(event, key).await,
+				| AppEvent::SendRequest(_)
+				| AppEvent::DisplayGrpcMethodPicker(_) => self.handle_selected_request_event(event, key).await,
 
 				/* Param tabs */
 				AppEvent::NextParamTab(_)
 				| AppEvent::ModifyRequestAuthMethod(_)
 				| AppEvent::ModifyRequestBodyContentType(_)
 				| AppEvent::ModifyRequestMessageType(_)
+				| AppEvent::HalfCloseGrpcStream(_)
+				| AppEvent::ToggleGrpcReflection(_)
+				| AppEvent::ChangeGrpcCompression(_)
 				| AppEvent::EditRequestQueryParam(_)
 				| AppEvent::RequestQueryParamsMoveUp(_)
 				| AppEvent::RequestQueryParamsMoveDown(_)
@@ -312,6 +342,45 @@ impl App<'_> {
 				| AppEvent::ModifyRequestAuthDigestOpaque(_)
 				| AppEvent::CancelEditRequestAuthDigestOpaque(_)
 				| AppEvent::KeyEventEditRequestAuthDigestOpaque(_)
+				| AppEvent::ModifyRequestAuthOAuth2TokenUrl(_)
+				| AppEvent::CancelEditRequestAuthOAuth2TokenUrl(_)
+				| AppEvent::KeyEventEditRequestAuthOAuth2TokenUrl(_)
+				| AppEvent::ModifyRequestAuthOAuth2AuthUrl(_)
+				| AppEvent::CancelEditRequestAuthOAuth2AuthUrl(_)
+				| AppEvent::KeyEventEditRequestAuthOAuth2AuthUrl(_)
+				| AppEvent::ModifyRequestAuthOAuth2ClientId(_)
+				| AppEvent::CancelEditRequestAuthOAuth2ClientId(_)
+				| AppEvent::KeyEventEditRequestAuthOAuth2ClientId(_)
+				| AppEvent::ModifyRequestAuthOAuth2ClientSecret(_)
+				| AppEvent::CancelEditRequestAuthOAuth2ClientSecret(_)
+				| AppEvent::KeyEventEditRequestAuthOAuth2ClientSecret(_)
+				| AppEvent::ModifyRequestAuthOAuth2Scope(_)
+				| AppEvent::CancelEditRequestAuthOAuth2Scope(_)
+				| AppEvent::KeyEventEditRequestAuthOAuth2Scope(_)
+				| AppEvent::ModifyRequestAuthOAuth2Username(_)
+				| AppEvent::CancelEditRequestAuthOAuth2Username(_)
+				| AppEvent::KeyEventEditRequestAuthOAuth2Username(_)
+				| AppEvent::ModifyRequestAuthOAuth2Password(_)
+				| AppEvent::CancelEditRequestAuthOAuth2Password(_)
+				| AppEvent::KeyEventEditRequestAuthOAuth2Password(_)
+				| AppEvent::ModifyRequestAuthOAuth2RefreshToken(_)
+				| AppEvent::CancelEditRequestAuthOAuth2RefreshToken(_)
+				| AppEvent::KeyEventEditRequestAuthOAuth2RefreshToken(_)
+				| AppEvent::ModifyRequestAuthAwsSigV4AccessKeyId(_)
+				| AppEvent::CancelEditRequestAuthAwsSigV4AccessKeyId(_)
+				| AppEvent::KeyEventEditRequestAuthAwsSigV4AccessKeyId(_)
+				| AppEvent::ModifyRequestAuthAwsSigV4SecretAccessKey(_)
+				| AppEvent::CancelEditRequestAuthAwsSigV4SecretAccessKey(_)
+				| AppEvent::KeyEventEditRequestAuthAwsSigV4SecretAccessKey(_)
+				| AppEvent::ModifyRequestAuthAwsSigV4Region(_)
+				| AppEvent::CancelEditRequestAuthAwsSigV4Region(_)
+				| AppEvent::KeyEventEditRequestAuthAwsSigV4Region(_)
+				| AppEvent::ModifyRequestAuthAwsSigV4Service(_)
+				| AppEvent::CancelEditRequestAuthAwsSigV4Service(_)
+				| AppEvent::KeyEventEditRequestAuthAwsSigV4Service(_)
+				| AppEvent::ModifyRequestAuthAwsSigV4SessionToken(_)
+				| AppEvent::CancelEditRequestAuthAwsSigV4SessionToken(_)
+				| AppEvent::KeyEventEditRequestAuthAwsSigV4SessionToken(_)
 				| AppEvent::ModifyRequestHeader(_)
 				| AppEvent::CancelEditRequestHeader(_)
 				| AppEvent::KeyEventEditRequestHeader(_)
@@ -368,10 +437,29 @@ impl App<'_> {
 				/* Response body selection */
 				AppEvent::EnterResponseBodySelection(_)
 				| AppEvent::ExitResponseBodySelection(_)
-				| AppEvent::KeyEventSelectResponseBody(_) => {
+				| AppEvent::KeyEventSelectResponseBody(_)
+				| AppEvent::SearchResponseBody(_)
+				| AppEvent::ConfirmResponseBodySearch(_)
+				| AppEvent::CancelResponseBodySearch(_)
+				| AppEvent::KeyEventSearchResponseBody(_)
+				| AppEvent::NextResponseBodySearchMatch(_)
+				| AppEvent::PreviousResponseBodySearchMatch(_)
+				| AppEvent::FilterResponseBody(_)
+				| AppEvent::ModifyResponseBodyFilter(_)
+				| AppEvent::CancelEditResponseBodyFilter(_)
+				| AppEvent::KeyEventEditResponseBodyFilter(_)
+				| AppEvent::SaveResponseBody(_)
+				| AppEvent::ConfirmSaveResponseBody(_)
+				| AppEvent::CancelSaveResponseBody(_)
+				| AppEvent::KeyEventSaveResponseBody(_) => {
 					self.handle_response_body_event(event, key, terminal)
 				}
 
+				/* gRPC method picker */
+				AppEvent::GrpcMethodPickerMoveUp(_)
+				| AppEvent::GrpcMethodPickerMoveDown(_)
+				| AppEvent::SelectGrpcMethod(_) => self.handle_grpc_method_picker_event(event, key),
+
 				/* Theme picker */
 				AppEvent::ThemePickerMoveUp(_)
 				| AppEvent::ThemePickerMoveDown(_)
@@ -382,7 +470,10 @@ impl App<'_> {
 				| AppEvent::RequestSettingsMoveDown(_)
 				| AppEvent::RequestSettingsToggleSettingLeft(_)
 				| AppEvent::RequestSettingsToggleSettingRight(_)
-				| AppEvent::ModifyRequestSettings(_) => self.handle_settings_event(event, key),
+				| AppEvent::ModifyRequestSettings(_)
+				| AppEvent::ModifyRequestSettingText(_)
+				| AppEvent::CancelEditRequestSettingText(_)
+				| AppEvent::KeyEventEditRequestSettingText(_) => self.handle_settings_event(event, key),
 
 				/* Documentation (no-op) */
 				AppEvent::Documentation(_) => {}
@@ -23,6 +23,8 @@ impl App<'_> {
 			}
 			AppEvent::CreateEnvVariable(_) => self.tui_create_env_variable(),
 			AppEvent::DeleteEnvVariable(_) => self.tui_delete_env_variable(),
+			AppEvent::ToggleEnvVariableSecret(_) => self.tui_toggle_env_variable_secret(),
+			AppEvent::RevealEnvSecrets(_) => self.tui_toggle_reveal_secrets(),
 
 			AppEvent::ModifyEnvVariable(_) => match self
 				.env_editor_table
@@ -82,6 +82,35 @@ impl App<'_> {
 				.rename_collection_input
 				.key_event(key, None),
 
+			AppEvent::ModifyCollectionProperties(_) => match self
+				.collection_popups
+				.properties_text_area
+				.is_in_default_mode()
+			{
+				true => self.tui_modify_collection_properties(),
+				false => self
+					.collection_popups
+					.properties_text_area
+					.key_event(key, None),
+			},
+			AppEvent::CancelEditCollectionProperties(_) => {
+				match self
+					.collection_popups
+					.properties_text_area
+					.is_in_default_mode()
+				{
+					true => self.normal_state(),
+					false => self
+						.collection_popups
+						.properties_text_area
+						.key_event(key, None),
+				}
+			}
+			AppEvent::KeyEventEditCollectionProperties(_) => self
+				.collection_popups
+				.properties_text_area
+				.key_event(key, None),
+
 			_ => unreachable!("handle_folders_event called with non-folders event"),
 		}
 	}
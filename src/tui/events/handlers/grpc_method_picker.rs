@@ -0,0 +1,22 @@
+use crokey::KeyCombination;
+
+use crate::app::App;
+use crate::tui::events::AppEvent;
+
+impl App<'_> {
+	pub(in crate::tui::events) fn handle_grpc_method_picker_event(
+		&mut self,
+		event: &AppEvent,
+		_key: KeyCombination,
+	) {
+		match event {
+			AppEvent::GrpcMethodPickerMoveUp(_) => self.grpc_method_popup.previous(),
+			AppEvent::GrpcMethodPickerMoveDown(_) => self.grpc_method_popup.next(),
+			AppEvent::SelectGrpcMethod(_) => self.tui_select_grpc_method(),
+
+			_ => unreachable!(
+				"handle_grpc_method_picker_event called with non-grpc-method-picker event"
+			),
+		}
+	}
+}
@@ -0,0 +1,22 @@
+use crokey::KeyCombination;
+
+use crate::app::App;
+use crate::tui::events::AppEvent;
+
+impl App<'_> {
+	pub(in crate::tui::events) fn handle_history_event(
+		&mut self,
+		event: &AppEvent,
+		_key: KeyCombination,
+	) {
+		match event {
+			AppEvent::HistoryMoveUp(_) => self.history_popup.previous(),
+			AppEvent::HistoryMoveDown(_) => self.history_popup.next(),
+			AppEvent::HistoryToggleDiff(_) => self.history_popup.toggle_diff(),
+			AppEvent::ScrollHistoryUp(_) => self.history_popup.details_scrollbar.page_up(),
+			AppEvent::ScrollHistoryDown(_) => self.history_popup.details_scrollbar.page_down(),
+
+			_ => unreachable!("handle_history_event called with non-history event"),
+		}
+	}
+}
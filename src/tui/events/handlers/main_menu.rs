@@ -35,7 +35,9 @@ impl App<'_> {
 			AppEvent::DisplayEnvEditor(_) => self.display_env_editor_state(),
 			AppEvent::DisplayCookies(_) => self.display_cookies_state(),
 			AppEvent::DisplayLogs(_) => self.display_logs_state(),
+			AppEvent::DisplayHistory(_) => self.display_history_state(),
 			AppEvent::DisplayThemePicker(_) => self.choose_theme_state(),
+			AppEvent::EditElementProperties(_) => self.edit_collection_properties_state(),
 
 			AppEvent::GoBackToLastState(_) => match self.state {
 				crate::tui::app_states::AppState::ChoosingTheme => {
@@ -3,6 +3,8 @@ mod cookies;
 mod env_editor;
 mod export;
 mod folders;
+mod grpc_method_picker;
+mod history;
 mod logs;
 mod main_menu;
 mod param_tabs;
@@ -17,6 +17,9 @@ impl App<'_> {
 			AppEvent::ModifyRequestAuthMethod(_) => self.tui_next_request_auth(),
 			AppEvent::ModifyRequestBodyContentType(_) => self.tui_next_request_content_type(),
 			AppEvent::ModifyRequestMessageType(_) => self.tui_next_request_message_type(),
+			AppEvent::HalfCloseGrpcStream(_) => self.tui_half_close_grpc_stream(),
+			AppEvent::ToggleGrpcReflection(_) => self.tui_toggle_grpc_reflection(),
+			AppEvent::ChangeGrpcCompression(_) => self.tui_next_grpc_compression(),
 
 			/* Query params */
 			AppEvent::EditRequestQueryParam(_) => {
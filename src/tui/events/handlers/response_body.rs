@@ -32,6 +32,55 @@ impl App<'_> {
 				.body_text_area
 				.key_event(key, Some(terminal)),
 
+			/* Search */
+			AppEvent::SearchResponseBody(_) => self.search_response_body_state(),
+			AppEvent::ConfirmResponseBodySearch(_) => self.select_request_state(),
+			AppEvent::CancelResponseBodySearch(_) => self.tui_cancel_response_body_search(),
+			AppEvent::KeyEventSearchResponseBody(_) => {
+				self.response_view.body_search.input.key_event(key, None);
+				self.tui_search_response_body();
+			}
+			AppEvent::NextResponseBodySearchMatch(_) => self.tui_next_response_body_search_match(),
+			AppEvent::PreviousResponseBodySearchMatch(_) => {
+				self.tui_previous_response_body_search_match()
+			}
+
+			/* Filter */
+			AppEvent::FilterResponseBody(_) => self.edit_response_body_filter_state(),
+			AppEvent::ModifyResponseBodyFilter(_) => {
+				match self.response_view.body_filter_input.is_in_default_mode() {
+					true => self.tui_modify_response_body_filter(),
+					false => self.response_view.body_filter_input.key_event(key, None),
+				}
+			}
+			AppEvent::CancelEditResponseBodyFilter(_) => {
+				match self.response_view.body_filter_input.is_in_default_mode() {
+					true => self.select_request_state(),
+					false => self.response_view.body_filter_input.key_event(key, None),
+				}
+			}
+			AppEvent::KeyEventEditResponseBodyFilter(_) => {
+				self.response_view.body_filter_input.key_event(key, None)
+			}
+
+			/* Save */
+			AppEvent::SaveResponseBody(_) => self.save_response_body_state(),
+			AppEvent::ConfirmSaveResponseBody(_) => {
+				match self.response_view.body_save_input.is_in_default_mode() {
+					true => self.tui_save_response_body(),
+					false => self.response_view.body_save_input.key_event(key, None),
+				}
+			}
+			AppEvent::CancelSaveResponseBody(_) => {
+				match self.response_view.body_save_input.is_in_default_mode() {
+					true => self.select_request_state(),
+					false => self.response_view.body_save_input.key_event(key, None),
+				}
+			}
+			AppEvent::KeyEventSaveResponseBody(_) => {
+				self.response_view.body_save_input.key_event(key, None)
+			}
+
 			_ => unreachable!("handle_response_body_event called with non-response-body event"),
 		}
 	}
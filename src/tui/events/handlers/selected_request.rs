@@ -18,6 +18,7 @@ impl App<'_> {
 
 			AppEvent::NextView(_) => self.next_request_view(),
 			AppEvent::SendRequest(_) => self.tui_send_request().await,
+			AppEvent::DisplayGrpcMethodPicker(_) => self.tui_display_grpc_method_picker().await,
 
 			_ => {
 				unreachable!("handle_selected_request_event called with non-selected-request event")
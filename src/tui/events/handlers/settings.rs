@@ -7,7 +7,7 @@ impl App<'_> {
 	pub(in crate::tui::events) fn handle_settings_event(
 		&mut self,
 		event: &AppEvent,
-		_key: KeyCombination,
+		key: KeyCombination,
 	) {
 		match event {
 			AppEvent::RequestSettingsMoveUp(_) => self.request_editor.settings_popup.previous(),
@@ -16,10 +16,52 @@ impl App<'_> {
 				self.request_editor.settings_popup.toggle_setting_left()
 			}
 			AppEvent::RequestSettingsToggleSettingRight(_) => {
-				self.request_editor.settings_popup.toggle_setting_right()
+				match self
+					.request_editor
+					.settings_popup
+					.is_text_setting_selected()
+				{
+					true => self.edit_request_setting_text_state(),
+					false => self.request_editor.settings_popup.toggle_setting_right(),
+				}
 			}
 			AppEvent::ModifyRequestSettings(_) => self.tui_modify_request_settings(),
 
+			AppEvent::ModifyRequestSettingText(_) => match self
+				.request_editor
+				.settings_popup
+				.text_input
+				.is_in_default_mode()
+			{
+				true => {
+					self.request_editor.settings_popup.modify_text_setting();
+					self.resume_editing_request_settings_state();
+				}
+				false => self
+					.request_editor
+					.settings_popup
+					.text_input
+					.key_event(key, None),
+			},
+			AppEvent::CancelEditRequestSettingText(_) => match self
+				.request_editor
+				.settings_popup
+				.text_input
+				.is_in_default_mode()
+			{
+				true => self.resume_editing_request_settings_state(),
+				false => self
+					.request_editor
+					.settings_popup
+					.text_input
+					.key_event(key, None),
+			},
+			AppEvent::KeyEventEditRequestSettingText(_) => self
+				.request_editor
+				.settings_popup
+				.text_input
+				.key_event(key, None),
+
 			_ => unreachable!("handle_settings_event called with non-settings event"),
 		}
 	}
@@ -301,6 +301,467 @@ impl App<'_> {
 				self.request_editor.auth.digest_opaque.key_event(key, None)
 			}
 
+			/* Auth - OAuth2 Token URL */
+			AppEvent::ModifyRequestAuthOAuth2TokenUrl(_) => {
+				match self
+					.request_editor
+					.auth
+					.oauth2_token_url
+					.is_in_default_mode()
+				{
+					true => self.tui_modify_request_auth_oauth2_token_url(),
+					false => self
+						.request_editor
+						.auth
+						.oauth2_token_url
+						.key_event(key, None),
+				}
+			}
+			AppEvent::CancelEditRequestAuthOAuth2TokenUrl(_) => {
+				match self
+					.request_editor
+					.auth
+					.oauth2_token_url
+					.is_in_default_mode()
+				{
+					true => self.select_request_state(),
+					false => self
+						.request_editor
+						.auth
+						.oauth2_token_url
+						.key_event(key, None),
+				}
+			}
+			AppEvent::KeyEventEditRequestAuthOAuth2TokenUrl(_) => self
+				.request_editor
+				.auth
+				.oauth2_token_url
+				.key_event(key, None),
+
+			/* Auth - OAuth2 Authorization URL */
+			AppEvent::ModifyRequestAuthOAuth2AuthUrl(_) => {
+				match self
+					.request_editor
+					.auth
+					.oauth2_auth_url
+					.is_in_default_mode()
+				{
+					true => self.tui_modify_request_auth_oauth2_auth_url(),
+					false => self
+						.request_editor
+						.auth
+						.oauth2_auth_url
+						.key_event(key, None),
+				}
+			}
+			AppEvent::CancelEditRequestAuthOAuth2AuthUrl(_) => {
+				match self
+					.request_editor
+					.auth
+					.oauth2_auth_url
+					.is_in_default_mode()
+				{
+					true => self.select_request_state(),
+					false => self
+						.request_editor
+						.auth
+						.oauth2_auth_url
+						.key_event(key, None),
+				}
+			}
+			AppEvent::KeyEventEditRequestAuthOAuth2AuthUrl(_) => self
+				.request_editor
+				.auth
+				.oauth2_auth_url
+				.key_event(key, None),
+
+			/* Auth - OAuth2 Client ID */
+			AppEvent::ModifyRequestAuthOAuth2ClientId(_) => {
+				match self
+					.request_editor
+					.auth
+					.oauth2_client_id
+					.is_in_default_mode()
+				{
+					true => self.tui_modify_request_auth_oauth2_client_id(),
+					false => self
+						.request_editor
+						.auth
+						.oauth2_client_id
+						.key_event(key, None),
+				}
+			}
+			AppEvent::CancelEditRequestAuthOAuth2ClientId(_) => {
+				match self
+					.request_editor
+					.auth
+					.oauth2_client_id
+					.is_in_default_mode()
+				{
+					true => self.select_request_state(),
+					false => self
+						.request_editor
+						.auth
+						.oauth2_client_id
+						.key_event(key, None),
+				}
+			}
+			AppEvent::KeyEventEditRequestAuthOAuth2ClientId(_) => self
+				.request_editor
+				.auth
+				.oauth2_client_id
+				.key_event(key, None),
+
+			/* Auth - OAuth2 Client secret */
+			AppEvent::ModifyRequestAuthOAuth2ClientSecret(_) => {
+				match self
+					.request_editor
+					.auth
+					.oauth2_client_secret
+					.is_in_default_mode()
+				{
+					true => self.tui_modify_request_auth_oauth2_client_secret(),
+					false => self
+						.request_editor
+						.auth
+						.oauth2_client_secret
+						.key_event(key, None),
+				}
+			}
+			AppEvent::CancelEditRequestAuthOAuth2ClientSecret(_) => {
+				match self
+					.request_editor
+					.auth
+					.oauth2_client_secret
+					.is_in_default_mode()
+				{
+					true => self.select_request_state(),
+					false => self
+						.request_editor
+						.auth
+						.oauth2_client_secret
+						.key_event(key, None),
+				}
+			}
+			AppEvent::KeyEventEditRequestAuthOAuth2ClientSecret(_) => self
+				.request_editor
+				.auth
+				.oauth2_client_secret
+				.key_event(key, None),
+
+			/* Auth - OAuth2 Scope */
+			AppEvent::ModifyRequestAuthOAuth2Scope(_) => {
+				match self.request_editor.auth.oauth2_scope.is_in_default_mode() {
+					true => self.tui_modify_request_auth_oauth2_scope(),
+					false => self.request_editor.auth.oauth2_scope.key_event(key, None),
+				}
+			}
+			AppEvent::CancelEditRequestAuthOAuth2Scope(_) => {
+				match self.request_editor.auth.oauth2_scope.is_in_default_mode() {
+					true => self.select_request_state(),
+					false => self.request_editor.auth.oauth2_scope.key_event(key, None),
+				}
+			}
+			AppEvent::KeyEventEditRequestAuthOAuth2Scope(_) => {
+				self.request_editor.auth.oauth2_scope.key_event(key, None)
+			}
+
+			/* Auth - OAuth2 Username */
+			AppEvent::ModifyRequestAuthOAuth2Username(_) => {
+				match self
+					.request_editor
+					.auth
+					.oauth2_username
+					.is_in_default_mode()
+				{
+					true => self.tui_modify_request_auth_oauth2_username(),
+					false => self
+						.request_editor
+						.auth
+						.oauth2_username
+						.key_event(key, None),
+				}
+			}
+			AppEvent::CancelEditRequestAuthOAuth2Username(_) => {
+				match self
+					.request_editor
+					.auth
+					.oauth2_username
+					.is_in_default_mode()
+				{
+					true => self.select_request_state(),
+					false => self
+						.request_editor
+						.auth
+						.oauth2_username
+						.key_event(key, None),
+				}
+			}
+			AppEvent::KeyEventEditRequestAuthOAuth2Username(_) => self
+				.request_editor
+				.auth
+				.oauth2_username
+				.key_event(key, None),
+
+			/* Auth - OAuth2 Password */
+			AppEvent::ModifyRequestAuthOAuth2Password(_) => {
+				match self
+					.request_editor
+					.auth
+					.oauth2_password
+					.is_in_default_mode()
+				{
+					true => self.tui_modify_request_auth_oauth2_password(),
+					false => self
+						.request_editor
+						.auth
+						.oauth2_password
+						.key_event(key, None),
+				}
+			}
+			AppEvent::CancelEditRequestAuthOAuth2Password(_) => {
+				match self
+					.request_editor
+					.auth
+					.oauth2_password
+					.is_in_default_mode()
+				{
+					true => self.select_request_state(),
+					false => self
+						.request_editor
+						.auth
+						.oauth2_password
+						.key_event(key, None),
+				}
+			}
+			AppEvent::KeyEventEditRequestAuthOAuth2Password(_) => self
+				.request_editor
+				.auth
+				.oauth2_password
+				.key_event(key, None),
+
+			/* Auth - OAuth2 Refresh token */
+			AppEvent::ModifyRequestAuthOAuth2RefreshToken(_) => {
+				match self
+					.request_editor
+					.auth
+					.oauth2_refresh_token
+					.is_in_default_mode()
+				{
+					true => self.tui_modify_request_auth_oauth2_refresh_token(),
+					false => self
+						.request_editor
+						.auth
+						.oauth2_refresh_token
+						.key_event(key, None),
+				}
+			}
+			AppEvent::CancelEditRequestAuthOAuth2RefreshToken(_) => {
+				match self
+					.request_editor
+					.auth
+					.oauth2_refresh_token
+					.is_in_default_mode()
+				{
+					true => self.select_request_state(),
+					false => self
+						.request_editor
+						.auth
+						.oauth2_refresh_token
+						.key_event(key, None),
+				}
+			}
+			AppEvent::KeyEventEditRequestAuthOAuth2RefreshToken(_) => self
+				.request_editor
+				.auth
+				.oauth2_refresh_token
+				.key_event(key, None),
+
+			/* Auth - AWS SigV4 Access key ID */
+			AppEvent::ModifyRequestAuthAwsSigV4AccessKeyId(_) => {
+				match self
+					.request_editor
+					.auth
+					.aws_sig_v4_access_key_id
+					.is_in_default_mode()
+				{
+					true => self.tui_modify_request_auth_aws_sig_v4_access_key_id(),
+					false => self
+						.request_editor
+						.auth
+						.aws_sig_v4_access_key_id
+						.key_event(key, None),
+				}
+			}
+			AppEvent::CancelEditRequestAuthAwsSigV4AccessKeyId(_) => {
+				match self
+					.request_editor
+					.auth
+					.aws_sig_v4_access_key_id
+					.is_in_default_mode()
+				{
+					true => self.select_request_state(),
+					false => self
+						.request_editor
+						.auth
+						.aws_sig_v4_access_key_id
+						.key_event(key, None),
+				}
+			}
+			AppEvent::KeyEventEditRequestAuthAwsSigV4AccessKeyId(_) => self
+				.request_editor
+				.auth
+				.aws_sig_v4_access_key_id
+				.key_event(key, None),
+
+			/* Auth - AWS SigV4 Secret access key */
+			AppEvent::ModifyRequestAuthAwsSigV4SecretAccessKey(_) => {
+				match self
+					.request_editor
+					.auth
+					.aws_sig_v4_secret_access_key
+					.is_in_default_mode()
+				{
+					true => self.tui_modify_request_auth_aws_sig_v4_secret_access_key(),
+					false => self
+						.request_editor
+						.auth
+						.aws_sig_v4_secret_access_key
+						.key_event(key, None),
+				}
+			}
+			AppEvent::CancelEditRequestAuthAwsSigV4SecretAccessKey(_) => {
+				match self
+					.request_editor
+					.auth
+					.aws_sig_v4_secret_access_key
+					.is_in_default_mode()
+				{
+					true => self.select_request_state(),
+					false => self
+						.request_editor
+						.auth
+						.aws_sig_v4_secret_access_key
+						.key_event(key, None),
+				}
+			}
+			AppEvent::KeyEventEditRequestAuthAwsSigV4SecretAccessKey(_) => self
+				.request_editor
+				.auth
+				.aws_sig_v4_secret_access_key
+				.key_event(key, None),
+
+			/* Auth - AWS SigV4 Region */
+			AppEvent::ModifyRequestAuthAwsSigV4Region(_) => {
+				match self
+					.request_editor
+					.auth
+					.aws_sig_v4_region
+					.is_in_default_mode()
+				{
+					true => self.tui_modify_request_auth_aws_sig_v4_region(),
+					false => self
+						.request_editor
+						.auth
+						.aws_sig_v4_region
+						.key_event(key, None),
+				}
+			}
+			AppEvent::CancelEditRequestAuthAwsSigV4Region(_) => {
+				match self
+					.request_editor
+					.auth
+					.aws_sig_v4_region
+					.is_in_default_mode()
+				{
+					true => self.select_request_state(),
+					false => self
+						.request_editor
+						.auth
+						.aws_sig_v4_region
+						.key_event(key, None),
+				}
+			}
+			AppEvent::KeyEventEditRequestAuthAwsSigV4Region(_) => self
+				.request_editor
+				.auth
+				.aws_sig_v4_region
+				.key_event(key, None),
+
+			/* Auth - AWS SigV4 Service */
+			AppEvent::ModifyRequestAuthAwsSigV4Service(_) => {
+				match self
+					.request_editor
+					.auth
+					.aws_sig_v4_service
+					.is_in_default_mode()
+				{
+					true => self.tui_modify_request_auth_aws_sig_v4_service(),
+					false => self
+						.request_editor
+						.auth
+						.aws_sig_v4_service
+						.key_event(key, None),
+				}
+			}
+			AppEvent::CancelEditRequestAuthAwsSigV4Service(_) => {
+				match self
+					.request_editor
+					.auth
+					.aws_sig_v4_service
+					.is_in_default_mode()
+				{
+					true => self.select_request_state(),
+					false => self
+						.request_editor
+						.auth
+						.aws_sig_v4_service
+						.key_event(key, None),
+				}
+			}
+			AppEvent::KeyEventEditRequestAuthAwsSigV4Service(_) => self
+				.request_editor
+				.auth
+				.aws_sig_v4_service
+				.key_event(key, None),
+
+			/* Auth - AWS SigV4 Session token */
+			AppEvent::ModifyRequestAuthAwsSigV4SessionToken(_) => {
+				match self
+					.request_editor
+					.auth
+					.aws_sig_v4_session_token
+					.is_in_default_mode()
+				{
+					true => self.tui_modify_request_auth_aws_sig_v4_session_token(),
+					false => self
+						.request_editor
+						.auth
+						.aws_sig_v4_session_token
+						.key_event(key, None),
+				}
+			}
+			AppEvent::CancelEditRequestAuthAwsSigV4SessionToken(_) => {
+				match self
+					.request_editor
+					.auth
+					.aws_sig_v4_session_token
+					.is_in_default_mode()
+				{
+					true => self.select_request_state(),
+					false => self
+						.request_editor
+						.auth
+						.aws_sig_v4_session_token
+						.key_event(key, None),
+				}
+			}
+			AppEvent::KeyEventEditRequestAuthAwsSigV4SessionToken(_) => self
+				.request_editor
+				.auth
+				.aws_sig_v4_session_token
+				.key_event(key, None),
+
 			/* Header */
 			AppEvent::ModifyRequestHeader(_) => {
 				match self
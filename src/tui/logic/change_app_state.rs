@@ -1,5 +1,7 @@
 use crate::app::App;
+use crate::app::files::history::read_history;
 use crate::app::log::{LOGS, SHOULD_RECORD_LOGS};
+use crate::models::collection::TreeElement;
 use crate::models::export::ExportFormat;
 use crate::models::protocol::http::body::ContentType;
 use crate::models::protocol::protocol::Protocol;
@@ -7,10 +9,9 @@ use crate::tui::app_states::AppState;
 use crate::tui::ui::param_tabs::param_tabs::RequestParamsTabs;
 use crate::tui::utils::stateful::cookie_table::{StatefulCookieTable, cookie_to_row};
 use edtui::actions::MoveToEndOfLine;
-use std::sync::Arc;
 use std::sync::atomic::Ordering;
 use strum::VariantArray;
-use tracing::debug;
+use tracing::{debug, warn};
 
 macro_rules! define_simple_state_setters {
 	($($fn_name:ident => $state:ident);* $(;)?) => {
@@ -38,7 +39,21 @@ impl App<'_> {
 		edit_request_auth_digest_realm_state => EditingRequestAuthDigestRealm;
 		edit_request_auth_digest_nonce_state => EditingRequestAuthDigestNonce;
 		edit_request_auth_digest_opaque_state => EditingRequestAuthDigestOpaque;
+		edit_request_auth_oauth2_token_url_state => EditingRequestAuthOAuth2TokenUrl;
+		edit_request_auth_oauth2_auth_url_state => EditingRequestAuthOAuth2AuthUrl;
+		edit_request_auth_oauth2_client_id_state => EditingRequestAuthOAuth2ClientId;
+		edit_request_auth_oauth2_client_secret_state => EditingRequestAuthOAuth2ClientSecret;
+		edit_request_auth_oauth2_scope_state => EditingRequestAuthOAuth2Scope;
+		edit_request_auth_oauth2_username_state => EditingRequestAuthOAuth2Username;
+		edit_request_auth_oauth2_password_state => EditingRequestAuthOAuth2Password;
+		edit_request_auth_oauth2_refresh_token_state => EditingRequestAuthOAuth2RefreshToken;
+		edit_request_auth_aws_sig_v4_access_key_id_state => EditingRequestAuthAwsSigV4AccessKeyId;
+		edit_request_auth_aws_sig_v4_secret_access_key_state => EditingRequestAuthAwsSigV4SecretAccessKey;
+		edit_request_auth_aws_sig_v4_region_state => EditingRequestAuthAwsSigV4Region;
+		edit_request_auth_aws_sig_v4_service_state => EditingRequestAuthAwsSigV4Service;
+		edit_request_auth_aws_sig_v4_session_token_state => EditingRequestAuthAwsSigV4SessionToken;
 		edit_request_header_state => EditingRequestHeader;
+		resume_editing_request_settings_state => EditingRequestSettings;
 	}
 
 	fn set_app_state(&mut self, app_state: AppState) {
@@ -132,6 +147,8 @@ impl App<'_> {
 					.expect("selected index should be valid");
 				let text = match selection.1 {
 					0 => pair.0,
+					// A masked secret value is replaced rather than edited
+					1 if env.is_secret(pair.0) && !self.reveal_secrets => "",
 					1 => pair.1,
 					_ => unreachable!(),
 				};
@@ -148,7 +165,7 @@ impl App<'_> {
 	}
 
 	pub fn display_cookies_state(&mut self) {
-		let local_cookie_store = Arc::clone(&self.core.cookies_popup.cookie_store);
+		let local_cookie_store = self.tui_cookie_store();
 
 		self.core.cookies_popup.cookies_table.rows = vec![];
 
@@ -227,6 +244,43 @@ impl App<'_> {
 		self.set_app_state(AppState::DisplayingLogs);
 	}
 
+	/// Display the history of the selected request, or the whole history when none is selected
+	pub fn display_history_state(&mut self) {
+		let all_entries = read_history().unwrap_or_else(|e| {
+			warn!("{e:#}");
+			vec![]
+		});
+
+		let selected = self.collections_tree.selected.as_ref().map(|selected| {
+			let collection_name = self.core.collections[selected.collection_index()]
+				.name
+				.clone();
+			let request_name = self
+				.get_request_from_selection(selected)
+				.read()
+				.name
+				.clone();
+
+			(collection_name, request_name)
+		});
+
+		let entries = all_entries
+			.iter()
+			.rev()
+			.filter(|entry| match &selected {
+				None => true,
+				Some((collection_name, request_name)) => {
+					entry.collection.as_ref() == Some(collection_name)
+						&& &entry.request == request_name
+				}
+			})
+			.cloned()
+			.collect();
+
+		self.history_popup.init(all_entries, entries);
+		self.set_app_state(AppState::DisplayingHistory);
+	}
+
 	pub fn choose_element_to_create_state(&mut self) {
 		self.collection_popups.creation_popup.selection = 0;
 
@@ -266,27 +320,22 @@ impl App<'_> {
 
 		// Pre-populate folder selection based on current tree position
 		let collection = &self.core.collections[popup_selected_collection_index];
-		self.collection_popups.new_request_popup.folder_count = collection.folders.len();
+		let folder_paths = collection.folder_paths();
+
+		// If the user is currently on a folder, or on a request inside a folder, pre-select it
+		let current_folder_path = match selected_collection.split_first() {
+			Some((_, tree_path)) => match collection.resolve_tree_path(tree_path) {
+				Some(TreeElement::Folder(folder_path)) => folder_path,
+				Some(TreeElement::Request(folder_path, _)) => folder_path,
+				None => vec![],
+			},
+			None => vec![],
+		};
 
-		// If the user is currently inside a folder, pre-select that folder
-		if let Some(selected) = &self.collections_tree.selected {
-			if let Some(folder_index) = selected.folder_index() {
-				self.collection_popups.new_request_popup.selected_folder = Some(folder_index);
-			} else {
-				self.collection_popups.new_request_popup.selected_folder = None;
-			}
-		} else if selected_collection.len() == 2 {
-			// User might be on a folder node (not a request) - check if it's a folder
-			let child_index = selected_collection[1];
-			let folder_count = collection.folders.len();
-			if child_index < folder_count {
-				self.collection_popups.new_request_popup.selected_folder = Some(child_index);
-			} else {
-				self.collection_popups.new_request_popup.selected_folder = None;
-			}
-		} else {
-			self.collection_popups.new_request_popup.selected_folder = None;
-		}
+		self.collection_popups.new_request_popup.selected_folder = folder_paths
+			.iter()
+			.position(|folder_path| *folder_path == current_folder_path);
+		self.collection_popups.new_request_popup.folder_paths = folder_paths;
 
 		self.set_app_state(AppState::CreatingNewRequest);
 	}
@@ -320,27 +369,17 @@ impl App<'_> {
 	pub fn rename_request_state(&mut self) {
 		let selected = self.collections_tree.state.selected();
 
-		let request_name = match selected.len() {
-			2 => {
-				let collection_index = selected[0];
-				let child_index = selected[1];
-				let folder_count = self.core.collections[collection_index].folders.len();
-				let request_index = child_index - folder_count;
-				self.core.collections[collection_index].requests[request_index]
-					.read()
-					.name
-					.clone()
-			}
-			3 => {
-				let collection_index = selected[0];
-				let folder_index = selected[1];
-				let request_index = selected[2];
-				self.core.collections[collection_index].folders[folder_index].requests
-					[request_index]
-					.read()
-					.name
-					.clone()
-			}
+		let Some((collection_index, tree_path)) = selected.split_first() else {
+			return;
+		};
+		let collection = &self.core.collections[*collection_index];
+
+		let request_name = match collection.resolve_tree_path(tree_path) {
+			Some(TreeElement::Request(folder_path, request_index)) => collection
+				.requests_in(&folder_path)[request_index]
+				.read()
+				.name
+				.clone(),
 			_ => return,
 		};
 
@@ -376,11 +415,16 @@ impl App<'_> {
 	pub fn rename_folder_state(&mut self) {
 		let selected = self.collections_tree.state.selected();
 
-		if selected.len() == 2 {
-			let collection_index = selected[0];
-			let folder_index = selected[1];
+		let Some((collection_index, tree_path)) = selected.split_first() else {
+			return;
+		};
+		let collection = &self.core.collections[*collection_index];
 
-			let folder_name = &self.core.collections[collection_index].folders[folder_index].name;
+		if let Some(TreeElement::Folder(folder_path)) = collection.resolve_tree_path(tree_path) {
+			let folder_name = &collection
+				.folder(&folder_path)
+				.expect("folder path should be valid")
+				.name;
 			self.collection_popups.rename_collection_input.clear();
 			self.collection_popups
 				.rename_collection_input
@@ -394,6 +438,25 @@ impl App<'_> {
 		}
 	}
 
+	pub fn edit_collection_properties_state(&mut self) {
+		let Some((collection_index, folder_path)) = self.selected_collection_or_folder() else {
+			return;
+		};
+
+		let shared_properties = self.get_shared_properties(collection_index, &folder_path);
+		let yaml = serde_yaml_ng::to_string(&shared_properties).unwrap_or_default();
+
+		let text_area = &mut self.collection_popups.properties_text_area;
+		text_area.clear();
+		text_area.push_str(yaml.trim_end());
+		text_area.move_cursor_start();
+		text_area.reset_mode();
+		text_area.update_handler();
+		self.collection_popups.properties_error = None;
+
+		self.set_app_state(AppState::EditingCollectionProperties);
+	}
+
 	pub fn select_request_state(&mut self) {
 		self.set_app_state(AppState::SelectedRequest);
 		self.update_inputs();
@@ -511,6 +574,20 @@ impl App<'_> {
 		self.set_app_state(AppState::EditingRequestSettings);
 	}
 
+	pub fn edit_request_setting_text_state(&mut self) {
+		let settings_popup = &mut self.request_editor.settings_popup;
+		let value = settings_popup.settings[settings_popup.selection]
+			.1
+			.to_string();
+
+		settings_popup.text_input.clear();
+		settings_popup.text_input.push_str(&value);
+		settings_popup.text_input.state.execute(MoveToEndOfLine());
+
+		self.set_app_state(AppState::EditingRequestSettingText);
+		self.update_inputs();
+	}
+
 	pub fn choose_request_export_format_state(&mut self) {
 		self.export_request.selection = 0;
 
@@ -559,9 +636,10 @@ impl App<'_> {
 			use crate::models::response::ResponseContent;
 			if let ResponseContent::Body(body) = content {
 				debug!("Response body length: {}", body.len());
-				// Sync the response body to the text area
+				// Sync the displayed response body to the text area
+				let body = self.tui_displayed_response_body(body).to_string();
 				self.response_view.body_text_area.clear();
-				self.response_view.body_text_area.push_str(body);
+				self.response_view.body_text_area.push_str(&body);
 				self.response_view.body_text_area.move_cursor_start();
 				self.response_view.body_text_area.reset_mode();
 				self.response_view.body_text_area.update_handler();
@@ -581,6 +659,44 @@ impl App<'_> {
 		self.set_app_state(AppState::SelectedRequest);
 	}
 
+	pub fn search_response_body_state(&mut self) {
+		if !self.is_response_body_displayed() {
+			return;
+		}
+
+		self.response_view.body_search.input.move_cursor_line_end();
+		self.set_app_state(AppState::SearchingResponseBody);
+	}
+
+	pub fn edit_response_body_filter_state(&mut self) {
+		// A filter can be set before the request is sent
+		if self.request_result_tab != crate::tui::ui::result_tabs::RequestResultTabs::Body {
+			return;
+		}
+
+		self.response_view.body_filter_input.move_cursor_line_end();
+		self.set_app_state(AppState::EditingResponseBodyFilter);
+	}
+
+	pub fn save_response_body_state(&mut self) {
+		if self.request_result_tab != crate::tui::ui::result_tabs::RequestResultTabs::Body {
+			return;
+		}
+
+		let Some(default_path) = self.tui_default_response_body_path() else {
+			return;
+		};
+
+		self.response_view.body_save_input.clear();
+		self.response_view.body_save_input.push_str(&default_path);
+		self.response_view.body_save_input.move_cursor_line_end();
+		self.set_app_state(AppState::SavingResponseBody);
+	}
+
+	pub fn choose_grpc_method_state(&mut self) {
+		self.set_app_state(AppState::ChoosingGrpcMethod);
+	}
+
 	pub fn choose_theme_state(&mut self) {
 		self.theme_popup.init();
 		self.set_app_state(AppState::ChoosingTheme);
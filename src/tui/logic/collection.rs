@@ -4,7 +4,8 @@ use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
 use crate::models::auth::digest::Digest;
 use crate::models::auth::jwt::JwtToken;
-use crate::models::collection::ChildRef;
+use crate::models::collection::TreeElement;
+use crate::models::inheritance::SharedProperties;
 use crate::models::protocol::http::body::ContentType;
 use crate::models::protocol::protocol::Protocol;
 use crate::models::protocol::ws::message_type::MessageType;
@@ -16,9 +17,11 @@ impl App<'_> {
 		self.reset_inputs_mode();
 		self.clear_inputs();
 
-		let Some(local_selected_request) = self.get_selected_request_as_local() else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
+		let local_selected_request = self.get_request_from_selection(&selected);
+		self.load_oauth2_token(&local_selected_request, Some(selected.collection_index()));
 		let selected_request = local_selected_request.read();
 
 		self.request_editor
@@ -27,6 +30,12 @@ impl App<'_> {
 		self.request_editor.query_params_table.rows = selected_request.params.clone();
 		self.request_editor.headers_table.rows = selected_request.headers.clone();
 
+		if let Some(response_filter) = &selected_request.response_filter {
+			self.response_view
+				.body_filter_input
+				.push_str(response_filter);
+		}
+
 		if !selected_request.params.is_empty() {
 			let Some(selection) = self.request_editor.query_params_table.selection else {
 				return;
@@ -45,7 +54,7 @@ impl App<'_> {
 		}
 
 		match &selected_request.auth {
-			Auth::NoAuth => {
+			Auth::NoAuth | Auth::Inherit => {
 				self.request_editor.auth.text_input_selection.max_selection = 0;
 				self.request_editor.auth.text_input_selection.usable = false;
 			}
@@ -92,6 +101,68 @@ impl App<'_> {
 				self.request_editor.auth.digest_nonce.push_str(nonce);
 				self.request_editor.auth.digest_opaque.push_str(opaque);
 			}
+			Auth::OAuth2(oauth2) => {
+				self.request_editor.auth.text_input_selection.max_selection = 11;
+				self.request_editor.auth.text_input_selection.usable = true;
+
+				self.request_editor
+					.auth
+					.oauth2_token_url
+					.push_str(&oauth2.token_url);
+				self.request_editor
+					.auth
+					.oauth2_auth_url
+					.push_str(&oauth2.auth_url);
+				self.request_editor
+					.auth
+					.oauth2_client_id
+					.push_str(&oauth2.client_id);
+				self.request_editor
+					.auth
+					.oauth2_client_secret
+					.push_str(&oauth2.client_secret);
+				self.request_editor
+					.auth
+					.oauth2_scope
+					.push_str(&oauth2.scope);
+				self.request_editor
+					.auth
+					.oauth2_username
+					.push_str(&oauth2.username);
+				self.request_editor
+					.auth
+					.oauth2_password
+					.push_str(&oauth2.password);
+				self.request_editor
+					.auth
+					.oauth2_refresh_token
+					.push_str(&oauth2.refresh_token);
+			}
+			Auth::AwsSigV4(aws_sig_v4) => {
+				self.request_editor.auth.text_input_selection.max_selection = 5;
+				self.request_editor.auth.text_input_selection.usable = true;
+
+				self.request_editor
+					.auth
+					.aws_sig_v4_access_key_id
+					.push_str(&aws_sig_v4.access_key_id);
+				self.request_editor
+					.auth
+					.aws_sig_v4_secret_access_key
+					.push_str(&aws_sig_v4.secret_access_key);
+				self.request_editor
+					.auth
+					.aws_sig_v4_region
+					.push_str(&aws_sig_v4.region);
+				self.request_editor
+					.auth
+					.aws_sig_v4_service
+					.push_str(&aws_sig_v4.service);
+				self.request_editor
+					.auth
+					.aws_sig_v4_session_token
+					.push_str(&aws_sig_v4.session_token);
+			}
 		}
 
 		if !selected_request.headers.is_empty() {
@@ -155,6 +226,7 @@ impl App<'_> {
 			Protocol::WsRequest(ws_request) => {
 				let content = match &ws_request.message_type {
 					MessageType::Text(text) | MessageType::Close(text) => text.clone(),
+					MessageType::Event(event) => event.data.clone(),
 					MessageType::Binary(bytes)
 					| MessageType::Ping(bytes)
 					| MessageType::Pong(bytes) => String::from_utf8_lossy(bytes.as_ref()).to_string(),
@@ -192,30 +264,38 @@ impl App<'_> {
 			.push_str(post_request_script);
 	}
 
-	/// Returns true if the current tree selection points to a request (not a collection or folder).
-	fn is_selection_a_request(&self) -> bool {
+	/// Collection index of the tree selection and, unless a collection is selected, the folder
+	/// or request it points to
+	pub fn tree_selection(&self) -> Option<(usize, Option<TreeElement>)> {
 		let selected = self.collections_tree.state.selected();
-		match selected.len() {
-			2 => {
-				// Check if this is a root request or a folder
-				let collection_index = selected[0];
-				let child_index = selected[1];
-				let folder_count = self.core.collections[collection_index].folders.len();
-				child_index >= folder_count
+		let (collection_index, tree_path) = selected.split_first()?;
+
+		match tree_path.is_empty() {
+			true => Some((*collection_index, None)),
+			false => {
+				let element =
+					self.core.collections[*collection_index].resolve_tree_path(tree_path)?;
+				Some((*collection_index, Some(element)))
 			}
-			3 => true,
-			_ => false,
 		}
 	}
 
+	/// Returns true if the current tree selection points to a request (not a collection or folder).
+	fn is_selection_a_request(&self) -> bool {
+		matches!(
+			self.tree_selection(),
+			Some((_, Some(TreeElement::Request(_, _))))
+		)
+	}
+
 	pub fn select_request(&mut self) {
 		if self.is_selection_a_request() {
 			let collection_index = self.collections_tree.state.selected()[0];
-			let folder_count = self.core.collections[collection_index].folders.len();
 			self.collections_tree
-				.set_selected_with_context(folder_count);
+				.set_selected_with_context(&self.core.collections[collection_index]);
 			self.tui_update_request_param_tab();
 			self.tui_update_request_result_tab();
+			self.response_view.body_search.clear();
 			self.tui_update_query_params_selection();
 			self.tui_update_headers_selection();
 
@@ -244,30 +324,13 @@ impl App<'_> {
 	}
 
 	pub fn select_request_or_expand_collection(&mut self) {
-		let selected = self.collections_tree.state.selected();
-		match selected.len() {
-			1 => {
-				// Collection level - toggle expand/collapse
+		match self.tree_selection() {
+			// Collection or folder level - toggle expand/collapse
+			Some((_, None)) | Some((_, Some(TreeElement::Folder(_)))) => {
 				self.collections_tree.state.toggle_selected();
 			}
-			2 => {
-				let collection_index = selected[0];
-				let child_index = selected[1];
-				let folder_count = self.core.collections[collection_index].folders.len();
-
-				if child_index < folder_count {
-					// This is a folder - toggle expand/collapse
-					self.collections_tree.state.toggle_selected();
-				} else {
-					// This is a root request - select it
-					self.select_request();
-				}
-			}
-			3 => {
-				// Request inside a folder - select it
-				self.select_request();
-			}
-			_ => {}
+			Some((_, Some(TreeElement::Request(_, _)))) => self.select_request(),
+			None => {}
 		}
 	}
 
@@ -294,11 +357,19 @@ impl App<'_> {
 	pub fn tui_new_folder(&mut self) {
 		let new_folder_name = self.collection_popups.new_collection_input.to_string();
 
-		// Determine which collection to add the folder to
-		let selected = self.collections_tree.state.selected();
-		let collection_index = if !selected.is_empty() { selected[0] } else { 0 };
+		// Determine which collection, and which folder, to add the folder to
+		let (collection_index, parent_path) = match self.tree_selection() {
+			Some((collection_index, Some(TreeElement::Folder(folder_path)))) => {
+				(collection_index, folder_path)
+			}
+			Some((collection_index, Some(TreeElement::Request(folder_path, _)))) => {
+				(collection_index, folder_path)
+			}
+			Some((collection_index, None)) => (collection_index, vec![]),
+			None => (0, vec![]),
+		};
 
-		match self.new_folder(collection_index, new_folder_name) {
+		match self.new_folder(collection_index, &parent_path, new_folder_name) {
 			Ok(_) => {}
 			Err(_) => return,
 		}
@@ -317,7 +388,10 @@ impl App<'_> {
 
 		let selected_collection_index =
 			self.collection_popups.new_request_popup.selected_collection;
-		let selected_folder = self.collection_popups.new_request_popup.selected_folder;
+		let selected_folder_path = self
+			.collection_popups
+			.new_request_popup
+			.selected_folder_path();
 		let protocol = self.collection_popups.new_request_popup.protocol.clone();
 
 		let new_request = Request {
@@ -328,11 +402,13 @@ impl App<'_> {
 			..Default::default()
 		};
 
-		let result = match selected_folder {
-			Some(folder_index) => {
-				self.new_request_in_folder(selected_collection_index, folder_index, new_request)
-			}
-			None => self.new_request(selected_collection_index, new_request),
+		let result = match selected_folder_path.is_empty() {
+			false => self.new_request_in_folder(
+				selected_collection_index,
+				&selected_folder_path,
+				new_request,
+			),
+			true => self.new_request(selected_collection_index, new_request),
 		};
 
 		match result {
@@ -344,21 +420,12 @@ impl App<'_> {
 	}
 
 	pub fn delete_element(&mut self) {
-		let selected = self.collections_tree.state.selected();
-		match selected.len() {
+		match self.tree_selection() {
 			// Selection on a collection
-			1 => self.delete_collection_state(),
-			2 => {
-				let collection_index = selected[0];
-				let child_index = selected[1];
-				match self.core.collections[collection_index].resolve_child(child_index) {
-					ChildRef::Folder(_) => self.delete_folder_state(),
-					ChildRef::RootRequest(_) => self.delete_request_state(),
-				}
-			}
-			// Selection on a request inside a folder
-			3 => self.delete_request_state(),
-			_ => {}
+			Some((_, None)) => self.delete_collection_state(),
+			Some((_, Some(TreeElement::Folder(_)))) => self.delete_folder_state(),
+			Some((_, Some(TreeElement::Request(_, _)))) => self.delete_request_state(),
+			None => {}
 		}
 	}
 
@@ -374,72 +441,78 @@ impl App<'_> {
 	}
 
 	pub fn tui_delete_request(&mut self) {
-		let selected = self.collections_tree.state.selected().to_vec();
+		let tree_selection = self.tree_selection();
 
 		self.collections_tree.state.select(Vec::new());
 		self.collections_tree.selected = None;
 
-		match selected.len() {
-			2 => {
-				// Root-level request
-				let collection_index = selected[0];
-				let child_index = selected[1];
-				let folder_count = self.core.collections[collection_index].folders.len();
-				let request_index = child_index - folder_count;
-
-				match self.delete_request(collection_index, request_index) {
-					Ok(_) => {}
-					Err(_) => return,
-				}
-			}
-			3 => {
-				// Request inside a folder
-				let collection_index = selected[0];
-				let folder_index = selected[1];
-				let request_index = selected[2];
-
-				match self.delete_folder_request(collection_index, folder_index, request_index) {
-					Ok(_) => {}
-					Err(_) => return,
-				}
-			}
-			_ => return,
+		let Some((collection_index, Some(TreeElement::Request(folder_path, request_index)))) =
+			tree_selection
+		else {
+			return;
+		};
+
+		let result = match folder_path.is_empty() {
+			true => self.delete_request(collection_index, request_index),
+			false => self.delete_folder_request(collection_index, &folder_path, request_index),
+		};
+
+		match result {
+			Ok(_) => {}
+			Err(_) => return,
 		}
 
 		self.normal_state();
 	}
 
 	pub fn tui_delete_folder(&mut self) {
-		let selected = self.collections_tree.state.selected().to_vec();
+		let tree_selection = self.tree_selection();
 
 		self.collections_tree.state.select(Vec::new());
 		self.collections_tree.selected = None;
 
-		if selected.len() == 2 {
-			let collection_index = selected[0];
-			let folder_index = selected[1];
-			self.delete_folder(collection_index, folder_index);
+		if let Some((collection_index, Some(TreeElement::Folder(folder_path)))) = tree_selection {
+			self.delete_folder(collection_index, &folder_path);
 		}
 
 		self.normal_state();
 	}
 
+	/// Collection index, and folder path (empty for the collection itself) when a folder is
+	/// selected in the tree
+	pub fn selected_collection_or_folder(&self) -> Option<(usize, Vec<usize>)> {
+		match self.tree_selection()? {
+			(collection_index, None) => Some((collection_index, vec![])),
+			(collection_index, Some(TreeElement::Folder(folder_path))) => {
+				Some((collection_index, folder_path))
+			}
+			(_, Some(TreeElement::Request(_, _))) => None,
+		}
+	}
+
+	pub fn tui_modify_collection_properties(&mut self) {
+		let Some((collection_index, folder_path)) = self.selected_collection_or_folder() else {
+			return;
+		};
+
+		let yaml = self.collection_popups.properties_text_area.to_string();
+
+		match serde_yaml_ng::from_str::<SharedProperties>(&yaml) {
+			Ok(shared_properties) => {
+				self.set_shared_properties(collection_index, &folder_path, shared_properties);
+				self.normal_state();
+			}
+			Err(error) => self.collection_popups.properties_error = Some(error.to_string()),
+		}
+	}
+
 	pub fn rename_element(&mut self) {
-		let selected = self.collections_tree.state.selected();
-		match selected.len() {
+		match self.tree_selection() {
 			// Selection on a collection
-			1 => self.rename_collection_state(),
-			2 => {
-				let collection_index = selected[0];
-				let child_index = selected[1];
-				match self.core.collections[collection_index].resolve_child(child_index) {
-					ChildRef::Folder(_) => self.rename_folder_state(),
-					ChildRef::RootRequest(_) => self.rename_request_state(),
-				}
-			}
-			// Selection on a request inside a folder
-			3 => self.rename_request_state(),
-			_ => {}
+			Some((_, None)) => self.rename_collection_state(),
+			Some((_, Some(TreeElement::Folder(_)))) => self.rename_folder_state(),
+			Some((_, Some(TreeElement::Request(_, _)))) => self.rename_request_state(),
+			None => {}
 		}
 	}
 
@@ -457,36 +530,26 @@ impl App<'_> {
 
 	pub fn tui_rename_request(&mut self) {
 		let new_request_name = self.collection_popups.rename_request_input.to_string();
-		let selected = self.collections_tree.state.selected();
 
-		match selected.len() {
-			2 => {
-				let collection_index = selected[0];
-				let child_index = selected[1];
-				let folder_count = self.core.collections[collection_index].folders.len();
-				let request_index = child_index - folder_count;
+		let Some((collection_index, Some(TreeElement::Request(folder_path, request_index)))) =
+			self.tree_selection()
+		else {
+			return;
+		};
 
-				match self.rename_request(collection_index, request_index, new_request_name) {
-					Ok(_) => {}
-					Err(_) => return,
-				}
-			}
-			3 => {
-				let collection_index = selected[0];
-				let folder_index = selected[1];
-				let request_index = selected[2];
-
-				match self.rename_folder_request(
-					collection_index,
-					folder_index,
-					request_index,
-					new_request_name,
-				) {
-					Ok(_) => {}
-					Err(_) => return,
-				}
-			}
-			_ => return,
+		let result = match folder_path.is_empty() {
+			true => self.rename_request(collection_index, request_index, new_request_name),
+			false => self.rename_folder_request(
+				collection_index,
+				&folder_path,
+				request_index,
+				new_request_name,
+			),
+		};
+
+		match result {
+			Ok(_) => {}
+			Err(_) => return,
 		}
 
 		self.normal_state();
@@ -494,13 +557,11 @@ impl App<'_> {
 
 	pub fn tui_rename_folder(&mut self) {
 		let new_folder_name = self.collection_popups.rename_collection_input.to_string();
-		let selected = self.collections_tree.state.selected();
-
-		if selected.len() == 2 {
-			let collection_index = selected[0];
-			let folder_index = selected[1];
 
-			match self.rename_folder(collection_index, folder_index, new_folder_name) {
+		if let Some((collection_index, Some(TreeElement::Folder(folder_path)))) =
+			self.tree_selection()
+		{
+			match self.rename_folder(collection_index, &folder_path, new_folder_name) {
 				Ok(_) => {}
 				Err(_) => return,
 			}
@@ -510,50 +571,36 @@ impl App<'_> {
 	}
 
 	pub fn duplicate_element(&mut self) {
-		let selected = self.collections_tree.state.selected();
-		match selected.len() {
+		match self.tree_selection() {
 			// Selection on a collection
-			1 => {
-				let collection_index = selected[0];
+			Some((collection_index, None)) => {
 				let _ = self.duplicate_collection(collection_index);
 			}
-			2 => {
-				let collection_index = selected[0];
-				let child_index = selected[1];
-				match self.core.collections[collection_index].resolve_child(child_index) {
-					ChildRef::Folder(folder_index) => {
-						let _ = self.duplicate_folder(collection_index, folder_index);
-					}
-					ChildRef::RootRequest(request_index) => {
-						let _ = self.duplicate_request(collection_index, request_index);
-					}
-				}
+			Some((collection_index, Some(TreeElement::Folder(folder_path)))) => {
+				let _ = self.duplicate_folder(collection_index, &folder_path);
 			}
-			3 => {
-				let collection_index = selected[0];
-				let folder_index = selected[1];
-				let request_index = selected[2];
-				let _ =
-					self.duplicate_folder_request(collection_index, folder_index, request_index);
+			Some((collection_index, Some(TreeElement::Request(folder_path, request_index)))) => {
+				let _ = match folder_path.is_empty() {
+					true => self.duplicate_request(collection_index, request_index),
+					false => {
+						self.duplicate_folder_request(collection_index, &folder_path, request_index)
+					}
+				};
 			}
-			_ => {}
+			None => {}
 		}
 	}
 
 	pub fn tui_move_element_up(&mut self) {
-		let selected = self.collections_tree.state.selected();
-		match selected.len() {
-			1 => self.tui_move_collection_up(),
-			2 => {
-				let collection_index = selected[0];
-				let child_index = selected[1];
-				match self.core.collections[collection_index].resolve_child(child_index) {
-					ChildRef::Folder(_) => self.tui_move_folder_up(),
-					ChildRef::RootRequest(_) => self.tui_move_request_up(),
-				}
+		match self.tree_selection() {
+			Some((_, None)) => self.tui_move_collection_up(),
+			Some((collection_index, Some(TreeElement::Folder(folder_path)))) => {
+				self.tui_move_folder_up(collection_index, &folder_path)
 			}
-			3 => self.tui_move_folder_request_up(),
-			_ => {}
+			Some((collection_index, Some(TreeElement::Request(folder_path, request_index)))) => {
+				self.tui_move_request_up(collection_index, &folder_path, request_index)
+			}
+			None => {}
 		}
 	}
 
@@ -578,99 +625,65 @@ impl App<'_> {
 		self.update_collections_last_position();
 	}
 
-	pub fn tui_move_request_up(&mut self) {
-		let mut selection = self.collections_tree.state.selected().to_vec();
-		let collection_index = selection[0];
-		let folder_count = self.core.collections[collection_index].folders.len();
-
-		// Cannot move above the first root request (which is at tree index folder_count)
-		if selection[1] <= folder_count {
+	/// Move a request up among the requests of its folder, or the root-level ones
+	pub fn tui_move_request_up(
+		&mut self,
+		collection_index: usize,
+		folder_path: &[usize],
+		request_index: usize,
+	) {
+		// Cannot move above the first request, which comes right after the folders
+		if request_index == 0 {
 			return;
 		}
 
-		let request_index = selection[1] - folder_count;
-
-		// Retrieve the request
-		let request = self.core.collections[collection_index]
-			.requests
-			.remove(request_index);
-
-		// Decrement selection
-		selection[1] -= 1;
+		let mut selection = self.collections_tree.state.selected().to_vec();
+		let requests = self.core.collections[collection_index].requests_in_mut(folder_path);
 
-		// Insert the request at its new index
-		self.core.collections[collection_index]
-			.requests
-			.insert(request_index - 1, request);
+		// Retrieve the request and insert it at its new index
+		let request = requests.remove(request_index);
+		requests.insert(request_index - 1, request);
 
 		// Update the selection in order to move with the element
-		self.collections_tree.state.select(selection.clone());
+		*selection.last_mut().expect("selection should not be empty") -= 1;
+		self.collections_tree.state.select(selection);
 
 		self.save_collection_to_file(collection_index);
 	}
 
-	pub fn tui_move_folder_up(&mut self) {
-		let mut selection = self.collections_tree.state.selected().to_vec();
-		let collection_index = selection[0];
-		let folder_index = selection[1];
+	/// Move a folder up among its sibling folders
+	pub fn tui_move_folder_up(&mut self, collection_index: usize, folder_path: &[usize]) {
+		let Some((folder_index, parent_path)) = folder_path.split_last() else {
+			return;
+		};
 
 		// Cannot move above the first folder
-		if folder_index == 0 {
+		if *folder_index == 0 {
 			return;
 		}
 
-		let folder = self.core.collections[collection_index]
-			.folders
-			.remove(folder_index);
-
-		selection[1] -= 1;
-
-		self.core.collections[collection_index]
-			.folders
-			.insert(folder_index - 1, folder);
-
-		self.collections_tree.state.select(selection.clone());
-		self.save_collection_to_file(collection_index);
-	}
-
-	pub fn tui_move_folder_request_up(&mut self) {
 		let mut selection = self.collections_tree.state.selected().to_vec();
-		let collection_index = selection[0];
-		let folder_index = selection[1];
-		let request_index = selection[2];
-
-		if request_index == 0 {
-			return;
-		}
-
-		let request = self.core.collections[collection_index].folders[folder_index]
-			.requests
-			.remove(request_index);
+		let folders = self.core.collections[collection_index].folders_in_mut(parent_path);
 
-		selection[2] -= 1;
+		let folder = folders.remove(*folder_index);
+		folders.insert(folder_index - 1, folder);
 
-		self.core.collections[collection_index].folders[folder_index]
-			.requests
-			.insert(request_index - 1, request);
+		*selection.last_mut().expect("selection should not be empty") -= 1;
+		self.collections_tree.state.select(selection);
 
-		self.collections_tree.state.select(selection.clone());
 		self.save_collection_to_file(collection_index);
 	}
 
 	pub fn tui_move_element_down(&mut self) {
-		let selected = self.collections_tree.state.selected();
-		match selected.len() {
-			1 => self.tui_move_collection_down(),
-			2 => {
-				let collection_index = selected[0];
-				let child_index = selected[1];
-				match self.core.collections[collection_index].resolve_child(child_index) {
-					ChildRef::Folder(_) => self.tui_move_folder_down(),
-					ChildRef::RootRequest(_) => self.tui_move_request_down(),
-				}
+		match self.tree_selection() {
+			Some((_, None)) => self.tui_move_collection_down(),
+			Some((collection_index, Some(TreeElement::Folder(folder_path)))) => {
+				self.tui_move_folder_down(collection_index, &folder_path)
 			}
-			3 => self.tui_move_folder_request_down(),
-			_ => {}
+			Some((collection_index, Some(TreeElement::Request(folder_path, request_index)))) => {
+				self.tui_move_request_down(collection_index, &folder_path, request_index)
+			}
+			None => {}
 		}
 	}
 
@@ -695,84 +708,51 @@ impl App<'_> {
 		self.update_collections_last_position();
 	}
 
-	pub fn tui_move_request_down(&mut self) {
+	/// Move a request down among the requests of its folder, or the root-level ones
+	pub fn tui_move_request_down(
+		&mut self,
+		collection_index: usize,
+		folder_path: &[usize],
+		request_index: usize,
+	) {
 		let mut selection = self.collections_tree.state.selected().to_vec();
-		let collection_index = selection[0];
-		let folder_count = self.core.collections[collection_index].folders.len();
-		let request_index = selection[1] - folder_count;
+		let requests = self.core.collections[collection_index].requests_in_mut(folder_path);
 
 		// Cannot increment selection further
-		if request_index == self.core.collections[collection_index].requests.len() - 1 {
+		if request_index == requests.len() - 1 {
 			return;
 		}
 
-		// Retrieve the request
-		let request = self.core.collections[collection_index]
-			.requests
-			.remove(request_index);
-
-		// Increment selection
-		selection[1] += 1;
-
-		// Insert the request at its new index
-		self.core.collections[collection_index]
-			.requests
-			.insert(request_index + 1, request);
+		// Retrieve the request and insert it at its new index
+		let request = requests.remove(request_index);
+		requests.insert(request_index + 1, request);
 
 		// Update the selection in order to move with the element
-		self.collections_tree.state.select(selection.clone());
+		*selection.last_mut().expect("selection should not be empty") += 1;
+		self.collections_tree.state.select(selection);
 
 		self.save_collection_to_file(collection_index);
 	}
 
-	pub fn tui_move_folder_down(&mut self) {
-		let mut selection = self.collections_tree.state.selected().to_vec();
-		let collection_index = selection[0];
-		let folder_index = selection[1];
-
-		if folder_index == self.core.collections[collection_index].folders.len() - 1 {
+	/// Move a folder down among its sibling folders
+	pub fn tui_move_folder_down(&mut self, collection_index: usize, folder_path: &[usize]) {
+		let Some((folder_index, parent_path)) = folder_path.split_last() else {
 			return;
-		}
-
-		let folder = self.core.collections[collection_index]
-			.folders
-			.remove(folder_index);
-
-		selection[1] += 1;
-
-		self.core.collections[collection_index]
-			.folders
-			.insert(folder_index + 1, folder);
-
-		self.collections_tree.state.select(selection.clone());
-		self.save_collection_to_file(collection_index);
-	}
+		};
 
-	pub fn tui_move_folder_request_down(&mut self) {
 		let mut selection = self.collections_tree.state.selected().to_vec();
-		let collection_index = selection[0];
-		let folder_index = selection[1];
-		let request_index = selection[2];
-
-		if request_index
-			== self.core.collections[collection_index].folders[folder_index]
-				.requests
-				.len() - 1
-		{
+		let folders = self.core.collections[collection_index].folders_in_mut(parent_path);
+
+		if *folder_index == folders.len() - 1 {
 			return;
 		}
 
-		let request = self.core.collections[collection_index].folders[folder_index]
-			.requests
-			.remove(request_index);
+		let folder = folders.remove(*folder_index);
+		folders.insert(folder_index + 1, folder);
 
-		selection[2] += 1;
+		*selection.last_mut().expect("selection should not be empty") += 1;
+		self.collections_tree.state.select(selection);
 
-		self.core.collections[collection_index].folders[folder_index]
-			.requests
-			.insert(request_index + 1, request);
-
-		self.collections_tree.state.select(selection.clone());
 		self.save_collection_to_file(collection_index);
 	}
 }
@@ -4,6 +4,7 @@ use ratatui::style::Stylize;
 use ratatui::text::{Line, Span};
 use regex::Regex;
 use std::sync::Arc;
+use tracing::warn;
 
 use crate::app::App;
 use crate::app::files::environment::OS_ENV_VARS;
@@ -80,15 +81,22 @@ impl App<'_> {
 			}
 		}
 
-		// Fall back to global environment
+		// Fall back to global environment, secret values being masked unless revealed
 		let rows: Vec<KeyValue> = match self.get_selected_env_as_local() {
 			Some(local_env) => {
 				let env = local_env.read();
 				env.values
 					.iter()
-					.map(|(key, value)| KeyValue {
-						enabled: true,
-						data: (key.clone(), value.clone()),
+					.map(|(key, value)| {
+						let value = match self.reveal_secrets {
+							true => value,
+							false => env.displayed_value(key, value),
+						};
+
+						KeyValue {
+							enabled: true,
+							data: (key.clone(), value.to_string()),
+						}
 					})
 					.collect()
 			}
@@ -204,6 +212,50 @@ impl App<'_> {
 		self.tui_update_env_variable_table();
 	}
 
+	/// Only the keys of the global environments can be secret
+	pub fn tui_toggle_env_variable_secret(&mut self) {
+		if self.tui_active_collection_has_envs() {
+			return;
+		}
+
+		let Some((row, _)) = self.env_editor_table.selection else {
+			return;
+		};
+
+		let Some(local_env) = self.get_selected_env_as_local() else {
+			return;
+		};
+
+		let Some((key, is_secret)) = ({
+			let env = local_env.read();
+			env.values
+				.get_index(row)
+				.map(|(key, _)| (key.clone(), env.is_secret(key)))
+		}) else {
+			return;
+		};
+
+		let selected_env_index = self.core.selected_environment;
+
+		if let Err(e) = self.set_env_key_secret(selected_env_index, &key, !is_secret) {
+			warn!("Could not change the secret key \"{key}\": {e}");
+		}
+
+		self.tui_update_env_variable_table();
+		self.env_editor_table.update_selection(Some((row, 0)));
+	}
+
+	pub fn tui_toggle_reveal_secrets(&mut self) {
+		let selection = self.env_editor_table.selection;
+
+		self.reveal_secrets = !self.reveal_secrets;
+		self.tui_update_env_variable_table();
+
+		if selection.is_some() {
+			self.env_editor_table.update_selection(selection);
+		}
+	}
+
 	pub fn tui_delete_env_variable(&mut self) {
 		if self.env_editor_table.rows.is_empty() || self.env_editor_table.selection.is_none() {
 			return;
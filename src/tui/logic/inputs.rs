@@ -24,6 +24,10 @@ macro_rules! for_all_inputs {
 			let $input = &mut $self.collection_popups.rename_request_input;
 			$body;
 		}
+		{
+			let $input = &mut $self.request_editor.settings_popup.text_input;
+			$body;
+		}
 		{
 			let $input = &mut $self.request_editor.url_input;
 			$body;
@@ -76,6 +80,58 @@ macro_rules! for_all_inputs {
 			let $input = &mut $self.request_editor.auth.digest_opaque;
 			$body;
 		}
+		{
+			let $input = &mut $self.request_editor.auth.oauth2_token_url;
+			$body;
+		}
+		{
+			let $input = &mut $self.request_editor.auth.oauth2_auth_url;
+			$body;
+		}
+		{
+			let $input = &mut $self.request_editor.auth.oauth2_client_id;
+			$body;
+		}
+		{
+			let $input = &mut $self.request_editor.auth.oauth2_client_secret;
+			$body;
+		}
+		{
+			let $input = &mut $self.request_editor.auth.oauth2_scope;
+			$body;
+		}
+		{
+			let $input = &mut $self.request_editor.auth.oauth2_username;
+			$body;
+		}
+		{
+			let $input = &mut $self.request_editor.auth.oauth2_password;
+			$body;
+		}
+		{
+			let $input = &mut $self.request_editor.auth.oauth2_refresh_token;
+			$body;
+		}
+		{
+			let $input = &mut $self.request_editor.auth.aws_sig_v4_access_key_id;
+			$body;
+		}
+		{
+			let $input = &mut $self.request_editor.auth.aws_sig_v4_secret_access_key;
+			$body;
+		}
+		{
+			let $input = &mut $self.request_editor.auth.aws_sig_v4_region;
+			$body;
+		}
+		{
+			let $input = &mut $self.request_editor.auth.aws_sig_v4_service;
+			$body;
+		}
+		{
+			let $input = &mut $self.request_editor.auth.aws_sig_v4_session_token;
+			$body;
+		}
 		{
 			let $input = &mut $self.request_editor.headers_table.selection_text_input;
 			$body;
@@ -128,6 +184,14 @@ macro_rules! for_all_inputs {
 			let $input = &mut $self.script_console.post_request_text_area;
 			$body;
 		}
+		{
+			let $input = &mut $self.response_view.body_filter_input;
+			$body;
+		}
+		{
+			let $input = &mut $self.response_view.body_save_input;
+			$body;
+		}
 	}};
 }
 
@@ -201,6 +265,15 @@ impl App<'_> {
 			.selection_text_input
 			.insert_mode_only = true;
 
+		// The response body search is not cleared with the other inputs, and is always typed in
+		// insert mode
+		let body_search_input = &mut self.response_view.body_search.input;
+		body_search_input.default_mode = EditorMode::Insert;
+		body_search_input.insert_mode_only = true;
+		body_search_input.is_single_line = true;
+		body_search_input.update_handler();
+		body_search_input.reset_mode();
+
 		self.reset_inputs_mode();
 	}
 }
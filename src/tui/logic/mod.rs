@@ -4,5 +4,6 @@ pub(crate) mod environment;
 pub(crate) mod inputs;
 pub(crate) mod param_tabs;
 pub(crate) mod request;
+pub(crate) mod response_body;
 pub(crate) mod result_tabs;
 pub(crate) mod utils;
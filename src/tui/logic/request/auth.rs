@@ -1,5 +1,7 @@
 use crate::app::App;
-use crate::models::auth::auth::Auth::{BasicAuth, BearerToken, Digest, JwtToken, NoAuth};
+use crate::models::auth::auth::Auth::{
+	AwsSigV4, BasicAuth, BearerToken, Digest, Inherit, JwtToken, NoAuth, OAuth2,
+};
 use crate::models::auth::auth::next_auth;
 use crate::models::auth::digest::{
 	next_digest_algorithm, next_digest_qop, previous_digest_algorithm, previous_digest_qop,
@@ -8,6 +10,7 @@ use crate::models::auth::digest::{
 use crate::models::auth::jwt::{
 	next_jwt_algorithm, next_jwt_secret_type, previous_jwt_algorithm, previous_jwt_secret_type,
 };
+use crate::models::auth::oauth2::{next_oauth2_grant_type, previous_oauth2_grant_type};
 use crate::tui::ui::views::RequestView;
 use tracing::info;
 
@@ -17,7 +20,7 @@ macro_rules! define_auth_modify_handlers {
 	);* $(;)?) => {
 		$(
 			pub fn $fn_name(&mut self) {
-				let Some(idx) = self.collections_tree.selected else { return };
+				let Some(idx) = self.collections_tree.selected.clone() else { return };
 				let input_text = self.request_editor.auth.$input_field.to_string();
 				self.$modifier(idx.collection_index(), idx.request_index(), input_text);
 				self.select_request_state();
@@ -39,10 +42,23 @@ impl App<'_> {
 		tui_modify_request_auth_digest_realm, digest_realm, modify_request_auth_digest_realm;
 		tui_modify_request_auth_digest_nonce, digest_nonce, modify_request_auth_digest_nonce;
 		tui_modify_request_auth_digest_opaque, digest_opaque, modify_request_auth_digest_opaque;
+		tui_modify_request_auth_oauth2_token_url, oauth2_token_url, modify_request_auth_oauth2_token_url;
+		tui_modify_request_auth_oauth2_auth_url, oauth2_auth_url, modify_request_auth_oauth2_auth_url;
+		tui_modify_request_auth_oauth2_client_id, oauth2_client_id, modify_request_auth_oauth2_client_id;
+		tui_modify_request_auth_oauth2_client_secret, oauth2_client_secret, modify_request_auth_oauth2_client_secret;
+		tui_modify_request_auth_oauth2_scope, oauth2_scope, modify_request_auth_oauth2_scope;
+		tui_modify_request_auth_oauth2_username, oauth2_username, modify_request_auth_oauth2_username;
+		tui_modify_request_auth_oauth2_password, oauth2_password, modify_request_auth_oauth2_password;
+		tui_modify_request_auth_oauth2_refresh_token, oauth2_refresh_token, modify_request_auth_oauth2_refresh_token;
+		tui_modify_request_auth_aws_sig_v4_access_key_id, aws_sig_v4_access_key_id, modify_request_auth_aws_sig_v4_access_key_id;
+		tui_modify_request_auth_aws_sig_v4_secret_access_key, aws_sig_v4_secret_access_key, modify_request_auth_aws_sig_v4_secret_access_key;
+		tui_modify_request_auth_aws_sig_v4_region, aws_sig_v4_region, modify_request_auth_aws_sig_v4_region;
+		tui_modify_request_auth_aws_sig_v4_service, aws_sig_v4_service, modify_request_auth_aws_sig_v4_service;
+		tui_modify_request_auth_aws_sig_v4_session_token, aws_sig_v4_session_token, modify_request_auth_aws_sig_v4_session_token;
 	}
 
 	pub fn tui_next_request_auth(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -64,7 +80,7 @@ impl App<'_> {
 		let selected_request = local_selected_request.read();
 
 		match selected_request.auth {
-			NoAuth => {}
+			NoAuth | Inherit => {}
 			BasicAuth(_) => match self.request_editor.auth.text_input_selection.selected {
 				0 => self.edit_request_auth_username_state(),
 				1 => self.edit_request_auth_password_state(),
@@ -91,6 +107,25 @@ impl App<'_> {
 				5 => self.edit_request_auth_digest_opaque_state(),
 				_ => {}
 			},
+			OAuth2(_) => match self.request_editor.auth.text_input_selection.selected {
+				1 => self.edit_request_auth_oauth2_token_url_state(),
+				2 => self.edit_request_auth_oauth2_auth_url_state(),
+				3 => self.edit_request_auth_oauth2_client_id_state(),
+				4 => self.edit_request_auth_oauth2_client_secret_state(),
+				5 => self.edit_request_auth_oauth2_scope_state(),
+				6 => self.edit_request_auth_oauth2_username_state(),
+				7 => self.edit_request_auth_oauth2_password_state(),
+				8 => self.edit_request_auth_oauth2_refresh_token_state(),
+				_ => {}
+			},
+			AwsSigV4(_) => match self.request_editor.auth.text_input_selection.selected {
+				0 => self.edit_request_auth_aws_sig_v4_access_key_id_state(),
+				1 => self.edit_request_auth_aws_sig_v4_secret_access_key_state(),
+				2 => self.edit_request_auth_aws_sig_v4_region_state(),
+				3 => self.edit_request_auth_aws_sig_v4_service_state(),
+				4 => self.edit_request_auth_aws_sig_v4_session_token_state(),
+				_ => {}
+			},
 		}
 	}
 
@@ -108,7 +143,7 @@ impl App<'_> {
 		};
 
 		match request_auth {
-			NoAuth => {}
+			NoAuth | Inherit => {}
 			BasicAuth(_) => {}
 			BearerToken(_) => {}
 			JwtToken(_) => match self.request_editor.auth.text_input_selection.selected {
@@ -124,6 +159,13 @@ impl App<'_> {
 				10 => self.tui_request_auth_toggle_digest_charset(),
 				_ => {}
 			},
+			OAuth2(_) => match self.request_editor.auth.text_input_selection.selected {
+				0 => self.tui_request_auth_previous_oauth2_grant_type(),
+				9 => self.tui_request_auth_toggle_oauth2_pkce(),
+				10 => self.tui_request_auth_clear_oauth2_token(),
+				_ => {}
+			},
+			AwsSigV4(_) => {}
 		}
 	}
 
@@ -141,7 +183,7 @@ impl App<'_> {
 		};
 
 		match request_auth {
-			NoAuth => {}
+			NoAuth | Inherit => {}
 			BasicAuth(_) => {}
 			BearerToken(_) => {}
 			JwtToken(_) => match self.request_editor.auth.text_input_selection.selected {
@@ -157,11 +199,18 @@ impl App<'_> {
 				10 => self.tui_request_auth_toggle_digest_charset(),
 				_ => {}
 			},
+			OAuth2(_) => match self.request_editor.auth.text_input_selection.selected {
+				0 => self.tui_request_auth_next_oauth2_grant_type(),
+				9 => self.tui_request_auth_toggle_oauth2_pkce(),
+				10 => self.tui_request_auth_clear_oauth2_token(),
+				_ => {}
+			},
+			AwsSigV4(_) => {}
 		}
 	}
 
 	pub fn tui_request_auth_previous_jwt_algorithm(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -185,7 +234,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_request_auth_next_jwt_algorithm(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -209,7 +258,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_request_auth_previous_jwt_secret_type(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -230,7 +279,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_request_auth_next_jwt_secret_type(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -251,7 +300,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_request_auth_toggle_digest_stale(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -272,7 +321,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_request_auth_previous_digest_algorithm(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -293,7 +342,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_request_auth_next_digest_algorithm(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -314,7 +363,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_request_auth_previous_digest_qop(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -335,7 +384,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_request_auth_next_digest_qop(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -356,7 +405,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_request_auth_toggle_digest_user_hash(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -377,7 +426,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_request_auth_toggle_digest_charset(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -396,4 +445,80 @@ impl App<'_> {
 		self.save_collection_to_file(selected.collection_index());
 		self.select_request_state();
 	}
+
+	pub fn tui_request_auth_previous_oauth2_grant_type(&mut self) {
+		let Some(selected) = self.collections_tree.selected.clone() else {
+			return;
+		};
+		let local_selected_request = self.get_request_from_selection(&selected);
+
+		{
+			let mut selected_request = local_selected_request.write();
+			let oauth2 = selected_request.auth.get_oauth2_mut();
+
+			let previous_grant_type = previous_oauth2_grant_type(&oauth2.grant_type);
+
+			info!("Auth OAuth2 grant type set to \"{}\"", previous_grant_type);
+
+			oauth2.grant_type = previous_grant_type;
+		}
+
+		self.save_collection_to_file(selected.collection_index());
+		self.select_request_state();
+	}
+
+	pub fn tui_request_auth_next_oauth2_grant_type(&mut self) {
+		let Some(selected) = self.collections_tree.selected.clone() else {
+			return;
+		};
+		let local_selected_request = self.get_request_from_selection(&selected);
+
+		{
+			let mut selected_request = local_selected_request.write();
+			let oauth2 = selected_request.auth.get_oauth2_mut();
+
+			let new_grant_type = next_oauth2_grant_type(&oauth2.grant_type);
+
+			info!("Auth OAuth2 grant type set to \"{}\"", new_grant_type);
+
+			oauth2.grant_type = new_grant_type;
+		}
+
+		self.save_collection_to_file(selected.collection_index());
+		self.select_request_state();
+	}
+
+	pub fn tui_request_auth_toggle_oauth2_pkce(&mut self) {
+		let Some(selected) = self.collections_tree.selected.clone() else {
+			return;
+		};
+		let local_selected_request = self.get_request_from_selection(&selected);
+
+		{
+			let mut selected_request = local_selected_request.write();
+			let oauth2 = selected_request.auth.get_oauth2_mut();
+
+			let new_pkce = !oauth2.pkce;
+
+			info!("Auth OAuth2 PKCE set to \"{}\"", new_pkce);
+
+			oauth2.pkce = new_pkce;
+		}
+
+		self.save_collection_to_file(selected.collection_index());
+		self.select_request_state();
+	}
+
+	/// Forget the cached token, a new one is requested on the next send
+	pub fn tui_request_auth_clear_oauth2_token(&mut self) {
+		let Some(selected) = self.collections_tree.selected.clone() else {
+			return;
+		};
+		let local_selected_request = self.get_request_from_selection(&selected);
+
+		info!("Auth OAuth2 token cleared");
+
+		self.clear_oauth2_token(&local_selected_request, Some(selected.collection_index()));
+		self.select_request_state();
+	}
 }
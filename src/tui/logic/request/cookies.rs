@@ -1,8 +1,22 @@
+use std::sync::Arc;
+
 use crate::app::App;
 use crate::tui::utils::stateful::cookie_table::COOKIES_COLUMNS_NUMBER;
 use reqwest::Url;
+use reqwest_cookie_store::CookieStoreRwLock;
 
 impl App<'_> {
+	/// Cookie jar of the selected collection, or the global one
+	pub fn tui_cookie_store(&self) -> Arc<CookieStoreRwLock> {
+		let collection_index = self
+			.collections_tree
+			.selected
+			.as_ref()
+			.map(|selected| selected.collection_index());
+
+		self.get_cookie_store(collection_index)
+	}
+
 	pub fn tui_update_cookies_table_selection(&mut self) {
 		match self.core.cookies_popup.cookies_table.rows.is_empty() {
 			false => {
@@ -51,17 +65,18 @@ impl App<'_> {
 			.rows
 			.remove(selection.0);
 
+		let local_cookie_store = self.tui_cookie_store();
+
 		{
-			let mut local_cookie_store = self
-				.core
-				.cookies_popup
-				.cookie_store
+			let mut local_cookie_store = local_cookie_store
 				.write()
 				.expect("cookie store lock poisoned");
 
 			local_cookie_store.remove(&cookie_row[0], &cookie_row[3], &cookie_row[1]);
 		}
 
+		self.save_cookie_stores();
+
 		self.tui_update_cookies_table_selection();
 	}
 
@@ -88,11 +103,10 @@ impl App<'_> {
 		let old_path = row[3].clone();
 
 		// Remove old cookie from the store
+		let local_cookie_store = self.tui_cookie_store();
+
 		{
-			let mut local_cookie_store = self
-				.core
-				.cookies_popup
-				.cookie_store
+			let mut local_cookie_store = local_cookie_store
 				.write()
 				.expect("cookie store lock poisoned");
 			local_cookie_store.remove(&old_domain, &old_path, &old_name);
@@ -103,6 +117,7 @@ impl App<'_> {
 
 		// Re-insert the cookie into the store
 		self.insert_cookie_row_into_store(selection.0);
+		self.save_cookie_stores();
 
 		// Refresh the cookies display (reloads from store and sets state)
 		self.display_cookies_state();
@@ -183,10 +198,8 @@ impl App<'_> {
 		let url_string = format!("{}://{}{}", scheme, domain, path);
 
 		if let Ok(url) = Url::parse(&url_string) {
-			let mut local_cookie_store = self
-				.core
-				.cookies_popup
-				.cookie_store
+			let local_cookie_store = self.tui_cookie_store();
+			let mut local_cookie_store = local_cookie_store
 				.write()
 				.expect("cookie store lock poisoned");
 			let _ = local_cookie_store.parse(&cookie_str, &url);
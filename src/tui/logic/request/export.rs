@@ -1,4 +1,5 @@
 use crate::app::App;
+use crate::app::files::secrets::mask_secrets;
 use crate::tui::utils::syntax_highlighting::highlight;
 use ratatui::prelude::Line;
 use rayon::prelude::*;
@@ -12,11 +13,26 @@ impl App<'_> {
 		{
 			let selected_request = local_selected_request.read();
 
+			let collection_index = self
+				.collections_tree
+				.selected
+				.as_ref()
+				.map(|selected| selected.collection_index());
+
 			let export_format = self.export_request.get_selection();
 			let export_result = self
-				.export_request_to_string_with_format(export_format, &selected_request)
+				.export_request_to_string_with_format(
+					export_format,
+					&selected_request,
+					collection_index,
+				)
 				.unwrap_or_else(|error| error.to_string());
 
+			let export_result = match self.reveal_secrets {
+				true => export_result,
+				false => mask_secrets(&export_result),
+			};
+
 			self.display_request_export.content = export_result.clone();
 			self.display_request_export.title = export_format.to_string();
 			self.display_request_export
@@ -3,7 +3,7 @@ use crate::models::protocol::protocol::Protocol;
 
 impl App<'_> {
 	pub fn tui_modify_graphql_query(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -21,7 +21,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_modify_graphql_variables(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
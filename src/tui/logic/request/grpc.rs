@@ -1,9 +1,15 @@
+use std::time::Duration;
+
 use crate::app::App;
+use crate::app::request::grpc::descriptors::{list_grpc_methods, load_descriptor_pool};
+use crate::app::request::grpc::send::{half_close_grpc_stream, send_grpc_stream_message};
 use crate::models::protocol::protocol::Protocol;
+use crate::tui::utils::stateful::grpc_method_popup::GrpcMethodPopup;
+use tracing::{info, warn};
 
 impl App<'_> {
 	pub fn tui_modify_grpc_proto_file(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -21,7 +27,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_modify_grpc_service(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -39,7 +45,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_modify_grpc_method(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -57,7 +63,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_modify_grpc_message(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -67,9 +73,169 @@ impl App<'_> {
 
 			if let Protocol::GrpcRequest(grpc) = &mut selected_request.protocol {
 				grpc.message = self.grpc_message_text_area.to_string();
+
+				// While a client stream is open, the message is also sent on it
+				if grpc.can_send_messages() {
+					let message = grpc.message.clone();
+
+					match send_grpc_stream_message(grpc, &message) {
+						Ok(()) => {
+							info!("gRPC message sent");
+							*self.core.received_response.lock() = true;
+						}
+						Err(e) => warn!("Could not send gRPC message: {e}"),
+					}
+				}
+			}
+		}
+
+		self.save_collection_to_file(selected.collection_index());
+		self.select_request_state();
+	}
+
+	pub fn tui_half_close_grpc_stream(&mut self) {
+		let Some(local_selected_request) = self.get_selected_request_as_local() else {
+			return;
+		};
+
+		{
+			let mut selected_request = local_selected_request.write();
+
+			if let Protocol::GrpcRequest(grpc) = &mut selected_request.protocol {
+				half_close_grpc_stream(grpc);
+				info!("gRPC stream half-closed");
+			}
+		}
+
+		*self.core.received_response.lock() = true;
+	}
+
+	pub fn tui_toggle_grpc_reflection(&mut self) {
+		let Some(selected) = self.collections_tree.selected.clone() else {
+			return;
+		};
+		let local_selected_request = self.get_request_from_selection(&selected);
+
+		{
+			let mut selected_request = local_selected_request.write();
+
+			if let Protocol::GrpcRequest(grpc) = &mut selected_request.protocol {
+				grpc.reflection = !grpc.reflection;
 			}
 		}
 
+		self.save_collection_to_file(selected.collection_index());
+	}
+
+	pub fn tui_next_grpc_compression(&mut self) {
+		let Some(selected) = self.collections_tree.selected.clone() else {
+			return;
+		};
+		let local_selected_request = self.get_request_from_selection(&selected);
+
+		{
+			let mut selected_request = local_selected_request.write();
+
+			if let Protocol::GrpcRequest(grpc) = &mut selected_request.protocol {
+				grpc.compression = grpc.compression.next();
+			}
+		}
+
+		self.save_collection_to_file(selected.collection_index());
+	}
+
+	/// Load the descriptors of the selected request and list their methods in a popup
+	pub async fn tui_display_grpc_method_picker(&mut self) {
+		let Some(selected) = self.collections_tree.selected.clone() else {
+			return;
+		};
+		let collection_index = selected.collection_index();
+		let local_selected_request = self.get_request_from_selection(&selected);
+
+		let (grpc_request, url, headers, tls, timeout_ms) = {
+			let selected_request = local_selected_request.read();
+
+			let Protocol::GrpcRequest(grpc) = &selected_request.protocol else {
+				return;
+			};
+
+			let url = self.replace_env_keys_for_collection(&selected_request.url, collection_index);
+			let headers: Vec<(String, String)> = self
+				.request_inheritance(&selected_request, Some(collection_index))
+				.resolve_headers(&selected_request.headers)
+				.iter()
+				.filter(|header| header.enabled)
+				.map(|header| {
+					(
+						self.replace_env_keys_for_collection(&header.data.0, collection_index),
+						self.replace_env_keys_for_collection(&header.data.1, collection_index),
+					)
+				})
+				.collect();
+			let timeout_ms = selected_request.settings.timeout.as_u32().unwrap_or(30000) as u64;
+			let tls = self.resolve_tls_settings(&selected_request, Some(collection_index));
+
+			(grpc.clone(), url, headers, tls, timeout_ms)
+		};
+
+		let pool = load_descriptor_pool(
+			&grpc_request,
+			&url,
+			&headers,
+			&tls,
+			Duration::from_millis(timeout_ms),
+		)
+		.await;
+
+		self.grpc_method_popup = match pool {
+			Ok(pool) => {
+				let methods = list_grpc_methods(&pool);
+				let selection = methods
+					.iter()
+					.position(|m| {
+						m.service == grpc_request.service && m.method == grpc_request.method
+					})
+					.unwrap_or(0);
+
+				GrpcMethodPopup {
+					methods,
+					selection,
+					error: None,
+				}
+			}
+			Err(error) => GrpcMethodPopup {
+				error: Some(format!("{error:#}")),
+				..Default::default()
+			},
+		};
+
+		self.choose_grpc_method_state();
+	}
+
+	pub fn tui_select_grpc_method(&mut self) {
+		let Some(selected) = self.collections_tree.selected.clone() else {
+			return;
+		};
+		let Some(method) = self.grpc_method_popup.get_selection().cloned() else {
+			self.select_request_state();
+			return;
+		};
+		let local_selected_request = self.get_request_from_selection(&selected);
+
+		{
+			let mut selected_request = local_selected_request.write();
+
+			if let Protocol::GrpcRequest(grpc) = &mut selected_request.protocol {
+				grpc.service = method.service.clone();
+				grpc.method = method.method.clone();
+			}
+		}
+
+		self.grpc_service_input.clear();
+		self.grpc_service_input.push_str(&method.service);
+		self.grpc_method_input.clear();
+		self.grpc_method_input.push_str(&method.method);
+
 		self.save_collection_to_file(selected.collection_index());
 		self.select_request_state();
 	}
@@ -18,7 +18,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_modify_request_header(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 
@@ -46,7 +46,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_create_new_header(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 
@@ -74,7 +74,7 @@ impl App<'_> {
 		let Some(selection) = self.request_editor.headers_table.selection else {
 			return;
 		};
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 
@@ -102,7 +102,7 @@ impl App<'_> {
 			return;
 		};
 		let row = selection.0;
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 
@@ -130,7 +130,7 @@ impl App<'_> {
 			return;
 		};
 		let row = selection.0;
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 
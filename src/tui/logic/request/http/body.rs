@@ -29,7 +29,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_modify_request_form_data(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 
@@ -59,7 +59,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_create_new_form_data(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 
@@ -87,7 +87,7 @@ impl App<'_> {
 		let Some(selection) = self.request_editor.body_form_table.selection else {
 			return;
 		};
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 
@@ -114,7 +114,7 @@ impl App<'_> {
 		let Some(selection) = self.request_editor.body_form_table.selection else {
 			return;
 		};
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 
@@ -141,7 +141,7 @@ impl App<'_> {
 		let Some(selection) = self.request_editor.body_form_table.selection else {
 			return;
 		};
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 
@@ -160,7 +160,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_modify_request_body(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -197,7 +197,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_next_request_content_type(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
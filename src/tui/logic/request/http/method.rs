@@ -4,7 +4,7 @@ use tracing::info;
 
 impl App<'_> {
 	pub fn tui_next_request_method(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -26,7 +26,7 @@ impl App<'_> {
 			.query_params_table
 			.selection_text_input
 			.to_string();
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 
@@ -49,7 +49,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_create_new_query_param(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 
@@ -77,7 +77,7 @@ impl App<'_> {
 		let Some(selection) = self.request_editor.query_params_table.selection else {
 			return;
 		};
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 
@@ -105,7 +105,7 @@ impl App<'_> {
 			return;
 		};
 		let row = selection.0;
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 
@@ -133,7 +133,7 @@ impl App<'_> {
 			return;
 		};
 		let row = selection.0;
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 
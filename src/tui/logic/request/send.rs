@@ -1,14 +1,19 @@
 use crate::app::App;
+use crate::app::files::history::append_history_entry;
 use crate::app::request::grpc::send::send_grpc_request;
 use crate::app::request::http::send::send_http_request;
+use crate::app::request::oauth2::authorize_prepared_request;
+use crate::app::request::send::PrepareRequestError;
 use crate::app::request::ws::send::send_ws_request;
 use crate::models::auth::auth::Auth;
+use crate::models::history::HistoryEntry;
 use crate::models::protocol::protocol::Protocol;
 use futures_util::SinkExt;
 use reqwest_websocket::CloseCode;
 use std::sync::Arc;
 use tokio::task;
-use tracing::info;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
 
 impl App<'_> {
 	pub async fn tui_send_request(&mut self) {
@@ -31,9 +36,17 @@ impl App<'_> {
 		let ws_disconnect = {
 			let mut selected_request = local_selected_request.write();
 			match &mut selected_request.protocol {
-				Protocol::HttpRequest(_)
-				| Protocol::GraphqlRequest(_)
-				| Protocol::GrpcRequest(_) => None,
+				Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) => None,
+				Protocol::GrpcRequest(grpc_request) => {
+					// Sending again while a stream is open cancels the call
+					if let Some(call) = grpc_request.call.take() {
+						call.cancellation_token.cancel();
+						info!("gRPC call canceled");
+						return;
+					}
+
+					None
+				}
 				Protocol::WsRequest(ws_request) => {
 					if ws_request.is_connected
 						&& let Some(websocket) = ws_request.websocket.clone()
@@ -79,8 +92,21 @@ impl App<'_> {
 			.as_ref()
 			.map(|s| s.collection_index());
 
+		/* OAUTH2 TOKEN */
+
+		// A missing or expired token is requested by the send task, to keep the UI responsive
+		let oauth2_token_request =
+			match self.prepare_oauth2_token(&local_selected_request, collection_index, true) {
+				Ok(oauth2_token_request) => oauth2_token_request,
+				Err(oauth2_error) => {
+					local_selected_request.write().response.status_code =
+						Some(oauth2_error.to_string());
+					return;
+				}
+			};
+
 		// prepare_request is synchronous — safe to call while holding the lock.
-		let (prepared, protocol) = {
+		let (prepared, protocol, tls, inherited_headers) = {
 			let mut selected_request = local_selected_request.write();
 
 			let prepared = match self.prepare_request(&mut selected_request, collection_index) {
@@ -92,11 +118,21 @@ impl App<'_> {
 			};
 
 			let protocol = selected_request.protocol.clone();
-			(prepared, protocol)
+			let tls = self.resolve_tls_settings(&selected_request, collection_index);
+			let inherited_headers = self
+				.request_inheritance(&selected_request, collection_index)
+				.resolve_headers(&selected_request.headers);
+			(prepared, protocol, tls, inherited_headers)
 		};
 		// Guard is dropped here — safe to await for file body finalization
 
-		let prepared_request = match App::finalize_prepared_request(prepared).await {
+		for error in &prepared.unresolved {
+			warn!("{error}, sent as is");
+		}
+
+		let mut sent_request = prepared.sent_request.clone();
+		let connection_timer = prepared.connection_timer.clone();
+		let mut prepared_request = match App::finalize_prepared_request(prepared).await {
 			Ok(builder) => builder,
 			Err(finalize_error) => {
 				let mut selected_request = local_selected_request.write();
@@ -111,34 +147,92 @@ impl App<'_> {
 		let local_env = self.get_selected_env_as_local();
 
 		let local_should_refresh_scrollbars = Arc::clone(&self.core.received_response);
+		let local_pending_captures = Arc::clone(&self.core.pending_captures);
+
+		let max_body_size = self.core.config.get_max_response_body_size();
+		let should_record_history = !self.core.config.is_request_history_disabled();
+		let should_redact_credentials = !self.core.config.should_record_credential_headers();
+		let history_collection = collection_index
+			.and_then(|index| self.core.collections.get(index))
+			.map(|collection| collection.name.clone());
 
 		/* SEND REQUEST */
 
+		// The request can be canceled while its token is requested
+		let oauth2_cancellation_token = oauth2_token_request.is_some().then(|| {
+			let mut selected_request = local_selected_request.write();
+			selected_request.is_pending = true;
+			selected_request.cancellation_token.clone()
+		});
+
 		task::spawn(async move {
-			let response = match protocol {
+			if let (Some(oauth2_token_request), Some(cancellation_token)) =
+				(oauth2_token_request, oauth2_cancellation_token)
+			{
+				let token = tokio::select! {
+					_ = cancellation_token.cancelled() => None,
+					token = oauth2_token_request.acquire() => Some(token),
+				};
+
+				let mut selected_request = local_selected_request.write();
+
+				let status_code = match token {
+					Some(Ok(token)) => {
+						prepared_request =
+							authorize_prepared_request(prepared_request, &mut sent_request, &token);
+
+						// An inherited token is loaded from the cache once the request is selected
+						if let Auth::OAuth2(oauth2) = &mut selected_request.auth {
+							oauth2.token = Some(token);
+						}
+
+						None
+					}
+					Some(Err(oauth2_error)) => {
+						Some(PrepareRequestError::OAuth2(oauth2_error).to_string())
+					}
+					None => Some(String::from("CANCELED")),
+				};
+
+				if let Some(status_code) = status_code {
+					selected_request.response.status_code = Some(status_code);
+					selected_request.is_pending = false;
+					selected_request.cancellation_token = CancellationToken::new();
+					return;
+				}
+			}
+
+			let response = match &protocol {
 				Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) => {
-					send_http_request(prepared_request, local_selected_request.clone(), &local_env)
-						.await
+					send_http_request(
+						prepared_request,
+						connection_timer,
+						local_selected_request.clone(),
+						&local_env,
+						local_should_refresh_scrollbars.clone(),
+						max_body_size,
+						None,
+					)
+					.await
 				}
-				Protocol::GrpcRequest(ref grpc_req) => {
+				Protocol::GrpcRequest(grpc_req) => {
 					let url = {
 						let req = local_selected_request.read();
 						req.url.clone()
 					};
-					let headers = {
-						let req = local_selected_request.read();
-						req.headers
-							.iter()
-							.filter(|h| h.enabled)
-							.map(|h| (h.data.0.clone(), h.data.1.clone()))
-							.collect::<Vec<_>>()
-					};
+					let headers = inherited_headers
+						.iter()
+						.filter(|h| h.enabled)
+						.map(|h| (h.data.0.clone(), h.data.1.clone()))
+						.collect::<Vec<_>>();
 					send_grpc_request(
 						grpc_req,
 						&url,
 						&headers,
+						&tls,
 						local_selected_request.clone(),
 						&local_env,
+						local_should_refresh_scrollbars.clone(),
 					)
 					.await
 				}
@@ -161,9 +255,30 @@ impl App<'_> {
 						digest.update_from_www_authenticate_header(&response.headers)
 					}
 
+					let history_entry = should_record_history.then(|| {
+						HistoryEntry::new(
+							history_collection,
+							selected_request.name.clone(),
+							protocol.to_string(),
+							sent_request,
+							&response,
+						)
+					});
+
 					selected_request.response = response;
 
+					if !selected_request.capture_results.is_empty() {
+						local_pending_captures
+							.lock()
+							.push((collection_index, selected_request.capture_results.clone()));
+					}
+
 					*local_should_refresh_scrollbars.lock() = true;
+					drop(selected_request);
+
+					if let Some(history_entry) = history_entry {
+						append_history_entry(history_entry, should_redact_credentials);
+					}
 				}
 				Err(response_error) => {
 					let mut selected_request = local_selected_request.write();
@@ -2,7 +2,7 @@ use crate::app::App;
 
 impl App<'_> {
 	pub fn tui_modify_request_settings(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -9,7 +9,7 @@ impl App<'_> {
 			return;
 		}
 
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 
@@ -20,6 +20,10 @@ impl App<'_> {
 				None => {}
 				Some(content) => match content {
 					ResponseContent::Body(body) => {
+						let body = match &self.response_view.filtered_body {
+							Some(Ok(filtered_body)) => filtered_body,
+							_ => body,
+						};
 						let Some(clipboard) = self.clipboard.as_mut() else {
 							return;
 						};
@@ -47,11 +51,9 @@ impl App<'_> {
 				},
 			},
 			RequestResultTabs::Messages => {
-				let ws_request = selected_request
-					.get_ws_request()
-					.expect("request should be WebSocket");
-				let text = ws_request
-					.messages
+				let text = selected_request
+					.get_stream_messages()
+					.unwrap_or_default()
 					.iter()
 					.map(|m| {
 						format!(
@@ -86,13 +88,23 @@ impl App<'_> {
 				}
 			},
 			RequestResultTabs::Headers => {
-				let headers_string: String = selected_request
+				let mut headers_string: String = selected_request
 					.response
 					.headers
 					.par_iter()
 					.map(|(header, value)| format!("{}: {}\n", header, value))
 					.collect();
 
+				let trailers = selected_request.get_response_trailers();
+
+				if !trailers.is_empty() {
+					headers_string.push_str("\nTrailers\n");
+
+					for (trailer, value) in trailers {
+						headers_string.push_str(&format!("{}: {}\n", trailer, value));
+					}
+				}
+
 				if !headers_string.is_empty() {
 					let Some(clipboard) = self.clipboard.as_mut() else {
 						return;
@@ -102,6 +114,84 @@ impl App<'_> {
 					}
 				}
 			}
+			RequestResultTabs::Redirects => {
+				let text = selected_request.response.redirects_report();
+
+				if !text.is_empty() {
+					let Some(clipboard) = self.clipboard.as_mut() else {
+						return;
+					};
+					if let Err(e) = clipboard.set_text(text) {
+						tracing::warn!("Could not copy to clipboard: {e}");
+					}
+				}
+			}
+			RequestResultTabs::Timing => {
+				let text = selected_request.response.timing_report();
+
+				if !text.is_empty() {
+					let Some(clipboard) = self.clipboard.as_mut() else {
+						return;
+					};
+					if let Err(e) = clipboard.set_text(text) {
+						tracing::warn!("Could not copy to clipboard: {e}");
+					}
+				}
+			}
+			RequestResultTabs::Assertions => {
+				let text = selected_request
+					.assertion_results
+					.iter()
+					.map(|result| {
+						let status = match result.passed {
+							true => "PASS",
+							false => "FAIL",
+						};
+
+						match &result.message {
+							Some(message) => format!("{status} {} ({message})", result.assertion),
+							None => format!("{status} {}", result.assertion),
+						}
+					})
+					.collect::<Vec<String>>()
+					.join("\n");
+
+				if !text.is_empty() {
+					let Some(clipboard) = self.clipboard.as_mut() else {
+						return;
+					};
+					if let Err(e) = clipboard.set_text(text) {
+						tracing::warn!("Could not copy to clipboard: {e}");
+					}
+				}
+			}
+			RequestResultTabs::Tests => {
+				let text = selected_request
+					.test_results
+					.iter()
+					.map(|result| {
+						let status = match result.passed {
+							true => "PASS",
+							false => "FAIL",
+						};
+
+						match &result.error {
+							Some(error) => format!("{status} {} ({error})", result.name),
+							None => format!("{status} {}", result.name),
+						}
+					})
+					.collect::<Vec<String>>()
+					.join("\n");
+
+				if !text.is_empty() {
+					let Some(clipboard) = self.clipboard.as_mut() else {
+						return;
+					};
+					if let Err(e) = clipboard.set_text(text) {
+						tracing::warn!("Could not copy to clipboard: {e}");
+					}
+				}
+			}
 			RequestResultTabs::Console => {
 				let text = match (
 					&selected_request.console_output.pre_request_output,
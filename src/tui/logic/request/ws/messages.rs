@@ -10,7 +10,7 @@ use tracing::info;
 
 impl App<'_> {
 	pub async fn tui_send_request_message(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -30,7 +30,9 @@ impl App<'_> {
 					let lines = self.message_text_area.to_lines();
 
 					selected_ws_request.message_type = match selected_ws_request.message_type {
-						MessageType::Text(_) => MessageType::Text(lines.join("\n")),
+						MessageType::Text(_) | MessageType::Event(_) => {
+							MessageType::Text(lines.join("\n"))
+						}
 						MessageType::Binary(_) => MessageType::Binary(
 							lines.join("").as_bytes().to_vec().into_boxed_slice(),
 						),
@@ -45,6 +47,9 @@ impl App<'_> {
 
 					let message = match &selected_ws_request.message_type {
 						MessageType::Text(text) => reqwest_websocket::Message::Text(text.clone()),
+						MessageType::Event(event) => {
+							reqwest_websocket::Message::Text(event.data.clone())
+						}
 						MessageType::Binary(binary) => {
 							reqwest_websocket::Message::Binary(Bytes::from(binary.clone()))
 						}
@@ -99,7 +104,7 @@ impl App<'_> {
 	}
 
 	pub fn tui_next_request_message_type(&mut self) {
-		let Some(selected) = self.collections_tree.selected else {
+		let Some(selected) = self.collections_tree.selected.clone() else {
 			return;
 		};
 		let local_selected_request = self.get_request_from_selection(&selected);
@@ -125,14 +130,11 @@ impl App<'_> {
 			return 0;
 		};
 		let selected_request = local_selected_request.read();
-		let ws_request = selected_request
-			.get_ws_request()
-			.expect("request should be WebSocket");
 
 		let mut line_count = 0;
 		let mut last_sender = None;
 
-		for message in &ws_request.messages {
+		for message in selected_request.get_stream_messages().unwrap_or_default() {
 			let content = message.content.to_content();
 			let max_length = self.get_max_line_length(&content);
 			let lines = wrap(&content, max_length);
@@ -0,0 +1,213 @@
+use serde_json::Value;
+use std::fs;
+use tracing::{info, warn};
+
+use crate::app::App;
+use crate::app::files::utils::sanitize_file_name;
+use crate::app::request::json_path;
+use crate::models::protocol::http::body::find_file_format_in_content_type;
+use crate::models::response::{ResponseContent, format_size};
+use crate::tui::ui::result_tabs::RequestResultTabs;
+
+/// Text shown in the body tab: the response body, or its projection by the request filter
+pub fn displayed_response_body<'a>(
+	filtered_body: &'a Option<Result<String, String>>,
+	body: &'a str,
+) -> &'a str {
+	match filtered_body {
+		Some(Ok(filtered_body)) | Some(Err(filtered_body)) => filtered_body,
+		None => body,
+	}
+}
+
+/// Project a JSON response body with a JSONPath or jq-like expression. A single result is shown as
+/// is, several ones as an array.
+fn filter_response_body(body: &str, expression: &str) -> Result<String, String> {
+	let value: Value = serde_json::from_str(body)
+		.map_err(|error| format!("The response body is not JSON: {error}"))?;
+
+	let mut results = json_path::filter(&value, expression).map_err(|error| error.to_string())?;
+
+	let result = match results.len() {
+		1 => results.remove(0),
+		_ => Value::Array(results),
+	};
+
+	serde_json::to_string_pretty(&result).map_err(|error| error.to_string())
+}
+
+impl App<'_> {
+	pub fn tui_displayed_response_body<'a>(&'a self, body: &'a str) -> &'a str {
+		displayed_response_body(&self.response_view.filtered_body, body)
+	}
+
+	/// Whether the body tab is selected and shows a text response body
+	pub fn is_response_body_displayed(&self) -> bool {
+		if self.request_result_tab != RequestResultTabs::Body {
+			return false;
+		}
+
+		let Some(local_selected_request) = self.get_selected_request_as_local() else {
+			return false;
+		};
+		let selected_request = local_selected_request.read();
+
+		matches!(
+			selected_request.response.content,
+			Some(ResponseContent::Body(_))
+		)
+	}
+
+	pub fn tui_apply_response_body_filter(&mut self) {
+		self.response_view.filtered_body = None;
+
+		let Some(local_selected_request) = self.get_selected_request_as_local() else {
+			return;
+		};
+		let selected_request = local_selected_request.read();
+
+		if let Some(response_filter) = &selected_request.response_filter
+			&& let Some(ResponseContent::Body(body)) = &selected_request.response.content
+		{
+			self.response_view.filtered_body = Some(filter_response_body(body, response_filter));
+		}
+	}
+
+	pub fn tui_modify_response_body_filter(&mut self) {
+		let Some(selected) = self.collections_tree.selected.clone() else {
+			return;
+		};
+		let local_selected_request = self.get_request_from_selection(&selected);
+
+		let response_filter = self.response_view.body_filter_input.to_string();
+
+		{
+			let mut selected_request = local_selected_request.write();
+
+			selected_request.response_filter = match response_filter.trim() {
+				"" => None,
+				response_filter => Some(response_filter.to_string()),
+			};
+		}
+
+		self.save_collection_to_file(selected.collection_index());
+
+		self.response_view.vertical_scrollbar.top();
+		self.response_view.horizontal_scrollbar.top();
+
+		// Filters, highlights and searches the body again
+		*self.core.received_response.lock() = true;
+
+		self.select_request_state();
+	}
+
+	/// Search the query in the displayed body again, after the body changed
+	pub fn tui_update_response_body_search_matches(&mut self) {
+		let Some(local_selected_request) = self.get_selected_request_as_local() else {
+			self.response_view.body_search.matches.clear();
+			return;
+		};
+		let selected_request = local_selected_request.read();
+
+		match &selected_request.response.content {
+			Some(ResponseContent::Body(body)) => {
+				let displayed_body =
+					displayed_response_body(&self.response_view.filtered_body, body);
+				self.response_view
+					.body_search
+					.update_matches(displayed_body);
+			}
+			_ => self.response_view.body_search.matches.clear(),
+		}
+	}
+
+	/// Search the new query, going to the first match from the top of the displayed lines
+	pub fn tui_search_response_body(&mut self) {
+		self.tui_update_response_body_search_matches();
+
+		let first_displayed_line = self.response_view.vertical_scrollbar.scroll as usize;
+		self.response_view
+			.body_search
+			.select_from_line(first_displayed_line);
+
+		self.tui_scroll_to_response_body_search_match();
+	}
+
+	pub fn tui_next_response_body_search_match(&mut self) {
+		self.response_view.body_search.next_match();
+		self.tui_scroll_to_response_body_search_match();
+	}
+
+	pub fn tui_previous_response_body_search_match(&mut self) {
+		self.response_view.body_search.previous_match();
+		self.tui_scroll_to_response_body_search_match();
+	}
+
+	pub fn tui_cancel_response_body_search(&mut self) {
+		self.response_view.body_search.clear();
+		self.select_request_state();
+	}
+
+	/// File name suggested to save the response body, from the request name and the content type.
+	/// `None` when there is no body to save.
+	pub fn tui_default_response_body_path(&self) -> Option<String> {
+		let local_selected_request = self.get_selected_request_as_local()?;
+		let selected_request = local_selected_request.read();
+
+		selected_request.response.content.as_ref()?;
+
+		let extension = match find_file_format_in_content_type(&selected_request.response.headers) {
+			Some(file_format) if file_format == "plain" => String::from("txt"),
+			Some(file_format) if file_format == "octet" => String::from("bin"),
+			Some(file_format) => file_format,
+			None => String::from("bin"),
+		};
+
+		let file_name = sanitize_file_name(&selected_request.name);
+
+		Some(format!("{file_name}.{extension}"))
+	}
+
+	/// Write the received body to the path of the save input, as it was sent by the server
+	pub fn tui_save_response_body(&mut self) {
+		let path = self.response_view.body_save_input.to_string();
+		let path = path.trim();
+
+		if let Some(local_selected_request) = self.get_selected_request_as_local()
+			&& !path.is_empty()
+		{
+			let selected_request = local_selected_request.read();
+			let response = &selected_request.response;
+
+			let bytes: &[u8] = match (&response.content, response.raw_body.is_empty()) {
+				(_, false) => &response.raw_body,
+				(Some(ResponseContent::Body(body)), true) => body.as_bytes(),
+				(Some(ResponseContent::Image(image)), true) => &image.data,
+				(None, true) => &[],
+			};
+
+			match fs::write(path, bytes) {
+				Ok(()) if response.is_body_truncated => warn!(
+					"Only the first {} of the response body were saved to {path}, the whole body is over the in-memory limit",
+					format_size(bytes.len() as u64)
+				),
+				Ok(()) => info!("Response body saved to {path}"),
+				Err(error) => warn!("Could not save the response body to {path}: {error}"),
+			}
+		}
+
+		self.select_request_state();
+	}
+
+	fn tui_scroll_to_response_body_search_match(&mut self) {
+		let Some((line_index, _)) = self.response_view.body_search.current_match() else {
+			return;
+		};
+
+		let scrollbar = &mut self.response_view.vertical_scrollbar;
+		scrollbar.scroll = u16::try_from(*line_index)
+			.unwrap_or(u16::MAX)
+			.min(scrollbar.max_scroll);
+		scrollbar.state = scrollbar.state.position(scrollbar.scroll as usize);
+	}
+}
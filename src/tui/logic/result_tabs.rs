@@ -1,8 +1,8 @@
 use crate::app::App;
 use crate::app::files::theme::THEME;
 use crate::models::protocol::http::body::find_file_format_in_content_type;
-use crate::models::protocol::protocol::Protocol;
 use crate::models::response::ResponseContent;
+use crate::tui::logic::response_body::displayed_response_body;
 use crate::tui::ui::result_tabs::RequestResultTabs;
 use crate::tui::utils::syntax_highlighting::highlight;
 use ratatui::prelude::{Line, Stylize};
@@ -15,35 +15,25 @@ impl App<'_> {
 		};
 		let selected_request = local_selected_request.read();
 
-		self.request_result_tab = match self.request_result_tab {
-			RequestResultTabs::Body => RequestResultTabs::Cookies,
-			RequestResultTabs::Messages => RequestResultTabs::Cookies,
-			RequestResultTabs::Cookies => RequestResultTabs::Headers,
-			RequestResultTabs::Headers => {
-				let Some(local_selected_request) = self.get_selected_request_as_local() else {
-					return;
-				};
-				let selected_request = local_selected_request.read();
+		let has_console_output = selected_request.console_output.pre_request_output.is_some()
+			|| selected_request
+				.console_output
+				.post_request_output
+				.is_some();
 
-				match (
-					&selected_request.console_output.pre_request_output,
-					&selected_request.console_output.post_request_output,
-				) {
-					(None, None) => match selected_request.protocol {
-						Protocol::HttpRequest(_)
-						| Protocol::GraphqlRequest(_)
-						| Protocol::GrpcRequest(_) => RequestResultTabs::Body,
-						Protocol::WsRequest(_) => RequestResultTabs::Messages,
-					},
-					(_, _) => RequestResultTabs::Console,
-				}
-			}
-			RequestResultTabs::Console => match selected_request.protocol {
-				Protocol::HttpRequest(_)
-				| Protocol::GraphqlRequest(_)
-				| Protocol::GrpcRequest(_) => RequestResultTabs::Body,
-				Protocol::WsRequest(_) => RequestResultTabs::Messages,
-			},
+		// The console tab is only reachable when a script produced some output
+		let allowed_tabs: Vec<RequestResultTabs> =
+			RequestResultTabs::allowed_for(&selected_request)
+				.into_iter()
+				.filter(|tab| *tab != RequestResultTabs::Console || has_console_output)
+				.collect();
+
+		self.request_result_tab = match allowed_tabs
+			.iter()
+			.position(|tab| *tab == self.request_result_tab)
+		{
+			Some(index) => allowed_tabs[(index + 1) % allowed_tabs.len()],
+			None => RequestResultTabs::main_tab(&selected_request),
 		};
 
 		*self.core.received_response.lock() = true;
@@ -55,33 +45,16 @@ impl App<'_> {
 		};
 		let selected_request = local_selected_request.read();
 
-		if self.request_result_tab == RequestResultTabs::Console
-			&& selected_request.console_output.pre_request_output.is_none()
+		let has_no_console_output = selected_request.console_output.pre_request_output.is_none()
 			&& selected_request
 				.console_output
 				.post_request_output
-				.is_none()
+				.is_none();
+
+		if (self.request_result_tab == RequestResultTabs::Console && has_no_console_output)
+			|| !RequestResultTabs::allowed_for(&selected_request).contains(&self.request_result_tab)
 		{
-			self.request_result_tab = match selected_request.protocol {
-				Protocol::HttpRequest(_)
-				| Protocol::GraphqlRequest(_)
-				| Protocol::GrpcRequest(_) => RequestResultTabs::Body,
-				Protocol::WsRequest(_) => RequestResultTabs::Messages,
-			};
-		} else {
-			match selected_request.protocol {
-				Protocol::HttpRequest(_)
-				| Protocol::GraphqlRequest(_)
-				| Protocol::GrpcRequest(_)
-					if self.request_result_tab == RequestResultTabs::Messages =>
-				{
-					self.request_result_tab = RequestResultTabs::Body
-				}
-				Protocol::WsRequest(_) if self.request_result_tab == RequestResultTabs::Body => {
-					self.request_result_tab = RequestResultTabs::Messages
-				}
-				_ => {}
-			};
+			self.request_result_tab = RequestResultTabs::main_tab(&selected_request);
 		}
 	}
 
@@ -94,12 +67,21 @@ impl App<'_> {
 		self.syntax_highlighting.highlighted_body = None;
 		self.syntax_highlighting.highlighted_console_output = vec![];
 
-		if let Some(file_format) =
-			find_file_format_in_content_type(&selected_request.response.headers)
-			&& let Some(ResponseContent::Body(response_content)) =
-				&selected_request.response.content.as_ref()
-		{
-			self.syntax_highlighting.highlighted_body = highlight(response_content, &file_format);
+		match &self.response_view.filtered_body {
+			Some(Ok(filtered_body)) => {
+				self.syntax_highlighting.highlighted_body = highlight(filtered_body, "json");
+			}
+			Some(Err(_)) => {}
+			None => {
+				if let Some(file_format) =
+					find_file_format_in_content_type(&selected_request.response.headers)
+					&& let Some(ResponseContent::Body(response_content)) =
+						&selected_request.response.content.as_ref()
+				{
+					self.syntax_highlighting.highlighted_body =
+						highlight(response_content, &file_format);
+				}
+			}
 		}
 
 		if let Some(pre_request_console_output) =
@@ -169,6 +151,7 @@ impl App<'_> {
 				}
 				Some(content) => match content {
 					ResponseContent::Body(body) => {
+						let body = displayed_response_body(&self.response_view.filtered_body, body);
 						vertical_max = body.lines().count() as u16;
 						horizontal_max = App::get_max_str_len(body.lines()) as u16;
 					}
@@ -201,11 +184,17 @@ impl App<'_> {
 				}
 			},
 			RequestResultTabs::Headers => {
-				vertical_max = selected_request.response.headers.len() as u16;
+				let trailers = selected_request.get_response_trailers();
+
+				// Trailers are listed under an empty line and a title
+				vertical_max = match trailers.is_empty() {
+					true => selected_request.response.headers.len() as u16,
+					false => (selected_request.response.headers.len() + trailers.len() + 2) as u16,
+				};
 
 				let mut max_tmp: u16 = 0;
 
-				for (header, value) in &selected_request.response.headers {
+				for (header, value) in selected_request.response.headers.iter().chain(trailers) {
 					let str_len = (header.len() + value.len()) as u16;
 					if str_len > max_tmp {
 						max_tmp = str_len;
@@ -214,6 +203,51 @@ impl App<'_> {
 
 				horizontal_max = max_tmp;
 			}
+			RequestResultTabs::Redirects => {
+				let redirects_report = selected_request.response.redirects_report();
+
+				vertical_max = redirects_report.lines().count() as u16;
+				horizontal_max = App::get_max_str_len(redirects_report.lines()) as u16;
+			}
+			RequestResultTabs::Timing => {
+				let timing_report = selected_request.response.timing_report();
+
+				vertical_max = timing_report.lines().count() as u16;
+				horizontal_max = App::get_max_str_len(timing_report.lines()) as u16;
+			}
+			RequestResultTabs::Assertions => {
+				let lines: Vec<String> = match selected_request.assertion_results.is_empty() {
+					true => selected_request
+						.assertions
+						.iter()
+						.map(|assertion| format!("- {assertion}"))
+						.collect(),
+					false => selected_request
+						.assertion_results
+						.iter()
+						.map(|result| match &result.message {
+							Some(message) => format!("✘ FAIL {} ({message})", result.assertion),
+							None => format!("✔ PASS {}", result.assertion),
+						})
+						.collect(),
+				};
+
+				vertical_max = lines.len() as u16;
+				horizontal_max = lines.iter().map(|line| line.len()).max().unwrap_or(0) as u16;
+			}
+			RequestResultTabs::Tests => {
+				let lines: Vec<String> = selected_request
+					.test_results
+					.iter()
+					.map(|result| match &result.error {
+						Some(error) => format!("✘ FAIL {} ({error})", result.name),
+						None => format!("✔ PASS {}", result.name),
+					})
+					.collect();
+
+				vertical_max = lines.len() as u16;
+				horizontal_max = lines.iter().map(|line| line.len()).max().unwrap_or(0) as u16;
+			}
 			RequestResultTabs::Console => {
 				let console_output = match (
 					&selected_request.console_output.pre_request_output,
@@ -0,0 +1,80 @@
+use ratatui::Frame;
+use ratatui::layout::Direction::Vertical;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::prelude::{Position, Size, StatefulWidget};
+use tui_scrollview::{ScrollView, ScrollViewState};
+
+use crate::app::App;
+use crate::tui::app_states::AppState::{
+	EditingRequestAuthAwsSigV4AccessKeyId, EditingRequestAuthAwsSigV4Region,
+	EditingRequestAuthAwsSigV4SecretAccessKey, EditingRequestAuthAwsSigV4Service,
+	EditingRequestAuthAwsSigV4SessionToken, SelectedRequest,
+};
+use crate::tui::utils::stateful::text_input::SingleLineTextInput;
+
+impl App<'_> {
+	pub(super) fn render_aws_sig_v4_tab(&mut self, frame: &mut Frame, area: Rect) {
+		let scroll_view_size = Size::new(area.width.saturating_sub(1), 17);
+		let mut aws_sig_v4_scroll_view = ScrollView::new(scroll_view_size);
+
+		let aws_sig_v4_layout = Layout::new(Vertical, [Constraint::Length(3); 5])
+			.vertical_margin(1)
+			.horizontal_margin(4)
+			.split(aws_sig_v4_scroll_view.area());
+
+		let mut should_color_blocks = false;
+		let mut should_display_cursor = false;
+
+		// Prevent from rendering the cursor while no input text has been selected
+		match self.state {
+			SelectedRequest => {
+				should_color_blocks = true;
+			}
+			EditingRequestAuthAwsSigV4AccessKeyId
+			| EditingRequestAuthAwsSigV4SecretAccessKey
+			| EditingRequestAuthAwsSigV4Region
+			| EditingRequestAuthAwsSigV4Service
+			| EditingRequestAuthAwsSigV4SessionToken => {
+				should_color_blocks = true;
+				should_display_cursor = true;
+			}
+			_ => {}
+		};
+
+		let input_selected = self.request_editor.auth.text_input_selection.selected;
+
+		let auth = &mut self.request_editor.auth;
+		let text_inputs = [
+			&mut auth.aws_sig_v4_access_key_id,
+			&mut auth.aws_sig_v4_secret_access_key,
+			&mut auth.aws_sig_v4_region,
+			&mut auth.aws_sig_v4_service,
+			&mut auth.aws_sig_v4_session_token,
+		];
+
+		for (index, text_input) in text_inputs.into_iter().enumerate() {
+			let highlight = should_color_blocks && input_selected == index;
+
+			text_input.highlight_text = highlight;
+			text_input.highlight_block = highlight;
+			text_input.display_cursor = highlight && should_display_cursor;
+
+			aws_sig_v4_scroll_view
+				.render_widget(SingleLineTextInput(text_input), aws_sig_v4_layout[index]);
+		}
+
+		let mut scrollbar_state = ScrollViewState::new();
+
+		let scroll_adjustment = match area.height {
+			0 => 0,
+			_ => area.height / 3,
+		};
+
+		let scroll_offset = ((input_selected as u16 + 1) * 3)
+			.saturating_sub(area.height.saturating_sub(scroll_adjustment + 2));
+
+		scrollbar_state.set_offset(Position::new(0, scroll_offset));
+
+		aws_sig_v4_scroll_view.render(area, frame.buffer_mut(), &mut scrollbar_state)
+	}
+}
@@ -1,8 +1,10 @@
+mod aws_sig_v4_tab;
 mod basic_auth_tab;
 mod bearer_token_tab;
 mod body_file_tab;
 mod digest_tab;
 mod jwt_token_tab;
+mod oauth2_tab;
 #[allow(clippy::module_inception)]
 pub(crate) mod param_tabs;
 mod script;
@@ -0,0 +1,151 @@
+use crate::app::App;
+use crate::app::files::theme::THEME;
+use crate::tui::app_states::AppState::{
+	EditingRequestAuthOAuth2AuthUrl, EditingRequestAuthOAuth2ClientId,
+	EditingRequestAuthOAuth2ClientSecret, EditingRequestAuthOAuth2Password,
+	EditingRequestAuthOAuth2RefreshToken, EditingRequestAuthOAuth2Scope,
+	EditingRequestAuthOAuth2TokenUrl, EditingRequestAuthOAuth2Username, SelectedRequest,
+};
+use crate::tui::utils::stateful::text_input::SingleLineTextInput;
+use chrono::Utc;
+use ratatui::Frame;
+use ratatui::layout::Direction::Vertical;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::prelude::{Position, Size, StatefulWidget, Stylize};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use tui_scrollview::{ScrollView, ScrollViewState};
+
+impl App<'_> {
+	pub(super) fn render_oauth2_tab(&mut self, frame: &mut Frame, area: Rect) {
+		let scroll_view_size = Size::new(area.width.saturating_sub(1), 35);
+		let mut oauth2_scroll_view = ScrollView::new(scroll_view_size);
+
+		let oauth2_layout = Layout::new(Vertical, [Constraint::Length(3); 11])
+			.vertical_margin(1)
+			.horizontal_margin(4)
+			.split(oauth2_scroll_view.area());
+
+		let oauth2 = {
+			let Some(local_selected_request) = self.get_selected_request_as_local() else {
+				return;
+			};
+			let selected_request = local_selected_request.read();
+
+			selected_request.auth.get_oauth2().clone()
+		};
+
+		let mut grant_type_block = Block::new()
+			.title("Grant type ← →")
+			.borders(Borders::ALL)
+			.fg(THEME.read().ui.main_foreground_color);
+
+		let mut pkce_block = Block::new()
+			.title("PKCE ← →")
+			.borders(Borders::ALL)
+			.fg(THEME.read().ui.main_foreground_color);
+
+		let mut token_block = Block::new()
+			.title("Token (clear with ← →)")
+			.borders(Borders::ALL)
+			.fg(THEME.read().ui.main_foreground_color);
+
+		let mut should_color_blocks = false;
+		let mut should_display_cursor = false;
+
+		// Prevent from rendering the cursor while no input text has been selected
+		match self.state {
+			SelectedRequest => {
+				should_color_blocks = true;
+			}
+			EditingRequestAuthOAuth2TokenUrl
+			| EditingRequestAuthOAuth2AuthUrl
+			| EditingRequestAuthOAuth2ClientId
+			| EditingRequestAuthOAuth2ClientSecret
+			| EditingRequestAuthOAuth2Scope
+			| EditingRequestAuthOAuth2Username
+			| EditingRequestAuthOAuth2Password
+			| EditingRequestAuthOAuth2RefreshToken => {
+				should_color_blocks = true;
+				should_display_cursor = true;
+			}
+			_ => {}
+		};
+
+		let mut grant_type_paragraph =
+			Paragraph::new(oauth2.grant_type.to_string()).fg(THEME.read().ui.font_color);
+		let mut pkce_paragraph =
+			Paragraph::new(oauth2.pkce.to_string()).fg(THEME.read().ui.font_color);
+		let mut token_paragraph =
+			Paragraph::new(oauth2.token_state(Utc::now())).fg(THEME.read().ui.font_color);
+
+		let input_selected = self.request_editor.auth.text_input_selection.selected;
+
+		match input_selected {
+			0 if should_color_blocks => {
+				grant_type_block =
+					grant_type_block.fg(THEME.read().others.selection_highlight_color);
+				grant_type_paragraph =
+					grant_type_paragraph.fg(THEME.read().others.selection_highlight_color);
+			}
+			9 if should_color_blocks => {
+				pkce_block = pkce_block.fg(THEME.read().others.selection_highlight_color);
+				pkce_paragraph = pkce_paragraph.fg(THEME.read().others.selection_highlight_color);
+			}
+			10 if should_color_blocks => {
+				token_block = token_block.fg(THEME.read().others.selection_highlight_color);
+				token_paragraph = token_paragraph.fg(THEME.read().others.selection_highlight_color);
+			}
+			_ => {}
+		}
+
+		grant_type_paragraph = grant_type_paragraph.block(grant_type_block);
+		pkce_paragraph = pkce_paragraph.block(pkce_block);
+		token_paragraph = token_paragraph.block(token_block);
+
+		oauth2_scroll_view.render_widget(grant_type_paragraph, oauth2_layout[0]);
+
+		let auth = &mut self.request_editor.auth;
+		let text_inputs = [
+			&mut auth.oauth2_token_url,
+			&mut auth.oauth2_auth_url,
+			&mut auth.oauth2_client_id,
+			&mut auth.oauth2_client_secret,
+			&mut auth.oauth2_scope,
+			&mut auth.oauth2_username,
+			&mut auth.oauth2_password,
+			&mut auth.oauth2_refresh_token,
+		];
+
+		// Text inputs are the rows between the grant type and PKCE
+		for (index, text_input) in text_inputs.into_iter().enumerate() {
+			let highlight = should_color_blocks && input_selected == index + 1;
+
+			text_input.highlight_text = highlight;
+			text_input.highlight_block = highlight;
+			text_input.display_cursor = highlight && should_display_cursor;
+
+			oauth2_scroll_view
+				.render_widget(SingleLineTextInput(text_input), oauth2_layout[index + 1]);
+		}
+
+		oauth2_scroll_view.render_widget(pkce_paragraph, oauth2_layout[9]);
+		oauth2_scroll_view.render_widget(token_paragraph, oauth2_layout[10]);
+
+		let mut scrollbar_state = ScrollViewState::new();
+
+		let scroll_adjustment = match area.height {
+			0 => 0,
+			_ => area.height / 3,
+		};
+
+		let scroll_offset = match input_selected {
+			0 => 0,
+			_ => ((input_selected as u16 + 1) * 3)
+				.saturating_sub(area.height.saturating_sub(scroll_adjustment + 2)),
+		};
+
+		scrollbar_state.set_offset(Position::new(0, scroll_offset));
+
+		oauth2_scroll_view.render(area, frame.buffer_mut(), &mut scrollbar_state)
+	}
+}
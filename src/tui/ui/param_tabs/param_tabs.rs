@@ -8,7 +8,10 @@ use strum::{Display, EnumIter, FromRepr};
 
 use crate::app::App;
 use crate::app::files::theme::THEME;
-use crate::models::auth::auth::Auth::{BasicAuth, BearerToken, Digest, JwtToken, NoAuth};
+use crate::models::auth::auth::Auth::{
+	AwsSigV4, BasicAuth, BearerToken, Digest, Inherit, JwtToken, NoAuth, OAuth2,
+};
+use crate::models::protocol::grpc::grpc::GrpcCompression;
 use crate::models::protocol::http::body::ContentType::*;
 use crate::models::protocol::protocol::Protocol;
 use crate::models::request::Request;
@@ -142,7 +145,8 @@ impl App<'_> {
 				},
 				RequestParamsTabs::Auth => match request.auth {
 					NoAuth => tab.to_string().to_uppercase(),
-					BasicAuth(_) | BearerToken(_) | JwtToken(_) | Digest(_) => {
+					BasicAuth(_) | BearerToken(_) | JwtToken(_) | Digest(_) | OAuth2(_)
+					| AwsSigV4(_) | Inherit => {
 						format!("{} ({})", tab.to_string().to_uppercase(), request.auth)
 					}
 				},
@@ -269,10 +273,40 @@ impl App<'_> {
 
 					frame.render_widget(auth_paragraph, request_params_layout[1]);
 				}
+				Inherit => {
+					let inherited_auth = self
+						.collections_tree
+						.selected
+						.as_ref()
+						.map(|selected| {
+							self.folder_inheritance(
+								selected.collection_index(),
+								selected.folder_path(),
+							)
+							.auth
+						})
+						.unwrap_or_default();
+
+					let auth_lines = vec![
+						Line::default(),
+						Line::from(format!("Inherited auth: {inherited_auth}"))
+							.fg(THEME.read().ui.font_color),
+						Line::from("(Set with the collection or folder properties)")
+							.fg(THEME.read().ui.secondary_foreground_color),
+						Line::from("(Change auth method with ^a)")
+							.fg(THEME.read().ui.secondary_foreground_color),
+					];
+
+					let auth_paragraph = Paragraph::new(auth_lines).centered();
+
+					frame.render_widget(auth_paragraph, request_params_layout[1]);
+				}
 				BasicAuth(_) => self.render_basic_auth_tab(frame, request_params_layout[1]),
 				BearerToken(_) => self.render_bearer_token_tab(frame, request_params_layout[1]),
 				JwtToken(_) => self.render_jwt_token_tab(frame, request_params_layout[1]),
 				Digest(_) => self.render_digest_tab(frame, request_params_layout[1]),
+				OAuth2(_) => self.render_oauth2_tab(frame, request_params_layout[1]),
+				AwsSigV4(_) => self.render_aws_sig_v4_tab(frame, request_params_layout[1]),
 			},
 			RequestParamsTabs::Headers => {
 				self.request_editor.headers_table.is_editing =
@@ -390,7 +424,7 @@ impl App<'_> {
 					Vertical,
 					[
 						Constraint::Length(3),
-						Constraint::Length(3),
+						Constraint::Length(6),
 						Constraint::Fill(1),
 					],
 				)
@@ -405,6 +439,27 @@ impl App<'_> {
 					),
 					grpc_layout[0],
 				);
+
+				let (reflection, compression) = match &request.protocol {
+					Protocol::GrpcRequest(grpc) => (grpc.reflection, grpc.compression),
+					_ => (false, GrpcCompression::Identity),
+				};
+				let source = match reflection {
+					true => "Descriptors: server reflection (proto file ignored)",
+					false => "Descriptors: proto file",
+				};
+				let source_lines = vec![
+					Line::from(source).fg(THEME.read().ui.font_color),
+					Line::from("(Toggle server reflection with ^r)")
+						.fg(THEME.read().ui.secondary_foreground_color),
+					Line::default(),
+					Line::from(format!("Compression: {compression}"))
+						.fg(THEME.read().ui.font_color),
+					Line::from("(Change compression with ^g)")
+						.fg(THEME.read().ui.secondary_foreground_color),
+				];
+
+				frame.render_widget(Paragraph::new(source_lines).centered(), grpc_layout[1]);
 			}
 			RequestParamsTabs::GrpcService => {
 				let grpc_layout = Layout::new(
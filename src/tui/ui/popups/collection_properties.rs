@@ -0,0 +1,53 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::Stylize;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::app::App;
+use crate::app::files::theme::THEME;
+use crate::tui::utils::centered_rect::centered_rect;
+use crate::tui::utils::stateful::text_input::MultiLineTextInput;
+use crate::tui::utils::syntax_highlighting::YAML_SYNTAX_REF;
+
+impl App<'_> {
+	pub fn render_collection_properties_popup(&mut self, frame: &mut Frame) {
+		let popup_block = Block::default()
+			.title(" Auth, headers and scripts shared with the requests (YAML) ")
+			.borders(Borders::ALL)
+			.fg(THEME.read().ui.main_foreground_color)
+			.bg(THEME.read().ui.main_background_color);
+
+		let area = centered_rect(80, 20, frame.area());
+		let inner_area = popup_block.inner(area);
+
+		frame.render_widget(Clear, area);
+		frame.render_widget(popup_block, area);
+
+		let error_height = match self.collection_popups.properties_error {
+			Some(_) => 2,
+			None => 0,
+		};
+
+		let [text_area, error_area] =
+			Layout::vertical([Constraint::Fill(1), Constraint::Length(error_height)])
+				.areas(inner_area);
+
+		if let Some(error) = &self.collection_popups.properties_error {
+			let error_paragraph = Paragraph::new(error.as_str())
+				.fg(THEME.read().ui.secondary_foreground_color)
+				.wrap(Wrap { trim: true });
+
+			frame.render_widget(error_paragraph, error_area);
+		}
+
+		self.collection_popups.properties_text_area.display_cursor = true;
+
+		frame.render_widget(
+			MultiLineTextInput(
+				&mut self.collection_popups.properties_text_area,
+				YAML_SYNTAX_REF.clone(),
+			),
+			text_area,
+		);
+	}
+}
@@ -29,10 +29,10 @@ impl App<'_> {
 		)
 		.split(area);
 
-		// Sync folder_count from the actual collection data (needed after collection changes)
+		// Sync folder_paths from the actual collection data (needed after collection changes)
 		let collection =
 			&self.core.collections[self.collection_popups.new_request_popup.selected_collection];
-		self.collection_popups.new_request_popup.folder_count = collection.folders.len();
+		self.collection_popups.new_request_popup.folder_paths = collection.folder_paths();
 
 		// Collection selector (row 0)
 		let selected_collection_name = collection.name.clone();
@@ -52,16 +52,13 @@ impl App<'_> {
 
 		// Folder selector (row 1)
 		let selected_folder_name = match self.collection_popups.new_request_popup.selected_folder {
+			Some(_) => collection.folder_path_name(
+				&self
+					.collection_popups
+					.new_request_popup
+					.selected_folder_path(),
+			),
 			None => "None (root)".to_string(),
-			Some(folder_index)
-				if folder_index < self.collection_popups.new_request_popup.folder_count =>
-			{
-				self.core.collections[self.collection_popups.new_request_popup.selected_collection]
-					.folders[folder_index]
-					.name
-					.clone()
-			}
-			Some(_) => "None (root)".to_string(),
 		};
 		let selection_folder_block_color =
 			match self.collection_popups.new_request_popup.selection == 1 {
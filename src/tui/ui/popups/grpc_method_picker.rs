@@ -0,0 +1,103 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::Stylize;
+use ratatui::widgets::{
+	Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap,
+};
+
+use crate::app::App;
+use crate::app::files::theme::THEME;
+use crate::tui::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+	pub fn render_grpc_method_picker_popup(&mut self, frame: &mut Frame) {
+		let popup_block = Block::default()
+			.title(" Choose gRPC method ")
+			.borders(Borders::ALL)
+			.fg(THEME.read().ui.main_foreground_color)
+			.bg(THEME.read().ui.main_background_color);
+
+		if let Some(error) = &self.grpc_method_popup.error {
+			let area = centered_rect(70, 7, frame.area());
+
+			let paragraph = Paragraph::new(error.as_str())
+				.fg(THEME.read().ui.font_color)
+				.wrap(Wrap { trim: true })
+				.block(popup_block);
+
+			frame.render_widget(Clear, area);
+			frame.render_widget(paragraph, area);
+			return;
+		}
+
+		let lines: Vec<String> = self
+			.grpc_method_popup
+			.methods
+			.iter()
+			.map(|m| format!(" {}/{} ({}) ", m.service, m.method, m.kind()))
+			.collect();
+
+		let max_line_len = lines.iter().map(|line| line.len()).max().unwrap_or(20);
+
+		// +2 for borders
+		let popup_width = (max_line_len + 2).clamp(30, 100) as u16;
+		let visible_items = 10.min(lines.len()).max(1) as u16;
+		let popup_height = visible_items + 2;
+
+		let area = centered_rect(popup_width, popup_height, frame.area());
+
+		frame.render_widget(Clear, area);
+		frame.render_widget(popup_block.clone(), area);
+
+		let inner_area = popup_block.inner(area);
+
+		if lines.is_empty() {
+			let paragraph = Paragraph::new(" No service found ").fg(THEME.read().ui.font_color);
+			frame.render_widget(paragraph, inner_area);
+			return;
+		}
+
+		let total_items = lines.len();
+		let max_visible = visible_items as usize;
+		let selection = self.grpc_method_popup.selection;
+
+		let start_idx = if selection >= max_visible {
+			selection - max_visible + 1
+		} else {
+			0
+		};
+		let end_idx = (start_idx + max_visible).min(total_items);
+
+		let constraints: Vec<Constraint> =
+			(0..max_visible).map(|_| Constraint::Length(1)).collect();
+
+		let items_layout = Layout::vertical(constraints).split(inner_area);
+
+		for (display_idx, line_idx) in (start_idx..end_idx).enumerate() {
+			let mut paragraph =
+				Paragraph::new(lines[line_idx].as_str()).fg(THEME.read().ui.font_color);
+
+			if line_idx == selection {
+				paragraph = paragraph
+					.fg(THEME.read().others.selection_highlight_color)
+					.bold()
+					.bg(THEME.read().ui.secondary_background_color);
+			}
+
+			if display_idx < items_layout.len() {
+				frame.render_widget(paragraph, items_layout[display_idx]);
+			}
+		}
+
+		if total_items > max_visible {
+			let scrollbar = Scrollbar::default()
+				.orientation(ScrollbarOrientation::VerticalRight)
+				.begin_symbol(Some("▲"))
+				.end_symbol(Some("▼"));
+
+			let mut scrollbar_state = ScrollbarState::new(total_items).position(selection);
+
+			frame.render_stateful_widget(scrollbar, inner_area, &mut scrollbar_state);
+		}
+	}
+}
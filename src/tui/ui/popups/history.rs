@@ -0,0 +1,108 @@
+use ratatui::Frame;
+use ratatui::layout::Constraint;
+use ratatui::layout::Direction::Horizontal;
+use ratatui::layout::Layout;
+use ratatui::prelude::{Color, Line};
+use ratatui::style::Stylize;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+
+use crate::app::App;
+use crate::app::files::theme::THEME;
+use crate::models::history::DiffLine;
+use crate::tui::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+	pub fn render_history_popup(&mut self, frame: &mut Frame) {
+		let popup_block = Block::default()
+			.title("History")
+			.borders(Borders::ALL)
+			.fg(THEME.read().ui.font_color)
+			.bg(THEME.read().ui.main_background_color);
+
+		let area = centered_rect(140, 30, frame.area());
+
+		frame.render_widget(Clear, area);
+		frame.render_widget(popup_block, area);
+
+		let history_layout = Layout::new(
+			Horizontal,
+			[Constraint::Percentage(45), Constraint::Percentage(55)],
+		)
+		.margin(1)
+		.split(area);
+
+		/* ENTRIES */
+
+		let entries_block = Block::new()
+			.title(format!("Entries ({})", self.history_popup.entries.len()))
+			.borders(Borders::RIGHT);
+
+		if self.history_popup.entries.is_empty() {
+			let paragraph = Paragraph::new("No request sent yet")
+				.fg(THEME.read().ui.secondary_foreground_color)
+				.block(entries_block);
+
+			frame.render_widget(paragraph, history_layout[0]);
+			return;
+		}
+
+		let items: Vec<ListItem> = self
+			.history_popup
+			.entries
+			.iter()
+			.map(|entry| {
+				ListItem::new(Line::from(format!(
+					"{}  {} {}",
+					entry.local_time(),
+					entry.response.status_code.as_deref().unwrap_or("-"),
+					entry.request_path()
+				)))
+			})
+			.collect();
+
+		let list = List::new(items)
+			.highlight_style(THEME.read().others.selection_highlight_color)
+			.fg(THEME.read().ui.font_color)
+			.block(entries_block);
+
+		let mut list_state = ListState::default().with_selected(Some(self.history_popup.selection));
+
+		frame.render_stateful_widget(list, history_layout[0], &mut list_state);
+
+		/* DETAILS */
+
+		let details_title = match self.history_popup.show_diff {
+			true => "Diff with previous entry",
+			false => "Details",
+		};
+
+		let lines: Vec<Line> = self
+			.history_popup
+			.details_lines()
+			.into_iter()
+			.map(|line| match line {
+				DiffLine::Same(text) => match self.history_popup.show_diff {
+					true => Line::from(format!("  {text}")).fg(THEME.read().ui.font_color),
+					false => Line::from(text).fg(THEME.read().ui.font_color),
+				},
+				DiffLine::Removed(text) => Line::from(format!("- {text}")).fg(Color::Red),
+				DiffLine::Added(text) => Line::from(format!("+ {text}")).fg(Color::Green),
+			})
+			.collect();
+
+		self.history_popup
+			.details_scrollbar
+			.set_max_scroll(lines.len().saturating_sub(1) as u16);
+
+		let paragraph = Paragraph::new(lines)
+			.scroll((self.history_popup.details_scrollbar.scroll, 0))
+			.block(
+				Block::new()
+					.title(details_title)
+					.borders(Borders::NONE)
+					.fg(THEME.read().ui.font_color),
+			);
+
+		frame.render_widget(paragraph, history_layout[1]);
+	}
+}
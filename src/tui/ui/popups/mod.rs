@@ -1,3 +1,4 @@
+mod collection_properties;
 mod cookies;
 mod creating_element;
 mod creating_new_collection;
@@ -7,8 +8,11 @@ mod deleting_collection;
 mod deleting_folder;
 mod deleting_request;
 mod env_editor;
+mod grpc_method_picker;
 mod help;
+mod history;
 mod renaming_collection;
 mod renaming_folder;
 mod renaming_request;
+mod request_settings;
 mod theme_picker;
@@ -0,0 +1,88 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::Stylize;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::App;
+use crate::app::files::theme::THEME;
+use crate::models::settings::Setting;
+use crate::tui::app_states::AppState::EditingRequestSettingText;
+use crate::tui::utils::centered_rect::centered_rect;
+use crate::tui::utils::stateful::text_input::SingleLineTextInput;
+
+impl App<'_> {
+	pub fn render_request_settings_popup(&mut self, frame: &mut Frame) {
+		let popup_block = Block::default()
+			.title(" Request settings ")
+			.borders(Borders::ALL)
+			.fg(THEME.read().ui.main_foreground_color)
+			.bg(THEME.read().ui.main_background_color);
+
+		let settings_popup = &mut self.request_editor.settings_popup;
+
+		let name_width = settings_popup
+			.settings
+			.iter()
+			.map(|(name, _)| name.len())
+			.max()
+			.unwrap_or(0);
+
+		// +2 for borders
+		let popup_height = settings_popup.settings.len() as u16 + 2;
+		let area = centered_rect(80, popup_height, frame.area());
+
+		frame.render_widget(Clear, area);
+		frame.render_widget(popup_block.clone(), area);
+
+		let inner_area = popup_block.inner(area);
+		let settings_layout =
+			Layout::vertical(vec![Constraint::Length(1); settings_popup.settings.len()])
+				.split(inner_area);
+
+		for (index, (name, setting)) in settings_popup.settings.iter().enumerate() {
+			let is_selected = index == settings_popup.selection;
+			let name = format!(" {name:name_width$} : ");
+
+			if is_selected && self.state == EditingRequestSettingText {
+				let [name_area, input_area] = Layout::horizontal([
+					Constraint::Length(name.len() as u16),
+					Constraint::Fill(1),
+				])
+				.areas(settings_layout[index]);
+
+				frame.render_widget(
+					Paragraph::new(name).fg(THEME.read().others.selection_highlight_color),
+					name_area,
+				);
+
+				settings_popup.text_input.display_cursor = true;
+				frame.render_widget(
+					SingleLineTextInput(&mut settings_popup.text_input),
+					input_area,
+				);
+				continue;
+			}
+
+			let value = match setting {
+				Setting::Bool(true) => String::from("[x]"),
+				Setting::Bool(false) => String::from("[ ]"),
+				Setting::U32(u32) => format!("< {u32} >"),
+				Setting::Text(text) if text.is_empty() => String::from("(none)"),
+				Setting::Text(text) => text.clone(),
+			};
+
+			let mut line =
+				Line::from(vec![Span::raw(name), Span::raw(value)]).fg(THEME.read().ui.font_color);
+
+			if is_selected {
+				line = line
+					.fg(THEME.read().others.selection_highlight_color)
+					.bold()
+					.bg(THEME.read().ui.secondary_background_color);
+			}
+
+			frame.render_widget(Paragraph::new(line), settings_layout[index]);
+		}
+	}
+}
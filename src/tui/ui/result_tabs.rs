@@ -18,10 +18,11 @@ use crate::app::files::theme::THEME;
 use crate::models::protocol::protocol::Protocol;
 use crate::models::protocol::ws::ws::Sender;
 use crate::models::request::Request;
-use crate::models::response::ResponseContent;
+use crate::models::response::{RequestResponse, ResponseContent, format_size};
 use crate::tui::app_states::AppState;
+use crate::tui::logic::response_body::displayed_response_body;
 use crate::tui::utils::centered_rect::centered_rect;
-use crate::tui::utils::stateful::text_input::MultiLineTextInput;
+use crate::tui::utils::stateful::text_input::{MultiLineTextInput, SingleLineTextInput};
 use crate::tui::utils::syntax_highlighting::SYNTAX_SET;
 
 #[derive(Default, Clone, Copy, Debug, PartialOrd, PartialEq, Display, FromRepr, EnumIter)]
@@ -35,10 +36,68 @@ pub enum RequestResultTabs {
 	Cookies,
 	#[strum(to_string = "HEADERS")]
 	Headers,
+	#[strum(to_string = "REDIRECTS")]
+	Redirects,
+	#[strum(to_string = "TIMING")]
+	Timing,
+	#[strum(to_string = "ASSERTIONS")]
+	Assertions,
+	#[strum(to_string = "TESTS")]
+	Tests,
 	#[strum(to_string = "CONSOLE")]
 	Console,
 }
 
+impl RequestResultTabs {
+	/// Result tabs displayed for the given request, in order.
+	pub fn allowed_for(request: &Request) -> Vec<RequestResultTabs> {
+		let mut allowed_tabs = vec![
+			RequestResultTabs::main_tab(request),
+			RequestResultTabs::Cookies,
+			RequestResultTabs::Headers,
+		];
+
+		if !request.response.redirects.is_empty() {
+			allowed_tabs.push(RequestResultTabs::Redirects);
+		}
+
+		// Only HTTP responses are timed
+		if request.response.timing.is_some() {
+			allowed_tabs.push(RequestResultTabs::Timing);
+		}
+
+		if !request.assertions.is_empty() {
+			allowed_tabs.push(RequestResultTabs::Assertions);
+		}
+
+		// Tests only exist once a post-request script declared some
+		if !request.test_results.is_empty() {
+			allowed_tabs.push(RequestResultTabs::Tests);
+		}
+
+		allowed_tabs.push(RequestResultTabs::Console);
+
+		allowed_tabs
+	}
+
+	/// Body for request/response protocols, messages for WebSocket, streaming gRPC and
+	/// server-sent events
+	pub fn main_tab(request: &Request) -> RequestResultTabs {
+		match &request.protocol {
+			Protocol::HttpRequest(http_request) => match http_request.is_event_stream {
+				true => RequestResultTabs::Messages,
+				false => RequestResultTabs::Body,
+			},
+			Protocol::GraphqlRequest(_) => RequestResultTabs::Body,
+			Protocol::GrpcRequest(grpc_request) => match grpc_request.is_streaming {
+				true => RequestResultTabs::Messages,
+				false => RequestResultTabs::Body,
+			},
+			Protocol::WsRequest(_) => RequestResultTabs::Messages,
+		}
+	}
+}
+
 impl App<'_> {
 	pub(super) fn render_request_result(
 		&mut self,
@@ -58,52 +117,16 @@ impl App<'_> {
 
 		// REQUEST RESULT TABS
 
-		let allowed_tabs = match &request.protocol {
-			Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => {
-				vec![
-					RequestResultTabs::Body,
-					RequestResultTabs::Cookies,
-					RequestResultTabs::Headers,
-					RequestResultTabs::Console,
-				]
-			}
-			Protocol::WsRequest(_) => vec![
-				RequestResultTabs::Messages,
-				RequestResultTabs::Cookies,
-				RequestResultTabs::Headers,
-				RequestResultTabs::Console,
-			],
-		};
-
-		let selected_request_tab_index = match &request.protocol {
-			Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => {
-				match self.request_result_tab {
-					RequestResultTabs::Body => 0,
-					RequestResultTabs::Cookies => 1,
-					RequestResultTabs::Headers => 2,
-					RequestResultTabs::Console => 3,
-					_ => unreachable!(),
-				}
-			}
-			Protocol::WsRequest(_) => match self.request_result_tab {
-				RequestResultTabs::Messages => 0,
-				RequestResultTabs::Cookies => 1,
-				RequestResultTabs::Headers => 2,
-				RequestResultTabs::Console => 3,
-				_ => unreachable!(),
-			},
-		};
+		let allowed_tabs = RequestResultTabs::allowed_for(request);
+
+		let selected_request_tab_index = allowed_tabs
+			.iter()
+			.position(|tab| *tab == self.request_result_tab)
+			.unwrap_or(0);
 
 		let tab_texts: Vec<String> = allowed_tabs
 			.iter()
-			.map(|tab| match tab {
-				RequestResultTabs::Body => tab.to_string().to_uppercase(),
-				RequestResultTabs::Messages => tab.to_string().to_uppercase(),
-				RequestResultTabs::Cookies | RequestResultTabs::Headers => {
-					tab.to_string().to_uppercase()
-				}
-				RequestResultTabs::Console => tab.to_string().to_uppercase(),
-			})
+			.map(|tab| tab.to_string().to_uppercase())
 			.collect();
 
 		let max_tab_width = tab_texts.iter().map(|t| t.len()).max().unwrap_or(0) + 4;
@@ -140,14 +163,19 @@ impl App<'_> {
 
 		frame.render_widget(result_tabs, request_result_layout[0]);
 
-		// If the selected request is currently pending
-		if request.is_pending {
-			let area = centered_rect(9, 1, request_result_layout[2]);
+		// If the selected request is currently pending, events are shown as they are received
+		if request.is_pending && !request.is_event_stream() {
+			let label = match &request.download_progress {
+				None => String::from("Pending"),
+				Some(download_progress) => format!("Downloading {download_progress}"),
+			};
+
+			let area = centered_rect(label.len() as u16 + 2, 1, request_result_layout[2]);
 
 			self.response_view.throbber_state.calc_next();
 
 			let throbber = Throbber::default()
-				.label("Pending")
+				.label(label)
 				.style(Style::new().fg(THEME.read().ui.secondary_foreground_color))
 				.throbber_set(BRAILLE_DOUBLE)
 				.use_type(WhichUse::Spin);
@@ -168,18 +196,16 @@ impl App<'_> {
 				Some(duration) => duration,
 			};
 
-			let request_size = match &request.response.content {
-				None => "0 KB".to_string(),
-				Some(content) => match content {
-					ResponseContent::Body(body) => {
-						let size_in_kb = body.len() as f64 / 1024.0;
-						format!("{} KB", size_in_kb)
-					}
-					ResponseContent::Image(img) => {
-						let size_in_kb = img.data.len() as f64 / 1024.0;
-						format!("{:.2} KB", size_in_kb)
-					}
-				},
+			let request_size = match (&request.response.content, request.response.body_size) {
+				(_, Some(body_size)) if request.response.is_body_truncated => format!(
+					"{} (first {} shown)",
+					format_size(body_size),
+					format_size(request.response.raw_body.len() as u64)
+				),
+				(_, Some(body_size)) => format_size(body_size),
+				(None, None) => format_size(0),
+				(Some(ResponseContent::Body(body)), None) => format_size(body.len() as u64),
+				(Some(ResponseContent::Image(img)), None) => format_size(img.data.len() as u64),
 			};
 
 			let status_chunks =
@@ -207,9 +233,17 @@ impl App<'_> {
 
 			match self.request_result_tab {
 				RequestResultTabs::Body => match &request.response.content {
-					None => {}
+					None => {
+						self.render_response_body_inputs(frame, request_result_layout[2], request);
+					}
 					Some(content) => match content {
 						ResponseContent::Body(body) => {
+							let body_area = self.render_response_body_inputs(
+								frame,
+								request_result_layout[2],
+								request,
+							);
+
 							// If in selection mode, render the TextInput instead
 							if self.state == AppState::SelectingResponseBody {
 								let syntax = SYNTAX_SET.find_syntax_plain_text().clone();
@@ -218,11 +252,20 @@ impl App<'_> {
 										&mut self.response_view.body_text_area,
 										syntax,
 									),
-									request_result_layout[2],
+									body_area,
 								);
 							} else {
-								let lines: Vec<Line> =
-									if !self.core.config.is_syntax_highlighting_disabled()
+								let body = displayed_response_body(
+									&self.response_view.filtered_body,
+									body,
+								);
+
+								let mut lines: Vec<Line> =
+									if let Some(Err(_)) = &self.response_view.filtered_body {
+										body.lines()
+											.map(|line| Line::raw(line).fg(Color::Red))
+											.collect()
+									} else if !self.core.config.is_syntax_highlighting_disabled()
 										&& self.syntax_highlighting.highlighted_body.is_some()
 									{
 										self.syntax_highlighting
@@ -233,6 +276,8 @@ impl App<'_> {
 										body.lines().map(Line::raw).collect()
 									};
 
+								self.response_view.body_search.highlight(&mut lines);
+
 								let mut body_paragraph = Paragraph::new(lines);
 
 								if self.core.config.should_wrap_body() {
@@ -246,54 +291,55 @@ impl App<'_> {
 									));
 								}
 
-								frame.render_widget(body_paragraph, request_result_layout[2]);
+								frame.render_widget(body_paragraph, body_area);
 							}
 						}
-						ResponseContent::Image(image_response) => match &image_response.image {
-							_ if self.core.config.is_image_preview_disabled() => {
-								let image_disabled_paragraph =
-									Paragraph::new("\nImage preview disabled").centered();
-								frame.render_widget(
-									image_disabled_paragraph,
-									request_result_layout[2],
-								);
-							}
-							Some(image) => {
-								let picker =
-									match self.core.config.is_graphical_protocol_disabled() {
-										true => Picker::halfblocks(),
-										false => Picker::from_query_stdio()
-											.unwrap_or(Picker::halfblocks()),
-									};
-
-								let mut image_static = picker.new_resize_protocol(image.clone());
-
-								frame.render_stateful_widget(
-									StatefulImage::default(),
-									request_result_layout[2],
-									&mut image_static,
-								);
-							}
-							None => {
-								let image_error_paragraph =
-									Paragraph::new("\nCould not decode image")
-										.centered()
-										.fg(THEME.read().ui.font_color);
-								frame
-									.render_widget(image_error_paragraph, request_result_layout[2]);
+						ResponseContent::Image(image_response) => {
+							let image_area = self.render_response_body_inputs(
+								frame,
+								request_result_layout[2],
+								request,
+							);
+
+							match &image_response.image {
+								_ if self.core.config.is_image_preview_disabled() => {
+									let image_disabled_paragraph =
+										Paragraph::new("\nImage preview disabled").centered();
+									frame.render_widget(image_disabled_paragraph, image_area);
+								}
+								Some(image) => {
+									let picker =
+										match self.core.config.is_graphical_protocol_disabled() {
+											true => Picker::halfblocks(),
+											false => Picker::from_query_stdio()
+												.unwrap_or(Picker::halfblocks()),
+										};
+
+									let mut image_static =
+										picker.new_resize_protocol(image.clone());
+
+									frame.render_stateful_widget(
+										StatefulImage::default(),
+										image_area,
+										&mut image_static,
+									);
+								}
+								None => {
+									let image_error_paragraph =
+										Paragraph::new("\nCould not decode image")
+											.centered()
+											.fg(THEME.read().ui.font_color);
+									frame.render_widget(image_error_paragraph, image_area);
+								}
 							}
-						},
+						}
 					},
 				},
 				RequestResultTabs::Messages => {
-					let ws_request = request
-						.get_ws_request()
-						.expect("request should be WebSocket");
-
 					let mut messages = vec![];
 					let mut last_sender: Option<&Sender> = None;
 
-					for message in &ws_request.messages {
+					for message in request.get_stream_messages().unwrap_or_default() {
 						let mut alignment = Alignment::Right;
 
 						let content = message.content.to_content();
@@ -403,21 +449,33 @@ impl App<'_> {
 					frame.render_widget(cookies_paragraph, request_result_layout[2]);
 				}
 				RequestResultTabs::Headers => {
-					let result_headers: Vec<Line> = request
+					let header_line = |(header, value): &(String, String)| {
+						Line::from(vec![
+							Span::raw(header.clone())
+								.bold()
+								.fg(THEME.read().ui.secondary_foreground_color),
+							Span::raw(": ").fg(THEME.read().ui.secondary_foreground_color),
+							Span::raw(value.clone()).fg(THEME.read().ui.font_color),
+						])
+					};
+
+					let mut result_headers: Vec<Line> = request
 						.response
 						.headers
 						.par_iter()
-						.map(|(header, value)| {
-							Line::from(vec![
-								Span::raw(header)
-									.bold()
-									.fg(THEME.read().ui.secondary_foreground_color),
-								Span::raw(": ").fg(THEME.read().ui.secondary_foreground_color),
-								Span::raw(value).fg(THEME.read().ui.font_color),
-							])
-						})
+						.map(header_line)
 						.collect();
 
+					// gRPC trailing metadata
+					let trailers = request.get_response_trailers();
+
+					if !trailers.is_empty() {
+						result_headers.push(Line::default());
+						result_headers
+							.push(Line::from("Trailers").bold().fg(THEME.read().ui.font_color));
+						result_headers.extend(trailers.iter().map(header_line));
+					}
+
 					let headers_paragraph = Paragraph::new(result_headers).scroll((
 						self.response_view.vertical_scrollbar.scroll,
 						self.response_view.horizontal_scrollbar.scroll,
@@ -425,6 +483,71 @@ impl App<'_> {
 
 					frame.render_widget(headers_paragraph, request_result_layout[2]);
 				}
+				RequestResultTabs::Redirects => {
+					let redirects_paragraph = Paragraph::new(redirect_lines(&request.response))
+						.scroll((
+							self.response_view.vertical_scrollbar.scroll,
+							self.response_view.horizontal_scrollbar.scroll,
+						));
+
+					frame.render_widget(redirects_paragraph, request_result_layout[2]);
+				}
+				RequestResultTabs::Timing => {
+					let timing_paragraph =
+						Paragraph::new(timing_lines(&request.response)).scroll((
+							self.response_view.vertical_scrollbar.scroll,
+							self.response_view.horizontal_scrollbar.scroll,
+						));
+
+					frame.render_widget(timing_paragraph, request_result_layout[2]);
+				}
+				RequestResultTabs::Assertions => {
+					let assertion_lines: Vec<Line> = match request.assertion_results.is_empty() {
+						// Not sent yet, only list the assertions
+						true => request
+							.assertions
+							.iter()
+							.map(|assertion| {
+								Line::raw(format!("- {assertion}"))
+									.fg(THEME.read().ui.secondary_foreground_color)
+							})
+							.collect(),
+						false => request
+							.assertion_results
+							.iter()
+							.map(|result| {
+								check_result_line(
+									result.passed,
+									&result.assertion,
+									result.message.as_deref(),
+								)
+							})
+							.collect(),
+					};
+
+					let assertions_paragraph = Paragraph::new(assertion_lines).scroll((
+						self.response_view.vertical_scrollbar.scroll,
+						self.response_view.horizontal_scrollbar.scroll,
+					));
+
+					frame.render_widget(assertions_paragraph, request_result_layout[2]);
+				}
+				RequestResultTabs::Tests => {
+					let test_lines: Vec<Line> = request
+						.test_results
+						.iter()
+						.map(|result| {
+							check_result_line(result.passed, &result.name, result.error.as_deref())
+						})
+						.collect();
+
+					let tests_paragraph = Paragraph::new(test_lines).scroll((
+						self.response_view.vertical_scrollbar.scroll,
+						self.response_view.horizontal_scrollbar.scroll,
+					));
+
+					frame.render_widget(tests_paragraph, request_result_layout[2]);
+				}
 				RequestResultTabs::Console => {
 					let console_paragraph =
 						Paragraph::new(self.syntax_highlighting.highlighted_console_output.clone())
@@ -471,6 +594,78 @@ impl App<'_> {
 		self.last_messages_area_size.0 = request_result_layout[2].width.saturating_sub(1);
 		self.last_messages_area_size.1 = request_result_layout[2].height.saturating_sub(1);
 	}
+
+	/// Render the filter, search and save inputs under the response body, returning the body area
+	fn render_response_body_inputs(
+		&mut self,
+		frame: &mut Frame,
+		rect: Rect,
+		request: &Request,
+	) -> Rect {
+		let is_filter_displayed =
+			self.state == AppState::EditingResponseBodyFilter || request.response_filter.is_some();
+		let is_search_displayed = self.state == AppState::SearchingResponseBody
+			|| !self.response_view.body_search.query().is_empty();
+
+		let is_save_displayed = self.state == AppState::SavingResponseBody;
+
+		let body_layout = Layout::new(
+			Vertical,
+			[
+				Constraint::Fill(1),
+				Constraint::Length(if is_filter_displayed { 2 } else { 0 }),
+				Constraint::Length(if is_search_displayed { 2 } else { 0 }),
+				Constraint::Length(if is_save_displayed { 2 } else { 0 }),
+			],
+		)
+		.split(rect);
+
+		if is_filter_displayed {
+			self.response_view.body_filter_input.display_cursor =
+				self.state == AppState::EditingResponseBodyFilter;
+
+			frame.render_widget(
+				SingleLineTextInput(&mut self.response_view.body_filter_input),
+				body_layout[1],
+			);
+		}
+
+		if is_search_displayed {
+			let search_status = self.response_view.body_search.status();
+
+			let search_layout = Layout::horizontal([
+				Constraint::Fill(1),
+				Constraint::Length(search_status.len() as u16 + 1),
+			])
+			.split(body_layout[2]);
+
+			self.response_view.body_search.input.display_cursor =
+				self.state == AppState::SearchingResponseBody;
+
+			frame.render_widget(
+				SingleLineTextInput(&mut self.response_view.body_search.input),
+				search_layout[0],
+			);
+
+			frame.render_widget(
+				Paragraph::new(search_status)
+					.right_aligned()
+					.fg(THEME.read().ui.secondary_foreground_color),
+				search_layout[1],
+			);
+		}
+
+		if is_save_displayed {
+			self.response_view.body_save_input.display_cursor = true;
+
+			frame.render_widget(
+				SingleLineTextInput(&mut self.response_view.body_save_input),
+				body_layout[3],
+			);
+		}
+
+		body_layout[0]
+	}
 }
 
 fn status_code_color(code: &str) -> Color {
@@ -482,3 +677,142 @@ fn status_code_color(code: &str) -> Color {
 		_ => Color::DarkGray,
 	}
 }
+
+/// Each redirect followed, laid out like [`RequestResponse::redirects_report`], the cookies set by
+/// the redirects being highlighted
+fn redirect_lines<'a>(response: &RequestResponse) -> Vec<Line<'a>> {
+	let mut lines = vec![];
+
+	for (index, hop) in response.redirects.iter().enumerate() {
+		if index > 0 {
+			lines.push(Line::default());
+		}
+
+		lines.push(Line::from(vec![
+			Span::raw(format!("{}. ", index + 1)).fg(THEME.read().ui.secondary_foreground_color),
+			Span::raw(format!("{} ", hop.method))
+				.bold()
+				.fg(THEME.read().ui.font_color),
+			Span::raw(hop.url.clone()).fg(THEME.read().ui.font_color),
+		]));
+
+		lines.push(Line::from(vec![
+			Span::raw("   "),
+			Span::raw(hop.status_code.clone())
+				.bold()
+				.fg(status_code_color(&hop.status_code)),
+			Span::raw(" -> ").fg(THEME.read().ui.secondary_foreground_color),
+			Span::raw(hop.location.clone()).fg(THEME.read().ui.font_color),
+		]));
+
+		for (header, value) in &hop.headers {
+			let header_color = match header.eq_ignore_ascii_case("set-cookie") {
+				true => Color::Yellow,
+				false => THEME.read().ui.secondary_foreground_color,
+			};
+
+			lines.push(Line::from(vec![
+				Span::raw("   "),
+				Span::raw(header.clone()).bold().fg(header_color),
+				Span::raw(": ").fg(THEME.read().ui.secondary_foreground_color),
+				Span::raw(value.clone()).fg(THEME.read().ui.font_color),
+			]));
+		}
+	}
+
+	lines
+}
+
+/// Timing and connection details, the phases of the request being drawn as a waterfall
+fn timing_lines<'a>(response: &RequestResponse) -> Vec<Line<'a>> {
+	const WATERFALL_WIDTH: f64 = 40.0;
+	const PHASE_COLORS: [Color; 5] = [
+		Color::Cyan,
+		Color::Green,
+		Color::Magenta,
+		Color::Yellow,
+		Color::Blue,
+	];
+
+	let timing_details = response.timing_details();
+	let connection_details = response.connection_details();
+	let label_width = timing_details
+		.iter()
+		.chain(&connection_details)
+		.map(|(label, _)| label.len())
+		.max()
+		.unwrap_or(0);
+
+	// Offset and width of each phase in the waterfall
+	let mut bars: Vec<(usize, usize)> = vec![];
+
+	if let Some(timing) = &response.timing {
+		let total = timing.total.as_secs_f64();
+		let mut elapsed = 0.0;
+
+		for (_, duration) in timing.phases() {
+			let duration = duration.unwrap_or_default().as_secs_f64();
+
+			if total > 0.0 {
+				let offset = (elapsed / total * WATERFALL_WIDTH).round() as usize;
+				let width = (duration / total * WATERFALL_WIDTH).round() as usize;
+				bars.push((offset, width.max(usize::from(duration > 0.0))));
+			}
+
+			elapsed += duration;
+		}
+	}
+
+	let detail_line = |label: &str, value: &str| {
+		vec![
+			Span::raw(format!("{label:label_width$}  "))
+				.fg(THEME.read().ui.secondary_foreground_color),
+			Span::raw(format!("{value:10}")).fg(THEME.read().ui.font_color),
+		]
+	};
+
+	let mut lines = vec![];
+
+	for (index, (label, value)) in timing_details.iter().enumerate() {
+		let mut spans = detail_line(label, value);
+
+		if let Some((offset, width)) = bars.get(index) {
+			spans.push(Span::raw(" ".repeat(offset + 2)));
+			spans.push(Span::raw("█".repeat(*width)).fg(PHASE_COLORS[index]));
+		}
+
+		lines.push(Line::from(spans));
+	}
+
+	if !timing_details.is_empty() && !connection_details.is_empty() {
+		lines.push(Line::default());
+	}
+
+	for (label, value) in &connection_details {
+		lines.push(Line::from(detail_line(label, value)));
+	}
+
+	lines
+}
+
+/// A passed/failed line, used for both assertions and script tests
+fn check_result_line<'a>(passed: bool, name: &str, message: Option<&str>) -> Line<'a> {
+	let (symbol, color) = match passed {
+		true => ("✔ PASS", Color::Green),
+		false => ("✘ FAIL", Color::Red),
+	};
+
+	let mut spans = vec![
+		Span::raw(symbol).bold().fg(color),
+		Span::raw(" "),
+		Span::raw(name.to_string()).fg(THEME.read().ui.font_color),
+	];
+
+	if let Some(message) = message {
+		spans.push(
+			Span::raw(format!(" ({message})")).fg(THEME.read().ui.secondary_foreground_color),
+		);
+	}
+
+	Line::from(spans)
+}
@@ -65,7 +65,7 @@ impl<'a> App<'a> {
 
 		frame.render_widget(separator, inner_layout[1]);
 
-		match self.collections_tree.selected {
+		match self.collections_tree.selected.clone() {
 			None => self.render_homepage(frame, inner_layout[2]),
 			Some(selection) => {
 				let selected_request = self.get_request_from_selection(&selection).read().clone();
@@ -112,6 +112,7 @@ impl<'a> App<'a> {
 			ChoosingElementToCreate => self.render_creating_element_popup(frame),
 			DisplayingEnvEditor | EditingEnvVariable => self.render_env_editor_popup(frame),
 			DisplayingCookies | EditingCookies => self.render_cookies_popup(frame),
+			DisplayingHistory => self.render_history_popup(frame),
 			CreatingNewCollection => self.render_creating_new_collection_popup(frame),
 			CreatingNewRequest => self.render_creating_new_request_popup(frame),
 			CreatingNewFolder => self.render_creating_new_folder_popup(frame),
@@ -121,7 +122,12 @@ impl<'a> App<'a> {
 			RenamingCollection => self.render_renaming_collection_popup(frame),
 			RenamingRequest => self.render_renaming_request_popup(frame),
 			RenamingFolder => self.render_renaming_folder_popup(frame),
+			EditingCollectionProperties => self.render_collection_properties_popup(frame),
 			ChoosingTheme => self.render_theme_picker_popup(frame),
+			ChoosingGrpcMethod => self.render_grpc_method_picker_popup(frame),
+			EditingRequestSettings | EditingRequestSettingText => {
+				self.render_request_settings_popup(frame)
+			}
 			_ => {}
 		}
 
@@ -0,0 +1,163 @@
+use std::ops::Range;
+
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+
+use crate::app::files::theme::THEME;
+use crate::tui::utils::stateful::text_input::TextInput;
+
+/// Incremental search in the displayed response body
+pub struct BodySearch {
+	pub input: TextInput,
+	/// Line index and byte range of every match, in order
+	pub matches: Vec<(usize, Range<usize>)>,
+	/// Index of the current match
+	pub selection: usize,
+}
+
+impl Default for BodySearch {
+	fn default() -> Self {
+		BodySearch {
+			input: TextInput::new(Some(String::from("Search"))),
+			matches: vec![],
+			selection: 0,
+		}
+	}
+}
+
+impl BodySearch {
+	pub fn query(&self) -> String {
+		self.input.to_string()
+	}
+
+	pub fn clear(&mut self) {
+		self.input.clear();
+		self.matches.clear();
+		self.selection = 0;
+	}
+
+	/// Find the matches of the query in `text`, ignoring ASCII case. The current match is kept
+	/// when it still exists.
+	pub fn update_matches(&mut self, text: &str) {
+		let query = self.query().to_ascii_lowercase();
+
+		self.matches.clear();
+
+		if !query.is_empty() {
+			for (line_index, line) in text.lines().enumerate() {
+				let lowercase_line = line.to_ascii_lowercase();
+
+				self.matches.extend(
+					lowercase_line
+						.match_indices(&query)
+						.map(|(start, _)| (line_index, start..start + query.len())),
+				);
+			}
+		}
+
+		if self.selection >= self.matches.len() {
+			self.selection = 0;
+		}
+	}
+
+	/// Select the first match starting at or after `line_index`
+	pub fn select_from_line(&mut self, line_index: usize) {
+		self.selection = self
+			.matches
+			.iter()
+			.position(|(match_line, _)| *match_line >= line_index)
+			.unwrap_or(0);
+	}
+
+	pub fn next_match(&mut self) {
+		if !self.matches.is_empty() {
+			self.selection = (self.selection + 1) % self.matches.len();
+		}
+	}
+
+	pub fn previous_match(&mut self) {
+		if !self.matches.is_empty() {
+			self.selection = (self.selection + self.matches.len() - 1) % self.matches.len();
+		}
+	}
+
+	pub fn current_match(&self) -> Option<&(usize, Range<usize>)> {
+		self.matches.get(self.selection)
+	}
+
+	/// Position of the current match among all of them, e.g. `3/12`
+	pub fn status(&self) -> String {
+		match self.matches.is_empty() {
+			true => String::from("No match"),
+			false => format!("{}/{}", self.selection + 1, self.matches.len()),
+		}
+	}
+
+	/// Highlight the matches in the lines of the displayed text, the current one standing out
+	pub fn highlight<'a>(&self, lines: &mut [Line<'a>]) {
+		let match_style = Style::new()
+			.fg(THEME.read().ui.main_background_color)
+			.bg(THEME.read().ui.secondary_foreground_color);
+		let current_match_style = Style::new()
+			.fg(THEME.read().ui.main_background_color)
+			.bg(THEME.read().others.selection_highlight_color);
+
+		for (index, (line_index, range)) in self.matches.iter().enumerate() {
+			let Some(line) = lines.get_mut(*line_index) else {
+				continue;
+			};
+
+			let style = match index == self.selection {
+				true => current_match_style,
+				false => match_style,
+			};
+
+			*line = highlight_range(std::mem::take(line), range, style);
+		}
+	}
+}
+
+/// Split the spans of a line so that the bytes in `range` get `style`
+fn highlight_range<'a>(line: Line<'a>, range: &Range<usize>, style: Style) -> Line<'a> {
+	let mut spans: Vec<Span<'a>> = vec![];
+	let mut offset = 0;
+
+	for span in line.spans {
+		let span_start = offset;
+		let span_end = offset + span.content.len();
+		offset = span_end;
+
+		if span_end <= range.start || span_start >= range.end {
+			spans.push(span);
+			continue;
+		}
+
+		let start = range.start.saturating_sub(span_start);
+		let end = range.end.min(span_end) - span_start;
+		let content = span.content.as_ref();
+
+		if !content.is_char_boundary(start) || !content.is_char_boundary(end) {
+			spans.push(span);
+			continue;
+		}
+
+		if start > 0 {
+			spans.push(Span::styled(content[..start].to_string(), span.style));
+		}
+
+		spans.push(Span::styled(
+			content[start..end].to_string(),
+			span.style.patch(style),
+		));
+
+		if end < content.len() {
+			spans.push(Span::styled(content[end..].to_string(), span.style));
+		}
+	}
+
+	Line {
+		spans,
+		style: line.style,
+		alignment: line.alignment,
+	}
+}
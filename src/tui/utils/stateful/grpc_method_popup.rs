@@ -0,0 +1,34 @@
+use crate::app::request::grpc::descriptors::GrpcMethodInfo;
+
+#[derive(Default)]
+pub struct GrpcMethodPopup {
+	pub methods: Vec<GrpcMethodInfo>,
+	pub selection: usize,
+	/// Why the methods could not be listed
+	pub error: Option<String>,
+}
+
+impl GrpcMethodPopup {
+	pub fn next(&mut self) {
+		if self.methods.is_empty() {
+			return;
+		}
+
+		self.selection = (self.selection + 1) % self.methods.len();
+	}
+
+	pub fn previous(&mut self) {
+		if self.methods.is_empty() {
+			return;
+		}
+
+		self.selection = match self.selection {
+			0 => self.methods.len() - 1,
+			selection => selection - 1,
+		};
+	}
+
+	pub fn get_selection(&self) -> Option<&GrpcMethodInfo> {
+		self.methods.get(self.selection)
+	}
+}
@@ -0,0 +1,67 @@
+use crate::app::files::history::previous_history_entry;
+use crate::models::history::{DiffLine, HistoryEntry};
+use crate::tui::utils::stateful::stateful_scrollbar::StatefulScrollbar;
+
+/// Popup listing the request history, newest entry first
+#[derive(Default)]
+pub struct HistoryPopup {
+	/// Displayed entries, newest first
+	pub entries: Vec<HistoryEntry>,
+	/// Every recorded entry, oldest first, used to find the previous entry of a request
+	all_entries: Vec<HistoryEntry>,
+	pub selection: usize,
+	/// Show the diff with the previous entry of the same request instead of the details
+	pub show_diff: bool,
+	pub details_scrollbar: StatefulScrollbar,
+}
+
+impl HistoryPopup {
+	pub fn init(&mut self, all_entries: Vec<HistoryEntry>, entries: Vec<HistoryEntry>) {
+		self.all_entries = all_entries;
+		self.entries = entries;
+		self.selection = 0;
+		self.show_diff = false;
+		self.details_scrollbar.top();
+	}
+
+	pub fn selected_entry(&self) -> Option<&HistoryEntry> {
+		self.entries.get(self.selection)
+	}
+
+	pub fn previous(&mut self) {
+		if self.selection > 0 {
+			self.selection -= 1;
+			self.details_scrollbar.top();
+		}
+	}
+
+	pub fn next(&mut self) {
+		if self.selection + 1 < self.entries.len() {
+			self.selection += 1;
+			self.details_scrollbar.top();
+		}
+	}
+
+	pub fn toggle_diff(&mut self) {
+		self.show_diff = !self.show_diff;
+		self.details_scrollbar.top();
+	}
+
+	/// Lines of the details pane, either the selected entry or its diff with the previous one
+	pub fn details_lines(&self) -> Vec<DiffLine> {
+		let Some(entry) = self.selected_entry() else {
+			return vec![];
+		};
+
+		if !self.show_diff {
+			return entry.to_lines().into_iter().map(DiffLine::Same).collect();
+		}
+
+		match previous_history_entry(&self.all_entries, entry) {
+			Some(previous) => previous.diff(entry),
+			None => vec![DiffLine::Same(String::from(
+				"No earlier entry for this request",
+			))],
+		}
+	}
+}
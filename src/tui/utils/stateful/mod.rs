@@ -1,8 +1,11 @@
+pub(crate) mod body_search;
 pub(crate) mod choice_popup;
 pub(crate) mod cookie_table;
 pub(crate) mod cookies_popup;
 pub(crate) mod display_popup;
+pub(crate) mod grpc_method_popup;
 pub(crate) mod help_popup;
+pub(crate) mod history_popup;
 pub(crate) mod new_request_popup;
 pub(crate) mod script_console;
 pub(crate) mod settings_popup;
@@ -11,8 +11,11 @@ pub struct NewRequestPopup {
 	pub selected_collection: usize,
 	pub max_collection_selection: usize,
 
+	/// Index in `folder_paths`, `None` for the collection root
 	pub selected_folder: Option<usize>,
-	pub folder_count: usize,
+	/// Path of every folder of the selected collection, see
+	/// [`crate::models::collection::Collection::folder_paths`]
+	pub folder_paths: Vec<Vec<usize>>,
 
 	pub protocol: Protocol,
 
@@ -26,7 +29,7 @@ impl Default for NewRequestPopup {
 			selected_collection: 0,
 			max_collection_selection: 0,
 			selected_folder: None,
-			folder_count: 0,
+			folder_paths: vec![],
 			protocol: Protocol::default(),
 			text_input: TextInput::new(Some(String::from("Request name"))),
 		}
@@ -80,10 +83,10 @@ impl NewRequestPopup {
 		} else {
 			self.selected_collection = 0;
 		}
-		// Reset folder selection when collection changes — folder_count will be
+		// Reset folder selection when collection changes — folder_paths will be
 		// updated by the App before the next render
 		self.selected_folder = None;
-		self.folder_count = 0;
+		self.folder_paths.clear();
 	}
 
 	pub fn previous_collection(&mut self) {
@@ -94,33 +97,41 @@ impl NewRequestPopup {
 		}
 		// Reset folder selection when collection changes
 		self.selected_folder = None;
-		self.folder_count = 0;
+		self.folder_paths.clear();
 	}
 
 	pub fn next_folder(&mut self) {
-		if self.folder_count == 0 {
+		if self.folder_paths.is_empty() {
 			return;
 		}
 
 		self.selected_folder = match self.selected_folder {
 			None => Some(0),
-			Some(i) if i + 1 < self.folder_count => Some(i + 1),
+			Some(i) if i + 1 < self.folder_paths.len() => Some(i + 1),
 			Some(_) => None,
 		};
 	}
 
 	pub fn previous_folder(&mut self) {
-		if self.folder_count == 0 {
+		if self.folder_paths.is_empty() {
 			return;
 		}
 
 		self.selected_folder = match self.selected_folder {
-			None => Some(self.folder_count - 1),
+			None => Some(self.folder_paths.len() - 1),
 			Some(0) => None,
 			Some(i) => Some(i - 1),
 		};
 	}
 
+	/// Path of the folder the request is created in, empty for the collection root
+	pub fn selected_folder_path(&self) -> Vec<usize> {
+		self.selected_folder
+			.and_then(|index| self.folder_paths.get(index))
+			.cloned()
+			.unwrap_or_default()
+	}
+
 	pub fn next_protocol(&mut self) {
 		self.protocol = match self.protocol {
 			Protocol::HttpRequest(_) => Protocol::WsRequest(WsRequest::default()),
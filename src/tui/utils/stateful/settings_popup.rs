@@ -1,9 +1,21 @@
-use crate::models::settings::Setting;
+use crate::models::settings::{Setting, u32_setting_range};
+use crate::tui::utils::stateful::text_input::TextInput;
 
-#[derive(Default)]
 pub struct SettingsPopup {
 	pub settings: Vec<(String, Setting)>,
 	pub selection: usize,
+	/// Edits the selected text setting
+	pub text_input: TextInput,
+}
+
+impl Default for SettingsPopup {
+	fn default() -> Self {
+		SettingsPopup {
+			settings: vec![],
+			selection: 0,
+			text_input: TextInput::new(None),
+		}
+	}
 }
 
 impl SettingsPopup {
@@ -23,23 +35,41 @@ impl SettingsPopup {
 		}
 	}
 
+	pub fn is_text_setting_selected(&self) -> bool {
+		matches!(self.settings[self.selection].1, Setting::Text(_))
+	}
+
+	/// Text settings are cleared
 	pub fn toggle_setting_left(&mut self) {
-		match self.settings[self.selection].1 {
+		let (setting_name, setting) = &self.settings[self.selection];
+		let (step, min, _) = u32_setting_range(setting_name);
+
+		match *setting {
 			Setting::Bool(_) => self.settings[self.selection].1 = Setting::Bool(false),
-			Setting::U32(u32) => match u32 > 100 {
-				true => self.settings[self.selection].1 = Setting::U32(u32 - 100),
-				false => self.settings[self.selection].1 = Setting::U32(100),
+			Setting::U32(u32) => match u32 > min.saturating_add(step) {
+				true => self.settings[self.selection].1 = Setting::U32(u32 - step),
+				false => self.settings[self.selection].1 = Setting::U32(min),
 			},
+			Setting::Text(_) => self.settings[self.selection].1 = Setting::Text(String::new()),
 		}
 	}
 
+	/// Text settings are edited with [`SettingsPopup::text_input`] instead
 	pub fn toggle_setting_right(&mut self) {
-		match self.settings[self.selection].1 {
+		let (setting_name, setting) = &self.settings[self.selection];
+		let (step, _, max) = u32_setting_range(setting_name);
+
+		match *setting {
 			Setting::Bool(_) => self.settings[self.selection].1 = Setting::Bool(true),
-			Setting::U32(u32) => match u32 < 100000 {
-				true => self.settings[self.selection].1 = Setting::U32(u32 + 100),
-				false => self.settings[self.selection].1 = Setting::U32(100000),
+			Setting::U32(u32) => match u32 < max.saturating_sub(step) {
+				true => self.settings[self.selection].1 = Setting::U32(u32 + step),
+				false => self.settings[self.selection].1 = Setting::U32(max),
 			},
+			Setting::Text(_) => {}
 		}
 	}
+
+	pub fn modify_text_setting(&mut self) {
+		self.settings[self.selection].1 = Setting::Text(self.text_input.to_string());
+	}
 }
@@ -1,13 +1,16 @@
 use tui_tree_widget::{TreeItem, TreeState};
 
+use crate::models::collection::{Collection, TreeElement};
+
 /// Represents which request is currently selected in the tree.
 /// This maps tree selection paths back to collection/folder/request indexes.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SelectedRequest {
 	/// A root-level request: (collection_index, request_index)
 	RootRequest(usize, usize),
-	/// A request inside a folder: (collection_index, folder_index, request_index)
-	FolderRequest(usize, usize, usize),
+	/// A request inside a folder: (collection_index, folder_path, request_index), the folder path
+	/// holding the index of every folder from the collection down to the request one
+	FolderRequest(usize, Vec<usize>, usize),
 }
 
 impl SelectedRequest {
@@ -27,11 +30,11 @@ impl SelectedRequest {
 		}
 	}
 
-	/// Returns the folder index, if the request is inside a folder.
-	pub fn folder_index(&self) -> Option<usize> {
+	/// Returns the path of the folder holding the request, empty for a root-level request.
+	pub fn folder_path(&self) -> &[usize] {
 		match self {
-			SelectedRequest::RootRequest(_, _) => None,
-			SelectedRequest::FolderRequest(_, f, _) => Some(*f),
+			SelectedRequest::RootRequest(_, _) => &[],
+			SelectedRequest::FolderRequest(_, folder_path, _) => folder_path,
 		}
 	}
 }
@@ -52,40 +55,32 @@ impl StatefulTree<'_> {
 		self.state.key_down();
 	}
 
-	/// Call this when the user selects a request (depth 2 or 3 in the tree).
-	/// `folder_count` is the number of folders in the selected collection,
-	/// used to determine whether a depth-2 selection is a folder or a root request.
-	pub fn set_selected_with_context(&mut self, folder_count: usize) {
+	/// Call this when the user selects a request, at any depth in the tree.
+	/// `collection` is the selected collection, used to determine whether the selection is a
+	/// folder or a request.
+	pub fn set_selected_with_context(&mut self, collection: &Collection) {
 		let path = self.state.selected();
-		match path.len() {
-			2 => {
-				let collection_index = path[0];
-				let child_index = path[1];
-				if child_index < folder_count {
-					// This is a folder, not a request - don't select
-					self.selected = None;
-				} else {
-					// Root-level request (offset by folder count)
-					self.selected = Some(SelectedRequest::RootRequest(
-						collection_index,
-						child_index - folder_count,
-					));
+
+		self.selected = match path.split_first() {
+			Some((collection_index, tree_path)) => match collection.resolve_tree_path(tree_path) {
+				Some(TreeElement::Request(folder_path, request_index)) => {
+					match folder_path.is_empty() {
+						true => Some(SelectedRequest::RootRequest(
+							*collection_index,
+							request_index,
+						)),
+						false => Some(SelectedRequest::FolderRequest(
+							*collection_index,
+							folder_path,
+							request_index,
+						)),
+					}
 				}
-			}
-			3 => {
-				let collection_index = path[0];
-				let folder_index = path[1];
-				let request_index = path[2];
-				self.selected = Some(SelectedRequest::FolderRequest(
-					collection_index,
-					folder_index,
-					request_index,
-				));
-			}
-			_ => {
-				self.selected = None;
-			}
-		}
+				// A folder, not a request - don't select
+				Some(TreeElement::Folder(_)) | None => None,
+			},
+			None => None,
+		};
 	}
 
 	pub fn set_unselected(&mut self) {
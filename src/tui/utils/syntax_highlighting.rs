@@ -43,6 +43,11 @@ pub static JS_SYNTAX_REF: LazyLock<&'static SyntaxReference> = LazyLock::new(||
 		.find_syntax_by_extension("js")
 		.expect("js syntax should be in default syntax set")
 });
+pub static YAML_SYNTAX_REF: LazyLock<&'static SyntaxReference> = LazyLock::new(|| {
+	SYNTAX_SET
+		.find_syntax_by_extension("yaml")
+		.expect("yaml syntax should be in default syntax set")
+});
 pub static THEME_SET: LazyLock<Arc<ThemeSet>> =
 	LazyLock::new(|| Arc::new(ThemeSet::load_defaults()));
 pub static SYNTAX_THEME: LazyLock<&'static Theme> =
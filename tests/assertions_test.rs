@@ -0,0 +1,280 @@
+mod helpers;
+
+use helpers::{
+	collection_with_request, minimal_collection_json, seed_collection, send_request, squrl,
+	temp_dir,
+};
+use predicates::prelude::*;
+use serde_json::Value;
+use std::fs;
+
+// ── Assertions CRUD ───────────────────────────────────────────
+
+#[test]
+fn test_request_assertions_add_all_and_delete() {
+	let dir = temp_dir();
+	let collection_path = seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", "https://example.com"),
+	);
+
+	for assertion in ["status == 200", "jsonpath $.id == 42"] {
+		squrl()
+			.args([
+				"-d",
+				dir.path().to_str().unwrap(),
+				"request",
+				"assertions",
+				"my-api/req",
+				"add",
+				assertion,
+			])
+			.assert()
+			.success();
+	}
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"assertions",
+			"my-api/req",
+			"all",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("0: status == 200"))
+		.stdout(predicate::str::contains("1: jsonpath $.id == 42"));
+
+	let saved: Value =
+		serde_json::from_str(&fs::read_to_string(&collection_path).unwrap()).unwrap();
+	assert_eq!(saved["requests"][0]["assertions"][0]["status_equals"], 200);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"assertions",
+			"my-api/req",
+			"delete",
+			"0",
+		])
+		.assert()
+		.success();
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"assertions",
+			"my-api/req",
+			"all",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("0: jsonpath $.id == 42"))
+		.stdout(predicate::str::contains("status == 200").not());
+}
+
+#[test]
+fn test_request_assertions_add_invalid() {
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", "https://example.com"),
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"assertions",
+			"my-api/req",
+			"add",
+			"status is fine",
+		])
+		.assert()
+		.failure();
+}
+
+// ── Send ──────────────────────────────────────────────────────
+
+#[test]
+fn test_request_send_prints_passing_assertions() {
+	let mut server = mockito::Server::new();
+	server
+		.mock("GET", "/user")
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"id": 42}"#)
+		.create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&format!("{}/user", server.url()), |collection| {
+			collection["requests"][0]["assertions"] = serde_json::json!([
+				{"status_equals": 200},
+				{"json_path_equals": {"path": "$.id", "value": 42}}
+			]);
+		}),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.arg("--hide-content")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("PASS status == 200"))
+		.stdout(predicate::str::contains("PASS jsonpath $.id == 42"));
+}
+
+#[test]
+fn test_request_send_fails_on_failing_assertion() {
+	let mut server = mockito::Server::new();
+	server
+		.mock("GET", "/user")
+		.with_status(200)
+		.with_body(r#"{"id": 7}"#)
+		.create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&format!("{}/user", server.url()), |collection| {
+			collection["requests"][0]["assertions"] =
+				serde_json::json!([{"json_path_equals": {"path": "$.id", "value": 42}}]);
+		}),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.arg("--hide-content")
+		.assert()
+		.failure()
+		.stdout(predicate::str::contains(
+			"FAIL jsonpath $.id == 42 (Expected 42 at \"$.id\", got 7)",
+		))
+		.stderr(predicate::str::contains("1 of 1 assertion(s) failed"));
+}
+
+#[test]
+fn test_collection_send_status_assertion_overrides_default_check() {
+	let mut server = mockito::Server::new();
+	server.mock("GET", "/missing").with_status(404).create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&format!("{}/missing", server.url()), |collection| {
+			collection["requests"][0]["assertions"] = serde_json::json!([{"status_equals": 404}]);
+		}),
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"my-api",
+			"--hide-content",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("PASS req"));
+}
+
+#[test]
+fn test_collection_send_reports_failing_assertions() {
+	let mut server = mockito::Server::new();
+	server.mock("GET", "/ok").with_status(200).create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&format!("{}/ok", server.url()), |collection| {
+			collection["requests"][0]["assertions"] =
+				serde_json::json!([{"header_exists": "x-request-id"}]);
+		}),
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"my-api",
+			"--hide-content",
+		])
+		.assert()
+		.failure()
+		.stdout(predicate::str::contains("FAIL req"))
+		.stdout(predicate::str::contains(
+			"Assertion \"header x-request-id exists\" failed: Header \"x-request-id\" not found",
+		));
+}
+
+// ── .http round-trip ──────────────────────────────────────────
+
+#[test]
+fn test_http_file_assertions_roundtrip() {
+	// The .http collection is named after the project directory
+	let root = temp_dir();
+	let project = root.path().join("project");
+	fs::create_dir_all(project.join(".git")).unwrap();
+	fs::create_dir_all(project.join("requests")).unwrap();
+
+	let http_file = project.join("requests").join("users.http");
+	fs::write(
+		&http_file,
+		"### get-user\n# @assert status == 200\nGET https://example.com/users/1\n",
+	)
+	.unwrap();
+
+	let dir = temp_dir();
+
+	squrl()
+		.current_dir(&project)
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"assertions",
+			"project/get-user",
+			"add",
+			"header content-type matches ^application/json",
+		])
+		.assert()
+		.success();
+
+	let content = fs::read_to_string(&http_file).unwrap();
+	assert!(content.contains("# @assert status == 200\n"));
+	assert!(content.contains("# @assert header content-type matches ^application/json\n"));
+
+	squrl()
+		.current_dir(&project)
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"assertions",
+			"project/get-user",
+			"all",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("0: status == 200"))
+		.stdout(predicate::str::contains(
+			"1: header content-type matches ^application/json",
+		));
+}
@@ -0,0 +1,213 @@
+mod helpers;
+
+use helpers::{
+	collection_with_request, minimal_collection_json, seed_collection, send_request, squrl,
+	temp_dir,
+};
+use mockito::Matcher;
+use predicates::prelude::*;
+use serde_json::Value;
+
+/// Sign the request with the given AWS SigV4 auth, and send it with the given method and JSON body
+fn sign_request(request: &mut Value, aws_sig_v4: Value, method: &str, body: Value) {
+	request["auth"] = serde_json::json!({ "aws_sig_v4": aws_sig_v4 });
+	request["protocol"]["method"] = Value::from(method);
+	request["protocol"]["body"] = body;
+	request["headers"]
+		.as_array_mut()
+		.unwrap()
+		.push(serde_json::json!({"enabled": true, "data": ["content-type", "application/json"]}));
+}
+
+#[test]
+fn test_request_is_signed() {
+	let mut server = mockito::Server::new();
+	let mock = server
+		.mock("POST", "/prod/items")
+		.match_query(Matcher::UrlEncoded("page".into(), "2".into()))
+		.match_header(
+			"authorization",
+			Matcher::Regex(String::from(
+				r"^AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/\d{8}/eu-west-1/execute-api/aws4_request, SignedHeaders=content-type;host;x-amz-date;x-amz-security-token, Signature=[0-9a-f]{64}$",
+			)),
+		)
+		.match_header("x-amz-date", Matcher::Regex(String::from(r"^\d{8}T\d{6}Z$")))
+		.match_header("x-amz-security-token", "session")
+		// Only S3 gets the payload hash as a header
+		.match_header("x-amz-content-sha256", Matcher::Missing)
+		.match_body(r#"{"id": 1}"#)
+		.create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(
+			&format!("{}/prod/items?page=2", server.url()),
+			|collection| {
+				sign_request(
+					&mut collection["requests"][0],
+					serde_json::json!({
+						"access_key_id": "AKIDEXAMPLE",
+						"secret_access_key": "secret",
+						"region": "eu-west-1",
+						"service": "execute-api",
+						"session_token": "session"
+					}),
+					"POST",
+					serde_json::json!({"json": r#"{"id": 1}"#}),
+				);
+			},
+		),
+	);
+
+	send_request(dir.path(), "my-api/req").assert().success();
+
+	mock.assert();
+}
+
+#[test]
+fn test_s3_request_sends_payload_hash() {
+	let mut server = mockito::Server::new();
+	let mock = server
+		.mock("PUT", "/bucket/key.json")
+		.match_header(
+			"authorization",
+			Matcher::Regex(String::from(
+				"SignedHeaders=content-type;host;x-amz-content-sha256;x-amz-date, ",
+			)),
+		)
+		.match_header(
+			"x-amz-content-sha256",
+			"354aaef7a5f6ecbb2faee49fbe47a24e024cb62b3183b853a1ecc01e01920e49",
+		)
+		.create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&format!("{}/bucket/key.json", server.url()), |collection| {
+			sign_request(
+				&mut collection["requests"][0],
+				serde_json::json!({
+					"access_key_id": "AKIDEXAMPLE",
+					"secret_access_key": "secret",
+					"region": "us-east-1",
+					"service": "s3"
+				}),
+				"PUT",
+				serde_json::json!({"json": r#"{"id": 1}"#}),
+			);
+		}),
+	);
+
+	send_request(dir.path(), "my-api/req").assert().success();
+
+	mock.assert();
+}
+
+#[test]
+fn test_export_aws_sig_v4() {
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(
+			"https://abc.execute-api.eu-west-1.amazonaws.com/prod/items",
+			|collection| {
+				sign_request(
+					&mut collection["requests"][0],
+					serde_json::json!({
+						"access_key_id": "AKIDEXAMPLE",
+						"secret_access_key": "secret",
+						"region": "eu-west-1",
+						"service": "execute-api",
+						"session_token": "session"
+					}),
+					"GET",
+					Value::from("no_body"),
+				);
+			},
+		),
+	);
+
+	let export = |format: &str| {
+		squrl()
+			.args([
+				"-d",
+				dir.path().to_str().unwrap(),
+				"request",
+				"export",
+				"my-api/req",
+				format,
+			])
+			.assert()
+			.success()
+	};
+
+	// curl signs the request itself
+	export("curl")
+		.stdout(predicate::str::contains(
+			"--aws-sigv4 'aws:amz:eu-west-1:execute-api'",
+		))
+		.stdout(predicate::str::contains("--user 'AKIDEXAMPLE:secret'"))
+		.stdout(predicate::str::contains(
+			"--header 'X-Amz-Security-Token: session'",
+		));
+
+	export("http")
+		.stdout(predicate::str::is_match(r"\nX-Amz-Date: \d{8}T\d{6}Z\n").unwrap())
+		.stdout(predicate::str::contains(
+			"\nX-Amz-Security-Token: session\n",
+		))
+		.stdout(predicate::str::contains(
+			"\nAuthorization: AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/",
+		));
+}
+
+#[test]
+fn test_request_auth_set_aws_sig_v4() {
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", "https://example.com"),
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"auth",
+			"my-api/req",
+			"set",
+			"aws",
+			"AKIDEXAMPLE",
+			"secret",
+			"eu-west-1",
+			"execute-api",
+			"--session-token",
+			"session",
+		])
+		.assert()
+		.success();
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"auth",
+			"my-api/req",
+			"get",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("AWS SigV4"))
+		.stdout(predicate::str::contains("access_key_id: AKIDEXAMPLE"))
+		.stdout(predicate::str::contains("region: eu-west-1"))
+		.stdout(predicate::str::contains("service: execute-api"))
+		.stdout(predicate::str::contains("session_token: session"));
+}
@@ -0,0 +1,218 @@
+mod helpers;
+
+use helpers::{
+	collection_with_request, minimal_collection_json, seed_collection, seed_environment,
+	send_request, squrl, temp_dir,
+};
+use predicates::prelude::*;
+use serde_json::Value;
+use std::fs;
+
+// ── Captures CRUD ─────────────────────────────────────────────
+
+#[test]
+fn test_request_captures_add_all_and_delete() {
+	let dir = temp_dir();
+	let collection_path = seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", "https://example.com"),
+	);
+
+	for capture in ["TOKEN = jsonpath $.token", "SESSION = cookie session_id"] {
+		squrl()
+			.args([
+				"-d",
+				dir.path().to_str().unwrap(),
+				"request",
+				"captures",
+				"my-api/req",
+				"add",
+				capture,
+			])
+			.assert()
+			.success();
+	}
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"captures",
+			"my-api/req",
+			"all",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("0: TOKEN = jsonpath $.token"))
+		.stdout(predicate::str::contains("1: SESSION = cookie session_id"));
+
+	let saved: Value =
+		serde_json::from_str(&fs::read_to_string(&collection_path).unwrap()).unwrap();
+	assert_eq!(
+		saved["requests"][0]["captures"][1]["source"]["cookie"],
+		"session_id"
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"captures",
+			"my-api/req",
+			"delete",
+			"0",
+		])
+		.assert()
+		.success();
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"captures",
+			"my-api/req",
+			"all",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("0: SESSION = cookie session_id"))
+		.stdout(predicate::str::contains("TOKEN").not());
+}
+
+#[test]
+fn test_request_captures_add_invalid() {
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", "https://example.com"),
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"captures",
+			"my-api/req",
+			"add",
+			"TOKEN = body token",
+		])
+		.assert()
+		.failure();
+}
+
+// ── Chaining ──────────────────────────────────────────────────
+
+#[test]
+fn test_collection_send_chains_captured_value_through_global_env() {
+	let mut server = mockito::Server::new();
+	server
+		.mock("GET", "/login")
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"token": "abc123"}"#)
+		.create();
+	let me_mock = server
+		.mock("GET", "/me")
+		.match_header("authorization", "Bearer abc123")
+		.with_status(200)
+		.create();
+
+	let dir = temp_dir();
+	let env_path = seed_environment(dir.path(), "dev", "TOKEN=\n");
+	// The "req" login request captures a token used by the following "me" request
+	let collection = collection_with_request(&format!("{}/login", server.url()), |collection| {
+		let mut me = collection["requests"][0].clone();
+		me["name"] = Value::from("me");
+		me["url"] = Value::from(format!("{}/me", server.url()));
+		me["headers"] = serde_json::json!([
+			{"enabled": true, "data": ["authorization", "Bearer {{TOKEN}}"]}
+		]);
+
+		collection["requests"][0]["captures"] = serde_json::json!([
+			{"key": "TOKEN", "source": {"json_path": "$.token"}}
+		]);
+		collection["requests"].as_array_mut().unwrap().push(me);
+	});
+	seed_collection(dir.path(), "my-api", &collection);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"my-api",
+			"--env",
+			"dev",
+			"--hide-content",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("2 passed, 0 failed"));
+
+	me_mock.assert();
+	assert!(
+		fs::read_to_string(env_path)
+			.unwrap()
+			.contains("TOKEN=abc123")
+	);
+}
+
+#[test]
+fn test_request_send_writes_capture_to_collection_env() {
+	let mut server = mockito::Server::new();
+	server
+		.mock("GET", "/login")
+		.with_status(200)
+		.with_header("x-request-id", "req-42")
+		.create();
+
+	let dir = temp_dir();
+	let collection = collection_with_request(&format!("{}/login", server.url()), |collection| {
+		collection["environments"] = serde_json::json!([{"name": "dev", "values": {}}]);
+		collection["selected_environment"] = Value::from("dev");
+		collection["requests"][0]["captures"] = serde_json::json!([
+			{"key": "REQUEST_ID", "source": {"header": "x-request-id"}}
+		]);
+	});
+	let collection_path = seed_collection(dir.path(), "my-api", &collection);
+
+	send_request(dir.path(), "my-api/req")
+		.arg("--hide-content")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("PASS capture \"REQUEST_ID\""));
+
+	let saved: Value =
+		serde_json::from_str(&fs::read_to_string(&collection_path).unwrap()).unwrap();
+	assert_eq!(saved["environments"][0]["values"]["REQUEST_ID"], "req-42");
+}
+
+#[test]
+fn test_request_send_fails_on_missing_capture() {
+	let mut server = mockito::Server::new();
+	server.mock("GET", "/login").with_status(200).create();
+
+	let dir = temp_dir();
+	let collection = collection_with_request(&format!("{}/login", server.url()), |collection| {
+		collection["requests"][0]["captures"] = serde_json::json!([
+			{"key": "SESSION", "source": {"cookie": "session_id"}}
+		]);
+	});
+	seed_collection(dir.path(), "my-api", &collection);
+
+	send_request(dir.path(), "my-api/req")
+		.arg("--hide-content")
+		.assert()
+		.failure()
+		.stdout(predicate::str::contains(
+			"FAIL capture \"SESSION\" (Cookie \"session_id\" not found)",
+		))
+		.stderr(predicate::str::contains("1 of 1 capture(s) failed"));
+}
@@ -1,6 +1,6 @@
 mod helpers;
 
-use helpers::{seed_collection, squrl, temp_dir};
+use helpers::{seed_collection, send_request, squrl, temp_dir};
 use predicates::prelude::*;
 use std::fs;
 
@@ -970,16 +970,8 @@ fn test_collection_env_flag_on_send() {
 	// Send with --collection-env should not crash (the request will fail
 	// because the URL doesn't resolve env vars at DNS level, but the flag
 	// itself should be parsed and accepted)
-	let output = squrl()
-		.args([
-			"-d",
-			dir.path().to_str().unwrap(),
-			"request",
-			"send",
-			"my-api/get-users",
-			"--collection-env",
-			"prod",
-		])
+	let output = send_request(dir.path(), "my-api/get-users")
+		.args(["--collection-env", "prod"])
 		.assert();
 
 	// The command should have run (not failed on arg parsing)
@@ -0,0 +1,221 @@
+mod helpers;
+
+use helpers::{collection_with_request, minimal_collection_json, seed_collection, squrl, temp_dir};
+use predicates::prelude::*;
+use serde_json::Value;
+use std::fs;
+
+/// A "runner" collection with one root request and one folder holding a second request.
+fn collection_with_folder(server_url: &str) -> String {
+	collection_with_request(&format!("{server_url}/ok"), |collection| {
+		collection["name"] = Value::from("runner");
+		collection["requests"][0]["name"] = Value::from("root-request");
+
+		let mut folder_request = collection["requests"][0].clone();
+		folder_request["name"] = Value::from("folder-request");
+		folder_request["url"] = Value::from(format!("{server_url}/error"));
+
+		collection["folders"] = serde_json::json!([
+			{
+				"name": "my-folder",
+				"requests": [folder_request]
+			}
+		]);
+	})
+}
+
+#[test]
+fn test_collection_send_passes_on_success() {
+	let mut server = mockito::Server::new();
+	let mock = server.mock("GET", "/ok").with_status(200).create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"runner",
+		&minimal_collection_json("runner", "ok-request", &format!("{}/ok", server.url())),
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"runner",
+			"--hide-content",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("PASS ok-request"))
+		.stdout(predicate::str::contains("1 passed, 0 failed, 0 skipped"));
+
+	mock.assert();
+}
+
+#[test]
+fn test_collection_send_walks_folders_and_fails_on_error_status() {
+	let mut server = mockito::Server::new();
+	let ok_mock = server.mock("GET", "/ok").with_status(200).create();
+	let error_mock = server.mock("GET", "/error").with_status(500).create();
+
+	let dir = temp_dir();
+	seed_collection(dir.path(), "runner", &collection_with_folder(&server.url()));
+
+	let output = squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"runner",
+			"--hide-content",
+		])
+		.assert()
+		.failure()
+		.stdout(predicate::str::contains("FAIL my-folder/folder-request"))
+		.stdout(predicate::str::contains("PASS root-request"))
+		.stdout(predicate::str::contains("1 passed, 1 failed, 0 skipped"));
+
+	// Folder requests come before root requests, as in the collection tree
+	let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+	assert!(stdout.find("my-folder/folder-request") < stdout.find("root-request"));
+
+	ok_mock.assert();
+	error_mock.assert();
+}
+
+#[test]
+fn test_collection_send_bail_stops_at_first_failure() {
+	let mut server = mockito::Server::new();
+	let ok_mock = server
+		.mock("GET", "/ok")
+		.with_status(200)
+		.expect(0)
+		.create();
+	let error_mock = server.mock("GET", "/error").with_status(500).create();
+
+	let dir = temp_dir();
+	seed_collection(dir.path(), "runner", &collection_with_folder(&server.url()));
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"runner",
+			"--hide-content",
+			"--bail",
+		])
+		.assert()
+		.failure()
+		.stdout(predicate::str::contains("0 passed, 1 failed, 0 skipped"));
+
+	ok_mock.assert();
+	error_mock.assert();
+}
+
+#[test]
+fn test_collection_send_filters_and_iterations() {
+	let mut server = mockito::Server::new();
+	let ok_mock = server
+		.mock("GET", "/ok")
+		.with_status(200)
+		.expect(3)
+		.create();
+	let error_mock = server
+		.mock("GET", "/error")
+		.with_status(500)
+		.expect(0)
+		.create();
+
+	let dir = temp_dir();
+	seed_collection(dir.path(), "runner", &collection_with_folder(&server.url()));
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"runner",
+			"--hide-content",
+			"--request",
+			"root-request",
+			"--iterations",
+			"3",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("3 passed, 0 failed, 0 skipped"));
+
+	ok_mock.assert();
+	error_mock.assert();
+}
+
+#[test]
+fn test_collection_send_unknown_folder_filter_fails() {
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"runner",
+		&collection_with_folder("http://127.0.0.1:1"),
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"runner",
+			"--folder",
+			"does-not-exist",
+		])
+		.assert()
+		.failure()
+		.stderr(predicate::str::contains(
+			"No request matches the given filters",
+		));
+}
+
+#[test]
+fn test_collection_send_writes_reports() {
+	let mut server = mockito::Server::new();
+	server.mock("GET", "/ok").with_status(200).create();
+	server.mock("GET", "/error").with_status(500).create();
+
+	let dir = temp_dir();
+	seed_collection(dir.path(), "runner", &collection_with_folder(&server.url()));
+
+	let junit_path = dir.path().join("report.xml");
+	let json_path = dir.path().join("report.json");
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"runner",
+			"--hide-content",
+			"--junit",
+			junit_path.to_str().unwrap(),
+			"--json-report",
+			json_path.to_str().unwrap(),
+		])
+		.assert()
+		.failure();
+
+	let junit = fs::read_to_string(&junit_path).unwrap();
+	assert!(junit.contains("<testsuites name=\"runner\" tests=\"2\" failures=\"1\""));
+	assert!(junit.contains("<testcase name=\"my-folder/folder-request\""));
+	assert!(junit.contains("<failure message=\"Unexpected status: 500 Internal Server Error\"/>"));
+
+	let json: Value = serde_json::from_str(&fs::read_to_string(&json_path).unwrap()).unwrap();
+	assert_eq!(json["collection"], "runner");
+	assert_eq!(json["summary"]["total"], 2);
+	assert_eq!(json["summary"]["failed"], 1);
+	assert_eq!(json["results"][0]["folder"], "my-folder");
+}
@@ -0,0 +1,145 @@
+mod helpers;
+
+use helpers::{minimal_collection_json, seed_collection, send_request, squrl, temp_dir};
+use predicates::prelude::*;
+use std::fs;
+
+const NETSCAPE_COOKIES: &str = "# Netscape HTTP Cookie File\n\
+	.example.com\tTRUE\t/\tTRUE\t4102444800\tsession\tabc\n\
+	api.example.com\tFALSE\t/v1\tFALSE\t0\ttoken\txyz\n";
+
+fn cookies(dir: &std::path::Path, args: &[&str]) -> assert_cmd::assert::Assert {
+	squrl()
+		.args(["-d", dir.to_str().unwrap(), "cookies"])
+		.args(args)
+		.assert()
+}
+
+// ── Persistence ───────────────────────────────────────────────
+
+#[test]
+fn test_received_cookies_are_persisted_across_runs() {
+	let mut server = mockito::Server::new();
+	server
+		.mock("GET", "/login")
+		.with_status(200)
+		.with_header("set-cookie", "session_id=s3cr3t; Path=/; Max-Age=3600")
+		.create();
+	let me_mock = server
+		.mock("GET", "/me")
+		.match_header("cookie", "session_id=s3cr3t")
+		.with_status(200)
+		.create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"login",
+		&minimal_collection_json("login", "req", &format!("{}/login", server.url())),
+	);
+	seed_collection(
+		dir.path(),
+		"me",
+		&minimal_collection_json("me", "req", &format!("{}/me", server.url())),
+	);
+
+	send_request(dir.path(), "login/req")
+		.arg("--hide-content")
+		.assert()
+		.success();
+
+	assert!(dir.path().join("squrl.cookies.json").exists());
+
+	cookies(dir.path(), &["list"])
+		.success()
+		.stdout(predicate::str::contains("session_id=s3cr3t"));
+
+	// A new process sends the cookie received by the previous one
+	send_request(dir.path(), "me/req")
+		.arg("--hide-content")
+		.assert()
+		.success();
+
+	me_mock.assert();
+}
+
+#[test]
+fn test_per_collection_cookie_jars() {
+	let mut server = mockito::Server::new();
+	server
+		.mock("GET", "/login")
+		.with_status(200)
+		.with_header("set-cookie", "session_id=s3cr3t; Path=/; Max-Age=3600")
+		.create();
+
+	let dir = temp_dir();
+	fs::write(
+		dir.path().join("squrl.toml"),
+		"per_collection_cookie_jars = true\n",
+	)
+	.unwrap();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", &format!("{}/login", server.url())),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.arg("--hide-content")
+		.assert()
+		.success();
+
+	assert!(dir.path().join("cookies").join("my-api.json").exists());
+	assert!(!dir.path().join("squrl.cookies.json").exists());
+
+	cookies(dir.path(), &["--collection", "my-api", "list"])
+		.success()
+		.stdout(predicate::str::contains("session_id=s3cr3t"));
+
+	cookies(dir.path(), &["list"])
+		.success()
+		.stdout(predicate::str::contains("session_id").not());
+}
+
+// ── Import / export / clear ───────────────────────────────────
+
+#[test]
+fn test_cookies_import_export_and_clear() {
+	let dir = temp_dir();
+	let cookies_file = dir.path().join("cookies.txt");
+	fs::write(&cookies_file, NETSCAPE_COOKIES).unwrap();
+
+	cookies(dir.path(), &["import", cookies_file.to_str().unwrap()])
+		.success()
+		.stdout(predicate::str::contains("2 cookie(s) imported"));
+
+	cookies(dir.path(), &["list"])
+		.success()
+		.stdout(predicate::str::contains("example.com\t/\tsession=abc"))
+		.stdout(predicate::str::contains("api.example.com\t/v1\ttoken=xyz"));
+
+	let exported_file = dir.path().join("exported.txt");
+	cookies(dir.path(), &["export", exported_file.to_str().unwrap()]).success();
+
+	let exported = fs::read_to_string(&exported_file).unwrap();
+	assert!(exported.starts_with("# Netscape HTTP Cookie File"));
+	assert!(exported.contains(".example.com\tTRUE\t/\tTRUE\t"));
+	assert!(exported.contains("api.example.com\tFALSE\t/v1\tFALSE\t0\ttoken\txyz"));
+
+	cookies(dir.path(), &["clear"]).success();
+
+	cookies(dir.path(), &["export"])
+		.success()
+		.stdout(predicate::str::contains("token").not());
+}
+
+#[test]
+fn test_cookies_import_invalid_file() {
+	let dir = temp_dir();
+	let cookies_file = dir.path().join("cookies.txt");
+	fs::write(&cookies_file, "example.com\tFALSE\t/\n").unwrap();
+
+	cookies(dir.path(), &["import", cookies_file.to_str().unwrap()])
+		.failure()
+		.stderr(predicate::str::contains("Line 1"));
+}
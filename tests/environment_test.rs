@@ -1,8 +1,11 @@
 mod helpers;
 
-use helpers::{seed_environment, squrl, temp_dir};
+use helpers::{
+	collection_with_request, seed_collection, seed_environment, send_request, squrl, temp_dir,
+};
 use predicates::prelude::*;
 use std::fs;
+use std::path::Path;
 
 #[test]
 fn test_env_info() {
@@ -297,3 +300,119 @@ fn test_env_dry_run_does_not_write() {
 	assert!(content.contains("original"));
 	assert!(!content.contains("modified"));
 }
+
+/// A "staging" environment inheriting from "base", with local overrides
+fn seed_layered_environments(dir: &Path) {
+	seed_environment(
+		dir,
+		"base",
+		"HOST=api.example.com\nBASE=https://{{HOST}}/api\nTIMEOUT=30\n",
+	);
+	seed_environment(
+		dir,
+		"staging",
+		"# @extends base\nHOST=staging.example.com\nTIMEOUT={{TIMEOUT}}0\n",
+	);
+	fs::write(dir.join(".env.local"), "USER=me\n").unwrap();
+	fs::write(dir.join(".env.staging.local"), "USER=tester\n").unwrap();
+}
+
+#[test]
+fn test_env_resolve_layers() {
+	let dir = temp_dir();
+	seed_layered_environments(dir.path());
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"env",
+			"resolve",
+			"staging",
+		])
+		.assert()
+		.success()
+		.stdout(
+			"HOST: staging.example.com (.env.staging)\n\
+			BASE: https://staging.example.com/api (.env.base)\n\
+			TIMEOUT: 300 (.env.staging)\n\
+			USER: tester (.env.staging.local)\n",
+		);
+
+	// Local files are not environments
+	squrl()
+		.args(["-d", dir.path().to_str().unwrap(), "env", "info", "local"])
+		.assert()
+		.failure();
+
+	squrl()
+		.args(["-d", dir.path().to_str().unwrap(), "env", "info", "staging"])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("parent: base"));
+}
+
+#[test]
+fn test_send_uses_inherited_values() {
+	let mut server = mockito::Server::new();
+	let mock = server
+		.mock("GET", "/api/users")
+		.match_header("x-user", "tester")
+		.create();
+
+	let dir = temp_dir();
+	seed_layered_environments(dir.path());
+	// The parent URL points to the mock server
+	seed_environment(dir.path(), "base", &format!("BASE={}/api\n", server.url()));
+
+	let collection = collection_with_request("{{BASE}}/users", |collection| {
+		collection["requests"][0]["headers"] =
+			serde_json::json!([{"enabled": true, "data": ["x-user", "{{USER}}"]}]);
+	});
+	seed_collection(dir.path(), "my-api", &collection);
+
+	send_request(dir.path(), "my-api/req")
+		.args(["--env", "staging"])
+		.assert()
+		.success();
+
+	mock.assert();
+}
+
+#[test]
+fn test_env_parent_commands() {
+	let dir = temp_dir();
+	seed_environment(dir.path(), "base", "HOST=localhost\n");
+	seed_environment(dir.path(), "dev", "PORT=8080\n");
+
+	let run = |args: &[&str]| {
+		squrl()
+			.args(["-d", dir.path().to_str().unwrap(), "env", "parent"])
+			.args(args)
+			.assert()
+	};
+
+	run(&["dev", "set", "base"]).success();
+	run(&["dev", "get"]).success().stdout("base\n");
+	assert_eq!(
+		fs::read_to_string(dir.path().join(".env.dev")).unwrap(),
+		"# @extends base\nPORT=8080"
+	);
+
+	// Inheritance loops are refused
+	run(&["base", "set", "dev"])
+		.failure()
+		.stderr(predicate::str::contains(
+			"Environment \"base\" cannot inherit from itself",
+		));
+	run(&["dev", "set", "unknown"])
+		.failure()
+		.stderr(predicate::str::contains("Environment not found"));
+
+	run(&["dev", "remove"]).success();
+	run(&["dev", "get"]).success().stdout("");
+	assert_eq!(
+		fs::read_to_string(dir.path().join(".env.dev")).unwrap(),
+		"PORT=8080"
+	);
+}
@@ -0,0 +1,81 @@
+mod helpers;
+
+use helpers::{
+	collection_with_request, minimal_collection_json, seed_collection, send_request, squrl,
+	temp_dir,
+};
+use mockito::Matcher;
+use predicates::prelude::*;
+use serde_json::Value;
+
+#[test]
+fn test_send_prints_server_sent_events() {
+	let mut server = mockito::Server::new();
+	server
+		.mock("GET", "/")
+		.match_header("last-event-id", Matcher::Missing)
+		.with_status(200)
+		.with_header("content-type", "text/event-stream")
+		.with_body("retry: 10\n\n: comment\nevent: price\nid: 42\ndata: {\"value\": 1}\n\n")
+		.create();
+	let reconnection_mock = server
+		.mock("GET", "/")
+		.match_header("last-event-id", "42")
+		.with_status(204)
+		.create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", &server.url()),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.timeout(std::time::Duration::from_secs(10))
+		.assert()
+		.success()
+		.stdout(predicate::str::contains(
+			"New Event message from Server ===\nevent: price\nid: 42\n{\"value\": 1}",
+		))
+		.stdout(predicate::str::contains(
+			"New Close message from Server ===\nStream ended, reconnection answered 204 No Content",
+		))
+		.stdout(predicate::str::contains(": comment").not());
+
+	reconnection_mock.assert();
+}
+
+#[test]
+fn test_collection_send_reads_event_streams_for_the_request_timeout() {
+	let mut server = mockito::Server::new();
+	let mock = server
+		.mock("GET", "/")
+		.with_status(200)
+		.with_header("content-type", "text/event-stream")
+		.with_body("retry: 100\ndata: tick\n\n")
+		.expect_at_least(2)
+		.create();
+
+	let dir = temp_dir();
+	let collection = collection_with_request(&server.url(), |collection| {
+		collection["requests"][0]["settings"]["timeout"] = Value::from(1000);
+	});
+	seed_collection(dir.path(), "my-api", &collection);
+
+	// The stream is reconnected until the timeout, then the run goes on
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"my-api",
+		])
+		.timeout(std::time::Duration::from_secs(10))
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("1 passed, 0 failed, 0 skipped"));
+
+	mock.assert();
+}
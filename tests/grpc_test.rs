@@ -0,0 +1,388 @@
+mod helpers;
+
+use helpers::grpc_server::{GREETER_SERVICE, GrpcTestServer, Reflection, write_protos};
+use helpers::*;
+use predicates::prelude::*;
+use serde_json::Value;
+
+/// Turn the request into a call of a greeter service method, with the given proto file or with
+/// server reflection when it is empty
+fn grpc_call<'a>(
+	proto_file: &'a str,
+	method: &'a str,
+	message: &'a str,
+) -> impl FnOnce(&mut Value) + 'a {
+	move |collection| {
+		let request = &mut collection["requests"][0];
+		request["headers"] = serde_json::json!([]);
+		request["settings"]["timeout"] = Value::from(5000);
+		request["protocol"] = serde_json::json!({
+			"type": "grpc",
+			"proto_file": proto_file,
+			"import_paths": [],
+			"service": GREETER_SERVICE,
+			"method": method,
+			"message": message,
+			"reflection": proto_file.is_empty()
+		});
+	}
+}
+
+#[test]
+fn test_unary_call_with_reflection() {
+	let server = GrpcTestServer::start(Reflection::V1);
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(
+			&server.url(),
+			grpc_call("", "SayHello", r#"{"name": "world"}"#),
+		),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("Hello world"));
+}
+
+#[test]
+fn test_reflection_falls_back_to_v1alpha() {
+	let server = GrpcTestServer::start(Reflection::V1Alpha);
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(
+			&server.url(),
+			grpc_call("", "SayHello", r#"{"name": "alpha"}"#),
+		),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("Hello alpha"));
+}
+
+#[test]
+fn test_server_streaming_call_with_reflection() {
+	let server = GrpcTestServer::start(Reflection::V1);
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(
+			&server.url(),
+			grpc_call("", "StreamHellos", r#"{"name": "stream"}"#),
+		),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("Hello stream #1"))
+		.stdout(predicate::str::contains("Hello stream #3"));
+}
+
+#[test]
+fn test_client_streaming_call_reads_stdin() {
+	let server = GrpcTestServer::start(Reflection::V1);
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&server.url(), grpc_call("", "CollectHellos", "")),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.write_stdin("{\"name\": \"ann\"}\n{\"name\": \"bob\"}\n")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("Hello ann, bob"));
+}
+
+#[test]
+fn test_unary_call_with_proto_file() {
+	let server = GrpcTestServer::start(Reflection::None);
+	let dir = temp_dir();
+	let proto_file = write_protos(dir.path());
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(
+			&server.url(),
+			grpc_call(
+				proto_file.to_str().unwrap(),
+				"SayHello",
+				r#"{"name": "proto"}"#,
+			),
+		),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("Hello proto"));
+}
+
+#[test]
+fn test_server_streaming_call_with_proto_file() {
+	let server = GrpcTestServer::start(Reflection::None);
+	let dir = temp_dir();
+	let proto_file = write_protos(dir.path());
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(
+			&server.url(),
+			grpc_call(
+				proto_file.to_str().unwrap(),
+				"StreamHellos",
+				r#"{"name": "proto"}"#,
+			),
+		),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("Hello proto #1"))
+		.stdout(predicate::str::contains("Hello proto #2"))
+		.stdout(predicate::str::contains("Hello proto #3"));
+}
+
+#[test]
+fn test_client_streaming_call_with_proto_file() {
+	let server = GrpcTestServer::start(Reflection::None);
+	let dir = temp_dir();
+	let proto_file = write_protos(dir.path());
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(
+			&server.url(),
+			grpc_call(proto_file.to_str().unwrap(), "CollectHellos", ""),
+		),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.write_stdin("{\"name\": \"ann\"}\n{\"name\": \"bob\"}\n{\"name\": \"cid\"}\n")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("Hello ann, bob, cid"));
+}
+
+#[test]
+fn test_bidi_streaming_call_replies_to_each_message() {
+	let server = GrpcTestServer::start(Reflection::V1);
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&server.url(), grpc_call("", "ChatHellos", "")),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.write_stdin("{\"name\": \"ann\"}\n{\"name\": \"bob\"}\n")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("Hi ann"))
+		.stdout(predicate::str::contains("Hi bob"));
+}
+
+#[test]
+fn test_reflection_unavailable() {
+	let server = GrpcTestServer::start(Reflection::None);
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(
+			&server.url(),
+			grpc_call("", "SayHello", r#"{"name": "world"}"#),
+		),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.stdout(predicate::str::contains(
+			"Server reflection is not available",
+		));
+}
+
+#[test]
+fn test_failed_call_reports_status_and_trailers() {
+	let server = GrpcTestServer::start(Reflection::V1);
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(
+			&server.url(),
+			grpc_call("", "SayHello", r#"{"name": "missing"}"#),
+		),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.args(["--status-code", "--headers"])
+		.assert()
+		.failure()
+		.stderr(predicate::str::contains(
+			"Unexpected gRPC status: NOT_FOUND (5): user missing not found",
+		))
+		.stdout(predicate::str::contains(
+			"200 OK (gRPC NOT_FOUND (5): user missing not found)",
+		))
+		.stdout(predicate::str::contains("(\"x-trace-id\", \"trace-42\")"))
+		.stdout(predicate::str::contains("\"status\": \"NOT_FOUND\""))
+		.stdout(predicate::str::contains("\"reason\": \"USER_NOT_FOUND\""))
+		.stdout(predicate::str::contains(
+			"\"@type\": \"type.googleapis.com/google.rpc.ErrorInfo\"",
+		));
+}
+
+#[test]
+fn test_grpc_status_assertion_replaces_the_default_check() {
+	let server = GrpcTestServer::start(Reflection::V1);
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(
+			&server.url(),
+			grpc_call("", "SayHello", r#"{"name": "missing"}"#),
+		),
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"assertions",
+			"my-api/req",
+			"add",
+			"grpc-status == NOT_FOUND",
+		])
+		.assert()
+		.success();
+
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("PASS grpc-status == NOT_FOUND"));
+}
+
+#[test]
+fn test_collection_send_fails_a_failed_call() {
+	let server = GrpcTestServer::start(Reflection::V1);
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(
+			&server.url(),
+			grpc_call("", "SayHello", r#"{"name": "missing"}"#),
+		),
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"my-api",
+		])
+		.assert()
+		.failure()
+		.stdout(predicate::str::contains("FAIL req"))
+		.stdout(predicate::str::contains(
+			"Unexpected gRPC status: NOT_FOUND (5)",
+		));
+}
+
+#[test]
+fn test_successful_call_reports_ok_status() {
+	let server = GrpcTestServer::start(Reflection::V1);
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(
+			&server.url(),
+			grpc_call("", "SayHello", r#"{"name": "world"}"#),
+		),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.arg("--status-code")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("200 OK (gRPC OK (0))"));
+}
+
+#[test]
+fn test_unknown_service_reports_descriptors_error() {
+	let server = GrpcTestServer::start(Reflection::V1);
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&server.url(), grpc_call("", "Unknown", "{}")),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.arg("--status-code")
+		.assert()
+		.stdout(predicate::str::contains("COULD NOT LOAD gRPC DESCRIPTORS"))
+		.stdout(predicate::str::contains(
+			"Method 'Unknown' not found in service 'test.greeter.Greeter'",
+		));
+}
+
+#[test]
+fn test_unary_call_sends_deadline() {
+	let server = GrpcTestServer::start(Reflection::V1);
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(
+			&server.url(),
+			grpc_call("", "SayHello", r#"{"name": "world"}"#),
+		),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.arg("--headers")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains(
+			r#""x-received-grpc-timeout", "5000m""#,
+		));
+}
+
+#[test]
+fn test_gzip_compressed_calls() {
+	let server = GrpcTestServer::start(Reflection::V1);
+	let dir = temp_dir();
+
+	for (method, message, expected) in [
+		("SayHello", r#"{"name": "world"}"#, "Hello world"),
+		("StreamHellos", r#"{"name": "world"}"#, "Hello world #3"),
+	] {
+		let collection = collection_with_request(&server.url(), |collection| {
+			grpc_call("", method, message)(collection);
+			collection["requests"][0]["protocol"]["compression"] = "gzip".into();
+		});
+		seed_collection(dir.path(), "my-api", &collection);
+
+		send_request(dir.path(), "my-api/req")
+			.assert()
+			.success()
+			.stdout(predicate::str::contains(expected));
+	}
+}
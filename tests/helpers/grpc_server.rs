@@ -0,0 +1,449 @@
+//! Minimal HTTP/2 gRPC server implementing a greeter service and the server reflection service.
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::SocketAddr;
+use std::path::Path;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use prost::Message;
+use prost_types::FileDescriptorProto;
+
+const COMMON_PROTO: &str = r#"
+syntax = "proto3";
+package test.common;
+
+message HelloReply {
+	string message = 1;
+}
+"#;
+
+const GREETER_PROTO: &str = r#"
+syntax = "proto3";
+package test.greeter;
+
+import "common.proto";
+
+message HelloRequest {
+	string name = 1;
+}
+
+service Greeter {
+	rpc SayHello (HelloRequest) returns (test.common.HelloReply);
+	rpc StreamHellos (HelloRequest) returns (stream test.common.HelloReply);
+	rpc CollectHellos (stream HelloRequest) returns (test.common.HelloReply);
+	rpc ChatHellos (stream HelloRequest) returns (stream test.common.HelloReply);
+}
+"#;
+
+pub const GREETER_SERVICE: &str = "test.greeter.Greeter";
+
+#[derive(Clone, PartialEq, Message)]
+struct HelloRequest {
+	#[prost(string, tag = "1")]
+	name: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct HelloReply {
+	#[prost(string, tag = "1")]
+	message: String,
+}
+
+/// `google.rpc.Status`
+#[derive(Clone, PartialEq, Message)]
+struct RpcStatus {
+	#[prost(int32, tag = "1")]
+	code: i32,
+	#[prost(string, tag = "2")]
+	message: String,
+	#[prost(message, repeated, tag = "3")]
+	details: Vec<prost_types::Any>,
+}
+
+/// `google.rpc.ErrorInfo`
+#[derive(Clone, PartialEq, Message)]
+struct ErrorInfo {
+	#[prost(string, tag = "1")]
+	reason: String,
+	#[prost(string, tag = "2")]
+	domain: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ServerReflectionRequest {
+	#[prost(oneof = "MessageRequest", tags = "3, 4, 7")]
+	message_request: Option<MessageRequest>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+enum MessageRequest {
+	#[prost(string, tag = "3")]
+	FileByFilename(String),
+	#[prost(string, tag = "4")]
+	FileContainingSymbol(String),
+	#[prost(string, tag = "7")]
+	ListServices(String),
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ServerReflectionResponse {
+	#[prost(oneof = "MessageResponse", tags = "4, 6, 7")]
+	message_response: Option<MessageResponse>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+enum MessageResponse {
+	#[prost(message, tag = "4")]
+	FileDescriptors(FileDescriptorResponse),
+	#[prost(message, tag = "6")]
+	Services(ListServiceResponse),
+	#[prost(message, tag = "7")]
+	Error(ErrorResponse),
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct FileDescriptorResponse {
+	#[prost(bytes = "vec", repeated, tag = "1")]
+	file_descriptor_proto: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ListServiceResponse {
+	#[prost(message, repeated, tag = "1")]
+	service: Vec<ServiceResponse>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ServiceResponse {
+	#[prost(string, tag = "1")]
+	name: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ErrorResponse {
+	#[prost(int32, tag = "1")]
+	error_code: i32,
+	#[prost(string, tag = "2")]
+	error_message: String,
+}
+
+/// Which reflection service versions the server exposes
+#[derive(Clone, Copy)]
+pub enum Reflection {
+	None,
+	V1,
+	V1Alpha,
+}
+
+pub struct GrpcTestServer {
+	pub addr: SocketAddr,
+}
+
+impl GrpcTestServer {
+	/// Start the server on a random port, it runs until the test process exits
+	pub fn start(reflection: Reflection) -> GrpcTestServer {
+		let files = compile_protos();
+		let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("should bind");
+		let addr = listener.local_addr().unwrap();
+		listener.set_nonblocking(true).unwrap();
+
+		std::thread::spawn(move || {
+			let runtime = tokio::runtime::Builder::new_current_thread()
+				.enable_all()
+				.build()
+				.unwrap();
+
+			runtime.block_on(async move {
+				let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+
+				loop {
+					let Ok((socket, _)) = listener.accept().await else {
+						continue;
+					};
+					let files = files.clone();
+
+					tokio::spawn(async move {
+						let Ok(mut connection) = h2::server::handshake(socket).await else {
+							return;
+						};
+
+						while let Some(Ok((request, respond))) = connection.accept().await {
+							let files = files.clone();
+							tokio::spawn(handle_call(request, respond, files, reflection));
+						}
+					});
+				}
+			});
+		});
+
+		GrpcTestServer { addr }
+	}
+
+	pub fn url(&self) -> String {
+		format!("http://{}", self.addr)
+	}
+}
+
+/// Write the test protos in `dir`, returns the path of the greeter proto
+pub fn write_protos(dir: &Path) -> std::path::PathBuf {
+	std::fs::write(dir.join("common.proto"), COMMON_PROTO).unwrap();
+	std::fs::write(dir.join("greeter.proto"), GREETER_PROTO).unwrap();
+	dir.join("greeter.proto")
+}
+
+/// Encoded file descriptors by file name
+fn compile_protos() -> HashMap<String, Vec<u8>> {
+	let dir = tempfile::tempdir().unwrap();
+	write_protos(dir.path());
+
+	protox::Compiler::new([dir.path()])
+		.unwrap()
+		.include_imports(true)
+		.open_file("greeter.proto")
+		.unwrap()
+		.file_descriptor_set()
+		.file
+		.into_iter()
+		.map(|file: FileDescriptorProto| (file.name().to_string(), file.encode_to_vec()))
+		.collect()
+}
+
+fn frame(message: &impl Message) -> Bytes {
+	let encoded = message.encode_to_vec();
+	let mut frame = BytesMut::with_capacity(5 + encoded.len());
+	frame.put_u8(0);
+	frame.put_u32(encoded.len() as u32);
+	frame.put_slice(&encoded);
+	frame.freeze()
+}
+
+/// Compress the message of an uncompressed frame with gzip
+fn gzip_frame(frame: Bytes) -> Bytes {
+	let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+	encoder.write_all(&frame[5..]).unwrap();
+	let compressed = encoder.finish().unwrap();
+
+	let mut gzipped = BytesMut::with_capacity(5 + compressed.len());
+	gzipped.put_u8(1);
+	gzipped.put_u32(compressed.len() as u32);
+	gzipped.put_slice(&compressed);
+	gzipped.freeze()
+}
+
+fn split_frames(mut data: Bytes) -> Vec<Bytes> {
+	let mut messages = vec![];
+
+	while data.len() >= 5 {
+		let compressed = data.get_u8() == 1;
+		let length = data.get_u32() as usize;
+		let message = data.split_to(length);
+
+		if compressed {
+			let mut decompressed = vec![];
+			flate2::read::GzDecoder::new(message.as_ref())
+				.read_to_end(&mut decompressed)
+				.unwrap();
+			messages.push(Bytes::from(decompressed));
+		} else {
+			messages.push(message);
+		}
+	}
+
+	messages
+}
+
+async fn handle_call(
+	request: http::Request<h2::RecvStream>,
+	mut respond: h2::server::SendResponse<Bytes>,
+	files: HashMap<String, Vec<u8>>,
+	reflection: Reflection,
+) {
+	let path = request.uri().path().to_string();
+	let gzip = request
+		.headers()
+		.get("grpc-encoding")
+		.is_some_and(|encoding| encoding == "gzip");
+	let grpc_timeout = request.headers().get("grpc-timeout").cloned();
+	let mut body = request.into_body();
+	let mut data = BytesMut::new();
+
+	while let Some(Ok(chunk)) = body.data().await {
+		let _ = body.flow_control().release_capacity(chunk.len());
+		data.extend_from_slice(&chunk);
+	}
+
+	let requests = split_frames(data.freeze());
+	let mut trailers = http::HeaderMap::new();
+	trailers.insert("grpc-status", http::HeaderValue::from_static("0"));
+
+	// Echo the deadline so that tests can check it
+	if let Some(grpc_timeout) = grpc_timeout {
+		trailers.insert("x-received-grpc-timeout", grpc_timeout);
+	}
+
+	let replies: Vec<Bytes> = match (path.as_str(), reflection) {
+		("/test.greeter.Greeter/SayHello", _) => {
+			let request = HelloRequest::decode(requests[0].clone()).unwrap();
+
+			// Failed call, with a message, status details and custom trailing metadata
+			if request.name == "missing" {
+				let details = RpcStatus {
+					code: 5,
+					message: String::from("user missing not found"),
+					details: vec![prost_types::Any {
+						type_url: String::from("type.googleapis.com/google.rpc.ErrorInfo"),
+						value: ErrorInfo {
+							reason: String::from("USER_NOT_FOUND"),
+							domain: String::from("test.greeter"),
+						}
+						.encode_to_vec(),
+					}],
+				};
+				let details = base64::Engine::encode(
+					&base64::engine::general_purpose::STANDARD_NO_PAD,
+					details.encode_to_vec(),
+				);
+
+				trailers.insert("grpc-status", http::HeaderValue::from_static("5"));
+				trailers.insert(
+					"grpc-message",
+					http::HeaderValue::from_static("user%20missing%20not%20found"),
+				);
+				trailers.insert(
+					"grpc-status-details-bin",
+					http::HeaderValue::from_str(&details).unwrap(),
+				);
+				trailers.insert("x-trace-id", http::HeaderValue::from_static("trace-42"));
+
+				vec![]
+			} else {
+				vec![frame(&HelloReply {
+					message: format!("Hello {}", request.name),
+				})]
+			}
+		}
+		("/test.greeter.Greeter/StreamHellos", _) => {
+			let request = HelloRequest::decode(requests[0].clone()).unwrap();
+			(1..=3)
+				.map(|i| {
+					frame(&HelloReply {
+						message: format!("Hello {} #{i}", request.name),
+					})
+				})
+				.collect()
+		}
+		("/test.greeter.Greeter/CollectHellos", _) => {
+			let names: Vec<String> = requests
+				.into_iter()
+				.map(|request| HelloRequest::decode(request).unwrap().name)
+				.collect();
+			vec![frame(&HelloReply {
+				message: format!("Hello {}", names.join(", ")),
+			})]
+		}
+		// Replies once the request stream is closed, one reply per request
+		("/test.greeter.Greeter/ChatHellos", _) => requests
+			.into_iter()
+			.map(|request| {
+				frame(&HelloReply {
+					message: format!("Hi {}", HelloRequest::decode(request).unwrap().name),
+				})
+			})
+			.collect(),
+		("/grpc.reflection.v1.ServerReflection/ServerReflectionInfo", Reflection::V1)
+		| ("/grpc.reflection.v1alpha.ServerReflection/ServerReflectionInfo", Reflection::V1Alpha) => {
+			let service = path
+				.trim_start_matches('/')
+				.split('/')
+				.next()
+				.unwrap()
+				.to_string();
+
+			requests
+				.into_iter()
+				.map(|request| {
+					let request = ServerReflectionRequest::decode(request).unwrap();
+					frame(&reflect(request, &files, &service))
+				})
+				.collect()
+		}
+		_ => {
+			let response = http::Response::builder()
+				.status(200)
+				.header("content-type", "application/grpc")
+				.header("grpc-status", "12")
+				.body(())
+				.unwrap();
+			let _ = respond.send_response(response, true);
+			return;
+		}
+	};
+
+	// Reply with the encoding of the request
+	let (replies, encoding) = match gzip {
+		true => (replies.into_iter().map(gzip_frame).collect(), "gzip"),
+		false => (replies, "identity"),
+	};
+
+	let response = http::Response::builder()
+		.status(200)
+		.header("content-type", "application/grpc")
+		.header("grpc-encoding", encoding)
+		.body(())
+		.unwrap();
+
+	let Ok(mut send) = respond.send_response(response, false) else {
+		return;
+	};
+
+	for reply in replies {
+		let _ = send.send_data(reply, false);
+	}
+
+	let _ = send.send_trailers(trailers);
+}
+
+fn reflect(
+	request: ServerReflectionRequest,
+	files: &HashMap<String, Vec<u8>>,
+	reflection_service: &str,
+) -> ServerReflectionResponse {
+	let not_found = |what: String| {
+		MessageResponse::Error(ErrorResponse {
+			error_code: 5,
+			error_message: format!("{what} not found"),
+		})
+	};
+
+	let message_response = match request.message_request {
+		Some(MessageRequest::ListServices(_)) => MessageResponse::Services(ListServiceResponse {
+			service: [GREETER_SERVICE, reflection_service]
+				.into_iter()
+				.map(|name| ServiceResponse {
+					name: name.to_string(),
+				})
+				.collect(),
+		}),
+		// Only the file defining the symbol is sent, its imports have to be asked for by name
+		Some(MessageRequest::FileContainingSymbol(symbol)) => match symbol.as_str() {
+			GREETER_SERVICE => file_response(&files["greeter.proto"]),
+			_ => not_found(symbol),
+		},
+		Some(MessageRequest::FileByFilename(file_name)) => match files.get(&file_name) {
+			Some(file) => file_response(file),
+			None => not_found(file_name),
+		},
+		None => not_found(String::from("request")),
+	};
+
+	ServerReflectionResponse {
+		message_response: Some(message_response),
+	}
+}
+
+fn file_response(file: &[u8]) -> MessageResponse {
+	MessageResponse::FileDescriptors(FileDescriptorResponse {
+		file_descriptor_proto: vec![file.to_vec()],
+	})
+}
@@ -1,5 +1,8 @@
 #![allow(dead_code)]
 
+pub mod grpc_server;
+pub mod tls_server;
+
 use assert_cmd::Command;
 use std::fs;
 use std::path::Path;
@@ -24,6 +27,13 @@ pub fn seed_collection(dir: &Path, name: &str, json: &str) -> std::path::PathBuf
 	path
 }
 
+/// Read back a JSON collection file of a temp directory.
+pub fn read_collection(dir: &Path, name: &str) -> serde_json::Value {
+	let content =
+		fs::read_to_string(dir.join(format!("{name}.json"))).expect("should read collection file");
+	serde_json::from_str(&content).expect("collection file should be valid JSON")
+}
+
 /// Seed a temp directory with an environment file (.env.<name>).
 pub fn seed_environment(dir: &Path, name: &str, content: &str) -> std::path::PathBuf {
 	let path = dir.join(format!(".env.{name}"));
@@ -69,6 +79,23 @@ pub fn minimal_collection_json(collection_name: &str, request_name: &str, url: &
 	.to_string()
 }
 
+/// A "my-api" collection JSON whose single "req" request to `url` is customized by `edit`, which
+/// gets the whole collection.
+pub fn collection_with_request(url: &str, edit: impl FnOnce(&mut serde_json::Value)) -> String {
+	let mut collection: serde_json::Value =
+		serde_json::from_str(&minimal_collection_json("my-api", "req", url))
+			.expect("minimal collection should be valid JSON");
+	edit(&mut collection);
+	collection.to_string()
+}
+
+/// `squrl request send <path>` in a temp directory, further arguments can be added.
+pub fn send_request(dir: &Path, path: &str) -> Command {
+	let mut command = squrl();
+	command.args(["-d", dir.to_str().unwrap(), "request", "send", path]);
+	command
+}
+
 /// A collection JSON with multiple requests.
 pub fn multi_request_collection_json(collection_name: &str) -> String {
 	serde_json::json!({
@@ -137,6 +164,31 @@ pub fn multi_request_collection_json(collection_name: &str) -> String {
 	.to_string()
 }
 
+/// Mock a `{method} /login` request redirected with a session cookie to `/home`, itself redirected
+/// to `/final`. Both redirected requests expect the cookie.
+pub fn mock_redirect_chain(server: &mut mockito::ServerGuard, method: &str) -> Vec<mockito::Mock> {
+	vec![
+		server
+			.mock(method, "/login")
+			.with_status(302)
+			.with_header("location", "/home")
+			.with_header("set-cookie", "session=1; Path=/")
+			.create(),
+		server
+			.mock("GET", "/home")
+			.match_header("cookie", "session=1")
+			.with_status(301)
+			.with_header("location", "/final")
+			.create(),
+		server
+			.mock("GET", "/final")
+			.match_header("cookie", "session=1")
+			.with_status(200)
+			.with_body("final")
+			.create(),
+	]
+}
+
 /// A minimal .http file content with one request.
 pub fn minimal_http_file() -> &'static str {
 	"### Get Users\nGET https://httpbin.org/get\n\n### Post Data\nPOST https://httpbin.org/post\nContent-Type: application/json\n\n{\"name\": \"test\"}\n"
@@ -0,0 +1,161 @@
+//! Minimal HTTPS servers that only accept clients presenting a certificate signed by the test CA.
+//!
+//! The certificates in `tests/helpers/tls` were generated with openssl: `ca.pem` signs
+//! `server.pem` (for `localhost` and `127.0.0.1`) and `client.pem`, every key is a P-256 key.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::WebPkiClientVerifier;
+use rustls::{RootCertStore, ServerConfig, ServerConnection, StreamOwned};
+
+/// Path of a file of `tests/helpers/tls`
+pub fn tls_fixture(name: &str) -> String {
+	format!("{}/tests/helpers/tls/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+pub struct TlsTestServer {
+	pub port: u16,
+}
+
+impl TlsTestServer {
+	/// Answer `200 OK` with the `mtls ok` body to every client authenticated by its certificate
+	pub fn start() -> Self {
+		Self::start_with_body("text/plain", "mtls ok")
+	}
+
+	/// Answer `200 OK` with the given body to every client authenticated by its certificate
+	pub fn start_with_body(content_type: &'static str, body: &'static str) -> Self {
+		let config = Arc::new(server_config());
+
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let port = listener.local_addr().unwrap().port();
+
+		std::thread::spawn(move || {
+			for stream in listener.incoming().flatten() {
+				let connection = ServerConnection::new(config.clone()).unwrap();
+				let mut stream = StreamOwned::new(connection, stream);
+
+				// The handshake happens on the first read, clients without certificate fail here
+				let mut reader = BufReader::new(&mut stream);
+				let mut line = String::new();
+				let mut handshake_failed = false;
+
+				loop {
+					line.clear();
+					match reader.read_line(&mut line) {
+						Ok(0) | Err(_) => {
+							handshake_failed = true;
+							break;
+						}
+						Ok(_) if line == "\r\n" => break,
+						Ok(_) => {}
+					}
+				}
+
+				if handshake_failed {
+					continue;
+				}
+
+				let response = format!(
+					"HTTP/1.1 200 OK\r\ncontent-type: {content_type}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+					body.len()
+				);
+				let _ = stream.write_all(response.as_bytes());
+				stream.conn.send_close_notify();
+				let _ = stream.flush();
+			}
+		});
+
+		TlsTestServer { port }
+	}
+
+	/// Answer `200 OK` with the `h2 ok` body to the HTTP/2 requests of every client authenticated
+	/// by its certificate, several requests sharing a connection
+	pub fn start_http2() -> Self {
+		let mut config = server_config();
+		config.alpn_protocols = vec![b"h2".to_vec()];
+		let acceptor = tokio_rustls::TlsAcceptor::from(Arc::new(config));
+
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let port = listener.local_addr().unwrap().port();
+		listener.set_nonblocking(true).unwrap();
+
+		std::thread::spawn(move || {
+			let runtime = tokio::runtime::Builder::new_current_thread()
+				.enable_all()
+				.build()
+				.unwrap();
+
+			runtime.block_on(async move {
+				let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+
+				loop {
+					let Ok((socket, _)) = listener.accept().await else {
+						continue;
+					};
+					let acceptor = acceptor.clone();
+
+					tokio::spawn(async move {
+						let Ok(stream) = acceptor.accept(socket).await else {
+							return;
+						};
+						let Ok(mut connection) = h2::server::handshake(stream).await else {
+							return;
+						};
+
+						while let Some(Ok((_, mut respond))) = connection.accept().await {
+							let response = http::Response::builder()
+								.header("content-type", "text/plain")
+								.body(())
+								.unwrap();
+
+							if let Ok(mut body) = respond.send_response(response, false) {
+								let _ = body.send_data(Bytes::from_static(b"h2 ok"), true);
+							}
+						}
+					});
+				}
+			});
+		});
+
+		TlsTestServer { port }
+	}
+
+	pub fn url(&self) -> String {
+		format!("https://localhost:{}", self.port)
+	}
+}
+
+/// Server presenting `server.pem` and the CA, requiring a client certificate signed by the CA
+fn server_config() -> ServerConfig {
+	let provider = Arc::new(rustls::crypto::aws_lc_rs::default_provider());
+
+	let mut client_roots = RootCertStore::empty();
+	client_roots
+		.add(CertificateDer::from_pem_file(tls_fixture("ca.pem")).unwrap())
+		.unwrap();
+	let client_verifier =
+		WebPkiClientVerifier::builder_with_provider(Arc::new(client_roots), provider.clone())
+			.build()
+			.unwrap();
+
+	// The CA is sent as the rest of the chain, like servers sending their intermediates
+	let certificates = ["server.pem", "ca.pem"]
+		.into_iter()
+		.flat_map(|name| CertificateDer::pem_file_iter(tls_fixture(name)).unwrap())
+		.map(Result::unwrap)
+		.collect();
+	let key = PrivateKeyDer::from_pem_file(tls_fixture("server.key")).unwrap();
+
+	ServerConfig::builder_with_provider(provider)
+		.with_safe_default_protocol_versions()
+		.unwrap()
+		.with_client_cert_verifier(client_verifier)
+		.with_single_cert(certificates, key)
+		.unwrap()
+}
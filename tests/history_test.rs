@@ -0,0 +1,423 @@
+mod helpers;
+
+use helpers::{
+	collection_with_request, minimal_collection_json, seed_collection, seed_environment,
+	send_request, squrl, temp_dir,
+};
+use predicates::prelude::*;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+/// Ids listed by `history list`, newest first
+fn history_ids(dir: &Path) -> Vec<String> {
+	let output = squrl()
+		.args(["-d", dir.to_str().unwrap(), "history", "list"])
+		.output()
+		.unwrap();
+
+	String::from_utf8(output.stdout)
+		.unwrap()
+		.lines()
+		.map(|line| line.split_whitespace().next().unwrap().to_string())
+		.collect()
+}
+
+/// A collection with one request whose URL and header use `{{ID}}`
+fn seed_items_collection(dir: &Path, server_url: &str) {
+	let collection =
+		collection_with_request(&format!("{server_url}/items/{{{{ID}}}}"), |collection| {
+			collection["requests"][0]["headers"] = serde_json::json!([
+				{"enabled": true, "data": ["x-item", "{{ID}}"]}
+			]);
+		});
+
+	seed_collection(dir, "my-api", &collection);
+}
+
+// ── Recording ─────────────────────────────────────────────────
+
+#[test]
+fn test_sent_requests_are_recorded_resolved() {
+	let mut server = mockito::Server::new();
+	server
+		.mock("GET", "/items/1")
+		.with_status(201)
+		.with_header("x-answer", "yes")
+		.with_body("created")
+		.create();
+
+	let dir = temp_dir();
+	seed_environment(dir.path(), "dev", "ID=1\n");
+	seed_items_collection(dir.path(), &server.url());
+
+	send_request(dir.path(), "my-api/req")
+		.args(["--hide-content", "--env", "dev"])
+		.assert()
+		.success();
+
+	let history = fs::read_to_string(dir.path().join("squrl.history.jsonl")).unwrap();
+	assert_eq!(history.lines().count(), 1);
+
+	let entry: Value = serde_json::from_str(history.lines().next().unwrap()).unwrap();
+	assert_eq!(entry["collection"], "my-api");
+	assert_eq!(entry["request"], "req");
+	assert_eq!(entry["sent"]["method"], "GET");
+	assert_eq!(entry["sent"]["url"], format!("{}/items/1", server.url()));
+	assert_eq!(
+		entry["sent"]["headers"][0],
+		serde_json::json!(["x-item", "1"])
+	);
+	assert_eq!(entry["response"]["body_size"], 7);
+
+	let ids = history_ids(dir.path());
+	assert_eq!(ids.len(), 1);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"history",
+			"show",
+			&ids[0],
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("request: my-api/req"))
+		.stdout(predicate::str::contains("> x-item: 1"))
+		.stdout(predicate::str::contains("status: 201 Created"))
+		.stdout(predicate::str::contains("< x-answer: yes"));
+}
+
+#[test]
+fn test_binary_body_size_is_recorded() {
+	let mut server = mockito::Server::new();
+	server
+		.mock("GET", "/")
+		.with_status(200)
+		.with_header("content-type", "application/octet-stream")
+		.with_body([0x00, 0x9f, 0x92, 0x41])
+		.create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", &server.url()),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.arg("--hide-content")
+		.assert()
+		.success();
+
+	// The size of the received bytes, not of their hexdump
+	let history = fs::read_to_string(dir.path().join("squrl.history.jsonl")).unwrap();
+	let entry: Value = serde_json::from_str(history.lines().next().unwrap()).unwrap();
+	assert_eq!(entry["response"]["body_size"], 4);
+}
+
+#[test]
+fn test_history_can_be_disabled() {
+	let mut server = mockito::Server::new();
+	server.mock("GET", "/").with_status(200).create();
+
+	let dir = temp_dir();
+	fs::write(
+		dir.path().join("squrl.toml"),
+		"disable_request_history = true\n",
+	)
+	.unwrap();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", &server.url()),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.arg("--hide-content")
+		.assert()
+		.success();
+
+	assert!(!dir.path().join("squrl.history.jsonl").exists());
+}
+
+/// A collection with one request using a bearer token and a cookie header
+fn seed_authenticated_collection(dir: &Path, server_url: &str, token: &str) {
+	let collection = collection_with_request(&format!("{server_url}/me"), |collection| {
+		collection["requests"][0]["auth"] = serde_json::json!({"bearer_token": {"token": token}});
+		collection["requests"][0]["headers"] = serde_json::json!([
+			{"enabled": true, "data": ["cookie", "session=abc"]},
+			{"enabled": true, "data": ["x-other", "visible"]}
+		]);
+	});
+
+	seed_collection(dir, "my-api", &collection);
+}
+
+/// Value of a sent header of the only history entry
+fn recorded_header(dir: &Path, header_name: &str) -> Value {
+	let history = fs::read_to_string(dir.join("squrl.history.jsonl")).unwrap();
+	let entry: Value = serde_json::from_str(history.lines().next().unwrap()).unwrap();
+
+	entry["sent"]["headers"]
+		.as_array()
+		.unwrap()
+		.iter()
+		.find(|header| header[0] == header_name)
+		.map(|header| header[1].clone())
+		.unwrap_or(Value::Null)
+}
+
+#[test]
+fn test_credential_headers_are_redacted() {
+	let mut server = mockito::Server::new();
+	server.mock("GET", "/me").with_status(200).create();
+
+	let dir = temp_dir();
+	seed_authenticated_collection(dir.path(), &server.url(), "secret-token");
+
+	send_request(dir.path(), "my-api/req")
+		.arg("--hide-content")
+		.assert()
+		.success();
+
+	assert_eq!(recorded_header(dir.path(), "authorization"), "********");
+	assert_eq!(recorded_header(dir.path(), "cookie"), "********");
+	assert_eq!(recorded_header(dir.path(), "x-other"), "visible");
+}
+
+#[test]
+fn test_credential_headers_can_be_recorded() {
+	let mut server = mockito::Server::new();
+	server.mock("GET", "/me").with_status(200).create();
+
+	let dir = temp_dir();
+	fs::write(
+		dir.path().join("squrl.toml"),
+		"record_credential_headers = true\n",
+	)
+	.unwrap();
+	seed_authenticated_collection(dir.path(), &server.url(), "secret-token");
+
+	send_request(dir.path(), "my-api/req")
+		.arg("--hide-content")
+		.assert()
+		.success();
+
+	assert_eq!(
+		recorded_header(dir.path(), "authorization"),
+		"Bearer secret-token"
+	);
+	assert_eq!(recorded_header(dir.path(), "cookie"), "session=abc");
+}
+
+// ── Replay ────────────────────────────────────────────────────
+
+#[test]
+fn test_history_replay_sends_the_recorded_request() {
+	let mut server = mockito::Server::new();
+	let mock = server
+		.mock("GET", "/items/1")
+		.match_header("x-item", "1")
+		.with_status(200)
+		.expect(2)
+		.create();
+
+	let dir = temp_dir();
+	seed_environment(dir.path(), "dev", "ID=1\n");
+	seed_items_collection(dir.path(), &server.url());
+
+	send_request(dir.path(), "my-api/req")
+		.args(["--hide-content", "--env", "dev"])
+		.assert()
+		.success();
+
+	// The environment changed since, the replay still uses the recorded values
+	fs::write(dir.path().join(".env.dev"), "ID=2\n").unwrap();
+
+	let ids = history_ids(dir.path());
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"history",
+			"replay",
+			&ids[0],
+			"--status-code",
+			"--hide-content",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("200 OK"));
+
+	mock.assert();
+
+	squrl()
+		.args(["-d", dir.path().to_str().unwrap(), "history", "list"])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("my-api/req").count(2));
+}
+
+#[test]
+fn test_history_replay_resolves_the_auth_again() {
+	let mut server = mockito::Server::new();
+	server
+		.mock("GET", "/me")
+		.match_header("authorization", "Bearer old-token")
+		.with_status(200)
+		.create();
+	let replay_mock = server
+		.mock("GET", "/me")
+		.match_header("authorization", "Bearer new-token")
+		.match_header("cookie", "session=abc")
+		.match_header("x-other", "visible")
+		.with_status(200)
+		.create();
+
+	let dir = temp_dir();
+	seed_authenticated_collection(dir.path(), &server.url(), "old-token");
+
+	send_request(dir.path(), "my-api/req")
+		.arg("--hide-content")
+		.assert()
+		.success();
+
+	// The recorded credentials are redacted, the replay uses the current auth of the request
+	seed_authenticated_collection(dir.path(), &server.url(), "new-token");
+
+	let ids = history_ids(dir.path());
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"history",
+			"replay",
+			&ids[0],
+			"--hide-content",
+		])
+		.assert()
+		.success();
+
+	replay_mock.assert();
+}
+
+// ── Diff ──────────────────────────────────────────────────────
+
+#[test]
+fn test_history_diff_between_entries_of_the_same_request() {
+	let mut server = mockito::Server::new();
+	server.mock("GET", "/items/1").with_status(200).create();
+	server.mock("GET", "/items/2").with_status(404).create();
+
+	let dir = temp_dir();
+	seed_environment(dir.path(), "dev", "ID=1\n");
+	seed_environment(dir.path(), "prod", "ID=2\n");
+	seed_items_collection(dir.path(), &server.url());
+
+	send_request(dir.path(), "my-api/req")
+		.args(["--hide-content", "--env", "dev"])
+		.assert()
+		.success();
+	send_request(dir.path(), "my-api/req")
+		.args(["--hide-content", "--env", "prod"])
+		.assert()
+		.success();
+
+	let ids = history_ids(dir.path());
+	assert_eq!(ids.len(), 2);
+
+	// Without a second id, the entry is compared with the previous one of the same request
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"history",
+			"diff",
+			&ids[0],
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains(format!(
+			"- GET {}/items/1",
+			server.url()
+		)))
+		.stdout(predicate::str::contains(format!(
+			"+ GET {}/items/2",
+			server.url()
+		)))
+		.stdout(predicate::str::contains("- > x-item: 1"))
+		.stdout(predicate::str::contains("+ > x-item: 2"))
+		.stdout(predicate::str::contains("- status: 200 OK"))
+		.stdout(predicate::str::contains("+ status: 404 Not Found"));
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"history",
+			"diff",
+			&ids[1],
+			&ids[0],
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("+ status: 404 Not Found"));
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"history",
+			"diff",
+			&ids[1],
+		])
+		.assert()
+		.failure()
+		.stderr(predicate::str::contains("No earlier history entry"));
+}
+
+#[test]
+fn test_history_diff_rejects_different_requests() {
+	let mut server = mockito::Server::new();
+	server.mock("GET", "/").with_status(200).create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"first",
+		&minimal_collection_json("first", "req", &server.url()),
+	);
+	seed_collection(
+		dir.path(),
+		"second",
+		&minimal_collection_json("second", "req", &server.url()),
+	);
+
+	send_request(dir.path(), "first/req")
+		.arg("--hide-content")
+		.assert()
+		.success();
+	send_request(dir.path(), "second/req")
+		.arg("--hide-content")
+		.assert()
+		.success();
+
+	let ids = history_ids(dir.path());
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"history",
+			"diff",
+			&ids[0],
+			&ids[1],
+		])
+		.assert()
+		.failure()
+		.stderr(predicate::str::contains("not sent from the same request"));
+}
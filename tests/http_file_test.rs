@@ -0,0 +1,280 @@
+mod helpers;
+
+use helpers::{seed_environment, squrl, temp_dir};
+use predicates::prelude::*;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+/// A "login" request whose response handler stores a token used by the following "me" request,
+/// both requests building their URL from file variables
+const API_HTTP_FILE: &str = r#"@base = {{host}}/api
+@unused = value
+
+### Login
+# @name login
+POST {{base}}/login
+Content-Type: application/json
+
+< ./payloads/login.json
+
+> {%
+    client.test("logged in", () => client.assert(response.status === 200));
+    client.global.set("token", response.body.token);
+%}
+
+### me
+< {% request.variables.set("trace", "abc"); %}
+GET {{base}}/me
+Authorization: Bearer {{token}}
+X-Trace: {{trace}}
+"#;
+
+/// Seed an .http file, its included body and its environment files
+fn seed_http_files(dir: &Path, host: &str) {
+	fs::create_dir_all(dir.join("payloads")).unwrap();
+	fs::write(dir.join("api.http"), API_HTTP_FILE).unwrap();
+	fs::write(dir.join("payloads/login.json"), r#"{"user": "squrl"}"#).unwrap();
+	fs::write(
+		dir.join("http-client.env.json"),
+		serde_json::json!({"local": {"host": host}}).to_string(),
+	)
+	.unwrap();
+	fs::write(
+		dir.join("http-client.private.env.json"),
+		r#"{"local": {"password": "secret"}}"#,
+	)
+	.unwrap();
+}
+
+#[test]
+fn test_import_http_file_variables_environments_and_scripts() {
+	let squrl_dir = temp_dir();
+	let http_dir = temp_dir();
+	seed_http_files(http_dir.path(), "https://example.com");
+
+	squrl()
+		.args([
+			"-d",
+			squrl_dir.path().to_str().unwrap(),
+			"import",
+			"http-file",
+			http_dir.path().join("api.http").to_str().unwrap(),
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("Found environment \"local\""));
+
+	let collection: Value =
+		serde_json::from_str(&fs::read_to_string(squrl_dir.path().join("api.json")).unwrap())
+			.unwrap();
+
+	assert_eq!(collection["variables"]["base"], "{{host}}/api");
+	assert_eq!(collection["environments"][0]["name"], "local");
+	assert_eq!(
+		collection["environments"][0]["values"]["password"],
+		"secret"
+	);
+
+	let login = &collection["requests"][0];
+	assert_eq!(login["name"], "login");
+	assert_eq!(
+		login["protocol"]["body"]["file"],
+		http_dir
+			.path()
+			.join("payloads/login.json")
+			.to_str()
+			.unwrap()
+	);
+	assert!(
+		login["scripts"]["post_request_script"]
+			.as_str()
+			.unwrap()
+			.contains("client.global.set(\"token\", response.body.token);")
+	);
+	assert_eq!(
+		collection["requests"][1]["scripts"]["pre_request_script"],
+		"request.variables.set(\"trace\", \"abc\");"
+	);
+}
+
+#[test]
+fn test_send_imported_http_file() {
+	let mut server = mockito::Server::new();
+	let login_mock = server
+		.mock("POST", "/api/login")
+		.match_body(r#"{"user": "squrl"}"#)
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"token": "abc123"}"#)
+		.create();
+	let me_mock = server
+		.mock("GET", "/api/me")
+		.match_header("authorization", "Bearer abc123")
+		.match_header("x-trace", "abc")
+		.with_status(200)
+		.create();
+
+	let squrl_dir = temp_dir();
+	let http_dir = temp_dir();
+	seed_http_files(http_dir.path(), &server.url());
+	seed_environment(squrl_dir.path(), "dev", "token=\ntrace=\n");
+
+	squrl()
+		.args([
+			"-d",
+			squrl_dir.path().to_str().unwrap(),
+			"import",
+			"http-file",
+			http_dir.path().join("api.http").to_str().unwrap(),
+		])
+		.assert()
+		.success();
+
+	squrl()
+		.args([
+			"-d",
+			squrl_dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"api",
+			"--env",
+			"dev",
+			"--collection-env",
+			"local",
+			"--hide-content",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("2 passed, 0 failed"));
+
+	login_mock.assert();
+	me_mock.assert();
+}
+
+#[test]
+fn test_http_file_round_trip() {
+	// The .http collection is named after the project directory
+	let root = temp_dir();
+	let project = root.path().join("project");
+	let requests_dir = project.join("requests");
+	fs::create_dir_all(project.join(".git")).unwrap();
+	fs::create_dir_all(&requests_dir).unwrap();
+	seed_http_files(&requests_dir, "https://example.com");
+	fs::write(
+		requests_dir.join("status.http"),
+		"### status\nGET https://example.com/status\n",
+	)
+	.unwrap();
+
+	let dir = temp_dir();
+
+	squrl()
+		.current_dir(&project)
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"assertions",
+			"project/status",
+			"add",
+			"status == 200",
+		])
+		.assert()
+		.success();
+
+	let api = fs::read_to_string(requests_dir.join("api.http")).unwrap();
+	assert!(api.starts_with("@base = {{host}}/api\n@unused = value\n\n### login\n"));
+	assert!(api.contains("\n\n< ./payloads/login.json\n\n> {%\n    client.test("));
+	assert!(api.contains(
+		"\n%}\n\n### me\n< {%\nrequest.variables.set(\"trace\", \"abc\");\n%}\nGET {{base}}/me\n"
+	));
+
+	// Files without variables references do not declare them
+	let status = fs::read_to_string(requests_dir.join("status.http")).unwrap();
+	assert!(status.starts_with("### status\n# @assert status == 200\n"));
+
+	// Environments stay in their files, private values included
+	let env: Value = serde_json::from_str(
+		&fs::read_to_string(requests_dir.join("http-client.env.json")).unwrap(),
+	)
+	.unwrap();
+	assert_eq!(
+		env,
+		serde_json::json!({"local": {"host": "https://example.com"}})
+	);
+
+	let private_env: Value = serde_json::from_str(
+		&fs::read_to_string(requests_dir.join("http-client.private.env.json")).unwrap(),
+	)
+	.unwrap();
+	assert_eq!(
+		private_env,
+		serde_json::json!({"local": {"password": "secret"}})
+	);
+
+	// The written files are parsed back the same way
+	squrl()
+		.current_dir(&project)
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"info",
+			"project/login",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("{{base}}/login"));
+}
+
+#[test]
+fn test_http_file_keeps_response_filter() {
+	let root = temp_dir();
+	let project = root.path().join("project");
+	fs::create_dir_all(project.join(".git")).unwrap();
+	fs::create_dir_all(project.join("requests")).unwrap();
+
+	let http_file = project.join("requests").join("users.http");
+	fs::write(
+		&http_file,
+		"### users\n# @filter .users[] | .name\nGET https://example.com/users\n",
+	)
+	.unwrap();
+
+	let dir = temp_dir();
+
+	squrl()
+		.current_dir(&project)
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"assertions",
+			"project/users",
+			"add",
+			"status == 200",
+		])
+		.assert()
+		.success();
+
+	let content = fs::read_to_string(&http_file).unwrap();
+	assert!(
+		content.starts_with("### users\n# @assert status == 200\n# @filter .users[] | .name\n")
+	);
+
+	squrl()
+		.current_dir(&project)
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"info",
+			"project/users",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains(
+			"response filter: .users[] | .name",
+		));
+}
@@ -1,6 +1,6 @@
 mod helpers;
 
-use helpers::{minimal_http_file, squrl, temp_dir};
+use helpers::{minimal_http_file, send_request, squrl, temp_dir};
 use predicates::prelude::*;
 use std::fs;
 
@@ -404,3 +404,102 @@ paths:
 		.success()
 		.stdout(predicate::str::contains("YAML API"));
 }
+
+// ── Postman import ────────────────────────────────────────────
+
+/// A Postman collection with collection scripts and variables, and a request with a test script
+fn postman_collection_with_scripts(base_url: &str) -> String {
+	serde_json::json!({
+		"info": {
+			"name": "postman-api",
+			"schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"
+		},
+		"event": [
+			{
+				"listen": "prerequest",
+				"script": {
+					"type": "text/javascript",
+					"exec": [
+						"pm.request.headers.upsert({ key: 'X-Trace', value: pm.collectionVariables.get('trace') });"
+					]
+				}
+			},
+			{
+				"listen": "test",
+				"script": {
+					"type": "text/javascript",
+					"exec": ["pm.test('collection test', () => pm.response.to.have.status(200));"]
+				}
+			}
+		],
+		"variable": [
+			{ "key": "baseUrl", "value": base_url },
+			{ "key": "trace", "value": "abc" },
+			{ "key": "unused", "value": "nope", "disabled": true }
+		],
+		"item": [
+			{
+				"name": "items",
+				"event": [
+					{
+						"listen": "test",
+						"script": {
+							"type": "text/javascript",
+							"exec": ["pm.test('request test', () => pm.expect(pm.response.json().id).to.equal(1));"]
+						}
+					}
+				],
+				"request": {
+					"method": "GET",
+					"header": [],
+					"url": "{{baseUrl}}/items"
+				}
+			}
+		]
+	})
+	.to_string()
+}
+
+#[test]
+fn test_import_postman_collection_scripts_and_variables() {
+	let mut server = mockito::Server::new();
+	let mock = server
+		.mock("GET", "/items")
+		.match_header("x-trace", "abc")
+		.with_status(200)
+		.with_body(r#"{"id": 1}"#)
+		.create();
+
+	let dir = temp_dir();
+	let postman_file_path = dir.path().join("postman.json");
+	fs::write(
+		&postman_file_path,
+		postman_collection_with_scripts(&server.url()),
+	)
+	.unwrap();
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"import",
+			"postman",
+			postman_file_path.to_str().unwrap(),
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("Parsing Postman collection"));
+
+	let collection = fs::read_to_string(dir.path().join("postman-api.json")).unwrap();
+	assert!(collection.contains("collection_variables"));
+	assert!(!collection.contains("nope"));
+
+	send_request(dir.path(), "postman-api/items")
+		.arg("--hide-content")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("PASS test \"collection test\""))
+		.stdout(predicate::str::contains("PASS test \"request test\""));
+
+	mock.assert();
+}
@@ -0,0 +1,229 @@
+mod helpers;
+
+use helpers::{
+	collection_with_request, minimal_collection_json, read_collection, seed_collection,
+	send_request, squrl, temp_dir,
+};
+use mockito::Matcher;
+use predicates::prelude::*;
+use serde_json::Value;
+
+/// A collection sharing a bearer token and headers with a root request and a folder request,
+/// the folder overriding both
+fn inheriting_collection(server_url: &str) -> String {
+	collection_with_request(&format!("{server_url}/root"), |collection| {
+		collection["requests"][0]["name"] = Value::from("root-request");
+
+		collection["auth"] = serde_json::json!({"bearer_token": {"token": "collection-token"}});
+		collection["headers"] = serde_json::json!([
+			{"enabled": true, "data": ["x-team", "core"]},
+			{"enabled": true, "data": ["x-scope", "collection"]}
+		]);
+		collection["requests"][0]["auth"] = Value::from("inherit");
+
+		let mut folder_request = collection["requests"][0].clone();
+		folder_request["name"] = Value::from("folder-request");
+		folder_request["url"] = Value::from(format!("{server_url}/folder"));
+		folder_request["headers"]
+			.as_array_mut()
+			.unwrap()
+			.push(serde_json::json!({"enabled": true, "data": ["X-Scope", "request"]}));
+
+		collection["folders"] = serde_json::json!([
+			{
+				"name": "admin",
+				"requests": [folder_request],
+				"auth": {"bearer_token": {"token": "folder-token"}},
+				"headers": [{"enabled": true, "data": ["x-team", "admin"]}]
+			}
+		]);
+	})
+}
+
+#[test]
+fn test_requests_inherit_auth_and_headers() {
+	let mut server = mockito::Server::new();
+	let root_mock = server
+		.mock("GET", "/root")
+		.match_header("authorization", "Bearer collection-token")
+		.match_header("x-team", "core")
+		.match_header("x-scope", "collection")
+		.create();
+	let folder_mock = server
+		.mock("GET", "/folder")
+		.match_header("authorization", "Bearer folder-token")
+		.match_header("x-team", "admin")
+		.match_header("x-scope", "request")
+		.create();
+
+	let dir = temp_dir();
+	seed_collection(dir.path(), "my-api", &inheriting_collection(&server.url()));
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"my-api",
+			"--hide-content",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("2 passed, 0 failed"));
+
+	root_mock.assert();
+	folder_mock.assert();
+
+	// The inherited properties are saved back as they were written
+	let collection = read_collection(dir.path(), "my-api");
+	assert_eq!(collection["requests"][0]["auth"], "inherit");
+	assert_eq!(
+		collection["auth"]["bearer_token"]["token"],
+		"collection-token"
+	);
+	assert_eq!(collection["folders"][0]["headers"][0]["data"][1], "admin");
+}
+
+#[test]
+fn test_request_auth_overrides_inherited_auth() {
+	let mut server = mockito::Server::new();
+	let mock = server
+		.mock("GET", "/root")
+		.match_header("authorization", Matcher::Missing)
+		.match_header("x-team", "core")
+		.create();
+
+	let dir = temp_dir();
+	let mut collection: Value =
+		serde_json::from_str(&inheriting_collection(&server.url())).unwrap();
+	collection["requests"][0]["auth"] = serde_json::json!({"no_auth": null});
+	seed_collection(dir.path(), "my-api", &collection.to_string());
+
+	send_request(dir.path(), "my-api/root-request")
+		.assert()
+		.success();
+
+	mock.assert();
+}
+
+#[test]
+fn test_export_resolves_inherited_auth_and_headers() {
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&inheriting_collection("https://example.com"),
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"export",
+			"my-api/root-request",
+			"curl",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains(
+			"Authorization: Bearer collection-token",
+		))
+		.stdout(predicate::str::contains("--header 'X-Team: core'"));
+}
+
+#[test]
+fn test_collection_auth_header_and_scripts_commands() {
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", "https://example.com"),
+	);
+
+	let run = |args: &[&str]| {
+		squrl()
+			.args(["-d", dir.path().to_str().unwrap(), "collection"])
+			.args(args)
+			.assert()
+			.success()
+	};
+
+	run(&["auth", "my-api", "set", "bearer-token", "secret"]);
+	run(&["header", "my-api", "add", "x-team", "core"]);
+	run(&[
+		"scripts",
+		"my-api",
+		"set",
+		"pre",
+		"console.log('collection')",
+	]);
+
+	run(&["auth", "my-api", "get"])
+		.stdout(predicate::str::starts_with("Bearer"))
+		.stdout(predicate::str::contains("token: secret"));
+	run(&["header", "my-api", "get", "x-team"]).stdout("core\n");
+	run(&["scripts", "my-api", "get", "pre"]).stdout("console.log('collection')\n");
+
+	let collection = read_collection(dir.path(), "my-api");
+	assert_eq!(collection["auth"]["bearer_token"]["token"], "secret");
+	assert_eq!(collection["headers"][0]["data"][0], "x-team");
+	assert_eq!(
+		collection["scripts"]["pre_request_script"],
+		"console.log('collection')"
+	);
+
+	// "inherit" removes the collection auth
+	run(&["auth", "my-api", "set", "inherit"]);
+	run(&["header", "my-api", "delete", "x-team"]);
+
+	let collection = read_collection(dir.path(), "my-api");
+	assert!(collection.get("auth").is_none());
+	assert!(collection.get("headers").is_none());
+}
+
+#[test]
+fn test_folder_properties_commands() {
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&inheriting_collection("https://example.com"),
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"header",
+			"my-api",
+			"--folder",
+			"admin",
+			"set",
+			"x-team",
+			"ops",
+		])
+		.assert()
+		.success();
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"auth",
+			"my-api",
+			"--folder",
+			"unknown",
+			"get",
+		])
+		.assert()
+		.failure()
+		.stderr(predicate::str::contains("Folder not found"));
+
+	let collection = read_collection(dir.path(), "my-api");
+	assert_eq!(collection["folders"][0]["headers"][0]["data"][1], "ops");
+	assert_eq!(collection["headers"][0]["data"][1], "core");
+}
@@ -0,0 +1,436 @@
+mod helpers;
+
+use helpers::{
+	collection_with_request, minimal_collection_json, read_collection, seed_collection, squrl,
+	temp_dir,
+};
+use predicates::prelude::*;
+use serde_json::Value;
+use std::fs;
+
+/// A collection with a root request and an "admin" folder holding a "users" sub-folder, each
+/// level adding a header
+fn nested_collection(server_url: &str) -> String {
+	collection_with_request(&format!("{server_url}/root"), |collection| {
+		collection["requests"][0]["name"] = Value::from("root-request");
+
+		let mut admin_request = collection["requests"][0].clone();
+		admin_request["name"] = Value::from("admin-request");
+		admin_request["url"] = Value::from(format!("{server_url}/admin"));
+
+		let mut users_request = collection["requests"][0].clone();
+		users_request["name"] = Value::from("users-request");
+		users_request["url"] = Value::from(format!("{server_url}/admin/users"));
+
+		collection["headers"] =
+			serde_json::json!([{"enabled": true, "data": ["x-level", "collection"]}]);
+		collection["folders"] = serde_json::json!([
+			{
+				"name": "admin",
+				"headers": [{"enabled": true, "data": ["x-level", "admin"]}],
+				"folders": [
+					{
+						"name": "users",
+						"headers": [{"enabled": true, "data": ["x-level", "users"]}],
+						"requests": [users_request]
+					}
+				],
+				"requests": [admin_request]
+			}
+		]);
+	})
+}
+
+#[test]
+fn test_collection_send_runs_nested_folders() {
+	let mut server = mockito::Server::new();
+	let users_mock = server
+		.mock("GET", "/admin/users")
+		.match_header("x-level", "users")
+		.create();
+	let admin_mock = server
+		.mock("GET", "/admin")
+		.match_header("x-level", "admin")
+		.create();
+	let root_mock = server
+		.mock("GET", "/root")
+		.match_header("x-level", "collection")
+		.create();
+
+	let dir = temp_dir();
+	seed_collection(dir.path(), "my-api", &nested_collection(&server.url()));
+
+	let output = squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"my-api",
+			"--hide-content",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("PASS admin/users/users-request"))
+		.stdout(predicate::str::contains("PASS admin/admin-request"))
+		.stdout(predicate::str::contains("3 passed, 0 failed"))
+		.get_output()
+		.stdout
+		.clone();
+
+	// Same order as the collection tree: sub-folders first, then requests
+	let stdout = String::from_utf8(output).unwrap();
+	assert!(stdout.find("users-request") < stdout.find("admin-request"));
+	assert!(stdout.find("admin-request") < stdout.find("root-request"));
+
+	users_mock.assert();
+	admin_mock.assert();
+	root_mock.assert();
+}
+
+#[test]
+fn test_collection_send_folder_filter_includes_sub_folders() {
+	let mut server = mockito::Server::new();
+	let users_mock = server.mock("GET", "/admin/users").create();
+	let admin_mock = server.mock("GET", "/admin").create();
+	let root_mock = server.mock("GET", "/root").expect(0).create();
+
+	let dir = temp_dir();
+	seed_collection(dir.path(), "my-api", &nested_collection(&server.url()));
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"my-api",
+			"--folder",
+			"admin",
+			"--hide-content",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("2 passed, 0 failed"));
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"my-api",
+			"--folder",
+			"admin/users",
+			"--hide-content",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("1 passed, 0 failed"));
+
+	users_mock.expect(2).assert();
+	admin_mock.assert();
+	root_mock.assert();
+}
+
+#[test]
+fn test_folder_commands() {
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", "https://example.com"),
+	);
+
+	let run = |args: &[&str]| {
+		squrl()
+			.args([
+				"-d",
+				dir.path().to_str().unwrap(),
+				"collection",
+				"folder",
+				"my-api",
+			])
+			.args(args)
+			.assert()
+	};
+
+	run(&["new", "admin"]).success();
+	run(&["new", "admin/users"]).success();
+	run(&["new", "admin/users/roles"]).success();
+	run(&["new", "unknown/users"])
+		.failure()
+		.stderr(predicate::str::contains("Folder not found"));
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"new",
+			"my-api/list-roles",
+			"--folder",
+			"admin/users/roles",
+		])
+		.assert()
+		.success();
+
+	run(&["list"])
+		.success()
+		.stdout("admin/\n\tusers/\n\t\troles/\n");
+
+	let collection = read_collection(dir.path(), "my-api");
+	assert_eq!(
+		collection["folders"][0]["folders"][0]["folders"][0]["requests"][0]["name"],
+		"list-roles"
+	);
+
+	run(&["rename", "admin/users", "members"]).success();
+	run(&["move", "admin/users", "admin"])
+		.failure()
+		.stderr(predicate::str::contains("Folder not found"));
+
+	// Moving a folder into itself is refused
+	run(&["move", "admin", "admin/members"])
+		.failure()
+		.stderr(predicate::str::contains("cannot be moved into itself"));
+
+	// "roles" goes to the collection root with its request
+	run(&["move", "admin/members/roles"]).success();
+	run(&["list"])
+		.success()
+		.stdout("admin/\n\tmembers/\nroles/\n");
+
+	// Deleting "admin" moves "members" to the collection root
+	run(&["delete", "admin"]).success();
+
+	let collection = read_collection(dir.path(), "my-api");
+	let folder_names: Vec<&str> = collection["folders"]
+		.as_array()
+		.unwrap()
+		.iter()
+		.map(|folder| folder["name"].as_str().unwrap())
+		.collect();
+	assert_eq!(folder_names, ["roles", "members"]);
+	assert_eq!(
+		collection["folders"][0]["requests"][0]["name"],
+		"list-roles"
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"info",
+			"my-api",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains(
+			"folders:\n\troles/\n\t\tlist-roles\n",
+		));
+}
+
+#[test]
+fn test_folder_properties_at_any_depth() {
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&nested_collection("https://example.com"),
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"header",
+			"my-api",
+			"--folder",
+			"admin/users",
+			"set",
+			"x-level",
+			"members",
+		])
+		.assert()
+		.success();
+
+	let collection = read_collection(dir.path(), "my-api");
+	assert_eq!(
+		collection["folders"][0]["folders"][0]["headers"][0]["data"][1],
+		"members"
+	);
+	assert_eq!(collection["folders"][0]["headers"][0]["data"][1], "admin");
+}
+
+#[test]
+fn test_import_postman_collection_keeps_nested_folders() {
+	let request = |name: &str| {
+		serde_json::json!({
+			"name": name,
+			"request": {"method": "GET", "header": [], "url": "https://example.com"}
+		})
+	};
+
+	let postman_collection = serde_json::json!({
+		"info": {
+			"name": "postman-api",
+			"schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"
+		},
+		"item": [
+			{
+				"name": "admin",
+				"item": [
+					{"name": "users", "item": [request("list-users")]},
+					request("admin-request")
+				]
+			},
+			request("root-request")
+		]
+	});
+
+	let import = |max_depth: Option<&str>| {
+		let dir = temp_dir();
+		let postman_file_path = dir.path().join("postman.json");
+		fs::write(&postman_file_path, postman_collection.to_string()).unwrap();
+
+		let mut command = squrl();
+		command.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"import",
+			"postman",
+			postman_file_path.to_str().unwrap(),
+		]);
+
+		if let Some(max_depth) = max_depth {
+			command.args(["--max-depth", max_depth]);
+		}
+
+		command.assert().success();
+
+		let collection: Value =
+			serde_json::from_str(&fs::read_to_string(dir.path().join("postman-api.json")).unwrap())
+				.unwrap();
+		collection
+	};
+
+	let collection = import(None);
+	assert_eq!(collection["requests"][0]["name"], "root-request");
+	assert_eq!(collection["folders"][0]["name"], "admin");
+	assert_eq!(
+		collection["folders"][0]["requests"][0]["name"],
+		"admin-request"
+	);
+	assert_eq!(collection["folders"][0]["folders"][0]["name"], "users");
+	assert_eq!(
+		collection["folders"][0]["folders"][0]["requests"][0]["name"],
+		"list-users"
+	);
+
+	// Folders at the max depth get the requests of their sub-folders
+	let collection = import(Some("1"));
+	assert!(collection["folders"][0].get("folders").is_none());
+	assert_eq!(
+		collection["folders"][0]["requests"][0]["name"],
+		"list-users"
+	);
+	assert_eq!(
+		collection["folders"][0]["requests"][1]["name"],
+		"admin-request"
+	);
+}
+
+#[test]
+fn test_import_openapi_tags_become_folders() {
+	let squrl_dir = temp_dir();
+	let spec_dir = temp_dir();
+
+	let operation = |operation_id: &str, tags: Value| {
+		serde_json::json!({
+			"operationId": operation_id,
+			"tags": tags,
+			"responses": {"200": {"description": "OK"}}
+		})
+	};
+
+	let openapi_spec = serde_json::json!({
+		"openapi": "3.0.0",
+		"info": {"title": "Tagged API", "version": "1.0.0"},
+		"servers": [{"url": "https://api.example.com"}],
+		"paths": {
+			"/users": {"get": operation("listUsers", serde_json::json!(["admin/users"]))},
+			"/status": {"get": operation("getStatus", serde_json::json!([]))}
+		}
+	});
+
+	let spec_path = spec_dir.path().join("openapi.json");
+	fs::write(&spec_path, openapi_spec.to_string()).unwrap();
+
+	squrl()
+		.args([
+			"-d",
+			squrl_dir.path().to_str().unwrap(),
+			"import",
+			"open-api",
+			spec_path.to_str().unwrap(),
+		])
+		.assert()
+		.success();
+
+	let collection: Value = serde_json::from_str(
+		&fs::read_to_string(squrl_dir.path().join("Tagged API.json")).unwrap(),
+	)
+	.unwrap();
+
+	assert_eq!(collection["requests"][0]["name"], "getStatus");
+	assert_eq!(collection["folders"][0]["name"], "admin");
+	assert_eq!(collection["folders"][0]["folders"][0]["name"], "users");
+	assert_eq!(
+		collection["folders"][0]["folders"][0]["requests"][0]["name"],
+		"listUsers"
+	);
+}
+
+#[test]
+fn test_import_http_files_directories_become_folders() {
+	let squrl_dir = temp_dir();
+	let http_dir = temp_dir();
+
+	fs::create_dir_all(http_dir.path().join("admin/users")).unwrap();
+	fs::write(
+		http_dir.path().join("root.http"),
+		"### root-request\nGET https://example.com/root\n",
+	)
+	.unwrap();
+	fs::write(
+		http_dir.path().join("admin/users/users.http"),
+		"### list-users\nGET https://example.com/users\n",
+	)
+	.unwrap();
+
+	squrl()
+		.args([
+			"-d",
+			squrl_dir.path().to_str().unwrap(),
+			"import",
+			"http-file",
+			http_dir.path().to_str().unwrap(),
+			"http-api",
+			"--recursive",
+		])
+		.assert()
+		.success();
+
+	let collection: Value =
+		serde_json::from_str(&fs::read_to_string(squrl_dir.path().join("http-api.json")).unwrap())
+			.unwrap();
+
+	assert_eq!(collection["requests"][0]["name"], "root-request");
+	assert_eq!(
+		collection["folders"][0]["folders"][0]["requests"][0]["name"],
+		"list-users"
+	);
+}
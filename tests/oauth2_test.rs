@@ -0,0 +1,364 @@
+mod helpers;
+
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::Stdio;
+
+use helpers::tls_server::{TlsTestServer, tls_fixture};
+use helpers::{
+	collection_with_request, minimal_collection_json, seed_collection, send_request, squrl,
+	temp_dir,
+};
+use mockito::Matcher;
+use predicates::prelude::*;
+use serde_json::Value;
+
+const TOKEN_CACHE_FILE_NAME: &str = "squrl.oauth2-tokens.json";
+
+fn read_token_cache(dir: &std::path::Path) -> Value {
+	serde_json::from_str(&fs::read_to_string(dir.join(TOKEN_CACHE_FILE_NAME)).unwrap()).unwrap()
+}
+
+#[test]
+fn test_client_credentials_token_is_requested_then_cached() {
+	let mut server = mockito::Server::new();
+	let token_mock = server
+		.mock("POST", "/token")
+		// "app:s3cr3t"
+		.match_header("authorization", "Basic YXBwOnMzY3IzdA==")
+		.match_body(Matcher::AllOf(vec![
+			Matcher::UrlEncoded("grant_type".into(), "client_credentials".into()),
+			Matcher::UrlEncoded("scope".into(), "read write".into()),
+		]))
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"access_token": "tok-1", "token_type": "bearer", "expires_in": 3600}"#)
+		.expect(1)
+		.create();
+	let me_mock = server
+		.mock("GET", "/me")
+		.match_header("authorization", "Bearer tok-1")
+		.with_body("me")
+		.expect(2)
+		.create();
+
+	let dir = temp_dir();
+	let collection_path = seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&format!("{}/me", server.url()), |collection| {
+			collection["requests"][0]["auth"] = serde_json::json!({ "oauth2": {
+				"grant_type": "client_credentials",
+				"token_url": format!("{}/token", server.url()),
+				"client_id": "app",
+				"client_secret": "s3cr3t",
+				"scope": "read write"
+			}});
+		}),
+	);
+
+	send_request(dir.path(), "my-api/req").assert().success();
+	send_request(dir.path(), "my-api/req").assert().success();
+
+	token_mock.assert();
+	me_mock.assert();
+
+	let collection: Value =
+		serde_json::from_str(&fs::read_to_string(collection_path).unwrap()).unwrap();
+	assert!(collection["requests"][0]["auth"]["oauth2"]["token"].is_null());
+
+	let tokens = read_token_cache(dir.path());
+	assert_eq!(tokens[0]["client_id"], "app");
+	assert_eq!(tokens[0]["scope"], "read write");
+	assert_eq!(tokens[0]["token"]["access_token"], "tok-1");
+	assert!(tokens[0]["token"]["expires_at"].is_string());
+}
+
+#[test]
+fn test_expired_token_is_refreshed() {
+	let mut server = mockito::Server::new();
+	let token_mock = server
+		.mock("POST", "/token")
+		.match_body(Matcher::AllOf(vec![
+			Matcher::UrlEncoded("grant_type".into(), "refresh_token".into()),
+			Matcher::UrlEncoded("refresh_token".into(), "r1".into()),
+			Matcher::UrlEncoded("client_id".into(), "app".into()),
+		]))
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"access_token": "tok-2", "expires_in": 3600}"#)
+		.create();
+	let me_mock = server
+		.mock("GET", "/me")
+		.match_header("authorization", "Bearer tok-2")
+		.create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&format!("{}/me", server.url()), |collection| {
+			collection["requests"][0]["auth"] = serde_json::json!({ "oauth2": {
+				"grant_type": "password",
+				"token_url": format!("{}/token", server.url()),
+				"client_id": "app"
+			}});
+		}),
+	);
+	fs::write(
+		dir.path().join(TOKEN_CACHE_FILE_NAME),
+		serde_json::json!([{
+			"grant_type": "password",
+			"token_url": format!("{}/token", server.url()),
+			"client_id": "app",
+			"token": {
+				"access_token": "tok-1",
+				"token_type": "Bearer",
+				"refresh_token": "r1",
+				"expires_at": "2020-01-01T00:00:00Z"
+			}
+		}])
+		.to_string(),
+	)
+	.unwrap();
+
+	send_request(dir.path(), "my-api/req").assert().success();
+
+	token_mock.assert();
+	me_mock.assert();
+
+	// The refresh token is kept when the server does not rotate it
+	let tokens = read_token_cache(dir.path());
+	assert_eq!(tokens.as_array().unwrap().len(), 1);
+	assert_eq!(tokens[0]["token"]["access_token"], "tok-2");
+	assert_eq!(tokens[0]["token"]["refresh_token"], "r1");
+}
+
+#[test]
+fn test_token_endpoint_error_fails_the_send() {
+	let mut server = mockito::Server::new();
+	server
+		.mock("POST", "/token")
+		.with_status(401)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"error": "invalid_client", "error_description": "Unknown client"}"#)
+		.create();
+	let me_mock = server.mock("GET", "/me").expect(0).create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&format!("{}/me", server.url()), |collection| {
+			collection["requests"][0]["auth"] = serde_json::json!({ "oauth2": {
+				"grant_type": "client_credentials",
+				"token_url": format!("{}/token", server.url()),
+				"client_id": "app"
+			}});
+		}),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.failure()
+		.stderr(predicate::str::contains("COULD NOT GET OAUTH2 TOKEN"))
+		.stderr(predicate::str::contains("invalid_client: Unknown client"));
+
+	me_mock.assert();
+}
+
+#[test]
+fn test_token_is_requested_with_the_request_tls_settings() {
+	let token_server = TlsTestServer::start_with_body(
+		"application/json",
+		r#"{"access_token": "tls-token", "token_type": "bearer"}"#,
+	);
+
+	let mut server = mockito::Server::new();
+	let me_mock = server
+		.mock("GET", "/me")
+		.match_header("authorization", "Bearer tls-token")
+		.with_body("me")
+		.create();
+
+	let dir = temp_dir();
+	let collection = collection_with_request(&format!("{}/me", server.url()), |collection| {
+		collection["requests"][0]["auth"] = serde_json::json!({ "oauth2": {
+			"grant_type": "client_credentials",
+			"token_url": format!("{}/token", token_server.url()),
+			"client_id": "app"
+		}});
+
+		// The token server only accepts clients presenting a certificate signed by its CA
+		collection["tls"] = serde_json::json!({
+			"client_certificate": tls_fixture("client.pem"),
+			"client_key": tls_fixture("client.key"),
+			"ca_certificates": [tls_fixture("ca.pem")]
+		});
+	});
+	seed_collection(dir.path(), "my-api", &collection);
+
+	send_request(dir.path(), "my-api/req").assert().success();
+
+	me_mock.assert();
+}
+
+#[test]
+fn test_token_request_times_out_with_the_request() {
+	// Accepts the token request but never replies
+	let token_listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+	let token_url = format!("http://{}/token", token_listener.local_addr().unwrap());
+
+	let dir = temp_dir();
+	let collection = collection_with_request("http://127.0.0.1:1/me", |collection| {
+		collection["requests"][0]["auth"] = serde_json::json!({ "oauth2": {
+			"grant_type": "client_credentials",
+			"token_url": token_url,
+			"client_id": "app"
+		}});
+		collection["requests"][0]["settings"]["timeout"] = Value::from(500);
+	});
+	seed_collection(dir.path(), "my-api", &collection);
+
+	send_request(dir.path(), "my-api/req")
+		.timeout(std::time::Duration::from_secs(10))
+		.assert()
+		.failure()
+		.stderr(predicate::str::contains("COULD NOT GET OAUTH2 TOKEN"));
+
+	drop(token_listener);
+}
+
+#[test]
+fn test_authorization_code_with_pkce() {
+	let mut server = mockito::Server::new();
+	let token_mock = server
+		.mock("POST", "/token")
+		.match_body(Matcher::AllOf(vec![
+			Matcher::UrlEncoded("grant_type".into(), "authorization_code".into()),
+			Matcher::UrlEncoded("code".into(), "c0de".into()),
+			Matcher::UrlEncoded("client_id".into(), "app".into()),
+			Matcher::Regex("code_verifier=[0-9a-f]{64}".into()),
+			Matcher::Regex("redirect_uri=http%3A%2F%2F127.0.0.1%3A[0-9]+%2Fcallback".into()),
+		]))
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"access_token": "tok-3", "expires_in": 3600}"#)
+		.create();
+	let me_mock = server
+		.mock("GET", "/me")
+		.match_header("authorization", "Bearer tok-3")
+		.create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&format!("{}/me", server.url()), |collection| {
+			collection["requests"][0]["auth"] = serde_json::json!({ "oauth2": {
+				"grant_type": "authorization_code",
+				"token_url": format!("{}/token", server.url()),
+				"auth_url": "https://auth.example.com/authorize",
+				"client_id": "app",
+				"pkce": true
+			}});
+		}),
+	);
+
+	#[allow(deprecated)]
+	let mut child = std::process::Command::new(assert_cmd::cargo::cargo_bin("squrl"))
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"send",
+			"my-api/req",
+		])
+		.stdout(Stdio::null())
+		.stderr(Stdio::piped())
+		.spawn()
+		.unwrap();
+
+	// Act as the browser: read the authorization URL, then follow the redirect
+	let mut stderr_lines = BufReader::new(child.stderr.take().unwrap()).lines();
+	let authorization_url = stderr_lines
+		.by_ref()
+		.map(Result::unwrap)
+		.find(|line| line.starts_with("https://auth.example.com/authorize"))
+		.expect("the authorization URL should be printed");
+	let authorization_url = reqwest::Url::parse(&authorization_url).unwrap();
+	let query = |key: &str| {
+		authorization_url
+			.query_pairs()
+			.find(|(name, _)| name == key)
+			.map(|(_, value)| value.to_string())
+			.unwrap()
+	};
+
+	assert_eq!(query("code_challenge_method"), "S256");
+	let redirect_uri = reqwest::Url::parse(&query("redirect_uri")).unwrap();
+
+	let mut stream = TcpStream::connect(("127.0.0.1", redirect_uri.port().unwrap())).unwrap();
+	write!(
+		stream,
+		"GET /callback?code=c0de&state={} HTTP/1.1\r\nHost: 127.0.0.1\r\n\r\n",
+		query("state")
+	)
+	.unwrap();
+	let mut page = String::new();
+	stream.read_to_string(&mut page).unwrap();
+	assert!(page.starts_with("HTTP/1.1 200 OK"));
+
+	// Keep stderr open until the send is done
+	assert!(child.wait().unwrap().success());
+	drop(stderr_lines);
+
+	token_mock.assert();
+	me_mock.assert();
+}
+
+#[test]
+fn test_request_auth_set_oauth2() {
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", "https://example.com"),
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"auth",
+			"my-api/req",
+			"set",
+			"oauth2",
+			"client-credentials",
+			"https://auth.example.com/token",
+			"--client-id",
+			"app",
+			"--scope",
+			"read",
+		])
+		.assert()
+		.success();
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"auth",
+			"my-api/req",
+			"get",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("OAuth2"))
+		.stdout(predicate::str::contains("grant_type: Client credentials"))
+		.stdout(predicate::str::contains(
+			"token_url: https://auth.example.com/token",
+		))
+		.stdout(predicate::str::contains("client_id: app"))
+		.stdout(predicate::str::contains("token: No token"));
+}
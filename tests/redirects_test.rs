@@ -0,0 +1,82 @@
+mod helpers;
+
+use helpers::{
+	minimal_collection_json, mock_redirect_chain, seed_collection, send_request, squrl, temp_dir,
+};
+use predicates::prelude::*;
+
+#[test]
+fn test_send_prints_the_redirects() {
+	let mut server = mockito::Server::new();
+	let mocks = mock_redirect_chain(&mut server, "GET");
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", &format!("{}/login", server.url())),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.args(["--status-code", "--redirects"])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("200 OK"))
+		.stdout(predicate::str::contains(format!(
+			"1. GET {}/login\n   302 Found -> {}/home",
+			server.url(),
+			server.url()
+		)))
+		.stdout(predicate::str::contains("   set-cookie: session=1; Path=/"))
+		.stdout(predicate::str::contains(format!(
+			"2. GET {}/home\n   301 Moved Permanently -> {}/final",
+			server.url(),
+			server.url()
+		)))
+		.stdout(predicate::str::contains("final"));
+
+	for mock in mocks {
+		mock.assert();
+	}
+}
+
+#[test]
+fn test_redirects_are_only_printed_with_the_flag() {
+	let mut server = mockito::Server::new();
+	mock_redirect_chain(&mut server, "GET");
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", &format!("{}/login", server.url())),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("302 Found").not())
+		.stdout(predicate::str::contains("final"));
+}
+
+#[test]
+fn test_try_stops_at_max_redirects() {
+	let mut server = mockito::Server::new();
+	mock_redirect_chain(&mut server, "GET");
+
+	squrl()
+		.args([
+			"try",
+			"--url",
+			&format!("{}/login", server.url()),
+			"--max-redirects",
+			"1",
+			"--status-code",
+			"--redirects",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("301 Moved Permanently\n"))
+		.stdout(predicate::str::contains("1. GET"))
+		.stdout(predicate::str::contains("2. GET").not());
+}
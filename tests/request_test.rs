@@ -806,6 +806,59 @@ fn test_request_settings_set_timeout() {
 		.stdout(predicate::str::contains("5000"));
 }
 
+#[test]
+fn test_request_settings_set_max_redirects() {
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", "https://example.com"),
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"settings",
+			"my-api/req",
+			"set",
+			"max-redirects",
+			"3",
+		])
+		.assert()
+		.success();
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"settings",
+			"my-api/req",
+			"get",
+			"max-redirects",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("3"));
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"request",
+			"settings",
+			"my-api/req",
+			"set",
+			"max-redirects",
+			"true",
+		])
+		.assert()
+		.failure()
+		.stderr(predicate::str::contains("only takes positive int values"));
+}
+
 // ── Export ─────────────────────────────────────────────────────
 
 #[test]
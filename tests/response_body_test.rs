@@ -0,0 +1,141 @@
+mod helpers;
+
+use helpers::{minimal_collection_json, seed_collection, send_request, squrl, temp_dir};
+use predicates::prelude::*;
+use std::fs;
+
+#[test]
+fn test_send_writes_the_body_to_the_output_file() {
+	let mut server = mockito::Server::new();
+	let mock = server
+		.mock("GET", "/")
+		.with_status(200)
+		.with_body("downloaded body")
+		.create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", &server.url()),
+	);
+	let output = dir.path().join("body.txt");
+
+	send_request(dir.path(), "my-api/req")
+		.args(["--output", output.to_str().unwrap()])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("downloaded body").not())
+		.stderr(predicate::str::contains("Response body saved to"));
+
+	mock.assert();
+	assert_eq!(fs::read_to_string(output).unwrap(), "downloaded body");
+}
+
+#[test]
+fn test_send_truncates_a_body_over_the_max_size() {
+	let mut server = mockito::Server::new();
+	server
+		.mock("GET", "/")
+		.with_status(200)
+		.with_body("0123456789abcdef")
+		.create();
+
+	let dir = temp_dir();
+	fs::write(
+		dir.path().join("squrl.toml"),
+		"max_response_body_size = 10\n",
+	)
+	.unwrap();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", &server.url()),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("0123456789"))
+		.stdout(predicate::str::contains("abcdef").not())
+		.stderr(predicate::str::contains(
+			"Warning: Response body of 16 B truncated to its first 10 B",
+		));
+}
+
+#[test]
+fn test_send_prints_binary_bodies_as_a_hexdump() {
+	let mut server = mockito::Server::new();
+	server
+		.mock("GET", "/")
+		.with_status(200)
+		.with_header("content-type", "application/octet-stream")
+		.with_body([0x00, 0x9f, 0x92, 0x41])
+		.create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", &server.url()),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("00000000  00 9f 92 41"))
+		.stdout(predicate::str::contains("|...A|"));
+}
+
+#[test]
+fn test_collection_send_rejects_output() {
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", "http://127.0.0.1:1"),
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"my-api",
+			"--output",
+			"body.txt",
+		])
+		.assert()
+		.failure()
+		.stderr(predicate::str::contains(
+			"--output can only be used when sending a single request",
+		));
+}
+
+#[test]
+fn test_try_writes_the_body_to_the_output_file() {
+	let mut server = mockito::Server::new();
+	server
+		.mock("GET", "/file")
+		.with_status(200)
+		.with_body("try body")
+		.create();
+
+	let dir = temp_dir();
+	let output = dir.path().join("try.txt");
+
+	squrl()
+		.args([
+			"try",
+			"--url",
+			&format!("{}/file", server.url()),
+			"-o",
+			output.to_str().unwrap(),
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("try body").not());
+
+	assert_eq!(fs::read_to_string(output).unwrap(), "try body");
+}
@@ -0,0 +1,99 @@
+mod helpers;
+
+use helpers::{collection_with_request, seed_collection, send_request, squrl, temp_dir};
+use predicates::prelude::*;
+use serde_json::Value;
+
+#[test]
+fn test_request_send_prints_passing_script_tests() {
+	let mut server = mockito::Server::new();
+	server
+		.mock("GET", "/user")
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"id": 42}"#)
+		.create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&format!("{}/user", server.url()), |collection| {
+			collection["requests"][0]["scripts"]["post_request_script"] = Value::from(
+				r#"
+			pm.test("status is 200", () => pm.response.to.have.status(200));
+			test("has an id", () => expect(pm.response.json().id).to.equal(42));
+			"#,
+			);
+		}),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.arg("--hide-content")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("PASS test \"status is 200\""))
+		.stdout(predicate::str::contains("PASS test \"has an id\""));
+}
+
+#[test]
+fn test_request_send_fails_on_failing_script_test() {
+	let mut server = mockito::Server::new();
+	server
+		.mock("GET", "/user")
+		.with_status(200)
+		.with_body(r#"{"id": 7}"#)
+		.create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&format!("{}/user", server.url()), |collection| {
+			collection["requests"][0]["scripts"]["post_request_script"] = Value::from(
+				r#"pm.test("has id 42", () => pm.expect(pm.response.json().id).to.equal(42));"#,
+			);
+		}),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.arg("--hide-content")
+		.assert()
+		.failure()
+		.stdout(predicate::str::contains(
+			"FAIL test \"has id 42\" (expected 7 to equal 42)",
+		))
+		.stderr(predicate::str::contains("1 of 1 test(s) failed"));
+}
+
+#[test]
+fn test_collection_send_reports_failing_script_tests() {
+	let mut server = mockito::Server::new();
+	server.mock("GET", "/ok").with_status(200).create();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&format!("{}/ok", server.url()), |collection| {
+			collection["requests"][0]["scripts"]["post_request_script"] =
+				Value::from(r#"test("is created", () => pm.response.to.have.status(201));"#);
+		}),
+	);
+
+	squrl()
+		.args([
+			"-d",
+			dir.path().to_str().unwrap(),
+			"collection",
+			"send",
+			"my-api",
+			"--hide-content",
+		])
+		.assert()
+		.failure()
+		.stdout(predicate::str::contains("FAIL req"))
+		.stdout(predicate::str::contains(
+			"Test \"is created\" failed: expected response 200 to have status 201",
+		));
+}
@@ -0,0 +1,202 @@
+mod helpers;
+
+use helpers::{
+	collection_with_request, seed_collection, seed_environment, send_request, squrl, temp_dir,
+};
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+
+const SECRET: &str = "s3cr3t-value";
+
+/// Run squrl in the directory with the secrets passphrase in the environment. New passphrases
+/// get a key derived with few iterations, the secrets file keeps their count for the next runs.
+fn squrl_with_passphrase(dir: &Path, passphrase: &str) -> assert_cmd::Command {
+	let mut command = squrl();
+	command
+		.env("SQURL_SECRETS_PASSPHRASE", passphrase)
+		.env("SQURL_DEBUG_PBKDF2_ITERATIONS", "1000")
+		.args(["-d", dir.to_str().unwrap()]);
+	command
+}
+
+#[test]
+fn test_secret_values_are_encrypted_and_masked() {
+	let mut server = mockito::Server::new();
+	let mock = server
+		.mock("GET", "/me")
+		.match_header("x-api-key", SECRET)
+		.with_status(200)
+		.with_header("x-echo", SECRET)
+		.create();
+
+	let dir = temp_dir();
+	seed_environment(dir.path(), "dev", "HOST=localhost\nTOKEN=plain-token");
+	let collection = collection_with_request(&format!("{}/me", server.url()), |collection| {
+		collection["requests"][0]["headers"] = serde_json::json!([
+			{"enabled": true, "data": ["x-api-key", "{{API_KEY}}"]}
+		]);
+	});
+	seed_collection(dir.path(), "my-api", &collection);
+
+	squrl_with_passphrase(dir.path(), "passphrase")
+		.args(["env", "secret", "dev", "set", "API_KEY", SECRET])
+		.assert()
+		.success();
+	squrl_with_passphrase(dir.path(), "passphrase")
+		.args(["env", "secret", "dev", "mark", "TOKEN"])
+		.assert()
+		.success();
+
+	// Only the plain values stay in the environment file
+	assert_eq!(
+		fs::read_to_string(dir.path().join(".env.dev")).unwrap(),
+		"HOST=localhost"
+	);
+
+	let secrets_file = fs::read_to_string(dir.path().join("squrl.secrets")).unwrap();
+	assert!(secrets_file.contains("API_KEY"));
+	assert!(!secrets_file.contains(SECRET));
+	assert!(!secrets_file.contains("plain-token"));
+
+	squrl_with_passphrase(dir.path(), "passphrase")
+		.args(["env", "info", "dev"])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("API_KEY: ********"))
+		.stdout(predicate::str::contains("TOKEN: ********"))
+		.stdout(predicate::str::contains(SECRET).not());
+	squrl_with_passphrase(dir.path(), "passphrase")
+		.args(["env", "key", "dev", "get", "API_KEY"])
+		.assert()
+		.success()
+		.stdout("********\n");
+	squrl_with_passphrase(dir.path(), "passphrase")
+		.args(["env", "secret", "dev", "get", "API_KEY", "--reveal"])
+		.assert()
+		.success()
+		.stdout(format!("{SECRET}\n"));
+	squrl_with_passphrase(dir.path(), "passphrase")
+		.args(["env", "secret", "dev", "list"])
+		.assert()
+		.success()
+		.stdout("API_KEY\nTOKEN\n");
+
+	// The secret is sent, but not saved in the history
+	send_request(dir.path(), "my-api/req")
+		.env("SQURL_SECRETS_PASSPHRASE", "passphrase")
+		.args(["--env", "dev"])
+		.assert()
+		.success();
+	mock.assert();
+
+	let history = fs::read_to_string(dir.path().join("squrl.history.jsonl")).unwrap();
+	assert!(history.contains("********"));
+	assert!(!history.contains(SECRET));
+
+	// Exports are masked unless revealed
+	squrl_with_passphrase(dir.path(), "passphrase")
+		.args(["request", "export", "my-api/req", "curl"])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("X-Api-Key: ********"));
+	squrl_with_passphrase(dir.path(), "passphrase")
+		.args([
+			"request",
+			"export",
+			"my-api/req",
+			"curl",
+			"--reveal-secrets",
+		])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains(format!("X-Api-Key: {SECRET}")));
+
+	// Unmarking moves the value back to the environment file
+	squrl_with_passphrase(dir.path(), "passphrase")
+		.args(["env", "secret", "dev", "unmark", "TOKEN"])
+		.assert()
+		.success();
+	assert_eq!(
+		fs::read_to_string(dir.path().join(".env.dev")).unwrap(),
+		"HOST=localhost\nTOKEN=plain-token"
+	);
+}
+
+#[test]
+fn test_secrets_need_the_passphrase() {
+	let dir = temp_dir();
+	seed_environment(dir.path(), "dev", "HOST=localhost");
+
+	// Without a terminal, the passphrase can only come from the environment
+	squrl()
+		.args(["-d", dir.path().to_str().unwrap()])
+		.args(["env", "secret", "dev", "set", "API_KEY", SECRET])
+		.assert()
+		.failure()
+		.stderr(predicate::str::contains(
+			"set the SQURL_SECRETS_PASSPHRASE environment variable",
+		));
+
+	// The value can be piped to keep it out of the shell history
+	squrl_with_passphrase(dir.path(), "passphrase")
+		.args(["env", "secret", "dev", "set", "API_KEY"])
+		.write_stdin(format!("{SECRET}\n"))
+		.assert()
+		.success();
+
+	squrl_with_passphrase(dir.path(), "wrong")
+		.args(["env", "secret", "dev", "get", "API_KEY", "--reveal"])
+		.assert()
+		.failure()
+		.stderr(predicate::str::contains("Wrong passphrase"));
+
+	// Locked secrets are still kept out of the environment file
+	squrl()
+		.args(["-d", dir.path().to_str().unwrap()])
+		.args(["env", "key", "dev", "set", "HOST", "example.com"])
+		.assert()
+		.success();
+	assert_eq!(
+		fs::read_to_string(dir.path().join(".env.dev")).unwrap(),
+		"HOST=example.com"
+	);
+}
+
+#[test]
+fn test_rotate_secrets_passphrase() {
+	let dir = temp_dir();
+	seed_environment(dir.path(), "dev", "HOST=localhost");
+
+	squrl_with_passphrase(dir.path(), "old")
+		.args(["env", "secret", "dev", "set", "API_KEY", SECRET])
+		.assert()
+		.success();
+
+	squrl_with_passphrase(dir.path(), "old")
+		.env("SQURL_NEW_SECRETS_PASSPHRASE", "new")
+		.args(["env", "rotate-passphrase"])
+		.assert()
+		.success();
+
+	squrl_with_passphrase(dir.path(), "old")
+		.args(["env", "secret", "dev", "get", "API_KEY", "--reveal"])
+		.assert()
+		.failure();
+	squrl_with_passphrase(dir.path(), "new")
+		.args(["env", "secret", "dev", "get", "API_KEY", "--reveal"])
+		.assert()
+		.success()
+		.stdout(format!("{SECRET}\n"));
+
+	// Rotating a secret value keeps it secret
+	squrl_with_passphrase(dir.path(), "new")
+		.args(["env", "secret", "dev", "set", "API_KEY", "rotated"])
+		.assert()
+		.success();
+	squrl_with_passphrase(dir.path(), "new")
+		.args(["env", "secret", "dev", "get", "API_KEY", "--reveal"])
+		.assert()
+		.success()
+		.stdout("rotated\n");
+}
@@ -1,12 +1,16 @@
+mod helpers;
+
 use std::io::Cursor;
 use std::sync::Arc;
 use std::time::Duration;
 
 use image::{ImageFormat, RgbImage};
-use mockito;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 
+use helpers::mock_redirect_chain;
+use squrl::app::constants::DEFAULT_MAX_RESPONSE_BODY_SIZE;
 use squrl::app::request::http::send::send_http_request;
+use squrl::app::request::http::timing::ConnectionTimer;
 use squrl::models::request::{KeyValue, Request};
 use squrl::models::response::ResponseContent;
 use squrl::models::settings::{RequestSettings, Setting};
@@ -47,7 +51,16 @@ async fn test_successful_text_response() {
 	let request_builder = build_request_builder(&url);
 	let local_request = build_local_request(5000);
 
-	let result = send_http_request(request_builder, local_request.clone(), &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request.clone(),
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	mock.assert_async().await;
 
 	let response = result.unwrap();
@@ -61,6 +74,175 @@ async fn test_successful_text_response() {
 	}
 }
 
+#[tokio::test]
+async fn test_response_is_timed_with_the_connection_info() {
+	let mut server = mockito::Server::new_async().await;
+	server
+		.mock("GET", "/timed")
+		.with_status(200)
+		.with_body("timed")
+		.create_async()
+		.await;
+
+	let connection_timer = ConnectionTimer::default();
+	let client = reqwest::Client::builder()
+		.dns_resolver(connection_timer.clone())
+		.connector_layer(connection_timer.clone())
+		.build()
+		.unwrap();
+	let client = reqwest_middleware::ClientBuilder::new(client).build();
+
+	// Resolved by the timer instead of being an IP address
+	let url = format!("http://localhost:{}/timed", server.socket_address().port());
+
+	let response = send_http_request(
+		client.get(url),
+		connection_timer,
+		build_local_request(5000),
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await
+	.unwrap();
+
+	let timing = response.timing.unwrap();
+	assert!(timing.dns_lookup.is_some());
+	assert!(timing.connect.is_some());
+	assert_eq!(timing.tls_handshake, None);
+	assert_eq!(
+		timing.dns_lookup.unwrap() + timing.connect.unwrap() + timing.waiting + timing.download,
+		timing.total
+	);
+
+	let connection = response.connection.unwrap();
+	assert_eq!(connection.http_version, "HTTP/1.1");
+	assert_eq!(
+		connection.remote_address,
+		Some(server.socket_address().to_string())
+	);
+	assert_eq!(connection.tls_version, None);
+	assert_eq!(connection.certificate, None);
+}
+
+/// Client leaving the redirects to `send_http_request`, like the ones of prepared requests
+fn build_non_redirecting_client() -> reqwest_middleware::ClientWithMiddleware {
+	let client = reqwest::Client::builder()
+		.redirect(reqwest::redirect::Policy::none())
+		.cookie_store(true)
+		.build()
+		.unwrap();
+
+	reqwest_middleware::ClientBuilder::new(client).build()
+}
+
+#[tokio::test]
+async fn test_redirects_are_followed_and_recorded() {
+	let mut server = mockito::Server::new_async().await;
+	let mocks = mock_redirect_chain(&mut server, "POST");
+
+	let response = send_http_request(
+		build_non_redirecting_client()
+			.post(format!("{}/login", server.url()))
+			.body("user=me"),
+		ConnectionTimer::default(),
+		build_local_request(5000),
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await
+	.unwrap();
+
+	for mock in mocks {
+		mock.assert_async().await;
+	}
+
+	assert_eq!(response.status_code, Some("200 OK".to_string()));
+
+	match response.content {
+		Some(ResponseContent::Body(body)) => assert_eq!(body, "final"),
+		other => panic!("Expected Body content, got {:?}", other),
+	}
+
+	let [login, home] = response.redirects.as_slice() else {
+		panic!("Expected two redirects, got {:?}", response.redirects);
+	};
+
+	assert_eq!(login.method, "POST");
+	assert_eq!(login.url, format!("{}/login", server.url()));
+	assert_eq!(login.status_code, "302 Found");
+	assert_eq!(login.location, format!("{}/home", server.url()));
+	assert!(
+		login
+			.headers
+			.contains(&("set-cookie".to_string(), "session=1; Path=/".to_string()))
+	);
+
+	// 301 and 302 redirects are followed with a GET
+	assert_eq!(home.method, "GET");
+	assert_eq!(home.status_code, "301 Moved Permanently");
+	assert_eq!(home.location, format!("{}/final", server.url()));
+}
+
+#[tokio::test]
+async fn test_last_redirect_is_the_response_over_max_redirects() {
+	let mut server = mockito::Server::new_async().await;
+	mock_redirect_chain(&mut server, "POST");
+
+	let local_request = build_local_request(5000);
+	local_request.write().settings.max_redirects = Setting::U32(1);
+
+	let response = send_http_request(
+		build_non_redirecting_client().post(format!("{}/login", server.url())),
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await
+	.unwrap();
+
+	assert_eq!(
+		response.status_code,
+		Some("301 Moved Permanently".to_string())
+	);
+	assert_eq!(response.redirects.len(), 1);
+	assert!(
+		response
+			.headers
+			.contains(&("location".to_string(), "/final".to_string()))
+	);
+}
+
+#[tokio::test]
+async fn test_redirects_are_not_followed_when_disallowed() {
+	let mut server = mockito::Server::new_async().await;
+	mock_redirect_chain(&mut server, "POST");
+
+	let local_request = build_local_request(5000);
+	local_request.write().settings.allow_redirects = Setting::Bool(false);
+
+	let response = send_http_request(
+		build_non_redirecting_client().post(format!("{}/login", server.url())),
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await
+	.unwrap();
+
+	assert_eq!(response.status_code, Some("302 Found".to_string()));
+	assert!(response.redirects.is_empty());
+}
+
 #[tokio::test]
 async fn test_server_error_response() {
 	let mut server = mockito::Server::new_async().await;
@@ -75,7 +257,16 @@ async fn test_server_error_response() {
 	let request_builder = build_request_builder(&url);
 	let local_request = build_local_request(5000);
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	mock.assert_async().await;
 
 	let response = result.unwrap();
@@ -112,7 +303,16 @@ async fn test_timeout() {
 	let request_builder = build_request_builder(&url);
 	let local_request = build_local_request(100); // 100ms timeout
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 
 	let response = result.unwrap();
 	assert_eq!(response.status_code, Some("TIMEOUT".to_string()));
@@ -150,7 +350,16 @@ async fn test_cancellation() {
 		cancellation_token.cancel();
 	});
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 
 	let response = result.unwrap();
 	assert_eq!(response.status_code, Some("CANCELED".to_string()));
@@ -173,7 +382,16 @@ async fn test_response_headers_are_captured() {
 	let request_builder = build_request_builder(&url);
 	let local_request = build_local_request(5000);
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	mock.assert_async().await;
 
 	let response = result.unwrap();
@@ -208,7 +426,16 @@ async fn test_image_response() {
 	let request_builder = build_request_builder(&url);
 	let local_request = build_local_request(5000);
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	mock.assert_async().await;
 
 	let response = result.unwrap();
@@ -239,7 +466,16 @@ async fn test_sets_is_pending() {
 
 	assert!(!local_request.read().is_pending);
 
-	let _ = send_http_request(request_builder, local_request.clone(), &build_env()).await;
+	let _ = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request.clone(),
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 
 	// is_pending is set to true during the request, then back to false before returning
 	assert!(!local_request.read().is_pending);
@@ -251,11 +487,21 @@ async fn test_connection_error() {
 	let request_builder = build_request_builder("http://127.0.0.1:1");
 	let local_request = build_local_request(5000);
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 
 	let response = result.unwrap();
 	assert!(response.status_code.is_none());
 	assert!(response.duration.is_some());
+	assert!(response.timing.is_none());
 
 	match response.content {
 		Some(ResponseContent::Body(body)) => {
@@ -280,7 +526,16 @@ async fn test_cookies_are_captured() {
 	let request_builder = build_request_builder(&url);
 	let local_request = build_local_request(5000);
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	mock.assert_async().await;
 
 	let response = result.unwrap();
@@ -313,7 +568,16 @@ async fn test_json_pretty_print_enabled() {
 	let request_builder = build_request_builder(&url);
 	let local_request = build_local_request(5000);
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	mock.assert_async().await;
 
 	let response = result.unwrap();
@@ -354,7 +618,16 @@ async fn test_json_pretty_print_disabled() {
 		..Default::default()
 	}));
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	mock.assert_async().await;
 
 	let response = result.unwrap();
@@ -380,7 +653,16 @@ async fn test_empty_response_body() {
 	let request_builder = build_request_builder(&url);
 	let local_request = build_local_request(5000);
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	mock.assert_async().await;
 
 	let response = result.unwrap();
@@ -408,7 +690,16 @@ async fn test_multiple_response_headers() {
 	let request_builder = build_request_builder(&url);
 	let local_request = build_local_request(5000);
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	mock.assert_async().await;
 
 	let response = result.unwrap();
@@ -444,7 +735,16 @@ async fn test_request_with_custom_headers() {
 	}));
 
 	let request_builder = build_request_builder(&url);
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	mock.assert_async().await;
 
 	let response = result.unwrap();
@@ -465,7 +765,16 @@ async fn test_duration_format() {
 	let request_builder = build_request_builder(&url);
 	let local_request = build_local_request(5000);
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	let response = result.unwrap();
 
 	let duration = response.duration.unwrap();
@@ -497,21 +806,102 @@ async fn test_non_utf8_body_falls_back_to_hex_dump() {
 	let request_builder = build_request_builder(&url);
 	let local_request = build_local_request(5000);
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	mock.assert_async().await;
 
 	let response = result.unwrap();
 	match response.content {
 		Some(ResponseContent::Body(body)) => {
-			// Non-UTF8 bytes are formatted with {:#X?} on Bytes, which uses byte-string debug format
-			assert!(
-				body.contains("\\xff") || body.contains("\\xfe"),
-				"Expected byte-string debug body, got: {}",
-				body
+			assert_eq!(
+				body,
+				"00000000  ff fe 00 01                                       |....|\n"
 			);
 		}
 		other => panic!("Expected Body content with hex dump, got {:?}", other),
 	}
+	assert_eq!(response.raw_body, vec![0xFF, 0xFE, 0x00, 0x01]);
+}
+
+#[tokio::test]
+async fn test_body_over_max_size_is_truncated() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("GET", "/large")
+		.with_status(200)
+		.with_body("0123456789".repeat(100))
+		.create_async()
+		.await;
+
+	let url = format!("{}/large", server.url());
+	let request_builder = build_request_builder(&url);
+	let local_request = build_local_request(5000);
+
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request.clone(),
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		16,
+		None,
+	)
+	.await;
+	mock.assert_async().await;
+
+	let response = result.unwrap();
+	match response.content {
+		Some(ResponseContent::Body(body)) => assert_eq!(body, "0123456789012345"),
+		other => panic!("Expected Body content, got {:?}", other),
+	}
+	assert_eq!(response.body_size, Some(1000));
+	assert!(response.is_body_truncated);
+	assert!(local_request.read().download_progress.is_none());
+}
+
+#[tokio::test]
+async fn test_body_is_streamed_to_output_file() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("GET", "/download")
+		.with_status(200)
+		.with_body("0123456789".repeat(100))
+		.create_async()
+		.await;
+
+	let dir = tempfile::tempdir().unwrap();
+	let output = dir.path().join("body.txt");
+
+	let url = format!("{}/download", server.url());
+	let request_builder = build_request_builder(&url);
+	let local_request = build_local_request(5000);
+
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		16,
+		Some(&output),
+	)
+	.await;
+	mock.assert_async().await;
+
+	let response = result.unwrap();
+	assert!(response.is_body_truncated);
+	assert_eq!(
+		std::fs::read_to_string(output).unwrap(),
+		"0123456789".repeat(100)
+	);
 }
 
 #[tokio::test]
@@ -528,7 +918,16 @@ async fn test_404_not_found_status() {
 	let request_builder = build_request_builder(&url);
 	let local_request = build_local_request(5000);
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	mock.assert_async().await;
 
 	let response = result.unwrap();
@@ -554,7 +953,16 @@ async fn test_json_with_charset_in_content_type_still_pretty_prints() {
 	let request_builder = build_request_builder(&url);
 	let local_request = build_local_request(5000);
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	mock.assert_async().await;
 
 	let response = result.unwrap();
@@ -587,7 +995,16 @@ async fn test_xml_body_is_not_pretty_printed() {
 	let request_builder = build_request_builder(&url);
 	let local_request = build_local_request(5000);
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	mock.assert_async().await;
 
 	let response = result.unwrap();
@@ -612,7 +1029,16 @@ async fn test_no_cookies_header_gives_empty_cookies() {
 	let request_builder = build_request_builder(&url);
 	let local_request = build_local_request(5000);
 
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	mock.assert_async().await;
 
 	let response = result.unwrap();
@@ -636,7 +1062,16 @@ async fn test_cancellation_token_is_reset_after_request() {
 
 	// First request
 	let request_builder = build_request_builder(&url);
-	let _ = send_http_request(request_builder, local_request.clone(), &build_env()).await;
+	let _ = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request.clone(),
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 
 	// The cancellation token should be a fresh one, not already cancelled
 	assert!(
@@ -646,9 +1081,121 @@ async fn test_cancellation_token_is_reset_after_request() {
 
 	// Second request should succeed with the same local_request
 	let request_builder = build_request_builder(&url);
-	let result = send_http_request(request_builder, local_request, &build_env()).await;
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request,
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
 	mock.assert_async().await;
 
 	let response = result.unwrap();
 	assert_eq!(response.status_code, Some("200 OK".to_string()));
 }
+
+#[tokio::test]
+async fn test_event_stream_reconnects_with_last_event_id() {
+	let mut server = mockito::Server::new_async().await;
+	let stream_mock = server
+		.mock("GET", "/events")
+		.match_header("last-event-id", mockito::Matcher::Missing)
+		.with_status(200)
+		.with_header("content-type", "text/event-stream")
+		.with_body("retry: 10\n\nid: 1\ndata: first\n\nevent: update\nid: 2\ndata: second\n\n")
+		.create_async()
+		.await;
+	let reconnection_mock = server
+		.mock("GET", "/events")
+		.match_header("last-event-id", "2")
+		.with_status(204)
+		.create_async()
+		.await;
+
+	let url = format!("{}/events", server.url());
+	let request_builder = build_request_builder(&url);
+	let local_request = build_local_request(5000);
+
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request.clone(),
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
+	stream_mock.assert_async().await;
+	reconnection_mock.assert_async().await;
+
+	let response = result.unwrap();
+	assert_eq!(response.status_code, Some("200 OK".to_string()));
+
+	let request = local_request.read();
+	assert!(request.is_event_stream());
+
+	let messages: Vec<String> = request
+		.get_stream_messages()
+		.unwrap()
+		.iter()
+		.map(|message| message.content.to_content())
+		.collect();
+	assert_eq!(
+		messages,
+		vec![
+			"id: 1\nfirst",
+			"event: update\nid: 2\nsecond",
+			"Stream ended, reconnection answered 204 No Content",
+		]
+	);
+}
+
+#[tokio::test]
+async fn test_event_stream_is_read_until_cancelled() {
+	let mut server = mockito::Server::new_async().await;
+	server
+		.mock("GET", "/events")
+		.with_status(200)
+		.with_header("content-type", "text/event-stream")
+		.with_body("retry: 10\ndata: tick\n\n")
+		.expect_at_least(1)
+		.create_async()
+		.await;
+
+	let url = format!("{}/events", server.url());
+	let request_builder = build_request_builder(&url);
+	let local_request = build_local_request(5000);
+
+	let cancellation_token = local_request.read().cancellation_token.clone();
+	tokio::spawn(async move {
+		tokio::time::sleep(Duration::from_millis(300)).await;
+		cancellation_token.cancel();
+	});
+
+	let result = send_http_request(
+		request_builder,
+		ConnectionTimer::default(),
+		local_request.clone(),
+		&build_env(),
+		Arc::new(Mutex::new(false)),
+		DEFAULT_MAX_RESPONSE_BODY_SIZE,
+		None,
+	)
+	.await;
+
+	assert_eq!(result.unwrap().status_code, Some("200 OK".to_string()));
+
+	let request = local_request.read();
+	let messages = request.get_stream_messages().unwrap();
+	assert!(messages.len() > 1);
+	assert_eq!(messages[0].content.to_content(), "tick");
+	assert_eq!(
+		messages.last().unwrap().content.to_content(),
+		"Stream canceled"
+	);
+	assert!(!request.is_pending);
+}
@@ -0,0 +1,49 @@
+mod helpers;
+
+use helpers::{collection_with_request, seed_collection, seed_environment, send_request, temp_dir};
+use mockito::Matcher;
+use predicates::prelude::*;
+use std::fs;
+
+#[test]
+fn test_send_evaluates_templates_and_warns_about_unresolved_variables() {
+	let mut server = mockito::Server::new();
+	let mock = server
+		.mock("GET", "/v1/users")
+		.match_header("authorization", "Bearer file-token")
+		.match_header("x-user", "c3F1cmw6cGFzc3dvcmQ=")
+		.match_header("x-id", Matcher::Regex(String::from("^[1-9][0-9]$")))
+		.match_header("x-missing", "{{MISSING}}")
+		.create();
+
+	let dir = temp_dir();
+	seed_environment(dir.path(), "dev", "PASSWORD=password");
+	let token_path = dir.path().join("token.txt");
+	fs::write(&token_path, "file-token").unwrap();
+
+	// Template functions in the URL and headers
+	let collection = collection_with_request(
+		&format!("{}/{{{{VERSION:-v1}}}}/users", server.url()),
+		|collection| {
+			collection["requests"][0]["headers"] = serde_json::json!([
+				{"enabled": true, "data": ["authorization", format!("Bearer {{{{$file(\"{}\")}}}}", token_path.display())]},
+				{"enabled": true, "data": ["x-user", "{{$base64(\"{{$env.SQURL_TEST_USER}}:{{PASSWORD}}\")}}"]},
+				{"enabled": true, "data": ["x-id", "{{$randomInt(10, 99)}}"]},
+				{"enabled": true, "data": ["x-missing", "{{MISSING}}"]}
+			]);
+		},
+	);
+	seed_collection(dir.path(), "my-api", &collection);
+
+	send_request(dir.path(), "my-api/req")
+		.env("SQURL_TEST_USER", "squrl")
+		.args(["--env", "dev"])
+		.assert()
+		.success()
+		.stderr(predicate::str::contains(
+			"Warning: Unresolved variable \"MISSING\", sent as is",
+		))
+		.stderr(predicate::str::contains("VERSION").not());
+
+	mock.assert();
+}
@@ -0,0 +1,312 @@
+mod helpers;
+
+use std::sync::Arc;
+
+use helpers::tls_server::{TlsTestServer, tls_fixture};
+use helpers::{
+	collection_with_request, minimal_collection_json, seed_collection, send_request, squrl,
+	temp_dir,
+};
+use parking_lot::{Mutex, RwLock};
+use predicates::prelude::*;
+use serde_json::Value;
+use squrl::app::constants::DEFAULT_MAX_RESPONSE_BODY_SIZE;
+use squrl::app::request::http::send::send_http_request;
+use squrl::app::request::http::timing::ConnectionTimer;
+use squrl::app::request::tls::rustls_client_config;
+use squrl::models::request::Request;
+use squrl::models::settings::{RequestSettings, Setting, TlsSettings};
+
+#[test]
+fn test_request_client_certificate_and_ca() {
+	let server = TlsTestServer::start();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&server.url(), |collection| {
+			collection["requests"][0]["settings"]["tls"] = serde_json::json!({
+				"client_certificate": tls_fixture("client.pem"),
+				"client_key": tls_fixture("client.key"),
+				"ca_certificates": [tls_fixture("ca.pem")]
+			});
+		}),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("mtls ok"));
+}
+
+#[test]
+fn test_collection_tls_settings_are_inherited() {
+	let server = TlsTestServer::start();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&server.url(), |collection| {
+			collection["tls"] = serde_json::json!({
+				"client_certificate": tls_fixture("client.pem"),
+				"client_key": tls_fixture("client.key"),
+				"ca_certificates": [tls_fixture("ca.pem")]
+			});
+		}),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("mtls ok"));
+}
+
+#[test]
+fn test_server_rejects_request_without_client_certificate() {
+	let server = TlsTestServer::start();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&server.url(), |collection| {
+			collection["requests"][0]["settings"]["tls"] = serde_json::json!({
+				"ca_certificates": [tls_fixture("ca.pem")]
+			});
+		}),
+	);
+
+	// The handshake fails, which is reported as the response
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.stdout(predicate::str::contains("error sending request"))
+		.stdout(predicate::str::contains("mtls ok").not());
+}
+
+#[test]
+fn test_pkcs12_client_certificate() {
+	let server = TlsTestServer::start();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&server.url(), |collection| {
+			collection["requests"][0]["settings"]["tls"] = serde_json::json!({
+				"client_certificate": tls_fixture("client.p12"),
+				"client_certificate_password": "squrl",
+				"ca_certificates": [tls_fixture("ca.pem")]
+			});
+		}),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("mtls ok"));
+}
+
+#[test]
+fn test_pkcs12_client_certificate_errors_are_reported() {
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request("https://localhost", |collection| {
+			collection["requests"][0]["settings"]["tls"] = serde_json::json!({
+				"client_certificate": tls_fixture("client.p12"),
+				"client_certificate_password": "wrong"
+			});
+		}),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.failure()
+		.stderr(predicate::str::contains("COULD NOT LOAD TLS CERTIFICATES"))
+		.stderr(predicate::str::contains("client.p12: wrong password"));
+
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request("https://localhost", |collection| {
+			collection["requests"][0]["settings"]["tls"] = serde_json::json!({
+				"client_certificate": tls_fixture("client-legacy.p12"),
+				"client_certificate_password": "squrl"
+			});
+		}),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.assert()
+		.failure()
+		.stderr(predicate::str::contains(
+			"unsupported encryption algorithm, export the archive again",
+		));
+}
+
+#[test]
+fn test_request_settings_set_tls_paths() {
+	let dir = temp_dir();
+	let collection_path = seed_collection(
+		dir.path(),
+		"my-api",
+		&minimal_collection_json("my-api", "req", "https://example.com"),
+	);
+
+	let settings = |args: &[&str]| {
+		squrl()
+			.args([
+				"-d",
+				dir.path().to_str().unwrap(),
+				"request",
+				"settings",
+				"my-api/req",
+			])
+			.args(args)
+			.assert()
+	};
+
+	settings(&["set", "client-cert", "certs/client.pem"]).success();
+	settings(&["set", "ca-certs", "certs/ca.pem, certs/other-ca.pem"]).success();
+	settings(&["set", "client-key", "true"])
+		.failure()
+		.stderr(predicate::str::contains("only takes file path values"));
+	settings(&["set", "client-cert-password", "1234"]).success();
+	settings(&["get", "client-cert-password"])
+		.success()
+		.stdout("1234\n");
+
+	settings(&["get", "client-cert"])
+		.success()
+		.stdout("certs/client.pem\n");
+	settings(&["all"])
+		.success()
+		.stdout(predicate::str::contains(
+			"CA certificates: certs/ca.pem, certs/other-ca.pem",
+		));
+
+	let collection: Value =
+		serde_json::from_str(&std::fs::read_to_string(&collection_path).unwrap()).unwrap();
+	assert_eq!(
+		collection["requests"][0]["settings"]["tls"],
+		serde_json::json!({
+			"client_certificate": "certs/client.pem",
+			"client_certificate_password": "1234",
+			"ca_certificates": ["certs/ca.pem", "certs/other-ca.pem"]
+		})
+	);
+
+	// An empty path removes the certificate
+	settings(&["set", "client-cert", ""]).success();
+	settings(&["get", "client-cert"]).success().stdout("\n");
+}
+
+#[test]
+fn test_timing_shows_the_connection_and_server_certificate() {
+	let server = TlsTestServer::start();
+
+	let dir = temp_dir();
+	seed_collection(
+		dir.path(),
+		"my-api",
+		&collection_with_request(&server.url(), |collection| {
+			collection["requests"][0]["settings"]["tls"] = serde_json::json!({
+				"client_certificate": tls_fixture("client.pem"),
+				"client_key": tls_fixture("client.key"),
+				"ca_certificates": [tls_fixture("ca.pem")]
+			});
+		}),
+	);
+
+	send_request(dir.path(), "my-api/req")
+		.args(["--timing", "--hide-content"])
+		.assert()
+		.success()
+		.stdout(predicate::str::contains("TCP connect"))
+		.stdout(predicate::str::is_match(r"TLS handshake +\d").unwrap())
+		.stdout(predicate::str::is_match(r"TLS version +TLSv1\.3").unwrap())
+		.stdout(predicate::str::is_match(r"Cipher suite +TLS13_").unwrap())
+		.stdout(predicate::str::contains("Time to first byte"))
+		.stdout(predicate::str::contains("HTTP version"))
+		.stdout(predicate::str::contains("CN=squrl test server"))
+		.stdout(predicate::str::contains("CN=squrl test CA"))
+		.stdout(predicate::str::contains("localhost, 127.0.0.1"))
+		.stdout(
+			predicate::str::is_match(
+				r"Chain certificate +CN=squrl test CA \(issuer CN=squrl test CA, valid until",
+			)
+			.unwrap(),
+		);
+}
+
+#[tokio::test]
+async fn test_tls_handshake_is_recorded_through_the_connection_pool() {
+	let server = TlsTestServer::start_http2();
+
+	let tls = TlsSettings {
+		client_certificate: Some(tls_fixture("client.pem")),
+		client_key: Some(tls_fixture("client.key")),
+		client_certificate_password: None,
+		ca_certificates: vec![tls_fixture("ca.pem")],
+	};
+	let connection_timer = ConnectionTimer::default();
+	let client = reqwest::Client::builder()
+		.tls_backend_preconfigured(rustls_client_config(&tls, false, false).unwrap())
+		.dns_resolver(connection_timer.clone())
+		.connector_layer(connection_timer.clone())
+		.tls_info(true)
+		.build()
+		.unwrap();
+	let client = reqwest_middleware::ClientBuilder::new(client).build();
+
+	let mut responses = vec![];
+
+	// The second request reuses the HTTP/2 connection of the first one
+	for _ in 0..2 {
+		let local_request = Arc::new(RwLock::new(Request {
+			settings: RequestSettings {
+				timeout: Setting::U32(5000),
+				..Default::default()
+			},
+			..Default::default()
+		}));
+
+		let response = send_http_request(
+			client.get(server.url()),
+			connection_timer.clone(),
+			local_request,
+			&None,
+			Arc::new(Mutex::new(false)),
+			DEFAULT_MAX_RESPONSE_BODY_SIZE,
+			None,
+		)
+		.await
+		.unwrap();
+
+		responses.push(response);
+	}
+
+	for response in &responses {
+		let connection = response.connection.as_ref().unwrap();
+		assert_eq!(connection.http_version, "HTTP/2.0");
+		assert_eq!(connection.tls_version.as_deref(), Some("TLSv1.3"));
+		assert!(
+			connection
+				.cipher_suite
+				.as_ref()
+				.is_some_and(|cipher_suite| cipher_suite.starts_with("TLS13_"))
+		);
+		assert_eq!(connection.certificate_chain.len(), 1);
+	}
+
+	let first_timing = responses[0].timing.as_ref().unwrap();
+	assert!(first_timing.connect.is_some());
+	assert!(first_timing.tls_handshake.is_some());
+
+	let second_timing = responses[1].timing.as_ref().unwrap();
+	assert_eq!(second_timing.connect, None);
+	assert_eq!(second_timing.tls_handshake, None);
+}